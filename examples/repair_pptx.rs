@@ -124,6 +124,8 @@ fn demonstrate_issue_types() {
                 RepairIssue::CorruptedEntry { .. } => "CorruptedEntry",
                 RepairIssue::MissingNamespace { .. } => "MissingNamespace",
                 RepairIssue::EmptyRequiredElement { .. } => "EmptyRequiredElement",
+                RepairIssue::MissingLayoutRel { .. } => "MissingLayoutRel",
+                RepairIssue::DanglingRelId { .. } => "DanglingRelId",
             },
             issue.severity(),
             if issue.is_repairable() { "Yes" } else { "No" }
@@ -2,19 +2,30 @@
 //!
 //! High-level API for working with PowerPoint presentations.
 
+use crate::core::{
+    check_content, validate_package_bytes, PackageValidationIssue, SlideSize, SlideSizePreset,
+    ValidationCategory, ValidationWarning,
+};
 use crate::exc::{messages, PptxError, Result};
 use crate::export::html::export_to_html;
-use crate::generator::{create_pptx_with_settings, Image, PresentationSettings, PresentationTheme, SlideContent};
+use crate::generator::{create_pptx_with_content_to_writer, create_pptx_with_settings, Audio, AudioOptions, Compression, Image, PptxTemplate, PresentationSettings, PresentationTheme, PrintSettings, SlideContent, SlideMaster, SlideShowSettings};
 use crate::import::import_pptx;
+#[cfg(feature = "signing")]
+use crate::opc::PackageSigner;
+use std::io::{BufWriter, Seek, Write};
 use std::path::Path;
 use std::process::Command;
 
 /// Represents a PowerPoint presentation
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Presentation {
     title: String,
     slides: Vec<SlideContent>,
     settings: Option<PresentationSettings>,
+    #[cfg(feature = "signing")]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    signer: Option<PackageSigner>,
 }
 
 impl Presentation {
@@ -24,6 +35,8 @@ impl Presentation {
             title: String::new(),
             slides: Vec::new(),
             settings: None,
+            #[cfg(feature = "signing")]
+            signer: None,
         }
     }
 
@@ -33,9 +46,26 @@ impl Presentation {
             title: title.to_string(),
             slides: Vec::new(),
             settings: None,
+            #[cfg(feature = "signing")]
+            signer: None,
         }
     }
 
+    /// Start an empty presentation on an existing `.pptx` or `.potx` file's slide
+    /// masters, layouts, theme and the media they use
+    ///
+    /// New slides are placed on the template layout whose type matches their
+    /// [`SlideLayout`](crate::generator::SlideLayout); the template's own slides
+    /// are not copied.
+    pub fn from_template<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        PptxTemplate::load(path)?;
+        Ok(Presentation {
+            settings: Some(PresentationSettings::new().template(path.to_string_lossy())),
+            ..Presentation::new()
+        })
+    }
+
     /// Set the presentation title
     pub fn title(mut self, title: &str) -> Self {
         self.title = title.to_string();
@@ -50,10 +80,50 @@ impl Presentation {
 
     /// Append slides from another presentation
     pub fn add_presentation(mut self, other: Presentation) -> Self {
-        self.slides.extend(other.slides);
+        self.append(other);
         self
     }
 
+    /// Move the slides of `other`, with their images and charts, to the end of this deck.
+    ///
+    /// Slide ids, relationship ids and media/chart part names are assigned when the
+    /// merged deck is built, so parts from different sources never collide; images with
+    /// identical bytes share one media part.
+    pub fn append(&mut self, other: Presentation) {
+        self.slides.extend(other.slides);
+    }
+
+    /// Insert a slide at `index`, shifting later slides back; `index` may equal the slide count
+    pub fn insert_slide(&mut self, index: usize, slide: SlideContent) -> Result<()> {
+        if index > self.slides.len() {
+            return Err(PptxError::NotFound(messages::slide_not_found(index)));
+        }
+        self.slides.insert(index, slide);
+        Ok(())
+    }
+
+    /// Remove and return the slide at `index`
+    pub fn remove_slide(&mut self, index: usize) -> Result<SlideContent> {
+        if index >= self.slides.len() {
+            return Err(PptxError::NotFound(messages::slide_not_found(index)));
+        }
+        Ok(self.slides.remove(index))
+    }
+
+    /// Move the slide at `from` so it ends up at `to`.
+    ///
+    /// Slide parts, their relationships and the slide id list are numbered in
+    /// deck order when the presentation is built.
+    pub fn move_slide(&mut self, from: usize, to: usize) -> Result<()> {
+        let len = self.slides.len();
+        if let Some(index) = [from, to].into_iter().find(|&i| i >= len) {
+            return Err(PptxError::NotFound(messages::slide_not_found(index)));
+        }
+        let slide = self.slides.remove(from);
+        self.slides.insert(to, slide);
+        Ok(())
+    }
+
     /// Get the number of slides
     pub fn slide_count(&self) -> usize {
         self.slides.len()
@@ -77,6 +147,182 @@ impl Presentation {
         self
     }
 
+    /// Replace the stock slide master's background, title/body text styles and
+    /// placeholder frames; placeholder text across the deck inherits them
+    pub fn slide_master(mut self, master: SlideMaster) -> Self {
+        let settings = self.settings.take().unwrap_or_default();
+        self.settings = Some(settings.slide_master(master));
+        self
+    }
+
+    /// Add another slide master, with its own layouts and theme, for slides that
+    /// pick it with [`SlideContent::use_master`]; the first one added is master 2
+    pub fn add_master(mut self, master: SlideMaster) -> Self {
+        let settings = self.settings.take().unwrap_or_default();
+        self.settings = Some(settings.add_master(master));
+        self
+    }
+
+    /// Set custom slide dimensions in EMU (e.g. `12192000 x 6858000` for widescreen)
+    pub fn slide_size(self, width: u32, height: u32) -> Self {
+        self.set_slide_size(SlideSize::new(width, height))
+    }
+
+    /// Set slide dimensions from a preset (16:9, A4, ...)
+    pub fn slide_size_preset(self, preset: SlideSizePreset) -> Self {
+        self.set_slide_size(SlideSize::from_preset(preset))
+    }
+
+    /// Preconfigure the print dialog: what to print, handout layout, color mode.
+    ///
+    /// Written to `ppt/presProps.xml`; printing handouts also packages a handout master.
+    pub fn print_settings(mut self, print: PrintSettings) -> Self {
+        let settings = self.settings.take().unwrap_or_default();
+        self.settings = Some(settings.print(print));
+        self
+    }
+
+    /// How the deck runs as a slide show: presented, browsed or kiosk, looping,
+    /// pen color and which slides play.
+    ///
+    /// Written to `ppt/presProps.xml`; a custom slide range is also listed in
+    /// `ppt/presentation.xml`.
+    pub fn slide_show(mut self, show: SlideShowSettings) -> Self {
+        let settings = self.settings.take().unwrap_or_default();
+        self.settings = Some(settings.slide_show(show));
+        self
+    }
+
+    /// Record `timestamp` (a UTC time like `2024-01-31T09:00:00Z`) as the creation time
+    /// in `docProps/core.xml` instead of the time of building.
+    ///
+    /// Together with [`Self::modified_at`] this makes builds reproducible: the same
+    /// input yields byte-identical output. Fails on a malformed time or one outside 1980–2107.
+    pub fn created_at(mut self, timestamp: &str) -> Result<Self> {
+        self.settings = Some(self.settings.take().unwrap_or_default().created_at(timestamp)?);
+        Ok(self)
+    }
+
+    /// Record `timestamp` as the last-modified time; it also stamps every ZIP entry.
+    /// Defaults to the creation time when only that is set.
+    pub fn modified_at(mut self, timestamp: &str) -> Result<Self> {
+        self.settings = Some(self.settings.take().unwrap_or_default().modified_at(timestamp)?);
+        Ok(self)
+    }
+
+    /// ZIP compression for the package: `Stored`, `Fast`, `Default` (Deflate at
+    /// the default level, used when this is never called) or `Best`.
+    ///
+    /// Applies to media too unless [`Self::media_compression`] overrides it.
+    pub fn compression(mut self, compression: Compression) -> Self {
+        let settings = self.settings.take().unwrap_or_default();
+        self.settings = Some(settings.compression(compression));
+        self
+    }
+
+    /// ZIP compression for pictures and clips under `ppt/media/`. JPEG, PNG and
+    /// video are already compressed, so `Stored` saves CPU at little cost in size.
+    pub fn media_compression(mut self, compression: Compression) -> Self {
+        let settings = self.settings.take().unwrap_or_default();
+        self.settings = Some(settings.media_compression(compression));
+        self
+    }
+
+    /// Header text on every printed handout page (packages a handout master)
+    pub fn handout_header(mut self, text: &str) -> Self {
+        let settings = self.settings.take().unwrap_or_default();
+        self.settings = Some(settings.handout_header(text));
+        self
+    }
+
+    /// Footer text on every printed handout page (packages a handout master)
+    pub fn handout_footer(mut self, text: &str) -> Self {
+        let settings = self.settings.take().unwrap_or_default();
+        self.settings = Some(settings.handout_footer(text));
+        self
+    }
+
+    /// Header text on every notes page, on the notes master and each notes slide
+    pub fn notes_header(mut self, text: &str) -> Self {
+        let settings = self.settings.take().unwrap_or_default();
+        self.settings = Some(settings.notes_header(text));
+        self
+    }
+
+    /// Footer text on every notes page, on the notes master and each notes slide
+    pub fn notes_footer(mut self, text: &str) -> Self {
+        let settings = self.settings.take().unwrap_or_default();
+        self.settings = Some(settings.notes_footer(text));
+        self
+    }
+
+    /// Store identical images and clips once in `ppt/media/`, with every slide that
+    /// uses them pointing at the shared part. On by default; pass `false` to give
+    /// each slide its own copy.
+    pub fn dedup_media(mut self, enabled: bool) -> Self {
+        let settings = self.settings.take().unwrap_or_default();
+        self.settings = Some(settings.dedup_media(enabled));
+        self
+    }
+
+    /// Digitally sign the deck so PowerPoint reports it as signed and flags any later edit.
+    ///
+    /// `certificate` is an X.509 certificate and `private_key` its RSA key, each as
+    /// DER or PEM; keys may be PKCS#1 or unencrypted PKCS#8. The hash algorithm,
+    /// signing time and comments come from [`PresentationSettings::digital_signature`]
+    /// when set, otherwise SHA-256 and the time of building. Fails when the key or
+    /// certificate cannot be read or do not belong together.
+    #[cfg(feature = "signing")]
+    pub fn sign(
+        mut self,
+        certificate: &[u8],
+        private_key: &[u8],
+        commitment: crate::generator::SignatureCommitment,
+    ) -> Result<Self> {
+        self.signer = Some(PackageSigner::new(certificate, private_key, commitment)?);
+        Ok(self)
+    }
+
+    fn set_slide_size(mut self, size: SlideSize) -> Self {
+        let mut settings = self.settings.take().unwrap_or_default();
+        settings.slide_size = Some(size);
+        self.settings = Some(settings);
+        self
+    }
+
+    /// Play `audio` as background music from the first slide.
+    ///
+    /// The clip is embedded on slide 1 and started with the show; set
+    /// `play_across_slides` so it doesn't stop when the next slide appears, and
+    /// `hide_icon` to keep the speaker icon out of the show.
+    pub fn background_audio(mut self, audio: Audio, options: AudioOptions) -> Self {
+        let settings = self.settings.take().unwrap_or_default();
+        self.settings = Some(settings.background_audio(audio, options));
+        self
+    }
+
+    /// Get the slide dimensions (4:3 unless configured)
+    pub fn get_slide_size(&self) -> SlideSize {
+        self.settings.as_ref().and_then(|s| s.slide_size).unwrap_or_default()
+    }
+
+    /// Get the custom theme, if one is set (imported decks carry their own)
+    pub fn get_theme(&self) -> Option<&PresentationTheme> {
+        self.settings.as_ref().and_then(|s| s.theme.as_ref())
+    }
+
+    /// Serialize the deck definition (slides and settings) to JSON
+    #[cfg(feature = "serde")]
+    pub fn to_json(&self) -> Result<String> {
+        serde_json::to_string_pretty(self).map_err(|e| PptxError::Generic(e.to_string()))
+    }
+
+    /// Rebuild a deck definition from [`to_json`](Self::to_json) output
+    #[cfg(feature = "serde")]
+    pub fn from_json(json: &str) -> Result<Self> {
+        serde_json::from_str(json).map_err(|e| PptxError::InvalidValue(e.to_string()))
+    }
+
     /// Set presentation-level settings (theme, slide show, print, etc.)
     pub fn with_settings(mut self, settings: PresentationSettings) -> Self {
         self.settings = Some(settings);
@@ -90,8 +336,52 @@ impl Presentation {
                 messages::must_not_be_empty("presentation slides"),
             ));
         }
-        create_pptx_with_settings(&self.title, &self.slides, self.settings.clone())
-            .map_err(|e| PptxError::Generic(e.to_string()))
+        let bytes = create_pptx_with_settings(&self.title, &self.slides, self.settings.clone())
+            .map_err(|e| PptxError::Generic(e.to_string()))?;
+        self.apply_signature(bytes)
+    }
+
+    /// Sign built package bytes when a signer is configured
+    #[cfg(feature = "signing")]
+    fn apply_signature(&self, bytes: Vec<u8>) -> Result<Vec<u8>> {
+        match &self.signer {
+            Some(signer) => signer.sign(&bytes, self.settings.as_ref().and_then(|s| s.digital_signature.as_ref())),
+            None => Ok(bytes),
+        }
+    }
+
+    #[cfg(not(feature = "signing"))]
+    fn apply_signature(&self, bytes: Vec<u8>) -> Result<Vec<u8>> {
+        Ok(bytes)
+    }
+
+    #[cfg(feature = "signing")]
+    fn is_signed(&self) -> bool {
+        self.signer.is_some()
+    }
+
+    #[cfg(not(feature = "signing"))]
+    fn is_signed(&self) -> bool {
+        false
+    }
+
+    /// Check the presentation without changing it.
+    ///
+    /// Reports malformed colors and negative coordinates in the slide content, then
+    /// builds the deck and runs the package rules over it: every `sldIdLst` entry
+    /// resolves to a slide part, every `r:embed`/chart `r:id` resolves, and every
+    /// layout listed by the master exists. An empty result means the deck is clean.
+    pub fn validate(&self) -> Vec<ValidationWarning> {
+        let mut warnings = check_content(&self.slides, self.settings.as_ref());
+        match self.build() {
+            Ok(bytes) => warnings.extend(validate_package_bytes(&bytes).issues),
+            Err(e) => warnings.push(PackageValidationIssue::error(
+                ValidationCategory::Presentation,
+                format!("presentation could not be built: {e}"),
+                None,
+            )),
+        }
+        warnings
     }
 
     /// Consume the presentation and build PPTX bytes without cloning slide data.
@@ -101,14 +391,39 @@ impl Presentation {
                 messages::must_not_be_empty("presentation slides"),
             ));
         }
-        create_pptx_with_settings(&self.title, &self.slides, self.settings)
-            .map_err(|e| PptxError::Generic(e.to_string()))
+        #[cfg(feature = "signing")]
+        let signer = self
+            .signer
+            .map(|signer| (signer, self.settings.as_ref().and_then(|s| s.digital_signature.clone())));
+        let bytes = create_pptx_with_settings(&self.title, &self.slides, self.settings)
+            .map_err(|e| PptxError::Generic(e.to_string()))?;
+        #[cfg(feature = "signing")]
+        if let Some((signer, details)) = signer {
+            return signer.sign(&bytes, details.as_ref());
+        }
+        Ok(bytes)
+    }
+
+    /// Stream the presentation as PPTX into `writer` without buffering the whole archive
+    pub fn save_to<W: Write + Seek>(&self, writer: W) -> Result<W> {
+        if self.slides.is_empty() {
+            return Err(PptxError::InvalidState(
+                messages::must_not_be_empty("presentation slides"),
+            ));
+        }
+        if self.is_signed() {
+            // The signature covers the finished parts, so signed decks are built in memory
+            let mut writer = writer;
+            writer.write_all(&self.build()?)?;
+            return Ok(writer);
+        }
+        create_pptx_with_content_to_writer(writer, &self.title, &self.slides, self.settings.clone())
     }
 
     /// Save the presentation to a file
     pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<()> {
-        let data = self.build()?;
-        std::fs::write(path, data)?;
+        let file = std::fs::File::create(path)?;
+        self.save_to(BufWriter::new(file))?.flush()?;
         Ok(())
     }
 
@@ -169,6 +484,36 @@ impl Presentation {
         crate::export::pdf_export::export_to_pdf_bytes(self, options)
     }
 
+    /// Render a single slide as an SVG document for web embedding.
+    ///
+    /// Coordinates are mapped from EMU to SVG user units (1 unit = 1 pt).
+    ///
+    /// # Arguments
+    /// * `index` - 0-based slide index
+    ///
+    /// Requires the `render` Cargo feature.
+    #[cfg(feature = "render")]
+    pub fn render_slide_svg(&self, index: usize) -> Result<String> {
+        crate::core::validate_index(index, self.slides.len(), "slide index")?;
+        Ok(crate::export::svg::render_slide_svg(&self.slides[index], self.settings.as_ref()))
+    }
+
+    /// Render a single slide as a PNG thumbnail without external tools.
+    ///
+    /// Element boxes are placed at their EMU positions scaled to `width`
+    /// pixels and drawn in theme colors; text is approximated as glyph blocks.
+    ///
+    /// # Arguments
+    /// * `index` - 0-based slide index
+    /// * `width` - Output width in pixels; the height follows the slide's aspect ratio
+    ///
+    /// Requires the `render` Cargo feature.
+    #[cfg(feature = "render")]
+    pub fn render_slide_png(&self, index: usize, width: u32) -> Result<Vec<u8>> {
+        crate::core::validate_index(index, self.slides.len(), "slide index")?;
+        crate::export::png::render_slide_png(&self.slides[index], self.settings.as_ref(), width)
+    }
+
     /// Export slides to PNG images
     ///
     /// Requires `pdftoppm` (from poppler) to be installed.
@@ -435,6 +780,36 @@ impl Presentation {
 mod tests {
     use super::*;
 
+    fn titles(pres: &Presentation) -> Vec<&str> {
+        pres.slides().iter().map(|s| s.title.as_str()).collect()
+    }
+
+    #[test]
+    fn test_insert_remove_move_slides() {
+        let mut pres = Presentation::with_title("Edit")
+            .add_slide(SlideContent::new("A"))
+            .add_slide(SlideContent::new("B"))
+            .add_slide(SlideContent::new("C"));
+
+        pres.insert_slide(1, SlideContent::new("New")).unwrap();
+        pres.insert_slide(4, SlideContent::new("End")).unwrap();
+        assert_eq!(titles(&pres), ["A", "New", "B", "C", "End"]);
+
+        assert_eq!(pres.remove_slide(2).unwrap().title, "B");
+        pres.move_slide(3, 0).unwrap();
+        pres.move_slide(1, 2).unwrap();
+        assert_eq!(titles(&pres), ["End", "New", "A", "C"]);
+
+        assert!(pres.insert_slide(6, SlideContent::new("X")).is_err());
+        assert!(pres.remove_slide(4).is_err());
+        assert!(pres.move_slide(0, 4).is_err());
+        assert_eq!(pres.slide_count(), 4);
+
+        // Parts are renumbered in the new order
+        let reimported = crate::import::import_pptx_bytes(&pres.build().unwrap()).unwrap();
+        assert_eq!(titles(&reimported), ["End", "New", "A", "C"]);
+    }
+
     #[test]
     fn test_presentation_builder() {
         let pres = Presentation::with_title("Test")
@@ -451,4 +826,160 @@ mod tests {
         let result = pres.build();
         assert!(result.is_ok());
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_presentation_json_round_trip() {
+        use crate::generator::{ChartBuilder, ChartSeries, ChartType, Shape, ShapeType, TableBuilder};
+        use std::io::Read;
+
+        let pres = Presentation::with_title("Stored Deck")
+            .with_theme(PresentationTheme::corporate_blue())
+            .add_slide(
+                SlideContent::new("Overview")
+                    .add_bullet("First")
+                    .add_shape(Shape::new(ShapeType::RoundedRectangle, 914_400, 914_400, 1_828_800, 914_400).with_text("Box"))
+                    .notes("Speaker notes"),
+            )
+            .add_slide(SlideContent::new("Numbers").table(
+                TableBuilder::new(vec![1_828_800, 1_828_800]).add_simple_row(vec!["A", "B"]).build(),
+            ))
+            .add_slide(SlideContent::new("Chart").add_chart(
+                ChartBuilder::new("Sales", ChartType::Bar)
+                    .categories(vec!["Q1", "Q2"])
+                    .add_series(ChartSeries::new("2024", vec![1.0, 2.5]))
+                    .build(),
+            ));
+
+        let json = pres.to_json().unwrap();
+        assert!(json.contains(r#""RoundedRectangle""#));
+        let restored = Presentation::from_json(&json).unwrap();
+        assert_eq!(restored.to_json().unwrap(), json);
+
+        // Every part except the creation timestamp in docProps/core.xml is identical
+        let parts = |bytes: Vec<u8>| {
+            let mut archive = zip::ZipArchive::new(std::io::Cursor::new(bytes)).unwrap();
+            (0..archive.len())
+                .map(|i| {
+                    let mut file = archive.by_index(i).unwrap();
+                    let mut data = Vec::new();
+                    file.read_to_end(&mut data).unwrap();
+                    (file.name().to_string(), data)
+                })
+                .filter(|(name, _)| name != "docProps/core.xml")
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(parts(pres.build().unwrap()), parts(restored.build().unwrap()));
+
+        assert!(Presentation::from_json("{").is_err());
+    }
+
+    #[test]
+    fn test_presentation_save_to_writer() {
+        let pres = Presentation::with_title("Test").add_slide(SlideContent::new("Slide 1"));
+        let cursor = pres.save_to(std::io::Cursor::new(Vec::new())).unwrap();
+        let bytes = cursor.into_inner();
+        assert!(bytes.starts_with(b"PK"));
+        assert!(crate::core::validate_package_bytes(&bytes).is_valid());
+
+        assert!(Presentation::new().save_to(std::io::Cursor::new(Vec::new())).is_err());
+    }
+
+    #[cfg(feature = "render")]
+    #[test]
+    fn test_render_slide_png() {
+        let pres = Presentation::with_title("Test").add_slide(SlideContent::new("Slide 1"));
+        let png = pres.render_slide_png(0, 200).unwrap();
+        assert!(png.starts_with(b"\x89PNG"));
+        assert!(pres.render_slide_png(1, 200).is_err());
+    }
+
+    #[test]
+    fn test_validate_reports_content_and_package_issues() {
+        let clean = Presentation::with_title("Clean")
+            .add_slide(SlideContent::new("Slide 1").add_bullet("Point").title_color("1F4E79"));
+        assert_eq!(clean.validate(), Vec::new());
+
+        let mut bad_code = crate::generator::CodeBlock::new("x", "rust");
+        bad_code.x = -5;
+        let mut slide = SlideContent::new("Slide 2").content_color("BLEU");
+        slide.code_blocks.push(bad_code);
+        let warnings = clean.clone().add_slide(slide).validate();
+        assert_eq!(warnings.len(), 3, "{warnings:?}");
+        assert!(warnings[..2].iter().all(|w| w.path.as_deref() == Some("ppt/slides/slide2.xml")));
+        // The unparsed color also stops the build
+        assert_eq!(warnings[2].category, ValidationCategory::Presentation);
+        assert!(warnings[2].message.contains("\"BLEU\""), "{}", warnings[2].message);
+
+        let empty = Presentation::new().validate();
+        assert_eq!(empty[0].category, ValidationCategory::Presentation);
+    }
+
+    #[test]
+    fn test_print_settings_written_to_pres_props() {
+        use crate::generator::{HandoutLayout, PrintColorMode, PrintWhat};
+
+        let bytes = Presentation::with_title("Handouts")
+            .add_slide(SlideContent::new("Slide 1"))
+            .print_settings(
+                PrintSettings::new()
+                    .print_what(PrintWhat::Handouts)
+                    .handout_layout(HandoutLayout::SlidesPerPage6)
+                    .color_mode(PrintColorMode::Grayscale),
+            )
+            .build()
+            .unwrap();
+        let mut archive = zip::ZipArchive::new(std::io::Cursor::new(&bytes)).unwrap();
+        let mut props = String::new();
+        std::io::Read::read_to_string(&mut archive.by_name("ppt/presProps.xml").unwrap(), &mut props).unwrap();
+        assert!(props.contains(r#"<p:prnPr prnWhat="handouts6" clrMode="gray" scaleToFitPaper="1"/><p:extLst>"#));
+        assert!(archive.by_name("ppt/handoutMasters/handoutMaster1.xml").is_ok());
+        assert!(validate_package_bytes(&bytes).is_valid());
+    }
+
+
+    #[test]
+    fn test_slide_show_written_to_pres_props() {
+        let bytes = Presentation::with_title("Kiosk")
+            .add_slide(SlideContent::new("Slide 1"))
+            .slide_show(SlideShowSettings::kiosk())
+            .build()
+            .unwrap();
+        let mut archive = zip::ZipArchive::new(std::io::Cursor::new(&bytes)).unwrap();
+        let mut props = String::new();
+        std::io::Read::read_to_string(&mut archive.by_name("ppt/presProps.xml").unwrap(), &mut props).unwrap();
+        assert!(props.contains(r#"<p:showPr loop="1" showNarration="0"><p:kiosk restart="300000"/><p:sldAll/>"#));
+    }
+
+    #[test]
+    fn test_repeated_logo_stored_once_unless_dedup_disabled() {
+        let png = include_bytes!("../examples/assets/diagram.png").to_vec();
+        let deck = || {
+            (1..=5).fold(Presentation::with_title("Logos"), |pres, i| {
+                pres.add_slide(
+                    SlideContent::new(&format!("Slide {i}"))
+                        .add_image(crate::generator::Image::from_bytes(png.clone(), 914_400, 914_400, "PNG")),
+                )
+            })
+        };
+        let media_parts = |bytes: &[u8]| {
+            let archive = zip::ZipArchive::new(std::io::Cursor::new(bytes)).unwrap();
+            archive.file_names().filter(|name| name.starts_with("ppt/media/")).count()
+        };
+        let rels = |bytes: &[u8], slide: usize| {
+            let mut archive = zip::ZipArchive::new(std::io::Cursor::new(bytes)).unwrap();
+            let mut xml = String::new();
+            let name = format!("ppt/slides/_rels/slide{slide}.xml.rels");
+            std::io::Read::read_to_string(&mut archive.by_name(&name).unwrap(), &mut xml).unwrap();
+            xml
+        };
+
+        let shared = deck().build().unwrap();
+        assert_eq!(media_parts(&shared), 1);
+        assert!((1..=5).all(|i| rels(&shared, i).contains(r#"Target="../media/image1.png""#)));
+
+        let copies = deck().dedup_media(false).build().unwrap();
+        assert_eq!(media_parts(&copies), 5);
+        assert!(rels(&copies, 4).contains(r#"Target="../media/image4.png""#));
+    }
 }
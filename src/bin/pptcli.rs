@@ -3,7 +3,7 @@
 use clap::Parser;
 use ppt_rs::api::Presentation;
 use ppt_rs::cli::{
-    Cli, Commands, CreateCommand, ExportFormat, FromHtmlCommand, FromMarkdownCommand, InfoCommand,
+    Cli, Commands, ConvertCommand, CreateCommand, ExportFormat, FromHtmlCommand, FromMarkdownCommand, InfoCommand,
     ValidateCommand,
 };
 
@@ -65,6 +65,33 @@ fn main() {
                 }
             }
         }
+        Commands::Convert {
+            input,
+            input_flag,
+            output,
+            title,
+            format,
+        } => {
+            // clap requires one of the two
+            let input = input.or(input_flag).unwrap_or_default();
+            let output = output.unwrap_or_else(|| {
+                std::path::Path::new(&input)
+                    .with_extension("pptx")
+                    .to_string_lossy()
+                    .to_string()
+            });
+
+            match ConvertCommand::execute(&input, &output, title.as_deref(), format) {
+                Ok(_) => {
+                    println!("✓ Created presentation: {output}");
+                    println!("  Input: {input}");
+                }
+                Err(e) => {
+                    eprintln!("✗ Error: {e}");
+                    std::process::exit(1);
+                }
+            }
+        }
         Commands::Info { file } => match InfoCommand::execute(&file) {
             Ok(_) => {}
             Err(e) => {
@@ -140,9 +167,7 @@ fn main() {
 
             for input in inputs.iter().skip(1) {
                 match Presentation::from_path(input) {
-                    Ok(p) => {
-                        final_pres = final_pres.add_presentation(p);
-                    }
+                    Ok(p) => final_pres.append(p),
                     Err(e) => {
                         eprintln!("✗ Error loading {}: {}", input, e);
                         std::process::exit(1);
@@ -151,7 +176,7 @@ fn main() {
             }
 
             match final_pres.save(&output) {
-                Ok(_) => println!("✓ Merge completed: {}", output),
+                Ok(_) => println!("✓ Merge completed: {} ({} slides)", output, final_pres.slide_count()),
                 Err(e) => {
                     eprintln!("✗ Error saving merged file: {}", e);
                     std::process::exit(1);
@@ -1,14 +1,16 @@
 //! CLI commands implementation
 
+use super::parser::ConvertFormat;
 use crate::generator;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 pub struct CreateCommand;
 pub struct FromMarkdownCommand;
 pub struct FromHtmlCommand;
 pub struct InfoCommand;
 pub struct ValidateCommand;
+pub struct ConvertCommand;
 
 impl CreateCommand {
     pub fn execute(
@@ -131,6 +133,44 @@ impl FromHtmlCommand {
     }
 }
 
+impl ConvertCommand {
+    /// Resolve `Auto` from the input extension
+    pub fn detect_format(input: &str, format: ConvertFormat) -> Result<ConvertFormat, String> {
+        if format != ConvertFormat::Auto {
+            return Ok(format);
+        }
+        let ext = Path::new(input)
+            .extension()
+            .map(|e| e.to_string_lossy().to_lowercase())
+            .unwrap_or_default();
+        match ext.as_str() {
+            "md" | "markdown" => Ok(ConvertFormat::Markdown),
+            "html" | "htm" => Ok(ConvertFormat::Html),
+            _ => Err(format!(
+                "Cannot infer input format from '{input}'; use --format markdown or --format html"
+            )),
+        }
+    }
+
+    pub fn execute(
+        input: &str,
+        output: &str,
+        title: Option<&str>,
+        format: ConvertFormat,
+    ) -> Result<(), String> {
+        if !Path::new(input).is_file() {
+            return Err(format!("Input file not found: {input}"));
+        }
+
+        match Self::detect_format(input, format)? {
+            ConvertFormat::Html => {
+                FromHtmlCommand::execute(input, output, title, 50, 10, false, false, false)
+            }
+            _ => FromMarkdownCommand::execute(input, output, title),
+        }
+    }
+}
+
 impl InfoCommand {
     pub fn execute(file: &str) -> Result<(), String> {
         let metadata = fs::metadata(file).map_err(|e| format!("File not found: {e}"))?;
@@ -289,8 +329,6 @@ fn json_string(s: &str) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::fs;
-    use std::path::Path;
 
     #[test]
     fn test_create_command() {
@@ -310,4 +348,30 @@ mod tests {
         assert_eq!(escape_xml("<tag>"), "&lt;tag&gt;");
         assert_eq!(escape_xml("\"quoted\""), "&quot;quoted&quot;");
     }
+
+    #[test]
+    fn test_convert_format_detection() {
+        assert_eq!(ConvertCommand::detect_format("a.MD", ConvertFormat::Auto), Ok(ConvertFormat::Markdown));
+        assert_eq!(ConvertCommand::detect_format("a.htm", ConvertFormat::Auto), Ok(ConvertFormat::Html));
+        assert_eq!(ConvertCommand::detect_format("a.txt", ConvertFormat::Html), Ok(ConvertFormat::Html));
+        assert!(ConvertCommand::detect_format("a.txt", ConvertFormat::Auto).is_err());
+    }
+
+    #[test]
+    fn test_convert_command() {
+        let input = "/tmp/test_convert_notes.md";
+        let output = "/tmp/test_convert_notes.pptx";
+        fs::write(input, "# Notes\n- First point\n- Second point\n").unwrap();
+
+        let result = ConvertCommand::execute(input, output, None, ConvertFormat::Auto);
+        assert!(result.is_ok(), "{result:?}");
+        assert!(Path::new(output).exists());
+
+        let missing = ConvertCommand::execute("/tmp/does_not_exist.md", output, None, ConvertFormat::Auto);
+        assert!(missing.unwrap_err().contains("not found"));
+
+        // Cleanup
+        let _ = fs::remove_file(input);
+        let _ = fs::remove_file(output);
+    }
 }
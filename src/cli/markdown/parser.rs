@@ -12,8 +12,10 @@ use crate::generator::{
 
 /// Parse markdown content into slides
 pub fn parse(content: &str) -> Result<Vec<SlideContent>, String> {
+    // Windows files must produce the same slides as Unix ones
+    let content = content.replace("\r\n", "\n");
     let mut parser = MarkdownParser::new();
-    parser.parse(content)
+    parser.parse(&content)
 }
 
 /// Represents a task list item
@@ -10,10 +10,10 @@ pub mod parser;
 pub mod syntax;
 
 #[cfg(feature = "cli")]
-pub use commands::{CreateCommand, FromHtmlCommand, FromMarkdownCommand, InfoCommand, ValidateCommand};
+pub use commands::{ConvertCommand, CreateCommand, FromHtmlCommand, FromMarkdownCommand, InfoCommand, ValidateCommand};
 #[cfg(feature = "cli")]
 pub use markdown::parse_markdown;
 #[cfg(feature = "cli")]
-pub use parser::{Cli, Commands, ExportFormat};
+pub use parser::{Cli, Commands, ConvertFormat, ExportFormat};
 #[cfg(feature = "cli")]
 pub use syntax::{generate_highlighted_code_xml, highlight_code};
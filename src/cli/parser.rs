@@ -18,6 +18,9 @@ Examples:
   # Auto-generate output filename from Markdown
   pptcli md2ppt slides.md
 
+  # Convert Markdown or HTML, picking the converter from the extension
+  pptcli convert notes.md -o notes.pptx
+
   # Convert webpage to PowerPoint (requires --features web2ppt)
   pptcli web2ppt https://example.com -o output.pptx
 
@@ -119,6 +122,38 @@ Examples:
         title: Option<String>,
     },
 
+    /// Convert a Markdown or HTML file to PPTX
+    #[command(long_about = "Convert a Markdown or HTML file to a PowerPoint presentation.
+
+The converter is chosen from the input extension (.md, .markdown, .html, .htm)
+unless --format says otherwise.
+
+Examples:
+  pptcli convert notes.md -o notes.pptx
+  pptcli convert --input page.html --output page.pptx --title \"Page\"
+  pptcli convert slides.txt --format markdown  # Auto-generates slides.pptx")]
+    Convert {
+        /// Input file
+        #[arg(value_name = "INPUT", required_unless_present = "input_flag", help = "Path to the input Markdown or HTML file")]
+        input: Option<String>,
+
+        /// Input file, as a flag
+        #[arg(long = "input", short = 'i', value_name = "FILE", conflicts_with = "input")]
+        input_flag: Option<String>,
+
+        /// Output PPTX file (optional: auto-generated from input if not provided)
+        #[arg(short, long, help = "Path to the output PPTX file (default: INPUT.pptx)")]
+        output: Option<String>,
+
+        /// Presentation title
+        #[arg(long, help = "Title of the presentation")]
+        title: Option<String>,
+
+        /// Input format
+        #[arg(long, value_enum, default_value_t = ConvertFormat::Auto)]
+        format: ConvertFormat,
+    },
+
     /// Show presentation information
     #[command(long_about = "Display information about a PPTX file.
 
@@ -333,6 +368,14 @@ pub enum ExportFormat {
     Png,
 }
 
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ConvertFormat {
+    /// Infer from the input file extension
+    Auto,
+    Markdown,
+    Html,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -438,4 +481,30 @@ mod tests {
             _ => panic!("Expected Info command"),
         }
     }
+
+    #[test]
+    fn test_parse_convert() {
+        let cli = Cli::parse_from(["pptcli", "convert", "notes.md", "-o", "notes.pptx"]);
+        match cli.command {
+            Commands::Convert { input, input_flag, output, format, .. } => {
+                assert_eq!(input.as_deref(), Some("notes.md"));
+                assert_eq!(input_flag, None);
+                assert_eq!(output.as_deref(), Some("notes.pptx"));
+                assert_eq!(format, ConvertFormat::Auto);
+            }
+            _ => panic!("Expected Convert command"),
+        }
+
+        let cli = Cli::parse_from(["pptcli", "convert", "--input", "page.txt", "--format", "html"]);
+        match cli.command {
+            Commands::Convert { input, input_flag, format, .. } => {
+                assert_eq!(input, None);
+                assert_eq!(input_flag.as_deref(), Some("page.txt"));
+                assert_eq!(format, ConvertFormat::Html);
+            }
+            _ => panic!("Expected Convert command"),
+        }
+
+        assert!(Cli::try_parse_from(["pptcli", "convert"]).is_err());
+    }
 }
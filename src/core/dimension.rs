@@ -22,6 +22,111 @@ pub const SLIDE_WIDTH_EMU: u32 = 9144000;
 /// Standard slide height in EMU (7.5 inches)
 pub const SLIDE_HEIGHT_EMU: u32 = 6858000;
 
+/// Slide size presets matching the `type` values of `<p:sldSz>`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SlideSizePreset {
+    /// 10 x 7.5 in (4:3)
+    #[default]
+    Screen4x3,
+    /// 10 x 5.625 in (16:9)
+    Screen16x9,
+    /// 10 x 6.25 in (16:10)
+    Screen16x10,
+    /// A4 paper, 10.83 x 7.5 in (PowerPoint's A4 page)
+    A4,
+    /// US Letter paper, 10 x 7.5 in printable area
+    Letter,
+    /// 8 x 1 in banner
+    Banner,
+    /// Arbitrary dimensions
+    Custom,
+}
+
+impl SlideSizePreset {
+    /// Dimensions in EMU `(width, height)`; `Custom` falls back to 4:3
+    pub fn dimensions(&self) -> (u32, u32) {
+        match self {
+            SlideSizePreset::Screen4x3 | SlideSizePreset::Custom => (SLIDE_WIDTH_EMU, SLIDE_HEIGHT_EMU),
+            SlideSizePreset::Screen16x9 => (9144000, 5143500),
+            SlideSizePreset::Screen16x10 => (9144000, 5715000),
+            SlideSizePreset::A4 => (9906000, 6858000),
+            SlideSizePreset::Letter => (9144000, 6858000),
+            SlideSizePreset::Banner => (7315200, 914400),
+        }
+    }
+
+    /// Value of the `type` attribute on `<p:sldSz>`
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SlideSizePreset::Screen4x3 => "screen4x3",
+            SlideSizePreset::Screen16x9 => "screen16x9",
+            SlideSizePreset::Screen16x10 => "screen16x10",
+            SlideSizePreset::A4 => "A4",
+            SlideSizePreset::Letter => "letter",
+            SlideSizePreset::Banner => "banner",
+            SlideSizePreset::Custom => "custom",
+        }
+    }
+
+    /// `PresentationFormat` string for `docProps/app.xml`
+    pub fn presentation_format(&self) -> &'static str {
+        match self {
+            SlideSizePreset::Screen4x3 => "On-screen Show (4:3)",
+            SlideSizePreset::Screen16x9 => "On-screen Show (16:9)",
+            SlideSizePreset::Screen16x10 => "On-screen Show (16:10)",
+            SlideSizePreset::A4 => "A4 Paper (210x297 mm)",
+            SlideSizePreset::Letter => "Letter Paper (8.5x11 in)",
+            SlideSizePreset::Banner => "Banner",
+            SlideSizePreset::Custom => "Custom",
+        }
+    }
+}
+
+/// Slide dimensions in EMU with the preset they came from
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SlideSize {
+    pub width: u32,
+    pub height: u32,
+    pub preset: SlideSizePreset,
+}
+
+impl SlideSize {
+    /// Custom slide size in EMU
+    pub fn new(width: u32, height: u32) -> Self {
+        Self { width, height, preset: SlideSizePreset::Custom }
+    }
+
+    /// Slide size from a preset
+    pub fn from_preset(preset: SlideSizePreset) -> Self {
+        let (width, height) = preset.dimensions();
+        Self { width, height, preset }
+    }
+
+    /// Generate the `<p:sldSz>` element
+    pub fn to_xml(&self) -> String {
+        format!(
+            r#"<p:sldSz cx="{}" cy="{}" type="{}"/>"#,
+            self.width,
+            self.height,
+            self.preset.as_str()
+        )
+    }
+}
+
+impl Default for SlideSize {
+    fn default() -> Self {
+        Self::from_preset(SlideSizePreset::Screen4x3)
+    }
+}
+
+impl From<SlideSizePreset> for SlideSize {
+    fn from(preset: SlideSizePreset) -> Self {
+        Self::from_preset(preset)
+    }
+}
+
 /// EMU per inch
 const EMU_PER_INCH: f64 = 914400.0;
 /// EMU per centimeter
@@ -29,11 +134,27 @@ const EMU_PER_CM: f64 = 360000.0;
 /// EMU per point
 const EMU_PER_PT: f64 = 12700.0;
 
+/// Convert EMU to inches
+pub fn emu_to_inches(emu: i64) -> f64 {
+    emu as f64 / EMU_PER_INCH
+}
+
+/// Convert EMU to centimeters
+pub fn emu_to_cm(emu: i64) -> f64 {
+    emu as f64 / EMU_PER_CM
+}
+
+/// Convert EMU to points
+pub fn emu_to_pt(emu: i64) -> f64 {
+    emu as f64 / EMU_PER_PT
+}
+
 /// A flexible dimension that can be expressed in multiple units.
 ///
 /// All variants resolve to EMU (English Metric Units) at render time.
 /// `Ratio` is relative to a reference dimension (slide width for x/width, slide height for y/height).
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Dimension {
     /// Absolute value in EMU (English Metric Units)
     Emu(u32),
@@ -63,6 +184,41 @@ impl Dimension {
         }
     }
 
+    /// Unrounded value in EMU; `reference_emu` is only used for `Ratio`
+    fn emu_f64(&self, reference_emu: u32) -> f64 {
+        match self {
+            Dimension::Emu(v) => *v as f64,
+            Dimension::Inches(v) => v * EMU_PER_INCH,
+            Dimension::Cm(v) => v * EMU_PER_CM,
+            Dimension::Pt(v) => v * EMU_PER_PT,
+            Dimension::Ratio(r) => crate::core::clamp_ratio(*r) * reference_emu as f64,
+        }
+    }
+
+    /// Value in inches; `reference_emu` is only used for `Ratio`, as in [`Dimension::to_emu`]
+    pub fn to_inches(&self, reference_emu: u32) -> f64 {
+        match self {
+            Dimension::Inches(v) => *v,
+            _ => self.emu_f64(reference_emu) / EMU_PER_INCH,
+        }
+    }
+
+    /// Value in centimeters; `reference_emu` is only used for `Ratio`
+    pub fn to_cm(&self, reference_emu: u32) -> f64 {
+        match self {
+            Dimension::Cm(v) => *v,
+            _ => self.emu_f64(reference_emu) / EMU_PER_CM,
+        }
+    }
+
+    /// Value in points; `reference_emu` is only used for `Ratio`
+    pub fn to_pt(&self, reference_emu: u32) -> f64 {
+        match self {
+            Dimension::Pt(v) => *v,
+            _ => self.emu_f64(reference_emu) / EMU_PER_PT,
+        }
+    }
+
     /// Resolve X position or width to EMU (reference = slide width)
     pub fn to_emu_x(&self) -> u32 {
         self.to_emu(SLIDE_WIDTH_EMU)
@@ -72,6 +228,16 @@ impl Dimension {
     pub fn to_emu_y(&self) -> u32 {
         self.to_emu(SLIDE_HEIGHT_EMU)
     }
+
+    /// Resolve X position or width to EMU against a specific slide size
+    pub fn to_emu_x_in(&self, size: &SlideSize) -> u32 {
+        self.to_emu(size.width)
+    }
+
+    /// Resolve Y position or height to EMU against a specific slide size
+    pub fn to_emu_y_in(&self, size: &SlideSize) -> u32 {
+        self.to_emu(size.height)
+    }
 }
 
 /// Convenience: convert from u32 (treated as EMU)
@@ -263,4 +429,58 @@ mod tests {
         assert_eq!(Dimension::ratio(0.5), Dimension::Ratio(0.5));
         assert_eq!(Dimension::emu(914400), Dimension::Emu(914400));
     }
+
+    #[test]
+    fn test_slide_size_presets() {
+        let size = SlideSize::from_preset(SlideSizePreset::Screen16x9);
+        assert_eq!((size.width, size.height), (9144000, 5143500));
+        assert_eq!(size.to_xml(), r#"<p:sldSz cx="9144000" cy="5143500" type="screen16x9"/>"#);
+        assert_eq!(SlideSizePreset::A4.dimensions(), (9906000, 6858000));
+        assert_eq!(SlideSize::default().preset, SlideSizePreset::Screen4x3);
+        assert_eq!(SlideSize::new(12192000, 6858000).preset.as_str(), "custom");
+    }
+
+    #[test]
+    fn test_ratio_against_slide_size() {
+        let wide = SlideSize::new(12192000, 6858000);
+        assert_eq!(Dimension::Ratio(0.5).to_emu_x_in(&wide), 6096000);
+        assert_eq!(Dimension::Ratio(0.5).to_emu_y_in(&wide), 3429000);
+        assert_eq!(Dimension::Inches(1.0).to_emu_x_in(&wide), 914400);
+    }
+
+    #[test]
+    fn test_emu_to_units() {
+        assert_eq!(emu_to_inches(914400), 1.0);
+        assert_eq!(emu_to_cm(360000), 1.0);
+        assert_eq!(emu_to_pt(12700), 1.0);
+        assert_eq!(emu_to_inches(-457200), -0.5);
+        assert!((emu_to_cm(914400) - 2.54).abs() < 1e-12);
+        assert_eq!(emu_to_pt(914400), 72.0);
+    }
+
+    #[test]
+    fn test_dimension_to_units() {
+        assert_eq!(Dimension::Emu(914400).to_inches(0), 1.0);
+        assert_eq!(Dimension::Inches(1.25).to_pt(0), 90.0);
+        assert!((Dimension::Cm(2.54).to_inches(0) - 1.0).abs() < 1e-12);
+        assert_eq!(Dimension::Pt(36.0).to_inches(0), 0.5);
+        assert_eq!(Dimension::Ratio(0.5).to_inches(SLIDE_WIDTH_EMU), 5.0);
+        assert_eq!(Dimension::percent(10.0).to_cm(SLIDE_WIDTH_EMU), 2.54);
+    }
+
+    #[test]
+    fn test_unit_round_trip_within_one_emu() {
+        for v in [0.0, 0.1, 0.333, 1.0, 2.54, 7.5, 13.333, 100.0] {
+            let inches = Dimension::Inches(v).to_emu(0);
+            assert!((emu_to_inches(inches as i64) - v).abs() <= 1.0 / EMU_PER_INCH);
+            let cm = Dimension::Cm(v).to_emu(0);
+            assert!((emu_to_cm(cm as i64) - v).abs() <= 1.0 / EMU_PER_CM);
+            let pt = Dimension::Pt(v).to_emu(0);
+            assert!((emu_to_pt(pt as i64) - v).abs() <= 1.0 / EMU_PER_PT);
+            // Unit values convert back exactly without going through EMU
+            assert_eq!(Dimension::Inches(v).to_inches(0), v);
+            assert_eq!(Dimension::Cm(v).to_cm(0), v);
+            assert_eq!(Dimension::Pt(v).to_pt(0), v);
+        }
+    }
 }
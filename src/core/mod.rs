@@ -11,11 +11,15 @@ mod traits;
 mod validation;
 mod xml_utils;
 
-pub use dimension::{Dimension, FlexPosition, FlexSize, SLIDE_HEIGHT_EMU, SLIDE_WIDTH_EMU};
+pub use dimension::{
+    emu_to_cm, emu_to_inches, emu_to_pt, Dimension, FlexPosition, FlexSize, SlideSize, SlideSizePreset, SLIDE_HEIGHT_EMU, SLIDE_WIDTH_EMU,
+};
 pub use package_validation::{
     validate_package, validate_package_bytes, PackageValidationIssue, PackageValidationReport,
-    REQUIRED_PACKAGE_PARTS, ValidationCategory, ValidationSeverity,
+    REQUIRED_PACKAGE_PARTS, ValidationCategory, ValidationSeverity, ValidationWarning,
 };
+pub(crate) use package_validation::{check_content, settings_colors, slide_colors, slide_hex_colors};
+pub(crate) use package_validation::rels::{rels_source_dir, resolve_rel_target};
 pub use placement::ElementPlacement;
 pub use powerpoint_compat::{validate_powerpoint_structure, CompatReport};
 pub use traits::{Positioned, Sized as ElementSized, ToXml};
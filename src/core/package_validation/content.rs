@@ -0,0 +1,309 @@
+//! Checks on presentation content that the package rules can't see once XML is written.
+
+use crate::elements::{is_color_value, is_hex_color};
+use crate::generator::shapes::Shape;
+use crate::generator::slide_content::{PresentationSettings, SlideBackground, SlideContent};
+use crate::generator::text::TextFormat;
+
+use super::report::{PackageValidationIssue, ValidationCategory};
+
+/// Warn about malformed colors and negative EMU coordinates in slide content and theme.
+pub(crate) fn check_content(
+    slides: &[SlideContent],
+    settings: Option<&PresentationSettings>,
+) -> Vec<PackageValidationIssue> {
+    let mut issues = Vec::new();
+
+    for (i, slide) in slides.iter().enumerate() {
+        let path = format!("ppt/slides/slide{}.xml", i + 1);
+
+        for (what, color) in slide_colors(slide) {
+            if !is_color_value(color) {
+                issues.push(PackageValidationIssue::warning(
+                    ValidationCategory::Slide,
+                    format!("{what} \"{color}\" is not a 6-digit hex color or theme color"),
+                    Some(&path),
+                ));
+            }
+        }
+        for (what, color) in slide_hex_colors(slide) {
+            if !is_hex_color(color) {
+                issues.push(PackageValidationIssue::warning(
+                    ValidationCategory::Slide,
+                    format!("{what} \"{color}\" is not a 6-digit hex color"),
+                    Some(&path),
+                ));
+            }
+        }
+
+        for (c, code) in slide.code_blocks.iter().enumerate() {
+            if code.x < 0 || code.y < 0 || code.width < 0 || code.height < 0 {
+                issues.push(PackageValidationIssue::warning(
+                    ValidationCategory::Slide,
+                    format!(
+                        "code block {} has a negative EMU coordinate ({}, {}, {}, {})",
+                        c + 1,
+                        code.x,
+                        code.y,
+                        code.width,
+                        code.height
+                    ),
+                    Some(&path),
+                ));
+            }
+        }
+    }
+
+    if let Some(theme) = settings.and_then(|s| s.theme.as_ref()) {
+        let scheme = &theme.colors;
+        let slots = [
+            ("dk1", &scheme.dk1),
+            ("lt1", &scheme.lt1),
+            ("dk2", &scheme.dk2),
+            ("lt2", &scheme.lt2),
+            ("accent1", &scheme.accent1),
+            ("accent2", &scheme.accent2),
+            ("accent3", &scheme.accent3),
+            ("accent4", &scheme.accent4),
+            ("accent5", &scheme.accent5),
+            ("accent6", &scheme.accent6),
+            ("hlink", &scheme.hlink),
+            ("folHlink", &scheme.fol_hlink),
+        ];
+        for (slot, color) in slots {
+            if !is_hex_color(color) {
+                issues.push(PackageValidationIssue::warning(
+                    ValidationCategory::Theme,
+                    format!("theme color {slot} \"{color}\" is not a 6-digit hex color"),
+                    Some("ppt/theme/theme1.xml"),
+                ));
+            }
+        }
+    }
+
+    issues
+}
+
+/// Every color string on a slide, labelled by where it sits
+pub(crate) fn slide_colors(slide: &SlideContent) -> Vec<(String, &str)> {
+    let mut colors: Vec<(String, &str)> = Vec::new();
+    let optional = [
+        ("title color".to_string(), &slide.title_color),
+        ("content color".to_string(), &slide.content_color),
+    ]
+    .into_iter()
+    .chain(slide.bullets.iter().enumerate().filter_map(|(b, bullet)| {
+        let format = bullet.format.as_ref()?;
+        Some([
+            (format!("bullet {} color", b + 1), &format.color),
+            (format!("bullet {} highlight", b + 1), &format.highlight),
+        ])
+    }).flatten());
+    for (what, color) in optional {
+        if let Some(color) = color {
+            colors.push((what, color));
+        }
+    }
+    for (b, bullet) in slide.bullets.iter().enumerate() {
+        for run in &bullet.runs {
+            push_format_colors(&mut colors, &format!("bullet {} run", b + 1), &run.format);
+        }
+    }
+
+    if let Some(background) = &slide.background {
+        push_background_colors(&mut colors, "background", background);
+    }
+
+    for (s, shape) in slide.shapes.iter().enumerate() {
+        push_shape_colors(&mut colors, &format!("shape {}", s + 1), shape);
+    }
+    for (g, group) in slide.shape_groups.iter().enumerate() {
+        for (s, shape) in group.shapes.iter().enumerate() {
+            push_shape_colors(&mut colors, &format!("group {} shape {}", g + 1, s + 1), shape);
+        }
+        for (c, connector) in group.connectors.iter().enumerate() {
+            colors.push((format!("group {} connector {} line", g + 1, c + 1), &connector.line.color));
+        }
+    }
+
+    for (c, connector) in slide.connectors.iter().enumerate() {
+        colors.push((format!("connector {} line", c + 1), &connector.line.color));
+    }
+
+    for (m, image) in slide.images.iter().enumerate() {
+        if let Some(border) = &image.border {
+            colors.push((format!("image {} border", m + 1), &border.color));
+        }
+        if let Some((dark, light)) = &image.duotone {
+            colors.push((format!("image {} duotone dark", m + 1), dark));
+            colors.push((format!("image {} duotone light", m + 1), light));
+        }
+    }
+
+    if let Some(table) = &slide.table {
+        for (r, row) in table.rows.iter().enumerate() {
+            for (c, cell) in row.cells.iter().enumerate() {
+                if let Some(color) = &cell.text_color {
+                    colors.push((format!("table cell ({}, {}) text color", r + 1, c + 1), color));
+                }
+                if let Some(color) = &cell.background_color {
+                    colors.push((format!("table cell ({}, {}) background", r + 1, c + 1), color));
+                }
+            }
+        }
+    }
+
+    for (c, chart) in slide.charts.iter().enumerate() {
+        for (s, series) in chart.series.iter().enumerate() {
+            if let Some(color) = &series.color {
+                colors.push((format!("chart {} series {} color", c + 1, s + 1), color));
+            }
+            for color in &series.point_colors {
+                colors.push((format!("chart {} series {} point color", c + 1, s + 1), color));
+            }
+        }
+        if let Some(color) = chart.title_font.as_ref().and_then(|f| f.color.as_ref()) {
+            colors.push((format!("chart {} title color", c + 1), color));
+        }
+    }
+
+    colors
+}
+
+/// Color strings on a slide written outside DrawingML, which must be hex rather than a theme color
+pub(crate) fn slide_hex_colors(slide: &SlideContent) -> Vec<(String, &str)> {
+    let strokes = slide.ink_annotations.iter().flat_map(|ink| ink.strokes());
+    strokes.enumerate().map(|(k, stroke)| (format!("ink stroke {} pen", k + 1), stroke.pen.color.as_str())).collect()
+}
+
+/// Every color string in the slide show and slide master settings, labelled by where it sits
+pub(crate) fn settings_colors(settings: &PresentationSettings) -> Vec<(String, &str)> {
+    let mut colors: Vec<(String, &str)> = Vec::new();
+    if let Some(show) = &settings.slide_show {
+        colors.push(("slide show pen color".to_string(), &show.pen_color.color));
+    }
+    let masters = settings.slide_master.iter().chain(&settings.extra_masters);
+    for (m, master) in masters.enumerate() {
+        let what = format!("master {}", m + 1);
+        if let Some(background) = &master.background {
+            push_background_colors(&mut colors, &format!("{what} background"), background);
+        }
+        if let Some(format) = &master.title_style {
+            push_format_colors(&mut colors, &format!("{what} title"), format);
+        }
+        if let Some(format) = &master.body_style {
+            push_format_colors(&mut colors, &format!("{what} body"), format);
+        }
+    }
+    colors
+}
+
+fn push_background_colors<'a>(colors: &mut Vec<(String, &'a str)>, what: &str, background: &'a SlideBackground) {
+    match background {
+        SlideBackground::Color(color) => colors.push((format!("{what} color"), color)),
+        SlideBackground::Gradient(gradient) => {
+            for stop in &gradient.stops {
+                colors.push((format!("{what} gradient stop"), &stop.color));
+            }
+        }
+        SlideBackground::Image(_) => {}
+    }
+}
+
+fn push_shape_colors<'a>(colors: &mut Vec<(String, &'a str)>, what: &str, shape: &'a Shape) {
+    if let Some(fill) = &shape.fill {
+        colors.push((format!("{what} fill"), &fill.color));
+    }
+    if let Some(line) = &shape.line {
+        colors.push((format!("{what} line"), &line.color));
+    }
+    if let Some(gradient) = &shape.gradient {
+        for stop in &gradient.stops {
+            colors.push((format!("{what} gradient stop"), &stop.color));
+        }
+    }
+    if let Some(shadow) = &shape.shadow {
+        colors.push((format!("{what} shadow"), &shadow.color));
+    }
+    if let Some(glow) = &shape.glow {
+        colors.push((format!("{what} glow"), &glow.color));
+    }
+    for paragraph in shape.text_frame.iter().flat_map(|frame| &frame.paragraphs) {
+        for run in &paragraph.runs {
+            push_format_colors(colors, &format!("{what} text"), &run.format);
+        }
+    }
+}
+
+fn push_format_colors<'a>(colors: &mut Vec<(String, &'a str)>, what: &str, format: &'a TextFormat) {
+    if let Some(color) = &format.color {
+        colors.push((format!("{what} color"), color));
+    }
+    if let Some(color) = &format.highlight {
+        colors.push((format!("{what} highlight"), color));
+    }
+    if let Some(gradient) = &format.gradient {
+        for stop in &gradient.stops {
+            colors.push((format!("{what} gradient stop"), &stop.color));
+        }
+    }
+    if let Some(outline) = &format.outline {
+        colors.push((format!("{what} outline"), &outline.color));
+    }
+    if let Some(shadow) = &format.shadow {
+        colors.push((format!("{what} shadow"), &shadow.color));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generator::shapes::{Shape, ShapeFill, ShapeType};
+
+    #[test]
+    fn flags_bad_colors_with_slide_path() {
+        // Color setters keep strings that don't parse as written
+        let bad = SlideContent::new("Bad")
+            .title_color("RDE")
+            .add_shape(Shape::new(ShapeType::Rectangle, 0, 0, 10, 10).with_fill(ShapeFill::new("12345")));
+        let slides = vec![SlideContent::new("Ok").title_color("1F4E79"), bad];
+        let issues = check_content(&slides, None);
+        assert_eq!(issues.len(), 2, "{issues:?}");
+        assert!(issues.iter().all(|i| i.path.as_deref() == Some("ppt/slides/slide2.xml")));
+        assert!(issues[0].message.contains("title color \"RDE\""));
+        assert!(issues[1].message.contains("shape 1 fill"));
+    }
+
+    #[test]
+    fn collects_nested_colors() {
+        use crate::generator::shape_group::ShapeGroup;
+        use crate::generator::text::{Paragraph, Run, TextFrame};
+
+        let label = Shape::new(ShapeType::Rectangle, 0, 0, 10, 10)
+            .with_glow("4472C4", 100)
+            .with_text_frame(TextFrame::new().add_paragraph(Paragraph::new().add_run(Run::new("Hi").color("bleu"))));
+        let slide = SlideContent::new("Nested").add_shape_group(ShapeGroup::new(0, 0, 10, 10).add_shape(label));
+        let colors = slide_colors(&slide);
+        assert_eq!(
+            colors,
+            vec![
+                ("group 1 shape 1 glow".to_string(), "4472C4"),
+                ("group 1 shape 1 text color".to_string(), "bleu"),
+            ]
+        );
+    }
+
+    #[test]
+    fn hex_color_rules() {
+        assert!(is_hex_color("A1b2C3"));
+        assert!(!is_hex_color("#A1B2C3"));
+        assert!(!is_hex_color("GGGGGG"));
+    }
+
+    #[test]
+    fn accepts_theme_colors() {
+        let slides = vec![SlideContent::new("Theme")
+            .add_shape(Shape::new(ShapeType::Rectangle, 0, 0, 10, 10).with_fill(ShapeFill::new("accent1")))];
+        assert!(check_content(&slides, None).is_empty());
+    }
+}
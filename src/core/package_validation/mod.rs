@@ -3,8 +3,9 @@
 //! Run [`validate_package_bytes`] after generation (or in tests) to catch
 //! structural issues before opening in PowerPoint.
 
+mod content;
 mod context;
-mod rels;
+pub(crate) mod rels;
 mod report;
 mod rules;
 
@@ -12,3 +13,8 @@ pub use report::{
     PackageValidationIssue, PackageValidationReport, ValidationCategory, ValidationSeverity,
 };
 pub use rules::{validate_package, validate_package_bytes, REQUIRED_PACKAGE_PARTS};
+
+pub(crate) use content::{check_content, settings_colors, slide_colors, slide_hex_colors};
+
+/// Finding from [`Presentation::validate`](crate::api::Presentation::validate)
+pub type ValidationWarning = PackageValidationIssue;
@@ -45,6 +45,7 @@ pub fn validate_package<R: Read + Seek>(archive: &mut ZipArchive<R>) -> PackageV
     check_slide_relationships(&mut ctx, archive, &mut report);
     check_notes_slides(&ctx, &mut report);
     check_handout_package(&mut ctx, archive, &mut report);
+    check_print_properties(&mut ctx, archive, &mut report);
     check_notes_master(&mut ctx, archive, &mut report);
 
     report
@@ -169,7 +170,12 @@ fn check_presentation_structure<R: Read + Seek>(
         .map(|r| (r.id.as_str(), r.resolved.as_str()))
         .collect();
 
-    let sld_id_count = pres.matches("<p:sldId ").count();
+    // Custom shows list slides with <p:sldId> too; only the slide list counts
+    let sld_id_lst = pres
+        .split_once("<p:sldIdLst>")
+        .and_then(|(_, rest)| rest.split_once("</p:sldIdLst>"))
+        .map_or("", |(list, _)| list);
+    let sld_id_count = sld_id_lst.matches("<p:sldId ").count();
 
     if sld_id_count > 0 {
         let first_id = format!("id=\"{}\"", slide_id_value(1));
@@ -392,7 +398,8 @@ fn check_chart_packages<R: Read + Seek>(
         }
 
         if let Some(chart_xml) = ctx.read_part(archive, &chart_path)
-            && !chart_xml.contains("<c:externalData") {
+            && !chart_xml.contains("<c:externalData")
+            && !chart_xml.contains("<cx:externalData") {
                 report.push(PackageValidationIssue::error(
                     ValidationCategory::Chart,
                     format!("{chart_path} missing c:externalData"),
@@ -402,6 +409,7 @@ fn check_chart_packages<R: Read + Seek>(
 
         let idx = chart_path
             .trim_start_matches("ppt/charts/chart")
+            .trim_start_matches("Ex")
             .trim_end_matches(".xml");
         let embedding = format!("ppt/embeddings/Microsoft_Excel_Sheet{idx}.xlsx");
         if !ctx.has_part(&embedding) {
@@ -461,7 +469,7 @@ fn check_slide_relationships<R: Read + Seek>(
         }
 
         let mut shape_ids: HashSet<String> = HashSet::new();
-        for id in extract_attr_values(&slide_xml, "p:cNvPr", "id") {
+        for id in extract_attr_values(&strip_mc_fallbacks(&slide_xml), "p:cNvPr", "id") {
             if !shape_ids.insert(id.clone()) {
                 report.push(PackageValidationIssue::error(
                     ValidationCategory::Slide,
@@ -482,15 +490,6 @@ fn check_handout_package<R: Read + Seek>(
         return;
     }
 
-    if let Some(pres_props) = ctx.read_part(archive, "ppt/presProps.xml")
-        && pres_props.contains("<p:prnPr") {
-            report.push(PackageValidationIssue::error(
-                ValidationCategory::Presentation,
-                "presProps.xml must not contain p:prnPr when a handout master is packaged",
-                Some("ppt/presProps.xml"),
-            ));
-        }
-
     let handout_rels = ctx.relationships("ppt/handoutMasters/_rels/handoutMaster1.xml.rels");
     let has_theme3 = handout_rels
         .iter()
@@ -513,6 +512,31 @@ fn check_handout_package<R: Read + Seek>(
         }
 }
 
+/// `ST_PrintWhat` values accepted by `<p:prnPr prnWhat>`
+const PRINT_WHAT_VALUES: &[&str] = &[
+    "slides", "handouts1", "handouts2", "handouts3", "handouts4", "handouts6", "handouts9",
+    "notes", "outline",
+];
+
+fn check_print_properties<R: Read + Seek>(
+    ctx: &mut PackageContext,
+    archive: &mut ZipArchive<R>,
+    report: &mut PackageValidationReport,
+) {
+    let Some(pres_props) = ctx.read_part(archive, "ppt/presProps.xml") else {
+        return;
+    };
+    for value in extract_attr_values(&pres_props, "p:prnPr", "prnWhat") {
+        if !PRINT_WHAT_VALUES.contains(&value.as_str()) {
+            report.push(PackageValidationIssue::error(
+                ValidationCategory::Presentation,
+                format!("presProps.xml p:prnPr has invalid prnWhat \"{value}\""),
+                Some("ppt/presProps.xml"),
+            ));
+        }
+    }
+}
+
 fn check_notes_master<R: Read + Seek>(
     ctx: &mut PackageContext,
     archive: &mut ZipArchive<R>,
@@ -570,6 +594,22 @@ fn is_slide_part(path: &str) -> bool {
         && !path.contains("/_rels/")
 }
 
+/// Drop `<mc:Fallback>` branches; a consumer reads only one branch of an `mc:AlternateContent`,
+/// so shapes there legitimately repeat the ids of their `mc:Choice` counterparts.
+fn strip_mc_fallbacks(xml: &str) -> String {
+    let mut out = String::with_capacity(xml.len());
+    let mut rest = xml;
+    while let Some(start) = rest.find("<mc:Fallback>") {
+        out.push_str(&rest[..start]);
+        let Some(end) = rest[start..].find("</mc:Fallback>") else {
+            return out;
+        };
+        rest = &rest[start + end + "</mc:Fallback>".len()..];
+    }
+    out.push_str(rest);
+    out
+}
+
 /// Extract `attr="value"` from elements like `<p:sldId id="256" r:id="rId6"/>`.
 fn extract_attr_values(xml: &str, element: &str, attr: &str) -> Vec<String> {
     let mut values = Vec::new();
@@ -642,4 +682,9 @@ mod tests {
             vec!["rId2".to_string(), "rId3".to_string()]
         );
     }
+    #[test]
+    fn strip_mc_fallbacks_keeps_choice() {
+        let xml = r#"<mc:AlternateContent><mc:Choice><p:cNvPr id="5"/></mc:Choice><mc:Fallback><p:cNvPr id="5"/></mc:Fallback></mc:AlternateContent><p:cNvPr id="6"/>"#;
+        assert_eq!(extract_attr_values(&strip_mc_fallbacks(xml), "p:cNvPr", "id"), ["5", "6"]);
+    }
 }
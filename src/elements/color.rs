@@ -3,9 +3,10 @@
 //! Provides unified color handling for all PPTX elements.
 
 use crate::core::ToXml;
+use crate::exc::{messages, PptxError, Result};
 
 /// RGB color (6-digit hex)
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub struct RgbColor {
     pub r: u8,
     pub g: u8,
@@ -18,16 +19,59 @@ impl RgbColor {
         Self { r, g, b }
     }
 
-    /// Parse from hex string (e.g., "FF0000" or "#FF0000")
-    pub fn from_hex(hex: &str) -> Option<Self> {
-        let hex = hex.trim_start_matches('#');
-        if hex.len() != 6 {
-            return None;
-        }
-        let r = u8::from_str_radix(&hex[0..2], 16).ok()?;
-        let g = u8::from_str_radix(&hex[2..4], 16).ok()?;
-        let b = u8::from_str_radix(&hex[4..6], 16).ok()?;
-        Some(Self { r, g, b })
+    /// Parse `RRGGBB` or shorthand `RGB` hex, with or without `#` and in either case,
+    /// or a CSS color name such as `"steelblue"`
+    pub fn from_hex(hex: &str) -> Result<Self> {
+        let value = hex.trim();
+        let digits = value.strip_prefix('#').unwrap_or(value);
+        let parsed = if digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+            match digits.len() {
+                6 => u32::from_str_radix(digits, 16).ok(),
+                3 => u32::from_str_radix(digits, 16)
+                    .ok()
+                    // Each shorthand digit is doubled: F80 -> FF8800
+                    .map(|v| (v >> 8) * 0x110000 + ((v >> 4) & 0xF) * 0x1100 + (v & 0xF) * 0x11),
+                _ => None,
+            }
+        } else {
+            None
+        };
+        parsed
+            .or_else(|| Self::named(value))
+            .map(Self::from_u32)
+            .ok_or_else(|| PptxError::InvalidValue(messages::invalid_value("color", &format!("'{hex}'"))))
+    }
+
+    /// Create from hue in degrees and saturation and lightness in 0.0–1.0
+    pub fn from_hsl(h: f64, s: f64, l: f64) -> Self {
+        let h = h.rem_euclid(360.0) / 60.0;
+        let s = s.clamp(0.0, 1.0);
+        let l = l.clamp(0.0, 1.0);
+        let chroma = (1.0 - (2.0 * l - 1.0).abs()) * s;
+        let x = chroma * (1.0 - (h % 2.0 - 1.0).abs());
+        let (r, g, b) = match h as u32 {
+            0 => (chroma, x, 0.0),
+            1 => (x, chroma, 0.0),
+            2 => (0.0, chroma, x),
+            3 => (0.0, x, chroma),
+            4 => (x, 0.0, chroma),
+            _ => (chroma, 0.0, x),
+        };
+        let m = l - chroma / 2.0;
+        let channel = |v: f64| ((v + m) * 255.0).round() as u8;
+        Self::new(channel(r), channel(g), channel(b))
+    }
+
+    fn from_u32(rgb: u32) -> Self {
+        Self::new((rgb >> 16) as u8, (rgb >> 8) as u8, rgb as u8)
+    }
+
+    fn named(name: &str) -> Option<u32> {
+        let name = name.to_ascii_lowercase();
+        NAMED_COLORS
+            .binary_search_by(|(n, _)| n.cmp(&name.as_str()))
+            .ok()
+            .map(|i| NAMED_COLORS[i].1)
     }
 
     /// Convert to hex string (uppercase, no #)
@@ -65,8 +109,114 @@ impl Default for RgbColor {
     }
 }
 
-/// Scheme color (theme-based)
+impl std::str::FromStr for RgbColor {
+    type Err = PptxError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::from_hex(s)
+    }
+}
+
+/// RGB color argument of color setters: an [`RgbColor`], an `(r, g, b)` tuple, or a
+/// string parsed as by [`RgbColor::from_hex`].
+///
+/// A string that doesn't parse is kept as written instead of becoming black, and
+/// building the presentation then fails with an invalid color error.
 #[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RgbInput(String);
+
+impl RgbInput {
+    /// Uppercase hex of the color, or the unparsed string as given
+    pub fn into_value(self) -> String {
+        self.0
+    }
+}
+
+impl<T: Into<RgbColor>> From<T> for RgbInput {
+    fn from(color: T) -> Self {
+        Self(color.into().to_hex())
+    }
+}
+
+impl From<&str> for RgbInput {
+    fn from(s: &str) -> Self {
+        Self(RgbColor::from_hex(s).map_or_else(|_| s.to_string(), |rgb| rgb.to_hex()))
+    }
+}
+
+impl From<&String> for RgbInput {
+    fn from(s: &String) -> Self {
+        Self::from(s.as_str())
+    }
+}
+
+impl From<String> for RgbInput {
+    fn from(s: String) -> Self {
+        Self::from(s.as_str())
+    }
+}
+
+impl From<(u8, u8, u8)> for RgbColor {
+    fn from((r, g, b): (u8, u8, u8)) -> Self {
+        Self::new(r, g, b)
+    }
+}
+
+impl From<&RgbColor> for RgbColor {
+    fn from(color: &RgbColor) -> Self {
+        *color
+    }
+}
+
+/// CSS named colors, sorted by name
+const NAMED_COLORS: &[(&str, u32)] = &[
+    ("aliceblue", 0xF0F8FF), ("antiquewhite", 0xFAEBD7), ("aqua", 0x00FFFF),
+    ("aquamarine", 0x7FFFD4), ("azure", 0xF0FFFF), ("beige", 0xF5F5DC), ("bisque", 0xFFE4C4),
+    ("black", 0x000000), ("blanchedalmond", 0xFFEBCD), ("blue", 0x0000FF), ("blueviolet", 0x8A2BE2),
+    ("brown", 0xA52A2A), ("burlywood", 0xDEB887), ("cadetblue", 0x5F9EA0), ("chartreuse", 0x7FFF00),
+    ("chocolate", 0xD2691E), ("coral", 0xFF7F50), ("cornflowerblue", 0x6495ED),
+    ("cornsilk", 0xFFF8DC), ("crimson", 0xDC143C), ("cyan", 0x00FFFF), ("darkblue", 0x00008B),
+    ("darkcyan", 0x008B8B), ("darkgoldenrod", 0xB8860B), ("darkgray", 0xA9A9A9),
+    ("darkgreen", 0x006400), ("darkgrey", 0xA9A9A9), ("darkkhaki", 0xBDB76B),
+    ("darkmagenta", 0x8B008B), ("darkolivegreen", 0x556B2F), ("darkorange", 0xFF8C00),
+    ("darkorchid", 0x9932CC), ("darkred", 0x8B0000), ("darksalmon", 0xE9967A),
+    ("darkseagreen", 0x8FBC8F), ("darkslateblue", 0x483D8B), ("darkslategray", 0x2F4F4F),
+    ("darkslategrey", 0x2F4F4F), ("darkturquoise", 0x00CED1), ("darkviolet", 0x9400D3),
+    ("deeppink", 0xFF1493), ("deepskyblue", 0x00BFFF), ("dimgray", 0x696969), ("dimgrey", 0x696969),
+    ("dodgerblue", 0x1E90FF), ("firebrick", 0xB22222), ("floralwhite", 0xFFFAF0),
+    ("forestgreen", 0x228B22), ("fuchsia", 0xFF00FF), ("gainsboro", 0xDCDCDC),
+    ("ghostwhite", 0xF8F8FF), ("gold", 0xFFD700), ("goldenrod", 0xDAA520), ("gray", 0x808080),
+    ("green", 0x008000), ("greenyellow", 0xADFF2F), ("grey", 0x808080), ("honeydew", 0xF0FFF0),
+    ("hotpink", 0xFF69B4), ("indianred", 0xCD5C5C), ("indigo", 0x4B0082), ("ivory", 0xFFFFF0),
+    ("khaki", 0xF0E68C), ("lavender", 0xE6E6FA), ("lavenderblush", 0xFFF0F5),
+    ("lawngreen", 0x7CFC00), ("lemonchiffon", 0xFFFACD), ("lightblue", 0xADD8E6),
+    ("lightcoral", 0xF08080), ("lightcyan", 0xE0FFFF), ("lightgoldenrodyellow", 0xFAFAD2),
+    ("lightgray", 0xD3D3D3), ("lightgreen", 0x90EE90), ("lightgrey", 0xD3D3D3),
+    ("lightpink", 0xFFB6C1), ("lightsalmon", 0xFFA07A), ("lightseagreen", 0x20B2AA),
+    ("lightskyblue", 0x87CEFA), ("lightslategray", 0x778899), ("lightslategrey", 0x778899),
+    ("lightsteelblue", 0xB0C4DE), ("lightyellow", 0xFFFFE0), ("lime", 0x00FF00),
+    ("limegreen", 0x32CD32), ("linen", 0xFAF0E6), ("magenta", 0xFF00FF), ("maroon", 0x800000),
+    ("mediumaquamarine", 0x66CDAA), ("mediumblue", 0x0000CD), ("mediumorchid", 0xBA55D3),
+    ("mediumpurple", 0x9370DB), ("mediumseagreen", 0x3CB371), ("mediumslateblue", 0x7B68EE),
+    ("mediumspringgreen", 0x00FA9A), ("mediumturquoise", 0x48D1CC), ("mediumvioletred", 0xC71585),
+    ("midnightblue", 0x191970), ("mintcream", 0xF5FFFA), ("mistyrose", 0xFFE4E1),
+    ("moccasin", 0xFFE4B5), ("navajowhite", 0xFFDEAD), ("navy", 0x000080), ("oldlace", 0xFDF5E6),
+    ("olive", 0x808000), ("olivedrab", 0x6B8E23), ("orange", 0xFFA500), ("orangered", 0xFF4500),
+    ("orchid", 0xDA70D6), ("palegoldenrod", 0xEEE8AA), ("palegreen", 0x98FB98),
+    ("paleturquoise", 0xAFEEEE), ("palevioletred", 0xDB7093), ("papayawhip", 0xFFEFD5),
+    ("peachpuff", 0xFFDAB9), ("peru", 0xCD853F), ("pink", 0xFFC0CB), ("plum", 0xDDA0DD),
+    ("powderblue", 0xB0E0E6), ("purple", 0x800080), ("rebeccapurple", 0x663399), ("red", 0xFF0000),
+    ("rosybrown", 0xBC8F8F), ("royalblue", 0x4169E1), ("saddlebrown", 0x8B4513),
+    ("salmon", 0xFA8072), ("sandybrown", 0xF4A460), ("seagreen", 0x2E8B57), ("seashell", 0xFFF5EE),
+    ("sienna", 0xA0522D), ("silver", 0xC0C0C0), ("skyblue", 0x87CEEB), ("slateblue", 0x6A5ACD),
+    ("slategray", 0x708090), ("slategrey", 0x708090), ("snow", 0xFFFAFA), ("springgreen", 0x00FF7F),
+    ("steelblue", 0x4682B4), ("tan", 0xD2B48C), ("teal", 0x008080), ("thistle", 0xD8BFD8),
+    ("tomato", 0xFF6347), ("turquoise", 0x40E0D0), ("violet", 0xEE82EE), ("wheat", 0xF5DEB3),
+    ("white", 0xFFFFFF), ("whitesmoke", 0xF5F5F5), ("yellow", 0xFFFF00), ("yellowgreen", 0x9ACD32),
+];
+
+/// Scheme color (theme-based)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum SchemeColor {
     Accent1,
     Accent2,
@@ -87,6 +237,30 @@ pub enum SchemeColor {
 }
 
 impl SchemeColor {
+    const ALL: [SchemeColor; 16] = [
+        SchemeColor::Accent1,
+        SchemeColor::Accent2,
+        SchemeColor::Accent3,
+        SchemeColor::Accent4,
+        SchemeColor::Accent5,
+        SchemeColor::Accent6,
+        SchemeColor::Dark1,
+        SchemeColor::Dark2,
+        SchemeColor::Light1,
+        SchemeColor::Light2,
+        SchemeColor::Hyperlink,
+        SchemeColor::FollowedHyperlink,
+        SchemeColor::Background1,
+        SchemeColor::Background2,
+        SchemeColor::Text1,
+        SchemeColor::Text2,
+    ];
+
+    /// Look up a scheme color by its `val` name (e.g., "accent1", "tx1")
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|c| c.as_str() == name)
+    }
+
     pub fn as_str(&self) -> &'static str {
         match self {
             SchemeColor::Accent1 => "accent1",
@@ -130,13 +304,111 @@ impl Color {
 
     /// Create from hex string
     pub fn from_hex(hex: &str) -> Option<Self> {
-        RgbColor::from_hex(hex).map(Color::Rgb)
+        RgbColor::from_hex(hex).ok().map(Color::Rgb)
     }
 
     /// Create from scheme color
     pub fn scheme(color: SchemeColor) -> Self {
         Color::Scheme(color)
     }
+
+    /// Value kept in string color fields: uppercase hex, or the scheme color name
+    pub fn to_value(&self) -> String {
+        match self {
+            Color::Rgb(rgb) => rgb.to_hex(),
+            Color::Scheme(scheme) => scheme.as_str().to_string(),
+        }
+    }
+}
+
+/// Color element for a string color field (see [`Color::to_value`]), wrapping `children`
+/// such as `<a:alpha>`
+pub(crate) fn color_value_xml(value: &str, children: &str) -> String {
+    let (tag, val) = match SchemeColor::from_name(value) {
+        Some(scheme) => ("schemeClr", scheme.as_str()),
+        None => ("srgbClr", value),
+    };
+    if children.is_empty() {
+        format!(r#"<a:{tag} val="{val}"/>"#)
+    } else {
+        format!(r#"<a:{tag} val="{val}">{children}</a:{tag}>"#)
+    }
+}
+
+/// Whether a string color field holds 6-digit hex or a theme color name, which is
+/// what the color setters store for any input that parses
+pub(crate) fn is_color_value(value: &str) -> bool {
+    is_hex_color(value) || SchemeColor::from_name(value).is_some()
+}
+
+/// Whether a string color field holds 6-digit hex, as slots outside DrawingML need
+pub(crate) fn is_hex_color(value: &str) -> bool {
+    value.len() == 6 && value.bytes().all(|b| b.is_ascii_hexdigit())
+}
+
+impl From<RgbColor> for Color {
+    fn from(rgb: RgbColor) -> Self {
+        Color::Rgb(rgb)
+    }
+}
+
+impl From<SchemeColor> for Color {
+    fn from(scheme: SchemeColor) -> Self {
+        Color::Scheme(scheme)
+    }
+}
+
+/// Color argument of setters that also take theme colors: a [`Color`], [`RgbColor`] or
+/// [`SchemeColor`], or a string. Scheme color names such as `"accent1"` give a theme
+/// color; other strings are handled as by [`RgbInput`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ColorInput(String);
+
+impl ColorInput {
+    /// Uppercase hex or scheme color name (see [`Color::to_value`]), or the unparsed
+    /// string as given
+    pub fn into_value(self) -> String {
+        self.0
+    }
+}
+
+impl<T: Into<Color>> From<T> for ColorInput {
+    fn from(color: T) -> Self {
+        Self(color.into().to_value())
+    }
+}
+
+impl From<RgbInput> for ColorInput {
+    fn from(rgb: RgbInput) -> Self {
+        Self(rgb.into_value())
+    }
+}
+
+impl From<&str> for ColorInput {
+    fn from(s: &str) -> Self {
+        match SchemeColor::from_name(s.trim()) {
+            Some(scheme) => Self(scheme.as_str().to_string()),
+            None => RgbInput::from(s).into(),
+        }
+    }
+}
+
+impl From<&String> for ColorInput {
+    fn from(s: &String) -> Self {
+        Self::from(s.as_str())
+    }
+}
+
+impl From<String> for ColorInput {
+    fn from(s: String) -> Self {
+        Self::from(s.as_str())
+    }
+}
+
+impl From<(u8, u8, u8)> for Color {
+    fn from(rgb: (u8, u8, u8)) -> Self {
+        Color::Rgb(rgb.into())
+    }
 }
 
 impl ToXml for Color {
@@ -196,4 +468,84 @@ mod tests {
         assert_eq!(RgbColor::white().to_hex(), "FFFFFF");
         assert_eq!(RgbColor::red().to_hex(), "FF0000");
     }
+
+    #[test]
+    fn test_rgb_from_hex_formats() {
+        assert_eq!(RgbColor::from_hex("#4472c4").unwrap().to_hex(), "4472C4");
+        assert_eq!(RgbColor::from_hex("4472C4").unwrap().to_hex(), "4472C4");
+        assert_eq!(RgbColor::from_hex("#F80").unwrap().to_hex(), "FF8800");
+        assert_eq!(RgbColor::from_hex("abc").unwrap().to_hex(), "AABBCC");
+        assert_eq!(RgbColor::from_hex(" SteelBlue ").unwrap().to_hex(), "4682B4");
+        assert_eq!("rebeccapurple".parse::<RgbColor>().unwrap().to_hex(), "663399");
+    }
+
+    #[test]
+    fn test_rgb_from_hex_rejects_invalid() {
+        for bad in ["", "#", "FF00", "#GG0000", "FF00000", "notacolor", "#red"] {
+            assert!(RgbColor::from_hex(bad).is_err(), "{bad}");
+        }
+        assert!(Color::from_hex("nope").is_none());
+    }
+
+    #[test]
+    fn test_named_colors_sorted() {
+        assert!(NAMED_COLORS.windows(2).all(|w| w[0].0 < w[1].0));
+        assert_eq!(NAMED_COLORS.len(), 148);
+    }
+
+    #[test]
+    fn test_rgb_from_hsl() {
+        assert_eq!(RgbColor::from_hsl(0.0, 1.0, 0.5), RgbColor::red());
+        assert_eq!(RgbColor::from_hsl(120.0, 1.0, 0.5), RgbColor::green());
+        assert_eq!(RgbColor::from_hsl(240.0, 1.0, 0.5), RgbColor::blue());
+        assert_eq!(RgbColor::from_hsl(480.0, 1.0, 0.5), RgbColor::green());
+        assert_eq!(RgbColor::from_hsl(0.0, 0.0, 1.0), RgbColor::white());
+        assert_eq!(RgbColor::from_hsl(210.0, 0.5, 0.5).to_hex(), "4080BF");
+        assert_eq!(RgbColor::from_hsl(30.0, 1.0, 0.25).to_hex(), "804000");
+    }
+
+    #[test]
+    fn test_rgb_into_conversions() {
+        let from_tuple: RgbColor = (68, 114, 196).into();
+        assert_eq!(from_tuple.to_hex(), "4472C4");
+        let owned = String::from("#4472c4");
+        assert_eq!(RgbInput::from(&owned).into_value(), "4472C4");
+        assert_eq!(RgbInput::from(owned).into_value(), "4472C4");
+        assert_eq!(RgbInput::from(from_tuple).into_value(), "4472C4");
+    }
+
+    #[test]
+    fn test_unparsed_strings_are_kept() {
+        assert_eq!(RgbInput::from("#FF000").into_value(), "#FF000");
+        assert_eq!(RgbInput::from("blu").into_value(), "blu");
+        assert_eq!(ColorInput::from("blu").into_value(), "blu");
+    }
+
+
+    #[test]
+    fn test_scheme_color_names() {
+        assert_eq!(SchemeColor::from_name("accent1"), Some(SchemeColor::Accent1));
+        assert_eq!(SchemeColor::from_name("folHlink"), Some(SchemeColor::FollowedHyperlink));
+        assert_eq!(SchemeColor::from_name("Accent1"), None);
+        for scheme in SchemeColor::ALL {
+            assert_eq!(SchemeColor::from_name(scheme.as_str()), Some(scheme));
+        }
+    }
+
+    #[test]
+    fn test_color_input_values() {
+        assert_eq!(ColorInput::from("accent2").into_value(), "accent2");
+        assert_eq!(ColorInput::from("#f00").into_value(), "FF0000");
+        assert_eq!(ColorInput::from(SchemeColor::Text1).into_value(), "tx1");
+        assert_eq!(ColorInput::from((0, 128, 255)).into_value(), "0080FF");
+    }
+
+    #[test]
+    fn test_color_value_xml() {
+        assert_eq!(color_value_xml("accent1", ""), r#"<a:schemeClr val="accent1"/>"#);
+        assert_eq!(
+            color_value_xml("FF0000", r#"<a:alpha val="50000"/>"#),
+            r#"<a:srgbClr val="FF0000"><a:alpha val="50000"/></a:srgbClr>"#
+        );
+    }
 }
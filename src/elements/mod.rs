@@ -3,7 +3,8 @@
 mod color;
 mod position;
 
-pub use color::{Color, RgbColor, SchemeColor};
+pub use color::{Color, ColorInput, RgbColor, RgbInput, SchemeColor};
+pub(crate) use color::{color_value_xml, is_color_value, is_hex_color};
 pub use position::{Position, Size, Transform, EMU_PER_CM, EMU_PER_INCH, EMU_PER_MM, EMU_PER_PT};
 
 pub use crate::core::{ElementSized, Positioned, ToXml};
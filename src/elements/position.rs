@@ -5,7 +5,7 @@
 //! 1 cm = 360000 EMU
 //! 1 pt = 12700 EMU
 
-use crate::core::ToXml;
+use crate::core::{emu_to_cm, emu_to_inches, emu_to_pt, ToXml};
 
 /// EMU conversion constants
 pub const EMU_PER_INCH: i64 = 914400;
@@ -44,12 +44,32 @@ impl Position {
 
     /// Get X in inches
     pub fn x_inches(&self) -> f64 {
-        self.x as f64 / EMU_PER_INCH as f64
+        emu_to_inches(self.x)
     }
 
     /// Get Y in inches
     pub fn y_inches(&self) -> f64 {
-        self.y as f64 / EMU_PER_INCH as f64
+        emu_to_inches(self.y)
+    }
+
+    /// Get X in centimeters
+    pub fn x_cm(&self) -> f64 {
+        emu_to_cm(self.x)
+    }
+
+    /// Get Y in centimeters
+    pub fn y_cm(&self) -> f64 {
+        emu_to_cm(self.y)
+    }
+
+    /// Get X in points
+    pub fn x_pt(&self) -> f64 {
+        emu_to_pt(self.x)
+    }
+
+    /// Get Y in points
+    pub fn y_pt(&self) -> f64 {
+        emu_to_pt(self.y)
     }
 }
 
@@ -84,12 +104,32 @@ impl Size {
 
     /// Get width in inches
     pub fn width_inches(&self) -> f64 {
-        self.width as f64 / EMU_PER_INCH as f64
+        emu_to_inches(self.width)
     }
 
     /// Get height in inches
     pub fn height_inches(&self) -> f64 {
-        self.height as f64 / EMU_PER_INCH as f64
+        emu_to_inches(self.height)
+    }
+
+    /// Get width in centimeters
+    pub fn width_cm(&self) -> f64 {
+        emu_to_cm(self.width)
+    }
+
+    /// Get height in centimeters
+    pub fn height_cm(&self) -> f64 {
+        emu_to_cm(self.height)
+    }
+
+    /// Get width in points
+    pub fn width_pt(&self) -> f64 {
+        emu_to_pt(self.width)
+    }
+
+    /// Get height in points
+    pub fn height_pt(&self) -> f64 {
+        emu_to_pt(self.height)
     }
 }
 
@@ -181,4 +221,19 @@ mod tests {
         assert_eq!(EMU_PER_CM, 360000);
         assert_eq!(EMU_PER_PT, 12700);
     }
+
+    #[test]
+    fn test_unit_getters() {
+        let pos = Position::from_cm(2.5, 10.0);
+        assert!((pos.x_cm() - 2.5).abs() < 1e-9);
+        assert!((pos.y_cm() - 10.0).abs() < 1e-9);
+        assert_eq!(Position::new(12700, 914400).x_pt(), 1.0);
+        assert_eq!(Position::new(12700, 914400).y_pt(), 72.0);
+
+        let size = Size::from_inches(4.0, 0.5);
+        assert_eq!(size.width_pt(), 288.0);
+        assert_eq!(size.height_pt(), 36.0);
+        assert!((size.width_cm() - 10.16).abs() < 1e-9);
+        assert!((size.height_cm() - 1.27).abs() < 1e-9);
+    }
 }
@@ -235,7 +235,7 @@ fn render_image_with_options(image: &Image, options: &HtmlExportOptions) -> Opti
 }
 
 // Simple base64 encoder
-fn base64_encode(data: &[u8]) -> String {
+pub(crate) fn base64_encode(data: &[u8]) -> String {
     const ALPHABET: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
     let mut output = String::with_capacity(data.len() * 4 / 3 + 4);
     
@@ -10,6 +10,12 @@ pub mod slide_render;
 #[cfg(feature = "pdf-native")]
 pub mod pdf_export;
 
+#[cfg(feature = "render")]
+pub mod svg;
+
+#[cfg(feature = "render")]
+pub mod png;
+
 pub use md::{export_to_markdown, export_to_markdown_with_options, MarkdownOptions};
 pub use image_export::{
     export_to_images, export_slide_to_image, render_thumbnail,
@@ -21,3 +27,9 @@ pub use pdf_export::{
     export_to_pdf, export_to_pdf_bytes,
     PdfExportOptions, PdfOrientation,
 };
+
+#[cfg(feature = "render")]
+pub use svg::render_slide_svg;
+
+#[cfg(feature = "render")]
+pub use png::render_slide_png;
@@ -0,0 +1,410 @@
+//! Rasterize slides to PNG thumbnails without external tools.
+//!
+//! This is a preview renderer, not a faithful one: element boxes are placed
+//! exactly where the slide XML puts them (EMU scaled to the requested pixel
+//! width), while text is drawn as solid glyph blocks in the run's color so
+//! line positions and lengths read correctly at thumbnail sizes.
+//!
+//! Colors fall back to the presentation theme: `lt1` for the background and
+//! `dk1` for text without an explicit color.
+
+use std::io::Write;
+
+use crate::core::{SLIDE_HEIGHT_EMU, SLIDE_WIDTH_EMU};
+use crate::exc::{PptxError, Result};
+use crate::export::svg::{bullet_lines, placed_slide, theme_colors, title_box, units};
+use crate::generator::presentation_theme::ThemeColorScheme;
+use crate::generator::shapes::{Shape, ShapeType};
+use crate::generator::slide_content::{PresentationSettings, SlideBackground, SlideContent};
+use crate::generator::table::Table;
+
+/// Largest accepted output width in pixels
+pub const MAX_PNG_WIDTH: u32 = 8192;
+
+type Rgb = [u8; 3];
+
+fn parse_hex(hex: &str) -> Option<Rgb> {
+    let hex = hex.trim_start_matches('#');
+    if hex.len() != 6 {
+        return None;
+    }
+    let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+    Some([channel(0)?, channel(2)?, channel(4)?])
+}
+
+/// RGB pixel buffer addressed in points, scaled to pixels on draw
+struct Canvas {
+    width: u32,
+    height: u32,
+    /// Pixels per point
+    scale: f64,
+    pixels: Vec<u8>,
+}
+
+impl Canvas {
+    fn new(width: u32, height: u32, scale: f64, background: Rgb) -> Self {
+        let pixels = background.repeat((width * height) as usize);
+        Canvas { width, height, scale, pixels }
+    }
+
+    fn blend(&mut self, x: u32, y: u32, color: Rgb, alpha: f64) {
+        let i = ((y * self.width + x) * 3) as usize;
+        for (c, channel) in color.iter().enumerate() {
+            let dst = self.pixels[i + c] as f64;
+            self.pixels[i + c] = (dst + (*channel as f64 - dst) * alpha).round() as u8;
+        }
+    }
+
+    /// Pixel span covering `[start, start + len)` points, clamped to `limit`
+    fn span(&self, start: f64, len: f64, limit: u32) -> (u32, u32) {
+        let from = (start * self.scale).round().clamp(0.0, limit as f64) as u32;
+        let to = ((start + len) * self.scale).round().clamp(0.0, limit as f64) as u32;
+        (from, to)
+    }
+
+    fn fill_rect(&mut self, x: f64, y: f64, w: f64, h: f64, color: Rgb, alpha: f64) {
+        let (x0, x1) = self.span(x, w, self.width);
+        let (y0, y1) = self.span(y, h, self.height);
+        for py in y0..y1 {
+            for px in x0..x1 {
+                self.blend(px, py, color, alpha);
+            }
+        }
+    }
+
+    fn stroke_rect(&mut self, x: f64, y: f64, w: f64, h: f64, color: Rgb, thickness: f64) {
+        let t = thickness.max(1.0 / self.scale);
+        self.fill_rect(x, y, w, t, color, 1.0);
+        self.fill_rect(x, y + h - t, w, t, color, 1.0);
+        self.fill_rect(x, y, t, h, color, 1.0);
+        self.fill_rect(x + w - t, y, t, h, color, 1.0);
+    }
+
+    fn fill_ellipse(&mut self, x: f64, y: f64, w: f64, h: f64, color: Rgb, alpha: f64) {
+        let (x0, x1) = self.span(x, w, self.width);
+        let (y0, y1) = self.span(y, h, self.height);
+        let (cx, cy) = ((x + w / 2.0) * self.scale, (y + h / 2.0) * self.scale);
+        let (rx, ry) = (w / 2.0 * self.scale, h / 2.0 * self.scale);
+        if rx <= 0.0 || ry <= 0.0 {
+            return;
+        }
+        for py in y0..y1 {
+            for px in x0..x1 {
+                let dx = (px as f64 + 0.5 - cx) / rx;
+                let dy = (py as f64 + 0.5 - cy) / ry;
+                if dx * dx + dy * dy <= 1.0 {
+                    self.blend(px, py, color, alpha);
+                }
+            }
+        }
+    }
+
+    fn draw_line(&mut self, (x1, y1): (f64, f64), (x2, y2): (f64, f64), color: Rgb, thickness: f64) {
+        let length = ((x2 - x1).powi(2) + (y2 - y1).powi(2)).sqrt() * self.scale;
+        let steps = length.ceil().max(1.0) as u32;
+        let t = thickness.max(1.0 / self.scale);
+        for step in 0..=steps {
+            let f = step as f64 / steps as f64;
+            let (x, y) = (x1 + (x2 - x1) * f, y1 + (y2 - y1) * f);
+            self.fill_rect(x - t / 2.0, y - t / 2.0, t, t, color, 1.0);
+        }
+    }
+
+    /// Draw text as glyph blocks sitting on `baseline`, clipped to `max_width` points
+    fn text_blocks(&mut self, text: &str, (x, baseline): (f64, f64), size: f64, bold: bool, color: Rgb, max_width: f64) {
+        let advance = size * 0.5;
+        let glyph_width = if bold { advance * 0.85 } else { advance * 0.7 };
+        let glyph_height = size * 0.55;
+        for (i, ch) in text.chars().enumerate() {
+            let gx = x + advance * i as f64;
+            if gx + glyph_width > x + max_width {
+                break;
+            }
+            if !ch.is_whitespace() {
+                self.fill_rect(gx, baseline - glyph_height, glyph_width, glyph_height, color, 1.0);
+            }
+        }
+    }
+
+    fn text_width(text: &str, size: f64) -> f64 {
+        text.chars().count() as f64 * size * 0.5
+    }
+}
+
+fn render_title(canvas: &mut Canvas, slide: &SlideContent, text_color: Rgb) {
+    let Some(((bx, by, bw, bh), centered)) = title_box(slide.effective_layout()) else {
+        return;
+    };
+    if slide.title.is_empty() {
+        return;
+    }
+
+    let size = slide.title_size.unwrap_or(44) as f64;
+    let color = slide.title_color.as_deref().and_then(parse_hex).unwrap_or(text_color);
+    let baseline = units(by) + units(bh) / 2.0 + size * 0.35;
+    let x = if centered {
+        units(bx) + (units(bw) - Canvas::text_width(&slide.title, size)).max(0.0) / 2.0
+    } else {
+        units(bx)
+    };
+    canvas.text_blocks(&slide.title, (x, baseline), size, slide.title_bold, color, units(bw));
+}
+
+fn render_bullets(canvas: &mut Canvas, slide: &SlideContent, text_color: Rgb) {
+    let default_color = slide.content_color.as_deref().and_then(parse_hex).unwrap_or(text_color);
+    let right = canvas.width as f64 / canvas.scale;
+    for line in bullet_lines(slide) {
+        let color = line.color.as_deref().and_then(parse_hex).unwrap_or(default_color);
+        if !line.prefix.is_empty() {
+            canvas.text_blocks(&line.prefix, (line.x, line.y), line.size, line.bold, color, right - line.x);
+        }
+        let x = line.x + line.size * 1.2;
+        canvas.text_blocks(&line.text, (x, line.y), line.size, line.bold, color, right - x);
+    }
+}
+
+fn render_shape(canvas: &mut Canvas, shape: &Shape, scheme: &ThemeColorScheme, text_color: Rgb) {
+    let (x, y, w, h) = (units(shape.x), units(shape.y), units(shape.width), units(shape.height));
+    let fill = shape
+        .fill
+        .as_ref()
+        .and_then(|f| parse_hex(scheme.resolve(&f.color)).map(|c| (c, f.transparency)))
+        .or_else(|| {
+            shape
+                .gradient
+                .as_ref()
+                .and_then(|g| g.stops.first())
+                .and_then(|s| parse_hex(scheme.resolve(&s.color)))
+                .map(|c| (c, None))
+        });
+
+    if let Some((color, transparency)) = fill {
+        // `ShapeFill::transparency` holds the remaining opacity, as in svg.rs
+        let alpha = transparency.map_or(1.0, |a| a.min(100000) as f64 / 100000.0);
+        match shape.shape_type {
+            ShapeType::Ellipse | ShapeType::Circle => canvas.fill_ellipse(x, y, w, h, color, alpha),
+            _ => canvas.fill_rect(x, y, w, h, color, alpha),
+        }
+    }
+    if let Some(ref line) = shape.line
+        && let Some(color) = parse_hex(scheme.resolve(&line.color))
+        && !matches!(shape.shape_type, ShapeType::Ellipse | ShapeType::Circle)
+    {
+        canvas.stroke_rect(x, y, w, h, color, units(line.width));
+    }
+
+    if let Some(ref text) = shape.text {
+        let size = 18.0;
+        let tx = x + (w - Canvas::text_width(text, size)).max(0.0) / 2.0;
+        canvas.text_blocks(text, (tx, y + h / 2.0 + size * 0.35), size, false, text_color, w);
+    }
+}
+
+fn render_table(canvas: &mut Canvas, table: &Table, scheme: &ThemeColorScheme, text_color: Rgb) {
+    let grid = [0x80, 0x80, 0x80];
+    let mut y = table.y;
+    for row in &table.rows {
+        let row_height = row.height.unwrap_or(400000);
+        let mut x = table.x;
+        for (cell, col_width) in row.cells.iter().zip(&table.column_widths) {
+            let (cx, cy, cw, ch) = (units(x), units(y), units(*col_width), units(row_height));
+            if let Some(fill) = cell.background_color.as_deref().and_then(|c| parse_hex(scheme.resolve(c))) {
+                canvas.fill_rect(cx, cy, cw, ch, fill, 1.0);
+            }
+            canvas.stroke_rect(cx, cy, cw, ch, grid, 0.75);
+            if !cell.text.is_empty() && !cell.h_merge && !cell.v_merge {
+                let size = cell.font_size.unwrap_or(18) as f64;
+                let color = cell.text_color.as_deref().and_then(|c| parse_hex(scheme.resolve(c))).unwrap_or(text_color);
+                let tx = cx + (cw - Canvas::text_width(&cell.text, size)).max(0.0) / 2.0;
+                canvas.text_blocks(&cell.text, (tx, cy + ch / 2.0 + size * 0.35), size, cell.bold, color, cw);
+            }
+            x += col_width;
+        }
+        y += row_height;
+    }
+}
+
+/// Render a single slide as a PNG image `width` pixels wide.
+///
+/// The height follows the slide's aspect ratio. `settings` supplies the
+/// slide size and theme colors; `None` uses the 4:3 default and the Office theme.
+pub fn render_slide_png(slide: &SlideContent, settings: Option<&PresentationSettings>, width: u32) -> Result<Vec<u8>> {
+    if width == 0 || width > MAX_PNG_WIDTH {
+        return Err(PptxError::InvalidValue(format!(
+            "PNG width must be between 1 and {MAX_PNG_WIDTH} pixels, got {width}"
+        )));
+    }
+
+    let (slide_width, slide_height) = settings
+        .and_then(|s| s.slide_size)
+        .map(|size| (size.width, size.height))
+        .unwrap_or((SLIDE_WIDTH_EMU, SLIDE_HEIGHT_EMU));
+    let scheme = theme_colors(settings);
+    let text_color = parse_hex(&scheme.dk1).unwrap_or([0, 0, 0]);
+
+    let scale = width as f64 / units(slide_width);
+    let height = ((units(slide_height) * scale).round() as u32).max(1);
+    let background = match slide.background {
+        Some(SlideBackground::Color(ref hex)) => parse_hex(hex),
+        Some(SlideBackground::Gradient(ref gradient)) => gradient.stops.first().and_then(|s| parse_hex(&s.color)),
+        _ => None,
+    }
+    .or_else(|| parse_hex(&scheme.lt1))
+    .unwrap_or([0xFF, 0xFF, 0xFF]);
+
+    let mut canvas = Canvas::new(width, height, scale, background);
+    let slide = &*placed_slide(slide);
+
+    render_title(&mut canvas, slide, text_color);
+    render_bullets(&mut canvas, slide, text_color);
+    if let Some(ref table) = slide.table {
+        render_table(&mut canvas, table, &scheme, text_color);
+    }
+    for shape in &slide.shapes {
+        render_shape(&mut canvas, shape, &scheme, text_color);
+    }
+    for image in &slide.images {
+        let (x, y, w, h) = (units(image.x), units(image.y), units(image.width), units(image.height));
+        canvas.fill_rect(x, y, w, h, [0xE0, 0xE0, 0xE0], 1.0);
+        canvas.stroke_rect(x, y, w, h, [0x80, 0x80, 0x80], 0.75);
+    }
+    for connector in &slide.connectors {
+        let color = parse_hex(scheme.resolve(&connector.line.color)).unwrap_or(text_color);
+        canvas.draw_line(
+            (units(connector.start_x), units(connector.start_y)),
+            (units(connector.end_x), units(connector.end_y)),
+            color,
+            units(connector.line.width),
+        );
+    }
+    for chart in &slide.charts {
+        let (x, y, w, h) = (units(chart.x), units(chart.y), units(chart.width), units(chart.height));
+        canvas.fill_rect(x, y, w, h, [0xF5, 0xF5, 0xF5], 1.0);
+        canvas.stroke_rect(x, y, w, h, [0xBF, 0xBF, 0xBF], 0.75);
+    }
+
+    encode_png(&canvas)
+}
+
+fn png_chunk(out: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    out.extend_from_slice(&(data.len() as u32).to_be_bytes());
+    out.extend_from_slice(kind);
+    out.extend_from_slice(data);
+    let mut crc = flate2::Crc::new();
+    crc.update(kind);
+    crc.update(data);
+    out.extend_from_slice(&crc.sum().to_be_bytes());
+}
+
+/// Encode the canvas as an 8-bit RGB PNG
+fn encode_png(canvas: &Canvas) -> Result<Vec<u8>> {
+    let mut header = Vec::with_capacity(13);
+    header.extend_from_slice(&canvas.width.to_be_bytes());
+    header.extend_from_slice(&canvas.height.to_be_bytes());
+    // Bit depth 8, color type 2 (RGB), default compression/filter, no interlace
+    header.extend_from_slice(&[8, 2, 0, 0, 0]);
+
+    let row_len = canvas.width as usize * 3;
+    let mut encoder = flate2::write::ZlibEncoder::new(Vec::new(), flate2::Compression::default());
+    for row in canvas.pixels.chunks(row_len) {
+        // Filter type 0 (None) per scanline
+        encoder.write_all(&[0])?;
+        encoder.write_all(row)?;
+    }
+    let data = encoder.finish()?;
+
+    let mut png = Vec::with_capacity(data.len() + 64);
+    png.extend_from_slice(b"\x89PNG\r\n\x1a\n");
+    png_chunk(&mut png, b"IHDR", &header);
+    png_chunk(&mut png, b"IDAT", &data);
+    png_chunk(&mut png, b"IEND", &[]);
+    Ok(png)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generator::shapes::ShapeFill;
+    use crate::generator::PresentationTheme;
+    use std::io::Read;
+
+    /// Decode the RGB pixels written by `encode_png`
+    fn decode(png: &[u8]) -> (u32, u32, Vec<u8>) {
+        let width = u32::from_be_bytes(png[16..20].try_into().unwrap());
+        let height = u32::from_be_bytes(png[20..24].try_into().unwrap());
+        let idat_len = u32::from_be_bytes(png[33..37].try_into().unwrap()) as usize;
+        assert_eq!(&png[37..41], b"IDAT");
+        let mut raw = Vec::new();
+        flate2::read::ZlibDecoder::new(&png[41..41 + idat_len]).read_to_end(&mut raw).unwrap();
+        let pixels = raw.chunks(width as usize * 3 + 1).flat_map(|row| row[1..].to_vec()).collect();
+        (width, height, pixels)
+    }
+
+    fn pixel_at(pixels: &[u8], width: u32, x: u32, y: u32) -> [u8; 3] {
+        let i = ((y * width + x) * 3) as usize;
+        [pixels[i], pixels[i + 1], pixels[i + 2]]
+    }
+
+    #[test]
+    fn test_png_header_and_size() {
+        let png = render_slide_png(&SlideContent::new("Hello"), None, 320).unwrap();
+        assert!(png.starts_with(b"\x89PNG\r\n\x1a\n"));
+        assert_eq!(&png[12..16], b"IHDR");
+        assert!(png.ends_with(b"IEND\xae\x42\x60\x82"));
+        let (width, height, pixels) = decode(&png);
+        assert_eq!((width, height), (320, 240));
+        assert_eq!(pixels.len(), 320 * 240 * 3);
+    }
+
+    #[test]
+    fn test_shape_lands_at_emu_position() {
+        // 1270000 EMU = 100pt; at 720px wide one point is one pixel
+        let slide = SlideContent::new("").layout(crate::generator::SlideLayout::Blank).add_shape(
+            Shape::new(ShapeType::Rectangle, 1270000, 1270000, 1270000, 635000).with_fill(ShapeFill::new("FF0000")),
+        );
+        let (width, _, pixels) = decode(&render_slide_png(&slide, None, 720).unwrap());
+        assert_eq!(pixel_at(&pixels, width, 150, 120), [0xFF, 0, 0]);
+        assert_eq!(pixel_at(&pixels, width, 99, 120), [0xFF, 0xFF, 0xFF]);
+        assert_eq!(pixel_at(&pixels, width, 150, 151), [0xFF, 0xFF, 0xFF]);
+    }
+
+    #[test]
+    fn test_fill_transparency() {
+        let slide = SlideContent::new("").layout(crate::generator::SlideLayout::Blank)
+            .add_shape(Shape::new(ShapeType::Rectangle, 0, 0, 1270000, 1270000)
+                .with_fill(ShapeFill::new("FF0000").with_transparency(0)))
+            .add_shape(Shape::new(ShapeType::Rectangle, 2540000, 0, 1270000, 1270000)
+                .with_fill(ShapeFill::new("0000FF").with_transparency(50)));
+        let (width, _, pixels) = decode(&render_slide_png(&slide, None, 720).unwrap());
+        assert_eq!(pixel_at(&pixels, width, 50, 50), [0xFF, 0, 0]);
+        // Half-transparent blue over the white background
+        assert_eq!(pixel_at(&pixels, width, 250, 50), [0x80, 0x80, 0xFF]);
+    }
+
+    #[test]
+    fn test_theme_colors_and_slide_size() {
+        let mut colors = ThemeColorScheme::office();
+        colors.lt1 = "101010".into();
+        let theme = PresentationTheme::office().colors(colors);
+        let settings = PresentationSettings::new()
+            .theme(theme)
+            .slide_size(crate::core::SlideSizePreset::Screen16x9);
+        let (width, height, pixels) = decode(&render_slide_png(&SlideContent::new("Wide"), Some(&settings), 640).unwrap());
+        assert_eq!((width, height), (640, 360));
+        assert_eq!(pixel_at(&pixels, width, 5, 355), [0x10, 0x10, 0x10]);
+    }
+
+    #[test]
+    fn test_title_text_drawn_in_title_box() {
+        let slide = SlideContent::new("MMMM").title_color("00FF00");
+        let (width, _, pixels) = decode(&render_slide_png(&slide, None, 720).unwrap());
+        // Title box starts at 36pt; glyph blocks sit just above the baseline at ~81pt
+        assert_eq!(pixel_at(&pixels, width, 40, 70), [0, 0xFF, 0]);
+    }
+
+    #[test]
+    fn test_rejects_bad_width() {
+        assert!(render_slide_png(&SlideContent::new("x"), None, 0).is_err());
+        assert!(render_slide_png(&SlideContent::new("x"), None, MAX_PNG_WIDTH + 1).is_err());
+    }
+}
@@ -99,9 +99,7 @@ fn draw_text_line(
     y: f32,
     font_name: &str,
     font_size: f32,
-    r: f32,
-    g: f32,
-    b: f32,
+    (r, g, b): (f32, f32, f32),
 ) {
     set_color(out, r, g, b);
     set_font(out, font_name, font_size);
@@ -129,7 +127,7 @@ fn bullet_prefix(style: BulletStyle, index: u32) -> String {
     }
 }
 
-fn to_roman(mut num: u32) -> String {
+pub(crate) fn to_roman(mut num: u32) -> String {
     let pairs = [(1000, "M"), (900, "CM"), (500, "D"), (400, "CD"), (100, "C"), (90, "XC"), (50, "L"), (40, "XL"), (10, "X"), (9, "IX"), (5, "V"), (4, "IV"), (1, "I")];
     let mut result = String::new();
     for (val, sym) in pairs {
@@ -168,7 +166,7 @@ fn render_slide(
 
     let title_y = page_h - margin - title_size;
 
-    match slide.layout {
+    match slide.effective_layout() {
         SlideLayout::CenteredTitle | SlideLayout::SectionHeader => {
             let tw = approx_text_width(&slide.title, title_size);
             let tx = (page_w - tw) / 2.0;
@@ -179,7 +177,7 @@ fn render_slide(
                 title_y,
                 font_name(title_bold, title_italic),
                 title_size,
-                tr, tg, tb,
+                (tr, tg, tb),
             );
         }
         _ => {
@@ -190,7 +188,7 @@ fn render_slide(
                 title_y,
                 font_name(title_bold, title_italic),
                 title_size,
-                tr, tg, tb,
+                (tr, tg, tb),
             );
             // Underline
             if slide.title_underline {
@@ -215,7 +213,7 @@ fn render_slide(
     let line_height = content_size * 1.5;
     let mut y = title_y - line_height * 1.5;
 
-    let is_centered = matches!(slide.layout, SlideLayout::CenteredTitle | SlideLayout::SectionHeader);
+    let is_centered = matches!(slide.effective_layout(), SlideLayout::CenteredTitle | SlideLayout::SectionHeader);
 
     if !slide.bullets.is_empty() {
         let mut numbered = 0u32;
@@ -248,7 +246,7 @@ fn render_slide(
                     y,
                     font_name(b_bold, b_italic),
                     b_size,
-                    btr, btg, btb,
+                    (btr, btg, btb),
                 );
             }
 
@@ -261,7 +259,7 @@ fn render_slide(
                 y,
                 font_name(b_bold, b_italic),
                 b_size,
-                btr, btg, btb,
+                (btr, btg, btb),
             );
 
             y -= line_height;
@@ -269,6 +267,25 @@ fn render_slide(
                 numbered += 1;
             }
         }
+    } else if slide.has_columns() {
+        // Columns are listed one after the other, each under its heading
+        let columns = [(&slide.left_heading, &slide.left_bullets), (&slide.right_heading, &slide.right_bullets)];
+        for (heading, lines) in columns {
+            if let Some(heading) = heading
+                && y >= margin + line_height {
+                draw_text_line(&mut cs, heading, margin, y, font_name(true, content_italic), content_size, (cr, cg, cb));
+                y -= line_height;
+            }
+            for (i, line) in lines.iter().enumerate() {
+                if y < margin + line_height {
+                    break;
+                }
+                let prefix = bullet_prefix(slide.bullet_style, i as u32);
+                let text = if prefix.is_empty() { line.clone() } else { format!("{prefix} {line}") };
+                draw_text_line(&mut cs, &text, margin, y, font_name(content_bold, content_italic), content_size, (cr, cg, cb));
+                y -= line_height;
+            }
+        }
     } else if !slide.content.is_empty() {
         for line in &slide.content {
             if y < margin + line_height {
@@ -277,9 +294,9 @@ fn render_slide(
             if is_centered {
                 let tw = approx_text_width(line, content_size);
                 let tx = (page_w - tw) / 2.0;
-                draw_text_line(&mut cs, line, tx, y, font_name(content_bold, content_italic), content_size, cr, cg, cb);
+                draw_text_line(&mut cs, line, tx, y, font_name(content_bold, content_italic), content_size, (cr, cg, cb));
             } else {
-                draw_text_line(&mut cs, line, margin, y, font_name(content_bold, content_italic), content_size, cr, cg, cb);
+                draw_text_line(&mut cs, line, margin, y, font_name(content_bold, content_italic), content_size, (cr, cg, cb));
             }
             y -= line_height;
         }
@@ -304,7 +321,7 @@ fn render_slide(
             if y < margin {
                 break;
             }
-            draw_text_line(&mut cs, line, margin + 8.0, y, "Courier", code_size, 0.1, 0.1, 0.1);
+            draw_text_line(&mut cs, line, margin + 8.0, y, "Courier", code_size, (0.1, 0.1, 0.1));
             y -= code_line_h;
         }
         y -= line_height * 0.5;
@@ -320,7 +337,7 @@ fn render_slide(
         margin / 2.0,
         FONT_HELVETICA,
         10.0,
-        0.5, 0.5, 0.5,
+        (0.5, 0.5, 0.5),
     );
 
     cs
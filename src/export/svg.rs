@@ -0,0 +1,561 @@
+//! Render slides as standalone SVG documents for web previews.
+//!
+//! Element positions are mapped from EMU to SVG user units at 12700 EMU per
+//! unit (1 unit = 1 pt), so a default 4:3 slide becomes a `720 x 540` viewBox.
+//! The layout follows the same title/content boxes used by the slide XML
+//! generator and the PDF renderer, keeping previews consistent with the deck.
+//!
+//! Rendered elements:
+//! - Slide background (solid, gradient, or picture; the theme's `lt1` otherwise)
+//! - Title and bullet text
+//! - Shapes (rectangles, ellipses, rounded rectangles; other presets as boxes)
+//! - Tables (cell backgrounds, grid rules, and text)
+//! - Images (embedded as data URIs when bytes are available)
+//! - Connectors (straight lines)
+//! - Charts (labeled placeholder frames)
+
+use std::borrow::Cow;
+
+use crate::core::escape_xml;
+use crate::export::slide_render::to_roman;
+use crate::generator::gradients::{GradientFill, GradientType};
+use crate::generator::images::Image;
+use crate::generator::presentation_theme::ThemeColorScheme;
+use crate::generator::shapes::{Shape, ShapeType};
+use crate::generator::slide_content::{
+    place_caption_picture, BulletStyle, PresentationSettings, SlideBackground, SlideContent, SlideLayout, CAPTION_BOX,
+    CAPTION_TITLE_BOX, CENTERED_TITLE_BOX, CONTENT_BOX, SECTION_TITLE_BOX, TITLE_BOX,
+};
+use crate::generator::table::Table;
+
+/// EMU per SVG user unit (one typographic point)
+const EMU_PER_UNIT: f64 = 12700.0;
+
+/// A positioned line of body text, in points from the slide's top-left corner
+pub(crate) struct BulletLine {
+    pub prefix: String,
+    pub text: String,
+    pub x: f64,
+    /// Baseline
+    pub y: f64,
+    pub size: f64,
+    pub bold: bool,
+    pub italic: bool,
+    /// Explicit hex color; `None` uses the slide's content color
+    pub color: Option<String>,
+}
+
+pub(crate) fn units(emu: u32) -> f64 {
+    emu as f64 / EMU_PER_UNIT
+}
+
+fn fmt_units(emu: u32) -> String {
+    format!("{:.2}", units(emu))
+}
+
+/// Theme colors for previews: the deck's theme, or the Office theme without settings
+pub(crate) fn theme_colors(settings: Option<&PresentationSettings>) -> ThemeColorScheme {
+    settings
+        .and_then(|s| s.theme.as_ref())
+        .map(|t| t.colors.clone())
+        .unwrap_or_else(ThemeColorScheme::office)
+}
+
+/// SVG color for a color field, with scheme names looked up in the theme
+fn rgb(colors: &ThemeColorScheme, color: &str) -> String {
+    format!("#{}", colors.resolve(color).trim_start_matches('#').to_uppercase())
+}
+
+/// Font size in points, weight, slant and SVG fill of a text element
+struct TextStyle<'a> {
+    size_pt: f64,
+    bold: bool,
+    italic: bool,
+    color: &'a str,
+}
+
+impl<'a> TextStyle<'a> {
+    /// Regular upright text
+    fn plain(size_pt: f64, color: &'a str) -> Self {
+        TextStyle { size_pt, bold: false, italic: false, color }
+    }
+}
+
+fn push_text(svg: &mut String, text: &str, x: f64, y: f64, style: &TextStyle, anchor: &str) {
+    let TextStyle { size_pt, bold, italic, color } = style;
+    svg.push_str(&format!(
+        r#"<text x="{x:.2}" y="{y:.2}" font-family="Calibri, Arial, sans-serif" font-size="{size_pt:.1}" fill="{color}" text-anchor="{anchor}"{}{}>{}</text>"#,
+        if *bold { r#" font-weight="bold""# } else { "" },
+        if *italic { r#" font-style="italic""# } else { "" },
+        escape_xml(text),
+    ));
+    svg.push('\n');
+}
+
+fn bullet_prefix(style: BulletStyle, index: u32) -> String {
+    match style {
+        BulletStyle::Bullet => "\u{2022}".to_string(),
+        BulletStyle::Number => format!("{}.", index + 1),
+        BulletStyle::LetterLower => format!("{}.", char::from_u32('a' as u32 + index % 26).unwrap_or('a')),
+        BulletStyle::LetterUpper => format!("{}.", char::from_u32('A' as u32 + index % 26).unwrap_or('A')),
+        BulletStyle::RomanLower => format!("{}.", to_roman(index + 1).to_lowercase()),
+        BulletStyle::RomanUpper => format!("{}.", to_roman(index + 1)),
+        BulletStyle::Custom(ch) => ch.to_string(),
+        BulletStyle::None => String::new(),
+    }
+}
+
+/// Title box in EMU for the slide's layout and whether the title is centered
+pub(crate) fn title_box(layout: SlideLayout) -> Option<((u32, u32, u32, u32), bool)> {
+    match layout {
+        SlideLayout::Blank => None,
+        SlideLayout::CenteredTitle => Some((CENTERED_TITLE_BOX, true)),
+        SlideLayout::SectionHeader => Some((SECTION_TITLE_BOX, true)),
+        SlideLayout::PictureWithCaption => Some((CAPTION_TITLE_BOX, false)),
+        _ => Some((TITLE_BOX, false)),
+    }
+}
+
+/// The slide as generated: a picture-with-caption slide has its picture fitted to the picture area
+pub(crate) fn placed_slide(slide: &SlideContent) -> Cow<'_, SlideContent> {
+    if slide.effective_layout() == SlideLayout::PictureWithCaption && !slide.images.is_empty() {
+        Cow::Owned(place_caption_picture(slide))
+    } else {
+        Cow::Borrowed(slide)
+    }
+}
+
+fn render_title(svg: &mut String, slide: &SlideContent, colors: &ThemeColorScheme) {
+    let Some(((bx, by, bw, bh), centered)) = title_box(slide.effective_layout()) else {
+        return;
+    };
+    if slide.title.is_empty() {
+        return;
+    }
+
+    let size = slide.title_size.unwrap_or(44) as f64;
+    let color = slide.title_color.as_deref().map(|c| rgb(colors, c)).unwrap_or_else(|| "#000000".to_string());
+    let baseline = units(by) + units(bh) / 2.0 + size * 0.35;
+    let (x, anchor) = if centered {
+        (units(bx) + units(bw) / 2.0, "middle")
+    } else {
+        (units(bx), "start")
+    };
+    let style = TextStyle { size_pt: size, bold: slide.title_bold, italic: slide.title_italic, color: &color };
+    push_text(svg, &slide.title, x, baseline, &style, anchor);
+}
+
+/// Lay out the slide's bullets (or plain content lines) inside the content box
+pub(crate) fn bullet_lines(slide: &SlideContent) -> Vec<BulletLine> {
+    let layout = slide.effective_layout();
+    if matches!(layout, SlideLayout::Blank | SlideLayout::TitleOnly | SlideLayout::CenteredTitle | SlideLayout::SectionHeader)
+        || slide.table.is_some()
+    {
+        return Vec::new();
+    }
+
+    let (size, (bx, by, bw, bh)) = if layout == SlideLayout::PictureWithCaption {
+        (slide.content_size.unwrap_or(28).min(18) as f64, CAPTION_BOX)
+    } else {
+        (slide.content_size.unwrap_or(28) as f64, CONTENT_BOX)
+    };
+    let line_height = size * 1.2;
+    let bottom = units(by) + units(bh);
+
+    // (text, level, style, format, is column heading)
+    type Item<'a> = (String, u32, BulletStyle, Option<&'a crate::generator::slide_content::BulletTextFormat>, bool);
+    let two_columns = matches!(layout, SlideLayout::TwoColumn | SlideLayout::Comparison);
+    let (mut items, split): (Vec<Item>, usize) = if two_columns && slide.has_columns() {
+        let column = |heading: &Option<String>, texts: &[String]| -> Vec<Item> {
+            heading
+                .iter()
+                .map(|h| (h.clone(), 0, BulletStyle::None, None, true))
+                .chain(texts.iter().map(|t| (t.clone(), 0, slide.bullet_style, None, false)))
+                .collect()
+        };
+        let mut items = column(&slide.left_heading, &slide.left_bullets);
+        let split = items.len();
+        items.extend(column(&slide.right_heading, &slide.right_bullets));
+        (items, split)
+    } else {
+        let items: Vec<Item> = if slide.bullets.is_empty() {
+            slide.content.iter().map(|t| (t.clone(), 0, slide.bullet_style, None, false)).collect()
+        } else {
+            slide.bullets.iter().map(|b| (b.text.clone(), b.level, b.style, b.format.as_ref(), false)).collect()
+        };
+        let split = if two_columns { slide.column_split() } else { items.len() };
+        (items, split)
+    };
+    if layout == SlideLayout::PictureWithCaption {
+        // Captions drop the default bullet marker, as in the slide XML
+        for (_, _, style, _, _) in &mut items {
+            if matches!(style, BulletStyle::Bullet) {
+                *style = BulletStyle::None;
+            }
+        }
+    }
+
+    let columns = if two_columns { 2 } else { 1 };
+    let column_width = units(bw) / columns as f64;
+
+    let mut lines = Vec::new();
+    let mut numbered = 0u32;
+    for (i, (text, level, style, format, heading)) in items.into_iter().enumerate() {
+        let column = usize::from(i >= split);
+        let row = i - split * column;
+        let y = units(by) + line_height * (row as f64 + 1.0);
+        if y > bottom {
+            continue;
+        }
+
+        lines.push(BulletLine {
+            prefix: bullet_prefix(style, numbered),
+            text,
+            x: units(bx) + column_width * column as f64 + level as f64 * 36.0,
+            y,
+            size: format.and_then(|f| f.font_size).map(|s| s as f64).unwrap_or(size),
+            bold: heading || slide.content_bold || format.is_some_and(|f| f.bold),
+            italic: slide.content_italic || format.is_some_and(|f| f.italic),
+            color: format.and_then(|f| f.color.clone()),
+        });
+
+        if !matches!(style, BulletStyle::Bullet | BulletStyle::Custom(_) | BulletStyle::None) {
+            numbered += 1;
+        }
+    }
+    lines
+}
+
+fn render_bullets(svg: &mut String, slide: &SlideContent, colors: &ThemeColorScheme) {
+    let default_color = slide.content_color.as_deref().map(|c| rgb(colors, c)).unwrap_or_else(|| "#333333".to_string());
+    for line in bullet_lines(slide) {
+        let color = line.color.as_deref().map(|c| rgb(colors, c)).unwrap_or_else(|| default_color.clone());
+        let style = TextStyle { size_pt: line.size, bold: line.bold, italic: line.italic, color: &color };
+        if !line.prefix.is_empty() {
+            push_text(svg, &line.prefix, line.x, line.y, &style, "start");
+        }
+        push_text(svg, &line.text, line.x + line.size * 1.2, line.y, &style, "start");
+    }
+}
+
+fn render_shape(svg: &mut String, shape: &Shape, colors: &ThemeColorScheme) {
+    let (x, y, w, h) = (units(shape.x), units(shape.y), units(shape.width), units(shape.height));
+    let fill = shape
+        .fill
+        .as_ref()
+        .map(|f| rgb(colors, &f.color))
+        .or_else(|| shape.gradient.as_ref().and_then(|g| g.stops.first()).map(|s| rgb(colors, &s.color)))
+        .unwrap_or_else(|| "none".to_string());
+    let opacity = shape
+        .fill
+        .as_ref()
+        .and_then(|f| f.transparency)
+        .map(|alpha| format!(r#" fill-opacity="{:.2}""#, alpha as f64 / 100000.0))
+        .unwrap_or_default();
+    let stroke = shape
+        .line
+        .as_ref()
+        .map(|l| format!(r#" stroke="{}" stroke-width="{:.2}""#, rgb(colors, &l.color), units(l.width)))
+        .unwrap_or_default();
+    let transform = shape
+        .rotation
+        .filter(|r| *r != 0)
+        .map(|r| format!(r#" transform="rotate({} {:.2} {:.2})""#, r, x + w / 2.0, y + h / 2.0))
+        .unwrap_or_default();
+
+    let element = match shape.shape_type {
+        ShapeType::Ellipse | ShapeType::Circle => format!(
+            r#"<ellipse cx="{:.2}" cy="{:.2}" rx="{:.2}" ry="{:.2}" fill="{fill}"{opacity}{stroke}{transform}/>"#,
+            x + w / 2.0, y + h / 2.0, w / 2.0, h / 2.0
+        ),
+        ShapeType::RoundedRectangle => format!(
+            r#"<rect x="{x:.2}" y="{y:.2}" width="{w:.2}" height="{h:.2}" rx="{:.2}" fill="{fill}"{opacity}{stroke}{transform}/>"#,
+            w.min(h) * 0.1667
+        ),
+        _ => format!(
+            r#"<rect x="{x:.2}" y="{y:.2}" width="{w:.2}" height="{h:.2}" fill="{fill}"{opacity}{stroke}{transform}/>"#
+        ),
+    };
+    svg.push_str(&element);
+    svg.push('\n');
+
+    if let Some(ref text) = shape.text {
+        push_text(svg, text, x + w / 2.0, y + h / 2.0 + 6.0, &TextStyle::plain(18.0, "#000000"), "middle");
+    }
+}
+
+fn render_table(svg: &mut String, table: &Table, colors: &ThemeColorScheme) {
+    let mut y = table.y;
+    for row in &table.rows {
+        let row_height = row.height.unwrap_or(400000);
+        let mut x = table.x;
+        for (cell, col_width) in row.cells.iter().zip(&table.column_widths) {
+            let fill = cell.background_color.as_deref().map(|c| rgb(colors, c)).unwrap_or_else(|| "none".to_string());
+            svg.push_str(&format!(
+                r##"<rect x="{}" y="{}" width="{}" height="{}" fill="{fill}" stroke="#808080" stroke-width="0.75"/>"##,
+                fmt_units(x), fmt_units(y), fmt_units(*col_width), fmt_units(row_height)
+            ));
+            svg.push('\n');
+            if !cell.text.is_empty() && !cell.h_merge && !cell.v_merge {
+                let size = cell.font_size.unwrap_or(18) as f64;
+                let color = cell.text_color.as_deref().map(|c| rgb(colors, c)).unwrap_or_else(|| "#000000".to_string());
+                let baseline = units(y) + units(row_height) / 2.0 + size * 0.35;
+                let style = TextStyle { size_pt: size, bold: cell.bold, italic: cell.italic, color: &color };
+                push_text(svg, &cell.text, units(x) + units(*col_width) / 2.0, baseline, &style, "middle");
+            }
+            x += col_width;
+        }
+        y += row_height;
+    }
+}
+
+/// The image's bytes as a data URI, when they are available
+fn data_uri(image: &Image) -> Option<String> {
+    let bytes = image.get_bytes()?;
+    let mime = match image.format.to_lowercase().as_str() {
+        "jpg" | "jpeg" => "image/jpeg",
+        "gif" => "image/gif",
+        "svg" => "image/svg+xml",
+        _ => "image/png",
+    };
+    Some(format!("data:{mime};base64,{}", crate::export::html::base64_encode(&bytes)))
+}
+
+/// `<linearGradient>`/`<radialGradient>` with id `id` for a gradient fill
+fn gradient_def(gradient: &GradientFill, id: &str, colors: &ThemeColorScheme) -> String {
+    let stops: String = gradient
+        .stops
+        .iter()
+        .map(|stop| {
+            let opacity = stop
+                .transparency
+                .map(|t| format!(r#" stop-opacity="{:.2}""#, 1.0 - t as f64 / 100000.0))
+                .unwrap_or_default();
+            format!(
+                r#"<stop offset="{:.1}%" stop-color="{}"{opacity}/>"#,
+                stop.position as f64 / 1000.0,
+                rgb(colors, &stop.color)
+            )
+        })
+        .collect();
+    match gradient.gradient_type {
+        GradientType::Linear => format!(
+            r#"<linearGradient id="{id}" gradientTransform="rotate({} 0.5 0.5)">{stops}</linearGradient>"#,
+            gradient.direction.angle() / 60000
+        ),
+        _ => format!(r#"<radialGradient id="{id}">{stops}</radialGradient>"#),
+    }
+}
+
+fn render_background(svg: &mut String, slide: &SlideContent, colors: &ThemeColorScheme, width: &str, height: &str) {
+    let fill = match slide.background {
+        Some(SlideBackground::Color(ref color)) => rgb(colors, color),
+        Some(SlideBackground::Gradient(ref gradient)) if !gradient.stops.is_empty() => {
+            svg.push_str(&format!("<defs>{}</defs>\n", gradient_def(gradient, "slide-bg", colors)));
+            "url(#slide-bg)".to_string()
+        }
+        _ => rgb(colors, &colors.lt1),
+    };
+    svg.push_str(&format!(r#"<rect x="0" y="0" width="{width}" height="{height}" fill="{fill}"/>"#));
+    svg.push('\n');
+
+    if let Some(href) = slide.background.as_ref().and_then(|bg| bg.image()).and_then(data_uri) {
+        svg.push_str(&format!(
+            r#"<image x="0" y="0" width="{width}" height="{height}" preserveAspectRatio="none" href="{href}"/>"#
+        ));
+        svg.push('\n');
+    }
+}
+
+fn render_image(svg: &mut String, image: &Image) {
+    let (x, y, w, h) = (fmt_units(image.x), fmt_units(image.y), fmt_units(image.width), fmt_units(image.height));
+    match data_uri(image) {
+        Some(href) => {
+            svg.push_str(&format!(
+                r#"<image x="{x}" y="{y}" width="{w}" height="{h}" preserveAspectRatio="none" href="{href}"/>"#
+            ));
+        }
+        None => {
+            svg.push_str(&format!(
+                r##"<rect x="{x}" y="{y}" width="{w}" height="{h}" fill="#E0E0E0" stroke="#808080"/>"##
+            ));
+        }
+    }
+    svg.push('\n');
+}
+
+/// Render a single slide as a standalone SVG document.
+///
+/// `settings` supplies the slide size for the viewBox and the theme that scheme
+/// colors resolve against; `None` uses the 4:3 default and the Office theme.
+pub fn render_slide_svg(slide: &SlideContent, settings: Option<&PresentationSettings>) -> String {
+    let size = settings.and_then(|s| s.slide_size).unwrap_or_default();
+    let width = fmt_units(size.width);
+    let height = fmt_units(size.height);
+    let mut svg = String::with_capacity(4096);
+    svg.push_str(&format!(
+        r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 {width} {height}" width="{width}" height="{height}">"#
+    ));
+    svg.push('\n');
+
+    let colors = theme_colors(settings);
+    render_background(&mut svg, slide, &colors, &width, &height);
+    let slide = &*placed_slide(slide);
+    render_title(&mut svg, slide, &colors);
+    render_bullets(&mut svg, slide, &colors);
+
+    if let Some(ref table) = slide.table {
+        render_table(&mut svg, table, &colors);
+    }
+    for shape in &slide.shapes {
+        render_shape(&mut svg, shape, &colors);
+    }
+    for image in &slide.images {
+        render_image(&mut svg, image);
+    }
+    for connector in &slide.connectors {
+        svg.push_str(&format!(
+            r#"<line x1="{}" y1="{}" x2="{}" y2="{}" stroke="{}" stroke-width="{}"/>"#,
+            fmt_units(connector.start_x),
+            fmt_units(connector.start_y),
+            fmt_units(connector.end_x),
+            fmt_units(connector.end_y),
+            rgb(&colors, &connector.line.color),
+            fmt_units(connector.line.width),
+        ));
+        svg.push('\n');
+    }
+    for chart in &slide.charts {
+        let (x, y, w, h) = (units(chart.x), units(chart.y), units(chart.width), units(chart.height));
+        svg.push_str(&format!(
+            r##"<rect x="{x:.2}" y="{y:.2}" width="{w:.2}" height="{h:.2}" fill="#F5F5F5" stroke="#BFBFBF"/>"##
+        ));
+        svg.push('\n');
+        push_text(&mut svg, &chart.title, x + w / 2.0, y + h / 2.0, &TextStyle::plain(16.0, "#595959"), "middle");
+    }
+
+    svg.push_str("</svg>");
+    svg
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generator::shapes::ShapeFill;
+
+    #[test]
+    fn test_render_slide_svg_document() {
+        let slide = SlideContent::new("Hello & Welcome").add_bullet("First point");
+        let svg = render_slide_svg(&slide, None);
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.ends_with("</svg>"));
+        assert!(svg.contains(r#"viewBox="0 0 720.00 540.00""#));
+        assert!(svg.contains("Hello &amp; Welcome"));
+        assert!(svg.contains("First point"));
+    }
+
+    #[test]
+    fn test_background_fills() {
+        use crate::generator::gradients::GradientFill;
+        use crate::generator::presentation_theme::PresentationTheme;
+
+        let solid = SlideContent::new("Solid").background_color("1F4E79");
+        assert!(render_slide_svg(&solid, None).contains(r##"height="540.00" fill="#1F4E79"/>"##));
+
+        let gradient = SlideContent::new("Gradient").background_gradient(GradientFill::two_color("FF0000", "0000FF"));
+        let svg = render_slide_svg(&gradient, None);
+        assert!(svg.contains(r#"<linearGradient id="slide-bg" gradientTransform="rotate(90 0.5 0.5)">"#));
+        assert!(svg.contains(r##"<stop offset="0.0%" stop-color="#FF0000"/><stop offset="100.0%" stop-color="#0000FF"/>"##));
+        assert!(svg.contains(r#"fill="url(#slide-bg)"/>"#));
+
+        // Without a background, the theme's light color shows through
+        let theme = PresentationTheme::office().colors(ThemeColorScheme::office().light1("F2F2F2"));
+        let svg = render_slide_svg(&SlideContent::new("Plain"), Some(&PresentationSettings::new().theme(theme)));
+        assert!(svg.contains(r##"height="540.00" fill="#F2F2F2"/>"##));
+    }
+
+    #[test]
+    fn test_render_shapes_in_user_units() {
+        let slide = SlideContent::new("Shapes").add_shape(
+            Shape::new(ShapeType::Ellipse, 1270000, 1270000, 2540000, 1270000)
+                .with_fill(ShapeFill::new("FF0000")),
+        );
+        let svg = render_slide_svg(&slide, None);
+        assert!(svg.contains(r##"<ellipse cx="200.00" cy="150.00" rx="100.00" ry="50.00" fill="#FF0000""##));
+    }
+
+    #[test]
+    fn test_scheme_fill_resolves_against_theme() {
+        use crate::elements::SchemeColor;
+        use crate::generator::presentation_theme::PresentationTheme;
+
+        let slide = SlideContent::new("Scheme").add_shape(
+            Shape::new(ShapeType::Rectangle, 0, 0, 1270000, 1270000)
+                .with_fill(ShapeFill::new(SchemeColor::Accent1)),
+        );
+        assert!(render_slide_svg(&slide, None).contains(r##"fill="#4F81BD""##));
+
+        let theme = PresentationTheme::office().colors(ThemeColorScheme::office().accent1("AABBCC"));
+        let svg = render_slide_svg(&slide, Some(&PresentationSettings::new().theme(theme)));
+        assert!(svg.contains(r##"fill="#AABBCC""##));
+        assert!(!svg.contains("ACCENT1"));
+    }
+
+    #[test]
+    fn test_render_table_rules() {
+        let table = Table::from_data(vec![vec!["A", "B"]], vec![1270000, 1270000], 0, 0);
+        let slide = SlideContent::new("Table").table(table);
+        let svg = render_slide_svg(&slide, None);
+        assert_eq!(svg.matches(r##"stroke="#808080""##).count(), 2);
+        assert!(svg.contains(">A</text>"));
+    }
+
+    #[test]
+    fn test_view_box_follows_slide_size() {
+        use crate::core::SlideSizePreset;
+
+        let settings = PresentationSettings::new().slide_size(SlideSizePreset::Screen16x9);
+        let svg = render_slide_svg(&SlideContent::new("Wide"), Some(&settings));
+        assert!(svg.contains(r#"viewBox="0 0 720.00 405.00""#));
+    }
+
+    #[test]
+    fn test_roman_bullet_prefixes() {
+        assert_eq!(bullet_prefix(BulletStyle::RomanUpper, 3), "IV.");
+        assert_eq!(bullet_prefix(BulletStyle::RomanLower, 8), "ix.");
+        assert_eq!(bullet_prefix(BulletStyle::Number, 8), "9.");
+    }
+
+    #[test]
+    fn test_blank_layout_skips_title() {
+        let slide = SlideContent::new("Hidden").layout(SlideLayout::Blank);
+        assert!(!render_slide_svg(&slide, None).contains("Hidden"));
+    }
+
+    #[test]
+    fn test_column_break_splits_two_column_preview() {
+        let slide = SlideContent::new("Split")
+            .add_bullet("A")
+            .add_column_break()
+            .add_bullet("B")
+            .add_bullet("C")
+            .auto_layout();
+        let lines = bullet_lines(&slide);
+        assert!(lines[1].x > lines[0].x);
+        assert_eq!(lines[1].y, lines[0].y);
+        assert_eq!(lines[2].x, lines[1].x);
+    }
+
+    #[test]
+    fn test_comparison_preview_puts_headings_over_columns() {
+        let slide = SlideContent::new("Compare")
+            .left_heading("Pros")
+            .left_bullets(["Fast"])
+            .right_bullets(["Fragile"]);
+        let lines = bullet_lines(&slide);
+        assert!(lines[0].bold && lines[0].prefix.is_empty());
+        assert!(lines[1].y > lines[0].y);
+        assert_eq!(lines[2].y, lines[0].y);
+        assert!(lines[2].x > lines[1].x);
+    }
+}
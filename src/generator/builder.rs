@@ -1,82 +1,195 @@
 //! PPTX builder - orchestrates ZIP creation and file writing
 
+use std::borrow::Cow;
 use std::io::{Write, Seek, Cursor};
 use zip::write::FileOptions;
 use zip::ZipWriter;
-use crate::exc::Result;
-use crate::core::append_usize;
-use super::slide_content::SlideContent;
+use crate::exc::{messages, PptxError, Result};
+use crate::core::{append_usize, settings_colors, slide_colors, slide_hex_colors, SlideSize, SLIDE_HEIGHT_EMU, SLIDE_WIDTH_EMU};
+use crate::elements::{is_color_value, is_hex_color};
+use super::slide_content::{CommentAuthorList, PrintSettings, SlideComments, SlideContent};
 use super::memory_profile::estimate_output_capacity;
 use super::package_cache::{self, print_affects_theme_parts};
 use super::package_xml::{
     create_rels_xml_with_signature,
     create_presentation_rels_xml_full, create_presentation_rels_xml_full_with_fonts,
-    create_presentation_xml, create_presentation_xml_with_fonts,
+    create_presentation_xml_with_size, insert_embedded_font_list,
     create_content_types_xml_with_notes_and_charts,
     content_types_opening, append_digital_signature_content_type,
-    append_embedded_font_content_type, table_styles_rel_id,
+    append_embedded_font_content_type, table_styles_rel_id, slide_rel_id,
     create_pres_props_xml, create_view_props_xml, create_table_styles_xml,
-    create_handout_master_rels_xml,
+    create_handout_master_rels_xml, append_comment_content_types, append_comment_authors_relationship,
+    set_master_ids, slide_master_id, append_extra_master_relationships, append_extra_master_content_types,
+    COMMENTS_RELATIONSHIP_TYPE,
 };
 use super::slide_xml::{
     create_slide_xml, create_slide_xml_with_content, create_slide_rels_xml,
 };
 use super::theme_xml::{
-    create_slide_master_xml, create_master_rels_xml, create_theme_xml, create_layout_rels_xml,
+    create_custom_slide_master_xml, create_numbered_slide_master_xml, create_master_rels_xml, create_theme_xml,
+    create_layout_rels_xml,
 };
-use super::layout_parts::{create_slide_layout_xml, STANDARD_LAYOUT_COUNT};
+use super::layout_parts::{
+    create_numbered_layout_rels_xml, create_numbered_master_rels_xml, create_slide_layout_xml_with_size,
+    create_slide_layout_xml_for_master, master_theme_number, STANDARD_LAYOUT_COUNT,
+};
+use super::slide_master::SlideMaster;
+use super::hyperlinks::HyperlinkAction;
 use super::template::PptxTemplate;
-use super::props_xml::{create_core_props_xml, create_app_props_xml};
+use super::props_xml::{create_core_props_xml_with_dates, create_app_props_xml_with_format, zip_time};
 use super::notes_xml::*;
 use crate::generator::presentation_theme::office_theme_xml;
 use crate::generator::charts::{
-    chart_embedding_filename, create_chart_rels_xml, generate_chart_part_xml,
-    reference_workbook_bytes,
+    chart_embedding_filename, chart_part_filename, create_chart_rels_xml, generate_chart_part_xml,
+    chart_workbook_bytes, reference_workbook_bytes,
 };
+use crate::generator::slide_content::header_footer::{self, PageHeaderFooter};
 use crate::generator::slide_content::print_settings::PrintWhat;
 use crate::generator::slide_content::presentation_settings::PresentationSettings;
 use super::media_registry::MediaRegistry;
+use super::media::Audio;
+use super::images::Image;
+use super::shapes::Shape;
 
 fn zip_options() -> FileOptions {
     FileOptions::default()
 }
 
+/// Apply the configured compression, and stamp every entry with the configured
+/// modification (or creation) time so the archive bytes don't depend on when the
+/// deck was built
+fn package_file_options(options: &FileOptions, settings: Option<&PresentationSettings>) -> FileOptions {
+    let options = match settings.and_then(|s| s.compression) {
+        Some(compression) => compression.apply(*options),
+        None => *options,
+    };
+    settings
+        .and_then(|s| s.modified_at.as_deref().or(s.created_at.as_deref()))
+        .and_then(zip_time)
+        .map_or(options, |time| options.last_modified_time(time))
+}
+
+/// Options for `ppt/media/` parts: the media compression when set, otherwise
+/// the same as every other part
+fn media_file_options(options: &FileOptions, settings: Option<&PresentationSettings>) -> FileOptions {
+    match settings.and_then(|s| s.media_compression) {
+        Some(compression) => compression.apply(*options),
+        None => *options,
+    }
+}
+
 /// First relationship id after layout (rId1) and optional notes slide.
 fn slide_content_rel_start(has_notes: bool, image_count: usize) -> usize {
     2 + usize::from(has_notes) + image_count
 }
 
-fn build_media_registry(slides: &[SlideContent]) -> MediaRegistry {
-    let mut registry = MediaRegistry::default();
-    for slide in slides {
-        for image in &slide.images {
-            if let Some(bytes) = image.get_bytes() {
-                registry.image_number(&bytes, &image.extension());
-            }
-        }
+/// Whether identical media should share one part (on unless the settings opt out)
+fn dedup_media(settings: Option<&PresentationSettings>) -> bool {
+    !settings.is_some_and(|s| s.keep_duplicate_media)
+}
+
+fn build_media_registry(slides: &[SlideContent], settings: Option<&PresentationSettings>) -> MediaRegistry {
+    let mut registry = MediaRegistry::new(dedup_media(settings));
+    for (i, slide) in slides.iter().enumerate() {
+        register_slide_media(&mut registry, i + 1, slide);
     }
     registry
 }
 
-fn build_media_registry_lazy(slides: &dyn LazySlideSource) -> MediaRegistry {
-    let mut registry = MediaRegistry::default();
-    for i in 0..slides.slide_count() {
-        if let Some(slide) = slides.generate_slide(i) {
-            for image in &slide.images {
-                if let Some(bytes) = image.get_bytes() {
-                    registry.image_number(&bytes, &image.extension());
-                }
-            }
+/// Register a slide's images, followed by the SVG parts of SVG images, the background
+/// picture, each embedded video's clip and poster, and each audio clip and its icon.
+fn register_slide_media(registry: &mut MediaRegistry, slide_num: usize, slide: &SlideContent) {
+    for image in &slide.images {
+        if let Some(bytes) = image.get_bytes() {
+            registry.image_number(slide_num, &bytes, &image.extension());
         }
     }
-    registry
+    for svg in slide.images.iter().filter_map(|image| image.svg.as_ref()) {
+        registry.image_number(slide_num, svg, "svg");
+    }
+    if let Some(image) = slide.background.as_ref().and_then(|bg| bg.image())
+        && let Some(bytes) = image.get_bytes() {
+            registry.image_number(slide_num, &bytes, &image.extension());
+        }
+    for video in slide.videos.iter().filter(|video| video.has_data()) {
+        if let Some(bytes) = video.get_bytes() {
+            registry.media_number(slide_num, &bytes, video.format.extension());
+        }
+        let (poster, ext) = video.poster_bytes();
+        registry.image_number(slide_num, &poster, &ext);
+    }
+    for audio in slide.audios.iter().filter(|audio| audio.has_data()) {
+        if let Some(bytes) = audio.get_bytes() {
+            registry.media_number(slide_num, &bytes, audio.format.extension());
+        }
+        let (icon, ext) = audio.icon_bytes();
+        registry.image_number(slide_num, &icon, &ext);
+    }
+}
+
+/// Image relationships for SVG parts, numbered from `first_rid` (after images, charts, and ink).
+fn slide_svg_relationships(slide: &SlideContent, slide_num: usize, registry: &MediaRegistry, first_rid: usize) -> Vec<String> {
+    slide
+        .images
+        .iter()
+        .filter_map(|image| image.svg.as_ref())
+        .filter_map(|svg| registry.lookup_number(slide_num, svg, "svg"))
+        .enumerate()
+        .map(|(k, num)| {
+            super::images_xml::generate_image_relationship(first_rid + k, &format!("../media/image{num}.svg"))
+        })
+        .collect()
+}
+
+/// Relationships appended after the fixed slide parts: SVG images, the background picture,
+/// embedded videos and audio, the comment list, then hyperlinks.
+fn slide_extra_relationships(slide: &SlideContent, slide_num: usize, registry: &MediaRegistry, start_rid: usize) -> Vec<String> {
+    let svg_start = start_rid + slide.charts.len() + usize::from(slide.ink_annotations.is_some());
+    let mut rels = slide_svg_relationships(slide, slide_num, registry, svg_start);
+    if let Some(image) = slide.background.as_ref().and_then(|bg| bg.image())
+        && let Some(bytes) = image.get_bytes()
+        && let Some(num) = registry.lookup_number(slide_num, &bytes, &image.extension()) {
+            let target = format!("../media/image{}.{}", num, image.extension());
+            rels.push(super::images_xml::generate_image_relationship(svg_start + rels.len(), &target));
+        }
+    let videos = slide.videos.iter().filter(|video| video.has_data()).map(|video| {
+        (video.format.extension(), video.get_bytes(), video.poster_bytes(), super::media::VIDEO_RELATIONSHIP_TYPE)
+    });
+    let audios = slide.audios.iter().filter(|audio| audio.has_data()).map(|audio| {
+        (audio.format.extension(), audio.get_bytes(), audio.icon_bytes(), super::media::AUDIO_RELATIONSHIP_TYPE)
+    });
+    for (ext, bytes, (picture, picture_ext), link_type) in videos.chain(audios) {
+        let clip = bytes
+            .and_then(|bytes| registry.lookup_media_number(slide_num, &bytes, ext))
+            .unwrap_or(0);
+        let first_rid = svg_start + rels.len();
+        rels.extend(super::media::generate_media_relationships(
+            first_rid,
+            &format!("../media/media{clip}.{ext}"),
+            link_type,
+        ));
+        let picture_num = registry.lookup_number(slide_num, &picture, &picture_ext).unwrap_or(0);
+        rels.push(super::images_xml::generate_image_relationship(
+            first_rid + 2,
+            &format!("../media/image{picture_num}.{picture_ext}"),
+        ));
+    }
+    if !slide.comments.is_empty() {
+        rels.push(format!(
+            r#"<Relationship Id="rId{}" Type="{COMMENTS_RELATIONSHIP_TYPE}" Target="../comments/comment{slide_num}.xml"/>"#,
+            svg_start + rels.len(),
+        ));
+    }
+    rels.extend(slide_hyperlink_relationships(slide));
+    rels.extend(slide.raw_relationships.iter().enumerate().map(|(i, rel)| rel.to_xml(i)));
+    rels
 }
 
-fn slide_image_rel_targets(slide: &SlideContent, registry: &MediaRegistry) -> Vec<(usize, String)> {
+fn slide_image_rel_targets(slide: &SlideContent, slide_num: usize, registry: &MediaRegistry) -> Vec<(usize, String)> {
     let mut images = Vec::with_capacity(slide.images.len());
     for image in &slide.images {
         if let Some(bytes) = image.get_bytes()
-            && let Some(num) = registry.lookup_number(&bytes, &image.extension()) {
+            && let Some(num) = registry.lookup_number(slide_num, &bytes, &image.extension()) {
                 images.push((num, image.extension()));
             }
     }
@@ -88,18 +201,120 @@ fn slide_image_rel_targets(slide: &SlideContent, registry: &MediaRegistry) -> Ve
 /// references these ids via `<a:hlinkClick r:id="..."/>`, so the matching
 /// relationship must be present in the slide's `.rels` part.
 fn slide_hyperlink_relationships(slide: &SlideContent) -> Vec<String> {
-    slide
-        .shapes
-        .iter()
-        .filter_map(|s| s.hyperlink.as_ref())
+    super::slide_xml::resolved_shape_hyperlinks(slide)
+        .into_iter()
+        .flatten()
+        .filter(|h| h.action.has_relationship())
         .filter_map(|h| {
             h.r_id
                 .as_ref()
-                .map(|rid| crate::generator::generate_hyperlink_relationship_xml(h, rid))
+                .map(|rid| crate::generator::generate_hyperlink_relationship_xml(&h, rid))
         })
         .collect()
 }
 
+/// Fail on shape links to a slide past the end of the deck, which would leave a dangling relationship
+fn check_slide_links(slide: &SlideContent, slide_count: usize) -> Result<()> {
+    for link in slide.shapes.iter().filter_map(|s| s.hyperlink.as_ref()) {
+        if let Some(target) = link.action.target_slide_number()
+            && !(1..=slide_count).contains(&target)
+        {
+            // Quote the target the way the caller wrote it
+            let target = match link.action {
+                HyperlinkAction::JumpToSlide(index) => format!("slide index {index} (0-based)"),
+                _ => format!("slide {target}"),
+            };
+            return Err(PptxError::InvalidValue(messages::invalid_value(
+                "hyperlink",
+                &format!("links to {target}, but the deck has {slide_count} slides"),
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Fail on a slide that picks a slide master the deck doesn't have
+fn check_slide_master(slide: &SlideContent, master_count: usize) -> Result<()> {
+    match slide.master {
+        Some(master) if !(1..=master_count).contains(&master) => Err(PptxError::InvalidValue(messages::invalid_value(
+            "master",
+            &format!("slide uses master {master}, but the deck has {master_count} masters"),
+        ))),
+        _ => Ok(()),
+    }
+}
+
+/// Fail on a color string a setter kept because it didn't parse, which would be written as an invalid `srgbClr`
+fn check_colors<'a>(colors: impl IntoIterator<Item = (String, &'a str)>) -> Result<()> {
+    match colors.into_iter().find(|(_, color)| !is_color_value(color)) {
+        Some((what, color)) => Err(PptxError::InvalidValue(messages::invalid_value(
+            &what,
+            &format!("\"{color}\" is not a hex color, color name or theme color"),
+        ))),
+        None => Ok(()),
+    }
+}
+
+/// Fail on the slide's colors, including a theme color in a slot that only takes hex
+fn check_slide_colors(slide: &SlideContent) -> Result<()> {
+    check_colors(slide_colors(slide))?;
+    match slide_hex_colors(slide).into_iter().find(|(_, color)| !is_hex_color(color)) {
+        Some((what, color)) => Err(PptxError::InvalidValue(messages::invalid_value(
+            &what,
+            &format!("\"{color}\" is not a hex color or color name"),
+        ))),
+        None => Ok(()),
+    }
+}
+
+/// Review comments from every slide, with authors and per-author indexes assigned in slide order
+#[derive(Default)]
+struct DeckComments {
+    authors: CommentAuthorList,
+    /// Slide number and comments of each slide that has any
+    slides: Vec<(usize, SlideComments)>,
+}
+
+impl DeckComments {
+    fn collect<'a>(slides: impl Iterator<Item = (usize, &'a SlideContent)>) -> Self {
+        let mut deck = DeckComments::default();
+        for (slide_num, slide) in slides {
+            deck.add(slide_num, slide);
+        }
+        deck
+    }
+
+    /// Add the next slide's comments
+    fn add(&mut self, slide_num: usize, slide: &SlideContent) {
+        if !slide.comments.is_empty() {
+            let comments = SlideComments::from_named(&slide.comments, &mut self.authors);
+            self.slides.push((slide_num, comments));
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.slides.is_empty()
+    }
+}
+
+/// Whether data-derived runs should be marked `noProof="1"`.
+fn no_proof_data_runs(settings: Option<&PresentationSettings>) -> bool {
+    settings.map(|s| s.no_proof_data_runs).unwrap_or(false)
+}
+
+/// Apply presentation-level post-processing to generated slide XML.
+fn finish_slide_xml(xml: String, slide_num: usize, settings: Option<&PresentationSettings>) -> String {
+    let xml = match settings.and_then(|s| s.header_footer.as_ref()) {
+        Some(hf) if hf.is_visible() => header_footer::apply_to_slide_xml(&xml, hf, slide_num, &slide_size(settings)),
+        _ => xml,
+    };
+    if no_proof_data_runs(settings) {
+        super::text::mark_data_runs_no_proof(&xml)
+    } else {
+        xml
+    }
+}
+
 fn set_notes_part_path(path: &mut String, notes_part_num: usize) {
     path.clear();
     path.push_str("ppt/notesSlides/notesSlide");
@@ -158,14 +373,30 @@ pub fn create_pptx_with_template(
 
 /// Resolve layout part index for a slide (respects template layout count).
 fn resolve_layout_number(slide: &SlideContent, template: Option<&PptxTemplate>) -> usize {
-    let requested = slide.layout.layout_number();
+    let requested = slide.effective_layout().layout_number();
     template
-        .map(|t| t.resolve_layout_number(requested))
-        .unwrap_or(requested)
+        .map(|t| t.layout_for(requested, slide.master_index() + 1))
+        .unwrap_or(requested + slide.master_index() * STANDARD_LAYOUT_COUNT)
+}
+
+/// Number of slide masters the settings ask for
+fn master_count(settings: Option<&PresentationSettings>) -> usize {
+    settings.map_or(1, |s| s.master_count())
+}
+
+/// Number of slide masters the package carries: the template's, when there is one
+fn deck_master_count(settings: Option<&PresentationSettings>, template: Option<&PptxTemplate>) -> usize {
+    template.map_or_else(|| master_count(settings), PptxTemplate::master_count)
 }
 
 fn load_template(settings: Option<&PresentationSettings>) -> Result<Option<PptxTemplate>> {
     if let Some(path) = settings.and_then(|s| s.template_path.as_deref()) {
+        if master_count(settings) > 1 {
+            return Err(PptxError::InvalidValue(messages::invalid_value(
+                "extra_masters",
+                "a template supplies its own slide masters",
+            )));
+        }
         Ok(Some(PptxTemplate::load(path)?))
     } else {
         Ok(None)
@@ -401,31 +632,61 @@ fn collect_chart_info(slides: Option<&[SlideContent]>) -> ChartInfo {
     }
 }
 
-/// Collect chart metadata from lazy slide source
-fn collect_chart_info_lazy(slides: &dyn LazySlideSource) -> ChartInfo {
-    let mut total_charts = 0;
-    let mut slide_start_indices = Vec::with_capacity(slides.slide_count());
+/// Whether a handout master is packaged: printing handouts, or a handout header/footer.
+fn uses_handouts(settings: Option<&PresentationSettings>) -> bool {
+    let printing_handouts = settings
+        .and_then(|s| s.print.as_ref())
+        .map(|p| p.print_what == PrintWhat::Handouts)
+        .unwrap_or(false);
+    printing_handouts
+        || settings
+            .and_then(|s| s.handout_header_footer.as_ref())
+            .is_some_and(PageHeaderFooter::is_visible)
+}
 
-    for i in 0..slides.slide_count() {
-        slide_start_indices.push(total_charts + 1);
-        total_charts += slides
-            .slide_features(i)
-            .map(|(_, chart_count)| chart_count)
-            .unwrap_or(0);
+/// Handout header/footer: the print settings' text, overridden by any handout-specific text.
+fn handout_header_footer(settings: Option<&PresentationSettings>) -> PageHeaderFooter {
+    let printed = settings
+        .and_then(|s| s.print.as_ref())
+        .map(|p| p.page_header_footer())
+        .unwrap_or_default();
+    match settings.and_then(|s| s.handout_header_footer.clone()) {
+        Some(handout) => PageHeaderFooter {
+            header: handout.header.or(printed.header),
+            footer: handout.footer.or(printed.footer),
+            date: handout.date.or(printed.date),
+            page_numbers: handout.page_numbers || printed.page_numbers,
+        },
+        None => printed,
     }
+}
 
-    ChartInfo {
-        total_charts,
-        slide_start_indices,
+/// Notes page header/footer, when one is configured.
+fn notes_header_footer(settings: Option<&PresentationSettings>) -> Option<&PageHeaderFooter> {
+    settings
+        .and_then(|s| s.notes_header_footer.as_ref())
+        .filter(|hf| hf.is_visible())
+}
+
+/// Notes slide XML, with the configured notes page header/footer placeholders.
+fn notes_slide_xml(slide_num: usize, notes: &str, settings: Option<&PresentationSettings>) -> String {
+    let xml = create_notes_xml(slide_num, notes);
+    match notes_header_footer(settings) {
+        Some(header_footer) => header_footer::apply_to_notes_xml(&xml, header_footer),
+        None => xml,
     }
 }
 
-/// Whether print settings request handout master packaging.
-fn uses_handouts(settings: Option<&PresentationSettings>) -> bool {
-    settings
-        .and_then(|s| s.print.as_ref())
-        .map(|p| p.print_what == PrintWhat::Handouts)
-        .unwrap_or(false)
+/// Notes master content types for a deck whose notes master comes only from a notes
+/// header/footer (no slide has notes, so the notes slide overrides didn't add them).
+fn append_notes_master_content_types(content_types: &mut String) {
+    let Some(end) = content_types.rfind("</Types>") else {
+        return;
+    };
+    content_types.insert_str(
+        end,
+        "<Override PartName=\"/ppt/notesMasters/notesMaster1.xml\" ContentType=\"application/vnd.openxmlformats-officedocument.presentationml.notesMaster+xml\"/>\n<Override PartName=\"/ppt/theme/theme2.xml\" ContentType=\"application/vnd.openxmlformats-officedocument.theme+xml\"/>\n",
+    );
 }
 
 /// Whether settings configure a digital signature package.
@@ -448,6 +709,65 @@ fn embedded_fonts(settings: Option<&PresentationSettings>) -> Option<&super::sli
     settings.and_then(|s| s.embedded_fonts.as_ref())
 }
 
+/// Slide dimensions from settings, defaulting to 4:3.
+fn slide_size(settings: Option<&PresentationSettings>) -> SlideSize {
+    settings.and_then(|s| s.slide_size).unwrap_or_default()
+}
+
+/// Slide whose Dimension-placed shapes, images and charts are resolved against the deck's slide size
+fn with_fitted_elements<'a>(slide: &'a SlideContent, settings: Option<&PresentationSettings>) -> Cow<'a, SlideContent> {
+    if !slide.shapes.iter().any(Shape::has_dimension_placement)
+        && !slide.images.iter().any(Image::has_dimension_placement)
+        && !slide.charts.iter().any(|chart| chart.has_dimension_placement())
+    {
+        return Cow::Borrowed(slide);
+    }
+    let size = slide_size(settings);
+    let mut slide = slide.clone();
+    for shape in &mut slide.shapes {
+        shape.fit_to(&size);
+    }
+    for image in &mut slide.images {
+        image.fit_to(&size);
+    }
+    for chart in &mut slide.charts {
+        chart.fit_to(&size);
+    }
+    Cow::Owned(slide)
+}
+
+/// Generate `ppt/presentation.xml` with slide size, sections, and embedded fonts from settings.
+fn presentation_xml(
+    title: &str,
+    slide_count: usize,
+    has_notes: bool,
+    has_handout: bool,
+    settings: Option<&PresentationSettings>,
+    template: Option<&PptxTemplate>,
+) -> String {
+    let mut xml = create_presentation_xml_with_size(title, slide_count, has_notes, has_handout, &slide_size(settings));
+    if let Some(sections) = settings.and_then(|s| s.sections.as_ref())
+        && let Some(pos) = xml.find("<p:ext ") {
+            xml.insert_str(pos, &sections.to_ext_xml(slide_count));
+        }
+    if let Some(fonts) = embedded_fonts(settings) {
+        xml = insert_embedded_font_list(xml, fonts);
+    }
+    // The schema places <p:custShowLst> after <p:embeddedFontLst>, ahead of <p:defaultTextStyle>.
+    if let Some(list) = settings
+        .and_then(|s| s.slide_show.as_ref())
+        .and_then(|show| show.to_custom_show_list_xml(slide_count, |n| slide_rel_id(n, has_notes, has_handout)))
+        && let Some(pos) = xml.find("<p:defaultTextStyle") {
+            xml.insert_str(pos, &list);
+        }
+    let master_ids: Vec<u32> = match template {
+        Some(template) => template.master_ids().to_vec(),
+        None => (1..=master_count(settings)).map(slide_master_id).collect(),
+    };
+    set_master_ids(&mut xml, &master_ids);
+    xml
+}
+
 /// Prepare settings by assigning relationship IDs to embedded fonts.
 /// Must be called after `has_notes` and `has_handout` are known.
 fn prepare_settings(settings: &mut Option<PresentationSettings>, slide_count: usize, has_notes: bool, has_handout: bool) {
@@ -467,13 +787,7 @@ fn collect_slide_titles(custom_slides: Option<&[SlideContent]>, slide_count: usi
         Some(slides) => slides
             .iter()
             .enumerate()
-            .map(|(i, s)| {
-                if s.title.trim().is_empty() {
-                    format!("Slide {}", i + 1)
-                } else {
-                    s.title.clone()
-                }
-            })
+            .map(|(i, s)| slide_title(s, i + 1))
             .collect(),
         None => (0..slide_count)
             .map(|i| format!("Slide {}", i + 1))
@@ -481,39 +795,35 @@ fn collect_slide_titles(custom_slides: Option<&[SlideContent]>, slide_count: usi
     }
 }
 
-/// Collect slide titles for `docProps/app.xml` (lazy version).
-fn collect_slide_titles_lazy(slides: &dyn LazySlideSource, slide_count: usize) -> Vec<String> {
-    let mut titles = Vec::with_capacity(slide_count);
-    for i in 0..slide_count {
-        let title = slides
-            .generate_slide(i)
-            .map(|s| {
-                if s.title.trim().is_empty() {
-                    format!("Slide {}", i + 1)
-                } else {
-                    s.title
-                }
-            })
-            .unwrap_or_else(|| format!("Slide {}", i + 1));
-        titles.push(title);
+/// Title of a slide in `docProps/app.xml`, "Slide N" when it has none
+fn slide_title(slide: &SlideContent, slide_num: usize) -> String {
+    if slide.title.trim().is_empty() {
+        format!("Slide {slide_num}")
+    } else {
+        slide.title.clone()
     }
-    titles
+}
+
+/// Deck-wide facts shared by the parts that list the deck's slides and masters
+struct PackageLayout<'a> {
+    slide_count: usize,
+    has_notes: bool,
+    has_handout: bool,
+    settings: Option<&'a PresentationSettings>,
+    template: Option<&'a PptxTemplate>,
 }
 
 /// Write content types XML
 fn write_content_types<W: Write + Seek>(
     zip: &mut ZipWriter<W>,
     options: &FileOptions,
-    slide_count: usize,
+    layout: &PackageLayout,
     custom_slides: Option<&[SlideContent]>,
     chart_info: &ChartInfo,
-    has_handout: bool,
-    settings: Option<&PresentationSettings>,
+    media_registry: &MediaRegistry,
 ) -> Result<()> {
-    let media_exts = custom_slides
-        .map(build_media_registry)
-        .map(|registry| registry.extensions())
-        .unwrap_or_default();
+    let PackageLayout { slide_count, has_handout, settings, template, .. } = *layout;
+    let media_exts = media_registry.extensions();
     let mut content_types = create_content_types_xml_with_notes_and_charts(
         slide_count,
         custom_slides,
@@ -522,6 +832,10 @@ fn write_content_types<W: Write + Seek>(
         &media_exts,
     );
 
+    let slides_have_notes = custom_slides.is_some_and(|slides| slides.iter().any(|s| s.notes.is_some()));
+    if !slides_have_notes && notes_header_footer(settings).is_some() {
+        append_notes_master_content_types(&mut content_types);
+    }
     if has_digital_signature(settings) {
         append_digital_signature_content_type(&mut content_types);
     }
@@ -534,6 +848,22 @@ fn write_content_types<W: Write + Seek>(
         .unwrap_or(0);
     super::package_xml::append_ink_content_types(&mut content_types, ink_count);
 
+    let comment_slides: Vec<usize> = custom_slides
+        .map(|slides| {
+            slides
+                .iter()
+                .enumerate()
+                .filter(|(_, s)| !s.comments.is_empty())
+                .map(|(i, _)| i + 1)
+                .collect()
+        })
+        .unwrap_or_default();
+    append_comment_content_types(&mut content_types, &comment_slides);
+    append_extra_master_content_types(&mut content_types, master_count(settings));
+    if let Some(template) = template {
+        template.apply_content_types(&mut content_types);
+    }
+
     zip.start_file("[Content_Types].xml", *options)?;
     zip.write_all(content_types.as_bytes())?;
     Ok(())
@@ -543,16 +873,22 @@ fn write_content_types<W: Write + Seek>(
 fn write_presentation_relationships<W: Write + Seek>(
     zip: &mut ZipWriter<W>,
     options: &FileOptions,
-    slide_count: usize,
-    has_notes: bool,
-    has_handout: bool,
-    settings: Option<&PresentationSettings>,
+    layout: &PackageLayout,
+    has_comments: bool,
 ) -> Result<()> {
-    let pres_rels = if let Some(fonts) = embedded_fonts(settings) {
+    let PackageLayout { slide_count, has_notes, has_handout, settings, template } = *layout;
+    let mut pres_rels = if let Some(fonts) = embedded_fonts(settings) {
         create_presentation_rels_xml_full_with_fonts(slide_count, has_notes, has_handout, fonts)
     } else {
         create_presentation_rels_xml_full(slide_count, has_notes, has_handout)
     };
+    if has_comments {
+        // Comment authors follow tableStyles and any embedded fonts
+        let font_count = embedded_fonts(settings).map_or(0, |fonts| fonts.fonts().len());
+        let rid = table_styles_rel_id(slide_count, has_notes, has_handout) + 1 + font_count;
+        append_comment_authors_relationship(&mut pres_rels, rid);
+    }
+    append_extra_master_relationships(&mut pres_rels, deck_master_count(settings, template));
 
     zip.start_file("ppt/_rels/presentation.xml.rels", *options)?;
     zip.write_all(pres_rels.as_bytes())?;
@@ -564,6 +900,7 @@ fn write_standard_package_parts<W: Write + Seek>(
     zip: &mut ZipWriter<W>,
     options: &FileOptions,
     settings: Option<&PresentationSettings>,
+    template: Option<&PptxTemplate>,
 ) -> Result<()> {
     let pres_props = create_pres_props_xml(settings);
     zip.start_file("ppt/presProps.xml", *options)?;
@@ -573,9 +910,11 @@ fn write_standard_package_parts<W: Write + Seek>(
     zip.start_file("ppt/viewProps.xml", *options)?;
     zip.write_all(view_props.as_bytes())?;
 
-    let table_styles = create_table_styles_xml();
     zip.start_file("ppt/tableStyles.xml", *options)?;
-    zip.write_all(table_styles.as_bytes())?;
+    match template.and_then(PptxTemplate::table_styles) {
+        Some(table_styles) => zip.write_all(table_styles)?,
+        None => zip.write_all(create_table_styles_xml().as_bytes())?,
+    }
     Ok(())
 }
 
@@ -585,12 +924,7 @@ fn write_handout_master<W: Write + Seek>(
     options: &FileOptions,
     settings: Option<&PresentationSettings>,
 ) -> Result<()> {
-    use crate::generator::slide_content::print_settings::PrintSettings;
-
-    let handout_xml = settings
-        .and_then(|s| s.print.as_ref())
-        .map(|p| p.to_handout_master_xml())
-        .unwrap_or_else(|| PrintSettings::default().to_handout_master_xml());
+    let handout_xml = handout_header_footer(settings).handout_master_xml();
 
     zip.start_file("ppt/handoutMasters/handoutMaster1.xml", *options)?;
     zip.write_all(handout_xml.as_bytes())?;
@@ -608,8 +942,11 @@ fn write_handout_master<W: Write + Seek>(
 fn write_notes_master<W: Write + Seek>(
     zip: &mut ZipWriter<W>,
     options: &FileOptions,
+    settings: Option<&PresentationSettings>,
 ) -> Result<()> {
-    let notes_master = create_notes_master_xml();
+    let default_header_footer = PageHeaderFooter::default();
+    let header_footer = notes_header_footer(settings).unwrap_or(&default_header_footer);
+    let notes_master = create_notes_master_xml_with_header_footer(header_footer);
     zip.start_file("ppt/notesMasters/notesMaster1.xml", *options)?;
     zip.write_all(notes_master.as_bytes())?;
 
@@ -632,11 +969,17 @@ fn write_theme_and_layouts<W: Write + Seek>(
     let print = settings.and_then(|s| s.print.as_ref());
 
     if let Some(tmpl) = template {
-        for (path, data) in tmpl.parts() {
+        let theme = settings.and_then(|s| s.theme.as_ref());
+        let mut paths: Vec<&String> = tmpl.parts().keys().collect();
+        paths.sort();
+        for path in paths {
+            if theme.is_some() && path == "ppt/theme/theme1.xml" {
+                continue;
+            }
             zip.start_file(path, *options)?;
-            zip.write_all(data)?;
+            zip.write_all(&tmpl.parts()[path])?;
         }
-        if let Some(theme) = settings.and_then(|s| s.theme.as_ref()) {
+        if let Some(theme) = theme {
             let theme_xml = create_theme_xml(Some(theme));
             zip.start_file("ppt/theme/theme1.xml", *options)?;
             zip.write_all(theme_xml.as_bytes())?;
@@ -644,14 +987,20 @@ fn write_theme_and_layouts<W: Write + Seek>(
         return Ok(());
     }
 
-    let use_cached_layouts = !print_affects_theme_parts(print);
+    let master = settings.and_then(|s| s.slide_master.as_ref());
+    let size = slide_size(settings);
+    let stock_size = (size.width, size.height) == (SLIDE_WIDTH_EMU, SLIDE_HEIGHT_EMU);
+    let use_cached_layouts = !print_affects_theme_parts(print) && master.is_none() && stock_size;
 
     for n in 1..=STANDARD_LAYOUT_COUNT {
         zip.start_file(format!("ppt/slideLayouts/slideLayout{n}.xml"), *options)?;
         if use_cached_layouts {
             zip.write_all(package_cache::default_layout_xml(n).as_bytes())?;
         } else {
-            let layout_xml = create_slide_layout_xml(n, print);
+            let layout_xml = match master {
+                Some(master) => create_slide_layout_xml_for_master(n, master, &size),
+                None => create_slide_layout_xml_with_size(n, &size),
+            };
             zip.write_all(layout_xml.as_bytes())?;
         }
 
@@ -663,7 +1012,7 @@ fn write_theme_and_layouts<W: Write + Seek>(
     if use_cached_layouts {
         zip.write_all(package_cache::default_slide_master_xml().as_bytes())?;
     } else {
-        let slide_master = create_slide_master_xml(print);
+        let slide_master = create_custom_slide_master_xml(print, master, &size);
         zip.write_all(slide_master.as_bytes())?;
     }
 
@@ -675,7 +1024,8 @@ fn write_theme_and_layouts<W: Write + Seek>(
         zip.write_all(master_rels.as_bytes())?;
     }
 
-    if let Some(theme) = settings.and_then(|s| s.theme.as_ref()) {
+    let deck_theme = master.and_then(|m| m.theme.as_ref()).or(settings.and_then(|s| s.theme.as_ref()));
+    if let Some(theme) = deck_theme {
         let theme_xml = create_theme_xml(Some(theme));
         zip.start_file("ppt/theme/theme1.xml", *options)?;
         zip.write_all(theme_xml.as_bytes())?;
@@ -684,6 +1034,39 @@ fn write_theme_and_layouts<W: Write + Seek>(
         zip.write_all(office_theme_xml().as_bytes())?;
     }
 
+    for (master, number) in settings.map_or(&[][..], |s| &s.extra_masters).iter().zip(2..) {
+        write_extra_master(zip, options, print, master, number, &size)?;
+    }
+
+    Ok(())
+}
+
+/// Write slide master `number` > 1 with its layouts and theme
+fn write_extra_master<W: Write + Seek>(
+    zip: &mut ZipWriter<W>,
+    options: &FileOptions,
+    print: Option<&PrintSettings>,
+    master: &SlideMaster,
+    number: usize,
+    size: &SlideSize,
+) -> Result<()> {
+    let layout_rels = create_numbered_layout_rels_xml(number);
+    for n in 1..=STANDARD_LAYOUT_COUNT {
+        let part_num = (number - 1) * STANDARD_LAYOUT_COUNT + n;
+        zip.start_file(format!("ppt/slideLayouts/slideLayout{part_num}.xml"), *options)?;
+        zip.write_all(create_slide_layout_xml_for_master(n, master, size).as_bytes())?;
+        zip.start_file(format!("ppt/slideLayouts/_rels/slideLayout{part_num}.xml.rels"), *options)?;
+        zip.write_all(layout_rels.as_bytes())?;
+    }
+
+    zip.start_file(format!("ppt/slideMasters/slideMaster{number}.xml"), *options)?;
+    zip.write_all(create_numbered_slide_master_xml(print, Some(master), number, size).as_bytes())?;
+    zip.start_file(format!("ppt/slideMasters/_rels/slideMaster{number}.xml.rels"), *options)?;
+    zip.write_all(create_numbered_master_rels_xml(number).as_bytes())?;
+
+    let theme_xml = create_theme_xml(master.theme.as_ref());
+    zip.start_file(format!("ppt/theme/theme{}.xml", master_theme_number(number)), *options)?;
+    zip.write_all(theme_xml.as_bytes())?;
     Ok(())
 }
 
@@ -695,20 +1078,64 @@ fn write_document_properties<W: Write + Seek>(
     slide_count: usize,
     notes_count: usize,
     slide_titles: &[String],
+    settings: Option<&PresentationSettings>,
 ) -> Result<()> {
     // Core properties
-    let core_props = create_core_props_xml(title);
+    let core_props = create_core_props_xml_with_dates(
+        title,
+        settings.and_then(|s| s.created_at.as_deref()),
+        settings.and_then(|s| s.modified_at.as_deref()),
+    );
     zip.start_file("docProps/core.xml", *options)?;
     zip.write_all(core_props.as_bytes())?;
 
     // App properties
-    let app_props = create_app_props_xml(slide_count, notes_count, slide_titles);
+    let format = slide_size(settings).preset.presentation_format();
+    let app_props = create_app_props_xml_with_format(slide_count, notes_count, slide_titles, format);
     zip.start_file("docProps/app.xml", *options)?;
     zip.write_all(app_props.as_bytes())?;
 
     Ok(())
 }
 
+/// Slides with the presentation's background audio added to the first one
+fn with_background_audio<'a>(
+    slides: Option<&'a [SlideContent]>,
+    settings: Option<&PresentationSettings>,
+) -> Option<Cow<'a, [SlideContent]>> {
+    let slides = slides?;
+    match (settings.and_then(|s| s.background_audio.as_ref()), slides.split_first()) {
+        (Some(audio), Some((first, rest))) => {
+            let mut owned = Vec::with_capacity(slides.len());
+            owned.push(first.clone().add_audio(audio.clone()));
+            owned.extend_from_slice(rest);
+            Some(Cow::Owned(owned))
+        }
+        _ => Some(Cow::Borrowed(slides)),
+    }
+}
+
+/// Lazy source that adds the presentation's background audio to the first slide
+struct BackgroundAudioSource<'a> {
+    slides: &'a dyn LazySlideSource,
+    audio: Audio,
+}
+
+impl LazySlideSource for BackgroundAudioSource<'_> {
+    fn slide_count(&self) -> usize {
+        self.slides.slide_count()
+    }
+
+    fn generate_slide(&self, index: usize) -> Option<SlideContent> {
+        let slide = self.slides.generate_slide(index)?;
+        Some(if index == 0 { slide.add_audio(self.audio.clone()) } else { slide })
+    }
+
+    fn slide_features(&self, index: usize) -> Option<(bool, usize)> {
+        self.slides.slide_features(index)
+    }
+}
+
 /// Write all package files to the ZIP archive (eager version with Vec<SlideContent>)
 fn write_package_files<W: Write + Seek>(
     zip: &mut ZipWriter<W>,
@@ -718,18 +1145,32 @@ fn write_package_files<W: Write + Seek>(
     custom_slides: Option<&[SlideContent]>,
     mut settings: Option<PresentationSettings>,
 ) -> Result<()> {
+    let options = &package_file_options(options, settings.as_ref());
+    let slides_with_audio = with_background_audio(custom_slides, settings.as_ref());
+    let custom_slides = slides_with_audio.as_deref();
+    let template = load_template(settings.as_ref())?;
+    check_colors(settings.iter().flat_map(settings_colors))?;
+    for slide in custom_slides.unwrap_or_default() {
+        check_slide_links(slide, slide_count)?;
+        check_slide_master(slide, deck_master_count(settings.as_ref(), template.as_ref()))?;
+        check_slide_colors(slide)?;
+    }
     let has_notes = custom_slides
         .map(|slides| slides.iter().any(|s| s.notes.is_some()))
-        .unwrap_or(false);
+        .unwrap_or(false)
+        || notes_header_footer(settings.as_ref()).is_some();
     let has_handout = uses_handouts(settings.as_ref());
     prepare_settings(&mut settings, slide_count, has_notes, has_handout);
     let has_signature = has_digital_signature(settings.as_ref());
-    let template = load_template(settings.as_ref())?;
 
     let chart_info = collect_chart_info(custom_slides);
+    let comments = DeckComments::collect(custom_slides.unwrap_or_default().iter().enumerate().map(|(i, s)| (i + 1, s)));
+
+    let media_registry = build_media_registry(custom_slides.unwrap_or_default(), settings.as_ref());
+    let layout = PackageLayout { slide_count, has_notes, has_handout, settings: settings.as_ref(), template: template.as_ref() };
 
     // 1. Content types
-    write_content_types(zip, options, slide_count, custom_slides, &chart_info, has_handout, settings.as_ref())?;
+    write_content_types(zip, options, &layout, custom_slides, &chart_info, &media_registry)?;
 
     // 2. Package relationships
     let rels = create_rels_xml_with_signature(has_signature);
@@ -737,19 +1178,15 @@ fn write_package_files<W: Write + Seek>(
     zip.write_all(rels.as_bytes())?;
 
     // 3. Presentation relationships
-    write_presentation_relationships(zip, options, slide_count, has_notes, has_handout, settings.as_ref())?;
+    write_presentation_relationships(zip, options, &layout, !comments.is_empty())?;
 
     // 4. Presentation document
-    let presentation = if let Some(fonts) = embedded_fonts(settings.as_ref()) {
-        create_presentation_xml_with_fonts(title, slide_count, has_notes, has_handout, fonts)
-    } else {
-        create_presentation_xml(title, slide_count, has_notes, has_handout)
-    };
+    let presentation = presentation_xml(title, slide_count, has_notes, has_handout, settings.as_ref(), template.as_ref());
     zip.start_file("ppt/presentation.xml", *options)?;
     zip.write_all(presentation.as_bytes())?;
 
     // 5. Standard package parts (presProps, viewProps, tableStyles)
-    write_standard_package_parts(zip, options, settings.as_ref())?;
+    write_standard_package_parts(zip, options, settings.as_ref(), template.as_ref())?;
 
     // 6. Handout master (when printing handouts)
     if has_handout {
@@ -757,7 +1194,7 @@ fn write_package_files<W: Write + Seek>(
     }
 
     // 7. Slides
-    write_slides(zip, options, slide_count, custom_slides)?;
+    write_slides(zip, options, slide_count, custom_slides, settings.as_ref())?;
 
     // 8. Slide relationships
     write_slide_relationships_extended(
@@ -765,6 +1202,7 @@ fn write_package_files<W: Write + Seek>(
         options,
         custom_slides,
         &chart_info.slide_start_indices,
+        &media_registry,
         slide_count,
         template.as_ref(),
     )?;
@@ -772,7 +1210,7 @@ fn write_package_files<W: Write + Seek>(
     // 9. Notes relationships and master
     if has_notes {
         write_notes_relationships(zip, options, custom_slides)?;
-        write_notes_master(zip, options)?;
+        write_notes_master(zip, options, settings.as_ref())?;
     }
 
     // 10. Theme and layouts
@@ -783,7 +1221,7 @@ fn write_package_files<W: Write + Seek>(
         .map(|slides| slides.iter().filter(|s| s.notes.is_some()).count())
         .unwrap_or(0);
     let slide_titles = collect_slide_titles(custom_slides, slide_count);
-    write_document_properties(zip, options, title, slide_count, notes_count, &slide_titles)?;
+    write_document_properties(zip, options, title, slide_count, notes_count, &slide_titles, settings.as_ref())?;
 
     // 12. Charts (with embedded workbooks)
     if chart_info.total_charts > 0 {
@@ -791,14 +1229,17 @@ fn write_package_files<W: Write + Seek>(
     }
 
     // 13. Images
-    write_images(zip, options, custom_slides)?;
+    write_media_files(zip, &media_file_options(options, settings.as_ref()), &media_registry)?;
+
+    // 14. Comment authors and slide comments
+    write_comment_parts(zip, options, &comments)?;
 
-    // 14. Embedded font data parts
+    // 15. Embedded font data parts
     if let Some(fonts) = embedded_fonts(settings.as_ref()) {
         write_embedded_font_parts(zip, options, fonts)?;
     }
 
-    // 15. Digital signature package parts
+    // 16. Digital signature package parts
     if has_signature {
         write_digital_signature_parts(zip, options, settings.as_ref())?;
     }
@@ -807,6 +1248,9 @@ fn write_package_files<W: Write + Seek>(
 }
 
 /// Write all package files to the ZIP archive (lazy version with LazySlideSource)
+///
+/// Each slide is generated once: its parts are written first, and the package parts
+/// that list the slides are written afterwards from a [`LazyDeckSummary`].
 fn write_package_files_lazy<W: Write + Seek>(
     zip: &mut ZipWriter<W>,
     options: &FileOptions,
@@ -814,80 +1258,69 @@ fn write_package_files_lazy<W: Write + Seek>(
     slides: &dyn LazySlideSource,
     mut settings: Option<PresentationSettings>,
 ) -> Result<()> {
+    let options = &package_file_options(options, settings.as_ref());
+    let background_audio = settings.as_ref().and_then(|s| s.background_audio.clone());
+    let slides_with_audio;
+    let slides: &dyn LazySlideSource = match background_audio {
+        Some(audio) => {
+            slides_with_audio = BackgroundAudioSource { slides, audio };
+            &slides_with_audio
+        }
+        None => slides,
+    };
     let slide_count = slides.slide_count();
-    let has_notes = (0..slide_count).any(|i| {
-        slides
-            .slide_features(i)
-            .map(|(notes, _)| notes)
-            .unwrap_or(false)
-    });
+    let template = load_template(settings.as_ref())?;
+    check_colors(settings.iter().flat_map(settings_colors))?;
+
+    // 1. Slides, their relationships, notes, ink and charts (one generation per slide)
+    let deck = write_slide_packages_lazy(zip, options, slides, template.as_ref(), settings.as_ref())?;
+
+    let has_notes = deck.notes_count > 0 || notes_header_footer(settings.as_ref()).is_some();
     let has_handout = uses_handouts(settings.as_ref());
     prepare_settings(&mut settings, slide_count, has_notes, has_handout);
     let has_signature = has_digital_signature(settings.as_ref());
-    let template = load_template(settings.as_ref())?;
-
-    let chart_info = collect_chart_info_lazy(slides);
+    let layout = PackageLayout { slide_count, has_notes, has_handout, settings: settings.as_ref(), template: template.as_ref() };
 
-    // 1. Content types (lazy version)
-    write_content_types_lazy(zip, options, slide_count, slides, &chart_info, has_handout, settings.as_ref())?;
+    // 2. Content types
+    write_content_types_lazy(zip, options, &layout, &deck)?;
 
-    // 2. Package relationships
+    // 3. Package relationships
     let rels = create_rels_xml_with_signature(has_signature);
     zip.start_file("_rels/.rels", *options)?;
     zip.write_all(rels.as_bytes())?;
 
-    // 3. Presentation relationships
-    write_presentation_relationships(zip, options, slide_count, has_notes, has_handout, settings.as_ref())?;
+    // 4. Presentation relationships
+    write_presentation_relationships(zip, options, &layout, !deck.comments.is_empty())?;
 
-    // 4. Presentation document
-    let presentation = if let Some(fonts) = embedded_fonts(settings.as_ref()) {
-        create_presentation_xml_with_fonts(title, slide_count, has_notes, has_handout, fonts)
-    } else {
-        create_presentation_xml(title, slide_count, has_notes, has_handout)
-    };
+    // 5. Presentation document
+    let presentation = presentation_xml(title, slide_count, has_notes, has_handout, settings.as_ref(), template.as_ref());
     zip.start_file("ppt/presentation.xml", *options)?;
     zip.write_all(presentation.as_bytes())?;
 
-    // 5. Standard package parts
-    write_standard_package_parts(zip, options, settings.as_ref())?;
+    // 6. Standard package parts
+    write_standard_package_parts(zip, options, settings.as_ref(), template.as_ref())?;
 
-    // 6. Handout master
+    // 7. Handout master
     if has_handout {
         write_handout_master(zip, options, settings.as_ref())?;
     }
 
-    // 7–8–12. Slides, relationships, and charts (single pass per slide)
-    write_slide_packages_lazy(
-        zip,
-        options,
-        slides,
-        &chart_info.slide_start_indices,
-        template.as_ref(),
-    )?;
-
-    // 9. Notes relationships and master (lazy version)
+    // 8. Notes master
     if has_notes {
-        write_notes_relationships_lazy(zip, options, slides)?;
-        write_notes_master(zip, options)?;
+        write_notes_master(zip, options, settings.as_ref())?;
     }
 
-    // 10. Theme and layouts
+    // 9. Theme and layouts
     write_theme_and_layouts(zip, options, settings.as_ref(), template.as_ref())?;
 
-    // 11. Document properties
-    let notes_count = (0..slide_count)
-        .filter(|i| {
-            slides
-                .slide_features(*i)
-                .map(|(notes, _)| notes)
-                .unwrap_or(false)
-        })
-        .count();
-    let slide_titles = collect_slide_titles_lazy(slides, slide_count);
-    write_document_properties(zip, options, title, slide_count, notes_count, &slide_titles)?;
+    // 10. Document properties
+    write_document_properties(zip, options, title, slide_count, deck.notes_count, &deck.titles, settings.as_ref())?;
+
+    // 11. Images
+    write_media_files(zip, &media_file_options(options, settings.as_ref()), &deck.media)?;
 
-    // 12. Images
-    write_images_lazy(zip, options, slides)?;
+    // 12. Comment authors and slide comments
+    write_comment_parts(zip, options, &deck.comments)?;
 
     // 13. Embedded font data parts
     if let Some(fonts) = embedded_fonts(settings.as_ref()) {
@@ -902,38 +1335,28 @@ fn write_package_files_lazy<W: Write + Seek>(
     Ok(())
 }
 
+/// What the package parts need from lazily generated slides, kept once each slide is written
+struct LazyDeckSummary {
+    /// `docProps/app.xml` title of each slide
+    titles: Vec<String>,
+    notes_count: usize,
+    ink_count: usize,
+    /// Whether each chart, in deck order, is a chartEx part
+    chartex: Vec<bool>,
+    comments: DeckComments,
+    media: MediaRegistry,
+}
+
 /// Write content types for lazy slides
 fn write_content_types_lazy<W: Write + Seek>(
     zip: &mut ZipWriter<W>,
     options: &FileOptions,
-    slide_count: usize,
-    slides: &dyn LazySlideSource,
-    chart_info: &ChartInfo,
-    has_handout: bool,
-    settings: Option<&PresentationSettings>,
+    layout: &PackageLayout,
+    deck: &LazyDeckSummary,
 ) -> Result<()> {
-    let notes_count = (0..slide_count)
-        .filter(|i| {
-            slides
-                .slide_features(*i)
-                .map(|(notes, _)| notes)
-                .unwrap_or(false)
-        })
-        .count();
-
-    let ink_count = (0..slide_count)
-        .filter(|i| {
-            slides
-                .generate_slide(*i)
-                .map(|s| s.ink_annotations.is_some())
-                .unwrap_or(false)
-        })
-        .count();
-
-    let media_registry = build_media_registry_lazy(slides);
-    let media_exts = media_registry.extensions();
-
-    let mut content_types = content_types_opening(&media_exts, chart_info.total_charts);
+    let PackageLayout { slide_count, has_handout, settings, template, .. } = *layout;
+    let media_exts = deck.media.extensions();
+    let mut content_types = content_types_opening(&media_exts, deck.chartex.len());
 
     for i in 1..=slide_count {
         content_types.push_str(&format!(
@@ -941,20 +1364,12 @@ fn write_content_types_lazy<W: Write + Seek>(
         ));
     }
 
-    if notes_count > 0 {
-        let mut notes_index = 0usize;
-        for i in 0..slide_count {
-            if slides
-                .slide_features(i)
-                .map(|(notes, _)| notes)
-                .unwrap_or(false)
-            {
-                notes_index += 1;
-                content_types.push_str(&format!(
-                    "\n<Override PartName=\"/ppt/notesSlides/notesSlide{notes_index}.xml\" ContentType=\"application/vnd.openxmlformats-officedocument.presentationml.notesSlide+xml\"/>"
-                ));
-            }
-        }
+    for notes_index in 1..=deck.notes_count {
+        content_types.push_str(&format!(
+            "\n<Override PartName=\"/ppt/notesSlides/notesSlide{notes_index}.xml\" ContentType=\"application/vnd.openxmlformats-officedocument.presentationml.notesSlide+xml\"/>"
+        ));
+    }
+    if deck.notes_count > 0 || notes_header_footer(settings).is_some() {
         content_types.push_str("\n<Override PartName=\"/ppt/notesMasters/notesMaster1.xml\" ContentType=\"application/vnd.openxmlformats-officedocument.presentationml.notesMaster+xml\"/>");
         content_types.push_str("\n<Override PartName=\"/ppt/theme/theme2.xml\" ContentType=\"application/vnd.openxmlformats-officedocument.theme+xml\"/>");
     }
@@ -964,15 +1379,7 @@ fn write_content_types_lazy<W: Write + Seek>(
         content_types.push_str("\n<Override PartName=\"/ppt/theme/theme3.xml\" ContentType=\"application/vnd.openxmlformats-officedocument.theme+xml\"/>");
     }
 
-    for i in 1..=chart_info.total_charts {
-        content_types.push_str(&format!(
-            "\n<Override PartName=\"/ppt/charts/chart{i}.xml\" ContentType=\"application/vnd.openxmlformats-officedocument.drawingml.chart+xml\"/>"
-        ));
-        content_types.push_str(&format!(
-            "\n<Override PartName=\"/ppt/embeddings/{}\" ContentType=\"application/vnd.openxmlformats-officedocument.spreadsheetml.sheet\"/>",
-            chart_embedding_filename(i)
-        ));
-    }
+    super::package_xml::append_chart_content_type_overrides(&mut content_types, deck.chartex.len(), &deck.chartex);
 
     content_types.push_str(
         r#"
@@ -993,15 +1400,45 @@ fn write_content_types_lazy<W: Write + Seek>(
         append_embedded_font_content_type(&mut content_types);
     }
 
-    super::package_xml::append_ink_content_types(&mut content_types, ink_count);
+    super::package_xml::append_ink_content_types(&mut content_types, deck.ink_count);
+    let comment_slides: Vec<usize> = deck.comments.slides.iter().map(|(slide_num, _)| *slide_num).collect();
+    append_comment_content_types(&mut content_types, &comment_slides);
 
     content_types.push_str("\n</Types>");
+    append_extra_master_content_types(&mut content_types, master_count(settings));
+    if let Some(template) = template {
+        template.apply_content_types(&mut content_types);
+    }
 
     zip.start_file("[Content_Types].xml", *options)?;
     zip.write_all(content_types.as_bytes())?;
     Ok(())
 }
 
+/// A chart part, its rels and its embedded workbook, ready to write
+struct ChartPackage {
+    part_name: String,
+    chart_xml: String,
+    embedding_name: String,
+    rels_xml: String,
+    workbook: Cow<'static, [u8]>,
+}
+
+fn render_chart_package(chart_idx: usize, chart: &crate::generator::charts::Chart) -> Result<ChartPackage> {
+    let embedding_name = chart_embedding_filename(chart_idx);
+    Ok(ChartPackage {
+        part_name: chart_part_filename(chart_idx, chart),
+        chart_xml: generate_chart_part_xml(chart),
+        rels_xml: create_chart_rels_xml(&embedding_name),
+        workbook: if chart.editable_data {
+            Cow::Owned(chart_workbook_bytes(chart)?)
+        } else {
+            Cow::Borrowed(reference_workbook_bytes())
+        },
+        embedding_name,
+    })
+}
+
 /// Write a chart part with rels and embedded Excel workbook.
 fn write_chart_package<W: Write + Seek>(
     zip: &mut ZipWriter<W>,
@@ -1009,41 +1446,58 @@ fn write_chart_package<W: Write + Seek>(
     chart_idx: usize,
     chart: &crate::generator::charts::Chart,
 ) -> Result<()> {
-    let chart_xml = generate_chart_part_xml(chart);
-    zip.start_file(format!("ppt/charts/chart{chart_idx}.xml"), *options)?;
-    zip.write_all(chart_xml.as_bytes())?;
+    write_rendered_chart_package(zip, options, render_chart_package(chart_idx, chart)?)
+}
 
-    let embedding_name = chart_embedding_filename(chart_idx);
-    let rels_xml = create_chart_rels_xml(&embedding_name);
-    zip.start_file(format!("ppt/charts/_rels/chart{chart_idx}.xml.rels"), *options)?;
+fn write_rendered_chart_package<W: Write + Seek>(
+    zip: &mut ZipWriter<W>,
+    options: &FileOptions,
+    package: ChartPackage,
+) -> Result<()> {
+    let ChartPackage { part_name, chart_xml, embedding_name, rels_xml, workbook } = package;
+    zip.start_file(format!("ppt/charts/{part_name}"), *options)?;
+    zip.write_all(chart_xml.as_bytes())?;
+    zip.start_file(format!("ppt/charts/_rels/{part_name}.rels"), *options)?;
     zip.write_all(rels_xml.as_bytes())?;
-
     zip.start_file(format!("ppt/embeddings/{embedding_name}"), *options)?;
-    zip.write_all(reference_workbook_bytes())?;
+    zip.write_all(&workbook)?;
     Ok(())
 }
 
-/// Write slide XML, relationships, and chart parts in one pass (lazy version).
+/// Write slide XML, relationships, notes, ink, and chart parts in one pass (lazy version),
+/// generating each slide once.
 fn write_slide_packages_lazy<W: Write + Seek>(
     zip: &mut ZipWriter<W>,
     options: &FileOptions,
     slides: &dyn LazySlideSource,
-    slide_chart_start_indices: &[usize],
     template: Option<&PptxTemplate>,
-) -> Result<()> {
-    let media_registry = build_media_registry_lazy(slides);
+    settings: Option<&PresentationSettings>,
+) -> Result<LazyDeckSummary> {
+    let slide_count = slides.slide_count();
+    let mut deck = LazyDeckSummary {
+        titles: Vec::with_capacity(slide_count),
+        notes_count: 0,
+        ink_count: 0,
+        chartex: Vec::new(),
+        comments: DeckComments::default(),
+        media: MediaRegistry::new(dedup_media(settings)),
+    };
     let mut slide_path = String::with_capacity(48);
     let mut rels_path = String::with_capacity(56);
-    let mut notes_part_num = 0usize;
-    let mut ink_part_num = 0usize;
 
-    for (i, &start_chart_idx) in slide_chart_start_indices.iter().enumerate() {
+    for i in 0..slide_count {
+        let slide_num = i + 1;
         let Some(slide) = slides.generate_slide(i) else {
+            deck.titles.push(format!("Slide {slide_num}"));
             continue;
         };
-        let slide_num = i + 1;
+        check_slide_links(&slide, slide_count)?;
+        check_slide_master(&slide, deck_master_count(settings, template))?;
+        check_slide_colors(&slide)?;
+        register_slide_media(&mut deck.media, slide_num, &slide);
+        let start_chart_idx = deck.chartex.len() + 1;
         let layout_number = resolve_layout_number(&slide, template);
-        let images = slide_image_rel_targets(&slide, &media_registry);
+        let images = slide_image_rel_targets(&slide, slide_num, &deck.media);
         let image_count = images.len();
 
         let start_rid = slide_content_rel_start(slide.notes.is_some(), image_count);
@@ -1053,59 +1507,58 @@ fn write_slide_packages_lazy<W: Write + Seek>(
         }
 
         let ink_rel_id = if let Some(ref ink) = slide.ink_annotations {
-            ink_part_num += 1;
+            deck.ink_count += 1;
             let ink_xml = ink.part_xml();
-            zip.start_file(format!("ppt/ink/ink{ink_part_num}.xml"), *options)?;
+            zip.start_file(format!("ppt/ink/ink{}.xml", deck.ink_count), *options)?;
             zip.write_all(ink_xml.as_bytes())?;
             Some(format!("rId{}", start_rid + slide.charts.len()))
         } else {
             None
         };
 
-        let slide_xml = create_slide_xml_with_content(
+        let slide_xml = finish_slide_xml(
+            create_slide_xml_with_content(
+                slide_num,
+                &with_fitted_elements(&slide, settings),
+                &chart_rids,
+                ink_rel_id.as_deref(),
+            ),
             slide_num,
-            &slide,
-            &chart_rids,
-            ink_rel_id.as_deref(),
+            settings,
         );
         set_slide_xml_path(&mut slide_path, slide_num);
         zip.start_file(&slide_path, *options)?;
         zip.write_all(slide_xml.as_bytes())?;
 
-        let notes_part = if slide.notes.is_some() {
-            notes_part_num += 1;
-            Some(notes_part_num)
-        } else {
-            None
-        };
-
         if let Some(ref notes) = slide.notes {
-            let notes_xml = create_notes_xml(slide_num, notes);
-            set_notes_part_path(&mut slide_path, notes_part_num);
+            deck.notes_count += 1;
+            let notes_xml = notes_slide_xml(slide_num, notes, settings);
+            set_notes_part_path(&mut slide_path, deck.notes_count);
             zip.start_file(&slide_path, *options)?;
             zip.write_all(notes_xml.as_bytes())?;
+
+            let notes_rels = create_notes_rels_xml(slide_num);
+            zip.start_file(format!("ppt/notesSlides/_rels/notesSlide{}.xml.rels", deck.notes_count), *options)?;
+            zip.write_all(notes_rels.as_bytes())?;
         }
 
         let mut chart_rels = Vec::with_capacity(slide.charts.len());
-        for j in 0..slide.charts.len() {
+        for (j, chart) in slide.charts.iter().enumerate() {
             let mut rid = String::with_capacity(8);
             rid.push_str("rId");
             append_usize(&mut rid, start_rid + j);
-            let mut target = String::with_capacity(24);
-            target.push_str("../charts/chart");
-            append_usize(&mut target, start_chart_idx + j);
-            target.push_str(".xml");
+            let target = format!("../charts/{}", chart_part_filename(start_chart_idx + j, chart));
             chart_rels.push((rid, target));
         }
 
-        let ink_rel_tuple = ink_rel_id.map(|_| (start_rid + slide.charts.len(), ink_part_num));
+        let ink_rel_tuple = ink_rel_id.map(|_| (start_rid + slide.charts.len(), deck.ink_count));
         let slide_rels = super::package_xml::create_slide_rels_xml_with_images(
             layout_number,
             slide.notes.is_some(),
-            notes_part.unwrap_or(1),
+            if slide.notes.is_some() { deck.notes_count } else { 1 },
             &chart_rels,
             &images,
-            &slide_hyperlink_relationships(&slide),
+            &slide_extra_relationships(&slide, slide_num, &deck.media, start_rid),
             ink_rel_tuple,
         );
         set_slide_rels_path(&mut rels_path, slide_num);
@@ -1113,35 +1566,59 @@ fn write_slide_packages_lazy<W: Write + Seek>(
         zip.write_all(slide_rels.as_bytes())?;
 
         for (j, chart) in slide.charts.iter().enumerate() {
-            let chart_idx = start_chart_idx + j;
-            write_chart_package(zip, options, chart_idx, chart)?;
+            write_chart_package(zip, options, start_chart_idx + j, chart)?;
+            deck.chartex.push(chart.chart_type.is_chartex());
         }
+
+        deck.comments.add(slide_num, &slide);
+        deck.titles.push(slide_title(&slide, slide_num));
     }
 
-    Ok(())
+    Ok(deck)
 }
 
-/// Write notes relationship files (lazy version)
-fn write_notes_relationships_lazy<W: Write + Seek>(
-    zip: &mut ZipWriter<W>,
-    options: &FileOptions,
-    slides: &dyn LazySlideSource,
-) -> Result<()> {
-    let mut notes_part_num = 0usize;
-    for i in 0..slides.slide_count() {
-        if slides
-            .slide_features(i)
-            .map(|(notes, _)| notes)
-            .unwrap_or(false)
-        {
-            notes_part_num += 1;
-            let slide_num = i + 1;
-            let notes_rels = create_notes_rels_xml(slide_num);
-            zip.start_file(format!("ppt/notesSlides/_rels/notesSlide{notes_part_num}.xml.rels"), *options)?;
-            zip.write_all(notes_rels.as_bytes())?;
-        }
+/// Slide XML for one slide of a deck; depends only on the slide and its number,
+/// so slides can be rendered in any order
+fn render_slide_xml(slide_num: usize, slide: &SlideContent, settings: Option<&PresentationSettings>) -> String {
+    let mut chart_rids = Vec::with_capacity(slide.charts.len());
+    let start_rid = slide_content_rel_start(slide.notes.is_some(), slide.images.len());
+    for j in 0..slide.charts.len() {
+        push_chart_rid(&mut chart_rids, start_rid + j);
     }
-    Ok(())
+    let ink_rel_id = slide
+        .ink_annotations
+        .as_ref()
+        .map(|_| format!("rId{}", start_rid + slide.charts.len()));
+    finish_slide_xml(
+        create_slide_xml_with_content(
+            slide_num,
+            &with_fitted_elements(slide, settings),
+            &chart_rids,
+            ink_rel_id.as_deref(),
+        ),
+        slide_num,
+        settings,
+    )
+}
+
+/// Slide XML for every slide, in deck order.
+///
+/// With the `parallel` feature all slides are rendered up front across threads;
+/// otherwise each slide is rendered as the writer reaches it.
+#[cfg(feature = "parallel")]
+fn rendered_slides<'a>(
+    slides: &'a [SlideContent],
+    settings: Option<&'a PresentationSettings>,
+) -> impl Iterator<Item = String> + 'a {
+    super::parallel::map_ordered(slides, |i, slide| render_slide_xml(i + 1, slide, settings)).into_iter()
+}
+
+#[cfg(not(feature = "parallel"))]
+fn rendered_slides<'a>(
+    slides: &'a [SlideContent],
+    settings: Option<&'a PresentationSettings>,
+) -> impl Iterator<Item = String> + 'a {
+    slides.iter().enumerate().map(move |(i, slide)| render_slide_xml(i + 1, slide, settings))
 }
 
 /// Write slide XML files (eager version)
@@ -1150,6 +1627,7 @@ fn write_slides<W: Write + Seek>(
     options: &FileOptions,
     slide_count: usize,
     custom_slides: Option<&[SlideContent]>,
+    settings: Option<&PresentationSettings>,
 ) -> Result<()> {
     let mut zip_path = String::with_capacity(48);
 
@@ -1157,38 +1635,23 @@ fn write_slides<W: Write + Seek>(
         Some(slides) => {
             let mut notes_part_num = 0usize;
             let mut ink_part_num = 0usize;
-            for (i, slide) in slides.iter().enumerate() {
+            for ((i, slide), slide_xml) in slides.iter().enumerate().zip(rendered_slides(slides, settings)) {
                 let slide_num = i + 1;
 
-                let mut chart_rids = Vec::with_capacity(slide.charts.len());
-                let start_rid = slide_content_rel_start(slide.notes.is_some(), slide.images.len());
-                for j in 0..slide.charts.len() {
-                    push_chart_rid(&mut chart_rids, start_rid + j);
-                }
-
-                let ink_rel_id = if let Some(ref ink) = slide.ink_annotations {
+                if let Some(ref ink) = slide.ink_annotations {
                     ink_part_num += 1;
                     let ink_xml = ink.part_xml();
                     zip.start_file(format!("ppt/ink/ink{ink_part_num}.xml"), *options)?;
                     zip.write_all(ink_xml.as_bytes())?;
-                    Some(format!("rId{}", start_rid + slide.charts.len()))
-                } else {
-                    None
-                };
+                }
 
-                let slide_xml = create_slide_xml_with_content(
-                    slide_num,
-                    slide,
-                    &chart_rids,
-                    ink_rel_id.as_deref(),
-                );
                 set_slide_xml_path(&mut zip_path, slide_num);
                 zip.start_file(&zip_path, *options)?;
                 zip.write_all(slide_xml.as_bytes())?;
 
                 if let Some(notes) = &slide.notes {
                     notes_part_num += 1;
-                    let notes_xml = create_notes_xml(slide_num, notes);
+                    let notes_xml = notes_slide_xml(slide_num, notes, settings);
                     set_notes_part_path(&mut zip_path, notes_part_num);
                     zip.start_file(&zip_path, *options)?;
                     zip.write_all(notes_xml.as_bytes())?;
@@ -1197,7 +1660,7 @@ fn write_slides<W: Write + Seek>(
         }
         None => {
             for i in 1..=slide_count {
-                let slide_xml = create_slide_xml(i, "Presentation");
+                let slide_xml = finish_slide_xml(create_slide_xml(i, "Presentation"), i, settings);
                 set_slide_xml_path(&mut zip_path, i);
                 zip.start_file(&zip_path, *options)?;
                 zip.write_all(slide_xml.as_bytes())?;
@@ -1213,12 +1676,10 @@ fn write_slide_relationships_extended<W: Write + Seek>(
     options: &FileOptions,
     custom_slides: Option<&[SlideContent]>,
     slide_chart_start_indices: &[usize],
+    media_registry: &MediaRegistry,
     slide_count: usize,
     template: Option<&PptxTemplate>,
 ) -> Result<()> {
-    let media_registry = custom_slides
-        .map(build_media_registry)
-        .unwrap_or_default();
     let mut notes_part_num = 0usize;
 
     match custom_slides {
@@ -1228,7 +1689,7 @@ fn write_slide_relationships_extended<W: Write + Seek>(
             for (i, slide) in slides.iter().enumerate() {
                 let slide_num = i + 1;
                 let layout_number = resolve_layout_number(slide, template);
-                let images = slide_image_rel_targets(slide, &media_registry);
+                let images = slide_image_rel_targets(slide, slide_num, media_registry);
                 let image_count = images.len();
                 let notes_part = if slide.notes.is_some() {
                     notes_part_num += 1;
@@ -1241,14 +1702,11 @@ fn write_slide_relationships_extended<W: Write + Seek>(
                 let start_chart_idx = slide_chart_start_indices[i];
                 let start_rid = slide_content_rel_start(slide.notes.is_some(), image_count);
 
-                for j in 0..slide.charts.len() {
+                for (j, chart) in slide.charts.iter().enumerate() {
                     let mut rid = String::with_capacity(8);
                     rid.push_str("rId");
                     append_usize(&mut rid, start_rid + j);
-                    let mut target = String::with_capacity(24);
-                    target.push_str("../charts/chart");
-                    append_usize(&mut target, start_chart_idx + j);
-                    target.push_str(".xml");
+                    let target = format!("../charts/{}", chart_part_filename(start_chart_idx + j, chart));
                     chart_rels.push((rid, target));
                 }
 
@@ -1265,7 +1723,7 @@ fn write_slide_relationships_extended<W: Write + Seek>(
                     notes_part.unwrap_or(1),
                     &chart_rels,
                     &images,
-                    &slide_hyperlink_relationships(slide),
+                    &slide_extra_relationships(slide, slide_num, media_registry, start_rid),
                     ink_rel_tuple,
                 );
                 set_slide_rels_path(&mut zip_path, slide_num);
@@ -1286,6 +1744,24 @@ fn write_slide_relationships_extended<W: Write + Seek>(
     Ok(())
 }
 
+/// Write `ppt/commentAuthors.xml` and a `ppt/comments/commentN.xml` part per commented slide
+fn write_comment_parts<W: Write + Seek>(
+    zip: &mut ZipWriter<W>,
+    options: &FileOptions,
+    comments: &DeckComments,
+) -> Result<()> {
+    if comments.is_empty() {
+        return Ok(());
+    }
+    zip.start_file("ppt/commentAuthors.xml", *options)?;
+    zip.write_all(comments.authors.to_xml().as_bytes())?;
+    for (slide_num, slide_comments) in &comments.slides {
+        zip.start_file(format!("ppt/comments/comment{slide_num}.xml"), *options)?;
+        zip.write_all(slide_comments.to_xml().as_bytes())?;
+    }
+    Ok(())
+}
+
 /// Write chart files (eager version)
 fn write_charts<W: Write + Seek>(
     zip: &mut ZipWriter<W>,
@@ -1294,12 +1770,17 @@ fn write_charts<W: Write + Seek>(
     slide_chart_start_indices: &[usize],
 ) -> Result<()> {
     if let Some(slides) = custom_slides {
-        for (i, slide) in slides.iter().enumerate() {
-            let start_chart_idx = slide_chart_start_indices[i];
-            for (j, chart) in slide.charts.iter().enumerate() {
-                let chart_idx = start_chart_idx + j;
-                write_chart_package(zip, options, chart_idx, chart)?;
-            }
+        let charts: Vec<_> = slides
+            .iter()
+            .zip(slide_chart_start_indices)
+            .flat_map(|(slide, &start)| slide.charts.iter().enumerate().map(move |(j, chart)| (start + j, chart)))
+            .collect();
+        #[cfg(feature = "parallel")]
+        let packages = super::parallel::map_ordered(&charts, |_, &(idx, chart)| render_chart_package(idx, chart));
+        #[cfg(not(feature = "parallel"))]
+        let packages = charts.iter().map(|&(idx, chart)| render_chart_package(idx, chart));
+        for package in packages {
+            write_rendered_chart_package(zip, options, package?)?;
         }
     }
     Ok(())
@@ -1339,23 +1820,6 @@ fn write_embedded_font_parts<W: Write + Seek>(
     Ok(())
 }
 
-/// Write image files to ppt/media/
-fn write_images<W: Write + Seek>(
-    zip: &mut ZipWriter<W>,
-    options: &FileOptions,
-    custom_slides: Option<&[SlideContent]>,
-) -> Result<()> {
-    if let Some(slides) = custom_slides {
-        let registry = build_media_registry(slides);
-        for (i, (bytes, ext)) in registry.files().iter().enumerate() {
-            let filename = format!("ppt/media/image{}.{}", i + 1, ext);
-            zip.start_file(filename, *options)?;
-            zip.write_all(bytes)?;
-        }
-    }
-    Ok(())
-}
-
 /// Write digital signature parts (`_xmlsignatures/`).
 fn write_digital_signature_parts<W: Write + Seek>(
     zip: &mut ZipWriter<W>,
@@ -1376,18 +1840,22 @@ fn write_digital_signature_parts<W: Write + Seek>(
     Ok(())
 }
 
-/// Write image files from a lazy slide source.
-fn write_images_lazy<W: Write + Seek>(
+/// Write registered images and video/audio clips to ppt/media/
+fn write_media_files<W: Write + Seek>(
     zip: &mut ZipWriter<W>,
     options: &FileOptions,
-    slides: &dyn LazySlideSource,
+    registry: &MediaRegistry,
 ) -> Result<()> {
-    let registry = build_media_registry_lazy(slides);
     for (i, (bytes, ext)) in registry.files().iter().enumerate() {
         let filename = format!("ppt/media/image{}.{}", i + 1, ext);
         zip.start_file(filename, *options)?;
         zip.write_all(bytes)?;
     }
+    for (i, (bytes, ext)) in registry.media_files().iter().enumerate() {
+        let filename = format!("ppt/media/media{}.{}", i + 1, ext);
+        zip.start_file(filename, *options)?;
+        zip.write_all(bytes)?;
+    }
     Ok(())
 }
 
@@ -1482,19 +1950,21 @@ mod tests {
         let names: std::collections::HashSet<String> = (0..archive.len())
             .map(|i| archive.by_index(i).unwrap().name().to_string())
             .collect();
-        assert!(names.contains("ppt/fonts/Arial-regular.fntdata"));
+        assert!(names.contains("ppt/fonts/font1.fntdata"));
 
         let mut rels = String::new();
         archive.by_name("ppt/_rels/presentation.xml.rels").unwrap()
             .read_to_string(&mut rels).unwrap();
         assert!(rels.contains("relationships/font"));
-        assert!(rels.contains("fonts/Arial-regular.fntdata"));
+        assert!(rels.contains(r#"Target="fonts/font1.fntdata""#));
 
         let mut presentation = String::new();
         archive.by_name("ppt/presentation.xml").unwrap()
             .read_to_string(&mut presentation).unwrap();
         assert!(presentation.contains("<p:embeddedFontLst>"));
         assert!(presentation.contains("Arial"));
+        assert!(presentation.contains(r#"<p:presentation embedTrueTypeFonts="1" "#));
+        assert!(presentation.contains("</p:embeddedFontLst><p:defaultTextStyle>"));
     }
 
     #[test]
@@ -1542,6 +2012,94 @@ mod tests {
         assert!(slide.contains("p:contentPart"));
     }
 
+    #[test]
+    fn test_create_pptx_with_no_proof_data_runs() {
+        let table = crate::generator::table::Table::from_data(
+            vec![vec!["Region", "Revenue"], vec!["North", "1,200"]],
+            vec![2000000, 2000000],
+            0,
+            0,
+        );
+        let slides = [SlideContent::new("Sales").table(table)];
+        let settings = PresentationSettings::new().no_proof_data_runs(true);
+        let bytes = create_pptx_with_settings("Proofing", &slides, Some(settings)).unwrap();
+
+        let mut archive = zip::ZipArchive::new(Cursor::new(bytes)).unwrap();
+        let mut slide = String::new();
+        archive.by_name("ppt/slides/slide1.xml").unwrap()
+            .read_to_string(&mut slide).unwrap();
+        assert!(slide.contains(r#"noProof="1" lang="en-US" dirty="0"/><a:t>1,200</a:t>"#));
+        assert!(!slide.contains(r#"noProof="1" lang="en-US" dirty="0"/><a:t>North</a:t>"#));
+    }
+
+    #[test]
+    fn test_create_pptx_with_slide_size() {
+        let slides = [SlideContent::new("Wide")];
+        let settings = PresentationSettings::new()
+            .slide_size(crate::core::SlideSizePreset::Screen16x9);
+        let bytes = create_pptx_with_settings("Size", &slides, Some(settings)).unwrap();
+
+        let mut archive = zip::ZipArchive::new(Cursor::new(bytes)).unwrap();
+        let mut presentation = String::new();
+        archive.by_name("ppt/presentation.xml").unwrap()
+            .read_to_string(&mut presentation).unwrap();
+        assert!(presentation.contains(r#"<p:sldSz cx="9144000" cy="5143500" type="screen16x9"/>"#));
+        assert!(presentation.contains(r#"<p:notesSz cx="6858000" cy="9144000"/>"#));
+
+        let mut app = String::new();
+        archive.by_name("docProps/app.xml").unwrap()
+            .read_to_string(&mut app).unwrap();
+        assert!(app.contains("<PresentationFormat>On-screen Show (16:9)</PresentationFormat>"));
+    }
+
+    #[test]
+    fn test_dimension_chart_fits_slide_size() {
+        use crate::core::{Dimension, SlideSizePreset};
+        use crate::generator::charts::{Chart, ChartSeries, ChartType};
+
+        // Right 40% of the slide, full height
+        let chart = Chart::new("Sales", ChartType::Bar, vec!["Q1".into()], 0, 0, 0, 0)
+            .add_series(ChartSeries::new("2024", vec![1.0]))
+            .at(Dimension::Ratio(0.6), Dimension::Ratio(0.0))
+            .with_dimensions(Dimension::Ratio(0.4), Dimension::Ratio(1.0));
+        let slides = [SlideContent::new("Wide").add_chart(chart)];
+
+        for (preset, height) in [(SlideSizePreset::Screen4x3, 6858000), (SlideSizePreset::Screen16x9, 5143500)] {
+            let settings = PresentationSettings::new().slide_size(preset);
+            let bytes = create_pptx_with_settings("Size", &slides, Some(settings)).unwrap();
+            let mut archive = zip::ZipArchive::new(Cursor::new(bytes)).unwrap();
+            let mut slide = String::new();
+            archive.by_name("ppt/slides/slide1.xml").unwrap()
+                .read_to_string(&mut slide).unwrap();
+            assert!(slide.contains(&format!("<a:off x=\"5486400\" y=\"0\"/>\n<a:ext cx=\"3657600\" cy=\"{height}\"/>")));
+        }
+    }
+
+    #[test]
+    fn test_dimension_shape_fits_slide_size() {
+        use crate::core::{Dimension, SlideSizePreset};
+        use crate::generator::shapes::{Shape, ShapeType};
+
+        // Bottom half of the slide
+        let shape = Shape::from_dimensions(
+            ShapeType::Rectangle,
+            Dimension::Ratio(0.0), Dimension::Ratio(0.5),
+            Dimension::Ratio(1.0), Dimension::Ratio(0.5),
+        );
+        let slides = [SlideContent::new("Wide").add_shape(shape)];
+
+        for (preset, half) in [(SlideSizePreset::Screen4x3, 3429000), (SlideSizePreset::Screen16x9, 2571750)] {
+            let settings = PresentationSettings::new().slide_size(preset);
+            let bytes = create_pptx_with_settings("Size", &slides, Some(settings)).unwrap();
+            let mut archive = zip::ZipArchive::new(Cursor::new(bytes)).unwrap();
+            let mut slide = String::new();
+            archive.by_name("ppt/slides/slide1.xml").unwrap()
+                .read_to_string(&mut slide).unwrap();
+            assert!(slide.contains(&format!("<a:off x=\"0\" y=\"{half}\"/>")), "{slide}");
+            assert!(slide.contains(&format!("<a:ext cx=\"9144000\" cy=\"{half}\"/>")), "{slide}");
+        }
+    }
+
     #[test]
     fn test_create_pptx_with_content_to_writer() {
         let slides = vec![
@@ -1632,4 +2190,42 @@ mod tests {
         assert!(!eager.is_empty());
         assert!(!lazy.is_empty());
     }
+
+    #[test]
+    fn test_rendered_slides_keep_deck_order() {
+        use crate::generator::charts::{ChartBuilder, ChartSeries, ChartType};
+
+        let slides: Vec<SlideContent> = (1..=40)
+            .map(|i| {
+                let slide = SlideContent::new(&format!("Slide {i}")).add_bullet(&format!("Point {i}"));
+                if i % 3 == 0 {
+                    slide.add_chart(
+                        ChartBuilder::new(&format!("Chart {i}"), ChartType::Bar)
+                            .categories(vec!["Q1", "Q2"])
+                            .add_series(ChartSeries::new("Sales", vec![1.0, i as f64]))
+                            .build(),
+                    )
+                } else {
+                    slide
+                }
+            })
+            .collect();
+
+        let rendered: Vec<String> = rendered_slides(&slides, None).collect();
+        let sequential: Vec<String> = slides.iter().enumerate().map(|(i, s)| render_slide_xml(i + 1, s, None)).collect();
+        assert_eq!(rendered, sequential);
+
+        let bytes = create_pptx_with_content("Ordered", slides).unwrap();
+        let mut archive = zip::ZipArchive::new(Cursor::new(bytes)).unwrap();
+        for i in 1..=40 {
+            let mut xml = String::new();
+            archive.by_name(&format!("ppt/slides/slide{i}.xml")).unwrap().read_to_string(&mut xml).unwrap();
+            assert!(xml.contains(&format!("Slide {i}<")), "slide{i}.xml out of order");
+        }
+        for (n, i) in (3..=39).step_by(3).enumerate() {
+            let mut xml = String::new();
+            archive.by_name(&format!("ppt/charts/chart{}.xml", n + 1)).unwrap().read_to_string(&mut xml).unwrap();
+            assert!(xml.contains(&format!("Chart {i}<")), "chart{} out of order", n + 1);
+        }
+    }
 }
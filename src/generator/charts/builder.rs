@@ -1,7 +1,7 @@
 //! Chart builder for fluent API
 
 use crate::core::ElementPlacement;
-use super::data::{Chart, ChartSeries};
+use super::data::{AxisOptions, Chart, ChartFont, ChartSeries, DataLabelOptions, LegendPosition, ValueAxisScale};
 use super::types::ChartType;
 
 /// Chart builder for fluent API
@@ -11,6 +11,10 @@ pub struct ChartBuilder {
     categories: Vec<String>,
     series: Vec<ChartSeries>,
     placement: ElementPlacement,
+    data_labels: Option<DataLabelOptions>,
+    title_font: Option<ChartFont>,
+    axis_titles: (Option<String>, Option<String>),
+    legend_position: LegendPosition,
 }
 
 impl ChartBuilder {
@@ -22,6 +26,10 @@ impl ChartBuilder {
             categories: Vec::new(),
             series: Vec::new(),
             placement: ElementPlacement::chart_defaults(),
+            data_labels: None,
+            title_font: None,
+            axis_titles: (None, None),
+            legend_position: LegendPosition::default(),
         }
     }
 
@@ -49,6 +57,31 @@ impl ChartBuilder {
         self
     }
 
+    /// Label every point
+    pub fn data_labels(mut self, options: DataLabelOptions) -> Self {
+        self.data_labels = Some(options);
+        self
+    }
+
+    /// Set the chart title font
+    pub fn title_font(mut self, font: ChartFont) -> Self {
+        self.title_font = Some(font);
+        self
+    }
+
+    /// Title the category (X) and value (Y) axes; an empty string leaves that axis untitled
+    pub fn axis_title(mut self, x_title: &str, y_title: &str) -> Self {
+        let title = |t: &str| Some(t.to_string()).filter(|t| !t.is_empty());
+        self.axis_titles = (title(x_title), title(y_title));
+        self
+    }
+
+    /// Set where the legend is placed
+    pub fn legend_position(mut self, position: LegendPosition) -> Self {
+        self.legend_position = position;
+        self
+    }
+
     /// Build the chart
     pub fn build(self) -> Chart {
         Chart {
@@ -60,6 +93,18 @@ impl ChartBuilder {
             y: self.placement.y,
             width: self.placement.width,
             height: self.placement.height,
+            value_axis: ValueAxisScale::default(),
+            data_labels: self.data_labels,
+            title_font: self.title_font,
+            x_axis_title: self.axis_titles.0,
+            y_axis_title: self.axis_titles.1,
+            legend_position: self.legend_position,
+            first_slice_angle: 0,
+            x_axis: AxisOptions::default(),
+            y_axis: AxisOptions::default(),
+            editable_data: false,
+            dimension_position: None,
+            dimension_size: None,
         }
     }
 }
@@ -0,0 +1,310 @@
+//! Extended chart (chartEx) XML generation
+//!
+//! Office 2016 chart types such as waterfall are stored as `cx:chartSpace` parts
+//! (`ppt/charts/chartExN.xml`) with their own content type and slide relationship.
+
+use super::data::{Chart, ChartSeries, LegendPosition};
+use super::escape_xml;
+use super::xml::title_run_props;
+use crate::generator::text::proofing_attrs;
+
+/// Content type of a chartEx part
+pub const CHARTEX_CONTENT_TYPE: &str = "application/vnd.ms-office.chartex+xml";
+
+/// Relationship type from a slide to a chartEx part
+pub const CHARTEX_RELATIONSHIP_TYPE: &str = "http://schemas.microsoft.com/office/2014/relationships/chartEx";
+
+/// Scheme colors PowerPoint uses for waterfall increases, decreases and totals
+const RISING_COLOR: &str = "accent1";
+const FALLING_COLOR: &str = "accent2";
+const TOTAL_COLOR: &str = "accent3";
+
+/// File name of the chart part under `ppt/charts/`, e.g. `chart3.xml` or `chartEx3.xml`
+pub fn chart_part_filename(chart_idx: usize, chart: &Chart) -> String {
+    if chart.chart_type.is_chartex() {
+        format!("chartEx{chart_idx}.xml")
+    } else {
+        format!("chart{chart_idx}.xml")
+    }
+}
+
+/// Generate the slide graphic frame for a chartEx part.
+///
+/// Readers without chartEx support get a placeholder text box carrying the chart title.
+pub fn generate_chartex_ref_xml(chart: &Chart, r_id: &str, shape_id: usize) -> String {
+    format!(
+        r#"<mc:AlternateContent xmlns:mc="http://schemas.openxmlformats.org/markup-compatibility/2006">
+<mc:Choice xmlns:cx1="http://schemas.microsoft.com/office/drawing/2015/9/8/chartex" Requires="cx1">
+<p:graphicFrame>
+<p:nvGraphicFramePr>
+<p:cNvPr id="{id}" name="Chart {id}"/>
+<p:cNvGraphicFramePr/>
+<p:nvPr/>
+</p:nvGraphicFramePr>
+<p:xfrm>
+<a:off x="{x}" y="{y}"/>
+<a:ext cx="{cx}" cy="{cy}"/>
+</p:xfrm>
+<a:graphic>
+<a:graphicData uri="http://schemas.microsoft.com/office/drawing/2014/chartex">
+<cx:chart xmlns:cx="http://schemas.microsoft.com/office/drawing/2014/chartex" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships" r:id="{r_id}"/>
+</a:graphicData>
+</a:graphic>
+</p:graphicFrame>
+</mc:Choice>
+<mc:Fallback>
+<p:sp>
+<p:nvSpPr>
+<p:cNvPr id="{id}" name="Chart {id}"/>
+<p:cNvSpPr txBox="1"/>
+<p:nvPr/>
+</p:nvSpPr>
+<p:spPr>
+<a:xfrm>
+<a:off x="{x}" y="{y}"/>
+<a:ext cx="{cx}" cy="{cy}"/>
+</a:xfrm>
+<a:prstGeom prst="rect"><a:avLst/></a:prstGeom>
+</p:spPr>
+<p:txBody>
+<a:bodyPr anchor="ctr"/>
+<a:lstStyle/>
+<a:p><a:pPr algn="ctr"/><a:r><a:rPr lang="en-US"{proofing}/><a:t>{title}</a:t></a:r></a:p>
+</p:txBody>
+</p:sp>
+</mc:Fallback>
+</mc:AlternateContent>"#,
+        id = shape_id,
+        x = chart.x,
+        y = chart.y,
+        cx = chart.width,
+        cy = chart.height,
+        r_id = r_id,
+        title = escape_xml(&chart.title),
+        proofing = proofing_attrs(false),
+    )
+}
+
+/// Generate a waterfall chart from the first series.
+///
+/// Points listed in the series' `waterfall_totals` are drawn as totals; the rest
+/// are colored as increases or decreases by sign.
+pub fn generate_waterfall_chart_xml(chart: &Chart) -> String {
+    let empty = ChartSeries::new("", Vec::new());
+    let series = chart.series.first().unwrap_or(&empty);
+    let count = series.values.len();
+
+    let mut xml = format!(
+        r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<cx:chartSpace xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships" xmlns:cx="http://schemas.microsoft.com/office/drawing/2014/chartex">
+<cx:chartData>
+<cx:externalData r:id="rId1" cx:autoUpdate="0"/>
+<cx:data id="0">
+<cx:strDim type="cat">
+<cx:f>Sheet1!$A$2:$A${}</cx:f>
+<cx:lvl ptCount="{}">"#,
+        1 + count,
+        count
+    );
+    for (idx, cat) in chart.categories.iter().take(count).enumerate() {
+        xml.push_str(&format!("\n<cx:pt idx=\"{}\">{}</cx:pt>", idx, escape_xml(cat)));
+    }
+    xml.push_str(&format!(
+        r#"
+</cx:lvl>
+</cx:strDim>
+<cx:numDim type="val">
+<cx:f>Sheet1!$B$2:$B${}</cx:f>
+<cx:lvl ptCount="{}" formatCode="General">"#,
+        1 + count,
+        count
+    ));
+    for (idx, value) in series.values.iter().enumerate() {
+        xml.push_str(&format!("\n<cx:pt idx=\"{}\">{}</cx:pt>", idx, value));
+    }
+    xml.push_str(&format!(
+        r#"
+</cx:lvl>
+</cx:numDim>
+</cx:data>
+</cx:chartData>
+<cx:chart>
+<cx:title pos="t" align="ctr" overlay="0">
+<cx:tx>
+<cx:txData>
+<cx:v>{}</cx:v>
+</cx:txData>
+</cx:tx>
+<cx:txPr>
+<a:bodyPr/>
+<a:lstStyle/>
+<a:p>
+<a:pPr>
+{}
+</a:pPr>
+<a:endParaRPr lang="en-US"/>
+</a:p>
+</cx:txPr>
+</cx:title>
+<cx:plotArea>
+<cx:plotAreaRegion>
+<cx:series layoutId="waterfall" uniqueId="{{6F1D2C3B-0000-4000-8000-000000000001}}">
+<cx:tx>
+<cx:txData>
+<cx:f>Sheet1!$B$1</cx:f>
+<cx:v>{}</cx:v>
+</cx:txData>
+</cx:tx>"#,
+        escape_xml(&chart.title),
+        title_run_props(chart.title_font.as_ref(), "a:defRPr"),
+        escape_xml(&series.name)
+    ));
+
+    for (idx, value) in series.values.iter().enumerate() {
+        let color = if series.waterfall_totals.contains(&idx) {
+            TOTAL_COLOR
+        } else if *value < 0.0 {
+            FALLING_COLOR
+        } else {
+            RISING_COLOR
+        };
+        xml.push_str(&format!(
+            r#"
+<cx:dataPt idx="{}">
+<cx:spPr><a:solidFill><a:schemeClr val="{}"/></a:solidFill></cx:spPr>
+</cx:dataPt>"#,
+            idx, color
+        ));
+    }
+
+    if let Some(labels) = &chart.data_labels {
+        xml.push_str(&format!(
+            r#"
+<cx:dataLabels pos="outEnd">{}
+<cx:visibility seriesName="{}" categoryName="{}" value="{}"/>
+</cx:dataLabels>"#,
+            labels
+                .number_format
+                .as_ref()
+                .map(|f| format!("\n<cx:numFmt formatCode=\"{}\" sourceLinked=\"0\"/>", escape_xml(f)))
+                .unwrap_or_default(),
+            if labels.show_series_name { 1 } else { 0 },
+            if labels.show_category { 1 } else { 0 },
+            if labels.show_value { 1 } else { 0 }
+        ));
+    }
+
+    xml.push_str(
+        r#"
+<cx:dataId val="0"/>
+<cx:layoutPr>
+<cx:subtotals>"#,
+    );
+    for idx in series.waterfall_totals.iter().filter(|&&i| i < count) {
+        xml.push_str(&format!("\n<cx:idx val=\"{}\"/>", idx));
+    }
+
+    let scale = &chart.value_axis;
+    let scaling = scale.max.map(|v| format!(" max=\"{v}\"")).unwrap_or_default()
+        + &scale.min.map(|v| format!(" min=\"{v}\"")).unwrap_or_default()
+        + &scale.major_unit.map(|v| format!(" majorUnit=\"{v}\"")).unwrap_or_default();
+    let num_fmt = scale
+        .number_format
+        .as_ref()
+        .map(|f| format!("\n<cx:numFmt formatCode=\"{}\" sourceLinked=\"0\"/>", escape_xml(f)))
+        .unwrap_or_default();
+
+    xml.push_str(&format!(
+        r#"
+</cx:subtotals>
+</cx:layoutPr>
+</cx:series>
+</cx:plotAreaRegion>
+<cx:axis id="0">
+<cx:catScaling gapWidth="0.5"/>{}
+<cx:tickLabels/>
+</cx:axis>
+<cx:axis id="1">
+<cx:valScaling{}/>{}
+<cx:majorGridlines/>
+<cx:tickLabels/>{}
+</cx:axis>
+</cx:plotArea>
+<cx:legend pos="{}" align="ctr" overlay="0"/>
+</cx:chart>
+</cx:chartSpace>"#,
+        axis_title_xml(chart.x_axis_title.as_deref()),
+        scaling,
+        axis_title_xml(chart.y_axis_title.as_deref()),
+        num_fmt,
+        legend_pos(chart.legend_position)
+    ));
+
+    xml
+}
+
+/// `<cx:title>` of an axis
+fn axis_title_xml(title: Option<&str>) -> String {
+    title
+        .map(|t| {
+            format!(
+                "\n<cx:title>\n<cx:tx>\n<cx:txData>\n<cx:v>{}</cx:v>\n</cx:txData>\n</cx:tx>\n</cx:title>",
+                escape_xml(t)
+            )
+        })
+        .unwrap_or_default()
+}
+
+/// chartEx legends have no corner position; top-right falls back to the right edge
+fn legend_pos(position: LegendPosition) -> &'static str {
+    match position {
+        LegendPosition::TopRight => "r",
+        other => other.as_str(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generator::charts::ChartType;
+
+    fn waterfall() -> Chart {
+        Chart::new(
+            "Cash flow",
+            ChartType::Waterfall,
+            vec!["Start", "Sales", "Costs", "End"].into_iter().map(String::from).collect(),
+            0, 0, 5000000, 3750000,
+        )
+        .add_series(ChartSeries::new("Cash", vec![100.0, 40.0, -25.0, 115.0]).with_waterfall_totals(vec![0, 3]))
+    }
+
+    #[test]
+    fn test_waterfall_chart_xml() {
+        let xml = generate_waterfall_chart_xml(&waterfall());
+        assert!(xml.contains(r#"<cx:series layoutId="waterfall""#));
+        assert!(xml.contains("<cx:subtotals>\n<cx:idx val=\"0\"/>\n<cx:idx val=\"3\"/>\n</cx:subtotals>"));
+        assert!(xml.contains(r#"<cx:externalData r:id="rId1""#));
+
+        // Totals, increases and decreases each get their own color
+        let fills: Vec<&str> = xml
+            .split("<a:schemeClr val=\"")
+            .skip(1)
+            .map(|s| &s[..s.find('"').unwrap()])
+            .collect();
+        assert_eq!(fills, [TOTAL_COLOR, RISING_COLOR, FALLING_COLOR, TOTAL_COLOR]);
+    }
+
+    #[test]
+    fn test_chartex_part_and_ref() {
+        let chart = waterfall();
+        assert_eq!(chart_part_filename(2, &chart), "chartEx2.xml");
+        let bar = Chart::new("Bar", ChartType::Bar, vec![], 0, 0, 1, 1);
+        assert_eq!(chart_part_filename(2, &bar), "chart2.xml");
+
+        let xml = generate_chartex_ref_xml(&chart, "rId3", 7);
+        assert!(xml.contains(r#"Requires="cx1""#));
+        assert!(xml.contains(r#"<cx:chart xmlns:cx="http://schemas.microsoft.com/office/drawing/2014/chartex""#));
+        assert!(xml.contains("<mc:Fallback>"));
+        assert!(xml.contains(r#"<a:rPr lang="en-US" dirty="0"/>"#));
+    }
+}
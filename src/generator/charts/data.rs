@@ -1,12 +1,36 @@
 //! Chart data structures
 
+use crate::elements::RgbInput;
 use super::types::ChartType;
+use crate::core::{Dimension, SlideSize};
 
 /// Chart data series
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ChartSeries {
     pub name: String,
     pub values: Vec<f64>,
+    /// Series fill color (RGB hex); `None` uses the theme default
+    pub color: Option<String>,
+    /// Per-point colors for pie/doughnut slices (RGB hex)
+    pub point_colors: Vec<String>,
+    /// Plot against the secondary value axis (combo chart line series)
+    pub secondary_axis: bool,
+    /// Bubble sizes for bubble charts; points without one are sized by `|value|`
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub sizes: Vec<f64>,
+    /// X coordinates for scatter and bubble charts; points without one use their 1-based index
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub x_values: Vec<f64>,
+    /// Waterfall points drawn as totals/subtotals from the axis instead of as steps
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub waterfall_totals: Vec<usize>,
+    /// Distance every pie/doughnut slice is pulled out, as a percent of the radius
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub explosion: u32,
+    /// Per-slice `(index, percent)` explosions, overriding `explosion` for that slice
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub slice_explosions: Vec<(usize, u32)>,
 }
 
 impl ChartSeries {
@@ -15,9 +39,93 @@ impl ChartSeries {
         ChartSeries {
             name: name.to_string(),
             values,
+            color: None,
+            point_colors: Vec::new(),
+            secondary_axis: false,
+            sizes: Vec::new(),
+            x_values: Vec::new(),
+            waterfall_totals: Vec::new(),
+            explosion: 0,
+            slice_explosions: Vec::new(),
         }
     }
 
+    /// Create an XY series from `(x, y)` points for scatter and bubble charts
+    pub fn xy(name: &str, points: Vec<(f64, f64)>) -> Self {
+        let (x_values, values) = points.into_iter().unzip();
+        ChartSeries {
+            x_values,
+            ..Self::new(name, values)
+        }
+    }
+
+    /// Plot this series against the secondary (right-hand) value axis
+    pub fn on_secondary_axis(mut self, secondary: bool) -> Self {
+        self.secondary_axis = secondary;
+        self
+    }
+
+    /// Set the series color (RGB hex, e.g. "4472C4")
+    pub fn with_color(mut self, color: impl Into<RgbInput>) -> Self {
+        self.color = Some(color.into().into_value());
+        self
+    }
+
+    /// Set per-point colors (RGB hex), used for pie and doughnut slices
+    pub fn with_colors(mut self, colors: Vec<String>) -> Self {
+        self.point_colors = colors
+            .iter()
+            .map(|c| RgbInput::from(c).into_value())
+            .collect();
+        self
+    }
+
+    /// Set bubble sizes, one per value, as an independent third variable
+    pub fn with_bubble_sizes(mut self, sizes: Vec<f64>) -> Self {
+        self.sizes = sizes;
+        self
+    }
+
+    /// Mark waterfall points (by index) as totals or subtotals
+    pub fn with_waterfall_totals(mut self, indices: Vec<usize>) -> Self {
+        self.waterfall_totals = indices;
+        self
+    }
+
+    /// Pull one pie slice out from the center by `percent` of the radius
+    pub fn explode_slice(mut self, index: usize, percent: u32) -> Self {
+        self.slice_explosions.retain(|(i, _)| *i != index);
+        self.slice_explosions.push((index, percent));
+        self
+    }
+
+    /// Pull every pie slice out from the center by `percent` of the radius
+    pub fn explode_all(mut self, percent: u32) -> Self {
+        self.explosion = percent;
+        self
+    }
+
+    /// Explosion of slice `i`: its own setting, or the series-wide one
+    pub fn slice_explosion(&self, i: usize) -> u32 {
+        self.slice_explosions
+            .iter()
+            .find(|(idx, _)| *idx == i)
+            .map_or(self.explosion, |(_, percent)| *percent)
+    }
+
+    /// Bubble size of point `i`: the explicit size, or `|value|` when none was given
+    pub fn bubble_size(&self, i: usize) -> f64 {
+        self.sizes
+            .get(i)
+            .copied()
+            .unwrap_or_else(|| self.values.get(i).map_or(0.0, |v| v.abs()))
+    }
+
+    /// X coordinate of point `i`: the explicit value, or the 1-based index when none was given
+    pub fn x_value(&self, i: usize) -> f64 {
+        self.x_values.get(i).copied().unwrap_or((i + 1) as f64)
+    }
+
     /// Get the number of data points
     pub fn len(&self) -> usize {
         self.values.len()
@@ -29,8 +137,209 @@ impl ChartSeries {
     }
 }
 
+/// Value axis scaling and number format; unset fields use PowerPoint's auto scaling
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ValueAxisScale {
+    pub min: Option<f64>,
+    pub max: Option<f64>,
+    pub major_unit: Option<f64>,
+    /// Excel number format code, e.g. "0.0%" or "$#,##0"
+    pub number_format: Option<String>,
+}
+
+/// Tick mark style on an axis (`c:majorTickMark` / `c:minorTickMark`)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TickMark {
+    None,
+    Inside,
+    Outside,
+    Cross,
+}
+
+impl TickMark {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TickMark::None => "none",
+            TickMark::Inside => "in",
+            TickMark::Outside => "out",
+            TickMark::Cross => "cross",
+        }
+    }
+}
+
+/// Where tick labels sit relative to the axis (`c:tickLblPos`)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TickLabelPosition {
+    High,
+    Low,
+    NextTo,
+    None,
+}
+
+impl TickLabelPosition {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TickLabelPosition::High => "high",
+            TickLabelPosition::Low => "low",
+            TickLabelPosition::NextTo => "nextTo",
+            TickLabelPosition::None => "none",
+        }
+    }
+}
+
+/// Gridlines and tick marks of an axis; the default keeps major gridlines and PowerPoint's tick defaults
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AxisOptions {
+    pub major_gridlines: bool,
+    pub minor_gridlines: bool,
+    pub major_tick: Option<TickMark>,
+    pub minor_tick: Option<TickMark>,
+    pub tick_label_position: Option<TickLabelPosition>,
+}
+
+impl Default for AxisOptions {
+    fn default() -> Self {
+        AxisOptions {
+            major_gridlines: true,
+            minor_gridlines: false,
+            major_tick: None,
+            minor_tick: None,
+            tick_label_position: None,
+        }
+    }
+}
+
+/// Where data labels sit relative to their point (`c:dLblPos`)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DataLabelPosition {
+    Center,
+    InsideEnd,
+    InsideBase,
+    OutsideEnd,
+    Above,
+    Below,
+    Left,
+    Right,
+    BestFit,
+}
+
+impl DataLabelPosition {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DataLabelPosition::Center => "ctr",
+            DataLabelPosition::InsideEnd => "inEnd",
+            DataLabelPosition::InsideBase => "inBase",
+            DataLabelPosition::OutsideEnd => "outEnd",
+            DataLabelPosition::Above => "t",
+            DataLabelPosition::Below => "b",
+            DataLabelPosition::Left => "l",
+            DataLabelPosition::Right => "r",
+            DataLabelPosition::BestFit => "bestFit",
+        }
+    }
+
+    /// Whether PowerPoint accepts this position in a chart group such as `c:barChart`;
+    /// area, radar, doughnut and stock charts take no position at all
+    pub fn supported_in(&self, group: &str) -> bool {
+        use DataLabelPosition::*;
+        match group {
+            "c:barChart" => matches!(self, Center | InsideEnd | InsideBase | OutsideEnd),
+            "c:lineChart" | "c:scatterChart" | "c:bubbleChart" => {
+                matches!(self, Center | Above | Below | Left | Right)
+            }
+            "c:pieChart" => matches!(self, Center | InsideEnd | OutsideEnd | BestFit),
+            _ => false,
+        }
+    }
+}
+
+/// Data labels shown on every point; an unsupported `position` for the chart type is left out
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DataLabelOptions {
+    pub show_value: bool,
+    pub show_category: bool,
+    /// Share of the whole; only meaningful for pie and doughnut charts
+    pub show_percent: bool,
+    pub show_series_name: bool,
+    /// Excel number format code, e.g. "#,##0" or "0.0%"
+    pub number_format: Option<String>,
+    pub position: Option<DataLabelPosition>,
+}
+
+/// Font of the chart title; unset fields keep the default 18pt gray Calibri
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChartFont {
+    pub size: Option<u32>, // in points
+    pub bold: bool,
+    pub color: Option<String>, // RGB hex color
+    pub face: Option<String>,  // Font family name
+}
+
+impl ChartFont {
+    /// Create a font with default settings
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the size in points
+    pub fn size(mut self, size: u32) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Set bold
+    pub fn bold(mut self) -> Self {
+        self.bold = true;
+        self
+    }
+
+    /// Set the color (RGB hex, e.g. "404040")
+    pub fn color(mut self, color: impl Into<RgbInput>) -> Self {
+        self.color = Some(color.into().into_value());
+        self
+    }
+
+    /// Set the font family
+    pub fn face(mut self, face: &str) -> Self {
+        self.face = Some(face.to_string());
+        self
+    }
+}
+
+/// Where the legend sits around the plot area (`c:legendPos`)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LegendPosition {
+    #[default]
+    Right,
+    Top,
+    Bottom,
+    Left,
+    TopRight,
+}
+
+impl LegendPosition {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LegendPosition::Right => "r",
+            LegendPosition::Top => "t",
+            LegendPosition::Bottom => "b",
+            LegendPosition::Left => "l",
+            LegendPosition::TopRight => "tr",
+        }
+    }
+}
+
 /// Chart definition
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Chart {
     pub title: String,
     pub chart_type: ChartType,
@@ -40,6 +349,38 @@ pub struct Chart {
     pub y: u32,      // Position Y in EMU
     pub width: u32,  // Width in EMU
     pub height: u32, // Height in EMU
+    pub value_axis: ValueAxisScale,
+    /// Data labels; `None` keeps the chart type's default (none, or category and percent for pies)
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub data_labels: Option<DataLabelOptions>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub title_font: Option<ChartFont>,
+    /// Title of the category (or scatter X) axis
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub x_axis_title: Option<String>,
+    /// Title of the value axis
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub y_axis_title: Option<String>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub legend_position: LegendPosition,
+    /// Angle in degrees, clockwise from 12 o'clock, at which the first pie/doughnut slice starts
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub first_slice_angle: u32,
+    /// Category axis (the X value axis of scatter and bubble charts)
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub x_axis: AxisOptions,
+    /// Primary value axis
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub y_axis: AxisOptions,
+    /// Embed a workbook holding this chart's data instead of the placeholder workbook
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub editable_data: bool,
+    /// Position set with [`Chart::at`], re-resolved against the deck's slide size on build
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub dimension_position: Option<(Dimension, Dimension)>,
+    /// Size set with [`Chart::with_dimensions`], re-resolved against the deck's slide size on build
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub dimension_size: Option<(Dimension, Dimension)>,
 }
 
 impl Chart {
@@ -62,6 +403,18 @@ impl Chart {
             y,
             width,
             height,
+            value_axis: ValueAxisScale::default(),
+            data_labels: None,
+            title_font: None,
+            x_axis_title: None,
+            y_axis_title: None,
+            legend_position: LegendPosition::default(),
+            first_slice_angle: 0,
+            x_axis: AxisOptions::default(),
+            y_axis: AxisOptions::default(),
+            editable_data: false,
+            dimension_position: None,
+            dimension_size: None,
         }
     }
 
@@ -71,6 +424,121 @@ impl Chart {
         self
     }
 
+    /// Label every point, e.g. values above each bar
+    pub fn data_labels(mut self, options: DataLabelOptions) -> Self {
+        self.data_labels = Some(options);
+        self
+    }
+
+    /// Set the chart title font
+    pub fn title_font(mut self, font: ChartFont) -> Self {
+        self.title_font = Some(font);
+        self
+    }
+
+    /// Title the category (X) and value (Y) axes; an empty string leaves that axis untitled
+    pub fn axis_title(mut self, x_title: &str, y_title: &str) -> Self {
+        self.x_axis_title = Some(x_title.to_string()).filter(|t| !t.is_empty());
+        self.y_axis_title = Some(y_title.to_string()).filter(|t| !t.is_empty());
+        self
+    }
+
+    /// Set where the legend is placed
+    pub fn legend_position(mut self, position: LegendPosition) -> Self {
+        self.legend_position = position;
+        self
+    }
+
+    /// Rotate a pie or doughnut so its first slice starts `degrees` clockwise from the top
+    pub fn first_slice_angle(mut self, degrees: u32) -> Self {
+        self.first_slice_angle = degrees % 360;
+        self
+    }
+
+    /// Set gridlines and tick marks of the category (X) axis
+    pub fn x_axis(mut self, options: AxisOptions) -> Self {
+        self.x_axis = options;
+        self
+    }
+
+    /// Set gridlines and tick marks of the value (Y) axis
+    pub fn y_axis(mut self, options: AxisOptions) -> Self {
+        self.y_axis = options;
+        self
+    }
+
+    /// Embed the categories and series values in the chart's workbook, so "Edit Data"
+    /// in PowerPoint opens the chart's own numbers
+    pub fn editable_data(mut self, editable: bool) -> Self {
+        self.editable_data = editable;
+        self
+    }
+
+    /// Set position using flexible Dimension units (fluent).
+    ///
+    /// Ratios are relative to the slide the chart ends up on, so a chart at
+    /// `Ratio(0.6)` starts 60% across both 4:3 and 16:9 decks.
+    ///
+    /// ```
+    /// use ppt_rs::core::Dimension;
+    /// use ppt_rs::generator::{Chart, ChartType};
+    ///
+    /// // Right 40% of the slide
+    /// let chart = Chart::new("Sales", ChartType::Bar, vec![], 0, 0, 0, 0)
+    ///     .at(Dimension::Ratio(0.6), Dimension::Ratio(0.0))
+    ///     .with_dimensions(Dimension::Ratio(0.4), Dimension::Ratio(1.0));
+    /// ```
+    pub fn at(mut self, x: Dimension, y: Dimension) -> Self {
+        self.x = x.to_emu_x();
+        self.y = y.to_emu_y();
+        self.dimension_position = Some((x, y));
+        self
+    }
+
+    /// Set size using flexible Dimension units (fluent); see [`Chart::at`]
+    pub fn with_dimensions(mut self, width: Dimension, height: Dimension) -> Self {
+        self.width = width.to_emu_x();
+        self.height = height.to_emu_y();
+        self.dimension_size = Some((width, height));
+        self
+    }
+
+    /// Resolve Dimension placement against a slide size
+    pub fn fit_to(&mut self, size: &SlideSize) {
+        if let Some((x, y)) = &self.dimension_position {
+            self.x = x.to_emu_x_in(size);
+            self.y = y.to_emu_y_in(size);
+        }
+        if let Some((width, height)) = &self.dimension_size {
+            self.width = width.to_emu_x_in(size);
+            self.height = height.to_emu_y_in(size);
+        }
+    }
+
+    /// Whether the chart was placed with Dimension units
+    pub fn has_dimension_placement(&self) -> bool {
+        self.dimension_position.is_some() || self.dimension_size.is_some()
+    }
+
+    /// Fix the value axis range
+    pub fn value_axis_bounds(mut self, min: f64, max: f64) -> Self {
+        self.value_axis.min = Some(min);
+        self.value_axis.max = Some(max);
+        self
+    }
+
+    /// Set the interval between major value axis ticks
+    pub fn major_unit(mut self, unit: f64) -> Self {
+        self.value_axis.major_unit = Some(unit);
+        self
+    }
+
+    /// Set the value axis number format (e.g. "0.0%", "$#,##0")
+    pub fn value_axis_number_format(mut self, format: &str) -> Self {
+        self.value_axis.number_format = Some(format.to_string());
+        self
+    }
+
     /// Get number of categories
     pub fn category_count(&self) -> usize {
         self.categories.len()
@@ -94,6 +562,41 @@ mod tests {
         assert!(!series.is_empty());
     }
 
+    #[test]
+    fn test_chart_series_colors() {
+        let series = ChartSeries::new("Sales", vec![1.0, 2.0])
+            .with_color("#4472c4")
+            .with_colors(vec!["ff0000".to_string(), "#00FF00".to_string()]);
+        assert_eq!(series.color.as_deref(), Some("4472C4"));
+        assert_eq!(series.point_colors, vec!["FF0000", "00FF00"]);
+    }
+
+    #[test]
+    fn test_slice_explosion() {
+        let series = ChartSeries::new("Share", vec![50.0, 30.0, 20.0])
+            .explode_all(5)
+            .explode_slice(0, 10)
+            .explode_slice(0, 15);
+        assert_eq!(series.slice_explosion(0), 15);
+        assert_eq!(series.slice_explosion(2), 5);
+        assert_eq!(series.slice_explosions.len(), 1);
+    }
+
+    #[test]
+    fn test_chart_dimension_placement() {
+        use crate::core::SlideSizePreset;
+
+        let mut chart = Chart::new("Test", ChartType::Bar, vec![], 0, 0, 0, 0)
+            .at(Dimension::Ratio(0.6), Dimension::Inches(1.0))
+            .with_dimensions(Dimension::Ratio(0.4), Dimension::Ratio(0.5));
+        assert_eq!((chart.x, chart.width), (5486400, 3657600));
+        assert_eq!(chart.height, 3429000);
+
+        chart.fit_to(&SlideSizePreset::Screen16x9.into());
+        assert_eq!((chart.x, chart.y), (5486400, 914400));
+        assert_eq!(chart.height, 2571750);
+    }
+
     #[test]
     fn test_chart_add_series() {
         let chart = Chart::new("Test", ChartType::Pie, vec!["A".to_string()], 0, 0, 1000000, 1000000)
@@ -101,4 +604,17 @@ mod tests {
 
         assert_eq!(chart.series_count(), 1);
     }
+
+    #[test]
+    fn test_chart_text_options() {
+        let chart = Chart::new("Test", ChartType::Bar, vec![], 0, 0, 1000000, 1000000)
+            .title_font(ChartFont::new().size(24).bold().color("#404040"))
+            .axis_title("Quarter", "")
+            .legend_position(LegendPosition::Bottom);
+
+        assert_eq!(chart.title_font.unwrap().color.as_deref(), Some("404040"));
+        assert_eq!(chart.x_axis_title.as_deref(), Some("Quarter"));
+        assert_eq!(chart.y_axis_title, None);
+        assert_eq!(chart.legend_position.as_str(), "b");
+    }
 }
@@ -1,4 +1,18 @@
 //! Embedded Excel workbook for chart data (PowerPoint requires package link).
+//!
+//! Chart XML formulas and the generated worksheet share one cell layout: categories
+//! in column A and series `i` in column `B + i`, names in row 1 and data from row 2.
+//! Scatter and bubble series each own a block of X, Y (and size) columns instead.
+
+use std::io::{Cursor, Read, Write};
+
+use zip::write::FileOptions;
+use zip::{ZipArchive, ZipWriter};
+
+use super::data::Chart;
+use super::escape_xml;
+use super::types::ChartType;
+use crate::exc::Result;
 
 /// Filename for the embedded workbook associated with a chart part.
 pub fn chart_embedding_filename(chart_idx: usize) -> String {
@@ -19,3 +33,208 @@ pub fn create_chart_rels_xml(embedding_filename: &str) -> String {
 pub fn reference_workbook_bytes() -> &'static [u8] {
     include_bytes!("../reference_chart_embedding.xlsx")
 }
+
+/// Worksheet columns (0-based) holding one series
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SeriesColumns {
+    /// Categories, or X values for scatter and bubble charts
+    pub x: usize,
+    /// Series name (row 1) and values
+    pub value: usize,
+    /// Bubble sizes; same as `value` for other chart types
+    pub size: usize,
+}
+
+/// Columns of series `idx` in the chart worksheet
+pub fn series_columns(chart_type: ChartType, idx: usize) -> SeriesColumns {
+    match chart_type {
+        ChartType::Bubble => SeriesColumns { x: 3 * idx, value: 3 * idx + 1, size: 3 * idx + 2 },
+        ChartType::Scatter | ChartType::ScatterLines | ChartType::ScatterSmooth => {
+            SeriesColumns { x: 2 * idx, value: 2 * idx + 1, size: 2 * idx + 1 }
+        }
+        _ => SeriesColumns { x: 0, value: idx + 1, size: idx + 1 },
+    }
+}
+
+/// Spreadsheet column name, e.g. 0 → "A", 27 → "AB"
+pub fn column_name(mut col: usize) -> String {
+    let mut name = Vec::new();
+    loop {
+        name.push(b'A' + (col % 26) as u8);
+        if col < 26 {
+            break;
+        }
+        col = col / 26 - 1;
+    }
+    name.reverse();
+    String::from_utf8(name).unwrap_or_default()
+}
+
+/// Formula for `count` data cells of a column, starting below the header row
+pub fn data_range(col: usize, count: usize) -> String {
+    let col = column_name(col);
+    format!("Sheet1!${col}$2:${col}${}", 1 + count)
+}
+
+/// Formula for the header (series name) cell of a column
+pub fn header_cell(col: usize) -> String {
+    format!("Sheet1!${}$1", column_name(col))
+}
+
+enum Cell {
+    Text(String),
+    Number(f64),
+}
+
+/// Cells of the chart worksheet as `(row, column, value)`, both 0-based
+fn chart_cells(chart: &Chart) -> Vec<(usize, usize, Cell)> {
+    let mut cells = Vec::new();
+    let xy = matches!(
+        chart.chart_type,
+        ChartType::Scatter | ChartType::ScatterLines | ChartType::ScatterSmooth | ChartType::Bubble
+    );
+    if !xy {
+        for (i, category) in chart.categories.iter().enumerate() {
+            cells.push((i + 1, 0, Cell::Text(category.clone())));
+        }
+    }
+    for (idx, series) in chart.series.iter().enumerate() {
+        let cols = series_columns(chart.chart_type, idx);
+        cells.push((0, cols.value, Cell::Text(series.name.clone())));
+        for (i, value) in series.values.iter().enumerate() {
+            cells.push((i + 1, cols.value, Cell::Number(*value)));
+            if xy {
+                cells.push((i + 1, cols.x, Cell::Number(series.x_value(i))));
+            }
+            if chart.chart_type == ChartType::Bubble {
+                cells.push((i + 1, cols.size, Cell::Number(series.bubble_size(i))));
+            }
+        }
+    }
+    cells.sort_by_key(|(row, col, _)| (*row, *col));
+    cells
+}
+
+/// `xl/worksheets/sheet1.xml` and `xl/sharedStrings.xml` for the chart's data
+fn chart_sheet_xml(chart: &Chart) -> (String, String) {
+    let cells = chart_cells(chart);
+    let last_row = cells.iter().map(|(row, _, _)| *row).max().unwrap_or(0);
+    let last_col = cells.iter().map(|(_, col, _)| *col).max().unwrap_or(0);
+
+    let mut strings = Vec::new();
+    let mut sheet_data = String::new();
+    let mut current_row = None;
+    for (row, col, cell) in &cells {
+        if current_row != Some(*row) {
+            if current_row.is_some() {
+                sheet_data.push_str("</row>");
+            }
+            sheet_data.push_str(&format!(r#"<row r="{}">"#, row + 1));
+            current_row = Some(*row);
+        }
+        let reference = format!("{}{}", column_name(*col), row + 1);
+        match cell {
+            Cell::Text(text) => {
+                sheet_data.push_str(&format!(r#"<c r="{}" t="s"><v>{}</v></c>"#, reference, strings.len()));
+                strings.push(format!("<si><t>{}</t></si>", escape_xml(text)));
+            }
+            Cell::Number(value) => {
+                sheet_data.push_str(&format!(r#"<c r="{}"><v>{}</v></c>"#, reference, value));
+            }
+        }
+    }
+    if current_row.is_some() {
+        sheet_data.push_str("</row>");
+    }
+
+    let sheet = format!(
+        r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships"><dimension ref="A1:{}{}"/><sheetViews><sheetView tabSelected="1" workbookViewId="0"/></sheetViews><sheetFormatPr defaultRowHeight="15"/><sheetData>{}</sheetData><pageMargins left="0.7" right="0.7" top="0.75" bottom="0.75" header="0.3" footer="0.3"/></worksheet>"#,
+        column_name(last_col),
+        last_row + 1,
+        sheet_data
+    );
+    let shared_strings = format!(
+        r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<sst xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" count="{0}" uniqueCount="{0}">{1}</sst>"#,
+        strings.len(),
+        strings.concat()
+    );
+    (sheet, shared_strings)
+}
+
+/// Workbook holding the chart's categories and series values where its formulas point,
+/// so "Edit Data" in PowerPoint opens the chart's actual numbers.
+pub fn chart_workbook_bytes(chart: &Chart) -> Result<Vec<u8>> {
+    let (sheet, shared_strings) = chart_sheet_xml(chart);
+    let mut reference = ZipArchive::new(Cursor::new(reference_workbook_bytes()))?;
+    let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+    let options = FileOptions::default();
+
+    for i in 0..reference.len() {
+        let mut file = reference.by_index(i)?;
+        let name = file.name().to_string();
+        zip.start_file(&name, options.last_modified_time(file.last_modified()))?;
+        match name.as_str() {
+            "xl/worksheets/sheet1.xml" => zip.write_all(sheet.as_bytes())?,
+            "xl/sharedStrings.xml" => zip.write_all(shared_strings.as_bytes())?,
+            _ => {
+                let mut bytes = Vec::new();
+                file.read_to_end(&mut bytes)?;
+                zip.write_all(&bytes)?;
+            }
+        }
+    }
+
+    Ok(zip.finish()?.into_inner())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generator::charts::ChartSeries;
+
+    fn read(bytes: &[u8], name: &str) -> String {
+        let mut archive = ZipArchive::new(Cursor::new(bytes)).unwrap();
+        let mut s = String::new();
+        archive.by_name(name).unwrap().read_to_string(&mut s).unwrap();
+        s
+    }
+
+    #[test]
+    fn test_column_name() {
+        assert_eq!(column_name(0), "A");
+        assert_eq!(column_name(25), "Z");
+        assert_eq!(column_name(26), "AA");
+        assert_eq!(column_name(27), "AB");
+        assert_eq!(data_range(2, 4), "Sheet1!$C$2:$C$5");
+        assert_eq!(header_cell(1), "Sheet1!$B$1");
+    }
+
+    #[test]
+    fn test_chart_workbook_cells() {
+        let chart = Chart::new("Sales", ChartType::Bar, vec!["Q1".into(), "Q2".into()], 0, 0, 1, 1)
+            .add_series(ChartSeries::new("2024", vec![10.0, 20.0]))
+            .add_series(ChartSeries::new("2025", vec![15.0, 25.5]));
+        let bytes = chart_workbook_bytes(&chart).unwrap();
+
+        let sheet = read(&bytes, "xl/worksheets/sheet1.xml");
+        assert!(sheet.contains(r#"<dimension ref="A1:C3"/>"#));
+        assert!(sheet.contains(r#"<row r="1"><c r="B1" t="s"><v>0</v></c><c r="C1" t="s"><v>1</v></c></row>"#));
+        assert!(sheet.contains(r#"<row r="3"><c r="A3" t="s"><v>3</v></c><c r="B3"><v>20</v></c><c r="C3"><v>25.5</v></c></row>"#));
+        let strings = read(&bytes, "xl/sharedStrings.xml");
+        assert!(strings.contains("<si><t>2024</t></si><si><t>2025</t></si><si><t>Q1</t></si><si><t>Q2</t></si>"));
+        assert!(read(&bytes, "xl/workbook.xml").contains(r#"<sheet name="Sheet1""#));
+    }
+
+    #[test]
+    fn test_bubble_workbook_columns() {
+        let chart = Chart::new("Teams", ChartType::Bubble, vec![], 0, 0, 1, 1)
+            .add_series(ChartSeries::xy("A", vec![(1.5, 2.0)]).with_bubble_sizes(vec![9.0]))
+            .add_series(ChartSeries::xy("B", vec![(3.0, 4.0)]));
+        let sheet = read(&chart_workbook_bytes(&chart).unwrap(), "xl/worksheets/sheet1.xml");
+        assert!(sheet.contains(
+            r#"<row r="2"><c r="A2"><v>1.5</v></c><c r="B2"><v>2</v></c><c r="C2"><v>9</v></c><c r="D2"><v>3</v></c><c r="E2"><v>4</v></c><c r="F2"><v>4</v></c></row>"#
+        ));
+    }
+}
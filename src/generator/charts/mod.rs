@@ -5,18 +5,25 @@
 //! - `data` - Chart data structures (Series, Chart)
 //! - `builder` - Fluent chart builder
 //! - `xml` - XML generation for charts
+//! - `chartex` - Extended (Office 2016+) chart parts such as waterfall
 
 mod types;
 mod data;
 mod builder;
 mod embedding;
 pub mod xml;
+pub mod chartex;
 
 pub use types::ChartType;
-pub use data::{Chart, ChartSeries};
+pub use data::{
+    AxisOptions, Chart, ChartFont, ChartSeries, DataLabelOptions, DataLabelPosition, LegendPosition,
+    TickLabelPosition, TickMark, ValueAxisScale,
+};
 pub use builder::ChartBuilder;
 pub use embedding::{
-    chart_embedding_filename, create_chart_rels_xml, reference_workbook_bytes,
+    chart_embedding_filename, chart_workbook_bytes, column_name, create_chart_rels_xml,
+    reference_workbook_bytes, series_columns, SeriesColumns,
 };
 pub use xml::{generate_chart_part_xml, generate_chart_ref_xml};
+pub use chartex::{chart_part_filename, CHARTEX_CONTENT_TYPE, CHARTEX_RELATIONSHIP_TYPE};
 pub(crate) use crate::core::escape_xml;
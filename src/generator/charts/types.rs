@@ -2,6 +2,7 @@
 
 /// Chart types supported
 #[derive(Clone, Debug, PartialEq, Eq, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ChartType {
     /// Vertical bar chart
     Bar,
@@ -45,6 +46,8 @@ pub enum ChartType {
     StockOHLC,
     /// Combo chart (bar + line)
     Combo,
+    /// Waterfall chart (Office 2016+ extended chart)
+    Waterfall,
 }
 
 impl ChartType {
@@ -72,6 +75,7 @@ impl ChartType {
             ChartType::StockHLC => "stockHLC",
             ChartType::StockOHLC => "stockOHLC",
             ChartType::Combo => "combo",
+            ChartType::Waterfall => "waterfall",
         }
     }
 
@@ -89,9 +93,15 @@ impl ChartType {
             ChartType::Radar | ChartType::RadarFilled => "c:radarChart",
             ChartType::StockHLC | ChartType::StockOHLC => "c:stockChart",
             ChartType::Combo => "c:barChart", // Primary chart type for combo
+            ChartType::Waterfall => "cx:plotAreaRegion",
         }
     }
 
+    /// Whether the chart is written as an extended `cx:chartSpace` (chartEx) part
+    pub fn is_chartex(&self) -> bool {
+        matches!(self, ChartType::Waterfall)
+    }
+
     /// Get bar direction for bar charts
     pub fn bar_direction(&self) -> Option<&str> {
         match self {
@@ -207,6 +217,12 @@ mod tests {
         assert!(!ChartType::Pie.has_markers());
     }
 
+    #[test]
+    fn test_is_chartex() {
+        assert!(ChartType::Waterfall.is_chartex());
+        assert!(!ChartType::Bar.is_chartex());
+    }
+
     #[test]
     fn test_is_smooth() {
         assert!(ChartType::ScatterSmooth.is_smooth());
@@ -1,8 +1,10 @@
 //! Chart XML generation
 
 use super::types::ChartType;
-use super::data::Chart;
+use super::data::{AxisOptions, Chart, ChartFont, ChartSeries, DataLabelOptions, ValueAxisScale};
+use super::embedding::{data_range, header_cell, series_columns};
 use super::escape_xml;
+use crate::elements::color_value_xml;
 
 /// Generate chart XML content (for ppt/charts/chartN.xml)
 pub fn generate_chart_part_xml(chart: &Chart) -> String {
@@ -25,11 +27,15 @@ pub fn generate_chart_part_xml(chart: &Chart) -> String {
         ChartType::Radar | ChartType::RadarFilled => generate_radar_chart_xml(chart),
         ChartType::StockHLC | ChartType::StockOHLC => generate_stock_chart_xml(chart),
         ChartType::Combo => generate_combo_chart_xml(chart),
+        ChartType::Waterfall => super::chartex::generate_waterfall_chart_xml(chart),
     }
 }
 
 /// Generate chart reference XML for slide (p:graphicFrame)
 pub fn generate_chart_ref_xml(chart: &Chart, r_id: &str, shape_id: usize) -> String {
+    if chart.chart_type.is_chartex() {
+        return super::chartex::generate_chartex_ref_xml(chart, r_id, shape_id);
+    }
     format!(
         r#"<p:graphicFrame>
 <p:nvGraphicFramePr>
@@ -70,12 +76,7 @@ fn chart_part_header(chart: &Chart) -> String {
 <a:defRPr/>
 </a:pPr>
 <a:r>
-<a:rPr lang="en-US" sz="1800" b="0" i="0" u="none" strike="noStrike">
-<a:solidFill>
-<a:srgbClr val="595959"/>
-</a:solidFill>
-<a:latin typeface="Calibri"/>
-</a:rPr>
+{}
 <a:t>{}</a:t>
 </a:r>
 </a:p>
@@ -87,15 +88,70 @@ fn chart_part_header(chart: &Chart) -> String {
 <c:autoTitleDeleted val="0"/>
 <c:plotArea>
 <c:layout/>"#,
+        title_run_props(chart.title_font.as_ref(), "a:rPr"),
         escape_xml(&chart.title)
     )
 }
 
+/// Run properties (`a:rPr` or `a:defRPr`) of the chart title; unset font fields fall back to 18pt gray Calibri
+pub(super) fn title_run_props(font: Option<&ChartFont>, element: &str) -> String {
+    let default = ChartFont::default();
+    let font = font.unwrap_or(&default);
+    format!(
+        r#"<{element} lang="en-US" sz="{}" b="{}" i="0" u="none" strike="noStrike" dirty="0">
+<a:solidFill>
+{}
+</a:solidFill>
+<a:latin typeface="{}"/>
+</{element}>"#,
+        font.size.unwrap_or(18) * 100,
+        if font.bold { 1 } else { 0 },
+        color_value_xml(&escape_xml(font.color.as_deref().unwrap_or("595959")), ""),
+        escape_xml(font.face.as_deref().unwrap_or("Calibri"))
+    )
+}
+
+/// `<c:title>` of an axis at `ax_pos`; titles of the left and right axes read bottom to top
+fn axis_title_xml(title: Option<&str>, ax_pos: &str) -> String {
+    let Some(title) = title else {
+        return String::new();
+    };
+    let body_pr = if matches!(ax_pos, "l" | "r") {
+        r#"<a:bodyPr rot="-5400000" vert="horz"/>"#
+    } else {
+        "<a:bodyPr/>"
+    };
+    format!(
+        r#"
+<c:title>
+<c:tx>
+<c:rich>
+{}
+<a:lstStyle/>
+<a:p>
+<a:pPr>
+<a:defRPr/>
+</a:pPr>
+<a:r>
+<a:rPr lang="en-US" sz="1200" b="0" dirty="0"/>
+<a:t>{}</a:t>
+</a:r>
+</a:p>
+</c:rich>
+</c:tx>
+<c:overlay val="0"/>
+</c:title>"#,
+        body_pr,
+        escape_xml(title)
+    )
+}
+
 /// Generate the chart part footer
-fn chart_part_footer() -> &'static str {
-    r#"</c:plotArea>
+fn chart_part_footer(chart: &Chart) -> String {
+    format!(
+        r#"</c:plotArea>
 <c:legend>
-<c:legendPos val="r"/>
+<c:legendPos val="{}"/>
 <c:layout/>
 <c:overlay val="0"/>
 </c:legend>
@@ -106,11 +162,120 @@ fn chart_part_footer() -> &'static str {
 <c:externalData r:id="rId1">
 <c:autoUpdate val="0"/>
 </c:externalData>
-</c:chartSpace>"#
+</c:chartSpace>"#,
+        chart.legend_position.as_str()
+    )
+}
+
+/// Solid fill `<c:spPr>` for a series color; line-based charts color the stroke instead.
+fn series_sp_pr(color: Option<&str>, line: bool) -> String {
+    match color {
+        Some(color) if line => format!(
+            r#"
+<c:spPr><a:ln w="28575"><a:solidFill>{}</a:solidFill></a:ln></c:spPr>"#,
+            color_value_xml(&escape_xml(color), "")
+        ),
+        Some(color) => format!(
+            r#"
+<c:spPr><a:solidFill>{}</a:solidFill></c:spPr>"#,
+            color_value_xml(&escape_xml(color), "")
+        ),
+        None => String::new(),
+    }
+}
+
+/// Series explosion and `<c:dPt>` entries coloring or pulling out individual pie/doughnut slices
+fn slice_data_points(series: &ChartSeries) -> String {
+    let mut xml = String::new();
+    if series.explosion > 0 {
+        xml.push_str(&format!("\n<c:explosion val=\"{}\"/>", series.explosion));
+    }
+    let exploded = series.slice_explosions.iter().map(|(idx, _)| idx + 1).max().unwrap_or(0);
+    for idx in 0..series.point_colors.len().max(exploded) {
+        let explosion = match series.slice_explosions.iter().find(|(i, _)| *i == idx) {
+            Some((_, percent)) => format!("\n<c:explosion val=\"{percent}\"/>"),
+            None => String::new(),
+        };
+        let fill = match series.point_colors.get(idx) {
+            Some(color) => format!(
+                "\n<c:spPr><a:solidFill>{}</a:solidFill></c:spPr>",
+                color_value_xml(&escape_xml(color), "")
+            ),
+            None => String::new(),
+        };
+        if explosion.is_empty() && fill.is_empty() {
+            continue;
+        }
+        xml.push_str(&format!(
+            r#"
+<c:dPt>
+<c:idx val="{}"/>
+<c:bubble3D val="0"/>{}{}
+</c:dPt>"#,
+            idx, explosion, fill
+        ));
+    }
+    xml
+}
+
+/// Series `<c:dLbls>` for a series in chart group `group` (e.g. `c:barChart`); unset options keep `default`
+fn data_labels_xml(options: Option<&DataLabelOptions>, group: &str, default: &str) -> String {
+    let Some(options) = options else {
+        return default.to_string();
+    };
+    let num_fmt = options
+        .number_format
+        .as_ref()
+        .map(|f| format!("\n<c:numFmt formatCode=\"{}\" sourceLinked=\"0\"/>", escape_xml(f)))
+        .unwrap_or_default();
+    let position = options
+        .position
+        .filter(|p| p.supported_in(group))
+        .map(|p| format!("\n<c:dLblPos val=\"{}\"/>", p.as_str()))
+        .unwrap_or_default();
+    let flag = |on: bool| if on { 1 } else { 0 };
+    format!(
+        r#"
+<c:dLbls>{}{}
+<c:showLegendKey val="0"/>
+<c:showVal val="{}"/>
+<c:showCatName val="{}"/>
+<c:showSerName val="{}"/>
+<c:showPercent val="{}"/>
+<c:showBubbleSize val="0"/>
+</c:dLbls>"#,
+        num_fmt,
+        position,
+        flag(options.show_value),
+        flag(options.show_category),
+        flag(options.show_series_name),
+        flag(options.show_percent)
+    )
+}
+
+/// Labels of a series in `group` when the chart leaves its defaults alone: none
+fn series_data_labels(chart: &Chart, group: &str) -> String {
+    data_labels_xml(chart.data_labels.as_ref(), group, "\n<c:dLbls>\n<c:showVal val=\"0\"/>\n</c:dLbls>")
 }
 
-/// Generate series data XML
-fn generate_series_data(_chart: &Chart, idx: usize, series_name: &str, values: &[f64]) -> String {
+/// Labels of a pie or doughnut series; by default slices show category and percent
+fn slice_data_labels(chart: &Chart, group: &str) -> String {
+    data_labels_xml(
+        chart.data_labels.as_ref(),
+        group,
+        "\n<c:dLbls>\n<c:showCatName val=\"1\"/>\n<c:showPercent val=\"1\"/>\n</c:dLbls>",
+    )
+}
+
+/// Whether series of this chart are drawn as lines rather than filled areas
+fn is_line_series(chart: &Chart) -> bool {
+    matches!(chart.chart_type.xml_element(), "c:lineChart" | "c:radarChart" | "c:scatterChart")
+}
+
+/// Generate series data XML (`line` colors the stroke instead of the fill; `labels` is its `<c:dLbls>`)
+fn generate_series_data(idx: usize, series: &ChartSeries, line: bool, labels: &str) -> String {
+    let series_name = &series.name;
+    let values = &series.values;
     let mut xml = format!(
         r#"
 <c:ser>
@@ -123,31 +288,33 @@ fn generate_series_data(_chart: &Chart, idx: usize, series_name: &str, values: &
 <a:lstStyle/>
 <a:p>
 <a:r>
-<a:rPr lang="en-US" sz="1000"/>
+<a:rPr lang="en-US" sz="1000" dirty="0"/>
 <a:t>{}</a:t>
 </a:r>
 </a:p>
 </c:rich>
 </c:tx>
-</c:title>
-<c:dLbls>
-<c:showVal val="0"/>
-</c:dLbls>
+</c:title>{}{}
 <c:val>
 <c:numRef>
-<c:f>Sheet1!$B${}:$B${}</c:f>
+<c:f>{}</c:f>
 <c:numCache>
 <c:formatCode>General</c:formatCode>"#,
-        idx, idx, escape_xml(series_name), 2 + idx, 2 + idx + values.len()
+        idx,
+        idx,
+        escape_xml(series_name),
+        series_sp_pr(series.color.as_deref(), line),
+        labels,
+        data_range(series_columns(ChartType::Bar, idx).value, values.len())
     );
 
-    for value in values {
+    for (i, value) in values.iter().enumerate() {
         xml.push_str(&format!(
             r#"
-<c:pt idx="0">
+<c:pt idx="{}">
 <c:v>{}</c:v>
 </c:pt>"#,
-            value
+            i, value
         ));
     }
 
@@ -164,25 +331,40 @@ fn generate_series_data(_chart: &Chart, idx: usize, series_name: &str, values: &
 
 /// Generate category axis XML
 fn generate_category_axis(chart: &Chart, ax_pos: &str) -> String {
+    category_axis_xml(chart, 1, ax_pos, 2, false)
+}
+
+/// Generate category axis XML with explicit axis ids (`deleted` hides the axis)
+fn category_axis_xml(chart: &Chart, ax_id: u32, ax_pos: &str, cross_ax: u32, deleted: bool) -> String {
+    let hidden = AxisOptions::default();
+    let axis = if deleted { &hidden } else { &chart.x_axis };
     let mut xml = format!(
         r#"
 <c:catAx>
-<c:axId val="1"/>
+<c:axId val="{}"/>
 <c:scaling>
 <c:orientation val="minMax"/>
 </c:scaling>
-<c:delete val="0"/>
-<c:axPos val="{}"/>
-<c:majorGridlines/>
-<c:numFmt formatCode="General" sourceLinked="1"/>
-<c:tickLblPos val="low"/>
-<c:crossAx val="2"/>
+<c:delete val="{}"/>
+<c:axPos val="{}"/>{}{}
+<c:numFmt formatCode="General" sourceLinked="1"/>{}
+<c:tickLblPos val="{}"/>
+<c:crossAx val="{}"/>
 <c:crosses val="autoZero"/>
 <c:strRef>
 <c:f>Sheet1!$A$2:$A${}</c:f>
 <c:strCache>
 <c:ptCount val="{}"/>"#,
-        ax_pos, 1 + chart.category_count(), chart.category_count()
+        ax_id,
+        if deleted { 1 } else { 0 },
+        ax_pos,
+        gridlines_xml(axis),
+        if deleted { String::new() } else { axis_title_xml(chart.x_axis_title.as_deref(), ax_pos) },
+        tick_marks_xml(axis),
+        axis.tick_label_position.map_or("low", |p| p.as_str()),
+        cross_ax,
+        1 + chart.category_count(),
+        chart.category_count()
     );
 
     for (idx, cat) in chart.categories.iter().enumerate() {
@@ -205,43 +387,106 @@ fn generate_category_axis(chart: &Chart, ax_pos: &str) -> String {
     xml
 }
 
-/// Generate value axis XML
-fn generate_value_axis(ax_pos: &str) -> String {
+/// Generate value axis XML using the chart's axis scale
+fn generate_value_axis(chart: &Chart, ax_pos: &str) -> String {
+    value_axis_xml(&chart.value_axis, &chart.y_axis, chart.y_axis_title.as_deref(), 2, ax_pos, 1, false)
+}
+
+/// `<c:majorGridlines/>` and `<c:minorGridlines/>` of an axis
+fn gridlines_xml(axis: &AxisOptions) -> String {
+    let mut xml = String::new();
+    if axis.major_gridlines {
+        xml.push_str("\n<c:majorGridlines/>");
+    }
+    if axis.minor_gridlines {
+        xml.push_str("\n<c:minorGridlines/>");
+    }
+    xml
+}
+
+/// Explicit `<c:majorTickMark>` / `<c:minorTickMark>`; unset marks are left to PowerPoint
+fn tick_marks_xml(axis: &AxisOptions) -> String {
+    axis.major_tick
+        .map(|t| format!("\n<c:majorTickMark val=\"{}\"/>", t.as_str()))
+        .unwrap_or_default()
+        + &axis
+            .minor_tick
+            .map(|t| format!("\n<c:minorTickMark val=\"{}\"/>", t.as_str()))
+            .unwrap_or_default()
+}
+
+/// Generate value axis XML with explicit axis ids.
+///
+/// A secondary axis has no gridlines and crosses its category axis at the maximum,
+/// so it renders on the opposite side of the plot area.
+fn value_axis_xml(
+    scale: &ValueAxisScale,
+    axis: &AxisOptions,
+    title: Option<&str>,
+    ax_id: u32,
+    ax_pos: &str,
+    cross_ax: u32,
+    secondary: bool,
+) -> String {
+    let bounds = scale.max.map(|v| format!("\n<c:max val=\"{v}\"/>")).unwrap_or_default()
+        + &scale.min.map(|v| format!("\n<c:min val=\"{v}\"/>")).unwrap_or_default();
+    let num_fmt = match scale.number_format {
+        Some(ref format) => format!(r#"<c:numFmt formatCode="{}" sourceLinked="0"/>"#, escape_xml(format)),
+        None => r#"<c:numFmt formatCode="General" sourceLinked="1"/>"#.to_string(),
+    };
+    let major_unit = scale
+        .major_unit
+        .map(|v| format!("\n<c:majorUnit val=\"{v}\"/>"))
+        .unwrap_or_default();
+
     format!(
         r#"
 <c:valAx>
-<c:axId val="2"/>
+<c:axId val="{}"/>
 <c:scaling>
-<c:orientation val="minMax"/>
+<c:orientation val="minMax"/>{}
 </c:scaling>
 <c:delete val="0"/>
-<c:axPos val="{}"/>
-<c:majorGridlines/>
-<c:numFmt formatCode="General" sourceLinked="1"/>
-<c:tickLblPos val="low"/>
-<c:crossAx val="1"/>
-<c:crosses val="autoZero"/>
+<c:axPos val="{}"/>{}{}
+{}{}
+<c:tickLblPos val="{}"/>
+<c:crossAx val="{}"/>
+<c:crosses val="{}"/>{}
 </c:valAx>"#,
-        ax_pos
+        ax_id,
+        bounds,
+        ax_pos,
+        if secondary { String::new() } else { gridlines_xml(axis) },
+        axis_title_xml(title, ax_pos),
+        num_fmt,
+        tick_marks_xml(axis),
+        axis.tick_label_position.map_or(if secondary { "nextTo" } else { "low" }, |p| p.as_str()),
+        cross_ax,
+        if secondary { "max" } else { "autoZero" },
+        major_unit
     )
 }
 
 /// Generate bar chart XML
 fn generate_bar_chart_xml(chart: &Chart) -> String {
     let mut xml = chart_part_header(chart);
-    
-    xml.push_str(r#"<c:barChart>
-<c:barDir val="bar"/>
-<c:grouping val="clustered"/>"#);
+    let direction = chart.chart_type.bar_direction().unwrap_or("col");
+    // Horizontal bars put categories up the left side and values along the bottom
+    let (cat_pos, val_pos) = if direction == "bar" { ("l", "b") } else { ("b", "l") };
+
+    xml.push_str(&format!(r#"<c:barChart>
+<c:barDir val="{direction}"/>
+<c:grouping val="clustered"/>"#));
 
+    let labels = series_data_labels(chart, "c:barChart");
     for (idx, series) in chart.series.iter().enumerate() {
-        xml.push_str(&generate_series_data(chart, idx, &series.name, &series.values));
+        xml.push_str(&generate_series_data(idx, series, is_line_series(chart), &labels));
     }
 
-    xml.push_str(&generate_category_axis(chart, "l"));
-    xml.push_str(&generate_value_axis("b"));
+    xml.push_str(&generate_category_axis(chart, cat_pos));
+    xml.push_str(&generate_value_axis(chart, val_pos));
     xml.push_str("</c:barChart>");
-    xml.push_str(chart_part_footer());
+    xml.push_str(&chart_part_footer(chart));
 
     xml
 }
@@ -253,14 +498,15 @@ fn generate_line_chart_xml(chart: &Chart) -> String {
     xml.push_str(r#"<c:lineChart>
 <c:grouping val="lineMarkers"/>"#);
 
+    let labels = series_data_labels(chart, "c:lineChart");
     for (idx, series) in chart.series.iter().enumerate() {
-        xml.push_str(&generate_series_data(chart, idx, &series.name, &series.values));
+        xml.push_str(&generate_series_data(idx, series, is_line_series(chart), &labels));
     }
 
     xml.push_str(&generate_category_axis(chart, "b"));
-    xml.push_str(&generate_value_axis("l"));
+    xml.push_str(&generate_value_axis(chart, "l"));
     xml.push_str("</c:lineChart>");
-    xml.push_str(chart_part_footer());
+    xml.push_str(&chart_part_footer(chart));
 
     xml
 }
@@ -286,23 +532,22 @@ fn generate_pie_chart_xml(chart: &Chart) -> String {
 <a:lstStyle/>
 <a:p>
 <a:r>
-<a:rPr lang="en-US" sz="1000"/>
+<a:rPr lang="en-US" sz="1000" dirty="0"/>
 <a:t>{}</a:t>
 </a:r>
 </a:p>
 </c:rich>
 </c:tx>
-</c:title>
-<c:dLbls>
-<c:showCatName val="1"/>
-<c:showPercent val="1"/>
-</c:dLbls>
+</c:title>{}{}{}
 <c:val>
 <c:numRef>
 <c:f>Sheet1!$B$2:$B${}</c:f>
 <c:numCache>
 <c:formatCode>General</c:formatCode>"#,
             escape_xml(&series.name),
+            series_sp_pr(series.color.as_deref(), false),
+            slice_data_points(series),
+            slice_data_labels(chart, "c:pieChart"),
             1 + series.values.len()
         ));
 
@@ -349,8 +594,9 @@ fn generate_pie_chart_xml(chart: &Chart) -> String {
         );
     }
 
+    xml.push_str(&format!("\n<c:firstSliceAng val=\"{}\"/>", chart.first_slice_angle));
     xml.push_str("</c:pieChart>");
-    xml.push_str(chart_part_footer());
+    xml.push_str(&chart_part_footer(chart));
 
     xml
 }
@@ -360,8 +606,7 @@ fn generate_doughnut_chart_xml(chart: &Chart) -> String {
     let mut xml = chart_part_header(chart);
     
     xml.push_str(r#"<c:doughnutChart>
-<c:varyColors val="1"/>
-<c:holeSize val="50"/>"#);
+<c:varyColors val="1"/>"#);
 
     // Doughnut chart uses first series only (like pie)
     if let Some(series) = chart.series.first() {
@@ -378,17 +623,16 @@ fn generate_doughnut_chart_xml(chart: &Chart) -> String {
 <c:pt idx="0"><c:v>{}</c:v></c:pt>
 </c:strCache>
 </c:strRef>
-</c:tx>
-<c:dLbls>
-<c:showCatName val="1"/>
-<c:showPercent val="1"/>
-</c:dLbls>
+</c:tx>{}{}{}
 <c:val>
 <c:numRef>
 <c:f>Sheet1!$B$2:$B${}</c:f>
 <c:numCache>
 <c:formatCode>General</c:formatCode>"#,
             escape_xml(&series.name),
+            series_sp_pr(series.color.as_deref(), false),
+            slice_data_points(series),
+            slice_data_labels(chart, "c:doughnutChart"),
             1 + series.values.len()
         ));
 
@@ -435,8 +679,12 @@ fn generate_doughnut_chart_xml(chart: &Chart) -> String {
         );
     }
 
+    xml.push_str(&format!(
+        "\n<c:firstSliceAng val=\"{}\"/>\n<c:holeSize val=\"50\"/>",
+        chart.first_slice_angle
+    ));
     xml.push_str("</c:doughnutChart>");
-    xml.push_str(chart_part_footer());
+    xml.push_str(&chart_part_footer(chart));
 
     xml
 }
@@ -449,14 +697,15 @@ fn generate_area_chart_xml(chart: &Chart) -> String {
     xml.push_str(&format!(r#"<c:areaChart>
 <c:grouping val="{}"/>"#, grouping));
 
+    let labels = series_data_labels(chart, "c:areaChart");
     for (idx, series) in chart.series.iter().enumerate() {
-        xml.push_str(&generate_series_data(chart, idx, &series.name, &series.values));
+        xml.push_str(&generate_series_data(idx, series, is_line_series(chart), &labels));
     }
 
     xml.push_str(&generate_category_axis(chart, "b"));
-    xml.push_str(&generate_value_axis("l"));
+    xml.push_str(&generate_value_axis(chart, "l"));
     xml.push_str("</c:areaChart>");
-    xml.push_str(chart_part_footer());
+    xml.push_str(&chart_part_footer(chart));
 
     xml
 }
@@ -470,6 +719,7 @@ fn generate_scatter_chart_xml(chart: &Chart) -> String {
 <c:scatterStyle val="{}"/>"#, scatter_style));
 
     for (idx, series) in chart.series.iter().enumerate() {
+        let cols = series_columns(chart.chart_type, idx);
         xml.push_str(&format!(
             r#"
 <c:ser>
@@ -477,29 +727,34 @@ fn generate_scatter_chart_xml(chart: &Chart) -> String {
 <c:order val="{}"/>
 <c:tx>
 <c:strRef>
-<c:f>Sheet1!$B$1</c:f>
+<c:f>{}</c:f>
 <c:strCache>
 <c:ptCount val="1"/>
 <c:pt idx="0"><c:v>{}</c:v></c:pt>
 </c:strCache>
 </c:strRef>
-</c:tx>
+</c:tx>{}{}
 <c:xVal>
 <c:numRef>
-<c:f>Sheet1!$A$2:$A${}</c:f>
+<c:f>{}</c:f>
 <c:numCache>
 <c:formatCode>General</c:formatCode>"#,
-            idx, idx, escape_xml(&series.name), 1 + series.values.len()
+            idx,
+            idx,
+            header_cell(cols.value),
+            escape_xml(&series.name),
+            series_sp_pr(series.color.as_deref(), is_line_series(chart)),
+            data_labels_xml(chart.data_labels.as_ref(), "c:scatterChart", ""),
+            data_range(cols.x, series.values.len())
         ));
 
-        // X values (use index as X)
-        for (i, _) in series.values.iter().enumerate() {
+        for i in 0..series.values.len() {
             xml.push_str(&format!(
                 r#"
 <c:pt idx="{}">
 <c:v>{}</c:v>
 </c:pt>"#,
-                i, i + 1
+                i, series.x_value(i)
             ));
         }
 
@@ -510,10 +765,10 @@ fn generate_scatter_chart_xml(chart: &Chart) -> String {
 </c:xVal>
 <c:yVal>
 <c:numRef>
-<c:f>Sheet1!$B$2:$B${}</c:f>
+<c:f>{}</c:f>
 <c:numCache>
 <c:formatCode>General</c:formatCode>"#,
-            1 + series.values.len()
+            data_range(cols.value, series.values.len())
         ));
 
         for (i, value) in series.values.iter().enumerate() {
@@ -535,10 +790,10 @@ fn generate_scatter_chart_xml(chart: &Chart) -> String {
         );
     }
 
-    xml.push_str(&generate_value_axis("b"));
-    xml.push_str(&generate_value_axis("l"));
+    xml.push_str(&value_axis_xml(&ValueAxisScale::default(), &chart.x_axis, chart.x_axis_title.as_deref(), 1, "b", 2, false));
+    xml.push_str(&generate_value_axis(chart, "l"));
     xml.push_str("</c:scatterChart>");
-    xml.push_str(chart_part_footer());
+    xml.push_str(&chart_part_footer(chart));
 
     xml
 }
@@ -552,6 +807,7 @@ fn generate_bubble_chart_xml(chart: &Chart) -> String {
 <c:bubbleScale val="100"/>"#);
 
     for (idx, series) in chart.series.iter().enumerate() {
+        let cols = series_columns(chart.chart_type, idx);
         xml.push_str(&format!(
             r#"
 <c:ser>
@@ -559,28 +815,34 @@ fn generate_bubble_chart_xml(chart: &Chart) -> String {
 <c:order val="{}"/>
 <c:tx>
 <c:strRef>
-<c:f>Sheet1!$B$1</c:f>
+<c:f>{}</c:f>
 <c:strCache>
 <c:ptCount val="1"/>
 <c:pt idx="0"><c:v>{}</c:v></c:pt>
 </c:strCache>
 </c:strRef>
-</c:tx>
+</c:tx>{}{}
 <c:xVal>
 <c:numRef>
-<c:f>Sheet1!$A$2:$A${}</c:f>
+<c:f>{}</c:f>
 <c:numCache>
 <c:formatCode>General</c:formatCode>"#,
-            idx, idx, escape_xml(&series.name), 1 + series.values.len()
+            idx,
+            idx,
+            header_cell(cols.value),
+            escape_xml(&series.name),
+            series_sp_pr(series.color.as_deref(), is_line_series(chart)),
+            data_labels_xml(chart.data_labels.as_ref(), "c:bubbleChart", ""),
+            data_range(cols.x, series.values.len())
         ));
 
-        for (i, _) in series.values.iter().enumerate() {
+        for i in 0..series.values.len() {
             xml.push_str(&format!(
                 r#"
 <c:pt idx="{}">
 <c:v>{}</c:v>
 </c:pt>"#,
-                i, i + 1
+                i, series.x_value(i)
             ));
         }
 
@@ -591,10 +853,10 @@ fn generate_bubble_chart_xml(chart: &Chart) -> String {
 </c:xVal>
 <c:yVal>
 <c:numRef>
-<c:f>Sheet1!$B$2:$B${}</c:f>
+<c:f>{}</c:f>
 <c:numCache>
 <c:formatCode>General</c:formatCode>"#,
-            1 + series.values.len()
+            data_range(cols.value, series.values.len())
         ));
 
         for (i, value) in series.values.iter().enumerate() {
@@ -614,20 +876,19 @@ fn generate_bubble_chart_xml(chart: &Chart) -> String {
 </c:yVal>
 <c:bubbleSize>
 <c:numRef>
-<c:f>Sheet1!$C$2:$C${}</c:f>
+<c:f>{}</c:f>
 <c:numCache>
 <c:formatCode>General</c:formatCode>"#,
-            1 + series.values.len()
+            data_range(cols.size, series.values.len())
         ));
 
-        // Bubble sizes (use values as sizes)
-        for (i, value) in series.values.iter().enumerate() {
+        for i in 0..series.values.len() {
             xml.push_str(&format!(
                 r#"
 <c:pt idx="{}">
 <c:v>{}</c:v>
 </c:pt>"#,
-                i, value.abs()
+                i, series.bubble_size(i)
             ));
         }
 
@@ -640,10 +901,10 @@ fn generate_bubble_chart_xml(chart: &Chart) -> String {
         );
     }
 
-    xml.push_str(&generate_value_axis("b"));
-    xml.push_str(&generate_value_axis("l"));
+    xml.push_str(&value_axis_xml(&ValueAxisScale::default(), &chart.x_axis, chart.x_axis_title.as_deref(), 1, "b", 2, false));
+    xml.push_str(&generate_value_axis(chart, "l"));
     xml.push_str("</c:bubbleChart>");
-    xml.push_str(chart_part_footer());
+    xml.push_str(&chart_part_footer(chart));
 
     xml
 }
@@ -656,14 +917,15 @@ fn generate_radar_chart_xml(chart: &Chart) -> String {
     xml.push_str(&format!(r#"<c:radarChart>
 <c:radarStyle val="{}"/>"#, radar_style));
 
+    let labels = series_data_labels(chart, "c:radarChart");
     for (idx, series) in chart.series.iter().enumerate() {
-        xml.push_str(&generate_series_data(chart, idx, &series.name, &series.values));
+        xml.push_str(&generate_series_data(idx, series, is_line_series(chart), &labels));
     }
 
     xml.push_str(&generate_category_axis(chart, "b"));
-    xml.push_str(&generate_value_axis("l"));
+    xml.push_str(&generate_value_axis(chart, "l"));
     xml.push_str("</c:radarChart>");
-    xml.push_str(chart_part_footer());
+    xml.push_str(&chart_part_footer(chart));
 
     xml
 }
@@ -675,49 +937,76 @@ fn generate_stock_chart_xml(chart: &Chart) -> String {
     xml.push_str(r#"<c:stockChart>"#);
 
     // Stock charts need High, Low, Close (and optionally Open) series
+    let labels = series_data_labels(chart, "c:stockChart");
     for (idx, series) in chart.series.iter().enumerate() {
-        xml.push_str(&generate_series_data(chart, idx, &series.name, &series.values));
+        xml.push_str(&generate_series_data(idx, series, is_line_series(chart), &labels));
     }
 
     xml.push_str(&generate_category_axis(chart, "b"));
-    xml.push_str(&generate_value_axis("l"));
+    xml.push_str(&generate_value_axis(chart, "l"));
     xml.push_str("</c:stockChart>");
-    xml.push_str(chart_part_footer());
+    xml.push_str(&chart_part_footer(chart));
 
     xml
 }
 
 /// Generate combo chart XML (bar + line)
+///
+/// The first half of the series are drawn as clustered columns and the rest as
+/// lines. Line series marked `on_secondary_axis` are plotted against a second
+/// value axis (id 3) on the right, paired with a hidden category axis (id 4).
 fn generate_combo_chart_xml(chart: &Chart) -> String {
     let mut xml = chart_part_header(chart);
-    
+
+    let mid = (chart.series.len() / 2).max(1);
+
     // First half of series as bars
     xml.push_str(r#"<c:barChart>
 <c:barDir val="col"/>
 <c:grouping val="clustered"/>"#);
 
-    let mid = chart.series.len() / 2;
-    for (idx, series) in chart.series.iter().take(mid.max(1)).enumerate() {
-        xml.push_str(&generate_series_data(chart, idx, &series.name, &series.values));
+    let labels = series_data_labels(chart, "c:barChart");
+    for (idx, series) in chart.series.iter().take(mid).enumerate() {
+        xml.push_str(&generate_series_data(idx, series, false, &labels));
     }
 
-    xml.push_str(&generate_category_axis(chart, "b"));
-    xml.push_str(&generate_value_axis("l"));
-    xml.push_str("</c:barChart>");
+    xml.push_str(r#"
+<c:axId val="1"/>
+<c:axId val="2"/>
+</c:barChart>"#);
 
-    // Second half as lines
-    if chart.series.len() > 1 {
+    // Second half as lines, split between the primary and secondary value axes
+    let lines: Vec<(usize, &ChartSeries)> = chart.series.iter().enumerate().skip(mid).collect();
+    let primary: Vec<_> = lines.iter().filter(|(_, s)| !s.secondary_axis).collect();
+    let secondary: Vec<_> = lines.iter().filter(|(_, s)| s.secondary_axis).collect();
+
+    let line_labels = series_data_labels(chart, "c:lineChart");
+    for (group, cat_ax, val_ax) in [(&primary, 1, 2), (&secondary, 4, 3)] {
+        if group.is_empty() {
+            continue;
+        }
         xml.push_str(r#"<c:lineChart>
 <c:grouping val="standard"/>"#);
-
-        for (idx, series) in chart.series.iter().skip(mid.max(1)).enumerate() {
-            xml.push_str(&generate_series_data(chart, mid + idx, &series.name, &series.values));
+        for (idx, series) in group.iter() {
+            xml.push_str(&generate_series_data(*idx, series, true, &line_labels));
         }
+        xml.push_str(&format!(
+            r#"
+<c:marker val="1"/>
+<c:axId val="{cat_ax}"/>
+<c:axId val="{val_ax}"/>
+</c:lineChart>"#
+        ));
+    }
 
-        xml.push_str("</c:lineChart>");
+    xml.push_str(&category_axis_xml(chart, 1, "b", 2, false));
+    xml.push_str(&value_axis_xml(&chart.value_axis, &chart.y_axis, chart.y_axis_title.as_deref(), 2, "l", 1, false));
+    if !secondary.is_empty() {
+        xml.push_str(&category_axis_xml(chart, 4, "b", 3, true));
+        xml.push_str(&value_axis_xml(&ValueAxisScale::default(), &AxisOptions::default(), None, 3, "r", 4, true));
     }
 
-    xml.push_str(chart_part_footer());
+    xml.push_str(&chart_part_footer(chart));
 
     xml
 }
@@ -725,7 +1014,7 @@ fn generate_combo_chart_xml(chart: &Chart) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::generator::charts::ChartSeries;
+    use crate::generator::charts::{ChartSeries, DataLabelPosition, LegendPosition, TickLabelPosition, TickMark};
 
     #[test]
     fn test_generate_bar_chart_xml() {
@@ -741,6 +1030,33 @@ mod tests {
         assert!(xml.contains("Sales"));
     }
 
+    #[test]
+    fn test_bar_direction_follows_chart_type() {
+        let chart = Chart::new(
+            "Sales",
+            ChartType::Bar,
+            vec!["Q1".to_string(), "Q2".to_string()],
+            0, 0, 5000000, 3750000,
+        ).add_series(ChartSeries::new("2024", vec![100.0, 150.0]));
+        let axis_sides = |xml: &str| -> Vec<String> {
+            xml.match_indices(r#"<c:axPos val=""#)
+                .map(|(at, tag)| xml[at + tag.len()..at + tag.len() + 1].to_string())
+                .collect()
+        };
+
+        // Column charts stand bars up from categories along the bottom
+        let xml = generate_bar_chart_xml(&chart);
+        assert!(xml.contains(r#"<c:barDir val="col"/>"#));
+        assert_eq!(axis_sides(&xml), ["b", "l"]);
+
+        // Horizontal bar charts run bars out from categories up the left side
+        let mut horizontal = chart;
+        horizontal.chart_type = ChartType::BarHorizontal;
+        let xml = generate_bar_chart_xml(&horizontal);
+        assert!(xml.contains(r#"<c:barDir val="bar"/>"#));
+        assert_eq!(axis_sides(&xml), ["l", "b"]);
+    }
+
     #[test]
     fn test_generate_line_chart_xml() {
         let chart = Chart::new(
@@ -765,5 +1081,321 @@ mod tests {
 
         let xml = generate_pie_chart_xml(&chart);
         assert!(xml.contains("pieChart"));
+        assert!(!xml.contains("<c:dPt>"));
+    }
+
+    /// Numbers in the `<c:{tag}>` cache of the first series
+    fn cached_values(xml: &str, tag: &str) -> Vec<String> {
+        let start = xml.find(&format!("<c:{tag}>")).unwrap();
+        let end = xml.find(&format!("</c:{tag}>")).unwrap();
+        xml[start..end]
+            .split("<c:v>")
+            .skip(1)
+            .map(|v| v[..v.find('<').unwrap()].to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_scatter_xy_points() {
+        let chart = |series: ChartSeries| {
+            generate_scatter_chart_xml(
+                &Chart::new("Sensor", ChartType::Scatter, vec![], 0, 0, 5000000, 3750000).add_series(series),
+            )
+        };
+
+        let xml = chart(ChartSeries::xy("Reading", vec![(0.5, 10.0), (2.25, 12.5), (7.0, 9.0)]));
+        assert_eq!(cached_values(&xml, "xVal"), ["0.5", "2.25", "7"]);
+        assert_eq!(cached_values(&xml, "yVal"), ["10", "12.5", "9"]);
+
+        // Series without X values keep the 1-based index
+        let xml = chart(ChartSeries::new("Reading", vec![10.0, 12.5]));
+        assert_eq!(cached_values(&xml, "xVal"), ["1", "2"]);
+    }
+
+    #[test]
+    fn test_xy_value_axes_cross_each_other() {
+        let axis_ids = |xml: &str| {
+            ["axId", "crossAx"].map(|tag| {
+                xml.split(&format!("<c:{tag} val=\""))
+                    .skip(1)
+                    .filter_map(|rest| rest.split('"').next())
+                    .map(str::to_string)
+                    .collect::<Vec<_>>()
+            })
+        };
+
+        let scatter = Chart::new("Sensor", ChartType::Scatter, vec![], 0, 0, 5000000, 3750000)
+            .add_series(ChartSeries::xy("Reading", vec![(0.5, 10.0), (2.0, 12.5)]));
+        let bubble = Chart::new("Teams", ChartType::Bubble, vec![], 0, 0, 5000000, 3750000)
+            .add_series(ChartSeries::new("Margin", vec![0.1, 0.25]));
+        for xml in [generate_scatter_chart_xml(&scatter), generate_bubble_chart_xml(&bubble)] {
+            let [ax_ids, cross_ax] = axis_ids(&xml);
+            assert_eq!(ax_ids, ["1", "2"]);
+            assert_eq!(cross_ax, ["2", "1"]);
+        }
+    }
+
+    #[test]
+    fn test_bubble_sizes() {
+        let bubble_sizes = |series: ChartSeries| {
+            let chart = Chart::new("Teams", ChartType::Bubble, vec![], 0, 0, 5000000, 3750000)
+                .add_series(series);
+            cached_values(&generate_bubble_chart_xml(&chart), "bubbleSize")
+        };
+
+        // Margin on the y axis, headcount as the bubble size
+        let explicit = ChartSeries::new("Margin", vec![-0.1, 0.25, 0.4]).with_bubble_sizes(vec![120.0, 45.0, 300.0]);
+        assert_eq!(bubble_sizes(explicit), ["120", "45", "300"]);
+
+        // Without sizes the magnitude of each value is used
+        assert_eq!(bubble_sizes(ChartSeries::new("Margin", vec![-0.1, 0.25])), ["0.1", "0.25"]);
+    }
+
+    #[test]
+    fn test_data_labels() {
+        let chart = |chart_type: ChartType| {
+            Chart::new("Sales", chart_type, vec!["Q1".to_string(), "Q2".to_string()], 0, 0, 5000000, 3750000)
+                .add_series(ChartSeries::new("2024", vec![1200.0, 15000.0]))
+        };
+        let values = DataLabelOptions {
+            show_value: true,
+            number_format: Some("#,##0".to_string()),
+            position: Some(DataLabelPosition::OutsideEnd),
+            ..Default::default()
+        };
+
+        let xml = generate_bar_chart_xml(&chart(ChartType::Bar).data_labels(values.clone()));
+        assert!(xml.contains(r##"<c:numFmt formatCode="#,##0" sourceLinked="0"/>
+<c:dLblPos val="outEnd"/>"##));
+        assert!(xml.contains(r#"<c:showVal val="1"/>"#));
+        assert!(xml.contains(r#"<c:showCatName val="0"/>"#));
+
+        // Area charts take no label position
+        let xml = generate_area_chart_xml(&chart(ChartType::Area).data_labels(values.clone()));
+        assert!(xml.contains(r#"<c:showVal val="1"/>"#));
+        assert!(!xml.contains("<c:dLblPos"));
+
+        let xml = generate_scatter_chart_xml(&chart(ChartType::Scatter).data_labels(values));
+        assert!(xml.contains(r#"<c:showVal val="1"/>"#));
+
+        // Unset keeps the old output: no labels, or category and percent on pies
+        let xml = generate_bar_chart_xml(&chart(ChartType::Bar));
+        assert!(xml.contains("<c:dLbls>\n<c:showVal val=\"0\"/>\n</c:dLbls>"));
+        assert!(!generate_scatter_chart_xml(&chart(ChartType::Scatter)).contains("<c:dLbls>"));
+        let xml = generate_pie_chart_xml(&chart(ChartType::Pie));
+        assert!(xml.contains(r#"<c:showCatName val="1"/>"#));
+        assert!(!xml.contains("<c:showVal"));
+    }
+
+    #[test]
+    fn test_title_font_axis_titles_and_legend() {
+        let chart = Chart::new("Revenue", ChartType::Bar, vec!["Q1".to_string()], 0, 0, 5000000, 3750000)
+            .add_series(ChartSeries::new("2024", vec![100.0]));
+
+        // Defaults are unchanged
+        let xml = generate_bar_chart_xml(&chart);
+        assert!(xml.contains(r#"sz="1800" b="0""#));
+        assert!(xml.contains(r#"<c:legendPos val="r"/>"#));
+        let titles = xml.matches("<c:title>").count();
+
+        let xml = generate_bar_chart_xml(
+            &chart
+                .title_font(ChartFont::new().size(24).bold().color("404040"))
+                .axis_title("Quarter", "Revenue ($)")
+                .legend_position(LegendPosition::Bottom),
+        );
+        assert!(xml.contains(r#"sz="2400" b="1""#));
+        assert!(xml.contains(r#"<a:srgbClr val="404040"/>"#));
+        assert!(xml.contains(r#"<c:legendPos val="b"/>"#));
+        assert_eq!(xml.matches("<c:title>").count(), titles + 2);
+        assert!(xml.contains("<a:t>Quarter</a:t>"));
+        assert!(xml.contains("<a:t>Revenue ($)</a:t>"));
+        // The title sits between the gridlines and the number format
+        let val_ax = &xml[xml.find("<c:valAx>").unwrap()..];
+        assert!(val_ax.find("<c:majorGridlines/>").unwrap() < val_ax.find("<c:title>").unwrap());
+        assert!(val_ax.find("<c:title>").unwrap() < val_ax.find("<c:numFmt").unwrap());
+    }
+
+    #[test]
+    fn test_series_color_emits_sp_pr() {
+        let chart = Chart::new(
+            "Sales",
+            ChartType::Bar,
+            vec!["Q1".to_string(), "Q2".to_string()],
+            0, 0, 5000000, 3750000,
+        )
+        .add_series(ChartSeries::new("2024", vec![100.0, 150.0]).with_color("4472C4"))
+        .add_series(ChartSeries::new("2025", vec![120.0, 160.0]));
+
+        let xml = generate_bar_chart_xml(&chart);
+        assert_eq!(xml.matches("<c:spPr>").count(), 1);
+        assert!(xml.contains(r#"<c:spPr><a:solidFill><a:srgbClr val="4472C4"/></a:solidFill></c:spPr>"#));
+    }
+
+    #[test]
+    fn test_line_series_color_on_stroke() {
+        let chart = Chart::new("Trend", ChartType::Line, vec!["Jan".to_string()], 0, 0, 5000000, 3750000)
+            .add_series(ChartSeries::new("Revenue", vec![1000.0]).with_color("ED7D31"));
+
+        let xml = generate_line_chart_xml(&chart);
+        assert!(xml.contains(r#"<a:ln w="28575"><a:solidFill><a:srgbClr val="ED7D31"/>"#));
+    }
+
+    #[test]
+    fn test_series_theme_color() {
+        let chart = Chart::new("Sales", ChartType::Bar, vec!["Q1".to_string()], 0, 0, 5000000, 3750000)
+            .add_series(ChartSeries::new("2024", vec![100.0]).with_color("accent1"));
+
+        let xml = generate_bar_chart_xml(&chart);
+        assert!(xml.contains(r#"<c:spPr><a:solidFill><a:schemeClr val="accent1"/></a:solidFill></c:spPr>"#));
+    }
+
+    #[test]
+    fn test_pie_point_colors_emit_dpt() {
+        let chart = Chart::new(
+            "Distribution",
+            ChartType::Pie,
+            vec!["A".to_string(), "B".to_string()],
+            0, 0, 5000000, 3750000,
+        )
+        .add_series(
+            ChartSeries::new("Data", vec![30.0, 70.0])
+                .with_colors(vec!["FF0000".to_string(), "00B050".to_string()]),
+        );
+
+        let xml = generate_pie_chart_xml(&chart);
+        assert_eq!(xml.matches("<c:dPt>").count(), 2);
+        assert!(xml.contains(r#"<c:idx val="1"/>
+<c:bubble3D val="0"/>
+<c:spPr><a:solidFill><a:srgbClr val="00B050"/>"#));
+    }
+
+    #[test]
+    fn test_pie_explosion_and_first_slice_angle() {
+        let pie = |series: ChartSeries| {
+            Chart::new("Share", ChartType::Pie, vec!["A".into(), "B".into(), "C".into()], 0, 0, 5000000, 3750000)
+                .add_series(series)
+        };
+
+        let xml = generate_pie_chart_xml(&pie(ChartSeries::new("Data", vec![20.0, 50.0, 30.0])));
+        assert!(xml.contains("</c:ser>\n<c:firstSliceAng val=\"0\"/></c:pieChart>"));
+        assert!(!xml.contains("<c:explosion"));
+
+        // Pull out the largest slice and rotate it to start at the top
+        let chart = pie(ChartSeries::new("Data", vec![20.0, 50.0, 30.0]).explode_slice(1, 15)).first_slice_angle(288);
+        let xml = generate_pie_chart_xml(&chart);
+        assert_eq!(xml.matches("<c:dPt>").count(), 1);
+        assert!(xml.contains("<c:idx val=\"1\"/>\n<c:bubble3D val=\"0\"/>\n<c:explosion val=\"15\"/>\n</c:dPt>"));
+        assert!(xml.contains(r#"<c:firstSliceAng val="288"/>"#));
+
+        let xml = generate_pie_chart_xml(&pie(ChartSeries::new("Data", vec![1.0, 2.0]).explode_all(10)));
+        assert!(xml.contains("</c:title>\n<c:explosion val=\"10\"/>"));
+
+        // Doughnut keeps schema order: series, first slice angle, hole size
+        let mut doughnut = chart.first_slice_angle(90);
+        doughnut.chart_type = ChartType::Doughnut;
+        let xml = generate_doughnut_chart_xml(&doughnut);
+        assert!(xml.contains("</c:ser>\n<c:firstSliceAng val=\"90\"/>\n<c:holeSize val=\"50\"/></c:doughnutChart>"));
+    }
+
+    #[test]
+    fn test_axis_gridlines_and_ticks() {
+        let chart = Chart::new("Trend", ChartType::Line, vec!["Jan".to_string()], 0, 0, 5000000, 3750000)
+            .add_series(ChartSeries::new("Revenue", vec![1000.0]));
+
+        // Defaults keep major gridlines on both axes and no explicit tick marks
+        let xml = generate_line_chart_xml(&chart);
+        assert_eq!(xml.matches("<c:majorGridlines/>").count(), 2);
+        assert!(!xml.contains("TickMark"));
+
+        // Clean chart: no gridlines, tick marks only on the value axis
+        let xml = generate_line_chart_xml(
+            &chart
+                .x_axis(AxisOptions {
+                    major_gridlines: false,
+                    major_tick: Some(TickMark::None),
+                    ..Default::default()
+                })
+                .y_axis(AxisOptions {
+                    major_gridlines: false,
+                    major_tick: Some(TickMark::Outside),
+                    minor_tick: Some(TickMark::Inside),
+                    tick_label_position: Some(TickLabelPosition::NextTo),
+                    ..Default::default()
+                }),
+        );
+        assert!(!xml.contains("Gridlines"));
+        let cat_ax = &xml[xml.find("<c:catAx>").unwrap()..xml.find("</c:catAx>").unwrap()];
+        assert!(cat_ax.contains("<c:majorTickMark val=\"none\"/>\n<c:tickLblPos val=\"low\"/>"));
+        let val_ax = &xml[xml.find("<c:valAx>").unwrap()..];
+        assert!(val_ax.contains(
+            "<c:majorTickMark val=\"out\"/>\n<c:minorTickMark val=\"in\"/>\n<c:tickLblPos val=\"nextTo\"/>"
+        ));
+    }
+
+    #[test]
+    fn test_combo_chart_secondary_axis() {
+        let chart = Chart::new(
+            "Revenue vs Margin",
+            ChartType::Combo,
+            vec!["Q1".to_string(), "Q2".to_string()],
+            0, 0, 5000000, 3750000,
+        )
+        .add_series(ChartSeries::new("Revenue", vec![1200.0, 1500.0]))
+        .add_series(ChartSeries::new("Margin", vec![0.12, 0.18]).on_secondary_axis(true));
+
+        let xml = generate_combo_chart_xml(&chart);
+        assert_eq!(xml.matches("<c:valAx>").count(), 2);
+        assert_eq!(xml.matches("<c:catAx>").count(), 2);
+        assert!(xml.contains(r#"<c:axId val="4"/>
+<c:axId val="3"/>
+</c:lineChart>"#));
+        assert!(xml.contains(r#"<c:axId val="3"/>
+<c:scaling>
+<c:orientation val="minMax"/>
+</c:scaling>
+<c:delete val="0"/>
+<c:axPos val="r"/>"#));
+        assert!(xml.contains(r#"<c:crosses val="max"/>"#));
+    }
+
+    #[test]
+    fn test_combo_chart_without_secondary_axis() {
+        let chart = Chart::new("Combo", ChartType::Combo, vec!["Q1".to_string()], 0, 0, 5000000, 3750000)
+            .add_series(ChartSeries::new("Bars", vec![1.0]))
+            .add_series(ChartSeries::new("Line", vec![2.0]));
+
+        let xml = generate_combo_chart_xml(&chart);
+        assert_eq!(xml.matches("<c:valAx>").count(), 1);
+        assert!(!xml.contains(r#"<c:crosses val="max"/>"#));
+    }
+
+    #[test]
+    fn test_value_axis_scale() {
+        let chart = Chart::new("Growth", ChartType::Line, vec!["Q1".to_string()], 0, 0, 5000000, 3750000)
+            .add_series(ChartSeries::new("Rate", vec![0.25]))
+            .value_axis_bounds(0.0, 1.0)
+            .major_unit(0.25)
+            .value_axis_number_format("0.0%");
+
+        let xml = generate_line_chart_xml(&chart);
+        assert!(xml.contains(r#"<c:orientation val="minMax"/>
+<c:max val="1"/>
+<c:min val="0"/>
+</c:scaling>"#));
+        assert!(xml.contains(r#"<c:majorUnit val="0.25"/>"#));
+        assert!(xml.contains(r#"<c:numFmt formatCode="0.0%" sourceLinked="0"/>"#));
+    }
+
+    #[test]
+    fn test_value_axis_default_unchanged() {
+        let chart = Chart::new("Plain", ChartType::Bar, vec!["Q1".to_string()], 0, 0, 5000000, 3750000)
+            .add_series(ChartSeries::new("Data", vec![1.0]));
+
+        let xml = generate_bar_chart_xml(&chart);
+        assert!(!xml.contains("<c:max"));
+        assert!(!xml.contains("<c:majorUnit"));
+        assert!(xml.contains(r#"<c:orientation val="minMax"/>
+</c:scaling>"#));
     }
 }
@@ -2,10 +2,12 @@
 //!
 //! Provides connector types and XML generation for connecting shapes.
 
+use crate::elements::{color_value_xml, RgbInput};
 use crate::core::escape_xml;
 
 /// Connector types available in PPTX
 #[derive(Clone, Debug, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ConnectorType {
     /// Straight line connector
     Straight,
@@ -37,6 +39,7 @@ impl ConnectorType {
 
 /// Arrow head types for connectors
 #[derive(Clone, Debug, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ArrowType {
     /// No arrow
     None,
@@ -68,6 +71,7 @@ impl ArrowType {
 
 /// Arrow size
 #[derive(Clone, Debug, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ArrowSize {
     Small,
     Medium,
@@ -87,6 +91,7 @@ impl ArrowSize {
 
 /// Connection point on a shape
 #[derive(Clone, Debug, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ConnectionSite {
     /// Top center
     Top,
@@ -127,6 +132,7 @@ impl ConnectionSite {
 
 /// Connector line style
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ConnectorLine {
     /// Line color (RGB hex)
     pub color: String,
@@ -148,9 +154,9 @@ impl Default for ConnectorLine {
 
 impl ConnectorLine {
     /// Create new connector line
-    pub fn new(color: &str, width: u32) -> Self {
+    pub fn new(color: impl Into<RgbInput>, width: u32) -> Self {
         ConnectorLine {
-            color: color.trim_start_matches('#').to_uppercase(),
+            color: color.into().into_value(),
             width,
             dash: LineDash::Solid,
         }
@@ -165,6 +171,7 @@ impl ConnectorLine {
 
 /// Line dash styles
 #[derive(Clone, Debug, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum LineDash {
     Solid,
     Dash,
@@ -192,6 +199,7 @@ impl LineDash {
 
 /// Connector definition
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Connector {
     /// Connector type
     pub connector_type: ConnectorType,
@@ -272,8 +280,8 @@ impl Connector {
     }
 
     /// Set line color
-    pub fn with_color(mut self, color: &str) -> Self {
-        self.line.color = color.trim_start_matches('#').to_uppercase();
+    pub fn with_color(mut self, color: impl Into<RgbInput>) -> Self {
+        self.line.color = color.into().into_value();
         self
     }
 
@@ -399,14 +407,14 @@ pub fn generate_connector_xml(connector: &Connector, shape_id: usize) -> String
 </a:prstGeom>
 <a:ln w="{}">
 <a:solidFill>
-<a:srgbClr val="{}"/>
+{}
 </a:solidFill>
 <a:prstDash val="{}"/>"#,
         flip_h, flip_v,
         x, y, cx, cy,
         connector.connector_type.preset_name(),
         connector.line.width,
-        connector.line.color,
+        color_value_xml(&connector.line.color, ""),
         connector.line.dash.xml_value()
     ));
 
@@ -444,7 +452,7 @@ pub fn generate_connector_xml(connector: &Connector, shape_id: usize) -> String
 <a:lstStyle/>
 <a:p>
 <a:r>
-<a:rPr lang="en-US" sz="1000"/>
+<a:rPr lang="en-US" sz="1000" dirty="0"/>
 <a:t>{}</a:t>
 </a:r>
 </a:p>
@@ -510,6 +518,13 @@ mod tests {
         assert!(xml.contains("tailEnd"));
     }
 
+    #[test]
+    fn test_connector_theme_color() {
+        let xml = generate_connector_xml(&Connector::straight(0, 0, 1000000, 500000).with_color("accent2"), 1);
+        assert!(xml.contains(r#"<a:schemeClr val="accent2"/>"#));
+        assert!(!xml.contains("srgbClr"));
+    }
+
     #[test]
     fn test_connector_with_label() {
         let conn = Connector::straight(0, 0, 1000000, 500000)
@@ -0,0 +1,322 @@
+//! SmartArt-style diagrams built from grouped shapes
+//!
+//! A [`Diagram`] lays out rounded rectangles and arrow connectors in a region of
+//! the slide and returns them as one [`ShapeGroup`]. No `dgm` parts are written,
+//! so the result edits like ordinary shapes.
+
+use crate::elements::RgbInput;
+use super::connectors::{ArrowType, Connector, ConnectorLine};
+use super::shape_group::ShapeGroup;
+use super::shapes::{Shape, ShapeFill, ShapeType};
+
+/// Space between process steps, as a fraction of the step width
+const PROCESS_GAP: f64 = 0.35;
+/// Space between hierarchy levels, as a fraction of the box height
+const LEVEL_GAP: f64 = 0.6;
+/// Gap left between a connector end and the box it points at
+const ARROW_PAD: f64 = 45_720.0;
+
+/// A box in a hierarchy diagram and the boxes below it
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DiagramNode {
+    pub text: String,
+    pub children: Vec<DiagramNode>,
+}
+
+impl DiagramNode {
+    pub fn new(text: &str) -> Self {
+        DiagramNode { text: text.to_string(), children: Vec::new() }
+    }
+
+    /// Add a child box
+    pub fn child(mut self, child: DiagramNode) -> Self {
+        self.children.push(child);
+        self
+    }
+
+    /// Add leaf children from their labels
+    pub fn children(mut self, labels: Vec<&str>) -> Self {
+        self.children.extend(labels.into_iter().map(DiagramNode::new));
+        self
+    }
+
+    fn depth(&self) -> usize {
+        1 + self.children.iter().map(|c| c.depth()).max().unwrap_or(0)
+    }
+
+    fn leaf_count(&self) -> usize {
+        if self.children.is_empty() {
+            1
+        } else {
+            self.children.iter().map(|c| c.leaf_count()).sum()
+        }
+    }
+}
+
+/// Diagram layout
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DiagramKind {
+    /// Steps left to right, each pointing at the next
+    Process(Vec<String>),
+    /// Steps clockwise around an ellipse, the last pointing back at the first
+    Cycle(Vec<String>),
+    /// Tree with the root on top, joined by org-chart style lines
+    Hierarchy(DiagramNode),
+}
+
+/// Process, cycle, or hierarchy diagram placed in a slide region
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Diagram {
+    pub kind: DiagramKind,
+    /// Region on the slide in EMU
+    pub x: u32,
+    pub y: u32,
+    pub width: u32,
+    pub height: u32,
+    /// Box fill (RGB hex)
+    pub fill: String,
+    /// Connector color (RGB hex)
+    pub line_color: String,
+}
+
+impl Diagram {
+    fn new(kind: DiagramKind) -> Self {
+        Diagram {
+            kind,
+            x: 457_200,
+            y: 1_600_200,
+            width: 8_229_600,
+            height: 4_572_000,
+            fill: "4472C4".to_string(),
+            line_color: "7F7F7F".to_string(),
+        }
+    }
+
+    /// Horizontal process: one box per step with arrows between them
+    pub fn process(steps: Vec<&str>) -> Self {
+        Self::new(DiagramKind::Process(steps.into_iter().map(String::from).collect()))
+    }
+
+    /// Cycle: boxes around an ellipse with arrows running clockwise
+    pub fn cycle(steps: Vec<&str>) -> Self {
+        Self::new(DiagramKind::Cycle(steps.into_iter().map(String::from).collect()))
+    }
+
+    /// Hierarchy (org chart) growing down from `root`
+    pub fn hierarchy(root: DiagramNode) -> Self {
+        Self::new(DiagramKind::Hierarchy(root))
+    }
+
+    /// Place the diagram in a region of the slide (EMU)
+    pub fn region(mut self, x: u32, y: u32, width: u32, height: u32) -> Self {
+        self.x = x;
+        self.y = y;
+        self.width = width;
+        self.height = height;
+        self
+    }
+
+    /// Set the box fill color
+    pub fn fill(mut self, color: impl Into<RgbInput>) -> Self {
+        self.fill = color.into().into_value();
+        self
+    }
+
+    /// Set the connector color
+    pub fn line_color(mut self, color: impl Into<RgbInput>) -> Self {
+        self.line_color = color.into().into_value();
+        self
+    }
+
+    /// Lay out the boxes and connectors as a group filling the region
+    pub fn build(&self) -> ShapeGroup {
+        let mut group = ShapeGroup::new(self.x, self.y, self.width, self.height).with_name(match self.kind {
+            DiagramKind::Process(_) => "Process Diagram",
+            DiagramKind::Cycle(_) => "Cycle Diagram",
+            DiagramKind::Hierarchy(_) => "Hierarchy Diagram",
+        });
+        let (w, h) = (self.width as f64, self.height as f64);
+        match &self.kind {
+            DiagramKind::Process(steps) => self.layout_process(&mut group, steps, w, h),
+            DiagramKind::Cycle(steps) => self.layout_cycle(&mut group, steps, w, h),
+            DiagramKind::Hierarchy(root) => {
+                let levels = root.depth() as f64;
+                let box_h = h / (levels + (levels - 1.0) * LEVEL_GAP);
+                let box_w = (w / root.leaf_count() as f64) * 0.8;
+                self.layout_node(&mut group, root, 0.0, w, 0.0, (box_w, box_h));
+            }
+        }
+        group
+    }
+
+    fn step_box(&self, text: &str, center: (f64, f64), size: (f64, f64)) -> Shape {
+        let x = (center.0 - size.0 / 2.0).max(0.0);
+        let y = (center.1 - size.1 / 2.0).max(0.0);
+        Shape::new(ShapeType::RoundedRectangle, x as u32, y as u32, size.0 as u32, size.1 as u32)
+            .with_fill(ShapeFill::new(&self.fill))
+            .with_text(text)
+    }
+
+    fn line(&self, from: (f64, f64), to: (f64, f64), arrow: bool) -> Connector {
+        let connector = Connector::straight(from.0 as u32, from.1 as u32, to.0 as u32, to.1 as u32)
+            .with_line(ConnectorLine::new(&self.line_color, 19_050));
+        if arrow {
+            connector.with_end_arrow(ArrowType::Triangle)
+        } else {
+            connector
+        }
+    }
+
+    fn layout_process(&self, group: &mut ShapeGroup, steps: &[String], w: f64, h: f64) {
+        let n = steps.len() as f64;
+        let box_w = w / (n + (n - 1.0).max(0.0) * PROCESS_GAP);
+        let box_h = h.min(box_w * 0.75);
+        let gap = box_w * PROCESS_GAP;
+        let mid_y = h / 2.0;
+        for (i, step) in steps.iter().enumerate() {
+            let left = i as f64 * (box_w + gap);
+            group.shapes.push(self.step_box(step, (left + box_w / 2.0, mid_y), (box_w, box_h)));
+            if i + 1 < steps.len() {
+                let from = (left + box_w + ARROW_PAD, mid_y);
+                let to = (left + box_w + gap - ARROW_PAD, mid_y);
+                group.connectors.push(self.line(from, to, true));
+            }
+        }
+    }
+
+    fn layout_cycle(&self, group: &mut ShapeGroup, steps: &[String], w: f64, h: f64) {
+        let n = steps.len();
+        let size = (w * 0.28, h * 0.2);
+        let (rx, ry) = ((w - size.0) / 2.0, (h - size.1) / 2.0);
+        let centers: Vec<(f64, f64)> = (0..n)
+            .map(|i| {
+                // Start at twelve o'clock and run clockwise
+                let angle = (i as f64 / n as f64) * std::f64::consts::TAU - std::f64::consts::FRAC_PI_2;
+                (w / 2.0 + rx * angle.cos(), h / 2.0 + ry * angle.sin())
+            })
+            .collect();
+        for (step, &center) in steps.iter().zip(&centers) {
+            group.shapes.push(self.step_box(step, center, size));
+        }
+        if n < 2 {
+            return;
+        }
+        for i in 0..n {
+            let (a, b) = (centers[i], centers[(i + 1) % n]);
+            let (dx, dy) = (b.0 - a.0, b.1 - a.1);
+            let len = (dx * dx + dy * dy).sqrt();
+            if len == 0.0 {
+                continue;
+            }
+            let (ux, uy) = (dx / len, dy / len);
+            // Distance from a box center to its edge along the connector
+            let edge = (size.0 / 2.0 / ux.abs().max(f64::EPSILON)).min(size.1 / 2.0 / uy.abs().max(f64::EPSILON));
+            let inset = edge + ARROW_PAD;
+            if 2.0 * inset >= len {
+                continue;
+            }
+            let from = (a.0 + ux * inset, a.1 + uy * inset);
+            let to = (b.0 - ux * inset, b.1 - uy * inset);
+            group.connectors.push(self.line(from, to, true));
+        }
+    }
+
+    /// Place `node` centered over the horizontal span `[left, left + span)` at `top`,
+    /// then its children side by side below, sized by their leaf counts
+    fn layout_node(&self, group: &mut ShapeGroup, node: &DiagramNode, left: f64, span: f64, top: f64, size: (f64, f64)) {
+        let box_h = size.1;
+        let center_x = left + span / 2.0;
+        group.shapes.push(self.step_box(&node.text, (center_x, top + box_h / 2.0), size));
+        if node.children.is_empty() {
+            return;
+        }
+
+        let child_top = top + box_h * (1.0 + LEVEL_GAP);
+        let bar_y = top + box_h * (1.0 + LEVEL_GAP / 2.0);
+        group.connectors.push(self.line((center_x, top + box_h), (center_x, bar_y), false));
+
+        let leaves = node.leaf_count() as f64;
+        let mut child_left = left;
+        let mut child_centers = Vec::with_capacity(node.children.len());
+        for child in &node.children {
+            let child_span = span * child.leaf_count() as f64 / leaves;
+            child_centers.push(child_left + child_span / 2.0);
+            self.layout_node(group, child, child_left, child_span, child_top, size);
+            child_left += child_span;
+        }
+        if let (Some(&first), Some(&last)) = (child_centers.first(), child_centers.last())
+            && last > first {
+                group.connectors.push(self.line((first, bar_y), (last, bar_y), false));
+            }
+        for x in child_centers {
+            group.connectors.push(self.line((x, bar_y), (x, child_top), false));
+        }
+    }
+}
+
+impl From<Diagram> for ShapeGroup {
+    fn from(diagram: Diagram) -> Self {
+        diagram.build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_five_step_process_bar() {
+        let group = Diagram::process(vec!["Plan", "Build", "Test", "Ship", "Learn"])
+            .region(0, 0, 9_000_000, 1_000_000)
+            .build();
+        assert_eq!(group.shapes.len(), 5);
+        assert_eq!(group.connectors.len(), 4);
+        assert_eq!((group.width, group.height), (9_000_000, 1_000_000));
+
+        // Evenly spaced boxes of one size, the last ending at the region's right edge
+        let step = group.shapes[1].x - group.shapes[0].x;
+        assert!(group.shapes.windows(2).all(|w| w[1].x - w[0].x == step || w[1].x - w[0].x == step + 1));
+        let last = &group.shapes[4];
+        assert!((last.x + last.width).abs_diff(9_000_000) <= 5);
+        assert_eq!(group.shapes[2].text.as_deref(), Some("Test"));
+
+        // Each arrow runs between neighbouring boxes
+        let arrow = &group.connectors[0];
+        assert_eq!(arrow.end_arrow, ArrowType::Triangle);
+        assert!(arrow.start_x > group.shapes[0].x + group.shapes[0].width);
+        assert!(arrow.end_x < group.shapes[1].x);
+    }
+
+    #[test]
+    fn test_cycle_closes_the_loop() {
+        let group = Diagram::cycle(vec!["Plan", "Do", "Check", "Act"]).build();
+        assert_eq!(group.shapes.len(), 4);
+        assert_eq!(group.connectors.len(), 4);
+        // First step sits at the top center
+        let top = &group.shapes[0];
+        assert_eq!(top.y, 0);
+        assert!((top.x + top.width / 2).abs_diff(group.width / 2) <= 1);
+    }
+
+    #[test]
+    fn test_hierarchy_layout() {
+        let root = DiagramNode::new("CEO")
+            .child(DiagramNode::new("CTO").children(vec!["Dev", "Ops"]))
+            .child(DiagramNode::new("CFO"));
+        let group = Diagram::hierarchy(root).region(0, 0, 6_000_000, 3_000_000).build();
+        assert_eq!(group.shapes.len(), 5);
+        // Root stem, bar and two drops under the CEO; the same under the CTO
+        assert_eq!(group.connectors.len(), 8);
+        assert!(group.connectors.iter().all(|c| c.end_arrow == ArrowType::None));
+
+        let ceo = &group.shapes[0];
+        let dev = &group.shapes[2];
+        assert_eq!(ceo.y, 0);
+        assert!(dev.y > ceo.y + ceo.height);
+        assert!(dev.y + dev.height <= 3_000_000);
+        assert_eq!(group.id_count(), 1 + 5 + 8);
+    }
+}
@@ -2,8 +2,11 @@
 //!
 //! Provides gradient types and XML generation for shape fills.
 
+use crate::elements::{color_value_xml, RgbInput};
+
 /// Gradient types
 #[derive(Clone, Debug, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum GradientType {
     /// Linear gradient
     Linear,
@@ -11,7 +14,7 @@ pub enum GradientType {
     Radial,
     /// Rectangular gradient
     Rectangular,
-    /// Path gradient
+    /// Path gradient following the shape outline
     Path,
 }
 
@@ -22,13 +25,14 @@ impl GradientType {
             GradientType::Linear => "lin",
             GradientType::Radial => "circle",
             GradientType::Rectangular => "rect",
-            GradientType::Path => "path",
+            GradientType::Path => "shape",
         }
     }
 }
 
 /// Gradient direction for linear gradients (in degrees)
 #[derive(Clone, Debug, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum GradientDirection {
     /// Left to right (0°)
     Horizontal,
@@ -43,20 +47,21 @@ pub enum GradientDirection {
 }
 
 impl GradientDirection {
-    /// Get angle in 60000ths of a degree (OOXML format)
+    /// Get angle in 60000ths of a degree (OOXML format), normalized to 0-359°
     pub fn angle(&self) -> u32 {
         match self {
             GradientDirection::Horizontal => 0,
             GradientDirection::Vertical => 5400000,
             GradientDirection::DiagonalDown => 2700000,
             GradientDirection::DiagonalUp => 18900000,
-            GradientDirection::Custom(deg) => deg * 60000,
+            GradientDirection::Custom(deg) => (deg % 360) * 60000,
         }
     }
 }
 
 /// A color stop in a gradient
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GradientStop {
     /// Position (0-100000, where 100000 = 100%)
     pub position: u32,
@@ -68,26 +73,26 @@ pub struct GradientStop {
 
 impl GradientStop {
     /// Create a new gradient stop
-    pub fn new(position: u32, color: &str) -> Self {
+    pub fn new(position: u32, color: impl Into<RgbInput>) -> Self {
         GradientStop {
             position: position.min(100000),
-            color: color.trim_start_matches('#').to_uppercase(),
+            color: color.into().into_value(),
             transparency: None,
         }
     }
 
     /// Create stop at start (0%)
-    pub fn start(color: &str) -> Self {
+    pub fn start(color: impl Into<RgbInput>) -> Self {
         Self::new(0, color)
     }
 
     /// Create stop at middle (50%)
-    pub fn middle(color: &str) -> Self {
+    pub fn middle(color: impl Into<RgbInput>) -> Self {
         Self::new(50000, color)
     }
 
     /// Create stop at end (100%)
-    pub fn end(color: &str) -> Self {
+    pub fn end(color: impl Into<RgbInput>) -> Self {
         Self::new(100000, color)
     }
 
@@ -100,6 +105,7 @@ impl GradientStop {
 
 /// Gradient fill definition
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GradientFill {
     /// Gradient type
     pub gradient_type: GradientType,
@@ -135,14 +141,14 @@ impl GradientFill {
     }
 
     /// Create a simple two-color gradient
-    pub fn two_color(start_color: &str, end_color: &str) -> Self {
+    pub fn two_color(start_color: impl Into<RgbInput>, end_color: impl Into<RgbInput>) -> Self {
         Self::linear(GradientDirection::Vertical)
             .add_stop(GradientStop::start(start_color))
             .add_stop(GradientStop::end(end_color))
     }
 
     /// Create a three-color gradient
-    pub fn three_color(start_color: &str, middle_color: &str, end_color: &str) -> Self {
+    pub fn three_color(start_color: impl Into<RgbInput>, middle_color: impl Into<RgbInput>, end_color: impl Into<RgbInput>) -> Self {
         Self::linear(GradientDirection::Vertical)
             .add_stop(GradientStop::start(start_color))
             .add_stop(GradientStop::middle(middle_color))
@@ -245,16 +251,11 @@ pub fn generate_gradient_fill_xml(gradient: &GradientFill) -> String {
     // Generate gradient stop list
     xml.push_str("<a:gsLst>");
     for stop in &gradient.stops {
-        xml.push_str(&format!(
-            r#"<a:gs pos="{}"><a:srgbClr val="{}""#,
-            stop.position, stop.color
-        ));
-
-        if let Some(alpha) = stop.transparency {
-            xml.push_str(&format!(r#"><a:alpha val="{}"/></a:srgbClr>"#, 100000 - alpha));
-        } else {
-            xml.push_str("/>");
-        }
+        let alpha = stop
+            .transparency
+            .map(|alpha| format!(r#"<a:alpha val="{}"/>"#, 100000 - alpha))
+            .unwrap_or_default();
+        xml.push_str(&format!(r#"<a:gs pos="{}">{}"#, stop.position, color_value_xml(&stop.color, &alpha)));
 
         xml.push_str("</a:gs>");
     }
@@ -295,6 +296,8 @@ mod tests {
         assert_eq!(GradientDirection::Horizontal.angle(), 0);
         assert_eq!(GradientDirection::Vertical.angle(), 5400000);
         assert_eq!(GradientDirection::Custom(45).angle(), 2700000);
+        assert_eq!(GradientDirection::Custom(405).angle(), 2700000);
+        assert_eq!(GradientDirection::Custom(360).angle(), 0);
     }
 
     #[test]
@@ -352,4 +355,10 @@ mod tests {
         assert!(xml.contains("path"));
         assert!(xml.contains("circle"));
     }
+
+    #[test]
+    fn test_path_gradient_uses_shape_path_type() {
+        let gradient = GradientFill::new(GradientType::Path).add_stop(GradientStop::start("FFFFFF"));
+        assert!(generate_gradient_fill_xml(&gradient).contains(r#"<a:path path="shape">"#));
+    }
 }
@@ -4,13 +4,21 @@
 
 use crate::core::escape_xml;
 
+/// Relationship type for a jump to another slide in the deck
+const SLIDE_RELATIONSHIP_TYPE: &str = "http://schemas.openxmlformats.org/officeDocument/2006/relationships/slide";
+/// Relationship type for URL, email, and file links
+const HYPERLINK_RELATIONSHIP_TYPE: &str = "http://schemas.openxmlformats.org/officeDocument/2006/relationships/hyperlink";
+
 /// Hyperlink action types
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum HyperlinkAction {
     /// Link to external URL
     Url(String),
-    /// Link to another slide in the presentation
+    /// Link to another slide in the presentation, by 1-based slide number
     Slide(u32),
+    /// Link to another slide in the presentation, by 0-based position in the deck
+    JumpToSlide(usize),
     /// Link to first slide
     FirstSlide,
     /// Link to last slide
@@ -19,6 +27,8 @@ pub enum HyperlinkAction {
     NextSlide,
     /// Link to previous slide
     PreviousSlide,
+    /// Link back to the slide shown before the current one
+    LastSlideViewed,
     /// Link to end show
     EndShow,
     /// Link to email address
@@ -33,11 +43,25 @@ impl HyperlinkAction {
         HyperlinkAction::Url(url.to_string())
     }
 
-    /// Create slide hyperlink
+    /// Create a link to 1-based slide number `slide_num`, so `slide(1)` is the first slide
     pub fn slide(slide_num: u32) -> Self {
         HyperlinkAction::Slide(slide_num)
     }
 
+    /// Create a link to the slide at 0-based `index` in the deck, so `jump_to_slide(0)` is the first slide
+    pub fn jump_to_slide(index: usize) -> Self {
+        HyperlinkAction::JumpToSlide(index)
+    }
+
+    /// 1-based number of the slide this action jumps to, for slide links
+    pub fn target_slide_number(&self) -> Option<usize> {
+        match self {
+            HyperlinkAction::Slide(num) => Some(*num as usize),
+            HyperlinkAction::JumpToSlide(index) => Some(index + 1),
+            _ => None,
+        }
+    }
+
     /// Create email hyperlink
     pub fn email(address: &str) -> Self {
         HyperlinkAction::Email {
@@ -64,10 +88,12 @@ impl HyperlinkAction {
         match self {
             HyperlinkAction::Url(url) => url.clone(),
             HyperlinkAction::Slide(num) => format!("slide{}.xml", num),
+            HyperlinkAction::JumpToSlide(index) => format!("slide{}.xml", index + 1),
             HyperlinkAction::FirstSlide => "ppaction://hlinkshowjump?jump=firstslide".to_string(),
             HyperlinkAction::LastSlide => "ppaction://hlinkshowjump?jump=lastslide".to_string(),
             HyperlinkAction::NextSlide => "ppaction://hlinkshowjump?jump=nextslide".to_string(),
             HyperlinkAction::PreviousSlide => "ppaction://hlinkshowjump?jump=previousslide".to_string(),
+            HyperlinkAction::LastSlideViewed => "ppaction://hlinkshowjump?jump=lastslideviewed".to_string(),
             HyperlinkAction::EndShow => "ppaction://hlinkshowjump?jump=endshow".to_string(),
             HyperlinkAction::Email { address, subject } => {
                 let mut mailto = format!("mailto:{}", address);
@@ -88,13 +114,29 @@ impl HyperlinkAction {
         )
     }
 
+    /// Whether the link targets a package relationship; show navigation
+    /// (first/last/next/previous slide, end show) is carried by `action` alone
+    pub fn has_relationship(&self) -> bool {
+        !matches!(
+            self,
+            HyperlinkAction::FirstSlide
+                | HyperlinkAction::LastSlide
+                | HyperlinkAction::NextSlide
+                | HyperlinkAction::PreviousSlide
+                | HyperlinkAction::LastSlideViewed
+                | HyperlinkAction::EndShow
+        )
+    }
+
     /// Get the action type for internal links
     pub fn action_type(&self) -> Option<&'static str> {
         match self {
+            HyperlinkAction::Slide(_) | HyperlinkAction::JumpToSlide(_) => Some("ppaction://hlinksldjump"),
             HyperlinkAction::FirstSlide => Some("ppaction://hlinkshowjump?jump=firstslide"),
             HyperlinkAction::LastSlide => Some("ppaction://hlinkshowjump?jump=lastslide"),
             HyperlinkAction::NextSlide => Some("ppaction://hlinkshowjump?jump=nextslide"),
             HyperlinkAction::PreviousSlide => Some("ppaction://hlinkshowjump?jump=previousslide"),
+            HyperlinkAction::LastSlideViewed => Some("ppaction://hlinkshowjump?jump=lastslideviewed"),
             HyperlinkAction::EndShow => Some("ppaction://hlinkshowjump?jump=endshow"),
             _ => None,
         }
@@ -103,6 +145,7 @@ impl HyperlinkAction {
 
 /// Hyperlink definition
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Hyperlink {
     /// The action to perform when clicked
     pub action: HyperlinkAction,
@@ -130,11 +173,16 @@ impl Hyperlink {
         Self::new(HyperlinkAction::url(url))
     }
 
-    /// Create slide hyperlink
+    /// Create a link to 1-based slide number `slide_num`, so `slide(1)` is the first slide
     pub fn slide(slide_num: u32) -> Self {
         Self::new(HyperlinkAction::slide(slide_num))
     }
 
+    /// Create a link to the slide at 0-based `index` in the deck, so `jump_to_slide(0)` is the first slide
+    pub fn jump_to_slide(index: usize) -> Self {
+        Self::new(HyperlinkAction::jump_to_slide(index))
+    }
+
     /// Create email hyperlink
     pub fn email(address: &str) -> Self {
         Self::new(HyperlinkAction::email(address))
@@ -159,9 +207,14 @@ impl Hyperlink {
     }
 }
 
+/// `r:id` for a link's `<a:hlinkClick>`: show navigation has no relationship and uses an empty id
+fn click_r_id<'a>(hyperlink: &Hyperlink, r_id: &'a str) -> &'a str {
+    if hyperlink.action.has_relationship() { r_id } else { "" }
+}
+
 /// Generate hyperlink XML for text run
 pub fn generate_text_hyperlink_xml(hyperlink: &Hyperlink, r_id: &str) -> String {
-    let mut xml = format!(r#"<a:hlinkClick r:id="{}""#, r_id);
+    let mut xml = format!(r#"<a:hlinkClick r:id="{}""#, click_r_id(hyperlink, r_id));
 
     if let Some(tooltip) = &hyperlink.tooltip {
         xml.push_str(&format!(r#" tooltip="{}""#, escape_xml(tooltip)));
@@ -182,7 +235,7 @@ pub fn generate_text_hyperlink_xml(hyperlink: &Hyperlink, r_id: &str) -> String
 
 /// Generate hyperlink XML for shape
 pub fn generate_shape_hyperlink_xml(hyperlink: &Hyperlink, r_id: &str) -> String {
-    let mut xml = format!(r#"<a:hlinkClick r:id="{}""#, r_id);
+    let mut xml = format!(r#"<a:hlinkClick r:id="{}""#, click_r_id(hyperlink, r_id));
 
     if let Some(tooltip) = &hyperlink.tooltip {
         xml.push_str(&format!(r#" tooltip="{}""#, escape_xml(tooltip)));
@@ -208,10 +261,16 @@ pub fn generate_hyperlink_relationship_xml(hyperlink: &Hyperlink, r_id: &str) ->
     } else {
         ""
     };
+    let rel_type = if hyperlink.action.target_slide_number().is_some() {
+        SLIDE_RELATIONSHIP_TYPE
+    } else {
+        HYPERLINK_RELATIONSHIP_TYPE
+    };
 
     format!(
-        r#"<Relationship Id="{}" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/hyperlink" Target="{}"{}/>"#,
+        r#"<Relationship Id="{}" Type="{}" Target="{}"{}/>"#,
         r_id,
+        rel_type,
         escape_xml(&target),
         target_mode
     )
@@ -256,6 +315,7 @@ mod tests {
         assert!(HyperlinkAction::NextSlide.action_type().is_some());
         assert!(HyperlinkAction::PreviousSlide.action_type().is_some());
         assert!(HyperlinkAction::EndShow.action_type().is_some());
+        assert!(HyperlinkAction::LastSlideViewed.action_type().unwrap().ends_with("lastslideviewed"));
         assert!(HyperlinkAction::url("test").action_type().is_none());
     }
 
@@ -278,6 +338,34 @@ mod tests {
         assert!(xml.contains("External"));
     }
 
+    #[test]
+    fn test_jump_to_slide() {
+        let link = Hyperlink::jump_to_slide(2).with_tooltip("Results");
+        assert_eq!(link.action.target_slide_number(), Some(3));
+        assert_eq!(HyperlinkAction::slide(3).target_slide_number(), Some(3));
+        assert!(!link.action.is_external());
+
+        let xml = generate_shape_hyperlink_xml(&link, "rId4");
+        assert!(xml.contains(r#"r:id="rId4""#));
+        assert!(xml.contains(r#"action="ppaction://hlinksldjump""#));
+
+        let rel = generate_hyperlink_relationship_xml(&link, "rId4");
+        assert!(rel.contains("relationships/slide\""));
+        assert!(rel.contains(r#"Target="slide3.xml""#));
+        assert!(!rel.contains("External"));
+    }
+
+    #[test]
+    fn test_show_navigation_has_no_relationship() {
+        let link = Hyperlink::new(HyperlinkAction::NextSlide);
+        assert!(!link.action.has_relationship());
+        assert!(Hyperlink::jump_to_slide(0).action.has_relationship());
+
+        let xml = generate_shape_hyperlink_xml(&link, "rId4");
+        assert!(xml.contains(r#"r:id="""#));
+        assert!(xml.contains("jump=nextslide"));
+    }
+
     #[test]
     fn test_email_with_subject() {
         let action = HyperlinkAction::email_with_subject("test@example.com", "Hello");
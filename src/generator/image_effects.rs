@@ -2,6 +2,7 @@
 //!
 //! Extracted from `images_xml.rs` so effect presets are testable and reusable.
 
+use crate::elements::color_value_xml;
 use crate::generator::images::{Crop, ImageEffect};
 
 /// Generate `<a:effectLst>` content for the given effects (empty if none).
@@ -38,14 +39,42 @@ pub fn generate_effect_xml(effect: &ImageEffect) -> String {
     }
 }
 
+/// Generate `<a:blip>` child adjustments: `<a:alphaModFix>` for transparency and `<a:duotone>`.
+pub fn generate_blip_adjustments_xml(transparency: Option<u8>, duotone: Option<&(String, String)>) -> String {
+    let mut xml = String::new();
+    if let Some(percent) = transparency {
+        // OOXML stores the remaining opacity, not the transparency
+        let amt = (100 - u32::from(percent.min(100))) * 1000;
+        xml.push_str(&format!(r#"<a:alphaModFix amt="{amt}"/>"#));
+    }
+    if let Some((dark, light)) = duotone {
+        xml.push_str(&format!("<a:duotone>{}{}</a:duotone>", color_value_xml(dark, ""), color_value_xml(light, "")));
+    }
+    xml
+}
+
+/// Generate the `<a:extLst>` that points a blip at its SVG part (must be the blip's last child)
+pub fn generate_svg_blip_ext_xml(svg_rel_id: &str) -> String {
+    format!(
+        r#"<a:extLst><a:ext uri="{{96DAC541-7B7A-43D3-8B79-37D633B846F1}}"><asvg:svgBlip xmlns:asvg="http://schemas.microsoft.com/office/drawing/2016/SVG/main" r:embed="{svg_rel_id}"/></a:ext></a:extLst>"#
+    )
+}
+
 /// Generate `<p:blipFill>` with optional crop via `<a:srcRect>`.
 pub fn generate_blip_fill_xml(rel_id: &str, crop: Option<&Crop>) -> String {
+    generate_blip_fill_xml_with_adjustments(rel_id, crop, "")
+}
+
+/// Generate `<p:blipFill>` with optional crop and `<a:blip>` adjustments (see `generate_blip_adjustments_xml`).
+pub fn generate_blip_fill_xml_with_adjustments(rel_id: &str, crop: Option<&Crop>, adjustments: &str) -> String {
+    let blip = if adjustments.is_empty() {
+        format!(r#"<a:blip r:embed="{rel_id}"/>"#)
+    } else {
+        format!(r#"<a:blip r:embed="{rel_id}">{adjustments}</a:blip>"#)
+    };
     match crop {
         Some(crop) => {
-            let l = (crop.left * 100_000.0) as u32;
-            let t = (crop.top * 100_000.0) as u32;
-            let r = (crop.right * 100_000.0) as u32;
-            let b = (crop.bottom * 100_000.0) as u32;
+            let (l, t, r, b) = crop.to_ooxml();
             let mut attrs = String::new();
             for (name, value) in [("l", l), ("t", t), ("r", r), ("b", b)] {
                 if value > 0 {
@@ -54,7 +83,7 @@ pub fn generate_blip_fill_xml(rel_id: &str, crop: Option<&Crop>) -> String {
             }
             format!(
                 r#"<p:blipFill>
-<a:blip r:embed="{rel_id}"/>
+{blip}
 <a:srcRect{attrs}/>
 <a:stretch>
 <a:fillRect/>
@@ -64,7 +93,7 @@ pub fn generate_blip_fill_xml(rel_id: &str, crop: Option<&Crop>) -> String {
         }
         None => format!(
             r#"<p:blipFill>
-<a:blip r:embed="{rel_id}"/>
+{blip}
 <a:stretch>
 <a:fillRect/>
 </a:stretch>
@@ -117,4 +146,29 @@ mod tests {
         assert!(xml.contains(r#"l="10000""#));
         assert!(xml.contains(r#"t="20000""#));
     }
+
+    #[test]
+    fn test_crop_clamps_out_of_range_values() {
+        let crop = Crop::new(-0.5, 0.25, 1.5, 0.9);
+        assert_eq!(crop.to_ooxml(), (0, 25000, 100000, 75000));
+
+        let xml = generate_blip_fill_xml("rId2", Some(&Crop::new(0.333, 0.0, 0.0, 0.0)));
+        assert!(xml.contains(r#"<a:srcRect l="33300"/>"#));
+    }
+
+    #[test]
+    fn test_blip_adjustments() {
+        let dark = ("1F3864".to_string(), "accent1".to_string());
+        let adjustments = generate_blip_adjustments_xml(Some(30), Some(&dark));
+        assert_eq!(
+            adjustments,
+            r#"<a:alphaModFix amt="70000"/><a:duotone><a:srgbClr val="1F3864"/><a:schemeClr val="accent1"/></a:duotone>"#
+        );
+
+        let crop = Crop::new(0.1, 0.0, 0.0, 0.0);
+        let xml = generate_blip_fill_xml_with_adjustments("rId2", Some(&crop), &adjustments);
+        assert!(xml.contains(r#"<a:blip r:embed="rId2"><a:alphaModFix amt="70000"/>"#));
+        assert!(xml.contains(r#"<a:srcRect l="10000"/>"#));
+        assert_eq!(generate_blip_adjustments_xml(None, None), "");
+    }
 }
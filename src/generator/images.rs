@@ -3,7 +3,9 @@
 //! Handles image metadata, embedding, and XML generation
 
 use std::path::Path;
-use crate::core::{Dimension, ElementPlacement, ElementSized, Positioned};
+use crate::core::{Dimension, ElementPlacement, ElementSized, Positioned, SlideSize};
+use crate::elements::ColorInput;
+use crate::generator::shapes::ShapeLine;
 
 /// Normalize format string and derive file extension
 fn format_and_ext(format: &str) -> (String, String) {
@@ -24,6 +26,7 @@ fn generate_image_filename(format: &str) -> (String, String) {
 
 /// Image data source
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ImageSource {
     /// Load from file path
     File(String),
@@ -38,6 +41,7 @@ pub enum ImageSource {
 
 /// Image crop configuration (values 0.0 to 1.0)
 #[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Crop {
     pub left: f64,
     pub top: f64,
@@ -46,14 +50,28 @@ pub struct Crop {
 }
 
 impl Crop {
-    /// Create a new crop configuration
+    /// Create a new crop configuration from edge fractions (0.0-1.0).
+    ///
+    /// Values are clamped to 0.0-1.0, and the right/bottom edges are reduced so
+    /// opposite edges never overlap.
     pub fn new(left: f64, top: f64, right: f64, bottom: f64) -> Self {
+        let clamp = |v: f64| if v.is_nan() { 0.0 } else { v.clamp(0.0, 1.0) };
+        let (left, top) = (clamp(left), clamp(top));
+        let right = clamp(right).min(1.0 - left);
+        let bottom = clamp(bottom).min(1.0 - top);
         Self { left, top, right, bottom }
     }
+
+    /// Edge offsets in 1000ths of a percent (`l`, `t`, `r`, `b` on `<a:srcRect>`)
+    pub fn to_ooxml(&self) -> (u32, u32, u32, u32) {
+        let convert = |v: f64| (v.clamp(0.0, 1.0) * 100_000.0).round() as u32;
+        (convert(self.left), convert(self.top), convert(self.right), convert(self.bottom))
+    }
 }
 
 /// Image effects
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ImageEffect {
     /// Outer shadow
     Shadow,
@@ -71,6 +89,7 @@ pub enum ImageEffect {
 
 /// Image metadata and properties
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Image {
     pub filename: String,
     pub width: u32,      // in EMU
@@ -86,23 +105,30 @@ pub struct Image {
     pub effects: Vec<ImageEffect>,
     /// Accessibility alt text (description)
     pub alt_text: Option<String>,
+    /// Stacking order relative to other slide elements (higher draws on top)
+    pub z_order: i32,
+    /// Transparency (0-100 percent)
+    pub transparency: Option<u8>,
+    /// Duotone recolor (dark, light); each is a hex color or a scheme color name
+    pub duotone: Option<(String, String)>,
+    /// Corner radius in EMU (renders the frame as a rounded rectangle)
+    pub corner_radius: Option<u32>,
+    /// Frame outline
+    pub border: Option<ShapeLine>,
+    /// SVG data shown by modern PowerPoint; the main source is the raster fallback
+    pub svg: Option<Vec<u8>>,
+    /// Position set with [`Image::at`], re-resolved against the deck's slide size on build
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub dimension_position: Option<(Dimension, Dimension)>,
+    /// Size set with [`Image::with_dimensions`], re-resolved against the deck's slide size on build
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub dimension_size: Option<(Dimension, Dimension)>,
 }
 
 impl Image {
     /// Create a new image
     pub fn new(filename: &str, width: u32, height: u32, format: &str) -> Self {
-        Image {
-            filename: filename.to_string(),
-            width,
-            height,
-            x: 0,
-            y: 0,
-            format: format.to_uppercase(),
-            source: Some(ImageSource::File(filename.to_string())),
-            crop: None,
-            effects: Vec::new(),
-            alt_text: None,
-        }
+        Self::with_source(filename.to_string(), width, height, format.to_uppercase(), ImageSource::File(filename.to_string()))
     }
 
     /// Create an image from a file path, automatically detecting dimensions
@@ -120,18 +146,7 @@ impl Image {
         let w_emu = w * 9525;
         let h_emu = h * 9525;
         
-        Ok(Image {
-            filename,
-            width: w_emu,
-            height: h_emu,
-            x: 0,
-            y: 0,
-            format,
-            source: Some(ImageSource::File(path_str)),
-            crop: None,
-            effects: Vec::new(),
-            alt_text: None,
-        })
+        Ok(Self::with_source(filename, w_emu, h_emu, format, ImageSource::File(path_str)))
     }
     
     /// Create an image from base64 encoded data
@@ -166,6 +181,54 @@ impl Image {
         Self::with_source(filename, width, height, fmt, ImageSource::Url(url.to_string()))
     }
 
+    /// Download an image now and embed its bytes.
+    ///
+    /// The format comes from the magic bytes, falling back to the `Content-Type`
+    /// header; the size comes from the pixel dimensions at 96 DPI. The body is read
+    /// once into a buffer pre-sized from `Content-Length` and moved into the image.
+    #[cfg(feature = "web2ppt")]
+    pub fn fetch_url(url: &str) -> crate::exc::Result<Self> {
+        use crate::exc::PptxError;
+        use std::io::Read;
+
+        let fetch_error = |e: &dyn std::fmt::Display| PptxError::Generic(format!("Failed to fetch image {url}: {e}"));
+        let client = reqwest::blocking::Client::builder()
+            .user_agent("Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/91.0.4472.124 Safari/537.36")
+            .build()
+            .map_err(|e| fetch_error(&e))?;
+        let mut resp = client.get(url).send().map_err(|e| fetch_error(&e))?;
+        if !resp.status().is_success() {
+            return Err(fetch_error(&format!("HTTP {}", resp.status().as_u16())));
+        }
+
+        let content_type = resp
+            .headers()
+            .get(reqwest::header::CONTENT_TYPE)
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_string);
+        let mut data = Vec::with_capacity(resp.content_length().unwrap_or(0).min(64 * 1024 * 1024) as usize);
+        resp.read_to_end(&mut data).map_err(|e| fetch_error(&e))?;
+
+        Self::from_downloaded(url, data, content_type.as_deref())
+    }
+
+    /// Embed downloaded bytes, detecting the format and natural size
+    #[cfg(feature = "web2ppt")]
+    pub(crate) fn from_downloaded(url: &str, data: Vec<u8>, content_type: Option<&str>) -> crate::exc::Result<Self> {
+        use crate::exc::PptxError;
+
+        let format = detect_image_format(&data)
+            .or_else(|| content_type.and_then(image_format_from_content_type))
+            .ok_or_else(|| PptxError::InvalidValue(format!("Unrecognized image format at {url}")))?;
+        let defaults = ElementPlacement::image_defaults();
+        let (width, height) = read_image_dimensions(&data)
+            .map(|(w, h, _)| (w * 9525, h * 9525))
+            .unwrap_or((defaults.width, defaults.height));
+
+        let (filename, fmt) = generate_image_filename(format);
+        Ok(Self::with_source(filename, width, height, fmt, ImageSource::Bytes(data)))
+    }
+
     /// Internal constructor to avoid repeating struct init
     fn with_source(filename: String, width: u32, height: u32, format: String, source: ImageSource) -> Self {
         Image {
@@ -179,9 +242,23 @@ impl Image {
             crop: None,
             effects: Vec::new(),
             alt_text: None,
+            z_order: 0,
+            transparency: None,
+            duotone: None,
+            corner_radius: None,
+            border: None,
+            svg: None,
+            dimension_position: None,
+            dimension_size: None,
         }
     }
 
+    /// Set stacking order; negative values draw behind the title and body placeholders
+    pub fn with_z_order(mut self, z_order: i32) -> Self {
+        self.z_order = z_order;
+        self
+    }
+
     /// Set accessibility alt text for the image.
     pub fn with_alt_text(mut self, alt: &str) -> Self {
         self.alt_text = Some(alt.to_string());
@@ -236,6 +313,43 @@ impl Image {
         self
     }
 
+    /// Crop edges by fractions of the image (0.0-1.0), same as `with_crop`
+    pub fn crop(self, left: f64, top: f64, right: f64, bottom: f64) -> Self {
+        self.with_crop(left, top, right, bottom)
+    }
+
+    /// Make the image semi-transparent (0-100 percent)
+    pub fn transparency(mut self, percent: u8) -> Self {
+        self.transparency = Some(percent.min(100));
+        self
+    }
+
+    /// Recolor the image between a dark and a light color.
+    ///
+    /// Colors are hex values (`"1F3864"`) or scheme color names (`"accent1"`, `"tx1"`).
+    pub fn duotone(mut self, dark: impl Into<ColorInput>, light: impl Into<ColorInput>) -> Self {
+        self.duotone = Some((dark.into().into_value(), light.into().into_value()));
+        self
+    }
+
+    /// Attach SVG data; the image's own data becomes the raster fallback for older viewers
+    pub fn with_svg(mut self, svg: Vec<u8>) -> Self {
+        self.svg = Some(svg);
+        self
+    }
+
+    /// Round the image frame corners (radius in EMU)
+    pub fn rounded_corners(mut self, radius_emu: u32) -> Self {
+        self.corner_radius = Some(radius_emu);
+        self
+    }
+
+    /// Outline the image frame (width in EMU; hex color or scheme color name)
+    pub fn border(mut self, width_emu: u32, color: impl Into<ColorInput>) -> Self {
+        self.border = Some(ShapeLine { color: color.into().into_value(), width: width_emu });
+        self
+    }
+
     /// Add an image effect
     pub fn with_effect(mut self, effect: ImageEffect) -> Self {
         self.effects.push(effect);
@@ -285,19 +399,39 @@ impl Image {
         }
     }
 
-    /// Set position using flexible Dimension units (fluent).
+    /// Set position using flexible Dimension units (fluent); ratios are relative
+    /// to the slide the image ends up on
     pub fn at(mut self, x: Dimension, y: Dimension) -> Self {
         self.x = x.to_emu_x();
         self.y = y.to_emu_y();
+        self.dimension_position = Some((x, y));
         self
     }
 
-    /// Set size using flexible Dimension units (fluent).
+    /// Set size using flexible Dimension units (fluent); see [`Image::at`]
     pub fn with_dimensions(mut self, width: Dimension, height: Dimension) -> Self {
         self.width = width.to_emu_x();
         self.height = height.to_emu_y();
+        self.dimension_size = Some((width, height));
         self
     }
+
+    /// Resolve Dimension placement against a slide size
+    pub fn fit_to(&mut self, size: &SlideSize) {
+        if let Some((x, y)) = &self.dimension_position {
+            self.x = x.to_emu_x_in(size);
+            self.y = y.to_emu_y_in(size);
+        }
+        if let Some((width, height)) = &self.dimension_size {
+            self.width = width.to_emu_x_in(size);
+            self.height = height.to_emu_y_in(size);
+        }
+    }
+
+    /// Whether the image was placed with Dimension units
+    pub fn has_dimension_placement(&self) -> bool {
+        self.dimension_position.is_some() || self.dimension_size.is_some()
+    }
 }
 
 impl Positioned for Image {
@@ -374,9 +508,10 @@ pub struct ImageBuilder {
     filename: String,
     placement: ElementPlacement,
     format: String,
-    source: Option<ImageSource>,
+    source: ImageSource,
     effects: Vec<ImageEffect>,
     crop: Option<Crop>,
+    svg: Option<Vec<u8>>,
 }
 
 impl ImageBuilder {
@@ -388,13 +523,19 @@ impl ImageBuilder {
             .map(|s| s.to_uppercase())
             .unwrap_or_else(|| "PNG".to_string());
 
+        Self::base(filename.to_string(), width, height, format, ImageSource::File(filename.to_string()))
+    }
+
+    /// Builder for `source` with no effects, crop or SVG
+    fn base(filename: String, width: u32, height: u32, format: String, source: ImageSource) -> Self {
         ImageBuilder {
-            filename: filename.to_string(),
+            filename,
             placement: ElementPlacement::new().with_size(width, height),
             format,
-            source: Some(ImageSource::File(filename.to_string())),
+            source,
             effects: Vec::new(),
             crop: None,
+            svg: None,
         }
     }
     
@@ -414,14 +555,7 @@ impl ImageBuilder {
     /// Create image builder from base64 data
     pub fn from_base64(data: &str, width: u32, height: u32, format: &str) -> Self {
         let (upper, ext) = format_and_ext(format);
-        ImageBuilder {
-            filename: format!("image.{ext}"),
-            placement: ElementPlacement::new().with_size(width, height),
-            format: upper,
-            source: Some(ImageSource::Base64(data.to_string())),
-            effects: Vec::new(),
-            crop: None,
-        }
+        Self::base(format!("image.{ext}"), width, height, upper, ImageSource::Base64(data.to_string()))
     }
     
     /// Create image from base64 with default size (2 inches square)
@@ -440,14 +574,16 @@ impl ImageBuilder {
     /// Create image builder from bytes
     pub fn from_bytes(data: Vec<u8>, width: u32, height: u32, format: &str) -> Self {
         let (upper, ext) = format_and_ext(format);
-        ImageBuilder {
-            filename: format!("image.{ext}"),
-            placement: ElementPlacement::new().with_size(width, height),
-            format: upper,
-            source: Some(ImageSource::Bytes(data)),
-            effects: Vec::new(),
-            crop: None,
-        }
+        Self::base(format!("image.{ext}"), width, height, upper, ImageSource::Bytes(data))
+    }
+    
+    /// Create an SVG image with a raster fallback for viewers without SVG support.
+    ///
+    /// PowerPoint 2016+ renders the SVG; older viewers show `png_fallback`.
+    pub fn svg(svg: Vec<u8>, png_fallback: Vec<u8>, width: u32, height: u32) -> Self {
+        let mut builder = Self::from_bytes(png_fallback, width, height, "PNG");
+        builder.svg = Some(svg);
+        builder
     }
     
     /// Create image from bytes with default size (2 inches square)
@@ -477,19 +613,7 @@ impl ImageBuilder {
         let defaults = ElementPlacement::image_defaults();
         
         // Detect format from magic bytes
-        let format = if data.len() >= 4 {
-            if &data[0..4] == b"\x89PNG" {
-                "PNG"
-            } else if data.len() >= 2 && &data[0..2] == b"\xFF\xD8" {
-                "JPEG"
-            } else if data.len() >= 6 && &data[0..6] == b"GIF89a" || &data[0..6] == b"GIF87a" {
-                "GIF"
-            } else {
-                "PNG" // default
-            }
-        } else {
-            "PNG"
-        };
+        let format = detect_image_format(&data).unwrap_or("PNG");
         
         Self::from_bytes(data, defaults.width, defaults.height, format)
     }
@@ -578,145 +702,83 @@ impl ImageBuilder {
     /// Build the image
     pub fn build(self) -> Image {
         Image {
-            filename: self.filename,
-            width: self.placement.width,
-            height: self.placement.height,
             x: self.placement.x,
             y: self.placement.y,
-            format: self.format,
-            source: self.source,
             crop: self.crop,
             effects: self.effects,
-            alt_text: None,
+            svg: self.svg,
+            ..Image::with_source(self.filename, self.placement.width, self.placement.height, self.format, self.source)
         }
     }
     
     /// Build with crop
     pub fn build_with_crop(self, left: f64, top: f64, right: f64, bottom: f64) -> Image {
-        Image {
-            filename: self.filename,
-            width: self.placement.width,
-            height: self.placement.height,
-            x: self.placement.x,
-            y: self.placement.y,
-            format: self.format,
-            source: self.source,
-            crop: Some(Crop::new(left, top, right, bottom)),
-            effects: Vec::new(),
-            alt_text: None,
-        }
+        Image { crop: Some(Crop::new(left, top, right, bottom)), effects: Vec::new(), ..self.build() }
     }
     
     /// Build with shadow effect
     pub fn build_with_shadow(self) -> Image {
-        Image {
-            filename: self.filename,
-            width: self.placement.width,
-            height: self.placement.height,
-            x: self.placement.x,
-            y: self.placement.y,
-            format: self.format,
-            source: self.source,
-            crop: None,
-            effects: vec![ImageEffect::Shadow],
-            alt_text: None,
-        }
+        Image { crop: None, effects: vec![ImageEffect::Shadow], ..self.build() }
     }
     
     /// Build with reflection effect
     pub fn build_with_reflection(self) -> Image {
-        Image {
-            filename: self.filename,
-            width: self.placement.width,
-            height: self.placement.height,
-            x: self.placement.x,
-            y: self.placement.y,
-            format: self.format,
-            source: self.source,
-            crop: None,
-            effects: vec![ImageEffect::Reflection],
-            alt_text: None,
-        }
+        Image { crop: None, effects: vec![ImageEffect::Reflection], ..self.build() }
     }
     
     /// Build with both shadow and reflection effects
     pub fn build_with_effects(self) -> Image {
-        Image {
-            filename: self.filename,
-            width: self.placement.width,
-            height: self.placement.height,
-            x: self.placement.x,
-            y: self.placement.y,
-            format: self.format,
-            source: self.source,
-            crop: None,
-            effects: vec![ImageEffect::Shadow, ImageEffect::Reflection],
-            alt_text: None,
-        }
+        Image { crop: None, effects: vec![ImageEffect::Shadow, ImageEffect::Reflection], ..self.build() }
     }
     
     /// Build with glow effect
     pub fn build_with_glow(self) -> Image {
-        Image {
-            filename: self.filename,
-            width: self.placement.width,
-            height: self.placement.height,
-            x: self.placement.x,
-            y: self.placement.y,
-            format: self.format,
-            source: self.source,
-            crop: None,
-            effects: vec![ImageEffect::Glow],
-            alt_text: None,
-        }
+        Image { crop: None, effects: vec![ImageEffect::Glow], ..self.build() }
     }
     
     /// Build with soft edges effect
     pub fn build_with_soft_edges(self) -> Image {
-        Image {
-            filename: self.filename,
-            width: self.placement.width,
-            height: self.placement.height,
-            x: self.placement.x,
-            y: self.placement.y,
-            format: self.format,
-            source: self.source,
-            crop: None,
-            effects: vec![ImageEffect::SoftEdges],
-            alt_text: None,
-        }
+        Image { crop: None, effects: vec![ImageEffect::SoftEdges], ..self.build() }
     }
     
     /// Build with inner shadow effect
     pub fn build_with_inner_shadow(self) -> Image {
-        Image {
-            filename: self.filename,
-            width: self.placement.width,
-            height: self.placement.height,
-            x: self.placement.x,
-            y: self.placement.y,
-            format: self.format,
-            source: self.source,
-            crop: None,
-            effects: vec![ImageEffect::InnerShadow],
-            alt_text: None,
-        }
+        Image { crop: None, effects: vec![ImageEffect::InnerShadow], ..self.build() }
     }
     
     /// Build with blur effect
     pub fn build_with_blur(self) -> Image {
-        Image {
-            filename: self.filename,
-            width: self.placement.width,
-            height: self.placement.height,
-            x: self.placement.x,
-            y: self.placement.y,
-            format: self.format,
-            source: self.source,
-            crop: None,
-            effects: vec![ImageEffect::Blur],
-            alt_text: None,
-        }
+        Image { crop: None, effects: vec![ImageEffect::Blur], ..self.build() }
+    }
+}
+
+/// Detect an image format (PNG, JPEG, GIF, WEBP, BMP) from its magic bytes
+pub fn detect_image_format(data: &[u8]) -> Option<&'static str> {
+    if data.starts_with(b"\x89PNG\r\n\x1a\n") {
+        Some("PNG")
+    } else if data.starts_with(&[0xFF, 0xD8, 0xFF]) {
+        Some("JPEG")
+    } else if data.starts_with(b"GIF87a") || data.starts_with(b"GIF89a") {
+        Some("GIF")
+    } else if data.len() >= 12 && &data[0..4] == b"RIFF" && &data[8..12] == b"WEBP" {
+        Some("WEBP")
+    } else if data.starts_with(b"BM") {
+        Some("BMP")
+    } else {
+        None
+    }
+}
+
+/// Map an HTTP `Content-Type` to an image format name
+pub fn image_format_from_content_type(content_type: &str) -> Option<&'static str> {
+    let mime = content_type.split(';').next().unwrap_or("").trim().to_ascii_lowercase();
+    match mime.as_str() {
+        "image/png" => Some("PNG"),
+        "image/jpeg" | "image/jpg" | "image/pjpeg" => Some("JPEG"),
+        "image/gif" => Some("GIF"),
+        "image/webp" => Some("WEBP"),
+        "image/bmp" | "image/x-ms-bmp" => Some("BMP"),
+        _ => None,
     }
 }
 
@@ -800,6 +862,19 @@ fn read_jpeg_dimensions(data: &[u8]) -> Option<(u32, u32, String)> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_detect_image_format() {
+        assert_eq!(detect_image_format(b"\x89PNG\r\n\x1a\n...."), Some("PNG"));
+        assert_eq!(detect_image_format(&[0xFF, 0xD8, 0xFF, 0xE0]), Some("JPEG"));
+        assert_eq!(detect_image_format(b"GIF89a.."), Some("GIF"));
+        assert_eq!(detect_image_format(b"RIFF\0\0\0\0WEBPVP8 "), Some("WEBP"));
+        assert_eq!(detect_image_format(b"<html>"), None);
+
+        assert_eq!(image_format_from_content_type("image/jpeg; charset=binary"), Some("JPEG"));
+        assert_eq!(image_format_from_content_type("IMAGE/WEBP"), Some("WEBP"));
+        assert_eq!(image_format_from_content_type("text/html"), None);
+    }
+
     #[test]
     fn test_image_creation() {
         let img = Image::new("test.png", 1920, 1080, "PNG");
@@ -3,19 +3,43 @@
 //! Generates proper PPTX XML for image embedding and display
 
 use crate::core::escape_xml;
-use crate::generator::image_effects::{generate_blip_fill_xml, generate_effect_list_xml};
+use crate::elements::color_value_xml;
+use crate::generator::image_effects::{
+    generate_blip_adjustments_xml, generate_blip_fill_xml_with_adjustments, generate_effect_list_xml,
+    generate_svg_blip_ext_xml,
+};
 use crate::generator::images::Image;
 
 /// Generate image XML for a slide
 pub fn generate_image_xml(image: &Image, shape_id: usize, rel_id: usize) -> String {
+    generate_image_xml_with_svg(image, shape_id, rel_id, None)
+}
+
+/// Generate image XML whose blip also references an SVG part (`svg_rel_id`)
+pub fn generate_image_xml_with_svg(image: &Image, shape_id: usize, rel_id: usize, svg_rel_id: Option<usize>) -> String {
     let rel_id_str = format!("rId{rel_id}");
-    let blip_fill = generate_blip_fill_xml(&rel_id_str, image.crop.as_ref());
+    let mut adjustments = generate_blip_adjustments_xml(image.transparency, image.duotone.as_ref());
+    if let Some(svg_rel_id) = svg_rel_id {
+        adjustments.push_str(&generate_svg_blip_ext_xml(&format!("rId{svg_rel_id}")));
+    }
+    let blip_fill = generate_blip_fill_xml_with_adjustments(&rel_id_str, image.crop.as_ref(), &adjustments);
     let effects_xml = generate_effect_list_xml(&image.effects);
     let descr_attr = image
         .alt_text
         .as_ref()
         .map(|alt| format!(r#" descr="{}""#, escape_xml(alt)))
         .unwrap_or_default();
+    let geometry_xml = generate_frame_geometry_xml(image);
+    let border_xml = image
+        .border
+        .as_ref()
+        .map(|line| {
+            format!(
+                r#"<a:ln w="{}"><a:solidFill>{}</a:solidFill></a:ln>"#,
+                line.width, color_value_xml(&line.color, "")
+            )
+        })
+        .unwrap_or_default();
 
     format!(
         r#"<p:pic>
@@ -32,10 +56,8 @@ pub fn generate_image_xml(image: &Image, shape_id: usize, rel_id: usize) -> Stri
 <a:off x="{}" y="{}"/>
 <a:ext cx="{}" cy="{}"/>
 </a:xfrm>
-<a:prstGeom prst="rect">
-<a:avLst/>
-</a:prstGeom>
 {}
+{}{}
 </p:spPr>
 </p:pic>"#,
         shape_id,
@@ -46,10 +68,29 @@ pub fn generate_image_xml(image: &Image, shape_id: usize, rel_id: usize) -> Stri
         image.y,
         image.width,
         image.height,
+        geometry_xml,
+        border_xml,
         effects_xml
     )
 }
 
+/// Frame geometry: a plain rectangle, or `roundRect` with its `adj` set from the corner radius
+fn generate_frame_geometry_xml(image: &Image) -> String {
+    match image.corner_radius {
+        Some(radius) => {
+            // roundRect's adj is the radius as a fraction (1/100000) of the shorter side, max 50%
+            let short_side = image.width.min(image.height).max(1) as u64;
+            let adj = (radius as u64 * 100_000 / short_side).min(50_000);
+            format!(
+                r#"<a:prstGeom prst="roundRect">
+<a:avLst><a:gd name="adj" fmla="val {adj}"/></a:avLst>
+</a:prstGeom>"#
+            )
+        }
+        None => "<a:prstGeom prst=\"rect\">\n<a:avLst/>\n</a:prstGeom>".to_string(),
+    }
+}
+
 /// Generate image relationship XML
 pub fn generate_image_relationship(rel_id: usize, image_path: &str) -> String {
     format!(
@@ -81,6 +122,7 @@ pub fn generate_image_content_type(extension: &str) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::elements::SchemeColor;
     use crate::generator::images::{Image, ImageEffect};
 
     #[test]
@@ -112,6 +154,53 @@ mod tests {
         assert!(xml.contains("cy=\"1080000\""));
     }
 
+    #[test]
+    fn test_generate_image_with_transparency_and_duotone() {
+        let img = Image::new("logo.png", 100, 100, "PNG")
+            .transparency(25)
+            .duotone("#000000", "bg1")
+            .with_crop(0.0, 0.1, 0.0, 0.0);
+        let xml = generate_image_xml(&img, 1, 2);
+
+        assert!(xml.contains(r#"<a:blip r:embed="rId2"><a:alphaModFix amt="75000"/><a:duotone><a:srgbClr val="000000"/><a:schemeClr val="bg1"/></a:duotone></a:blip>"#));
+        assert!(xml.contains(r#"<a:srcRect t="10000"/>"#));
+    }
+
+    #[test]
+    fn test_generate_image_with_rounded_border() {
+        let img = Image::new("shot.png", 2000000, 1000000, "PNG")
+            .rounded_corners(100000)
+            .border(12700, "#a6a6a6");
+        let xml = generate_image_xml(&img, 1, 2);
+        assert!(xml.contains(r#"<a:prstGeom prst="roundRect">"#));
+        assert!(xml.contains(r#"<a:gd name="adj" fmla="val 10000"/>"#));
+        assert!(xml.contains(r#"<a:ln w="12700"><a:solidFill><a:srgbClr val="A6A6A6"/></a:solidFill></a:ln>"#));
+        assert!(xml.find("</a:prstGeom>").unwrap() < xml.find("<a:ln").unwrap());
+
+        // Works together with a crop and caps the radius at half the short side
+        let cropped = img.with_crop(0.1, 0.1, 0.1, 0.1).rounded_corners(5000000);
+        let xml = generate_image_xml(&cropped, 1, 2);
+        assert!(xml.contains("<a:srcRect"));
+        assert!(xml.contains(r#"fmla="val 50000""#));
+        assert!(xml.contains("<a:ln w=\"12700\">"));
+
+        let themed = Image::new("shot.png", 2000000, 1000000, "PNG").border(12700, SchemeColor::Accent2);
+        let xml = generate_image_xml(&themed, 1, 2);
+        assert!(xml.contains(r#"<a:ln w="12700"><a:solidFill><a:schemeClr val="accent2"/></a:solidFill></a:ln>"#));
+    }
+
+    #[test]
+    fn test_generate_image_with_svg_blip() {
+        let img = Image::from_bytes(vec![1, 2, 3], 100, 100, "PNG")
+            .with_svg(b"<svg/>".to_vec())
+            .transparency(10);
+        let xml = generate_image_xml_with_svg(&img, 1, 2, Some(5));
+
+        assert!(xml.contains(r#"<a:blip r:embed="rId2"><a:alphaModFix amt="90000"/><a:extLst>"#));
+        assert!(xml.contains(r#"<asvg:svgBlip xmlns:asvg="http://schemas.microsoft.com/office/drawing/2016/SVG/main" r:embed="rId5"/></a:ext></a:extLst></a:blip>"#));
+        assert!(!generate_image_xml(&img, 1, 2).contains("svgBlip"));
+    }
+
     #[test]
     fn test_generate_image_relationship() {
         let rel = generate_image_relationship(1, "../media/image1.png");
@@ -2,10 +2,13 @@
 
 use crate::core::append_usize;
 use crate::core::escape_xml;
+use crate::core::{SlideSize, SLIDE_HEIGHT_EMU, SLIDE_WIDTH_EMU};
 use crate::generator::slide_content::print_settings::PrintSettings;
+use crate::generator::slide_master::SlideMaster;
+use crate::generator::text::proofing_attrs;
 
 /// Number of standard layouts emitted on slide master 1.
-pub const STANDARD_LAYOUT_COUNT: usize = 7;
+pub const STANDARD_LAYOUT_COUNT: usize = 9;
 
 #[allow(clippy::too_many_arguments)]
 fn placeholder(id: u32, name: &str, ph_type: &str, ph_idx: Option<u32>, x: u32, y: u32, cx: u32, cy: u32) -> String {
@@ -57,9 +60,10 @@ pub fn slide_master_footer_shapes(print: Option<&PrintSettings>) -> String {
         let _ = header; // placeholder inherits text via slide; fixed text optional in master
     }
 
+    let proofing = proofing_attrs(false);
     if let Some(ref footer) = p.footer {
         xml.push_str(&format!(
-            r#"<p:sp><p:nvSpPr><p:cNvPr id="{id}" name="Footer Placeholder"/><p:cNvSpPr><a:spLocks noGrp="1"/></p:cNvSpPr><p:nvPr><p:ph type="ftr" sz="quarter"/></p:nvPr></p:nvSpPr><p:spPr><a:xfrm><a:off x="3124200" y="6356350"/><a:ext cx="2895600" cy="365125"/></a:xfrm><a:prstGeom prst="rect"><a:avLst/></a:prstGeom><a:noFill/></p:spPr><p:txBody><a:bodyPr/><a:lstStyle/><a:p><a:r><a:rPr lang="en-US" sz="1200"/><a:t>{}</a:t></a:r></a:p></p:txBody></p:sp>"#,
+            r#"<p:sp><p:nvSpPr><p:cNvPr id="{id}" name="Footer Placeholder"/><p:cNvSpPr><a:spLocks noGrp="1"/></p:cNvSpPr><p:nvPr><p:ph type="ftr" sz="quarter"/></p:nvPr></p:nvSpPr><p:spPr><a:xfrm><a:off x="3124200" y="6356350"/><a:ext cx="2895600" cy="365125"/></a:xfrm><a:prstGeom prst="rect"><a:avLst/></a:prstGeom><a:noFill/></p:spPr><p:txBody><a:bodyPr/><a:lstStyle/><a:p><a:r><a:rPr lang="en-US" sz="1200"{proofing}/><a:t>{}</a:t></a:r></a:p></p:txBody></p:sp>"#,
             escape_xml(footer)
         ));
         id += 1;
@@ -67,84 +71,179 @@ pub fn slide_master_footer_shapes(print: Option<&PrintSettings>) -> String {
 
     if p.print_date {
         xml.push_str(&format!(
-            r#"<p:sp><p:nvSpPr><p:cNvPr id="{id}" name="Date Placeholder"/><p:cNvSpPr><a:spLocks noGrp="1"/></p:cNvSpPr><p:nvPr><p:ph type="dt" sz="half"/></p:nvPr></p:nvSpPr><p:spPr><a:xfrm><a:off x="457200" y="6356350"/><a:ext cx="2133600" cy="365125"/></a:xfrm><a:prstGeom prst="rect"><a:avLst/></a:prstGeom><a:noFill/></p:spPr><p:txBody><a:bodyPr/><a:lstStyle/><a:p><a:fld id="{{B6F15528-F159-4107-2D14-000000000000}}" type="datetimeFigureOut"><a:rPr lang="en-US" sz="1200"/><a:t>6/1/2026</a:t></a:fld><a:endParaRPr lang="en-US" sz="1200"/></a:p></p:txBody></p:sp>"#
+            r#"<p:sp><p:nvSpPr><p:cNvPr id="{id}" name="Date Placeholder"/><p:cNvSpPr><a:spLocks noGrp="1"/></p:cNvSpPr><p:nvPr><p:ph type="dt" sz="half"/></p:nvPr></p:nvSpPr><p:spPr><a:xfrm><a:off x="457200" y="6356350"/><a:ext cx="2133600" cy="365125"/></a:xfrm><a:prstGeom prst="rect"><a:avLst/></a:prstGeom><a:noFill/></p:spPr><p:txBody><a:bodyPr/><a:lstStyle/><a:p><a:fld id="{{B6F15528-F159-4107-2D14-000000000000}}" type="datetimeFigureOut"><a:rPr lang="en-US" sz="1200"{proofing}/><a:t>6/1/2026</a:t></a:fld><a:endParaRPr lang="en-US" sz="1200"/></a:p></p:txBody></p:sp>"#
         ));
         id += 1;
     }
 
     if p.print_page_numbers {
         xml.push_str(&format!(
-            r#"<p:sp><p:nvSpPr><p:cNvPr id="{id}" name="Slide Number Placeholder"/><p:cNvSpPr><a:spLocks noGrp="1"/></p:cNvSpPr><p:nvPr><p:ph type="sldNum" sz="quarter"/></p:nvPr></p:nvSpPr><p:spPr><a:xfrm><a:off x="8610600" y="6356350"/><a:ext cx="533400" cy="365125"/></a:xfrm><a:prstGeom prst="rect"><a:avLst/></a:prstGeom><a:noFill/></p:spPr><p:txBody><a:bodyPr/><a:lstStyle/><a:p><a:fld id="{{B6F15528-F159-4107-2D14-000000000001}}" type="slidenum"><a:rPr lang="en-US" sz="1200"/><a:t>‹#›</a:t></a:fld><a:endParaRPr lang="en-US" sz="1200"/></a:p></p:txBody></p:sp>"#
+            r#"<p:sp><p:nvSpPr><p:cNvPr id="{id}" name="Slide Number Placeholder"/><p:cNvSpPr><a:spLocks noGrp="1"/></p:cNvSpPr><p:nvPr><p:ph type="sldNum" sz="quarter"/></p:nvPr></p:nvSpPr><p:spPr><a:xfrm><a:off x="8610600" y="6356350"/><a:ext cx="533400" cy="365125"/></a:xfrm><a:prstGeom prst="rect"><a:avLst/></a:prstGeom><a:noFill/></p:spPr><p:txBody><a:bodyPr/><a:lstStyle/><a:p><a:fld id="{{B6F15528-F159-4107-2D14-000000000001}}" type="slidenum"><a:rPr lang="en-US" sz="1200"{proofing}/><a:t>‹#›</a:t></a:fld><a:endParaRPr lang="en-US" sz="1200"/></a:p></p:txBody></p:sp>"#
         ));
     }
 
     xml
 }
 
+/// Placeholder on one of the standard layouts
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LayoutPlaceholder {
+    pub name: &'static str,
+    /// `<p:ph type>` value
+    pub ph_type: &'static str,
+    /// `<p:ph idx>` value
+    pub idx: Option<u32>,
+    /// Position and size (x, y, cx, cy) in EMU
+    pub bounds: (u32, u32, u32, u32),
+}
+
+const fn ph(name: &'static str, ph_type: &'static str, idx: Option<u32>, bounds: (u32, u32, u32, u32)) -> LayoutPlaceholder {
+    LayoutPlaceholder { name, ph_type, idx, bounds }
+}
+
+/// Stock title placeholder frame (x, y, cx, cy) shared by the master and most layouts
+pub const TITLE_BOUNDS: (u32, u32, u32, u32) = (457_200, 274_638, 8_229_600, 1_143_000);
+/// Stock body placeholder frame on the master and the Title and Content layout
+pub const BODY_BOUNDS: (u32, u32, u32, u32) = (457_200, 1_600_200, 8_229_600, 4_525_963);
+
+const TITLE: LayoutPlaceholder = ph("Title", "title", None, TITLE_BOUNDS);
+
+const LAYOUT_1: &[LayoutPlaceholder] = &[
+    ph("Title", "ctrTitle", None, (1_524_000, 1_828_800, 6_096_000, 1_828_800)),
+    ph("Subtitle", "subTitle", None, (1_524_000, 3_657_600, 6_096_000, 914_400)),
+];
+const LAYOUT_2: &[LayoutPlaceholder] = &[TITLE, ph("Content", "body", Some(1), BODY_BOUNDS)];
+const LAYOUT_3: &[LayoutPlaceholder] = &[
+    TITLE,
+    ph("Content Left", "body", Some(1), (457_200, 1_600_200, 4_025_400, 4_525_963)),
+    ph("Content Right", "body", Some(2), (4_661_400, 1_600_200, 4_025_400, 4_525_963)),
+];
+const LAYOUT_4: &[LayoutPlaceholder] = &[
+    ph("Title", "title", None, (457_200, 1_600_200, 8_229_600, 1_828_800)),
+    ph("Subtitle", "body", Some(1), (457_200, 3_657_600, 8_229_600, 1_371_600)),
+];
+const LAYOUT_6: &[LayoutPlaceholder] = &[TITLE];
+const LAYOUT_7: &[LayoutPlaceholder] = &[
+    ph("Title", "title", None, (457_200, 274_638, 8_229_600, 800_000)),
+    ph("Content", "body", Some(1), (457_200, 1_200_000, 8_229_600, 5_000_000)),
+];
+const LAYOUT_8: &[LayoutPlaceholder] = &[
+    ph("Title", "title", None, (1_792_288, 4_800_600, 5_486_400, 566_738)),
+    ph("Picture", "pic", Some(1), (1_792_288, 612_775, 5_486_400, 4_114_800)),
+    ph("Caption", "body", Some(2), (1_792_288, 5_367_338, 5_486_400, 804_862)),
+];
+const LAYOUT_9: &[LayoutPlaceholder] = &[
+    TITLE,
+    ph("Heading Left", "body", Some(1), (457_200, 1_535_113, 4_040_188, 639_762)),
+    ph("Content Left", "body", Some(2), (457_200, 2_174_875, 4_040_188, 3_951_288)),
+    ph("Heading Right", "body", Some(3), (4_645_025, 1_535_113, 4_041_775, 639_762)),
+    ph("Content Right", "body", Some(4), (4_645_025, 2_174_875, 4_041_775, 3_951_288)),
+];
+
+/// Frame (x, y, cx, cy) laid out for the 4:3 slide, stretched to a slide of `size`
+pub fn scale_bounds(bounds: (u32, u32, u32, u32), size: &SlideSize) -> (u32, u32, u32, u32) {
+    let scale = |v: u32, to: u32, from: u32| (v as u64 * to as u64 / from as u64) as u32;
+    let (x, y, cx, cy) = bounds;
+    (
+        scale(x, size.width, SLIDE_WIDTH_EMU),
+        scale(y, size.height, SLIDE_HEIGHT_EMU),
+        scale(cx, size.width, SLIDE_WIDTH_EMU),
+        scale(cy, size.height, SLIDE_HEIGHT_EMU),
+    )
+}
+
+/// Placeholders of standard layout `n` (1-based), in shape order
+pub fn layout_placeholders(n: usize) -> &'static [LayoutPlaceholder] {
+    match n {
+        1 => LAYOUT_1,
+        2 => LAYOUT_2,
+        3 => LAYOUT_3,
+        4 => LAYOUT_4,
+        6 => LAYOUT_6,
+        7 => LAYOUT_7,
+        8 => LAYOUT_8,
+        9 => LAYOUT_9,
+        _ => &[],
+    }
+}
+
 /// Generate layout XML for layout index `n` (1-based).
 pub fn create_slide_layout_xml(n: usize, _print: Option<&PrintSettings>) -> String {
+    layout_xml(n, |p| p.bounds)
+}
+
+/// Generate layout XML for layout `n` with its placeholders stretched to the slide size
+pub fn create_slide_layout_xml_with_size(n: usize, size: &SlideSize) -> String {
+    layout_xml(n, |p| scale_bounds(p.bounds, size))
+}
+
+/// Generate layout XML for layout `n` under a custom master: placeholders at the
+/// stock title and body frames move to the master's frames, the rest are
+/// stretched to the slide size
+pub fn create_slide_layout_xml_for_master(n: usize, master: &SlideMaster, size: &SlideSize) -> String {
+    layout_xml(n, |p| match p.bounds {
+        TITLE_BOUNDS if p.ph_type == "title" => master.title_frame(size),
+        BODY_BOUNDS if p.ph_type == "body" => master.body_frame(size),
+        bounds => scale_bounds(bounds, size),
+    })
+}
+
+/// `type` attribute of standard layout `n` (1-based), used to find the matching layout in a template
+pub fn layout_type(n: usize) -> &'static str {
     match n {
-        1 => layout_shell(
-            "title",
-            "Title Slide",
-            &format!(
-                "{}{}",
-                placeholder(2, "Title", "ctrTitle", None, 1_524_000, 1_828_800, 6_096_000, 1_828_800),
-                placeholder(3, "Subtitle", "subTitle", None, 1_524_000, 3_657_600, 6_096_000, 914_400),
-            ),
-        ),
-        2 => layout_shell(
-            "obj",
-            "Title and Content",
-            &format!(
-                "{}{}",
-                placeholder(2, "Title", "title", None, 457_200, 274_638, 8_229_600, 1_143_000),
-                placeholder(3, "Content", "body", Some(1), 457_200, 1_600_200, 8_229_600, 4_525_963),
-            ),
-        ),
-        3 => layout_shell(
-            "twoObj",
-            "Two Content",
-            &format!(
-                "{}{}{}",
-                placeholder(2, "Title", "title", None, 457_200, 274_638, 8_229_600, 1_143_000),
-                placeholder(3, "Content Left", "body", Some(1), 457_200, 1_600_200, 4_025_400, 4_525_963),
-                placeholder(4, "Content Right", "body", Some(2), 4_661_400, 1_600_200, 4_025_400, 4_525_963),
-            ),
-        ),
-        4 => layout_shell(
-            "secHead",
-            "Section Header",
-            &format!(
-                "{}{}",
-                placeholder(2, "Title", "title", None, 457_200, 1_600_200, 8_229_600, 1_828_800),
-                placeholder(3, "Subtitle", "body", Some(1), 457_200, 3_657_600, 8_229_600, 1_371_600),
-            ),
-        ),
-        5 => layout_shell("blank", "Blank", ""),
-        6 => layout_shell(
-            "titleOnly",
-            "Title Only",
-            &placeholder(2, "Title", "title", None, 457_200, 274_638, 8_229_600, 1_143_000),
-        ),
-        7 => layout_shell(
-            "obj",
-            "Title and Big Content",
-            &format!(
-                "{}{}",
-                placeholder(2, "Title", "title", None, 457_200, 274_638, 8_229_600, 800_000),
-                placeholder(3, "Content", "body", Some(1), 457_200, 1_200_000, 8_229_600, 5_000_000),
-            ),
-        ),
-        _ => layout_shell("blank", "Blank", ""),
+        1 => "title",
+        2 | 7 => "obj",
+        3 => "twoObj",
+        4 => "secHead",
+        6 => "titleOnly",
+        8 => "picTx",
+        9 => "twoTxTwoObj",
+        _ => "blank",
     }
 }
 
+fn layout_xml(n: usize, frame: impl Fn(&LayoutPlaceholder) -> (u32, u32, u32, u32)) -> String {
+    let name = match n {
+        1 => "Title Slide",
+        2 => "Title and Content",
+        3 => "Two Content",
+        4 => "Section Header",
+        6 => "Title Only",
+        7 => "Title and Big Content",
+        8 => "Picture with Caption",
+        9 => "Comparison",
+        _ => "Blank",
+    };
+    let shapes: String = layout_placeholders(n)
+        .iter()
+        .zip(2u32..)
+        .map(|(p, id)| {
+            let (x, y, cx, cy) = frame(p);
+            placeholder(id, p.name, p.ph_type, p.idx, x, y, cx, cy)
+        })
+        .collect();
+    layout_shell(layout_type(n), name, &shapes)
+}
+
 pub fn create_layout_rels_xml() -> &'static str {
     super::package_cache::layout_rels_xml()
 }
 
 pub fn create_master_rels_xml(layout_count: usize) -> String {
+    numbered_master_rels_xml(0, layout_count, 1)
+}
+
+/// Relationships of slide master `number` (1-based): its standard layouts, which
+/// follow the layouts of earlier masters, and its theme
+pub fn create_numbered_master_rels_xml(number: usize) -> String {
+    numbered_master_rels_xml(
+        (number - 1) * STANDARD_LAYOUT_COUNT,
+        STANDARD_LAYOUT_COUNT,
+        master_theme_number(number),
+    )
+}
+
+fn numbered_master_rels_xml(first_layout: usize, layout_count: usize, theme: usize) -> String {
     let mut xml = String::from(
         r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
 <Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships">"#,
@@ -153,15 +252,28 @@ pub fn create_master_rels_xml(layout_count: usize) -> String {
         xml.push_str("\n<Relationship Id=\"rId");
         append_usize(&mut xml, i);
         xml.push_str("\" Type=\"http://schemas.openxmlformats.org/officeDocument/2006/relationships/slideLayout\" Target=\"../slideLayouts/slideLayout");
-        append_usize(&mut xml, i);
+        append_usize(&mut xml, first_layout + i);
         xml.push_str(".xml\"/>");
     }
     xml.push_str("\n<Relationship Id=\"rId");
     append_usize(&mut xml, layout_count + 1);
-    xml.push_str("\" Type=\"http://schemas.openxmlformats.org/officeDocument/2006/relationships/theme\" Target=\"../theme/theme1.xml\"/>\n</Relationships>");
+    xml.push_str("\" Type=\"http://schemas.openxmlformats.org/officeDocument/2006/relationships/theme\" Target=\"../theme/theme");
+    append_usize(&mut xml, theme);
+    xml.push_str(".xml\"/>\n</Relationships>");
     xml
 }
 
+/// Relationships of a layout on slide master `number`
+pub fn create_numbered_layout_rels_xml(number: usize) -> String {
+    create_layout_rels_xml().replace("slideMaster1.xml", &format!("slideMaster{number}.xml"))
+}
+
+/// Theme part number of slide master `number`: `theme1` for the first master;
+/// `theme2` and `theme3` belong to the notes and handout masters
+pub fn master_theme_number(number: usize) -> usize {
+    if number <= 1 { 1 } else { number + 2 }
+}
+
 pub fn append_layout_content_type_overrides(xml: &mut String, layout_count: usize) {
     for i in 1..=layout_count {
         xml.push_str("\n<Override PartName=\"/ppt/slideLayouts/slideLayout");
@@ -190,6 +302,15 @@ mod tests {
         }
         assert!(create_slide_layout_xml(2, None).contains(r#"type="body""#));
         assert!(create_slide_layout_xml(4, None).contains("secHead"));
+        assert!(create_slide_layout_xml(8, None).contains(r#"type="pic""#));
+        assert!(create_slide_layout_xml(9, None).contains(r#"idx="4""#));
+    }
+
+    #[test]
+    fn test_master_footer_runs_are_not_dirty() {
+        let print = PrintSettings::new().footer("Confidential").print_date(true).print_page_numbers(true);
+        let xml = slide_master_footer_shapes(Some(&print));
+        assert_eq!(xml.matches(r#"sz="1200" dirty="0"/>"#).count(), 3);
     }
 
     #[test]
@@ -199,4 +320,29 @@ mod tests {
         assert!(rels.contains(r#"Id="rId8""#));
         assert!(rels.contains("theme/theme1.xml"));
     }
+
+    #[test]
+    fn test_numbered_master_rels_follow_earlier_masters() {
+        let rels = create_numbered_master_rels_xml(3);
+        assert!(rels.contains(r#"Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/slideLayout" Target="../slideLayouts/slideLayout19.xml""#));
+        assert!(rels.contains("slideLayout27.xml"));
+        assert!(rels.contains(r#"Id="rId10" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/theme" Target="../theme/theme5.xml""#));
+        assert!(create_numbered_layout_rels_xml(3).contains("../slideMasters/slideMaster3.xml"));
+    }
+
+    #[test]
+    fn test_layout_placeholders_fit_slide_size() {
+        use crate::core::SlideSizePreset;
+
+        let wide = SlideSize::from_preset(SlideSizePreset::Screen16x9);
+        assert_eq!(scale_bounds(BODY_BOUNDS, &wide), (457_200, 1_200_150, 8_229_600, 3_394_472));
+        assert_eq!(scale_bounds(BODY_BOUNDS, &SlideSize::default()), BODY_BOUNDS);
+        let xml = create_slide_layout_xml_with_size(2, &wide);
+        assert!(xml.contains(r#"<a:off x="457200" y="1200150"/><a:ext cx="8229600" cy="3394472"/>"#));
+
+        let master = SlideMaster { title_bounds: Some((1, 2, 3, 4)), ..Default::default() };
+        let xml = create_slide_layout_xml_for_master(2, &master, &wide);
+        assert!(xml.contains(r#"<a:off x="1" y="2"/><a:ext cx="3" cy="4"/>"#));
+        assert!(xml.contains(r#"<a:off x="457200" y="1200150"/>"#));
+    }
 }
@@ -4,7 +4,8 @@ use crate::core::XmlWriter;
 use crate::generator::constants::{
     SLIDE_WIDTH, SLIDE_HEIGHT,
 };
-use crate::generator::slide_content::BulletStyle;
+use crate::generator::slide_content::{BulletStyle, BulletTextFormat};
+use crate::generator::text::{color_element_xml, color_to_xml};
 
 /// XML declaration and namespaces
 pub const XML_DECL: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>"#;
@@ -44,6 +45,25 @@ impl ExtendedTextProps {
         }
     }
     
+    /// These defaults overridden by a bullet's own formatting, if it has any
+    pub fn with_bullet_format(&self, format: Option<&BulletTextFormat>) -> Self {
+        match format {
+            Some(fmt) => Self {
+                size: fmt.font_size.map(|s| s * 100).unwrap_or(self.size),
+                bold: fmt.bold || self.bold,
+                italic: fmt.italic || self.italic,
+                underline: fmt.underline || self.underline,
+                strikethrough: fmt.strikethrough,
+                subscript: fmt.subscript,
+                superscript: fmt.superscript,
+                color: fmt.color.clone().or_else(|| self.color.clone()),
+                highlight: fmt.highlight.clone(),
+                font_family: fmt.font_family.clone().or_else(|| self.font_family.clone()),
+            },
+            None => self.clone(),
+        }
+    }
+
     pub fn to_xml(&self) -> String {
         let mut attrs = format!(
             r#"<a:rPr lang="en-US" sz="{}" b="{}" i="{}" dirty="0""#,
@@ -68,18 +88,12 @@ impl ExtendedTextProps {
 
         attrs.push('>');
 
-        if let Some(ref hex_color) = self.color {
-            let clean_color = hex_color.trim_start_matches('#').to_uppercase();
-            attrs.push_str(&format!(
-                r#"<a:solidFill><a:srgbClr val="{clean_color}"/></a:solidFill>"#
-            ));
+        if let Some(ref color) = self.color {
+            attrs.push_str(&color_to_xml(color));
         }
         
         if let Some(ref highlight) = self.highlight {
-            let clean_color = highlight.trim_start_matches('#').to_uppercase();
-            attrs.push_str(&format!(
-                r#"<a:highlight><a:srgbClr val="{clean_color}"/></a:highlight>"#
-            ));
+            attrs.push_str(&format!("<a:highlight>{}</a:highlight>", color_element_xml(highlight)));
         }
         
         if let Some(ref font) = self.font_family {
@@ -20,13 +20,13 @@ use super::slide_content::{SlideContent, SlideLayout};
 
 /// Generate slide XML based on layout type
 pub fn create_slide_xml_for_layout(content: &SlideContent) -> String {
-    match content.layout {
+    match content.effective_layout() {
         SlideLayout::Blank => BlankLayout::generate(),
         SlideLayout::TitleOnly => TitleOnlyLayout::generate(content),
         SlideLayout::CenteredTitle => CenteredTitleLayout::generate(content),
         SlideLayout::TitleAndBigContent => TitleBigContentLayout::generate(content),
-        SlideLayout::TwoColumn => TwoColumnLayout::generate(content),
+        SlideLayout::TwoColumn | SlideLayout::Comparison => TwoColumnLayout::generate(content),
         SlideLayout::SectionHeader => CenteredTitleLayout::generate(content),
-        SlideLayout::TitleAndContent => TitleContentLayout::generate(content),
+        SlideLayout::TitleAndContent | SlideLayout::PictureWithCaption => TitleContentLayout::generate(content),
     }
 }
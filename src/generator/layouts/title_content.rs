@@ -1,6 +1,6 @@
 //! Title and content slide layouts
 
-use super::common::{SlideXmlBuilder, generate_text_props, escape_xml, ShapePosition, TextContent};
+use super::common::{SlideXmlBuilder, ExtendedTextProps, generate_text_props, escape_xml, ShapePosition, TextContent};
 use crate::generator::slide_content::SlideContent;
 use crate::generator::shapes_xml::generate_shape_xml;
 use crate::generator::constants::{
@@ -27,13 +27,14 @@ impl TitleContentLayout {
             content.title_color.as_deref(),
         );
 
-        let content_props = generate_text_props(
+        let content_defaults = ExtendedTextProps::with_basic(
             content_size,
             content.content_bold,
             content.content_italic,
             content.content_underline,
             content.content_color.as_deref(),
         );
+        let content_props = content_defaults.to_xml();
 
         let mut builder = SlideXmlBuilder::new()
             .start_slide_with_bg()
@@ -47,7 +48,7 @@ impl TitleContentLayout {
             // Use bullets with styles
             builder = builder.start_content_body(3, CONTENT_X, CONTENT_Y_START, CONTENT_WIDTH, CONTENT_HEIGHT);
             for bullet in &content.bullets {
-                builder = builder.add_bullet_with_style(&bullet.text, &content_props, bullet.level, bullet.style);
+                builder = builder.add_bullet_with_style(&bullet.text, &content_defaults.with_bullet_format(bullet.format.as_ref()).to_xml(), bullet.level, bullet.style);
             }
             builder = builder.end_content_body();
         } else if !content.content.is_empty() {
@@ -90,7 +91,7 @@ impl TitleContentLayout {
 <a:p>
 <a:pPr algn="ctr"/>
 <a:r>
-<a:rPr lang="en-US" sz="1400"/>
+<a:rPr lang="en-US" sz="1400" dirty="0"/>
 <a:t>📷 {}</a:t>
 </a:r>
 </a:p>
@@ -130,13 +131,14 @@ impl TitleBigContentLayout {
             content.title_color.as_deref(),
         );
 
-        let content_props = generate_text_props(
+        let content_defaults = ExtendedTextProps::with_basic(
             content_size,
             content.content_bold,
             content.content_italic,
             content.content_underline,
             content.content_color.as_deref(),
         );
+        let content_props = content_defaults.to_xml();
 
         let mut builder = SlideXmlBuilder::new()
             .start_slide_with_bg()
@@ -146,7 +148,7 @@ impl TitleBigContentLayout {
         if !content.bullets.is_empty() {
             builder = builder.start_content_body(3, CONTENT_X, CONTENT_Y_START_BIG, CONTENT_WIDTH, CONTENT_HEIGHT_BIG);
             for bullet in &content.bullets {
-                builder = builder.add_bullet_with_style(&bullet.text, &content_props, bullet.level, bullet.style);
+                builder = builder.add_bullet_with_style(&bullet.text, &content_defaults.with_bullet_format(bullet.format.as_ref()).to_xml(), bullet.level, bullet.style);
             }
             builder = builder.end_content_body();
         } else if !content.content.is_empty() {
@@ -167,6 +169,7 @@ impl TitleBigContentLayout {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::generator::slide_content::BulletPoint;
 
     #[test]
     fn test_title_content_layout() {
@@ -189,4 +192,17 @@ mod tests {
         assert!(xml.contains("Big Content"));
         assert!(xml.contains("5668800")); // Larger content height
     }
+
+    #[test]
+    fn test_bullet_format_overrides_content_props() {
+        let content = SlideContent::new("Risks")
+            .add_bullet("Budget")
+            .add_bullet_point(BulletPoint::new("Schedule").font_size(28).color("FF0000"));
+        let xml = TitleContentLayout::generate(&content);
+
+        let hot = &xml[xml.find("<a:t>Budget</a:t>").unwrap()..xml.find("<a:t>Schedule</a:t>").unwrap()];
+        assert!(hot.contains(r#"sz="2800""#));
+        assert!(hot.contains(r#"<a:srgbClr val="FF0000"/>"#));
+        assert_eq!(xml.matches("FF0000").count(), 1);
+    }
 }
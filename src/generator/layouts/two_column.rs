@@ -1,6 +1,6 @@
 //! Two-column slide layout
 
-use super::common::{SlideXmlBuilder, generate_text_props, ShapePosition, TextContent};
+use super::common::{SlideXmlBuilder, ExtendedTextProps, generate_text_props, ShapePosition, TextContent};
 use crate::generator::slide_content::SlideContent;
 
 /// Two-column slide layout generator
@@ -21,13 +21,14 @@ impl TwoColumnLayout {
             content.title_color.as_deref(),
         );
 
-        let content_props = generate_text_props(
+        let content_defaults = ExtendedTextProps::with_basic(
             content_size,
             content.content_bold,
             content.content_italic,
             content.content_underline,
             content.content_color.as_deref(),
         );
+        let content_props = content_defaults.to_xml();
 
         let mut builder = SlideXmlBuilder::new()
             .start_slide_with_bg()
@@ -39,7 +40,7 @@ impl TwoColumnLayout {
         let bullet_count = if use_styled_bullets { content.bullets.len() } else { content.content.len() };
         
         if bullet_count > 0 {
-            let mid = bullet_count.div_ceil(2);
+            let mid = content.column_split();
 
             // Left column
             builder = builder.raw(r#"
@@ -64,7 +65,7 @@ impl TwoColumnLayout {
 
             if use_styled_bullets {
                 for bullet in &content.bullets[..mid] {
-                    builder = builder.add_bullet_with_style(&bullet.text, &content_props, bullet.level, bullet.style);
+                    builder = builder.add_bullet_with_style(&bullet.text, &content_defaults.with_bullet_format(bullet.format.as_ref()).to_xml(), bullet.level, bullet.style);
                 }
             } else {
                 for bullet in &content.content[..mid] {
@@ -97,7 +98,7 @@ impl TwoColumnLayout {
 
                 if use_styled_bullets {
                     for bullet in &content.bullets[mid..] {
-                        builder = builder.add_bullet_with_style(&bullet.text, &content_props, bullet.level, bullet.style);
+                        builder = builder.add_bullet_with_style(&bullet.text, &content_defaults.with_bullet_format(bullet.format.as_ref()).to_xml(), bullet.level, bullet.style);
                     }
                 } else {
                     for bullet in &content.content[mid..] {
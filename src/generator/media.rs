@@ -1,11 +1,34 @@
 //! Media embedding support for PPTX (video and audio)
 //!
 //! Provides types and XML generation for embedding videos and audio files.
+//!
+//! An embedded clip is stored once under `ppt/media/` and related twice from the
+//! slide: as a `p14:media` embed and as the `a:videoFile`/`a:audioFile` link.
+//! Its picture frame shows a poster image until playback starts.
+
+use std::path::Path;
 
 use crate::core::escape_xml;
+use super::images::Image;
+
+/// Relationship type of the `p14:media` embed
+pub const MEDIA_RELATIONSHIP_TYPE: &str = "http://schemas.microsoft.com/office/2007/relationships/media";
+
+/// Relationship type of the `a:videoFile` link
+pub const VIDEO_RELATIONSHIP_TYPE: &str = "http://schemas.openxmlformats.org/officeDocument/2006/relationships/video";
+
+/// Relationship type of the `a:audioFile` link
+pub const AUDIO_RELATIONSHIP_TYPE: &str = "http://schemas.openxmlformats.org/officeDocument/2006/relationships/audio";
+
+/// Black 16:9 frame shown for videos without a poster image
+const DEFAULT_POSTER: &[u8] = include_bytes!("video_poster.png");
+
+/// Speaker icon drawn for embedded audio
+const AUDIO_ICON: &[u8] = include_bytes!("audio_icon.png");
 
 /// Video format types
 #[derive(Clone, Debug, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum VideoFormat {
     Mp4,
     Wmv,
@@ -60,6 +83,7 @@ impl VideoFormat {
 
 /// Audio format types
 #[derive(Clone, Debug, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AudioFormat {
     Mp3,
     Wav,
@@ -114,6 +138,7 @@ impl AudioFormat {
 
 /// Video playback options
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct VideoOptions {
     /// Auto-play when slide is shown
     pub auto_play: bool,
@@ -129,6 +154,9 @@ pub struct VideoOptions {
     pub end_time: Option<u32>,
     /// Volume (0-100)
     pub volume: u32,
+    /// Cover image shown before playback
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub poster: Option<Image>,
 }
 
 impl Default for VideoOptions {
@@ -141,6 +169,7 @@ impl Default for VideoOptions {
             start_time: None,
             end_time: None,
             volume: 100,
+            poster: None,
         }
     }
 }
@@ -183,10 +212,49 @@ impl VideoOptions {
         self.end_time = Some(ms);
         self
     }
+
+    /// Set the cover image shown before playback
+    pub fn poster(mut self, image: Image) -> Self {
+        self.poster = Some(image);
+        self
+    }
+
+    /// Play when the slide is shown instead of on click
+    pub fn autoplay(mut self, auto_play: bool) -> Self {
+        self.auto_play = auto_play;
+        self
+    }
+
+    /// Repeat until the slide is left
+    pub fn loop_playback(mut self, loop_playback: bool) -> Self {
+        self.loop_playback = loop_playback;
+        self
+    }
+
+    /// Skip the first `ms` milliseconds of the clip
+    pub fn trim_start(self, ms: u32) -> Self {
+        self.with_start_time(ms)
+    }
+
+    /// Stop playback `ms` milliseconds into the clip
+    pub fn trim_end(self, ms: u32) -> Self {
+        self.with_end_time(ms)
+    }
+
+    /// `<p14:trim>` for the start and end times, if any
+    fn trim_xml(&self) -> String {
+        if self.start_time.is_none() && self.end_time.is_none() {
+            return String::new();
+        }
+        let st = self.start_time.map(|ms| format!(r#" st="{ms}""#)).unwrap_or_default();
+        let end = self.end_time.map(|ms| format!(r#" end="{ms}""#)).unwrap_or_default();
+        format!("<p14:trim{st}{end}/>")
+    }
 }
 
 /// Audio playback options
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AudioOptions {
     /// Auto-play when slide is shown
     pub auto_play: bool,
@@ -233,6 +301,12 @@ impl AudioOptions {
         self
     }
 
+    /// Hide the speaker icon during the slide show
+    pub fn hide_icon(mut self, hide: bool) -> Self {
+        self.hide_during_show = hide;
+        self
+    }
+
     /// Set volume (0-100)
     pub fn with_volume(mut self, volume: u32) -> Self {
         self.volume = volume.min(100);
@@ -242,6 +316,7 @@ impl AudioOptions {
 
 /// Video element
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Video {
     /// Video file path or URL
     pub source: String,
@@ -261,6 +336,9 @@ pub struct Video {
     pub poster: Option<String>,
     /// Alt text
     pub alt_text: Option<String>,
+    /// Clip bytes; when unset the clip is read from `source`
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub data: Option<Vec<u8>>,
 }
 
 impl Video {
@@ -276,6 +354,62 @@ impl Video {
             options: VideoOptions::default(),
             poster: None,
             alt_text: None,
+            data: None,
+        }
+    }
+
+    /// Create from in-memory clip bytes
+    pub fn from_bytes(data: Vec<u8>, format: VideoFormat, x: u32, y: u32, width: u32, height: u32) -> Self {
+        let source = format!("video.{}", format.extension());
+        Video {
+            data: Some(data),
+            ..Self::new(&source, format, x, y, width, height)
+        }
+    }
+
+    /// Whether the clip can be embedded (in-memory bytes or an existing file)
+    pub fn has_data(&self) -> bool {
+        self.data.is_some() || Path::new(&self.source).is_file()
+    }
+
+    /// Clip bytes to embed
+    pub fn get_bytes(&self) -> Option<Vec<u8>> {
+        match &self.data {
+            Some(data) => Some(data.clone()),
+            None => std::fs::read(&self.source).ok(),
+        }
+    }
+
+    /// Poster image bytes and extension: the options' poster, then the `with_poster`
+    /// file, then a plain black frame
+    pub fn poster_bytes(&self) -> (Vec<u8>, String) {
+        if let Some(image) = &self.options.poster
+            && let Some(bytes) = image.get_bytes() {
+                return (bytes, image.extension());
+            }
+        if let Some(path) = &self.poster
+            && let Ok(bytes) = std::fs::read(path) {
+                let ext = Path::new(path)
+                    .extension()
+                    .and_then(|ext| ext.to_str())
+                    .map(|ext| ext.to_lowercase())
+                    .unwrap_or_else(|| "png".to_string());
+                return (bytes, ext);
+            }
+        (DEFAULT_POSTER.to_vec(), "png".to_string())
+    }
+
+    /// Timing wiring for this video drawn as shape `shape_id`
+    pub fn playback(&self, shape_id: u32) -> MediaPlayback {
+        MediaPlayback {
+            shape_id,
+            video: true,
+            auto_play: self.options.auto_play,
+            loop_playback: self.options.loop_playback,
+            muted: self.options.muted,
+            hide_when_stopped: self.options.hide_when_stopped,
+            play_across_slides: false,
+            volume: self.options.volume,
         }
     }
 
@@ -305,8 +439,27 @@ impl Video {
     }
 }
 
+/// How a media shape plays during the slide show
+#[derive(Clone, Debug, Copy, PartialEq, Eq)]
+pub struct MediaPlayback {
+    /// `cNvPr` id of the media picture
+    pub shape_id: u32,
+    /// Video rather than audio
+    pub video: bool,
+    /// Start with the slide instead of on click
+    pub auto_play: bool,
+    pub loop_playback: bool,
+    pub muted: bool,
+    pub hide_when_stopped: bool,
+    /// Keep playing as the following slides are shown
+    pub play_across_slides: bool,
+    /// Volume (0-100)
+    pub volume: u32,
+}
+
 /// Audio element
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Audio {
     /// Audio file path or URL
     pub source: String,
@@ -324,6 +477,9 @@ pub struct Audio {
     pub options: AudioOptions,
     /// Alt text
     pub alt_text: Option<String>,
+    /// Clip bytes; when unset the clip is read from `source`
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub data: Option<Vec<u8>>,
 }
 
 impl Audio {
@@ -338,6 +494,48 @@ impl Audio {
             height,
             options: AudioOptions::default(),
             alt_text: None,
+            data: None,
+        }
+    }
+
+    /// Create from in-memory clip bytes
+    pub fn from_bytes(data: Vec<u8>, format: AudioFormat, x: u32, y: u32, width: u32, height: u32) -> Self {
+        let source = format!("audio.{}", format.extension());
+        Audio {
+            data: Some(data),
+            ..Self::new(&source, format, x, y, width, height)
+        }
+    }
+
+    /// Whether the clip can be embedded (in-memory bytes or an existing file)
+    pub fn has_data(&self) -> bool {
+        self.data.is_some() || Path::new(&self.source).is_file()
+    }
+
+    /// Clip bytes to embed
+    pub fn get_bytes(&self) -> Option<Vec<u8>> {
+        match &self.data {
+            Some(data) => Some(data.clone()),
+            None => std::fs::read(&self.source).ok(),
+        }
+    }
+
+    /// Speaker icon bytes and extension
+    pub fn icon_bytes(&self) -> (Vec<u8>, String) {
+        (AUDIO_ICON.to_vec(), "png".to_string())
+    }
+
+    /// Timing wiring for this clip drawn as shape `shape_id`
+    pub fn playback(&self, shape_id: u32) -> MediaPlayback {
+        MediaPlayback {
+            shape_id,
+            video: false,
+            auto_play: self.options.auto_play,
+            loop_playback: self.options.loop_playback,
+            muted: false,
+            hide_when_stopped: self.options.hide_during_show,
+            play_across_slides: self.options.play_across_slides,
+            volume: self.options.volume,
         }
     }
 
@@ -361,9 +559,26 @@ impl Audio {
     }
 }
 
-/// Generate video XML for slide
-pub fn generate_video_xml(video: &Video, shape_id: usize, video_r_id: &str, _image_r_id: &str) -> String {
+/// Generate video XML for slide.
+///
+/// `media_r_id` is the `p14:media` embed, `video_r_id` the `a:videoFile` link to the
+/// same part, and `image_r_id` the poster image filling the frame.
+pub fn generate_video_xml(
+    video: &Video,
+    shape_id: usize,
+    media_r_id: &str,
+    video_r_id: &str,
+    image_r_id: &str,
+) -> String {
     let alt_text = video.alt_text.as_deref().unwrap_or("Video");
+    let trim = video.options.trim_xml();
+    let media = if trim.is_empty() {
+        format!(r#"<p14:media xmlns:p14="http://schemas.microsoft.com/office/powerpoint/2010/main" r:embed="{media_r_id}"/>"#)
+    } else {
+        format!(
+            r#"<p14:media xmlns:p14="http://schemas.microsoft.com/office/powerpoint/2010/main" r:embed="{media_r_id}">{trim}</p14:media>"#
+        )
+    };
 
     format!(
         r#"<p:pic>
@@ -378,7 +593,7 @@ pub fn generate_video_xml(video: &Video, shape_id: usize, video_r_id: &str, _ima
 <a:videoFile r:link="{}"/>
 <p:extLst>
 <p:ext uri="{{DAA4B4D4-6D71-4841-9C94-3DE7FCFB9230}}">
-<p14:media xmlns:p14="http://schemas.microsoft.com/office/powerpoint/2010/main" r:embed="{}"/>
+{}
 </p:ext>
 </p:extLst>
 </p:nvPr>
@@ -400,13 +615,33 @@ pub fn generate_video_xml(video: &Video, shape_id: usize, video_r_id: &str, _ima
 </p:spPr>
 </p:pic>"#,
         shape_id, shape_id, escape_xml(alt_text),
-        video_r_id, video_r_id, video_r_id,
+        video_r_id, media,
+        image_r_id,
         video.x, video.y, video.width, video.height
     )
 }
 
-/// Generate audio XML for slide
-pub fn generate_audio_xml(audio: &Audio, shape_id: usize, audio_r_id: &str) -> String {
+/// Slide relationships for an embedded media part: the `p14:media` embed at
+/// `first_rid`, then the `link_type` link (video or audio) at `first_rid + 1`
+pub fn generate_media_relationships(first_rid: usize, target: &str, link_type: &str) -> [String; 2] {
+    let target = escape_xml(target);
+    [
+        format!(r#"<Relationship Id="rId{first_rid}" Type="{MEDIA_RELATIONSHIP_TYPE}" Target="{target}"/>"#),
+        format!(r#"<Relationship Id="rId{}" Type="{link_type}" Target="{target}"/>"#, first_rid + 1),
+    ]
+}
+
+/// Generate audio XML for slide.
+///
+/// `media_r_id` is the `p14:media` embed, `audio_r_id` the `a:audioFile` link to the
+/// same part, and `image_r_id` the speaker icon.
+pub fn generate_audio_xml(
+    audio: &Audio,
+    shape_id: usize,
+    media_r_id: &str,
+    audio_r_id: &str,
+    image_r_id: &str,
+) -> String {
     let alt_text = audio.alt_text.as_deref().unwrap_or("Audio");
 
     format!(
@@ -444,7 +679,7 @@ pub fn generate_audio_xml(audio: &Audio, shape_id: usize, audio_r_id: &str) -> S
 </p:spPr>
 </p:pic>"#,
         shape_id, shape_id, escape_xml(alt_text),
-        audio_r_id, audio_r_id, audio_r_id,
+        audio_r_id, media_r_id, image_r_id,
         audio.x, audio.y, audio.width, audio.height
     )
 }
@@ -508,9 +743,13 @@ mod tests {
     #[test]
     fn test_audio_options() {
         let opts = AudioOptions::auto_play()
-            .with_play_across_slides(true);
+            .with_play_across_slides(true)
+            .hide_icon(true);
         assert!(opts.auto_play);
         assert!(opts.play_across_slides);
+
+        let playback = Audio::from_bytes(vec![0; 4], AudioFormat::Mp3, 0, 0, 1, 1).with_options(opts).playback(7);
+        assert!(!playback.video && playback.play_across_slides && playback.hide_when_stopped);
     }
 
     #[test]
@@ -541,16 +780,57 @@ mod tests {
     #[test]
     fn test_generate_video_xml() {
         let video = Video::new("video.mp4", VideoFormat::Mp4, 0, 0, 1000000, 750000);
-        let xml = generate_video_xml(&video, 1, "rId1", "rId2");
+        let xml = generate_video_xml(&video, 1, "rId1", "rId2", "rId3");
         assert!(xml.contains("p:pic"));
-        assert!(xml.contains("videoFile"));
+        assert!(xml.contains(r#"<a:videoFile r:link="rId2"/>"#));
+        assert!(xml.contains(r#"r:embed="rId1"/>"#));
+        assert!(xml.contains(r#"<a:blip r:embed="rId3"/>"#));
+        assert!(!xml.contains("p14:trim"));
+    }
+
+    #[test]
+    fn test_video_poster_and_trim() {
+        let poster = Image::from_bytes(vec![1, 2, 3], 100, 100, "JPG");
+        let opts = VideoOptions::default()
+            .poster(poster)
+            .autoplay(true)
+            .loop_playback(true)
+            .trim_start(1500)
+            .trim_end(9000);
+        assert!(opts.auto_play && opts.loop_playback);
+        let video = Video::from_bytes(vec![0; 8], VideoFormat::Mp4, 0, 0, 1000000, 750000).with_options(opts);
+        assert_eq!(video.poster_bytes(), (vec![1, 2, 3], "jpg".to_string()));
+
+        let xml = generate_video_xml(&video, 5, "rId4", "rId5", "rId6");
+        assert!(xml.contains(r#"r:embed="rId4"><p14:trim st="1500" end="9000"/></p14:media>"#));
+
+        let playback = video.playback(5);
+        assert!(playback.video && playback.auto_play && playback.loop_playback);
+    }
+
+    #[test]
+    fn test_video_default_poster() {
+        let video = Video::from_bytes(vec![0; 8], VideoFormat::Mp4, 0, 0, 1, 1);
+        assert!(video.has_data());
+        let (bytes, ext) = video.poster_bytes();
+        assert!(bytes.starts_with(b"\x89PNG"));
+        assert_eq!(ext, "png");
+        assert!(!Video::new("missing.mp4", VideoFormat::Mp4, 0, 0, 1, 1).has_data());
+    }
+
+    #[test]
+    fn test_media_relationships() {
+        let [media, link] = generate_media_relationships(4, "../media/media1.mp4", VIDEO_RELATIONSHIP_TYPE);
+        assert!(media.contains(r#"Id="rId4" Type="http://schemas.microsoft.com/office/2007/relationships/media""#));
+        assert!(link.contains(r#"Id="rId5" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/video" Target="../media/media1.mp4""#));
     }
 
     #[test]
     fn test_generate_audio_xml() {
         let audio = Audio::new("audio.mp3", AudioFormat::Mp3, 0, 0, 500000, 500000);
-        let xml = generate_audio_xml(&audio, 1, "rId1");
+        let xml = generate_audio_xml(&audio, 1, "rId1", "rId2", "rId3");
         assert!(xml.contains("p:pic"));
-        assert!(xml.contains("audioFile"));
+        assert!(xml.contains(r#"<a:audioFile r:link="rId2"/>"#));
+        assert!(xml.contains(r#"<a:blip r:embed="rId3"/>"#));
     }
 }
@@ -1,33 +1,121 @@
-//! Deduplicate embedded slide media by byte content.
+//! Deduplicate embedded slide media by content hash.
 
-/// Assigns stable 1-based `imageN` part numbers, reusing prior entries for identical bytes.
-#[derive(Default)]
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+
+/// Which numbering a blob belongs to: `imageN` parts or `mediaN` video/audio clips
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+enum MediaKind {
+    Image,
+    Clip,
+}
+
+/// Bucket of a blob: its content hash, extension, and the slide it is scoped to
+/// (always 0 when deduplicating across the whole deck). Blobs in the same bucket
+/// are compared byte for byte.
+#[derive(PartialEq, Eq, Hash)]
+struct MediaKey {
+    kind: MediaKind,
+    slide: usize,
+    hash: u64,
+    ext: String,
+}
+
+/// Assigns stable 1-based `imageN` (and `mediaN` for video/audio clips) part numbers,
+/// reusing prior entries for identical bytes.
+///
+/// With deduplication off, identical bytes are still shared within one slide but
+/// every slide gets its own copy.
 pub struct MediaRegistry {
+    dedup: bool,
+    index: HashMap<MediaKey, Vec<usize>>,
     entries: Vec<(Vec<u8>, String)>,
+    clips: Vec<(Vec<u8>, String)>,
+}
+
+impl Default for MediaRegistry {
+    fn default() -> Self {
+        Self::new(true)
+    }
 }
 
 impl MediaRegistry {
-    pub fn image_number(&mut self, bytes: &[u8], ext: &str) -> usize {
-        if let Some(num) = self.lookup_number(bytes, ext) {
-            return num;
+    pub fn new(dedup: bool) -> Self {
+        Self { dedup, index: HashMap::new(), entries: Vec::new(), clips: Vec::new() }
+    }
+
+    fn key(&self, kind: MediaKind, slide: usize, bytes: &[u8], ext: &str) -> MediaKey {
+        let mut hasher = DefaultHasher::new();
+        bytes.hash(&mut hasher);
+        MediaKey {
+            kind,
+            slide: if self.dedup { 0 } else { slide },
+            hash: hasher.finish(),
+            ext: ext.to_string(),
+        }
+    }
+
+    fn list(&self, kind: MediaKind) -> &[(Vec<u8>, String)] {
+        match kind {
+            MediaKind::Image => &self.entries,
+            MediaKind::Clip => &self.clips,
         }
-        self.entries.push((bytes.to_vec(), ext.to_string()));
-        self.entries.len()
     }
 
-    pub fn lookup_number(&self, bytes: &[u8], ext: &str) -> Option<usize> {
-        self.entries
+    /// Part number of already registered bytes
+    fn find(&self, kind: MediaKind, slide: usize, bytes: &[u8], ext: &str) -> Option<usize> {
+        let list = self.list(kind);
+        self.index
+            .get(&self.key(kind, slide, bytes, ext))?
             .iter()
-            .position(|(existing, existing_ext)| existing.as_slice() == bytes && existing_ext == ext)
-            .map(|i| i + 1)
+            .copied()
+            .find(|&num| list[num - 1].0 == bytes)
+    }
+
+    fn register(&mut self, kind: MediaKind, slide: usize, bytes: &[u8], ext: &str) -> usize {
+        if let Some(num) = self.find(kind, slide, bytes, ext) {
+            return num;
+        }
+        let key = self.key(kind, slide, bytes, ext);
+        let list = match kind {
+            MediaKind::Image => &mut self.entries,
+            MediaKind::Clip => &mut self.clips,
+        };
+        list.push((bytes.to_vec(), ext.to_string()));
+        let num = list.len();
+        self.index.entry(key).or_default().push(num);
+        num
+    }
+
+    /// Part number of an image on `slide`, registering it if new
+    pub fn image_number(&mut self, slide: usize, bytes: &[u8], ext: &str) -> usize {
+        self.register(MediaKind::Image, slide, bytes, ext)
+    }
+
+    pub fn lookup_number(&self, slide: usize, bytes: &[u8], ext: &str) -> Option<usize> {
+        self.find(MediaKind::Image, slide, bytes, ext)
     }
 
     pub fn files(&self) -> &[(Vec<u8>, String)] {
         &self.entries
     }
 
+    /// Part number of a video or audio clip on `slide`, registering it if new
+    pub fn media_number(&mut self, slide: usize, bytes: &[u8], ext: &str) -> usize {
+        self.register(MediaKind::Clip, slide, bytes, ext)
+    }
+
+    pub fn lookup_media_number(&self, slide: usize, bytes: &[u8], ext: &str) -> Option<usize> {
+        self.find(MediaKind::Clip, slide, bytes, ext)
+    }
+
+    pub fn media_files(&self) -> &[(Vec<u8>, String)] {
+        &self.clips
+    }
+
     pub fn extensions(&self) -> Vec<String> {
-        self.entries.iter().map(|(_, ext)| ext.clone()).collect()
+        self.entries.iter().chain(&self.clips).map(|(_, ext)| ext.clone()).collect()
     }
 }
 
@@ -38,12 +126,35 @@ mod tests {
     #[test]
     fn deduplicates_identical_image_bytes() {
         let mut registry = MediaRegistry::default();
-        let a = registry.image_number(b"same", "png");
-        let b = registry.image_number(b"other", "jpg");
-        let c = registry.image_number(b"same", "png");
+        let a = registry.image_number(1, b"same", "png");
+        let b = registry.image_number(1, b"other", "jpg");
+        let c = registry.image_number(2, b"same", "png");
         assert_eq!(a, 1);
         assert_eq!(b, 2);
         assert_eq!(c, 1);
         assert_eq!(registry.files().len(), 2);
+        assert_eq!(registry.lookup_number(7, b"same", "png"), Some(1));
+        assert_eq!(registry.lookup_number(1, b"same", "jpg"), None);
+    }
+
+    #[test]
+    fn numbers_clips_separately_from_images() {
+        let mut registry = MediaRegistry::default();
+        registry.image_number(1, b"poster", "png");
+        assert_eq!(registry.media_number(1, b"clip", "mp4"), 1);
+        assert_eq!(registry.media_number(2, b"clip", "mp4"), 1);
+        assert_eq!(registry.media_files().len(), 1);
+        assert_eq!(registry.extensions(), ["png", "mp4"]);
+    }
+
+    #[test]
+    fn without_dedup_each_slide_gets_its_own_copy() {
+        let mut registry = MediaRegistry::new(false);
+        assert_eq!(registry.image_number(1, b"logo", "png"), 1);
+        assert_eq!(registry.image_number(1, b"logo", "png"), 1);
+        assert_eq!(registry.image_number(2, b"logo", "png"), 2);
+        assert_eq!(registry.lookup_number(2, b"logo", "png"), Some(2));
+        assert_eq!(registry.lookup_number(3, b"logo", "png"), None);
+        assert_eq!(registry.files().len(), 2);
     }
 }
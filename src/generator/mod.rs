@@ -9,6 +9,7 @@ pub mod package_xml;
 pub mod slide_xml;
 pub mod theme_xml;
 pub mod presentation_theme;
+pub mod slide_master;
 pub mod props_xml;
 
 pub mod layout_parts;
@@ -22,12 +23,16 @@ pub mod builder;
 pub mod memory_profile;
 pub mod package_cache;
 pub mod media_registry;
+#[cfg(feature = "parallel")]
+mod parallel;
 
 // Text module (modularized)
 pub mod text;
 
 pub mod shapes;
 pub mod shapes_xml;
+pub mod shape_group;
+pub mod diagram;
 
 // Table module (modularized)
 pub mod table;
@@ -59,17 +64,20 @@ pub use builder::{
 };
 pub use template::PptxTemplate;
 pub use layout_parts::STANDARD_LAYOUT_COUNT;
-pub use notes_xml::{create_notes_xml, create_notes_rels_xml, create_notes_master_xml, create_notes_master_rels_xml};
-pub use slide_content::{SlideContent, SlideLayout};
+pub use notes_xml::{create_notes_xml, create_notes_rels_xml, create_notes_master_xml, create_notes_master_xml_with_header_footer, create_notes_master_rels_xml};
+pub use slide_content::{SlideContent, SlideLayout, PlaceholderType};
 pub use presentation_theme::{PresentationTheme, ThemeColorScheme, ThemeFonts};
-pub use slide_content::{CodeBlock, BulletStyle, BulletPoint, BulletTextFormat, TransitionType, Comment, CommentAuthor, CommentAuthorList, SlideComments, SlideSection, SectionManager, DigitalSignature, SignerInfo, HashAlgorithm, SignatureCommitment, InkAnnotations, InkStroke, InkPen, InkPoint, PenTip, SlideShowSettings, ShowType, PenColor, SlideRange, PrintSettings, HandoutLayout, PrintColorMode, PrintWhat, Orientation, TableMergeMap, MergeRegion, CellMergeState, EmbeddedFontList, EmbeddedFont, FontStyle, FontCharset, PresentationSettings};
-pub use text::{TextFormat, FormattedText, TextFrame, Paragraph, Run, TextAlign, TextAnchor, TextDirection, RtlLanguage, RtlTextProps};
-pub use shapes::{Shape, ShapeType, ShapeFill, ShapeLine, GradientFill as ShapeGradientFill, GradientStop as ShapeGradientStop, GradientDirection as ShapeGradientDirection, FillType, emu_to_inches, inches_to_emu, cm_to_emu};
+pub use slide_master::{MasterBuilder, SlideMaster};
+pub use slide_content::{CodeBlock, CodeTheme, BulletStyle, BulletPoint, BulletTextFormat, TransitionType, TransitionDirection, SlideTransition, Animation, AnimationEffect, AnimationTrigger, SlideAnimation, Comment, CommentAuthor, CommentAuthorList, SlideComments, SlideSection, SectionManager, DigitalSignature, SignerInfo, HashAlgorithm, SignatureCommitment, InkAnnotations, InkStroke, InkPen, InkPoint, PenTip, SlideShowSettings, ShowType, PenColor, SlideRange, PrintSettings, HandoutLayout, PrintColorMode, PrintWhat, Orientation, TableMergeMap, MergeRegion, CellMergeState, EmbeddedFontList, EmbeddedFont, FontStyle, FontCharset, PresentationSettings, SlideBackground, HeaderFooter, DateMode, PageHeaderFooter, Compression};
+pub use text::{TextFormat, FormattedText, TextFrame, Paragraph, Run, TextAlign, TextAnchor, TextOrientation, AutoFit, LineSpacing, TextDirection, RtlLanguage, RtlTextProps};
+pub use shapes::{Shape, ShapeType, ShapeFill, ShapeLine, ShapeShadow, PresetShadow, ShapeGlow, ReflectionPreset, PathSegment, GradientFill as ShapeGradientFill, GradientStop as ShapeGradientStop, GradientDirection as ShapeGradientDirection, FillType, emu_to_inches, inches_to_emu, cm_to_emu};
 pub use shapes_xml::{generate_shape_xml, generate_shapes_xml, generate_connector_xml};
+pub use shape_group::{ShapeGroup, generate_group_xml};
+pub use diagram::{Diagram, DiagramKind, DiagramNode};
 pub use table::{Table, TableRow, TableCell, TableBuilder, CellAlign, CellVAlign, generate_table_xml as generate_table_xml_new};
-pub use images::{Image, ImageBuilder, ImageSource};
+pub use images::{Image, ImageBuilder, ImageSource, detect_image_format, image_format_from_content_type};
 pub use images_xml::{generate_image_xml, generate_image_relationship, generate_image_content_type};
-pub use charts::{Chart, ChartType, ChartSeries, ChartBuilder, generate_chart_part_xml, generate_chart_ref_xml};
+pub use charts::{Chart, ChartType, ChartSeries, ChartBuilder, AxisOptions, ChartFont, DataLabelOptions, DataLabelPosition, LegendPosition, TickLabelPosition, TickMark, ValueAxisScale, generate_chart_part_xml, generate_chart_ref_xml};
 
 // New element exports
 pub use connectors::{Connector, ConnectorType, ConnectorLine, ArrowType, ArrowSize, ConnectionSite, LineDash, generate_connector_xml as generate_cxn_xml};
@@ -152,4 +160,65 @@ mod tests {
         assert_eq!(slide.bullets[2].level, 1);
         assert_eq!(slide.bullets[3].level, 0);
     }
+    
+    #[test]
+    fn test_outline_numbering() {
+        let slide = SlideContent::new("Outline")
+            .add_outline_item("Plan", 0)
+            .add_outline_item("Budget", 1)
+            .add_outline_item("Staffing", 1)
+            .add_bullet_point(BulletPoint::new("Review").with_style(BulletStyle::Number).start_at(2));
+        
+        assert_eq!(slide.bullets[0].style, BulletStyle::Number);
+        assert_eq!(slide.bullets[1].style, BulletStyle::LetterLower);
+        assert_eq!(slide.content[1], "  Budget");
+        
+        let xml = slide_xml::create_slide_xml_with_content(1, &slide, &[], None);
+        assert!(xml.contains(r#"<a:buAutoNum type="arabicPeriod"/>"#));
+        assert!(xml.contains(r#"<a:buAutoNum type="alphaLcPeriod"/>"#));
+        assert!(xml.contains(r#"<a:buAutoNum type="arabicPeriod" startAt="2"/>"#));
+        
+        // startAt is ignored for non-numbered styles
+        assert_eq!(BulletStyle::Bullet.to_xml_with_start(Some(3)), BulletStyle::Bullet.to_xml());
+    }
+
+    #[test]
+    fn test_formatted_bullet_runs() {
+        let slide = SlideContent::new("Status")
+            .add_formatted_bullet(vec![
+                FormattedText::new("Status: "),
+                FormattedText::new("Done").bold().color("00B050"),
+            ])
+            .add_bullet_point(BulletPoint::formatted(vec![FormattedText::new("Nested").italic()]).with_level(1));
+
+        assert_eq!(slide.bullets[0].text, "Status: Done");
+        assert_eq!(slide.content[1], "  Nested");
+
+        let xml = slide_xml::create_slide_xml_with_content(1, &slide, &[], None);
+        let status = &xml[xml.find(r#"<a:pPr lvl="0""#).unwrap()..xml.find(r#"<a:pPr lvl="1""#).unwrap()];
+        assert!(status.contains(r#"<a:buChar char="•"/>"#));
+        assert!(status.contains(r#"b="0" i="0" dirty="0">"#));
+        assert!(status.contains("<a:t>Status: </a:t>"));
+        assert!(status.contains(r#"b="1" i="0" dirty="0"><a:solidFill><a:srgbClr val="00B050"/></a:solidFill>"#));
+        assert!(status.contains("<a:t>Done</a:t>"));
+        assert!(xml.contains(r#"i="1" dirty="0">"#));
+    }
+
+    #[test]
+    fn test_highlighted_bullet_overrides_slide_format() {
+        let slide = SlideContent::new("Risks")
+            .content_size(20)
+            .content_color("000000")
+            .add_bullet("Budget")
+            .add_bullet_point(BulletPoint::new("Schedule").font_size(28).color("FF0000").bold().with_level(1))
+            .add_bullet("Staffing");
+
+        let xml = slide_xml::create_slide_xml_with_content(1, &slide, &[], None);
+        let hot = &xml[xml.find(r#"<a:pPr lvl="1""#).unwrap()..xml.find("<a:t>Schedule</a:t>").unwrap()];
+        assert!(hot.contains(r#"marL="1371600""#));
+        assert!(hot.contains(r#"sz="2800" b="1""#));
+        assert!(hot.contains(r#"<a:srgbClr val="FF0000"/>"#));
+        assert_eq!(xml.matches(r#"sz="2000" b="0""#).count(), 2);
+        assert_eq!(xml.matches(r#"<a:srgbClr val="000000"/>"#).count(), 2);
+    }
 }
@@ -1,6 +1,8 @@
 //! Notes XML generation for speaker notes
 
 use crate::core::escape_xml;
+use super::text::proofing_attrs;
+use super::slide_content::header_footer::{PageHeaderFooter, PageMaster};
 
 /// Generate a proper GUID for field IDs
 /// Format: {XXXXXXXX-XXXX-XXXX-XXXX-XXXXXXXXXXXX}
@@ -13,6 +15,7 @@ fn generate_field_guid(slide_num: usize) -> String {
 pub fn create_notes_xml(slide_num: usize, notes_text: &str) -> String {
     let escaped_notes = escape_xml(notes_text);
     let field_guid = generate_field_guid(slide_num);
+    let proofing = proofing_attrs(false);
     
     format!(r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
 <p:notes xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships" xmlns:p="http://schemas.openxmlformats.org/presentationml/2006/main">
@@ -59,7 +62,7 @@ pub fn create_notes_xml(slide_num: usize, notes_text: &str) -> String {
 <a:lstStyle/>
 <a:p>
 <a:r>
-<a:rPr lang="en-US" dirty="0"/>
+<a:rPr lang="en-US"{proofing}/>
 <a:t>{escaped_notes}</a:t>
 </a:r>
 </a:p>
@@ -81,7 +84,7 @@ pub fn create_notes_xml(slide_num: usize, notes_text: &str) -> String {
 <a:lstStyle/>
 <a:p>
 <a:fld id="{field_guid}" type="slidenum">
-<a:rPr lang="en-US"/>
+<a:rPr lang="en-US"{proofing}/>
 <a:t>{slide_num}</a:t>
 </a:fld>
 <a:endParaRPr lang="en-US"/>
@@ -107,7 +110,19 @@ pub fn create_notes_rels_xml(slide_num: usize) -> String {
 
 /// Generate notes master XML
 pub fn create_notes_master_xml() -> String {
-    r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+    create_notes_master_xml_with_header_footer(&PageHeaderFooter::default())
+}
+
+/// Notes master whose header carries `header_footer.header`, with date, footer and
+/// page number placeholders added for the parts that are set
+pub fn create_notes_master_xml_with_header_footer(header_footer: &PageHeaderFooter) -> String {
+    let header = match header_footer.header {
+        Some(ref text) => format!("<a:r>\n<a:rPr lang=\"en-US\"{}/>\n<a:t>{}</a:t>\n</a:r>", proofing_attrs(false), escape_xml(text)),
+        None => "<a:endParaRPr lang=\"en-US\"/>".to_string(),
+    };
+    let corners = PageHeaderFooter { header: None, ..header_footer.clone() }.page_shapes_xml(PageMaster::Notes, 5, true);
+    let proofing = proofing_attrs(false);
+    format!(r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
 <p:notesMaster xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships" xmlns:p="http://schemas.openxmlformats.org/presentationml/2006/main">
 <p:cSld>
 <p:bg><p:bgRef idx="1001"><a:schemeClr val="bg1"/></p:bgRef></p:bg>
@@ -152,7 +167,7 @@ pub fn create_notes_master_xml() -> String {
 </a:lvl1pPr>
 </a:lstStyle>
 <a:p>
-<a:endParaRPr lang="en-US"/>
+{header}
 </a:p>
 </p:txBody>
 </p:sp>
@@ -214,13 +229,13 @@ pub fn create_notes_master_xml() -> String {
 <a:p>
 <a:pPr lvl="0"/>
 <a:r>
-<a:rPr lang="en-US"/>
+<a:rPr lang="en-US"{proofing}/>
 <a:t>Click to edit Master text styles</a:t>
 </a:r>
 </a:p>
 </p:txBody>
 </p:sp>
-</p:spTree>
+{corners}</p:spTree>
 </p:cSld>
 <p:clrMap bg1="lt1" tx1="dk1" bg2="lt2" tx2="dk2" accent1="accent1" accent2="accent2" accent3="accent3" accent4="accent4" accent5="accent5" accent6="accent6" hlink="hlink" folHlink="folHlink"/>
 <p:notesStyle>
@@ -235,7 +250,7 @@ pub fn create_notes_master_xml() -> String {
 </a:defRPr>
 </a:lvl1pPr>
 </p:notesStyle>
-</p:notesMaster>"#.to_string()
+</p:notesMaster>"#)
 }
 
 /// Generate notes master relationship XML
@@ -266,6 +281,14 @@ mod tests {
         assert!(xml.contains("&quot;chars&quot;"));
     }
 
+    #[test]
+    fn test_notes_runs_are_not_dirty() {
+        let header_footer = PageHeaderFooter { header: Some("Draft".into()), ..Default::default() };
+        for xml in [create_notes_xml(1, "Notes"), create_notes_master_xml_with_header_footer(&header_footer)] {
+            assert_eq!(xml.matches("<a:rPr ").count(), xml.matches(r#"dirty="0""#).count());
+        }
+    }
+
     #[test]
     fn test_create_notes_rels_xml() {
         let xml = create_notes_rels_xml(3);
@@ -281,6 +304,20 @@ mod tests {
         assert!(xml.contains("Notes Placeholder"));
     }
 
+    #[test]
+    fn test_notes_master_header_footer() {
+        let plain = create_notes_master_xml();
+        assert!(!plain.contains(r#"type="ftr""#));
+        let xml = create_notes_master_xml_with_header_footer(
+            &PageHeaderFooter::new().header("Course & Notes").footer("Page footer").page_numbers(true),
+        );
+        let header = &xml[xml.find(r#"type="hdr""#).unwrap()..xml.find("Slide Image Placeholder").unwrap()];
+        assert!(header.contains("<a:t>Course &amp; Notes</a:t>"));
+        assert!(xml.contains(r#"<p:cNvPr id="5" name="Footer Placeholder 5"/>"#));
+        assert!(xml.contains(r#"<p:ph type="sldNum" sz="quarter" idx="5"/>"#));
+        assert!(xml.find("Footer Placeholder").unwrap() < xml.find("</p:spTree>").unwrap());
+    }
+
     #[test]
     fn test_create_notes_master_rels_xml() {
         let xml = create_notes_master_rels_xml();
@@ -1,11 +1,14 @@
 //! Package-level XML generation (content types, relationships, presentation)
 
-use crate::core::{append_usize, escape_xml};
+use crate::core::{append_usize, escape_xml, SlideSize};
 use crate::generator::slide_content::presentation_settings::PresentationSettings;
 use crate::generator::slide_content::embedded_fonts::EmbeddedFontList;
-use crate::generator::charts::chart_embedding_filename;
+use crate::generator::charts::{
+    chart_embedding_filename, CHARTEX_CONTENT_TYPE, CHARTEX_RELATIONSHIP_TYPE,
+};
 use crate::generator::layout_parts::append_layout_content_type_overrides;
-use crate::generator::layout_parts::STANDARD_LAYOUT_COUNT;
+use crate::generator::media::{audio_content_type, video_content_type, AudioFormat, VideoFormat};
+use crate::generator::layout_parts::{master_theme_number, STANDARD_LAYOUT_COUNT};
 use crate::generator::theme_xml::layout_rel_target;
 
 /// First slide master id (`0x80000000`). Layout and notes master ids follow in the same id space.
@@ -17,6 +20,84 @@ pub const NOTES_MASTER_ID: u32 = SLIDE_LAYOUT_ID + STANDARD_LAYOUT_COUNT as u32;
 /// Id for handoutMaster1.
 pub const HANDOUT_MASTER_ID: u32 = NOTES_MASTER_ID + 1;
 
+/// Id of slide master `number` (1-based). Masters after the first take ids past the
+/// notes and handout masters, each followed by the ids of its layouts.
+pub fn slide_master_id(number: usize) -> u32 {
+    if number <= 1 {
+        SLIDE_MASTER_ID
+    } else {
+        HANDOUT_MASTER_ID + 1 + ((number - 2) * (STANDARD_LAYOUT_COUNT + 1)) as u32
+    }
+}
+
+/// Relationship id from `presentation.xml` to slide master `number` > 1.
+///
+/// Named rather than numbered so the slide, notes and props ids stay put.
+pub fn extra_master_rel_id(number: usize) -> String {
+    format!("rIdMaster{number}")
+}
+
+/// Set the master ids in `presentation.xml`'s `sldMasterIdLst`, one per master in order.
+///
+/// The first id replaces the stock master's; the rest are listed after it.
+pub fn set_master_ids(xml: &mut String, master_ids: &[u32]) {
+    const FIRST_ID: &str = "<p:sldMasterId id=\"";
+    if let Some(&first) = master_ids.first()
+        && let Some(start) = xml.find(FIRST_ID).map(|pos| pos + FIRST_ID.len())
+        && let Some(len) = xml[start..].find('"')
+    {
+        xml.replace_range(start..start + len, &first.to_string());
+    }
+    let Some(pos) = xml.find("</p:sldMasterIdLst>") else {
+        return;
+    };
+    let entries: String = master_ids
+        .iter()
+        .zip(1..)
+        .skip(1)
+        .map(|(id, n)| format!("<p:sldMasterId id=\"{id}\" r:id=\"{}\"/>\n", extra_master_rel_id(n)))
+        .collect();
+    xml.insert_str(pos, &entries);
+}
+
+/// Relate masters 2..=`master_count` from `ppt/_rels/presentation.xml.rels`.
+pub fn append_extra_master_relationships(xml: &mut String, master_count: usize) {
+    let Some(pos) = xml.rfind("</Relationships>") else {
+        return;
+    };
+    let rels: String = (2..=master_count)
+        .map(|n| {
+            format!(
+                "<Relationship Id=\"{}\" Type=\"http://schemas.openxmlformats.org/officeDocument/2006/relationships/slideMaster\" Target=\"slideMasters/slideMaster{n}.xml\"/>\n",
+                extra_master_rel_id(n),
+            )
+        })
+        .collect();
+    xml.insert_str(pos, &rels);
+}
+
+/// Content type overrides for masters 2..=`master_count`, their layouts and themes.
+pub fn append_extra_master_content_types(xml: &mut String, master_count: usize) {
+    let Some(pos) = xml.rfind("</Types>") else {
+        return;
+    };
+    let mut overrides = String::new();
+    for n in 2..=master_count {
+        overrides.push_str("<Override PartName=\"/ppt/slideMasters/slideMaster");
+        append_usize(&mut overrides, n);
+        overrides.push_str(".xml\" ContentType=\"application/vnd.openxmlformats-officedocument.presentationml.slideMaster+xml\"/>\n");
+        for layout in (n - 1) * STANDARD_LAYOUT_COUNT + 1..=n * STANDARD_LAYOUT_COUNT {
+            overrides.push_str("<Override PartName=\"/ppt/slideLayouts/slideLayout");
+            append_usize(&mut overrides, layout);
+            overrides.push_str(".xml\" ContentType=\"application/vnd.openxmlformats-officedocument.presentationml.slideLayout+xml\"/>\n");
+        }
+        overrides.push_str("<Override PartName=\"/ppt/theme/theme");
+        append_usize(&mut overrides, master_theme_number(n));
+        overrides.push_str(".xml\" ContentType=\"application/vnd.openxmlformats-officedocument.theme+xml\"/>\n");
+    }
+    xml.insert_str(pos, &overrides);
+}
+
 const DEFAULT_TEXT_STYLE: &str = r#"<p:defaultTextStyle><a:defPPr><a:defRPr lang="en-US"/></a:defPPr><a:lvl1pPr marL="0" algn="l" defTabSz="457200" rtl="0" eaLnBrk="1" latinLnBrk="0" hangingPunct="1"><a:defRPr sz="1800" kern="1200"><a:solidFill><a:schemeClr val="tx1"/></a:solidFill><a:latin typeface="+mn-lt"/><a:ea typeface="+mn-ea"/><a:cs typeface="+mn-cs"/></a:defRPr></a:lvl1pPr><a:lvl2pPr marL="457200" algn="l" defTabSz="457200" rtl="0" eaLnBrk="1" latinLnBrk="0" hangingPunct="1"><a:defRPr sz="1800" kern="1200"><a:solidFill><a:schemeClr val="tx1"/></a:solidFill><a:latin typeface="+mn-lt"/><a:ea typeface="+mn-ea"/><a:cs typeface="+mn-cs"/></a:defRPr></a:lvl2pPr><a:lvl3pPr marL="914400" algn="l" defTabSz="457200" rtl="0" eaLnBrk="1" latinLnBrk="0" hangingPunct="1"><a:defRPr sz="1800" kern="1200"><a:solidFill><a:schemeClr val="tx1"/></a:solidFill><a:latin typeface="+mn-lt"/><a:ea typeface="+mn-ea"/><a:cs typeface="+mn-cs"/></a:defRPr></a:lvl3pPr><a:lvl4pPr marL="1371600" algn="l" defTabSz="457200" rtl="0" eaLnBrk="1" latinLnBrk="0" hangingPunct="1"><a:defRPr sz="1800" kern="1200"><a:solidFill><a:schemeClr val="tx1"/></a:solidFill><a:latin typeface="+mn-lt"/><a:ea typeface="+mn-ea"/><a:cs typeface="+mn-cs"/></a:defRPr></a:lvl4pPr><a:lvl5pPr marL="1828800" algn="l" defTabSz="457200" rtl="0" eaLnBrk="1" latinLnBrk="0" hangingPunct="1"><a:defRPr sz="1800" kern="1200"><a:solidFill><a:schemeClr val="tx1"/></a:solidFill><a:latin typeface="+mn-lt"/><a:ea typeface="+mn-ea"/><a:cs typeface="+mn-cs"/></a:defRPr></a:lvl5pPr><a:lvl6pPr marL="2286000" algn="l" defTabSz="457200" rtl="0" eaLnBrk="1" latinLnBrk="0" hangingPunct="1"><a:defRPr sz="1800" kern="1200"><a:solidFill><a:schemeClr val="tx1"/></a:solidFill><a:latin typeface="+mn-lt"/><a:ea typeface="+mn-ea"/><a:cs typeface="+mn-cs"/></a:defRPr></a:lvl6pPr><a:lvl7pPr marL="2743200" algn="l" defTabSz="457200" rtl="0" eaLnBrk="1" latinLnBrk="0" hangingPunct="1"><a:defRPr sz="1800" kern="1200"><a:solidFill><a:schemeClr val="tx1"/></a:solidFill><a:latin typeface="+mn-lt"/><a:ea typeface="+mn-ea"/><a:cs typeface="+mn-cs"/></a:defRPr></a:lvl7pPr><a:lvl8pPr marL="3200400" algn="l" defTabSz="457200" rtl="0" eaLnBrk="1" latinLnBrk="0" hangingPunct="1"><a:defRPr sz="1800" kern="1200"><a:solidFill><a:schemeClr val="tx1"/></a:solidFill><a:latin typeface="+mn-lt"/><a:ea typeface="+mn-ea"/><a:cs typeface="+mn-cs"/></a:defRPr></a:lvl8pPr><a:lvl9pPr marL="3657600" algn="l" defTabSz="457200" rtl="0" eaLnBrk="1" latinLnBrk="0" hangingPunct="1"><a:defRPr sz="1800" kern="1200"><a:solidFill><a:schemeClr val="tx1"/></a:solidFill><a:latin typeface="+mn-lt"/><a:ea typeface="+mn-ea"/><a:cs typeface="+mn-cs"/></a:defRPr></a:lvl9pPr></p:defaultTextStyle>"#;
 
 const CONTENT_TYPES_HEADER: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
@@ -29,11 +110,13 @@ fn append_image_content_type_defaults(xml: &mut String, media_exts: &[String]) {
     let mut has_jpg = false;
     let mut has_png = false;
     let mut has_gif = false;
+    let mut has_svg = false;
     for ext in media_exts {
         match ext.as_str() {
             "jpg" | "jpeg" => has_jpg = true,
             "png" => has_png = true,
             "gif" => has_gif = true,
+            "svg" => has_svg = true,
             _ => {}
         }
     }
@@ -46,6 +129,22 @@ fn append_image_content_type_defaults(xml: &mut String, media_exts: &[String]) {
     if has_gif {
         xml.push_str(r#"<Default Extension="gif" ContentType="image/gif"/>"#);
     }
+    if has_svg {
+        xml.push_str(r#"<Default Extension="svg" ContentType="image/svg+xml"/>"#);
+    }
+
+    let mut clip_defaults: Vec<String> = Vec::new();
+    for ext in media_exts {
+        let default = match (VideoFormat::from_extension(ext), AudioFormat::from_extension(ext)) {
+            (Some(format), _) => video_content_type(format),
+            (None, Some(format)) => audio_content_type(format),
+            (None, None) => continue,
+        };
+        if !clip_defaults.contains(&default) {
+            xml.push_str(&default);
+            clip_defaults.push(default);
+        }
+    }
 }
 
 pub fn content_types_opening(media_exts: &[String], chart_count: usize) -> String {
@@ -278,13 +377,15 @@ pub fn create_pres_props_xml(settings: Option<&PresentationSettings>) -> String
         r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?><p:presentationPr xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships" xmlns:p="http://schemas.openxmlformats.org/presentationml/2006/main">"#,
     );
 
-    if let Some(s) = settings
-        && let Some(ref show) = s.slide_show {
+    if let Some(s) = settings {
+        // CT_PresentationProperties order: prnPr before showPr
+        if let Some(ref print) = s.print {
+            xml.push_str(&print.to_prnpr_xml());
+        }
+        if let Some(ref show) = s.slide_show {
             xml.push_str(&show.to_xml());
         }
-        // Handout print preferences are expressed via the handout master part.
-        // PowerPoint strips `<p:prnPr>` from presProps on repair when it is paired
-        // with a packaged handout master, so we do not emit it during generation.
+    }
 
     xml.push_str(
         r#"<p:extLst><p:ext uri="{E76CE94A-603C-4142-B9EB-6D1370010A27}"><p14:discardImageEditData xmlns:p14="http://schemas.microsoft.com/office/powerpoint/2010/main" val="0"/></p:ext><p:ext uri="{D31A062A-798A-4329-ABDD-BBA856620510}"><p14:defaultImageDpi xmlns:p14="http://schemas.microsoft.com/office/powerpoint/2010/main" val="0"/></p:ext><p:ext uri="{FD5EFAAD-0ECE-453E-9831-46B23BE46B34}"><p15:chartTrackingRefBased xmlns:p15="http://schemas.microsoft.com/office/powerpoint/2012/main" val="0"/></p:ext></p:extLst></p:presentationPr>"#,
@@ -311,10 +412,23 @@ pub fn create_view_props_xml() -> &'static str {
 
 /// Create ppt/presentation.xml
 pub fn create_presentation_xml(
+    title: &str,
+    slides: usize,
+    has_notes: bool,
+    has_handout: bool,
+) -> String {
+    create_presentation_xml_with_size(title, slides, has_notes, has_handout, &SlideSize::default())
+}
+
+/// Create ppt/presentation.xml with custom slide dimensions.
+///
+/// The notes page keeps the standard portrait size regardless of slide size.
+pub fn create_presentation_xml_with_size(
     _title: &str,
     slides: usize,
     has_notes: bool,
     has_handout: bool,
+    size: &SlideSize,
 ) -> String {
     let mut xml = String::with_capacity(896 + slides * 48);
     xml.push_str(
@@ -351,13 +465,9 @@ pub fn create_presentation_xml(
         xml.push_str("\"/>");
     }
 
-    xml.push_str(
-        r#"
-</p:sldIdLst>
-<p:sldSz cx="9144000" cy="6858000" type="screen4x3"/>
-<p:notesSz cx="6858000" cy="9144000"/>
-"#,
-    );
+    xml.push_str("\n</p:sldIdLst>\n");
+    xml.push_str(&size.to_xml());
+    xml.push_str("\n<p:notesSz cx=\"6858000\" cy=\"9144000\"/>\n");
     xml.push_str(DEFAULT_TEXT_STYLE);
     xml.push_str(
         r#"<p:extLst><p:ext uri="{EFAFB233-063F-42B5-8137-9DF3F51BA10A}"><p15:sldGuideLst xmlns:p15="http://schemas.microsoft.com/office/powerpoint/2012/main"/></p:ext></p:extLst></p:presentation>"#,
@@ -373,13 +483,22 @@ pub fn create_presentation_xml_with_fonts(
     has_handout: bool,
     fonts: &EmbeddedFontList,
 ) -> String {
-    let mut xml = create_presentation_xml(title, slides, has_notes, has_handout);
+    let xml = create_presentation_xml(title, slides, has_notes, has_handout);
+    insert_embedded_font_list(xml, fonts)
+}
+
+/// Insert `<p:embeddedFontLst>` into an existing presentation.xml.
+pub fn insert_embedded_font_list(mut xml: String, fonts: &EmbeddedFontList) -> String {
     let font_xml = fonts.to_xml();
     if !font_xml.is_empty() {
-        // Insert <p:embeddedFontLst> before the closing </p:presentation>.
-        if let Some(pos) = xml.rfind("</p:presentation>") {
+        // The schema places <p:embeddedFontLst> after <p:notesSz>, ahead of <p:defaultTextStyle>.
+        if let Some(pos) = xml.find("<p:defaultTextStyle").or_else(|| xml.rfind("</p:presentation>")) {
             xml.insert_str(pos, &font_xml);
         }
+        // Without this flag PowerPoint ignores the embedded font parts.
+        if !xml.contains("embedTrueTypeFonts=") {
+            xml = xml.replacen("<p:presentation ", r#"<p:presentation embedTrueTypeFonts="1" "#, 1);
+        }
     }
     xml
 }
@@ -420,14 +539,13 @@ pub fn create_content_types_xml_with_notes_and_charts(
         xml.push_str("\n<Override PartName=\"/ppt/theme/theme3.xml\" ContentType=\"application/vnd.openxmlformats-officedocument.theme+xml\"/>");
     }
 
-    for i in 1..=chart_count {
-        xml.push_str("\n<Override PartName=\"/ppt/charts/chart");
-        append_usize(&mut xml, i);
-        xml.push_str(".xml\" ContentType=\"application/vnd.openxmlformats-officedocument.drawingml.chart+xml\"/>");
-        xml.push_str("\n<Override PartName=\"/ppt/embeddings/");
-        xml.push_str(&chart_embedding_filename(i));
-        xml.push_str("\" ContentType=\"application/vnd.openxmlformats-officedocument.spreadsheetml.sheet\"/>");
-    }
+    let chartex: Vec<bool> = custom_slides
+        .into_iter()
+        .flatten()
+        .flat_map(|slide| &slide.charts)
+        .map(|chart| chart.chart_type.is_chartex())
+        .collect();
+    append_chart_content_type_overrides(&mut xml, chart_count, &chartex);
 
     append_layout_content_type_overrides(&mut xml, STANDARD_LAYOUT_COUNT);
     xml.push_str(CONTENT_TYPES_FOOTER);
@@ -473,6 +591,40 @@ pub fn append_ink_content_types(xml: &mut String, ink_count: usize) {
     }
 }
 
+/// Relationship type from a slide to its comment list part
+pub const COMMENTS_RELATIONSHIP_TYPE: &str = "http://schemas.openxmlformats.org/officeDocument/2006/relationships/comments";
+
+/// Append overrides for `ppt/commentAuthors.xml` and the comment parts of slides
+/// `slide_nums` to an existing `[Content_Types].xml` string (inserted before `</Types>`).
+pub fn append_comment_content_types(xml: &mut String, slide_nums: &[usize]) {
+    if slide_nums.is_empty() {
+        return;
+    }
+    if let Some(pos) = xml.rfind("</Types>") {
+        let mut overrides = String::from(
+            "\n<Override PartName=\"/ppt/commentAuthors.xml\" ContentType=\"application/vnd.openxmlformats-officedocument.presentationml.commentAuthors+xml\"/>",
+        );
+        for n in slide_nums {
+            overrides.push_str(&format!(
+                "\n<Override PartName=\"/ppt/comments/comment{n}.xml\" ContentType=\"application/vnd.openxmlformats-officedocument.presentationml.comments+xml\"/>"
+            ));
+        }
+        xml.insert_str(pos, &overrides);
+    }
+}
+
+/// Append the presentation's relationship to `commentAuthors.xml` (inserted before `</Relationships>`).
+pub fn append_comment_authors_relationship(xml: &mut String, rid: usize) {
+    if let Some(pos) = xml.rfind("</Relationships>") {
+        xml.insert_str(
+            pos,
+            &format!(
+                r#"<Relationship Id="rId{rid}" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/commentAuthors" Target="commentAuthors.xml"/>"#
+            ),
+        );
+    }
+}
+
 /// Handout master relationship XML (theme link).
 pub fn create_handout_master_rels_xml() -> String {
     r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
@@ -481,6 +633,39 @@ pub fn create_handout_master_rels_xml() -> String {
 </Relationships>"#.to_string()
 }
 
+/// Append overrides for charts `1..=chart_count` and their embedded workbooks.
+///
+/// `chartex` flags the deck's chartEx charts in order; charts past its end are classic chart parts.
+pub fn append_chart_content_type_overrides(xml: &mut String, chart_count: usize, chartex: &[bool]) {
+    for chart_idx in 1..=chart_count {
+        match chartex.get(chart_idx - 1) {
+            Some(true) => {
+                xml.push_str("\n<Override PartName=\"/ppt/charts/chartEx");
+                append_usize(xml, chart_idx);
+                xml.push_str(".xml\" ContentType=\"");
+                xml.push_str(CHARTEX_CONTENT_TYPE);
+            }
+            _ => {
+                xml.push_str("\n<Override PartName=\"/ppt/charts/chart");
+                append_usize(xml, chart_idx);
+                xml.push_str(".xml\" ContentType=\"application/vnd.openxmlformats-officedocument.drawingml.chart+xml");
+            }
+        }
+        xml.push_str("\"/>\n<Override PartName=\"/ppt/embeddings/");
+        xml.push_str(&chart_embedding_filename(chart_idx));
+        xml.push_str("\" ContentType=\"application/vnd.openxmlformats-officedocument.spreadsheetml.sheet\"/>");
+    }
+}
+
+/// Relationship type for a slide's chart target (`../charts/chartN.xml` or `../charts/chartExN.xml`)
+fn chart_relationship_type(target: &str) -> &'static str {
+    if target.contains("/chartEx") {
+        CHARTEX_RELATIONSHIP_TYPE
+    } else {
+        "http://schemas.openxmlformats.org/officeDocument/2006/relationships/chart"
+    }
+}
+
 /// Create slide relationship XML with notes and charts
 pub fn create_slide_rels_xml_extended(
     layout_number: usize,
@@ -507,7 +692,9 @@ pub fn create_slide_rels_xml_extended(
     for (rid, target) in chart_rels {
         xml.push_str("\n<Relationship Id=\"");
         xml.push_str(rid);
-        xml.push_str("\" Type=\"http://schemas.openxmlformats.org/officeDocument/2006/relationships/chart\" Target=\"");
+        xml.push_str("\" Type=\"");
+        xml.push_str(chart_relationship_type(target));
+        xml.push_str("\" Target=\"");
         xml.push_str(target);
         xml.push_str("\"/>");
     }
@@ -561,7 +748,9 @@ pub fn create_slide_rels_xml_with_images(
     for (rid, target) in chart_rels {
         xml.push_str("\n<Relationship Id=\"");
         xml.push_str(rid);
-        xml.push_str("\" Type=\"http://schemas.openxmlformats.org/officeDocument/2006/relationships/chart\" Target=\"");
+        xml.push_str("\" Type=\"");
+        xml.push_str(chart_relationship_type(target));
+        xml.push_str("\" Target=\"");
         xml.push_str(target);
         xml.push_str("\"/>");
     }
@@ -0,0 +1,36 @@
+//! Ordered parallel map used to render slide and chart parts on several threads
+
+use rayon::prelude::*;
+
+/// Apply `f` to every item (with its index) on rayon's thread pool and return the
+/// results in input order, the same as a sequential map whatever the scheduling.
+pub(crate) fn map_ordered<T, R, F>(items: &[T], f: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(usize, &T) -> R + Sync,
+{
+    items.par_iter().enumerate().map(|(i, item)| f(i, item)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_results_keep_input_order() {
+        let items: Vec<usize> = (0..1000).collect();
+        let out = map_ordered(&items, |i, &item| {
+            assert_eq!(i, item);
+            format!("slide{item}")
+        });
+        assert_eq!(out.len(), 1000);
+        assert!(out.iter().enumerate().all(|(i, s)| *s == format!("slide{i}")));
+    }
+
+    #[test]
+    fn test_empty_and_single_inputs() {
+        assert!(map_ordered(&[] as &[u8], |_, b| *b).is_empty());
+        assert_eq!(map_ordered(&[7u8], |i, b| (i, *b)), vec![(0, 7)]);
+    }
+}
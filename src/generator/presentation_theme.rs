@@ -4,6 +4,7 @@
 
 /// ECMA-376 color scheme (12 slots used by PowerPoint theme)
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ThemeColorScheme {
     /// Dark 1 — primary text
     pub dk1: String,
@@ -86,14 +87,75 @@ impl ThemeColorScheme {
         self
     }
 
+    pub fn accent4(mut self, hex: impl AsRef<str>) -> Self {
+        self.accent4 = normalize_hex(hex.as_ref());
+        self
+    }
+
+    pub fn accent5(mut self, hex: impl AsRef<str>) -> Self {
+        self.accent5 = normalize_hex(hex.as_ref());
+        self
+    }
+
+    pub fn accent6(mut self, hex: impl AsRef<str>) -> Self {
+        self.accent6 = normalize_hex(hex.as_ref());
+        self
+    }
+
+    pub fn dark1(mut self, hex: impl AsRef<str>) -> Self {
+        self.dk1 = normalize_hex(hex.as_ref());
+        self
+    }
+
+    pub fn light1(mut self, hex: impl AsRef<str>) -> Self {
+        self.lt1 = normalize_hex(hex.as_ref());
+        self
+    }
+
+    pub fn dark2(mut self, hex: impl AsRef<str>) -> Self {
+        self.dk2 = normalize_hex(hex.as_ref());
+        self
+    }
+
+    pub fn light2(mut self, hex: impl AsRef<str>) -> Self {
+        self.lt2 = normalize_hex(hex.as_ref());
+        self
+    }
+
     pub fn hyperlink(mut self, hex: impl AsRef<str>) -> Self {
         self.hlink = normalize_hex(hex.as_ref());
         self
     }
+
+    pub fn followed_hyperlink(mut self, hex: impl AsRef<str>) -> Self {
+        self.fol_hlink = normalize_hex(hex.as_ref());
+        self
+    }
+
+    /// Hex value for a color field: scheme names such as "accent1" or "tx1" map to
+    /// their slot in this scheme, anything else is returned unchanged
+    pub fn resolve<'a>(&'a self, color: &'a str) -> &'a str {
+        match color.trim() {
+            "dk1" | "tx1" => &self.dk1,
+            "lt1" | "bg1" => &self.lt1,
+            "dk2" | "tx2" => &self.dk2,
+            "lt2" | "bg2" => &self.lt2,
+            "accent1" => &self.accent1,
+            "accent2" => &self.accent2,
+            "accent3" => &self.accent3,
+            "accent4" => &self.accent4,
+            "accent5" => &self.accent5,
+            "accent6" => &self.accent6,
+            "hlink" => &self.hlink,
+            "folHlink" => &self.fol_hlink,
+            _ => color,
+        }
+    }
 }
 
 /// Theme font pair (major = headings, minor = body)
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ThemeFonts {
     pub major: String,
     pub minor: String,
@@ -117,6 +179,7 @@ impl ThemeFonts {
 
 /// Full presentation theme embedded in generated PPTX files
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PresentationTheme {
     pub name: String,
     pub colors: ThemeColorScheme,
@@ -189,6 +252,24 @@ impl PresentationTheme {
         )
     }
 
+    /// Navy/blue brand palette with six distinct accents and Segoe UI fonts
+    pub fn corporate_blue() -> Self {
+        let colors = ThemeColorScheme::office()
+            .dark2("1F3864")
+            .light2("DEEAF6")
+            .accent1("0F4C81")
+            .accent2("2E75B6")
+            .accent3("9DC3E6")
+            .accent4("F4B183")
+            .accent5("70AD47")
+            .accent6("7F7F7F")
+            .hyperlink("0563C1")
+            .followed_hyperlink("954F72");
+        Self::new("Corporate Blue")
+            .colors(colors)
+            .fonts(ThemeFonts::new("Segoe UI Semibold", "Segoe UI"))
+    }
+
     pub fn modern() -> Self {
         Self::from_palette(
             "Modern",
@@ -344,6 +425,34 @@ mod tests {
         assert!(xml.contains("AABBCC"));
     }
 
+    #[test]
+    fn test_resolve_scheme_names() {
+        let colors = ThemeColorScheme::office().accent1("AABBCC");
+        assert_eq!(colors.resolve("accent1"), "AABBCC");
+        assert_eq!(colors.resolve("tx1"), "000000");
+        assert_eq!(colors.resolve("FF0000"), "FF0000");
+    }
+
+    #[test]
+    fn test_every_color_slot_is_settable() {
+        let colors = ThemeColorScheme::office()
+            .dark1("#010101").light1("020202").dark2("030303").light2("040404")
+            .accent4("444444").accent5("555555").accent6("666666")
+            .followed_hyperlink("777777");
+        let xml = PresentationTheme::new("Brand").colors(colors).to_theme_xml();
+        for hex in ["010101", "020202", "030303", "040404", "444444", "555555", "666666", "777777"] {
+            assert!(xml.contains(hex), "missing {hex}");
+        }
+    }
+
+    #[test]
+    fn test_corporate_blue_theme() {
+        let xml = PresentationTheme::corporate_blue().to_theme_xml();
+        assert!(xml.contains(r#"name="Corporate Blue""#));
+        assert!(xml.contains("0F4C81"));
+        assert!(xml.contains(r#"typeface="Segoe UI""#));
+    }
+
     #[test]
     fn test_normalize_hex_strips_hash() {
         assert_eq!(normalize_hex("#ff8040"), "FF8040");
@@ -25,9 +25,38 @@ fn current_timestamp() -> String {
     )
 }
 
+/// ZIP entry time for a `YYYY-MM-DDThh:mm:ssZ` UTC timestamp, or `None` when the
+/// text is malformed or falls outside the years ZIP can record (1980–2107)
+pub(crate) fn zip_time(timestamp: &str) -> Option<zip::DateTime> {
+    let bytes = timestamp.as_bytes();
+    let shape_ok = bytes.len() == 20
+        && bytes.iter().enumerate().all(|(i, &b)| match i {
+            4 | 7 => b == b'-',
+            10 => b == b'T',
+            13 | 16 => b == b':',
+            19 => b == b'Z',
+            _ => b.is_ascii_digit(),
+        });
+    if !shape_ok {
+        return None;
+    }
+    let field = |range: std::ops::Range<usize>| timestamp[range].parse::<u16>().ok();
+    let (month, day) = (field(5..7)? as u8, field(8..10)? as u8);
+    let (hour, minute, second) = (field(11..13)? as u8, field(14..16)? as u8, field(17..19)? as u8);
+    zip::DateTime::from_date_and_time(field(0..4)?, month, day, hour, minute, second).ok()
+}
+
 /// Create core properties XML (docProps/core.xml)
 pub fn create_core_props_xml(title: &str) -> String {
-    let now = current_timestamp();
+    create_core_props_xml_with_dates(title, None, None)
+}
+
+/// Core properties with fixed creation/modification times (`YYYY-MM-DDThh:mm:ssZ`).
+///
+/// A missing time falls back to the other one, and to the current time when both are missing.
+pub fn create_core_props_xml_with_dates(title: &str, created: Option<&str>, modified: Option<&str>) -> String {
+    let created = created.or(modified).map(str::to_string).unwrap_or_else(current_timestamp);
+    let modified = modified.map_or_else(|| created.clone(), str::to_string);
     format!(
         r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
 <cp:coreProperties xmlns:cp="http://schemas.openxmlformats.org/package/2006/metadata/core-properties" xmlns:dc="http://purl.org/dc/elements/1.1/" xmlns:dcterms="http://purl.org/dc/terms/" xmlns:dcmitype="http://purl.org/dc/dcmitype/" xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance">
@@ -35,8 +64,8 @@ pub fn create_core_props_xml(title: &str) -> String {
 <dc:creator>pptx-rs</dc:creator>
 <cp:lastModifiedBy>pptx-rs</cp:lastModifiedBy>
 <cp:revision>1</cp:revision>
-<dcterms:created xsi:type="dcterms:W3CDTF">{now}</dcterms:created>
-<dcterms:modified xsi:type="dcterms:W3CDTF">{now}</dcterms:modified>
+<dcterms:created xsi:type="dcterms:W3CDTF">{created}</dcterms:created>
+<dcterms:modified xsi:type="dcterms:W3CDTF">{modified}</dcterms:modified>
 </cp:coreProperties>"#
     )
 }
@@ -87,6 +116,16 @@ fn heading_pairs_and_titles(slide_titles: &[String]) -> String {
 /// supplied than `slides`, placeholder titles are generated so the counts stay
 /// consistent.
 pub fn create_app_props_xml(slides: usize, notes_count: usize, slide_titles: &[String]) -> String {
+    create_app_props_xml_with_format(slides, notes_count, slide_titles, "On-screen Show (4:3)")
+}
+
+/// Create app properties XML with a custom `PresentationFormat` (e.g. "On-screen Show (16:9)")
+pub fn create_app_props_xml_with_format(
+    slides: usize,
+    notes_count: usize,
+    slide_titles: &[String],
+    presentation_format: &str,
+) -> String {
     let titles: Vec<String> = if slide_titles.len() >= slides {
         slide_titles.iter().take(slides).cloned().collect()
     } else {
@@ -98,6 +137,7 @@ pub fn create_app_props_xml(slides: usize, notes_count: usize, slide_titles: &[S
     };
 
     let heading_and_titles = heading_pairs_and_titles(&titles);
+    let presentation_format = xml_escape(presentation_format);
 
     format!(
         r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
@@ -105,7 +145,7 @@ pub fn create_app_props_xml(slides: usize, notes_count: usize, slide_titles: &[S
 <TotalTime>0</TotalTime>
 <Words>0</Words>
 <Application>pptx-rs</Application>
-<PresentationFormat>On-screen Show (4:3)</PresentationFormat>
+<PresentationFormat>{presentation_format}</PresentationFormat>
 <Paragraphs>0</Paragraphs>
 <Slides>{slides}</Slides>
 <Notes>{notes_count}</Notes>
@@ -120,3 +160,27 @@ pub fn create_app_props_xml(slides: usize, notes_count: usize, slide_titles: &[S
 </Properties>"#
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_zip_time_parses_utc_timestamps() {
+        let time = zip_time("2024-03-09T17:45:30Z").unwrap();
+        assert_eq!((time.year(), time.month(), time.day()), (2024, 3, 9));
+        assert_eq!((time.hour(), time.minute(), time.second()), (17, 45, 30));
+        for bad in ["2024-03-09", "2024-03-09T17:45:30+01:00", "2024-13-09T17:45:30Z", "1970-01-01T00:00:00Z", "2024-03-09t17:45:30Z"] {
+            assert!(zip_time(bad).is_none(), "{bad}");
+        }
+    }
+
+    #[test]
+    fn test_core_props_dates_fall_back_to_each_other() {
+        let xml = create_core_props_xml_with_dates("Deck", Some("2024-01-02T03:04:05Z"), None);
+        assert!(xml.contains(r#"<dcterms:created xsi:type="dcterms:W3CDTF">2024-01-02T03:04:05Z</dcterms:created>"#));
+        assert!(xml.contains(r#"<dcterms:modified xsi:type="dcterms:W3CDTF">2024-01-02T03:04:05Z</dcterms:modified>"#));
+        let xml = create_core_props_xml_with_dates("Deck", Some("2024-01-02T03:04:05Z"), Some("2024-02-01T00:00:00Z"));
+        assert!(xml.contains(">2024-02-01T00:00:00Z</dcterms:modified>"));
+    }
+}
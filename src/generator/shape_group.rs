@@ -0,0 +1,231 @@
+//! Group shape support for PPTX
+//!
+//! A group places its children in a child coordinate space (`chOff`/`chExt`)
+//! that PowerPoint maps onto the group's slide position (`off`/`ext`), so the
+//! whole group moves and scales as a unit.
+
+use super::connectors::{generate_connector_xml, Connector};
+use super::shapes::Shape;
+use super::shapes_xml::generate_shape_xml;
+
+/// A group of shapes that move and scale together
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ShapeGroup {
+    /// Group position on the slide in EMU
+    pub x: u32,
+    pub y: u32,
+    /// Group size on the slide in EMU
+    pub width: u32,
+    pub height: u32,
+    /// Origin of the child coordinate space
+    pub child_x: u32,
+    pub child_y: u32,
+    /// Size of the child coordinate space
+    pub child_width: u32,
+    pub child_height: u32,
+    /// Child shapes positioned in the child coordinate space
+    pub shapes: Vec<Shape>,
+    /// Child connectors, drawn after the shapes
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub connectors: Vec<Connector>,
+    /// Optional group name shown in the selection pane
+    pub name: Option<String>,
+}
+
+impl ShapeGroup {
+    /// Create a group at a slide position; children are positioned relative to its top-left corner
+    pub fn new(x: u32, y: u32, width: u32, height: u32) -> Self {
+        ShapeGroup {
+            x,
+            y,
+            width,
+            height,
+            child_x: 0,
+            child_y: 0,
+            child_width: width,
+            child_height: height,
+            shapes: Vec::new(),
+            connectors: Vec::new(),
+            name: None,
+        }
+    }
+
+    /// Create a group around shapes already positioned in slide coordinates
+    pub fn from_shapes(shapes: Vec<Shape>) -> Self {
+        let min_x = shapes.iter().map(|s| s.x).min().unwrap_or(0);
+        let min_y = shapes.iter().map(|s| s.y).min().unwrap_or(0);
+        let max_x = shapes.iter().map(|s| s.x + s.width).max().unwrap_or(0);
+        let max_y = shapes.iter().map(|s| s.y + s.height).max().unwrap_or(0);
+        let (width, height) = (max_x - min_x, max_y - min_y);
+
+        ShapeGroup {
+            x: min_x,
+            y: min_y,
+            width,
+            height,
+            child_x: min_x,
+            child_y: min_y,
+            child_width: width,
+            child_height: height,
+            shapes,
+            connectors: Vec::new(),
+            name: None,
+        }
+    }
+
+    /// Set the child coordinate space (`chOff`/`chExt`)
+    pub fn with_child_space(mut self, x: u32, y: u32, width: u32, height: u32) -> Self {
+        self.child_x = x;
+        self.child_y = y;
+        self.child_width = width;
+        self.child_height = height;
+        self
+    }
+
+    /// Move the group without changing its children
+    pub fn at(mut self, x: u32, y: u32) -> Self {
+        self.x = x;
+        self.y = y;
+        self
+    }
+
+    /// Resize the group; children scale with it
+    pub fn with_size(mut self, width: u32, height: u32) -> Self {
+        self.width = width;
+        self.height = height;
+        self
+    }
+
+    /// Set group name
+    pub fn with_name(mut self, name: &str) -> Self {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    /// Add a child shape (coordinates in the child space)
+    pub fn add_shape(mut self, shape: Shape) -> Self {
+        self.shapes.push(shape);
+        self
+    }
+
+    /// Add multiple child shapes
+    pub fn with_shapes(mut self, shapes: Vec<Shape>) -> Self {
+        self.shapes.extend(shapes);
+        self
+    }
+
+    /// Add a child connector (coordinates in the child space)
+    pub fn add_connector(mut self, connector: Connector) -> Self {
+        self.connectors.push(connector);
+        self
+    }
+
+    /// Number of shape IDs the group uses (the group itself plus its children)
+    pub fn id_count(&self) -> usize {
+        1 + self.shapes.len() + self.connectors.len()
+    }
+}
+
+/// Generate `<p:grpSp>` XML; children get consecutive IDs after `group_id` unless fixed
+pub fn generate_group_xml(group: &ShapeGroup, group_id: u32) -> String {
+    let name = group
+        .name
+        .as_deref()
+        .map(crate::core::escape_xml)
+        .unwrap_or_else(|| format!("Group {}", group_id));
+
+    let mut xml = format!(
+        r#"<p:grpSp>
+<p:nvGrpSpPr>
+<p:cNvPr id="{}" name="{}"/>
+<p:cNvGrpSpPr/>
+<p:nvPr/>
+</p:nvGrpSpPr>
+<p:grpSpPr>
+<a:xfrm>
+<a:off x="{}" y="{}"/>
+<a:ext cx="{}" cy="{}"/>
+<a:chOff x="{}" y="{}"/>
+<a:chExt cx="{}" cy="{}"/>
+</a:xfrm>
+</p:grpSpPr>"#,
+        group_id,
+        name,
+        group.x,
+        group.y,
+        group.width,
+        group.height,
+        group.child_x,
+        group.child_y,
+        group.child_width,
+        group.child_height,
+    );
+
+    for (i, shape) in group.shapes.iter().enumerate() {
+        xml.push('\n');
+        let shape_id = shape.id.unwrap_or(group_id + 1 + i as u32);
+        xml.push_str(&generate_shape_xml(shape, shape_id));
+    }
+    let connector_start_id = group_id as usize + 1 + group.shapes.len();
+    for (i, connector) in group.connectors.iter().enumerate() {
+        xml.push('\n');
+        xml.push_str(&generate_connector_xml(connector, connector_start_id + i));
+    }
+
+    xml.push_str("\n</p:grpSp>");
+    xml
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generator::shapes::ShapeType;
+
+    #[test]
+    fn test_group_xml() {
+        let group = ShapeGroup::new(914400, 914400, 1828800, 914400)
+            .add_shape(Shape::new(ShapeType::Rectangle, 0, 0, 914400, 914400))
+            .add_shape(Shape::new(ShapeType::Ellipse, 914400, 0, 914400, 914400));
+        let xml = generate_group_xml(&group, 40);
+
+        assert!(xml.starts_with("<p:grpSp>"));
+        assert!(xml.contains(r#"<p:cNvPr id="40" name="Group 40"/>"#));
+        assert!(xml.contains(r#"<a:off x="914400" y="914400"/>"#));
+        assert!(xml.contains(r#"<a:chOff x="0" y="0"/>"#));
+        assert!(xml.contains(r#"<a:chExt cx="1828800" cy="914400"/>"#));
+        assert!(xml.contains(r#"<p:cNvPr id="41" name="Shape 41"/>"#));
+        assert!(xml.contains(r#"<p:cNvPr id="42" name="Shape 42"/>"#));
+        assert_eq!(xml.matches("<p:sp>").count(), 2);
+    }
+
+    #[test]
+    fn test_group_from_shapes_and_move() {
+        let group = ShapeGroup::from_shapes(vec![
+            Shape::new(ShapeType::Rectangle, 100, 200, 300, 400),
+            Shape::new(ShapeType::Rectangle, 500, 100, 100, 100),
+        ]);
+        assert_eq!((group.x, group.y, group.width, group.height), (100, 100, 500, 500));
+        assert_eq!((group.child_x, group.child_y), (100, 100));
+
+        // Moving the group keeps children in the child space
+        let moved = group.at(0, 0);
+        let xml = generate_group_xml(&moved, 1);
+        assert!(xml.contains(r#"<a:off x="0" y="0"/>"#));
+        assert!(xml.contains(r#"<a:chOff x="100" y="100"/>"#));
+        assert!(xml.contains(r#"<a:off x="100" y="200"/>"#));
+    }
+
+    #[test]
+    fn test_group_connectors_follow_shapes() {
+        let group = ShapeGroup::new(0, 0, 2000, 1000)
+            .add_shape(Shape::new(ShapeType::Rectangle, 0, 0, 800, 1000))
+            .add_shape(Shape::new(ShapeType::Rectangle, 1200, 0, 800, 1000))
+            .add_connector(Connector::straight(800, 500, 1200, 500));
+        assert_eq!(group.id_count(), 4);
+        let xml = generate_group_xml(&group, 10);
+        let connector = xml.find("<p:cxnSp>").unwrap();
+        assert!(xml[connector..].contains(r#"<p:cNvPr id="13""#));
+        assert!(xml.find(r#"<p:cNvPr id="12""#).unwrap() < connector);
+    }
+}
@@ -4,6 +4,7 @@
 
 /// Shape types available in PPTX
 #[derive(Clone, Debug, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ShapeType {
     // Basic shapes
     Rectangle,
@@ -422,10 +423,24 @@ impl ShapeType {
             ShapeType::Minus => "Minus",
         }
     }
+
+    /// Navigation PowerPoint assigns a new action button of this type; the shape
+    /// uses it unless it has its own hyperlink
+    pub fn default_action(&self) -> Option<HyperlinkAction> {
+        match self {
+            ShapeType::ActionButtonForwardNext => Some(HyperlinkAction::NextSlide),
+            ShapeType::ActionButtonBackPrevious => Some(HyperlinkAction::PreviousSlide),
+            ShapeType::ActionButtonHome | ShapeType::ActionButtonBeginning => Some(HyperlinkAction::FirstSlide),
+            ShapeType::ActionButtonEnd => Some(HyperlinkAction::LastSlide),
+            ShapeType::ActionButtonReturn => Some(HyperlinkAction::LastSlideViewed),
+            _ => None,
+        }
+    }
 }
 
 /// Gradient direction for linear gradients
 #[derive(Clone, Debug, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum GradientDirection {
     /// Left to right (0 degrees)
     Horizontal,
@@ -437,10 +452,16 @@ pub enum GradientDirection {
     DiagonalUp,
     /// Custom angle in degrees (0-360)
     Angle(u32),
+    /// Circles radiating from the center
+    Radial,
+    /// Rectangles radiating from the center
+    Rectangular,
+    /// Radiating from the center, following the shape outline
+    Path,
 }
 
 impl GradientDirection {
-    /// Get angle in 60000ths of a degree (OOXML format)
+    /// Get angle in 60000ths of a degree (OOXML format); 0 for the radiating directions
     pub fn to_angle(&self) -> u32 {
         match self {
             GradientDirection::Horizontal => 0,
@@ -448,12 +469,34 @@ impl GradientDirection {
             GradientDirection::DiagonalDown => 2700000,  // 45 * 60000
             GradientDirection::DiagonalUp => 18900000,   // 315 * 60000
             GradientDirection::Angle(deg) => deg * 60000,
+            GradientDirection::Radial | GradientDirection::Rectangular | GradientDirection::Path => 0,
+        }
+    }
+
+    /// `path` attribute of `<a:path>` for the radiating directions, `None` for linear ones
+    pub fn path_type(&self) -> Option<&'static str> {
+        match self {
+            GradientDirection::Radial => Some("circle"),
+            GradientDirection::Rectangular => Some("rect"),
+            GradientDirection::Path => Some("shape"),
+            _ => None,
+        }
+    }
+
+    /// Shade element that follows `<a:gsLst>`: `<a:lin>` or `<a:path>`
+    pub fn shade_xml(&self) -> String {
+        match self.path_type() {
+            Some(path) => format!(
+                r#"<a:path path="{path}"><a:fillToRect l="50000" t="50000" r="50000" b="50000"/></a:path>"#
+            ),
+            None => format!(r#"<a:lin ang="{}" scaled="1"/>"#, self.to_angle()),
         }
     }
 }
 
 /// A gradient stop (color at a position)
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GradientStop {
     pub color: String,
     pub position: u32,  // 0-100000 (percentage * 1000)
@@ -462,9 +505,9 @@ pub struct GradientStop {
 
 impl GradientStop {
     /// Create a gradient stop at a position (0-100%)
-    pub fn new(color: &str, position_percent: u32) -> Self {
+    pub fn new(color: impl Into<RgbInput>, position_percent: u32) -> Self {
         GradientStop {
-            color: color.trim_start_matches('#').to_uppercase(),
+            color: color.into().into_value(),
             position: position_percent.min(100) * 1000,
             transparency: None,
         }
@@ -480,6 +523,7 @@ impl GradientStop {
 
 /// Gradient fill definition
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GradientFill {
     pub stops: Vec<GradientStop>,
     pub direction: GradientDirection,
@@ -487,7 +531,7 @@ pub struct GradientFill {
 
 impl GradientFill {
     /// Create a simple two-color gradient
-    pub fn linear(start_color: &str, end_color: &str, direction: GradientDirection) -> Self {
+    pub fn linear(start_color: impl Into<RgbInput>, end_color: impl Into<RgbInput>, direction: GradientDirection) -> Self {
         GradientFill {
             stops: vec![
                 GradientStop::new(start_color, 0),
@@ -498,7 +542,7 @@ impl GradientFill {
     }
     
     /// Create a three-color gradient
-    pub fn three_color(start: &str, middle: &str, end: &str, direction: GradientDirection) -> Self {
+    pub fn three_color(start: impl Into<RgbInput>, middle: impl Into<RgbInput>, end: impl Into<RgbInput>, direction: GradientDirection) -> Self {
         GradientFill {
             stops: vec![
                 GradientStop::new(start, 0),
@@ -517,6 +561,36 @@ impl GradientFill {
     }
 }
 
+/// Lets [`Shape::with_gradient`] take the gradients from [`crate::generator::gradients`],
+/// including [`crate::generator::PresetGradients`]
+impl From<gradients::GradientFill> for GradientFill {
+    fn from(gradient: gradients::GradientFill) -> Self {
+        let direction = match gradient.gradient_type {
+            gradients::GradientType::Linear => match gradient.direction {
+                gradients::GradientDirection::Horizontal => GradientDirection::Horizontal,
+                gradients::GradientDirection::Vertical => GradientDirection::Vertical,
+                gradients::GradientDirection::DiagonalDown => GradientDirection::DiagonalDown,
+                gradients::GradientDirection::DiagonalUp => GradientDirection::DiagonalUp,
+                gradients::GradientDirection::Custom(deg) => GradientDirection::Angle(deg % 360),
+            },
+            gradients::GradientType::Radial => GradientDirection::Radial,
+            gradients::GradientType::Rectangular => GradientDirection::Rectangular,
+            gradients::GradientType::Path => GradientDirection::Path,
+        };
+        let stops = gradient
+            .stops
+            .into_iter()
+            .map(|stop| GradientStop {
+                color: stop.color,
+                position: stop.position,
+                // Stored here as the remaining opacity rather than the transparency
+                transparency: stop.transparency.map(|t| 100000 - t.min(100000)),
+            })
+            .collect();
+        GradientFill { stops, direction }
+    }
+}
+
 /// Shape fill type - solid color or gradient
 #[derive(Clone, Debug)]
 pub enum FillType {
@@ -527,16 +601,17 @@ pub enum FillType {
 
 /// Shape fill/color properties
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ShapeFill {
-    pub color: String, // RGB hex color (e.g., "FF0000")
-    pub transparency: Option<u32>, // 0-100000 (100000 = fully transparent)
+    pub color: String, // RGB hex (e.g., "FF0000") or scheme color name (e.g., "accent1")
+    pub transparency: Option<u32>, // Remaining opacity, 0-100000 (100000 = opaque)
 }
 
 impl ShapeFill {
-    /// Create new shape fill with color
-    pub fn new(color: &str) -> Self {
+    /// Create new shape fill with an RGB or theme scheme color
+    pub fn new(color: impl Into<ColorInput>) -> Self {
         ShapeFill {
-            color: color.trim_start_matches('#').to_uppercase(),
+            color: color.into().into_value(),
             transparency: None,
         }
     }
@@ -554,8 +629,33 @@ impl ShapeFill {
     }
 }
 
+impl From<Color> for ShapeFill {
+    fn from(color: Color) -> Self {
+        ShapeFill::new(color)
+    }
+}
+
+impl From<RgbColor> for ShapeFill {
+    fn from(color: RgbColor) -> Self {
+        ShapeFill::new(color)
+    }
+}
+
+impl From<SchemeColor> for ShapeFill {
+    fn from(color: SchemeColor) -> Self {
+        ShapeFill::new(color)
+    }
+}
+
+impl From<&str> for ShapeFill {
+    fn from(color: &str) -> Self {
+        ShapeFill::new(color)
+    }
+}
+
 /// Shape line/border properties
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ShapeLine {
     pub color: String,
     pub width: u32, // in EMU (English Metric Units)
@@ -563,18 +663,244 @@ pub struct ShapeLine {
 
 impl ShapeLine {
     /// Create new shape line with color and width
-    pub fn new(color: &str, width: u32) -> Self {
+    pub fn new(color: impl Into<RgbInput>, width: u32) -> Self {
         ShapeLine {
-            color: color.trim_start_matches('#').to_uppercase(),
+            color: color.into().into_value(),
             width,
         }
     }
 }
 
-use crate::core::{Positioned, ElementSized, Dimension};
+/// Segment of a custom geometry path, in shape-local EMU
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PathSegment {
+    /// Start a new sub-path
+    MoveTo(u32, u32),
+    /// Straight line to a point
+    LineTo(u32, u32),
+    /// Quadratic Bézier: control point, end point
+    QuadTo((u32, u32), (u32, u32)),
+    /// Cubic Bézier: two control points, end point
+    CubicTo((u32, u32), (u32, u32), (u32, u32)),
+    /// Close the current sub-path
+    Close,
+}
+
+impl PathSegment {
+    /// Generate the `<a:path>` child element
+    pub fn to_xml(&self) -> String {
+        let pt = |(x, y): (u32, u32)| format!(r#"<a:pt x="{}" y="{}"/>"#, x, y);
+        match *self {
+            PathSegment::MoveTo(x, y) => format!("<a:moveTo>{}</a:moveTo>", pt((x, y))),
+            PathSegment::LineTo(x, y) => format!("<a:lnTo>{}</a:lnTo>", pt((x, y))),
+            PathSegment::QuadTo(c, end) => format!("<a:quadBezTo>{}{}</a:quadBezTo>", pt(c), pt(end)),
+            PathSegment::CubicTo(c1, c2, end) => {
+                format!("<a:cubicBezTo>{}{}{}</a:cubicBezTo>", pt(c1), pt(c2), pt(end))
+            }
+            PathSegment::Close => "<a:close/>".to_string(),
+        }
+    }
+}
+
+/// Common outer-shadow presets (PowerPoint's "Offset" gallery)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PresetShadow {
+    /// Shadow below and to the right
+    OffsetBottomRight,
+    /// Shadow directly below
+    OffsetBottom,
+    /// Shadow to the right
+    OffsetRight,
+    /// Shadow above
+    OffsetTop,
+    /// Soft shadow spread evenly around the shape
+    OffsetCenter,
+}
+
+/// Outer drop shadow for a shape
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ShapeShadow {
+    pub color: String,
+    pub blur_emu: u32,
+    pub distance_emu: u32,
+    /// Direction the shadow is cast in degrees (0 = right, 90 = down)
+    pub direction_deg: f64,
+    /// Shadow opacity (0-100 percent)
+    pub alpha: u32,
+}
+
+impl ShapeShadow {
+    /// Create a black shadow with PowerPoint's default 4pt blur, 3pt offset, and 40% opacity
+    pub fn new() -> Self {
+        ShapeShadow {
+            color: "000000".to_string(),
+            blur_emu: 50800,
+            distance_emu: 38100,
+            direction_deg: 45.0,
+            alpha: 40,
+        }
+    }
+
+    /// Create a shadow from a preset
+    pub fn preset(preset: PresetShadow) -> Self {
+        let shadow = Self::new();
+        match preset {
+            PresetShadow::OffsetBottomRight => shadow,
+            PresetShadow::OffsetBottom => shadow.direction(90.0),
+            PresetShadow::OffsetRight => shadow.direction(0.0),
+            PresetShadow::OffsetTop => shadow.direction(270.0),
+            PresetShadow::OffsetCenter => shadow.blur(63500).distance(0).direction(0.0),
+        }
+    }
+
+    /// Set shadow color (RGB hex)
+    pub fn color(mut self, color: impl Into<RgbInput>) -> Self {
+        self.color = color.into().into_value();
+        self
+    }
+
+    /// Set blur radius in EMU
+    pub fn blur(mut self, blur_emu: u32) -> Self {
+        self.blur_emu = blur_emu;
+        self
+    }
+
+    /// Set offset distance in EMU
+    pub fn distance(mut self, distance_emu: u32) -> Self {
+        self.distance_emu = distance_emu;
+        self
+    }
+
+    /// Set direction in degrees
+    pub fn direction(mut self, degrees: f64) -> Self {
+        self.direction_deg = degrees;
+        self
+    }
+
+    /// Set opacity (0-100 percent)
+    pub fn alpha(mut self, percent: u32) -> Self {
+        self.alpha = percent.min(100);
+        self
+    }
+
+    /// Direction in 60000ths of a degree, normalized to [0, 21600000)
+    pub fn direction_angle(&self) -> u32 {
+        ((self.direction_deg.rem_euclid(360.0) * 60000.0).round() as u32) % 21_600_000
+    }
+
+    /// Generate the `<a:outerShdw>` element
+    pub fn outer_shadow_xml(&self) -> String {
+        format!(
+            r#"<a:outerShdw blurRad="{}" dist="{}" dir="{}" algn="ctr" rotWithShape="0">{}</a:outerShdw>"#,
+            self.blur_emu,
+            self.distance_emu,
+            self.direction_angle(),
+            color_value_xml(&self.color, &format!(r#"<a:alpha val="{}"/>"#, self.alpha.min(100) * 1000))
+        )
+    }
+
+    /// Generate the `<a:effectLst>` element
+    pub fn to_xml(&self) -> String {
+        format!("<a:effectLst>{}</a:effectLst>", self.outer_shadow_xml())
+    }
+}
+
+impl Default for ShapeShadow {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Soft colored glow around a shape's outline
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ShapeGlow {
+    pub color: String,
+    /// Glow size in EMU
+    pub radius_emu: u32,
+    /// Opacity (0-100 percent)
+    pub alpha: u32,
+}
+
+impl ShapeGlow {
+    /// Create a glow at PowerPoint's default 40% opacity
+    pub fn new(color: impl Into<RgbInput>, radius_emu: u32) -> Self {
+        ShapeGlow {
+            color: color.into().into_value(),
+            radius_emu,
+            alpha: 40,
+        }
+    }
+
+    /// Set opacity (0-100 percent)
+    pub fn alpha(mut self, percent: u32) -> Self {
+        self.alpha = percent.min(100);
+        self
+    }
+
+    /// Generate the `<a:glow>` element
+    pub fn to_xml(&self) -> String {
+        format!(
+            r#"<a:glow rad="{}">{}</a:glow>"#,
+            self.radius_emu,
+            color_value_xml(&self.color, &format!(r#"<a:alpha val="{}"/>"#, self.alpha * 1000))
+        )
+    }
+}
+
+/// Reflection presets from PowerPoint's "Reflection Variations" gallery
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ReflectionPreset {
+    /// Tight reflection, touching
+    Tight,
+    /// Half reflection, touching
+    Half,
+    /// Full reflection, touching
+    Full,
+    /// Tight reflection, 4 pt offset
+    TightOffset,
+    /// Half reflection, 4 pt offset
+    HalfOffset,
+    /// Full reflection, 4 pt offset
+    FullOffset,
+}
+
+impl ReflectionPreset {
+    /// Generate the `<a:reflection>` element
+    pub fn to_xml(&self) -> String {
+        let (end_pos, dist) = match self {
+            ReflectionPreset::Tight => (35000, 0),
+            ReflectionPreset::Half => (55000, 0),
+            ReflectionPreset::Full => (90000, 0),
+            ReflectionPreset::TightOffset => (35000, 50800),
+            ReflectionPreset::HalfOffset => (55000, 50800),
+            ReflectionPreset::FullOffset => (90000, 50800),
+        };
+        let start_alpha = if matches!(self, ReflectionPreset::Tight | ReflectionPreset::TightOffset) {
+            52000
+        } else {
+            50000
+        };
+        let dist_attr = if dist > 0 { format!(r#" dist="{}""#, dist) } else { String::new() };
+        format!(
+            r#"<a:reflection blurRad="6350" stA="{}" endA="300" endPos="{}"{} dir="5400000" sy="-100000" algn="bl" rotWithShape="0"/>"#,
+            start_alpha, end_pos, dist_attr
+        )
+    }
+}
+
+use crate::elements::{color_value_xml, Color, ColorInput, RgbColor, RgbInput, SchemeColor};
+use super::gradients;
+use super::text::{TextAnchor, TextFrame};
+use super::hyperlinks::HyperlinkAction;
+use crate::core::{Positioned, ElementSized, Dimension, SlideSize};
 
 /// Shape definition
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Shape {
     pub shape_type: ShapeType,
     pub x: u32,      // Position X in EMU
@@ -585,12 +911,41 @@ pub struct Shape {
     pub gradient: Option<GradientFill>,
     pub line: Option<ShapeLine>,
     pub text: Option<String>,
+    /// Multi-paragraph text body; used instead of `text` when set
+    pub text_frame: Option<TextFrame>,
+    /// Vertical text anchor; `None` centers single-line text and top-anchors multi-line text
+    pub text_anchor: Option<TextAnchor>,
+    /// Whether text wraps at the shape edge; `None` wraps
+    pub text_wrap: Option<bool>,
     /// Optional fixed shape ID for connector anchoring
     pub id: Option<u32>,
     /// Rotation in degrees (0-360)
     pub rotation: Option<i32>,
+    /// Mirror horizontally
+    pub flip_h: bool,
+    /// Mirror vertically
+    pub flip_v: bool,
     /// Optional hyperlink
     pub hyperlink: Option<crate::generator::hyperlinks::Hyperlink>,
+    /// Optional outer drop shadow
+    pub shadow: Option<ShapeShadow>,
+    /// Optional glow around the outline
+    pub glow: Option<ShapeGlow>,
+    /// Optional reflection below the shape
+    pub reflection: Option<ReflectionPreset>,
+    /// Stacking order relative to other slide elements (higher draws on top)
+    pub z_order: i32,
+    /// Custom geometry path; replaces the preset geometry when set
+    pub path: Option<Vec<PathSegment>>,
+    /// Position set with [`Shape::at`], re-resolved against the deck's slide size on build
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub dimension_position: Option<(Dimension, Dimension)>,
+    /// Size set with [`Shape::with_dimensions`], re-resolved against the deck's slide size on build
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub dimension_size: Option<(Dimension, Dimension)>,
+    /// Points and closedness of a [`Shape::freeform`], re-resolved against the deck's slide size on build
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub dimension_points: Option<(Vec<(Dimension, Dimension)>, bool)>,
 }
 
 impl Shape {
@@ -606,12 +961,52 @@ impl Shape {
             gradient: None,
             line: None,
             text: None,
+            text_frame: None,
+            text_anchor: None,
+            text_wrap: None,
             id: None,
             rotation: None,
+            flip_h: false,
+            flip_v: false,
             hyperlink: None,
+            shadow: None,
+            glow: None,
+            reflection: None,
+            z_order: 0,
+            path: None,
+            dimension_position: None,
+            dimension_size: None,
+            dimension_points: None,
         }
     }
 
+    /// Create a freeform polygon through slide-space points; the shape is sized to their bounding box
+    ///
+    /// ```
+    /// use ppt_rs::core::Dimension;
+    /// use ppt_rs::generator::shapes::Shape;
+    ///
+    /// let triangle = Shape::freeform(vec![
+    ///     (Dimension::Inches(1.0), Dimension::Inches(2.0)),
+    ///     (Dimension::Inches(2.0), Dimension::Inches(1.0)),
+    ///     (Dimension::Inches(3.0), Dimension::Inches(2.0)),
+    /// ], true);
+    /// assert_eq!(triangle.width, 1828800);
+    /// ```
+    pub fn freeform(points: Vec<(Dimension, Dimension)>, closed: bool) -> Self {
+        let (x, y, width, height, segments) = freeform_geometry(&points, closed, &SlideSize::default());
+        let mut shape = Shape::freeform_path(x, y, width, height, segments);
+        shape.dimension_points = Some((points, closed));
+        shape
+    }
+
+    /// Create a shape from path segments in shape-local EMU (0,0 is the top-left corner)
+    pub fn freeform_path(x: u32, y: u32, width: u32, height: u32, segments: Vec<PathSegment>) -> Self {
+        let mut shape = Shape::new(ShapeType::Rectangle, x, y, width, height);
+        shape.path = Some(segments);
+        shape
+    }
+
     /// Set shape ID for connector anchoring
     pub fn with_id(mut self, id: u32) -> Self {
         self.id = Some(id);
@@ -624,6 +1019,18 @@ impl Shape {
         self
     }
 
+    /// Mirror the shape horizontally (`flipH`)
+    pub fn flip_horizontal(mut self) -> Self {
+        self.flip_h = true;
+        self
+    }
+
+    /// Mirror the shape vertically (`flipV`)
+    pub fn flip_vertical(mut self) -> Self {
+        self.flip_v = true;
+        self
+    }
+
     /// Set shape hyperlink
     pub fn with_hyperlink(mut self, hyperlink: crate::generator::hyperlinks::Hyperlink) -> Self {
         self.hyperlink = Some(hyperlink);
@@ -631,15 +1038,15 @@ impl Shape {
     }
 
     /// Set shape fill (solid color)
-    pub fn with_fill(mut self, fill: ShapeFill) -> Self {
-        self.fill = Some(fill);
+    pub fn with_fill(mut self, fill: impl Into<ShapeFill>) -> Self {
+        self.fill = Some(fill.into());
         self.gradient = None; // Clear gradient if setting solid fill
         self
     }
     
     /// Set gradient fill
-    pub fn with_gradient(mut self, gradient: GradientFill) -> Self {
-        self.gradient = Some(gradient);
+    pub fn with_gradient(mut self, gradient: impl Into<GradientFill>) -> Self {
+        self.gradient = Some(gradient.into());
         self.fill = None; // Clear solid fill if setting gradient
         self
     }
@@ -650,9 +1057,79 @@ impl Shape {
         self
     }
 
+    /// Set stacking order; negative values draw behind the title and body placeholders
+    pub fn with_z_order(mut self, z_order: i32) -> Self {
+        self.z_order = z_order;
+        self
+    }
+
+    /// Set outer drop shadow
+    pub fn with_shadow(mut self, shadow: ShapeShadow) -> Self {
+        self.shadow = Some(shadow);
+        self
+    }
+
+    /// Add a colored glow of `radius_emu` around the outline
+    pub fn with_glow(mut self, color: impl Into<RgbInput>, radius_emu: u32) -> Self {
+        self.glow = Some(ShapeGlow::new(color, radius_emu));
+        self
+    }
+
+    /// Add a reflection below the shape
+    pub fn with_reflection(mut self, preset: ReflectionPreset) -> Self {
+        self.reflection = Some(preset);
+        self
+    }
+
+    /// Generate the shape's `<a:effectLst>` (glow, shadow, reflection in schema order)
+    pub fn effects_xml(&self) -> String {
+        let glow = self.glow.as_ref().map(ShapeGlow::to_xml);
+        let shadow = self.shadow.as_ref().map(ShapeShadow::outer_shadow_xml);
+        let reflection = self.reflection.as_ref().map(ReflectionPreset::to_xml);
+        let effects: String = [glow, shadow, reflection].into_iter().flatten().collect();
+        if effects.is_empty() {
+            effects
+        } else {
+            format!("<a:effectLst>{}</a:effectLst>", effects)
+        }
+    }
+
     /// Set shape text
     pub fn with_text(mut self, text: &str) -> Self {
         self.text = Some(text.to_string());
+        self.text_frame = None;
+        self
+    }
+
+    /// Set a text frame with its own paragraphs, alignment, bullets and spacing
+    ///
+    /// ```
+    /// use ppt_rs::generator::shapes::{Shape, ShapeType};
+    /// use ppt_rs::generator::text::{Paragraph, Run, TextFrame};
+    ///
+    /// let callout = Shape::new(ShapeType::Rectangle, 0, 0, 3_000_000, 1_500_000).with_text_frame(
+    ///     TextFrame::new()
+    ///         .add_paragraph(Paragraph::new().add_run(Run::new("Heads up").bold()))
+    ///         .add_text("Doors open at 9")
+    ///         .add_text("Bring your badge"),
+    /// );
+    /// assert_eq!(callout.text_frame.unwrap().paragraphs.len(), 3);
+    /// ```
+    pub fn with_text_frame(mut self, text_frame: TextFrame) -> Self {
+        self.text_frame = Some(text_frame);
+        self.text = None;
+        self
+    }
+
+    /// Set the vertical anchor of the shape's text
+    pub fn text_anchor(mut self, anchor: TextAnchor) -> Self {
+        self.text_anchor = Some(anchor);
+        self
+    }
+
+    /// Wrap text at the shape edge, or keep each line on one line when `false`
+    pub fn text_wrap(mut self, wrap: bool) -> Self {
+        self.text_wrap = Some(wrap);
         self
     }
 
@@ -670,11 +1147,14 @@ impl Shape {
     /// );
     /// ```
     pub fn from_dimensions(shape_type: ShapeType, x: Dimension, y: Dimension, width: Dimension, height: Dimension) -> Self {
-        Shape::new(shape_type, x.to_emu_x(), y.to_emu_y(), width.to_emu_x(), height.to_emu_y())
+        Shape::new(shape_type, 0, 0, 0, 0).at(x, y).with_dimensions(width, height)
     }
 
     /// Set position using flexible Dimension units (fluent).
     ///
+    /// Ratios are relative to the slide the shape ends up on, so a shape at
+    /// `Ratio(0.5)` starts halfway across both 4:3 and 16:9 decks.
+    ///
     /// ```
     /// use ppt_rs::core::Dimension;
     /// use ppt_rs::generator::shapes::{Shape, ShapeType};
@@ -685,6 +1165,7 @@ impl Shape {
     pub fn at(mut self, x: Dimension, y: Dimension) -> Self {
         self.x = x.to_emu_x();
         self.y = y.to_emu_y();
+        self.dimension_position = Some((x, y));
         self
     }
 
@@ -700,8 +1181,54 @@ impl Shape {
     pub fn with_dimensions(mut self, width: Dimension, height: Dimension) -> Self {
         self.width = width.to_emu_x();
         self.height = height.to_emu_y();
+        self.dimension_size = Some((width, height));
         self
     }
+
+    /// Resolve Dimension placement against a slide size
+    pub fn fit_to(&mut self, size: &SlideSize) {
+        if let Some((points, closed)) = &self.dimension_points {
+            let (x, y, width, height, segments) = freeform_geometry(points, *closed, size);
+            (self.x, self.y, self.width, self.height) = (x, y, width, height);
+            self.path = Some(segments);
+        }
+        if let Some((x, y)) = &self.dimension_position {
+            self.x = x.to_emu_x_in(size);
+            self.y = y.to_emu_y_in(size);
+        }
+        if let Some((width, height)) = &self.dimension_size {
+            self.width = width.to_emu_x_in(size);
+            self.height = height.to_emu_y_in(size);
+        }
+    }
+
+    /// Whether the shape was placed with Dimension units
+    pub fn has_dimension_placement(&self) -> bool {
+        self.dimension_position.is_some() || self.dimension_size.is_some() || self.dimension_points.is_some()
+    }
+}
+
+/// Bounding box `(x, y, width, height)` and shape-local path of a polygon through slide-space points
+fn freeform_geometry(points: &[(Dimension, Dimension)], closed: bool, size: &SlideSize) -> (u32, u32, u32, u32, Vec<PathSegment>) {
+    let points: Vec<(u32, u32)> = points.iter().map(|(x, y)| (x.to_emu_x_in(size), y.to_emu_y_in(size))).collect();
+    let min_x = points.iter().map(|p| p.0).min().unwrap_or(0);
+    let min_y = points.iter().map(|p| p.1).min().unwrap_or(0);
+    let max_x = points.iter().map(|p| p.0).max().unwrap_or(0);
+    let max_y = points.iter().map(|p| p.1).max().unwrap_or(0);
+
+    let mut segments: Vec<PathSegment> = points
+        .iter()
+        .enumerate()
+        .map(|(i, &(x, y))| {
+            let (x, y) = (x - min_x, y - min_y);
+            if i == 0 { PathSegment::MoveTo(x, y) } else { PathSegment::LineTo(x, y) }
+        })
+        .collect();
+    if closed && !segments.is_empty() {
+        segments.push(PathSegment::Close);
+    }
+
+    (min_x, min_y, max_x - min_x, max_y - min_y, segments)
 }
 
 impl Positioned for Shape {
@@ -750,6 +1277,31 @@ mod tests {
         assert_eq!(ShapeType::Heart.preset_name(), "heart");
     }
 
+    #[test]
+    fn test_action_button_default_actions() {
+        assert_eq!(ShapeType::ActionButtonForwardNext.default_action(), Some(HyperlinkAction::NextSlide));
+        assert_eq!(ShapeType::ActionButtonBackPrevious.default_action(), Some(HyperlinkAction::PreviousSlide));
+        assert_eq!(ShapeType::ActionButtonHome.default_action(), Some(HyperlinkAction::FirstSlide));
+        assert_eq!(ShapeType::ActionButtonReturn.default_action(), Some(HyperlinkAction::LastSlideViewed));
+        assert_eq!(ShapeType::ActionButtonBlank.default_action(), None);
+        assert_eq!(ShapeType::Rectangle.default_action(), None);
+    }
+
+    #[test]
+    fn test_shape_shadow() {
+        let shadow = ShapeShadow::new();
+        assert_eq!(shadow.direction_angle(), 2_700_000);
+        let xml = shadow.to_xml();
+        assert!(xml.starts_with("<a:effectLst><a:outerShdw"));
+        assert!(xml.contains(r#"blurRad="50800" dist="38100" dir="2700000""#));
+        assert!(xml.contains(r#"<a:alpha val="40000"/>"#));
+
+        assert_eq!(ShapeShadow::new().direction(-90.0).direction_angle(), 16_200_000);
+        assert_eq!(ShapeShadow::new().direction(360.0).direction_angle(), 0);
+        assert_eq!(ShapeShadow::preset(PresetShadow::OffsetBottom).direction_angle(), 5_400_000);
+        assert_eq!(ShapeShadow::preset(PresetShadow::OffsetCenter).distance_emu, 0);
+    }
+
     #[test]
     fn test_shape_fill_builder() {
         let fill = ShapeFill::new("FF0000").transparency(50);
@@ -843,4 +1395,15 @@ mod tests {
         assert_eq!(shape.height, 4114800);
         assert!(shape.fill.is_some());
     }
+
+    #[test]
+    fn test_color_setters_normalize_input() {
+        assert_eq!(ShapeFill::new("#4472c4").color, "4472C4");
+        assert_eq!(ShapeFill::new("#f80").color, "FF8800");
+        assert_eq!(ShapeLine::new("navy", 12700).color, "000080");
+        assert_eq!(ShapeFill::new((68, 114, 196)).color, "4472C4");
+        assert_eq!(ShapeFill::new(RgbColor::from_hsl(0.0, 1.0, 0.5)).color, "FF0000");
+        assert_eq!(ShapeShadow::new().color("dimgray").color, "696969");
+        assert_eq!(GradientFill::linear("white", "#000", GradientDirection::Horizontal).stops[1].color, "000000");
+    }
 }
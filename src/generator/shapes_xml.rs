@@ -2,9 +2,11 @@
 //!
 //! Generates XML for shapes embedded in slides.
 
-use super::shapes::{Shape, ShapeFill, ShapeLine, GradientFill};
+use super::shapes::{Shape, ShapeFill, ShapeLine, GradientFill, PathSegment};
+use super::text::{TextAnchor, TextFrame};
 use crate::generator::hyperlinks::generate_shape_hyperlink_xml;
 use crate::core::escape_xml;
+use crate::elements::color_value_xml;
 
 /// Generate XML for a shape
 pub fn generate_shape_xml(shape: &Shape, shape_id: u32) -> String {
@@ -15,14 +17,32 @@ pub fn generate_shape_xml(shape: &Shape, shape_id: u32) -> String {
         generate_fill_xml(&shape.fill)
     };
     let line_xml = generate_line_xml(&shape.line);
+    let effect_xml = shape.effects_xml();
     let fill_color = shape.fill.as_ref().map(|f| f.color.as_str());
-    let text_xml = generate_text_xml_with_autofit(&shape.text, shape.width, shape.height, fill_color);
+    let text_xml = match &shape.text_frame {
+        Some(text_frame) => shape_text_frame(shape, text_frame).to_xml(),
+        None => generate_text_xml_with_autofit(shape, fill_color),
+    };
     
-    let rot_attr = if let Some(rot) = shape.rotation {
+    let mut xfrm_attrs = if let Some(rot) = shape.rotation {
         format!(r#" rot="{}""#, rot * 60000)
     } else {
         String::new()
     };
+    if shape.flip_h {
+        xfrm_attrs.push_str(r#" flipH="1""#);
+    }
+    if shape.flip_v {
+        xfrm_attrs.push_str(r#" flipV="1""#);
+    }
+
+    let geometry_xml = match &shape.path {
+        Some(segments) => generate_custom_geometry_xml(segments, shape.width, shape.height),
+        None => format!(
+            "<a:prstGeom prst=\"{}\">\n<a:avLst/>\n</a:prstGeom>",
+            shape.shape_type.preset_name()
+        ),
+    };
 
     let cnvpr_xml = if let Some(h) = &shape.hyperlink {
         if let Some(rid) = &h.r_id {
@@ -46,26 +66,34 @@ pub fn generate_shape_xml(shape: &Shape, shape_id: u32) -> String {
 <a:off x="{}" y="{}"/>
 <a:ext cx="{}" cy="{}"/>
 </a:xfrm>
-<a:prstGeom prst="{}">
-<a:avLst/>
-</a:prstGeom>
-{}{}
+{}
+{}{}{}
 </p:spPr>
 {}
 </p:sp>"#,
         cnvpr_xml,
-        rot_attr,
+        xfrm_attrs,
         shape.x,
         shape.y,
         shape.width,
         shape.height,
-        shape.shape_type.preset_name(),
+        geometry_xml,
         fill_xml,
         line_xml,
+        effect_xml,
         text_xml,
     )
 }
 
+/// Generate `<a:custGeom>` with a single path in the shape's own coordinate space
+fn generate_custom_geometry_xml(segments: &[PathSegment], width: u32, height: u32) -> String {
+    let path: String = segments.iter().map(PathSegment::to_xml).collect();
+    format!(
+        r#"<a:custGeom><a:avLst/><a:gdLst/><a:ahLst/><a:cxnLst/><a:rect l="l" t="t" r="r" b="b"/><a:pathLst><a:path w="{}" h="{}">{}</a:path></a:pathLst></a:custGeom>"#,
+        width, height, path
+    )
+}
+
 /// Generate fill XML for solid color
 fn generate_fill_xml(fill: &Option<ShapeFill>) -> String {
     match fill {
@@ -76,9 +104,9 @@ fn generate_fill_xml(fill: &Option<ShapeFill>) -> String {
             
             format!(
                 r#"<a:solidFill>
-<a:srgbClr val="{}">{}</a:srgbClr>
+{}
 </a:solidFill>"#,
-                f.color, alpha
+                color_value_xml(&f.color, &alpha)
             )
         }
         None => String::new(),
@@ -96,9 +124,9 @@ fn generate_gradient_xml(gradient: &GradientFill) -> String {
         
         stops_xml.push_str(&format!(
             r#"<a:gs pos="{}">
-<a:srgbClr val="{}">{}</a:srgbClr>
+{}
 </a:gs>"#,
-            stop.position, stop.color, alpha
+            stop.position, color_value_xml(&stop.color, &alpha)
         ));
     }
     
@@ -107,10 +135,10 @@ fn generate_gradient_xml(gradient: &GradientFill) -> String {
 <a:gsLst>
 {}
 </a:gsLst>
-<a:lin ang="{}" scaled="1"/>
+{}
 </a:gradFill>"#,
         stops_xml,
-        gradient.direction.to_angle()
+        gradient.direction.shade_xml()
     )
 }
 
@@ -121,10 +149,10 @@ fn generate_line_xml(line: &Option<ShapeLine>) -> String {
             format!(
                 r#"<a:ln w="{}">
 <a:solidFill>
-<a:srgbClr val="{}"/>
+{}
 </a:solidFill>
 </a:ln>"#,
-                l.width, l.color
+                l.width, color_value_xml(&l.color, "")
             )
         }
         None => String::new(),
@@ -334,9 +362,28 @@ fn get_text_color(fill_color: Option<&str>) -> &'static str {
     }
 }
 
+/// The shape's text frame with the shape-level anchor and wrap applied
+fn shape_text_frame(shape: &Shape, text_frame: &TextFrame) -> TextFrame {
+    let mut text_frame = text_frame.clone();
+    if let Some(anchor) = shape.text_anchor {
+        text_frame.anchor = anchor;
+    }
+    if let Some(wrap) = shape.text_wrap {
+        text_frame.wrap = wrap;
+    }
+    text_frame
+}
+
+/// `wrap` value for the shape's `<a:bodyPr>`
+fn wrap_attr(shape: &Shape) -> &'static str {
+    if shape.text_wrap.unwrap_or(true) { "square" } else { "none" }
+}
+
 /// Generate text body XML for shape with auto-fit font sizing
-fn generate_text_xml_with_autofit(text: &Option<String>, width: u32, height: u32, fill_color: Option<&str>) -> String {
-    match text {
+fn generate_text_xml_with_autofit(shape: &Shape, fill_color: Option<&str>) -> String {
+    let (width, height) = (shape.width, shape.height);
+    let wrap = wrap_attr(shape);
+    match &shape.text {
         Some(t) => {
             // Check if this is code (starts with [ and contains language tag)
             let is_code = t.starts_with('[') && t.contains("]\n");
@@ -359,9 +406,11 @@ fn generate_text_xml_with_autofit(text: &Option<String>, width: u32, height: u32
                 }
                 format!(
                     r#"<p:txBody>
-<a:bodyPr wrap="square" rtlCol="0" anchor="t" lIns="91440" tIns="45720" rIns="91440" bIns="45720"/>
+<a:bodyPr wrap="{}" rtlCol="0" anchor="{}" lIns="91440" tIns="45720" rIns="91440" bIns="45720"/>
 <a:lstStyle/>
 {}</p:txBody>"#,
+                    wrap,
+                    shape.text_anchor.unwrap_or(TextAnchor::Top).to_xml(),
                     paragraphs
                 )
             } else {
@@ -374,7 +423,11 @@ fn generate_text_xml_with_autofit(text: &Option<String>, width: u32, height: u32
                 // Use left alignment for multi-line text, center for single line
                 let is_multiline = t.contains('\n');
                 let alignment = if is_multiline { "l" } else { "ctr" };
-                let anchor = if is_multiline { "t" } else { "ctr" };
+                let anchor = match shape.text_anchor {
+                    Some(anchor) => anchor.to_xml(),
+                    None if is_multiline => "t",
+                    None => "ctr",
+                };
                 
                 // Calculate proper text insets (margins) in EMU
                 // Standard insets: 0.1 inch (91440 EMU) on left/right, 0.05 inch (45720 EMU) on top/bottom
@@ -386,7 +439,7 @@ fn generate_text_xml_with_autofit(text: &Option<String>, width: u32, height: u32
                 // Use PowerPoint's auto-fit feature for additional safety
                 format!(
                     r#"<p:txBody>
-<a:bodyPr wrap="square" rtlCol="0" anchor="{}" lIns="{}" tIns="{}" rIns="{}" bIns="{}">
+<a:bodyPr wrap="{}" rtlCol="0" anchor="{}" lIns="{}" tIns="{}" rIns="{}" bIns="{}">
 <a:normAutofit/>
 </a:bodyPr>
 <a:lstStyle/>
@@ -398,6 +451,7 @@ fn generate_text_xml_with_autofit(text: &Option<String>, width: u32, height: u32
 </a:r>
 </a:p>
 </p:txBody>"#,
+                    wrap,
                     anchor,
                     left_inset,
                     top_inset,
@@ -503,6 +557,52 @@ mod tests {
         assert!(xml.contains("p:txBody"));
     }
 
+    #[test]
+    fn test_generate_shape_with_text_frame() {
+        use crate::generator::text::{Paragraph, Run, TextAlign, TextFrame};
+        let frame = TextFrame::new()
+            .add_paragraph(Paragraph::new().add_run(Run::new("Heads up").bold()).align(TextAlign::Center))
+            .add_paragraph(Paragraph::with_text("Doors open at 9").bullet())
+            .add_paragraph(Paragraph::with_text("Bring your badge").spacing_before(6));
+        let shape = Shape::new(ShapeType::Rectangle, 0, 0, 3000000, 1500000).with_text_frame(frame);
+
+        let xml = generate_shape_xml(&shape, 1);
+
+        assert_eq!(xml.matches("<a:p>").count(), 3);
+        assert!(xml.contains(r#"<a:pPr algn="ctr" lvl="0"/>"#));
+        assert!(xml.contains(r#"b="1""#));
+        assert!(xml.contains("<a:buChar"));
+        assert!(xml.contains(r#"<a:spcBef><a:spcPts val="600"/></a:spcBef>"#));
+        assert_eq!(xml.matches("<p:txBody>").count(), 1);
+
+        // The plain-text setter replaces the frame
+        let xml = generate_shape_xml(&shape.with_text("Plain"), 1);
+        assert!(xml.contains("Plain") && !xml.contains("Heads up"));
+    }
+
+    #[test]
+    fn test_generate_shape_text_anchor_and_wrap() {
+        let label = Shape::new(ShapeType::Rectangle, 0, 0, 1000000, 300000)
+            .with_text("Status")
+            .text_anchor(TextAnchor::Bottom)
+            .text_wrap(false);
+        let xml = generate_shape_xml(&label, 1);
+        assert!(xml.contains(r#"<a:bodyPr wrap="none" rtlCol="0" anchor="b""#));
+
+        let multi = Shape::new(ShapeType::Rectangle, 0, 0, 1000000, 1000000)
+            .with_text("One\nTwo")
+            .text_anchor(TextAnchor::Middle);
+        assert!(generate_shape_xml(&multi, 1).contains(r#"<a:bodyPr wrap="square" rtlCol="0" anchor="ctr""#));
+
+        // Shape-level settings override the text frame's own
+        let framed = Shape::new(ShapeType::Rectangle, 0, 0, 1000000, 300000)
+            .with_text_frame(TextFrame::with_text("Framed"))
+            .text_anchor(TextAnchor::Middle)
+            .text_wrap(false);
+        assert!(generate_shape_xml(&framed, 1).contains(r#"<a:bodyPr wrap="none" lIns="91440""#));
+        assert!(generate_shape_xml(&framed, 1).contains(r#"anchor="ctr"/>"#));
+    }
+
     #[test]
     fn test_generate_shape_with_line() {
         let shape = Shape::new(ShapeType::Circle, 0, 0, 500000, 500000)
@@ -514,6 +614,34 @@ mod tests {
         assert!(xml.contains("25400"));
     }
 
+    #[test]
+    fn test_generate_shape_with_shadow() {
+        use crate::generator::shapes::ShapeShadow;
+        let shape = Shape::new(ShapeType::Rectangle, 0, 0, 500000, 500000)
+            .with_line(ShapeLine::new("000000", 12700))
+            .with_shadow(ShapeShadow::new());
+        
+        let xml = generate_shape_xml(&shape, 1);
+        
+        let ln = xml.find("</a:ln>").unwrap();
+        let effect = xml.find("<a:effectLst>").unwrap();
+        assert!(ln < effect && effect < xml.find("</p:spPr>").unwrap());
+        assert!(!generate_shape_xml(&Shape::new(ShapeType::Rectangle, 0, 0, 1, 1), 1).contains("effectLst"));
+    }
+
+    #[test]
+    fn test_generate_shape_rotation_and_flip() {
+        let shape = Shape::new(ShapeType::RightArrow, 0, 0, 500000, 500000)
+            .with_rotation(90)
+            .flip_horizontal()
+            .flip_vertical();
+        let xml = generate_shape_xml(&shape, 1);
+        assert!(xml.contains(r#"<a:xfrm rot="5400000" flipH="1" flipV="1">"#));
+
+        let xml = generate_shape_xml(&Shape::new(ShapeType::Triangle, 0, 0, 1, 1).flip_vertical(), 1);
+        assert!(xml.contains(r#"<a:xfrm flipV="1">"#));
+    }
+
     #[test]
     fn test_generate_multiple_shapes() {
         let shapes = vec![
@@ -665,4 +793,104 @@ mod tests {
         let xml2 = generate_shape_xml(&shape2, 1);
         assert!(xml2.contains("000000"), "Light fill should have black text");
     }
+
+    #[test]
+    fn test_freeform_shape_xml() {
+        use crate::core::Dimension;
+        use crate::generator::shapes::PathSegment;
+
+        let arrow = Shape::freeform(vec![
+            (Dimension::Emu(1000), Dimension::Emu(2000)),
+            (Dimension::Emu(3000), Dimension::Emu(500)),
+            (Dimension::Emu(5000), Dimension::Emu(2000)),
+        ], true);
+        assert_eq!((arrow.x, arrow.y, arrow.width, arrow.height), (1000, 500, 4000, 1500));
+
+        let xml = generate_shape_xml(&arrow, 1);
+        assert!(!xml.contains("prstGeom"));
+        assert!(xml.contains(r#"<a:path w="4000" h="1500"><a:moveTo><a:pt x="0" y="1500"/></a:moveTo><a:lnTo><a:pt x="2000" y="0"/></a:lnTo><a:lnTo><a:pt x="4000" y="1500"/></a:lnTo><a:close/></a:path>"#));
+
+        let open = Shape::freeform(vec![(Dimension::Emu(0), Dimension::Emu(0)), (Dimension::Emu(10), Dimension::Emu(10))], false);
+        assert!(!generate_shape_xml(&open, 1).contains("<a:close/>"));
+
+        let curve = Shape::freeform_path(0, 0, 100, 100, vec![
+            PathSegment::MoveTo(0, 100),
+            PathSegment::QuadTo((50, 0), (100, 100)),
+            PathSegment::CubicTo((0, 0), (100, 0), (0, 100)),
+        ]);
+        let xml = generate_shape_xml(&curve, 1);
+        assert!(xml.contains(r#"<a:quadBezTo><a:pt x="50" y="0"/><a:pt x="100" y="100"/></a:quadBezTo>"#));
+        assert!(xml.contains("<a:cubicBezTo>"));
+    }
+
+    #[test]
+    fn test_shape_effects_order() {
+        use crate::generator::shapes::{ReflectionPreset, ShapeShadow, ShapeType};
+
+        let shape = Shape::new(ShapeType::RoundedRectangle, 0, 0, 100, 100)
+            .with_reflection(ReflectionPreset::Tight)
+            .with_shadow(ShapeShadow::new())
+            .with_glow("#ffc000", 101600);
+        let xml = generate_shape_xml(&shape, 1);
+        assert_eq!(xml.matches("<a:effectLst>").count(), 1);
+        let glow = xml.find(r#"<a:glow rad="101600"><a:srgbClr val="FFC000"><a:alpha val="40000"/>"#).unwrap();
+        let shadow = xml.find("<a:outerShdw").unwrap();
+        let reflection = xml.find(r#"<a:reflection blurRad="6350" stA="52000" endA="300" endPos="35000" dir="5400000""#).unwrap();
+        assert!(glow < shadow && shadow < reflection);
+
+        let plain = Shape::new(ShapeType::Rectangle, 0, 0, 100, 100);
+        assert!(!generate_shape_xml(&plain, 1).contains("effectLst"));
+    }
+
+    #[test]
+    fn test_scheme_color_fill() {
+        use crate::elements::SchemeColor;
+
+        let shape = Shape::new(ShapeType::Rectangle, 0, 0, 100, 100).with_fill(SchemeColor::Accent1);
+        let xml = generate_shape_xml(&shape, 1);
+        assert!(xml.contains("<a:solidFill>\n<a:schemeClr val=\"accent1\"/>\n</a:solidFill>"));
+
+        let shape = Shape::new(ShapeType::Rectangle, 0, 0, 100, 100)
+            .with_fill(ShapeFill::new("accent2").with_transparency(50));
+        let xml = generate_shape_xml(&shape, 1);
+        assert!(xml.contains(r#"<a:schemeClr val="accent2"><a:alpha val="50000"/></a:schemeClr>"#));
+    }
+
+
+    #[test]
+    fn test_gradient_directions() {
+        use super::super::shapes::GradientDirection;
+
+        let button = |direction| {
+            let shape = Shape::new(ShapeType::RoundedRectangle, 0, 0, 100, 100)
+                .with_gradient(GradientFill::linear("4472C4", "1F3864", direction));
+            generate_shape_xml(&shape, 1)
+        };
+        assert!(button(GradientDirection::DiagonalDown).contains(r#"<a:lin ang="2700000" scaled="1"/>"#));
+        assert!(button(GradientDirection::Vertical).contains(r#"<a:lin ang="5400000" scaled="1"/>"#));
+        let radial = button(GradientDirection::Radial);
+        assert!(radial.contains(r#"<a:path path="circle"><a:fillToRect l="50000" t="50000" r="50000" b="50000"/></a:path>"#));
+        assert!(!radial.contains("<a:lin "));
+    }
+
+    #[test]
+    fn test_gradient_from_gradients_module() {
+        use crate::generator::gradients::{self, GradientStop, PresetGradients};
+
+        let diagonal = gradients::GradientFill::two_color("FF0000", "0000FF")
+            .with_direction(gradients::GradientDirection::DiagonalDown);
+        let xml = generate_shape_xml(&Shape::new(ShapeType::Rectangle, 0, 0, 100, 100).with_gradient(diagonal), 1);
+        assert!(xml.contains(r#"<a:lin ang="2700000" scaled="1"/>"#));
+        assert!(xml.contains(r#"<a:gs pos="100000">"#));
+
+        let faded = gradients::GradientFill::radial()
+            .add_stop(GradientStop::start("FFFFFF").with_transparency(25))
+            .add_stop(GradientStop::end("000000"));
+        let xml = generate_shape_xml(&Shape::new(ShapeType::Ellipse, 0, 0, 100, 100).with_gradient(faded), 1);
+        assert!(xml.contains(r#"<a:srgbClr val="FFFFFF"><a:alpha val="75000"/></a:srgbClr>"#));
+        assert!(xml.contains(r#"<a:path path="circle">"#));
+
+        let xml = generate_shape_xml(&Shape::new(ShapeType::Rectangle, 0, 0, 100, 100).with_gradient(PresetGradients::ocean()), 1);
+        assert_eq!(xml.matches("<a:gs ").count(), 3);
+    }
 }
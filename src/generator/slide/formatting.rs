@@ -4,6 +4,7 @@
 //! generates corresponding PPTX XML text runs.
 
 use crate::core::escape_xml;
+use crate::generator::text::color_to_xml;
 
 /// A text segment with formatting
 #[derive(Debug, Clone)]
@@ -132,8 +133,7 @@ pub fn generate_rich_text_runs(
             
             if let Some(color) = base_color {
                 props.push('>');
-                let clean_color = color.trim_start_matches('#').to_uppercase();
-                props.push_str(&format!(r#"<a:solidFill><a:srgbClr val="{}"/></a:solidFill>"#, clean_color));
+                props.push_str(&color_to_xml(color));
                 props.push_str("</a:rPr>");
             } else {
                 props.push_str("/>");
@@ -168,10 +168,7 @@ pub fn generate_text_props(
     props.push('>');
 
     if let Some(hex_color) = color {
-        let clean_color = hex_color.trim_start_matches('#').to_uppercase();
-        props.push_str(&format!(
-            r#"<a:solidFill><a:srgbClr val="{clean_color}"/></a:solidFill>"#
-        ));
+        props.push_str(&color_to_xml(hex_color));
     }
 
     props.push_str("</a:rPr>");
@@ -0,0 +1,521 @@
+//! Entrance and exit animations
+//!
+//! Animations are written as the slide's `<p:timing>` tree: one main sequence
+//! of click groups, each holding `par` containers that run effects with or
+//! after the previous one, using PowerPoint's own preset IDs so the effects
+//! show up by name in the Animation pane. Embedded video and audio join the same
+//! tree as media nodes with click-to-play triggers.
+
+use crate::generator::media::MediaPlayback;
+
+/// `cNvPr` id of the body placeholder that holds a slide's bullets
+pub const BODY_PLACEHOLDER_ID: u32 = 3;
+
+/// Built-in animation effect
+#[derive(Clone, Debug, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AnimationEffect {
+    Appear,
+    FadeIn,
+    /// Flies in from (or out to) the bottom edge
+    FlyIn,
+    /// Wipes from the bottom
+    Wipe,
+    Zoom,
+}
+
+impl AnimationEffect {
+    fn preset_id(&self) -> u32 {
+        match self {
+            AnimationEffect::Appear => 1,
+            AnimationEffect::FlyIn => 2,
+            AnimationEffect::FadeIn => 10,
+            AnimationEffect::Wipe => 22,
+            AnimationEffect::Zoom => 53,
+        }
+    }
+
+    fn preset_subtype(&self) -> u32 {
+        match self {
+            AnimationEffect::FlyIn | AnimationEffect::Wipe => 4,
+            AnimationEffect::Zoom => 16,
+            AnimationEffect::Appear | AnimationEffect::FadeIn => 0,
+        }
+    }
+}
+
+/// What starts an animation
+#[derive(Clone, Debug, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AnimationTrigger {
+    #[default]
+    OnClick,
+    WithPrevious,
+    AfterPrevious,
+}
+
+impl AnimationTrigger {
+    fn node_type(&self) -> &'static str {
+        match self {
+            AnimationTrigger::OnClick => "clickEffect",
+            AnimationTrigger::WithPrevious => "withEffect",
+            AnimationTrigger::AfterPrevious => "afterEffect",
+        }
+    }
+}
+
+/// An entrance or exit effect with its trigger and timing
+#[derive(Clone, Debug, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Animation {
+    pub effect: AnimationEffect,
+    pub trigger: AnimationTrigger,
+    pub delay_ms: u32,
+    pub duration_ms: u32,
+    /// Exit effect (hides the shape) instead of an entrance
+    pub exit: bool,
+}
+
+impl Animation {
+    /// Entrance effect, started on click, lasting 500ms
+    pub fn new(effect: AnimationEffect) -> Self {
+        Self {
+            effect,
+            trigger: AnimationTrigger::OnClick,
+            delay_ms: 0,
+            duration_ms: 500,
+            exit: false,
+        }
+    }
+
+    /// Exit effect, started on click, lasting 500ms
+    pub fn exit(effect: AnimationEffect) -> Self {
+        Self { exit: true, ..Self::new(effect) }
+    }
+
+    pub fn trigger(mut self, trigger: AnimationTrigger) -> Self {
+        self.trigger = trigger;
+        self
+    }
+
+    pub fn delay_ms(mut self, ms: u32) -> Self {
+        self.delay_ms = ms;
+        self
+    }
+
+    pub fn duration_ms(mut self, ms: u32) -> Self {
+        self.duration_ms = ms;
+        self
+    }
+
+    /// Time from the start of its container until the effect finishes
+    fn end_ms(&self) -> u32 {
+        let duration = match self.effect {
+            AnimationEffect::Appear => 1,
+            _ => self.duration_ms.max(1),
+        };
+        self.delay_ms + duration
+    }
+
+    fn group_id(&self) -> u32 {
+        u32::from(self.exit)
+    }
+}
+
+/// An animation bound to a shape (or one paragraph of its text)
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SlideAnimation {
+    /// `cNvPr` id of the target shape
+    pub shape_id: u32,
+    /// Animate only this paragraph (0-based) of the shape's text
+    pub paragraph: Option<usize>,
+    pub animation: Animation,
+}
+
+/// Generate `<p:timing>` for the slide's animations, in playback order.
+///
+/// `text_shape_ids` lists the `<p:sp>` ids that may get `<p:bldP>` build
+/// entries; pictures and graphic frames don't take one.
+pub fn timing_xml(animations: &[SlideAnimation], text_shape_ids: &[u32]) -> String {
+    timing_xml_with_media(animations, text_shape_ids, &[])
+}
+
+/// Generate `<p:timing>` for the slide's animations and embedded media.
+///
+/// Autoplaying media starts with the slide, ahead of the animations; clicking any
+/// media shape toggles play and pause.
+pub fn timing_xml_with_media(animations: &[SlideAnimation], text_shape_ids: &[u32], media: &[MediaPlayback]) -> String {
+    if animations.is_empty() && media.is_empty() {
+        return String::new();
+    }
+
+    // Click groups -> sequential `par`s -> effects that run together
+    let mut groups: Vec<Vec<Vec<&SlideAnimation>>> = Vec::new();
+    for anim in animations {
+        match (anim.animation.trigger, groups.last_mut()) {
+            (AnimationTrigger::WithPrevious, Some(group)) => {
+                group.last_mut().expect("groups are never empty").push(anim)
+            }
+            (AnimationTrigger::AfterPrevious, Some(group)) => group.push(vec![anim]),
+            _ => groups.push(vec![vec![anim]]),
+        }
+    }
+
+    let mut next_id = 3u32;
+    let mut seq = String::new();
+    let autoplay: Vec<&MediaPlayback> = media.iter().filter(|m| m.auto_play).collect();
+    if !autoplay.is_empty() {
+        seq.push_str(&format!(
+            r#"<p:par><p:cTn id="{}" fill="hold"><p:stCondLst><p:cond delay="indefinite"/><p:cond evt="onBegin" delay="0"><p:tn val="2"/></p:cond></p:stCondLst><p:childTnLst><p:par><p:cTn id="{}" fill="hold"><p:stCondLst><p:cond delay="0"/></p:stCondLst><p:childTnLst>"#,
+            next_id,
+            next_id + 1
+        ));
+        next_id += 2;
+        for (i, m) in autoplay.iter().enumerate() {
+            let node_type = if i == 0 { "afterEffect" } else { "withEffect" };
+            seq.push_str(&media_call_xml(m, 1, node_type, "playFrom(0.0)", &mut next_id));
+        }
+        seq.push_str("</p:childTnLst></p:cTn></p:par></p:childTnLst></p:cTn></p:par>");
+    }
+    for (i, group) in groups.iter().enumerate() {
+        // A leading with/after-previous effect starts as soon as the slide shows
+        let auto_start = i == 0 && group[0][0].animation.trigger != AnimationTrigger::OnClick;
+        let start_conds = if auto_start {
+            r#"<p:cond delay="indefinite"/><p:cond evt="onBegin" delay="0"><p:tn val="2"/></p:cond>"#
+        } else {
+            r#"<p:cond delay="indefinite"/>"#
+        };
+        seq.push_str(&format!(
+            r#"<p:par><p:cTn id="{}" fill="hold"><p:stCondLst>{}</p:stCondLst><p:childTnLst>"#,
+            next_id, start_conds
+        ));
+        next_id += 1;
+
+        let mut offset = 0u32;
+        for step in group {
+            seq.push_str(&format!(
+                r#"<p:par><p:cTn id="{}" fill="hold"><p:stCondLst><p:cond delay="{}"/></p:stCondLst><p:childTnLst>"#,
+                next_id, offset
+            ));
+            next_id += 1;
+            for anim in step {
+                seq.push_str(&effect_xml(anim, &mut next_id));
+            }
+            seq.push_str("</p:childTnLst></p:cTn></p:par>");
+            offset += step.iter().map(|a| a.animation.end_ms()).max().unwrap_or(0);
+        }
+
+        seq.push_str("</p:childTnLst></p:cTn></p:par>");
+    }
+
+    let mut builds: Vec<(u32, u32, bool)> = Vec::new();
+    for anim in animations {
+        let build = (anim.shape_id, anim.animation.group_id(), anim.paragraph.is_some());
+        if text_shape_ids.contains(&anim.shape_id)
+            && !builds.iter().any(|b| b.0 == build.0 && b.1 == build.1)
+        {
+            builds.push(build);
+        }
+    }
+    let bld_lst = if builds.is_empty() {
+        String::new()
+    } else {
+        let entries: String = builds
+            .iter()
+            .map(|(spid, grp, by_paragraph)| {
+                let build = if *by_paragraph { r#" build="p""# } else { r#" animBg="1""# };
+                format!(r#"<p:bldP spid="{}" grpId="{}"{}/>"#, spid, grp, build)
+            })
+            .collect();
+        format!("<p:bldLst>{}</p:bldLst>", entries)
+    };
+
+    // An empty main sequence is not allowed; click-to-play media alone doesn't need one
+    let main_seq = if seq.is_empty() {
+        String::new()
+    } else {
+        format!(
+            r#"<p:seq concurrent="1" nextAc="seek"><p:cTn id="2" dur="indefinite" nodeType="mainSeq"><p:childTnLst>{}</p:childTnLst></p:cTn><p:prevCondLst><p:cond evt="onPrev" delay="0"><p:tgtEl><p:sldTgt/></p:tgtEl></p:cond></p:prevCondLst><p:nextCondLst><p:cond evt="onNext" delay="0"><p:tgtEl><p:sldTgt/></p:tgtEl></p:cond></p:nextCondLst></p:seq>"#,
+            seq
+        )
+    };
+    let mut media_nodes = String::new();
+    for m in media {
+        media_nodes.push_str(&media_node_xml(m, &mut next_id));
+    }
+    for m in media {
+        media_nodes.push_str(&media_click_seq_xml(m, &mut next_id));
+    }
+
+    format!(
+        r#"<p:timing><p:tnLst><p:par><p:cTn id="1" dur="indefinite" restart="never" nodeType="tmRoot"><p:childTnLst>{}{}</p:childTnLst></p:cTn></p:par></p:tnLst>{}</p:timing>"#,
+        main_seq, media_nodes, bld_lst
+    )
+}
+
+/// `<p:video>`/`<p:audio>` node holding the playback settings of a media shape
+fn media_node_xml(m: &MediaPlayback, next_id: &mut u32) -> String {
+    let mut attrs = String::new();
+    if m.volume < 100 {
+        attrs.push_str(&format!(r#" vol="{}""#, m.volume * 1000));
+    }
+    if m.muted {
+        attrs.push_str(r#" mute="1""#);
+    }
+    // Slides to keep playing across; playback ends only when the show stops audio
+    let mut end = "";
+    if m.play_across_slides {
+        attrs.push_str(r#" numSld="999""#);
+        end = r#"<p:endCondLst><p:cond evt="onStopAudio" delay="0"><p:tgtEl><p:sldTgt/></p:tgtEl></p:cond></p:endCondLst>"#;
+    }
+    if m.hide_when_stopped {
+        attrs.push_str(r#" showWhenStopped="0""#);
+    }
+    let repeat = if m.loop_playback { r#" repeatCount="indefinite""# } else { "" };
+    let element = if m.video { "video" } else { "audio" };
+    let xml = format!(
+        r#"<p:{element}><p:cMediaNode{attrs}><p:cTn id="{}"{repeat} fill="hold" display="0"><p:stCondLst><p:cond delay="indefinite"/></p:stCondLst>{end}</p:cTn><p:tgtEl><p:spTgt spid="{}"/></p:tgtEl></p:cMediaNode></p:{element}>"#,
+        next_id, m.shape_id
+    );
+    *next_id += 1;
+    xml
+}
+
+/// Interactive sequence that toggles playback when the media shape is clicked
+fn media_click_seq_xml(m: &MediaPlayback, next_id: &mut u32) -> String {
+    let trigger = format!(
+        r#"<p:cond evt="onClick" delay="0"><p:tgtEl><p:spTgt spid="{}"/></p:tgtEl></p:cond>"#,
+        m.shape_id
+    );
+    let mut xml = format!(
+        r#"<p:seq concurrent="1" nextAc="seek"><p:cTn id="{}" restart="whenNotActive" fill="hold" evtFilter="cancelBubble" nodeType="interactiveSeq"><p:stCondLst>{}</p:stCondLst><p:endSync evt="end" delay="0"><p:rtn val="all"/></p:endSync><p:childTnLst><p:par><p:cTn id="{}" fill="hold"><p:stCondLst><p:cond delay="0"/></p:stCondLst><p:childTnLst><p:par><p:cTn id="{}" fill="hold"><p:stCondLst><p:cond delay="0"/></p:stCondLst><p:childTnLst>"#,
+        next_id,
+        trigger,
+        *next_id + 1,
+        *next_id + 2
+    );
+    *next_id += 3;
+    xml.push_str(&media_call_xml(m, 2, "clickEffect", "togglePause", next_id));
+    xml.push_str(&format!(
+        "</p:childTnLst></p:cTn></p:par></p:childTnLst></p:cTn></p:par></p:childTnLst></p:cTn><p:nextCondLst>{}</p:nextCondLst></p:seq>",
+        trigger
+    ));
+    xml
+}
+
+/// Media call effect (`presetClass="mediacall"`) running `cmd` on the media shape
+fn media_call_xml(m: &MediaPlayback, preset_id: u32, node_type: &str, cmd: &str, next_id: &mut u32) -> String {
+    let xml = format!(
+        r#"<p:par><p:cTn id="{}" presetID="{}" presetClass="mediacall" presetSubtype="0" fill="hold" nodeType="{}"><p:stCondLst><p:cond delay="0"/></p:stCondLst><p:childTnLst><p:cmd type="call" cmd="{}"><p:cBhvr><p:cTn id="{}" dur="1" fill="hold"/><p:tgtEl><p:spTgt spid="{}"/></p:tgtEl></p:cBhvr></p:cmd></p:childTnLst></p:cTn></p:par>"#,
+        next_id,
+        preset_id,
+        node_type,
+        cmd,
+        *next_id + 1,
+        m.shape_id
+    );
+    *next_id += 2;
+    xml
+}
+
+/// One effect's `par`: the preset container plus its behaviors
+fn effect_xml(anim: &SlideAnimation, next_id: &mut u32) -> String {
+    let a = &anim.animation;
+    let target = match anim.paragraph {
+        Some(p) => format!(
+            r#"<p:tgtEl><p:spTgt spid="{}"><p:txEl><p:pRg st="{p}" end="{p}"/></p:txEl></p:spTgt></p:tgtEl>"#,
+            anim.shape_id
+        ),
+        None => format!(r#"<p:tgtEl><p:spTgt spid="{}"/></p:tgtEl>"#, anim.shape_id),
+    };
+    let (class, transition) = if a.exit { ("exit", "out") } else { ("entr", "in") };
+
+    let mut xml = format!(
+        r#"<p:par><p:cTn id="{}" presetID="{}" presetClass="{}" presetSubtype="{}" fill="hold" grpId="{}" nodeType="{}"><p:stCondLst><p:cond delay="{}"/></p:stCondLst><p:childTnLst>"#,
+        next_id,
+        a.effect.preset_id(),
+        class,
+        a.effect.preset_subtype(),
+        a.group_id(),
+        a.trigger.node_type(),
+        a.delay_ms
+    );
+    *next_id += 1;
+
+    let dur = a.duration_ms.max(1);
+    // Entrances show the shape first; exits hide it once the effect has played
+    let set_delay = match (a.exit, a.effect) {
+        (true, AnimationEffect::Appear) | (false, _) => 0,
+        (true, _) => dur - 1,
+    };
+    let visibility = format!(
+        r#"<p:set><p:cBhvr><p:cTn id="{}" dur="1" fill="hold"><p:stCondLst><p:cond delay="{}"/></p:stCondLst></p:cTn>{}<p:attrNameLst><p:attrName>style.visibility</p:attrName></p:attrNameLst></p:cBhvr><p:to><p:strVal val="{}"/></p:to></p:set>"#,
+        next_id,
+        set_delay,
+        target,
+        if a.exit { "hidden" } else { "visible" }
+    );
+    *next_id += 1;
+    if !a.exit {
+        xml.push_str(&visibility);
+    }
+
+    match a.effect {
+        AnimationEffect::Appear => {}
+        AnimationEffect::FadeIn => {
+            xml.push_str(&anim_effect(transition, "fade", dur, &target, next_id));
+        }
+        AnimationEffect::Wipe => {
+            let filter = if a.exit { "wipe(down)" } else { "wipe(up)" };
+            xml.push_str(&anim_effect(transition, filter, dur, &target, next_id));
+        }
+        AnimationEffect::FlyIn => {
+            let (from, to) = if a.exit { ("#ppt_y", "1+#ppt_h/2") } else { ("1+#ppt_h/2", "#ppt_y") };
+            xml.push_str(&anim_property("ppt_x", "#ppt_x", "#ppt_x", dur, &target, next_id));
+            xml.push_str(&anim_property("ppt_y", from, to, dur, &target, next_id));
+        }
+        AnimationEffect::Zoom => {
+            for (attr, full) in [("ppt_w", "#ppt_w"), ("ppt_h", "#ppt_h")] {
+                let (from, to) = if a.exit { (full, "0") } else { ("0", full) };
+                xml.push_str(&anim_property(attr, from, to, dur, &target, next_id));
+            }
+            xml.push_str(&anim_effect(transition, "fade", dur, &target, next_id));
+        }
+    }
+
+    if a.exit {
+        xml.push_str(&visibility);
+    }
+    xml.push_str("</p:childTnLst></p:cTn></p:par>");
+    xml
+}
+
+fn anim_effect(transition: &str, filter: &str, dur: u32, target: &str, next_id: &mut u32) -> String {
+    let xml = format!(
+        r#"<p:animEffect transition="{}" filter="{}"><p:cBhvr><p:cTn id="{}" dur="{}"/>{}</p:cBhvr></p:animEffect>"#,
+        transition, filter, next_id, dur, target
+    );
+    *next_id += 1;
+    xml
+}
+
+fn anim_property(attr: &str, from: &str, to: &str, dur: u32, target: &str, next_id: &mut u32) -> String {
+    let xml = format!(
+        r#"<p:anim calcmode="lin" valueType="num"><p:cBhvr additive="base"><p:cTn id="{}" dur="{}" fill="hold"/>{}<p:attrNameLst><p:attrName>{}</p:attrName></p:attrNameLst></p:cBhvr><p:tavLst><p:tav tm="0"><p:val><p:strVal val="{}"/></p:val></p:tav><p:tav tm="100000"><p:val><p:strVal val="{}"/></p:val></p:tav></p:tavLst></p:anim>"#,
+        next_id, dur, target, attr, from, to
+    );
+    *next_id += 1;
+    xml
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn anim(shape_id: u32, paragraph: Option<usize>, animation: Animation) -> SlideAnimation {
+        SlideAnimation { shape_id, paragraph, animation }
+    }
+
+    #[test]
+    fn test_empty_timing() {
+        assert_eq!(timing_xml(&[], &[]), "");
+    }
+
+    fn video(shape_id: u32, auto_play: bool) -> MediaPlayback {
+        MediaPlayback {
+            shape_id,
+            video: true,
+            auto_play,
+            loop_playback: false,
+            play_across_slides: false,
+            muted: false,
+            hide_when_stopped: false,
+            volume: 100,
+        }
+    }
+
+    #[test]
+    fn test_click_to_play_video_timing() {
+        let xml = timing_xml_with_media(&[], &[], &[video(210, false)]);
+        assert!(!xml.contains("mainSeq"));
+        assert!(xml.contains(r#"<p:video><p:cMediaNode><p:cTn id="3" fill="hold" display="0">"#));
+        assert!(xml.contains(r#"nodeType="interactiveSeq"><p:stCondLst><p:cond evt="onClick" delay="0"><p:tgtEl><p:spTgt spid="210"/>"#));
+        assert!(xml.contains(r#"<p:cmd type="call" cmd="togglePause">"#));
+    }
+
+    #[test]
+    fn test_background_audio_timing() {
+        let audio = MediaPlayback {
+            video: false,
+            loop_playback: true,
+            play_across_slides: true,
+            hide_when_stopped: true,
+            ..video(4, true)
+        };
+        let xml = timing_xml_with_media(&[], &[], &[audio]);
+        assert!(xml.contains(r#"cmd="playFrom(0.0)""#));
+        assert!(xml.contains(r#"<p:audio><p:cMediaNode numSld="999" showWhenStopped="0"><p:cTn id="#));
+        assert!(xml.contains(r#"<p:endCondLst><p:cond evt="onStopAudio" delay="0"><p:tgtEl><p:sldTgt/></p:tgtEl></p:cond></p:endCondLst></p:cTn>"#));
+    }
+
+    #[test]
+    fn test_autoplay_looping_video_timing() {
+        let media = MediaPlayback { loop_playback: true, volume: 50, ..video(210, true) };
+        let fade = Animation::new(AnimationEffect::FadeIn);
+        let xml = timing_xml_with_media(&[anim(3, None, fade)], &[3], &[media]);
+        let main_seq = &xml[xml.find("mainSeq").unwrap()..xml.find("<p:video>").unwrap()];
+        assert!(main_seq.contains(r#"presetClass="mediacall" presetSubtype="0" fill="hold" nodeType="afterEffect""#));
+        assert!(main_seq.contains(r#"cmd="playFrom(0.0)""#));
+        assert!(main_seq.contains(r#"presetClass="entr""#));
+        let node = &xml[xml.find("<p:video>").unwrap()..xml.find("</p:video>").unwrap()];
+        assert!(node.starts_with(r#"<p:video><p:cMediaNode vol="50000"><p:cTn id="#));
+        assert!(node.contains(r#" repeatCount="indefinite" fill="hold" display="0">"#));
+    }
+
+    #[test]
+    fn test_click_fade_ins_per_paragraph() {
+        let fade = Animation::new(AnimationEffect::FadeIn);
+        let xml = timing_xml(&[anim(3, Some(0), fade), anim(3, Some(1), fade)], &[3]);
+        assert_eq!(xml.matches(r#"<p:cond delay="indefinite"/></p:stCondLst>"#).count(), 2);
+        assert_eq!(xml.matches(r#"presetID="10" presetClass="entr" presetSubtype="0" fill="hold" grpId="0" nodeType="clickEffect""#).count(), 2);
+        assert!(xml.contains(r#"<p:spTgt spid="3"><p:txEl><p:pRg st="1" end="1"/></p:txEl></p:spTgt>"#));
+        assert!(xml.contains(r#"<p:animEffect transition="in" filter="fade">"#));
+        assert!(xml.ends_with(r#"<p:bldLst><p:bldP spid="3" grpId="0" build="p"/></p:bldLst></p:timing>"#));
+
+        // cTn ids are unique
+        let ids: Vec<&str> = xml.match_indices(r#"<p:cTn id=""#)
+            .map(|(i, m)| &xml[i + m.len()..i + m.len() + xml[i + m.len()..].find('"').unwrap()])
+            .collect();
+        let mut unique = ids.clone();
+        unique.sort();
+        unique.dedup();
+        assert_eq!(ids.len(), unique.len());
+    }
+
+    #[test]
+    fn test_with_and_after_previous() {
+        let xml = timing_xml(&[
+            anim(10, None, Animation::new(AnimationEffect::FlyIn).trigger(AnimationTrigger::AfterPrevious)),
+            anim(11, None, Animation::new(AnimationEffect::Zoom).trigger(AnimationTrigger::WithPrevious)),
+            anim(12, None, Animation::new(AnimationEffect::Appear).trigger(AnimationTrigger::AfterPrevious).delay_ms(250)),
+        ], &[10, 11]);
+        // Leading after-previous effect starts with the slide, in a single click group
+        assert!(xml.contains(r#"<p:cond evt="onBegin" delay="0"><p:tn val="2"/></p:cond>"#));
+        assert_eq!(xml.matches(r#"delay="indefinite"/>"#).count(), 1);
+        // The third effect's par starts after the 500ms fly-in
+        assert!(xml.contains(r#"<p:cond delay="500"/></p:stCondLst><p:childTnLst><p:par><p:cTn id="15" presetID="1""#));
+        assert!(xml.contains(r#"<p:attrName>ppt_y</p:attrName></p:attrNameLst></p:cBhvr><p:tavLst><p:tav tm="0"><p:val><p:strVal val="1+#ppt_h/2"/>"#));
+        assert!(xml.contains(r#"<p:bldP spid="10" grpId="0" animBg="1"/><p:bldP spid="11" grpId="0" animBg="1"/></p:bldLst>"#));
+    }
+
+    #[test]
+    fn test_exit_hides_after_effect() {
+        let xml = timing_xml(&[anim(10, None, Animation::exit(AnimationEffect::FadeIn).duration_ms(800))], &[10]);
+        assert!(xml.contains(r#"presetClass="exit""#));
+        assert!(xml.contains(r#"grpId="1" nodeType="clickEffect""#));
+        let effect = xml.find(r#"<p:animEffect transition="out" filter="fade">"#).unwrap();
+        let hide = xml.find(r#"<p:cond delay="799"/></p:stCondLst></p:cTn><p:tgtEl><p:spTgt spid="10"/></p:tgtEl><p:attrNameLst><p:attrName>style.visibility</p:attrName></p:attrNameLst></p:cBhvr><p:to><p:strVal val="hidden"/>"#).unwrap();
+        assert!(effect < hide);
+    }
+}
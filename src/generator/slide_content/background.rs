@@ -0,0 +1,79 @@
+//! Per-slide background fills
+//!
+//! A slide background replaces the master's `bg1` reference with its own
+//! `<p:bg><p:bgPr>` fill: a solid color, a gradient, or a stretched picture.
+
+use crate::elements::{color_value_xml, RgbInput};
+use crate::generator::gradients::{generate_gradient_fill_xml, GradientFill};
+use crate::generator::images::Image;
+
+/// Background fill for a single slide
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SlideBackground {
+    /// Solid RGB color
+    Color(String),
+    /// Gradient fill
+    Gradient(GradientFill),
+    /// Picture stretched to the slide; stored as a media part
+    Image(Box<Image>),
+}
+
+impl SlideBackground {
+    /// Solid background from a hex color
+    pub fn color(hex: impl Into<RgbInput>) -> Self {
+        SlideBackground::Color(hex.into().into_value())
+    }
+
+    /// The background picture, if any
+    pub fn image(&self) -> Option<&Image> {
+        match self {
+            SlideBackground::Image(image) => Some(image.as_ref()),
+            _ => None,
+        }
+    }
+
+    /// Generate `<p:bg>`; `image_rel_id` is the picture's relationship for `Image` backgrounds
+    pub fn to_xml(&self, image_rel_id: Option<&str>) -> String {
+        let fill = match self {
+            SlideBackground::Color(color) => {
+                format!("<a:solidFill>{}</a:solidFill>", color_value_xml(color, ""))
+            }
+            SlideBackground::Gradient(gradient) => generate_gradient_fill_xml(gradient),
+            SlideBackground::Image(_) => match image_rel_id {
+                Some(rel_id) => format!(
+                    r#"<a:blipFill dpi="0" rotWithShape="1"><a:blip r:embed="{}"/><a:srcRect/><a:stretch><a:fillRect/></a:stretch></a:blipFill>"#,
+                    rel_id
+                ),
+                // Without a media part there is nothing to show; keep the theme background
+                None => return r#"<p:bg><p:bgRef idx="1001"><a:schemeClr val="bg1"/></p:bgRef></p:bg>"#.to_string(),
+            },
+        };
+        format!("<p:bg><p:bgPr>{}<a:effectLst/></p:bgPr></p:bg>", fill)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_color_background() {
+        let xml = SlideBackground::color("#1f1f1f").to_xml(None);
+        assert_eq!(
+            xml,
+            r#"<p:bg><p:bgPr><a:solidFill><a:srgbClr val="1F1F1F"/></a:solidFill><a:effectLst/></p:bgPr></p:bg>"#
+        );
+    }
+
+    #[test]
+    fn test_gradient_and_image_backgrounds() {
+        let xml = SlideBackground::Gradient(GradientFill::two_color("000000", "333333")).to_xml(None);
+        assert!(xml.starts_with("<p:bg><p:bgPr><a:gradFill"));
+
+        let image = Image::from_bytes(vec![1, 2, 3], 100, 100, "PNG");
+        let xml = SlideBackground::Image(Box::new(image.clone())).to_xml(Some("rId4"));
+        assert!(xml.contains(r#"<a:blip r:embed="rId4"/>"#));
+        assert!(SlideBackground::Image(Box::new(image)).to_xml(None).contains("<p:bgRef"));
+    }
+}
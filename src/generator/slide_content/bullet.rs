@@ -1,9 +1,12 @@
 //! Bullet point types and formatting
 
+use crate::elements::RgbInput;
 use crate::core::ToXml;
+use crate::generator::text::FormattedText;
 
 /// Bullet style for lists
 #[derive(Clone, Debug, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum BulletStyle {
     /// Standard bullet point (•)
     #[default]
@@ -39,6 +42,37 @@ impl BulletStyle {
         }
     }
     
+    /// Auto-numbering scheme (`arabicPeriod`, `alphaLcPeriod`, ...) for numbered styles
+    pub fn auto_num_type(&self) -> Option<&'static str> {
+        match self {
+            BulletStyle::Number => Some("arabicPeriod"),
+            BulletStyle::LetterLower => Some("alphaLcPeriod"),
+            BulletStyle::LetterUpper => Some("alphaUcPeriod"),
+            BulletStyle::RomanLower => Some("romanLcPeriod"),
+            BulletStyle::RomanUpper => Some("romanUcPeriod"),
+            _ => None,
+        }
+    }
+
+    /// Bullet XML with a starting number for auto-numbered styles
+    pub fn to_xml_with_start(&self, start_at: Option<u32>) -> String {
+        match (self.auto_num_type(), start_at) {
+            (Some(num_type), Some(start)) if start > 1 => {
+                format!(r#"<a:buAutoNum type="{}" startAt="{}"/>"#, num_type, start)
+            }
+            _ => self.to_xml(),
+        }
+    }
+
+    /// Outline numbering for an indent level: 1. / a. / i. repeating
+    pub fn outline(level: u32) -> Self {
+        match level % 3 {
+            0 => BulletStyle::Number,
+            1 => BulletStyle::LetterLower,
+            _ => BulletStyle::RomanLower,
+        }
+    }
+
     /// Get the OOXML indent level XML
     pub fn indent_xml(&self, level: u32) -> String {
         let indent = 457200 + (level * 457200); // 0.5 inch base + 0.5 inch per level
@@ -55,6 +89,7 @@ impl ToXml for BulletStyle {
 
 /// Text formatting for bullet points
 #[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BulletTextFormat {
     pub bold: bool,
     pub italic: bool,
@@ -105,13 +140,13 @@ impl BulletTextFormat {
         self
     }
     
-    pub fn color(mut self, hex: &str) -> Self {
-        self.color = Some(hex.trim_start_matches('#').to_uppercase());
+    pub fn color(mut self, hex: impl Into<RgbInput>) -> Self {
+        self.color = Some(hex.into().into_value());
         self
     }
     
-    pub fn highlight(mut self, hex: &str) -> Self {
-        self.highlight = Some(hex.trim_start_matches('#').to_uppercase());
+    pub fn highlight(mut self, hex: impl Into<RgbInput>) -> Self {
+        self.highlight = Some(hex.into().into_value());
         self
     }
     
@@ -128,11 +163,17 @@ impl BulletTextFormat {
 
 /// A bullet point with optional style and formatting
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BulletPoint {
     pub text: String,
     pub level: u32,
     pub style: BulletStyle,
     pub format: Option<BulletTextFormat>,
+    /// First number of an auto-numbered list (defaults to 1)
+    pub start_at: Option<u32>,
+    /// Runs with their own formatting; when set, `text` is their concatenation
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub runs: Vec<FormattedText>,
 }
 
 impl BulletPoint {
@@ -142,8 +183,16 @@ impl BulletPoint {
             level: 0,
             style: BulletStyle::Bullet,
             format: None,
+            start_at: None,
+            runs: Vec::new(),
         }
     }
+
+    /// Bullet made of runs with mixed formatting, e.g. "Status: " then a bold green "Done"
+    pub fn formatted(runs: Vec<FormattedText>) -> Self {
+        let text: String = runs.iter().map(|run| run.text.as_str()).collect();
+        BulletPoint { runs, ..BulletPoint::new(&text) }
+    }
     
     pub fn with_level(mut self, level: u32) -> Self {
         self.level = level;
@@ -160,6 +209,12 @@ impl BulletPoint {
         self
     }
     
+    /// Start numbering at `start` (numbered, lettered, and roman styles only)
+    pub fn start_at(mut self, start: u32) -> Self {
+        self.start_at = Some(start.max(1));
+        self
+    }
+    
     pub fn bold(mut self) -> Self {
         self.format = Some(self.format.unwrap_or_default().bold());
         self
@@ -170,6 +225,11 @@ impl BulletPoint {
         self
     }
     
+    pub fn underline(mut self) -> Self {
+        self.format = Some(self.format.unwrap_or_default().underline());
+        self
+    }
+    
     pub fn strikethrough(mut self) -> Self {
         self.format = Some(self.format.unwrap_or_default().strikethrough());
         self
@@ -185,12 +245,12 @@ impl BulletPoint {
         self
     }
     
-    pub fn highlight(mut self, color: &str) -> Self {
+    pub fn highlight(mut self, color: impl Into<RgbInput>) -> Self {
         self.format = Some(self.format.unwrap_or_default().highlight(color));
         self
     }
     
-    pub fn color(mut self, hex: &str) -> Self {
+    pub fn color(mut self, hex: impl Into<RgbInput>) -> Self {
         self.format = Some(self.format.unwrap_or_default().color(hex));
         self
     }
@@ -199,5 +259,10 @@ impl BulletPoint {
         self.format = Some(self.format.unwrap_or_default().font_size(size));
         self
     }
+    
+    pub fn font_family(mut self, family: &str) -> Self {
+        self.format = Some(self.format.unwrap_or_default().font_family(family));
+        self
+    }
 }
 
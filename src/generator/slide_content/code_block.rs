@@ -1,7 +1,10 @@
 //! Code block types for syntax highlighting
 
+use super::code_highlight::CodeTheme;
+
 /// A code block with syntax highlighting info
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CodeBlock {
     pub code: String,
     pub language: String,
@@ -9,6 +12,9 @@ pub struct CodeBlock {
     pub y: i64,
     pub width: i64,
     pub height: i64,
+    /// Background and token colors
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub theme: CodeTheme,
 }
 
 impl CodeBlock {
@@ -20,8 +26,21 @@ impl CodeBlock {
             y: 1800000,
             width: 8000000,
             height: 4000000,
+            theme: CodeTheme::default(),
         }
     }
+
+    /// Set the language used for highlighting (unknown languages render as plain text)
+    pub fn language(mut self, language: &str) -> Self {
+        self.language = language.to_string();
+        self
+    }
+
+    /// Set the color theme
+    pub fn theme(mut self, theme: CodeTheme) -> Self {
+        self.theme = theme;
+        self
+    }
     
     pub fn position(mut self, x: i64, y: i64) -> Self {
         self.x = x;
@@ -0,0 +1,357 @@
+//! Built-in syntax highlighting for code blocks
+//!
+//! A small tokenizer splits each line into keywords, strings, comments, and
+//! numbers for common languages; each token becomes its own colored run.
+//! Languages it doesn't know render as plain monospace text.
+
+use crate::core::escape_xml;
+
+/// Colors for a code block and its token kinds (RGB hex)
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CodeTheme {
+    pub background: String,
+    pub border: String,
+    pub plain: String,
+    pub keyword: String,
+    pub string: String,
+    pub comment: String,
+    pub number: String,
+}
+
+impl CodeTheme {
+    /// Solarized Dark (the default)
+    pub fn solarized_dark() -> Self {
+        Self::from_colors(["002B36", "073642", "839496", "859900", "2AA198", "586E75", "D33682"])
+    }
+
+    /// Solarized Light
+    pub fn solarized_light() -> Self {
+        Self::from_colors(["FDF6E3", "EEE8D5", "657B83", "859900", "2AA198", "93A1A1", "D33682"])
+    }
+
+    /// Monokai
+    pub fn monokai() -> Self {
+        Self::from_colors(["272822", "3E3D32", "F8F8F2", "F92672", "E6DB74", "75715E", "AE81FF"])
+    }
+
+    /// GitHub light
+    pub fn github_light() -> Self {
+        Self::from_colors(["F6F8FA", "D0D7DE", "24292F", "CF222E", "0A3069", "6E7781", "0550AE"])
+    }
+
+    fn from_colors([background, border, plain, keyword, string, comment, number]: [&str; 7]) -> Self {
+        Self {
+            background: background.into(),
+            border: border.into(),
+            plain: plain.into(),
+            keyword: keyword.into(),
+            string: string.into(),
+            comment: comment.into(),
+            number: number.into(),
+        }
+    }
+
+    fn color(&self, kind: TokenKind) -> &str {
+        match kind {
+            TokenKind::Plain => &self.plain,
+            TokenKind::Keyword => &self.keyword,
+            TokenKind::String => &self.string,
+            TokenKind::Comment => &self.comment,
+            TokenKind::Number => &self.number,
+        }
+    }
+}
+
+impl Default for CodeTheme {
+    fn default() -> Self {
+        Self::solarized_dark()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum TokenKind {
+    Plain,
+    Keyword,
+    String,
+    Comment,
+    Number,
+}
+
+/// Lexical rules for one language
+struct Syntax {
+    keywords: &'static [&'static str],
+    line_comments: &'static [&'static str],
+    block_comment: Option<(&'static str, &'static str)>,
+    quotes: &'static [char],
+    /// Keywords match regardless of case (keyword lists are lowercase)
+    ignore_case: bool,
+}
+
+const C_FAMILY_QUOTES: &[char] = &['"', '\''];
+
+fn syntax_for(language: &str) -> Option<Syntax> {
+    let syntax = match language.to_lowercase().as_str() {
+        "rust" | "rs" => Syntax {
+            keywords: &[
+                "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum", "extern", "false",
+                "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub", "ref", "return",
+                "self", "Self", "static", "struct", "super", "trait", "true", "type", "unsafe", "use", "where", "while",
+            ],
+            line_comments: &["//"],
+            block_comment: Some(("/*", "*/")),
+            quotes: &['"'],
+            ignore_case: false,
+        },
+        "python" | "py" => Syntax {
+            keywords: &[
+                "and", "as", "assert", "async", "await", "break", "class", "continue", "def", "del", "elif", "else",
+                "except", "False", "finally", "for", "from", "global", "if", "import", "in", "is", "lambda", "None",
+                "nonlocal", "not", "or", "pass", "raise", "return", "True", "try", "while", "with", "yield",
+            ],
+            line_comments: &["#"],
+            block_comment: None,
+            quotes: C_FAMILY_QUOTES,
+            ignore_case: false,
+        },
+        "javascript" | "js" | "typescript" | "ts" => Syntax {
+            keywords: &[
+                "async", "await", "break", "case", "catch", "class", "const", "continue", "default", "delete", "do",
+                "else", "export", "extends", "false", "finally", "for", "from", "function", "if", "import", "in",
+                "instanceof", "interface", "let", "new", "null", "return", "super", "switch", "this", "throw", "true",
+                "try", "type", "typeof", "undefined", "var", "void", "while", "yield",
+            ],
+            line_comments: &["//"],
+            block_comment: Some(("/*", "*/")),
+            quotes: &['"', '\'', '`'],
+            ignore_case: false,
+        },
+        "java" | "c" | "cpp" | "c++" | "csharp" | "c#" | "cs" | "kotlin" | "kt" | "swift" => Syntax {
+            keywords: &[
+                "abstract", "auto", "bool", "break", "case", "catch", "char", "class", "const", "continue", "default",
+                "do", "double", "else", "enum", "extends", "false", "final", "float", "for", "fun", "func", "if",
+                "implements", "import", "include", "int", "interface", "let", "long", "namespace", "new", "null",
+                "nullptr", "override", "package", "private", "protected", "public", "return", "short", "static",
+                "struct", "switch", "template", "this", "throw", "true", "try", "typedef", "unsigned", "using", "val",
+                "var", "virtual", "void", "while",
+            ],
+            line_comments: &["//"],
+            block_comment: Some(("/*", "*/")),
+            quotes: C_FAMILY_QUOTES,
+            ignore_case: false,
+        },
+        "go" => Syntax {
+            keywords: &[
+                "break", "case", "chan", "const", "continue", "default", "defer", "else", "false", "for", "func", "go",
+                "goto", "if", "import", "interface", "map", "nil", "package", "range", "return", "select", "struct",
+                "switch", "true", "type", "var",
+            ],
+            line_comments: &["//"],
+            block_comment: Some(("/*", "*/")),
+            quotes: &['"', '\'', '`'],
+            ignore_case: false,
+        },
+        "bash" | "sh" | "shell" | "zsh" => Syntax {
+            keywords: &[
+                "case", "do", "done", "echo", "elif", "else", "esac", "exit", "export", "fi", "for", "function", "if",
+                "in", "local", "return", "then", "until", "while",
+            ],
+            line_comments: &["#"],
+            block_comment: None,
+            quotes: C_FAMILY_QUOTES,
+            ignore_case: false,
+        },
+        "sql" => Syntax {
+            keywords: &[
+                "and", "as", "by", "create", "delete", "desc", "from", "group", "having", "insert", "into", "join",
+                "left", "limit", "not", "null", "on", "or", "order", "select", "set", "table", "update", "values",
+                "where",
+            ],
+            line_comments: &["--"],
+            block_comment: Some(("/*", "*/")),
+            quotes: &['\''],
+            ignore_case: true,
+        },
+        "json" => Syntax {
+            keywords: &["true", "false", "null"],
+            line_comments: &[],
+            block_comment: None,
+            quotes: &['"'],
+            ignore_case: false,
+        },
+        "toml" | "yaml" | "yml" => Syntax {
+            keywords: &["true", "false", "null"],
+            line_comments: &["#"],
+            block_comment: None,
+            quotes: C_FAMILY_QUOTES,
+            ignore_case: false,
+        },
+        _ => return None,
+    };
+    Some(syntax)
+}
+
+impl Syntax {
+    fn is_keyword(&self, word: &str) -> bool {
+        self.keywords.contains(&word) || (self.ignore_case && self.keywords.contains(&word.to_lowercase().as_str()))
+    }
+
+    /// Split one line into tokens; `in_block` carries an open block comment across lines
+    fn tokenize<'a>(&self, line: &'a str, in_block: &mut bool) -> Vec<(TokenKind, &'a str)> {
+        let mut spans: Vec<(TokenKind, usize, usize)> = Vec::new();
+        let mut pos = 0;
+
+        while pos < line.len() {
+            let rest = &line[pos..];
+            let ch = rest.chars().next().unwrap_or(' ');
+
+            let (kind, len) = if *in_block {
+                let (_, close) = self.block_comment.unwrap_or(("", ""));
+                match rest.find(close) {
+                    Some(i) => {
+                        *in_block = false;
+                        (TokenKind::Comment, i + close.len())
+                    }
+                    None => (TokenKind::Comment, rest.len()),
+                }
+            } else if self.line_comments.iter().any(|marker| rest.starts_with(marker)) {
+                (TokenKind::Comment, rest.len())
+            } else if let Some((open, _)) = self.block_comment.filter(|(open, _)| rest.starts_with(open)) {
+                *in_block = true;
+                (TokenKind::Comment, open.len())
+            } else if self.quotes.contains(&ch) {
+                (TokenKind::String, string_len(rest, ch))
+            } else if ch.is_ascii_digit() {
+                let len = rest
+                    .find(|c: char| !(c.is_ascii_alphanumeric() || c == '.' || c == '_'))
+                    .unwrap_or(rest.len());
+                (TokenKind::Number, len)
+            } else if ch.is_alphabetic() || ch == '_' {
+                let len = rest.find(|c: char| !(c.is_alphanumeric() || c == '_')).unwrap_or(rest.len());
+                let kind = if self.is_keyword(&rest[..len]) { TokenKind::Keyword } else { TokenKind::Plain };
+                (kind, len)
+            } else {
+                (TokenKind::Plain, ch.len_utf8())
+            };
+
+            // Whitespace takes the preceding color so it doesn't split runs
+            let kind = match spans.last() {
+                Some(&(last, _, _)) if rest[..len].trim().is_empty() => last,
+                _ => kind,
+            };
+            match spans.last_mut() {
+                Some((last, _, end)) if *last == kind => *end = pos + len,
+                _ => spans.push((kind, pos, pos + len)),
+            }
+            pos += len;
+        }
+
+        spans.into_iter().map(|(kind, start, end)| (kind, &line[start..end])).collect()
+    }
+}
+
+/// Byte length of a string literal starting at `rest[0]`, including an unterminated tail
+fn string_len(rest: &str, quote: char) -> usize {
+    let mut escaped = false;
+    for (i, c) in rest.char_indices().skip(1) {
+        match c {
+            '\\' if !escaped => escaped = true,
+            c if c == quote && !escaped => return i + c.len_utf8(),
+            _ => escaped = false,
+        }
+    }
+    rest.len()
+}
+
+fn run_xml(text: &str, color: &str) -> String {
+    // OOXML order: solidFill must come before latin font
+    format!(
+        r#"<a:r><a:rPr lang="en-US" sz="1400" dirty="0"><a:solidFill><a:srgbClr val="{}"/></a:solidFill><a:latin typeface="Consolas"/></a:rPr><a:t>{}</a:t></a:r>"#,
+        color,
+        escape_xml(text)
+    )
+}
+
+/// Whether the built-in tokenizer knows `language`
+pub fn supports_language(language: &str) -> bool {
+    syntax_for(language).is_some()
+}
+
+/// Paragraphs for a code block: one `<a:p>` per line with a colored run per token.
+///
+/// Unknown languages produce a single plain run per line.
+pub fn highlighted_code_xml(code: &str, language: &str, theme: &CodeTheme) -> String {
+    let syntax = syntax_for(language);
+    let mut in_block = false;
+    let mut xml = String::new();
+
+    for line in code.lines() {
+        xml.push_str(r#"<a:p><a:pPr algn="l"/>"#);
+        if line.is_empty() {
+            xml.push_str(&run_xml(" ", &theme.plain));
+        } else {
+            match syntax {
+                Some(ref syntax) => {
+                    for (kind, text) in syntax.tokenize(line, &mut in_block) {
+                        xml.push_str(&run_xml(text, theme.color(kind)));
+                    }
+                }
+                None => xml.push_str(&run_xml(line, &theme.plain)),
+            }
+        }
+        xml.push_str("</a:p>");
+    }
+
+    if xml.is_empty() {
+        xml.push_str(r#"<a:p><a:endParaRPr lang="en-US" dirty="0"/></a:p>"#);
+    }
+    xml
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kinds<'a>(language: &str, line: &'a str) -> Vec<(TokenKind, &'a str)> {
+        syntax_for(language).unwrap().tokenize(line, &mut false)
+    }
+
+    #[test]
+    fn test_rust_tokens() {
+        let tokens = kinds("rust", r#"let n = 42; // "answer""#);
+        assert_eq!(
+            tokens,
+            [
+                (TokenKind::Keyword, "let "),
+                (TokenKind::Plain, "n = "),
+                (TokenKind::Number, "42"),
+                (TokenKind::Plain, "; "),
+                (TokenKind::Comment, r#"// "answer""#),
+            ]
+        );
+        assert_eq!(kinds("rust", r#"println!("a \" b")"#)[1], (TokenKind::String, r#""a \" b""#));
+    }
+
+    #[test]
+    fn test_block_comment_spans_lines() {
+        let xml = highlighted_code_xml("/* start\nstill comment */ int x;", "c", &CodeTheme::monokai());
+        assert_eq!(xml.matches(r#"<a:srgbClr val="75715E"/>"#).count(), 2);
+        assert!(xml.contains(r#"<a:srgbClr val="F92672"/></a:solidFill><a:latin typeface="Consolas"/></a:rPr><a:t>int </a:t>"#));
+    }
+
+    #[test]
+    fn test_sql_keywords_ignore_case() {
+        assert_eq!(kinds("sql", "SELECT")[0].0, TokenKind::Keyword);
+        assert_eq!(kinds("python", "TRUE")[0].0, TokenKind::Plain);
+    }
+
+    #[test]
+    fn test_unknown_language_is_plain() {
+        let theme = CodeTheme::default();
+        let xml = highlighted_code_xml("if x <> 1\n\nend", "cobol", &theme);
+        assert_eq!(xml.matches("<a:p>").count(), 3);
+        assert_eq!(xml.matches("<a:r>").count(), 3);
+        assert!(xml.contains("<a:t>if x &lt;&gt; 1</a:t>"));
+        assert!(!supports_language("cobol"));
+    }
+}
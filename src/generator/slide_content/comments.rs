@@ -12,6 +12,8 @@ pub struct CommentAuthor {
     pub name: String,
     pub initials: String,
     pub color_index: u32,
+    /// Highest comment index used by this author
+    pub last_index: u32,
 }
 
 impl CommentAuthor {
@@ -21,6 +23,7 @@ impl CommentAuthor {
             name: name.to_string(),
             initials: initials.to_string(),
             color_index: id,
+            last_index: 0,
         }
     }
 
@@ -32,10 +35,11 @@ impl CommentAuthor {
     /// Generate XML for `<p:cmAuthor>` element
     pub fn to_xml(&self) -> String {
         format!(
-            r#"<p:cmAuthor id="{}" name="{}" initials="{}" lastIdx="1" clrIdx="{}"/>"#,
+            r#"<p:cmAuthor id="{}" name="{}" initials="{}" lastIdx="{}" clrIdx="{}"/>"#,
             self.id,
             xml_escape(&self.name),
             xml_escape(&self.initials),
+            self.last_index,
             self.color_index,
         )
     }
@@ -43,8 +47,12 @@ impl CommentAuthor {
 
 /// A single comment on a slide
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Comment {
     pub author_id: u32,
+    /// Author name, resolved to `author_id` when the presentation is written
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub author: String,
     pub text: String,
     pub date: String,
     pub x: u32,
@@ -57,6 +65,7 @@ impl Comment {
     pub fn new(author_id: u32, text: &str) -> Self {
         Self {
             author_id,
+            author: String::new(),
             text: text.to_string(),
             date: "2025-01-01T00:00:00.000".to_string(),
             x: 0,
@@ -65,6 +74,12 @@ impl Comment {
         }
     }
 
+    /// Attribute the comment to an author by name
+    pub fn author(mut self, name: &str) -> Self {
+        self.author = name.to_string();
+        self
+    }
+
     /// Set comment position (in EMU)
     pub fn position(mut self, x: u32, y: u32) -> Self {
         self.x = x;
@@ -124,6 +139,27 @@ impl CommentAuthorList {
         id
     }
 
+    /// Add or get an author by name, taking initials from the words of the name
+    pub fn get_or_add_named(&mut self, name: &str) -> u32 {
+        let initials: String = name
+            .split_whitespace()
+            .filter_map(|word| word.chars().next())
+            .flat_map(char::to_uppercase)
+            .collect();
+        self.get_or_add(name, &initials)
+    }
+
+    /// Next comment index for an author; indexes are unique per author across the presentation
+    pub fn next_index(&mut self, id: u32) -> u32 {
+        match self.authors.iter_mut().find(|a| a.id == id) {
+            Some(author) => {
+                author.last_index += 1;
+                author.last_index
+            }
+            None => 1,
+        }
+    }
+
     /// Get author by ID
     pub fn get_by_id(&self, id: u32) -> Option<&CommentAuthor> {
         self.authors.iter().find(|a| a.id == id)
@@ -186,6 +222,19 @@ impl SlideComments {
         );
     }
 
+    /// Resolve named comments against the author list, numbering each author's comments in turn
+    pub fn from_named(comments: &[Comment], authors: &mut CommentAuthorList) -> Self {
+        let comments = comments
+            .iter()
+            .map(|comment| {
+                let author_id = authors.get_or_add_named(&comment.author);
+                let index = authors.next_index(author_id);
+                Comment { author_id, index, ..comment.clone() }
+            })
+            .collect();
+        Self { comments }
+    }
+
     /// Get all comments
     pub fn comments(&self) -> &[Comment] {
         &self.comments
@@ -393,4 +442,25 @@ mod tests {
         assert!(xml.contains("Bob"));
         assert!(xml.matches("<p:cmAuthor ").count() == 2);
     }
+
+    #[test]
+    fn test_comment_author_initials_from_name() {
+        let mut list = CommentAuthorList::new();
+        let id = list.get_or_add_named("ada lovelace");
+        assert_eq!(list.get_by_id(id).unwrap().initials, "AL");
+    }
+
+    #[test]
+    fn test_slide_comments_from_named() {
+        let mut authors = CommentAuthorList::new();
+        let first = SlideComments::from_named(
+            &[Comment::new(0, "One").author("Alice"), Comment::new(0, "Two").author("Bob")],
+            &mut authors,
+        );
+        let second = SlideComments::from_named(&[Comment::new(0, "Three").author("Alice")], &mut authors);
+        assert_eq!(first.comments()[1].author_id, 1);
+        assert_eq!(second.comments()[0].author_id, 0);
+        assert_eq!(second.comments()[0].index, 2);
+        assert!(authors.to_xml().contains(r#"name="Alice" initials="A" lastIdx="2""#));
+    }
 }
@@ -1,20 +1,29 @@
 //! SlideContent struct for complex presentations
 
+use crate::elements::RgbInput;
 use crate::generator::table::Table;
 use crate::generator::shapes::Shape;
+use crate::generator::shape_group::ShapeGroup;
 use crate::generator::images::Image;
 use crate::generator::connectors::Connector;
 use crate::generator::media::{Video, Audio};
 use crate::generator::charts::Chart;
+use crate::generator::gradients::GradientFill;
+use crate::generator::text::FormattedText;
 
 use super::bullet::{BulletStyle, BulletPoint};
-use super::layout::SlideLayout;
+use super::layout::{PlaceholderType, SlideLayout};
 use super::code_block::CodeBlock;
-use super::transition::TransitionType;
+use super::animation::{Animation, SlideAnimation, BODY_PLACEHOLDER_ID};
+use super::transition::{SlideTransition, TransitionDirection, TransitionType};
 use super::ink_annotations::InkAnnotations;
+use super::background::SlideBackground;
+use super::comments::Comment;
+use super::raw_xml::{self, RawRelationship};
 
 /// Slide content for more complex presentations
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SlideContent {
     pub title: String,
     pub content: Vec<String>,
@@ -36,9 +45,11 @@ pub struct SlideContent {
     pub has_chart: bool,
     pub has_image: bool,
     pub layout: SlideLayout,
-    pub transition: TransitionType,
+    pub transition: SlideTransition,
     pub table: Option<Table>,
     pub shapes: Vec<Shape>,
+    /// Grouped shapes that move and scale together
+    pub shape_groups: Vec<ShapeGroup>,
     pub images: Vec<Image>,
     /// Speaker notes for the slide
     pub notes: Option<String>,
@@ -54,6 +65,46 @@ pub struct SlideContent {
     pub code_blocks: Vec<CodeBlock>,
     /// Ink annotations on the slide
     pub ink_annotations: Option<InkAnnotations>,
+    /// Slide-specific background (inherits the master's when `None`)
+    pub background: Option<SlideBackground>,
+    /// Entrance/exit animations in playback order
+    pub animations: Vec<SlideAnimation>,
+    /// Index of the first bullet in the right column of a two-content slide
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub column_break: Option<usize>,
+    /// Bullets for the left content placeholder of a two-content or comparison slide
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub left_bullets: Vec<String>,
+    /// Bullets for the right content placeholder of a two-content or comparison slide
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub right_bullets: Vec<String>,
+    /// Heading above the left column (comparison layout)
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub left_heading: Option<String>,
+    /// Heading above the right column (comparison layout)
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub right_heading: Option<String>,
+    /// Pick the layout from the slide's content unless one was set explicitly
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub auto_layout: bool,
+    /// Whether [`layout`](Self::layout) was called; an explicit layout beats auto selection
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub layout_explicit: bool,
+    /// Review comments shown in PowerPoint's comment pane
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub comments: Vec<Comment>,
+    /// Text for the layout's own placeholders, at most one per title and body slot
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub placeholders: Vec<(PlaceholderType, String)>,
+    /// Well-formed shape XML spliced into the shape tree after the generated shapes
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub raw_shapes: Vec<String>,
+    /// Extra slide relationships, with ids `rIdRaw1`, `rIdRaw2`, ... in order
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub raw_relationships: Vec<RawRelationship>,
+    /// Slide master the slide's layout comes from (1-based); the primary master when unset
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub master: Option<usize>,
 }
 
 impl SlideContent {
@@ -77,9 +128,10 @@ impl SlideContent {
             has_chart: false,
             has_image: false,
             layout: SlideLayout::TitleAndContent,
-            transition: TransitionType::None,
+            transition: SlideTransition::default(),
             table: None,
             shapes: Vec::new(),
+            shape_groups: Vec::new(),
             images: Vec::new(),
             notes: None,
             connectors: Vec::new(),
@@ -88,15 +140,95 @@ impl SlideContent {
             charts: Vec::new(),
             code_blocks: Vec::new(),
             ink_annotations: None,
+            background: None,
+            animations: Vec::new(),
+            column_break: None,
+            left_bullets: Vec::new(),
+            right_bullets: Vec::new(),
+            left_heading: None,
+            right_heading: None,
+            auto_layout: false,
+            layout_explicit: false,
+            comments: Vec::new(),
+            placeholders: Vec::new(),
+            raw_shapes: Vec::new(),
+            raw_relationships: Vec::new(),
+            master: None,
         }
     }
 
+    /// Fill the slide background with a solid color
+    pub fn background_color(mut self, hex: impl Into<RgbInput>) -> Self {
+        self.background = Some(SlideBackground::color(hex));
+        self
+    }
+
+    /// Fill the slide background with a gradient
+    pub fn background_gradient(mut self, gradient: GradientFill) -> Self {
+        self.background = Some(SlideBackground::Gradient(gradient));
+        self
+    }
+
+    /// Stretch a picture over the slide background
+    pub fn background_image(mut self, image: Image) -> Self {
+        self.background = Some(SlideBackground::Image(Box::new(image)));
+        self
+    }
+
     /// Set the slide transition
-    pub fn with_transition(mut self, transition: TransitionType) -> Self {
-        self.transition = transition;
+    pub fn with_transition(mut self, transition: impl Into<SlideTransition>) -> Self {
+        self.transition = transition.into();
+        self
+    }
+
+    /// Set the transition effect, keeping any direction and timing already set
+    pub fn transition(mut self, effect: TransitionType) -> Self {
+        self.transition.effect = effect;
+        self
+    }
+
+    /// Set the direction of a push, wipe, reveal, or cover transition
+    pub fn transition_direction(mut self, direction: TransitionDirection) -> Self {
+        self.transition.direction = Some(direction);
+        self
+    }
+
+    /// Set how long the transition effect takes
+    pub fn transition_duration_ms(mut self, ms: u32) -> Self {
+        self.transition.duration_ms = Some(ms);
+        self
+    }
+
+    /// Whether a click advances past this slide (default true)
+    pub fn advance_on_click(mut self, on_click: bool) -> Self {
+        self.transition.advance_on_click = on_click;
+        self
+    }
+
+    /// Advance to the next slide automatically after `ms`
+    pub fn advance_after_ms(mut self, ms: u32) -> Self {
+        self.transition.advance_after_ms = Some(ms);
         self
     }
     
+    /// Animate the shape with `cNvPr` id `shape_id` (see [`Shape::with_id`](crate::generator::Shape::with_id))
+    pub fn animate(mut self, shape_id: u32, animation: Animation) -> Self {
+        self.animations.push(SlideAnimation { shape_id, paragraph: None, animation });
+        self
+    }
+
+    /// Animate each bullet of the body placeholder in turn
+    pub fn animate_bullets(mut self, animation: Animation) -> Self {
+        for paragraph in 0..self.body_paragraph_count() {
+            self.animations.push(SlideAnimation {
+                shape_id: BODY_PLACEHOLDER_ID,
+                paragraph: Some(paragraph),
+                animation,
+            });
+        }
+        self
+    }
+
     /// Add a bullet point with default style
     pub fn add_bullet(mut self, text: &str) -> Self {
         self.content.push(text.to_string());
@@ -125,6 +257,26 @@ impl SlideContent {
         self
     }
     
+    /// Add a multi-level outline item: numbers at level 0, letters at level 1, roman at level 2
+    pub fn add_outline_item(mut self, text: &str, level: u32) -> Self {
+        self.content.push(format!("{}{}", "  ".repeat(level as usize), text));
+        self.bullets.push(BulletPoint::new(text).with_level(level).with_style(BulletStyle::outline(level)));
+        self
+    }
+
+    /// Add a fully configured bullet point
+    pub fn add_bullet_point(mut self, bullet: BulletPoint) -> Self {
+        self.content.push(format!("{}{}", "  ".repeat(bullet.level as usize), bullet.text));
+        self.bullets.push(bullet);
+        self
+    }
+    
+    /// Add a bullet whose runs carry their own formatting
+    pub fn add_formatted_bullet(self, runs: Vec<FormattedText>) -> Self {
+        let style = self.bullet_style;
+        self.add_bullet_point(BulletPoint::formatted(runs).with_style(style))
+    }
+
     /// Add a sub-bullet (indented)
     pub fn add_sub_bullet(mut self, text: &str) -> Self {
         self.content.push(format!("  {}", text));
@@ -178,13 +330,13 @@ impl SlideContent {
         self
     }
 
-    pub fn title_color(mut self, color: &str) -> Self {
-        self.title_color = Some(color.trim_start_matches('#').to_uppercase());
+    pub fn title_color(mut self, color: impl Into<RgbInput>) -> Self {
+        self.title_color = Some(color.into().into_value());
         self
     }
 
-    pub fn content_color(mut self, color: &str) -> Self {
-        self.content_color = Some(color.trim_start_matches('#').to_uppercase());
+    pub fn content_color(mut self, color: impl Into<RgbInput>) -> Self {
+        self.content_color = Some(color.into().into_value());
         self
     }
 
@@ -205,9 +357,151 @@ impl SlideContent {
 
     pub fn layout(mut self, layout: SlideLayout) -> Self {
         self.layout = layout;
+        self.layout_explicit = true;
+        self
+    }
+
+    /// Take the layout from slide master `master_id`: 1 is the primary master and
+    /// masters added with `add_master` count up from 2
+    pub fn use_master(mut self, master_id: usize) -> Self {
+        self.master = Some(master_id);
+        self
+    }
+
+    /// 0-based index of the slide master the slide uses
+    pub(crate) fn master_index(&self) -> usize {
+        self.master.unwrap_or(1).saturating_sub(1)
+    }
+
+    /// Choose the layout from the slide's content when it is generated.
+    ///
+    /// An explicit [`layout`](Self::layout) always wins, whichever is called first.
+    pub fn auto_layout(mut self) -> Self {
+        self.auto_layout = true;
         self
     }
 
+    /// Put text in the layout's placeholder so it takes the layout's position and the
+    /// master's fonts, instead of the slide's own title or body text box.
+    ///
+    /// Lines become paragraphs. Falls back to a text box when the layout has no such
+    /// placeholder. A second call for the same slot replaces the first.
+    pub fn set_placeholder(mut self, kind: PlaceholderType, text: &str) -> Self {
+        self.placeholders.retain(|(existing, _)| existing.is_title() != kind.is_title());
+        self.placeholders.push((kind, text.to_string()));
+        self
+    }
+
+    /// Start the right column: bullets added after this go in the second column
+    pub fn add_column_break(mut self) -> Self {
+        self.column_break = Some(self.body_paragraph_count());
+        self
+    }
+
+    /// Fill the left placeholder of a two-content slide (`idx="1"`)
+    pub fn left_bullets<I>(mut self, bullets: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        self.left_bullets = bullets.into_iter().map(Into::into).collect();
+        self.use_column_layout();
+        self
+    }
+
+    /// Fill the right placeholder of a two-content slide (`idx="2"`)
+    pub fn right_bullets<I>(mut self, bullets: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        self.right_bullets = bullets.into_iter().map(Into::into).collect();
+        self.use_column_layout();
+        self
+    }
+
+    /// Heading above the left column; switches the slide to the comparison layout
+    pub fn left_heading(mut self, heading: &str) -> Self {
+        self.left_heading = Some(heading.to_string());
+        self.use_column_layout();
+        self
+    }
+
+    /// Heading above the right column; switches the slide to the comparison layout
+    pub fn right_heading(mut self, heading: &str) -> Self {
+        self.right_heading = Some(heading.to_string());
+        self.use_column_layout();
+        self
+    }
+
+    /// Whether left/right column content was set
+    pub fn has_columns(&self) -> bool {
+        !self.left_bullets.is_empty()
+            || !self.right_bullets.is_empty()
+            || self.left_heading.is_some()
+            || self.right_heading.is_some()
+    }
+
+    fn column_layout(&self) -> SlideLayout {
+        if self.left_heading.is_some() || self.right_heading.is_some() {
+            SlideLayout::Comparison
+        } else {
+            SlideLayout::TwoColumn
+        }
+    }
+
+    fn use_column_layout(&mut self) {
+        if !self.layout_explicit {
+            self.layout = self.column_layout();
+        }
+    }
+
+    /// Layout suggested by the slide's content:
+    /// two content or comparison for left/right columns, picture with caption for one
+    /// image plus caption text, two content after a column break, title and content
+    /// for bullets, and title only otherwise.
+    pub fn suggested_layout(&self) -> SlideLayout {
+        let has_text = self.body_paragraph_count() > 0;
+        if self.has_columns() {
+            self.column_layout()
+        } else if self.images.len() == 1 && has_text && self.table.is_none() && self.charts.is_empty() {
+            SlideLayout::PictureWithCaption
+        } else if self.column_break.is_some() && has_text {
+            SlideLayout::TwoColumn
+        } else if has_text || self.table.is_some() {
+            SlideLayout::TitleAndContent
+        } else if self.shapes.is_empty()
+            && self.shape_groups.is_empty()
+            && self.images.is_empty()
+            && self.charts.is_empty()
+            && self.code_blocks.is_empty()
+        {
+            SlideLayout::TitleOnly
+        } else {
+            SlideLayout::TitleAndContent
+        }
+    }
+
+    /// Layout the slide is generated with
+    pub fn effective_layout(&self) -> SlideLayout {
+        if self.auto_layout && !self.layout_explicit {
+            self.suggested_layout()
+        } else {
+            self.layout
+        }
+    }
+
+    /// Number of body paragraphs: styled bullets when present, otherwise plain content lines
+    pub(crate) fn body_paragraph_count(&self) -> usize {
+        if self.bullets.is_empty() { self.content.len() } else { self.bullets.len() }
+    }
+
+    /// Where the body splits into two columns (halfway unless a column break was added)
+    pub(crate) fn column_split(&self) -> usize {
+        let count = self.body_paragraph_count();
+        self.column_break.map_or(count.div_ceil(2), |at| at.min(count))
+    }
+
     /// Alias for [`layout`](Self::layout) — per-slide layout selection.
     pub fn with_layout(self, layout: SlideLayout) -> Self {
         self.layout(layout)
@@ -231,6 +525,12 @@ impl SlideContent {
         self
     }
 
+    /// Add a group of shapes that move and scale together
+    pub fn add_shape_group(mut self, group: ShapeGroup) -> Self {
+        self.shape_groups.push(group);
+        self
+    }
+
     /// Add an image to the slide
     pub fn add_image(mut self, image: Image) -> Self {
         self.images.push(image);
@@ -245,9 +545,9 @@ impl SlideContent {
         self
     }
 
-    /// Add speaker notes to the slide
+    /// Add speaker notes to the slide (empty notes produce no notes slide)
     pub fn notes(mut self, notes: &str) -> Self {
-        self.notes = Some(notes.to_string());
+        self.notes = if notes.is_empty() { None } else { Some(notes.to_string()) };
         self
     }
 
@@ -292,6 +592,31 @@ impl SlideContent {
         self
     }
 
+    /// Add a review comment by `author`, anchored at a position on the slide (EMU)
+    pub fn add_comment(mut self, author: &str, text: &str, x: u32, y: u32) -> Self {
+        self.comments.push(Comment::new(0, text).author(author).position(x, y));
+        self
+    }
+
+    /// Add shape XML (`<p:sp>`, `<p:grpSp>`, `<p:graphicFrame>`, ...) the builder doesn't model
+    ///
+    /// The fragment may use the `a:`, `r:` and `p:` prefixes and must be well-formed.
+    /// Its `cNvPr` ids are renumbered on output so they don't collide with generated shapes.
+    pub fn add_raw_shape_xml(mut self, xml: &str) -> crate::exc::Result<Self> {
+        raw_xml::check_fragment(xml)?;
+        self.raw_shapes.push(xml.to_string());
+        Ok(self)
+    }
+
+    /// Add a relationship to the slide's `.rels` part for raw shape XML to reference
+    ///
+    /// The n-th raw relationship gets the id `rIdRaw{n}`. URL targets are written as
+    /// external; any other target must name a part that exists in the package.
+    pub fn add_raw_relationship(mut self, rel_type: &str, target: &str) -> Self {
+        self.raw_relationships.push(RawRelationship::new(rel_type, target));
+        self
+    }
+
     /// Add a chart to the slide
     pub fn add_chart(mut self, chart: Chart) -> Self {
         self.charts.push(chart);
@@ -5,6 +5,7 @@
 
 /// Hash algorithm used for signing
 #[derive(Clone, Debug, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum HashAlgorithm {
     #[default]
     Sha256,
@@ -35,6 +36,7 @@ impl HashAlgorithm {
 
 /// Signer identity information
 #[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SignerInfo {
     pub name: String,
     pub email: Option<String>,
@@ -68,6 +70,7 @@ impl SignerInfo {
 
 /// Digital signature configuration for a presentation
 #[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DigitalSignature {
     pub signer: SignerInfo,
     pub hash_algorithm: HashAlgorithm,
@@ -78,6 +81,7 @@ pub struct DigitalSignature {
 
 /// Commitment type for the signature
 #[derive(Clone, Debug, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SignatureCommitment {
     #[default]
     Created,
@@ -4,8 +4,11 @@
 //! on systems that don't have the fonts installed. Generates proper
 //! `<p:embeddedFontLst>` XML in presentation.xml.
 
+use crate::exc::{PptxError, Result};
+
 /// Font style variant
 #[derive(Clone, Debug, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum FontStyle {
     Regular,
     Bold,
@@ -30,10 +33,31 @@ impl FontStyle {
     pub fn is_italic(&self) -> bool {
         matches!(self, FontStyle::Italic | FontStyle::BoldItalic)
     }
+
+    /// Style for the given bold/italic combination
+    pub fn from_flags(bold: bool, italic: bool) -> Self {
+        match (bold, italic) {
+            (false, false) => FontStyle::Regular,
+            (true, false) => FontStyle::Bold,
+            (false, true) => FontStyle::Italic,
+            (true, true) => FontStyle::BoldItalic,
+        }
+    }
+
+    /// Position of this style's element inside `<p:embeddedFont>`
+    fn schema_order(&self) -> u8 {
+        match self {
+            FontStyle::Regular => 0,
+            FontStyle::Bold => 1,
+            FontStyle::Italic => 2,
+            FontStyle::BoldItalic => 3,
+        }
+    }
 }
 
 /// Character set for the font
 #[derive(Clone, Debug, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum FontCharset {
     #[default]
     Ansi,
@@ -75,6 +99,7 @@ impl FontCharset {
 
 /// A single embedded font entry
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EmbeddedFont {
     pub typeface: String,
     pub style: FontStyle,
@@ -83,6 +108,8 @@ pub struct EmbeddedFont {
     pub pitch_family: u8,
     pub data: Vec<u8>,
     pub relationship_id: String,
+    /// `N` in `ppt/fonts/fontN.fntdata`; assigned by [`EmbeddedFontList::add`]
+    pub part_number: usize,
 }
 
 impl EmbeddedFont {
@@ -96,7 +123,23 @@ impl EmbeddedFont {
             pitch_family: 0x22, // Variable pitch, Roman family
             data,
             relationship_id: rel_id.to_string(),
+            part_number: 1,
+        }
+    }
+
+    /// Create from TrueType/OpenType font file bytes; the relationship ID is assigned on packaging
+    pub fn from_bytes(typeface: &str, style: FontStyle, data: Vec<u8>) -> Result<Self> {
+        let is_sfnt = matches!(
+            data.get(..4),
+            Some([0x00, 0x01, 0x00, 0x00]) | Some(b"OTTO") | Some(b"true")
+        );
+        if !is_sfnt {
+            return Err(PptxError::InvalidValue(format!(
+                "font data for '{}' is not a TrueType or OpenType file",
+                typeface
+            )));
         }
+        Ok(Self::new(typeface, style, data, ""))
     }
 
     pub fn charset(mut self, charset: FontCharset) -> Self {
@@ -126,25 +169,18 @@ impl EmbeddedFont {
 
     /// Part name in the ZIP archive
     pub fn part_name(&self) -> String {
-        format!(
-            "ppt/fonts/{}-{}.fntdata",
-            self.typeface.replace(' ', ""),
-            self.style.xml_element()
-        )
+        format!("ppt/{}", self.rel_target())
     }
 
     /// Relationship target relative to `ppt/`.
     pub fn rel_target(&self) -> String {
-        format!(
-            "fonts/{}-{}.fntdata",
-            self.typeface.replace(' ', ""),
-            self.style.xml_element()
-        )
+        format!("fonts/font{}.fntdata", self.part_number)
     }
 }
 
 /// Manages all embedded fonts for a presentation
 #[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EmbeddedFontList {
     fonts: Vec<EmbeddedFont>,
 }
@@ -154,9 +190,18 @@ impl EmbeddedFontList {
         Self::default()
     }
 
-    /// Add an embedded font
-    pub fn add(&mut self, font: EmbeddedFont) {
-        self.fonts.push(font);
+    /// Add an embedded font; a second font for the same typeface and style replaces the first
+    pub fn add(&mut self, mut font: EmbeddedFont) {
+        match self.fonts.iter_mut().find(|f| f.typeface == font.typeface && f.style == font.style) {
+            Some(existing) => {
+                font.part_number = existing.part_number;
+                *existing = font;
+            }
+            None => {
+                font.part_number = self.fonts.len() + 1;
+                self.fonts.push(font);
+            }
+        }
     }
 
     /// Get all fonts
@@ -210,10 +255,12 @@ impl EmbeddedFontList {
         }
 
         for typeface in &seen_typefaces {
-            let variants: Vec<&EmbeddedFont> = self.fonts
+            let mut variants: Vec<&EmbeddedFont> = self.fonts
                 .iter()
                 .filter(|f| &f.typeface == typeface)
                 .collect();
+            // The schema requires regular, bold, italic, boldItalic in that order
+            variants.sort_by_key(|f| f.style.schema_order());
 
             xml.push_str("<p:embeddedFont>");
 
@@ -317,7 +364,42 @@ mod tests {
     #[test]
     fn test_embedded_font_part_name() {
         let font = EmbeddedFont::new("Times New Roman", FontStyle::BoldItalic, vec![], "rId1");
-        assert_eq!(font.part_name(), "ppt/fonts/TimesNewRoman-boldItalic.fntdata");
+        assert_eq!(font.part_name(), "ppt/fonts/font1.fntdata");
+
+        let mut list = EmbeddedFontList::new();
+        list.add(EmbeddedFont::new("Arial", FontStyle::Regular, vec![], ""));
+        list.add(font);
+        assert_eq!(list.fonts()[1].part_name(), "ppt/fonts/font2.fntdata");
+        assert_eq!(list.fonts()[1].rel_target(), "fonts/font2.fntdata");
+    }
+
+    #[test]
+    fn test_embedded_font_from_bytes() {
+        let ttf = vec![0x00, 0x01, 0x00, 0x00, 0x00, 0x0A];
+        let font = EmbeddedFont::from_bytes("Brand Sans", FontStyle::Regular, ttf).unwrap();
+        assert_eq!(font.relationship_id, "");
+        assert!(EmbeddedFont::from_bytes("Brand Sans", FontStyle::Bold, b"OTTO\0\x0A".to_vec()).is_ok());
+        assert!(EmbeddedFont::from_bytes("Brand Sans", FontStyle::Bold, vec![0; 8]).is_err());
+    }
+
+    #[test]
+    fn test_embedded_font_list_style_order_and_replace() {
+        let mut list = EmbeddedFontList::new();
+        list.add(EmbeddedFont::new("Brand", FontStyle::BoldItalic, vec![], "rId4"));
+        list.add(EmbeddedFont::new("Brand", FontStyle::Bold, vec![1], "rId3"));
+        list.add(EmbeddedFont::new("Brand", FontStyle::Regular, vec![], "rId2"));
+        list.add(EmbeddedFont::new("Brand", FontStyle::Bold, vec![2, 2], "rId3"));
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.fonts()[1].data, vec![2, 2]);
+        assert_eq!(list.fonts()[1].part_number, 2);
+
+        let xml = list.to_xml();
+        let regular = xml.find("<p:regular").unwrap();
+        let bold = xml.find("<p:bold ").unwrap();
+        let bold_italic = xml.find("<p:boldItalic").unwrap();
+        assert!(regular < bold && bold < bold_italic);
+        assert_eq!(FontStyle::from_flags(true, false), FontStyle::Bold);
+        assert_eq!(FontStyle::from_flags(true, true), FontStyle::BoldItalic);
     }
 
     #[test]
@@ -0,0 +1,352 @@
+//! Footer, date, and slide-number placeholders on generated slides, and the
+//! header/footer of printed notes pages and handouts
+//!
+//! The master's footer placeholders only show up on a slide when the slide
+//! carries its own `ftr`/`dt`/`sldNum` placeholder shapes, so these are
+//! appended to every slide's shape tree. Notes slides work the same way.
+
+use crate::core::{escape_xml, SlideSize};
+use crate::generator::layout_parts::scale_bounds;
+use crate::generator::text::proofing_attrs;
+
+/// Slide placeholder frames (x, y, cx, cy) along the bottom of the 4:3 slide
+const DATE_FRAME: (u32, u32, u32, u32) = (457_200, 6_356_350, 2_133_600, 365_125);
+const FOOTER_FRAME: (u32, u32, u32, u32) = (3_124_200, 6_356_350, 2_895_600, 365_125);
+const SLIDE_NUMBER_FRAME: (u32, u32, u32, u32) = (6_553_200, 6_356_350, 2_133_600, 365_125);
+
+/// What the date placeholder shows
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DateMode {
+    /// Date field that PowerPoint updates on open (`datetime1`, e.g. 10/15/2026)
+    Auto,
+    /// Literal text that never changes
+    Fixed(String),
+}
+
+impl DateMode {
+    /// Fixed date text
+    pub fn fixed(text: &str) -> Self {
+        DateMode::Fixed(text.to_string())
+    }
+}
+
+/// Footer text, date, and slide number shown on each slide
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HeaderFooter {
+    pub footer: Option<String>,
+    pub date: Option<DateMode>,
+    pub slide_numbers: bool,
+}
+
+impl HeaderFooter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn footer(mut self, text: &str) -> Self {
+        self.footer = Some(text.to_string());
+        self
+    }
+
+    pub fn date(mut self, mode: DateMode) -> Self {
+        self.date = Some(mode);
+        self
+    }
+
+    pub fn slide_numbers(mut self, show: bool) -> Self {
+        self.slide_numbers = show;
+        self
+    }
+
+    /// Whether any placeholder is shown
+    pub fn is_visible(&self) -> bool {
+        self.footer.is_some() || self.date.is_some() || self.slide_numbers
+    }
+
+    /// Placeholder shapes for slide `slide_num`, numbered from `first_id` and laid out for a slide of `size`
+    pub fn slide_shapes_xml(&self, slide_num: usize, first_id: u32, size: &SlideSize) -> String {
+        let mut xml = String::new();
+        let mut id = first_id;
+
+        if let Some(ref date) = self.date {
+            let content = match date {
+                DateMode::Auto => date_field(),
+                DateMode::Fixed(text) => text_run(text),
+            };
+            xml.push_str(&placeholder_shape(id, "Date Placeholder", "dt", "half", 10, scale_bounds(DATE_FRAME, size), &content));
+            id += 1;
+        }
+
+        if let Some(ref footer) = self.footer {
+            xml.push_str(&placeholder_shape(id, "Footer Placeholder", "ftr", "quarter", 11, scale_bounds(FOOTER_FRAME, size), &text_run(footer)));
+            id += 1;
+        }
+
+        if self.slide_numbers {
+            let content = slide_number_field(&slide_num.to_string());
+            xml.push_str(&placeholder_shape(id, "Slide Number Placeholder", "sldNum", "quarter", 12, scale_bounds(SLIDE_NUMBER_FRAME, size), &content));
+        }
+
+        xml
+    }
+}
+
+/// Header, footer, date, and page number printed on notes pages or handouts
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PageHeaderFooter {
+    pub header: Option<String>,
+    pub footer: Option<String>,
+    pub date: Option<DateMode>,
+    pub page_numbers: bool,
+}
+
+/// Which master a page placeholder belongs to; the two number their placeholders differently
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) enum PageMaster {
+    Notes,
+    Handout,
+}
+
+impl PageHeaderFooter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn header(mut self, text: &str) -> Self {
+        self.header = Some(text.to_string());
+        self
+    }
+
+    pub fn footer(mut self, text: &str) -> Self {
+        self.footer = Some(text.to_string());
+        self
+    }
+
+    pub fn date(mut self, mode: DateMode) -> Self {
+        self.date = Some(mode);
+        self
+    }
+
+    pub fn page_numbers(mut self, show: bool) -> Self {
+        self.page_numbers = show;
+        self
+    }
+
+    /// Whether any placeholder is shown
+    pub fn is_visible(&self) -> bool {
+        self.header.is_some() || self.footer.is_some() || self.date.is_some() || self.page_numbers
+    }
+
+    /// Header, date, footer and page number placeholders in the corners of a portrait
+    /// page, numbered from `first_id`. With `positioned` false the shapes carry no
+    /// geometry and inherit it from the master, as on a notes slide.
+    pub(crate) fn page_shapes_xml(&self, master: PageMaster, first_id: u32, positioned: bool) -> String {
+        let (date_idx, footer_idx, number_idx) = match master {
+            PageMaster::Notes => (1, 4, 5),
+            PageMaster::Handout => (1, 2, 3),
+        };
+        let mut xml = String::new();
+        let mut id = first_id;
+        let mut push = |name: &str, ph: String, corner: (u32, u32), align: &str, content: String| {
+            let sp_pr = if positioned {
+                format!(
+                    r#"<p:spPr><a:xfrm><a:off x="{}" y="{}"/><a:ext cx="2971800" cy="458788"/></a:xfrm><a:prstGeom prst="rect"><a:avLst/></a:prstGeom></p:spPr>"#,
+                    corner.0, corner.1
+                )
+            } else {
+                "<p:spPr/>".to_string()
+            };
+            let anchor = if corner.1 > 0 { r#" anchor="b""# } else { "" };
+            xml.push_str(&format!(
+                r#"<p:sp><p:nvSpPr><p:cNvPr id="{id}" name="{name} {id}"/><p:cNvSpPr><a:spLocks noGrp="1"/></p:cNvSpPr><p:nvPr>{ph}</p:nvPr></p:nvSpPr>{sp_pr}<p:txBody><a:bodyPr vert="horz" lIns="91440" tIns="45720" rIns="91440" bIns="45720" rtlCol="0"{anchor}/><a:lstStyle><a:lvl1pPr algn="{align}"><a:defRPr sz="1200"/></a:lvl1pPr></a:lstStyle><a:p>{content}</a:p></p:txBody></p:sp>"#
+            ));
+            id += 1;
+        };
+
+        if let Some(ref header) = self.header {
+            push("Header Placeholder", r#"<p:ph type="hdr" sz="quarter"/>"#.to_string(), (0, 0), "l", text_run(header));
+        }
+        if let Some(ref date) = self.date {
+            let content = match date {
+                DateMode::Auto => date_field(),
+                DateMode::Fixed(text) => text_run(text),
+            };
+            let sz = if master == PageMaster::Handout { r#" sz="quarter""# } else { "" };
+            push("Date Placeholder", format!(r#"<p:ph type="dt"{sz} idx="{date_idx}"/>"#), (3884613, 0), "r", content);
+        }
+        if let Some(ref footer) = self.footer {
+            push("Footer Placeholder", format!(r#"<p:ph type="ftr" sz="quarter" idx="{footer_idx}"/>"#), (0, 8685213), "l", text_run(footer));
+        }
+        if self.page_numbers {
+            push(
+                "Slide Number Placeholder",
+                format!(r#"<p:ph type="sldNum" sz="quarter" idx="{number_idx}"/>"#),
+                (3884613, 8685213),
+                "r",
+                slide_number_field("‹#›"),
+            );
+        }
+        xml
+    }
+
+    /// Handout master (`ppt/handoutMasters/handoutMaster1.xml`) showing these placeholders
+    pub fn handout_master_xml(&self) -> String {
+        format!(
+            r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?><p:handoutMaster xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships" xmlns:p="http://schemas.openxmlformats.org/presentationml/2006/main"><p:cSld><p:bg><p:bgRef idx="1001"><a:schemeClr val="bg1"/></p:bgRef></p:bg><p:spTree><p:nvGrpSpPr><p:cNvPr id="1" name=""/><p:cNvGrpSpPr/><p:nvPr/></p:nvGrpSpPr><p:grpSpPr><a:xfrm><a:off x="0" y="0"/><a:ext cx="0" cy="0"/><a:chOff x="0" y="0"/><a:chExt cx="0" cy="0"/></a:xfrm></p:grpSpPr>{}</p:spTree></p:cSld><p:clrMap bg1="lt1" tx1="dk1" bg2="lt2" tx2="dk2" accent1="accent1" accent2="accent2" accent3="accent3" accent4="accent4" accent5="accent5" accent6="accent6" hlink="hlink" folHlink="folHlink"/></p:handoutMaster>"#,
+            self.page_shapes_xml(PageMaster::Handout, 2, true)
+        )
+    }
+}
+
+fn text_run(text: &str) -> String {
+    format!(r#"<a:r><a:rPr lang="en-US"{}/><a:t>{}</a:t></a:r>"#, proofing_attrs(false), escape_xml(text))
+}
+
+fn date_field() -> String {
+    format!(
+        r#"<a:fld id="{{B6F15528-F159-4107-2D14-000000000000}}" type="datetime1"><a:rPr lang="en-US"{}/><a:t></a:t></a:fld>"#,
+        proofing_attrs(false)
+    )
+}
+
+fn slide_number_field(text: &str) -> String {
+    format!(
+        r#"<a:fld id="{{B6F15528-F159-4107-2D14-000000000001}}" type="slidenum"><a:rPr lang="en-US"{}/><a:t>{}</a:t></a:fld>"#,
+        proofing_attrs(false),
+        text
+    )
+}
+
+/// Add the header, date and footer placeholders to a notes slide; its slide number
+/// placeholder is always present
+pub(crate) fn apply_to_notes_xml(xml: &str, header_footer: &PageHeaderFooter) -> String {
+    let Some(end) = xml.rfind("</p:spTree>") else {
+        return xml.to_string();
+    };
+    let text_only = PageHeaderFooter { page_numbers: false, ..header_footer.clone() };
+    let shapes = text_only.page_shapes_xml(PageMaster::Notes, max_shape_id(xml) + 1, false);
+    let mut out = String::with_capacity(xml.len() + shapes.len());
+    out.push_str(&xml[..end]);
+    out.push_str(&shapes);
+    out.push_str(&xml[end..]);
+    out
+}
+
+/// Placeholder shape in frame `(x, y, cx, cy)`
+fn placeholder_shape(id: u32, name: &str, ph_type: &str, sz: &str, idx: u32, (x, y, cx, cy): (u32, u32, u32, u32), content: &str) -> String {
+    let align = match ph_type {
+        "ftr" => "ctr",
+        "sldNum" => "r",
+        _ => "l",
+    };
+    format!(
+        r#"<p:sp><p:nvSpPr><p:cNvPr id="{id}" name="{name} {id}"/><p:cNvSpPr><a:spLocks noGrp="1"/></p:cNvSpPr><p:nvPr><p:ph type="{ph_type}" sz="{sz}" idx="{idx}"/></p:nvPr></p:nvSpPr><p:spPr><a:xfrm><a:off x="{x}" y="{y}"/><a:ext cx="{cx}" cy="{cy}"/></a:xfrm></p:spPr><p:txBody><a:bodyPr/><a:lstStyle/><a:p><a:pPr algn="{align}"/>{content}</a:p></p:txBody></p:sp>"#
+    )
+}
+
+/// Append the header/footer placeholders to a slide's shape tree
+pub(crate) fn apply_to_slide_xml(xml: &str, header_footer: &HeaderFooter, slide_num: usize, size: &SlideSize) -> String {
+    let Some(end) = xml.rfind("</p:spTree>") else {
+        return xml.to_string();
+    };
+    let shapes = header_footer.slide_shapes_xml(slide_num, max_shape_id(xml) + 1, size);
+    let mut out = String::with_capacity(xml.len() + shapes.len());
+    out.push_str(&xml[..end]);
+    out.push_str(&shapes);
+    out.push_str(&xml[end..]);
+    out
+}
+
+/// Largest `cNvPr` id already used on the slide
+pub(crate) fn max_shape_id(xml: &str) -> u32 {
+    xml.match_indices(r#"<p:cNvPr id=""#)
+        .filter_map(|(pos, tag)| {
+            let rest = &xml[pos + tag.len()..];
+            rest[..rest.find('"')?].parse::<u32>().ok()
+        })
+        .max()
+        .unwrap_or(1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_slide_shapes() {
+        let hf = HeaderFooter::new()
+            .footer("Confidential — 2024")
+            .slide_numbers(true)
+            .date(DateMode::Auto);
+        let xml = hf.slide_shapes_xml(3, 5, &SlideSize::default());
+        assert!(xml.contains(r#"<p:cNvPr id="5" name="Date Placeholder 5"/>"#));
+        assert!(xml.contains(r#"type="datetime1""#));
+        assert!(xml.contains(r#"<p:ph type="ftr" sz="quarter" idx="11"/>"#));
+        assert!(xml.contains("<a:t>Confidential — 2024</a:t>"));
+        assert!(xml.contains(r#"type="slidenum"><a:rPr lang="en-US" dirty="0"/><a:t>3</a:t></a:fld>"#));
+        assert_eq!(xml.matches("<a:rPr ").count(), xml.matches(r#"dirty="0""#).count());
+        assert!(xml.contains(r#"<p:cNvPr id="7""#));
+        assert!(xml.contains(r#"<a:off x="6553200" y="6356350"/><a:ext cx="2133600" cy="365125"/>"#));
+    }
+
+    #[test]
+    fn test_slide_shapes_follow_slide_size() {
+        use crate::core::SlideSizePreset;
+
+        let hf = HeaderFooter::new().footer("Wide").slide_numbers(true).date(DateMode::Auto);
+        let wide = SlideSize::from(SlideSizePreset::Screen16x9);
+        let xml = hf.slide_shapes_xml(1, 2, &wide);
+        assert!(xml.contains(r#"<a:off x="457200" y="4767262"/><a:ext cx="2133600" cy="273843"/>"#));
+        assert!(xml.contains(r#"<a:off x="3124200" y="4767262"/>"#));
+        assert!(xml.contains(r#"<a:off x="6553200" y="4767262"/>"#));
+        assert!(!xml.contains("6356350"));
+    }
+
+    #[test]
+    fn test_fixed_date_and_visibility() {
+        assert!(!HeaderFooter::new().is_visible());
+        let hf = HeaderFooter::new().date(DateMode::fixed("Q3 <draft>"));
+        assert!(hf.is_visible());
+        let xml = hf.slide_shapes_xml(1, 2, &SlideSize::default());
+        assert!(xml.contains("<a:t>Q3 &lt;draft&gt;</a:t>"));
+        assert!(!xml.contains("ftr") && !xml.contains("sldNum"));
+    }
+
+    #[test]
+    fn test_handout_master_places_page_corners() {
+        let xml = PageHeaderFooter::new()
+            .header("Course 101")
+            .footer("Week 3")
+            .page_numbers(true)
+            .handout_master_xml();
+        assert!(xml.contains(r#"<p:ph type="hdr" sz="quarter"/>"#));
+        assert!(xml.contains(r#"<a:off x="0" y="0"/><a:ext cx="2971800" cy="458788"/>"#));
+        assert!(xml.contains(r#"<p:ph type="ftr" sz="quarter" idx="2"/>"#));
+        assert!(xml.contains(r#"<a:off x="3884613" y="8685213"/>"#));
+        assert!(xml.contains("<a:t>Course 101</a:t>") && xml.contains("<a:t>Week 3</a:t>"));
+        assert!(!xml.contains(r#"type="dt""#));
+        assert_eq!(xml.matches("<a:rPr ").count(), xml.matches(r#"dirty="0""#).count());
+    }
+
+    #[test]
+    fn test_apply_to_notes_xml_adds_text_placeholders() {
+        let notes = r#"<p:notes><p:cSld><p:spTree><p:sp><p:nvSpPr><p:cNvPr id="4" name="Slide Number Placeholder 3"/></p:nvSpPr></p:sp></p:spTree></p:cSld></p:notes>"#;
+        let hf = PageHeaderFooter::new().footer("Confidential").date(DateMode::fixed("May 2025")).page_numbers(true);
+        let xml = apply_to_notes_xml(notes, &hf);
+        assert!(xml.contains(r#"<p:cNvPr id="5" name="Date Placeholder 5"/>"#));
+        assert!(xml.contains(r#"<p:ph type="ftr" sz="quarter" idx="4"/></p:nvPr></p:nvSpPr><p:spPr/>"#));
+        assert_eq!(xml.matches(r#"type="sldNum""#).count(), 0);
+        assert!(!PageHeaderFooter::new().is_visible());
+    }
+
+    #[test]
+    fn test_apply_to_slide_xml_uses_fresh_ids() {
+        let slide = r#"<p:sld><p:cSld><p:spTree><p:nvGrpSpPr><p:cNvPr id="1" name=""/></p:nvGrpSpPr><p:sp><p:nvSpPr><p:cNvPr id="14" name="Box"/></p:nvSpPr></p:sp></p:spTree></p:cSld></p:sld>"#;
+        let xml = apply_to_slide_xml(slide, &HeaderFooter::new().slide_numbers(true), 2, &SlideSize::default());
+        assert!(xml.contains(r#"<p:cNvPr id="15" name="Slide Number Placeholder 15"/>"#));
+        assert!(xml.ends_with("</p:sp></p:spTree></p:cSld></p:sld>"));
+    }
+}
@@ -3,8 +3,11 @@
 //! Supports freehand ink strokes on slides using the OOXML `<p:inkGrp>` element.
 //! Each stroke is a series of points with pen properties (color, width).
 
+use crate::elements::RgbInput;
+
 /// Pen tip style
 #[derive(Clone, Debug, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PenTip {
     #[default]
     Ball,
@@ -22,6 +25,7 @@ impl PenTip {
 
 /// Ink pen properties
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InkPen {
     pub color: String,
     pub width: u32,
@@ -31,9 +35,9 @@ pub struct InkPen {
 
 impl InkPen {
     /// Create a pen with color (RGB hex) and width in hundredths of a mm
-    pub fn new(color: &str, width: u32) -> Self {
+    pub fn new(color: impl Into<RgbInput>, width: u32) -> Self {
         Self {
-            color: color.trim_start_matches('#').to_uppercase(),
+            color: color.into().into_value(),
             width,
             tip: PenTip::default(),
             opacity: 1.0,
@@ -74,6 +78,7 @@ impl InkPen {
 
 /// A single point in an ink stroke
 #[derive(Clone, Debug, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InkPoint {
     pub x: f64,
     pub y: f64,
@@ -87,6 +92,7 @@ impl InkPoint {
 
 /// A single ink stroke (continuous pen path)
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InkStroke {
     pub points: Vec<InkPoint>,
     pub pen: InkPen,
@@ -146,6 +152,7 @@ impl InkStroke {
 
 /// Collection of ink annotations on a slide
 #[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InkAnnotations {
     strokes: Vec<InkStroke>,
 }
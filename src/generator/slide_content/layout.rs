@@ -1,7 +1,31 @@
 //! Slide layout types
 
+use super::content::SlideContent;
+
+/// Title box in EMU (x, y, cx, cy) of the title-and-content style layouts
+pub(crate) const TITLE_BOX: (u32, u32, u32, u32) = (457_200, 274_638, 8_230_200, 1_143_000);
+/// Centered title slide title box in EMU
+pub(crate) const CENTERED_TITLE_BOX: (u32, u32, u32, u32) = (457_200, 2_743_200, 8_230_200, 1_371_600);
+/// Section header title box in EMU
+pub(crate) const SECTION_TITLE_BOX: (u32, u32, u32, u32) = (457_200, 1_600_200, 8_229_600, 1_828_800);
+/// Bullet and text content box in EMU below [`TITLE_BOX`]
+pub(crate) const CONTENT_BOX: (u32, u32, u32, u32) = (457_200, 1_600_200, 8_230_200, 4_572_000);
+/// Picture-with-caption title box in EMU (x, y, cx, cy), matching `slideLayout8.xml`
+pub(crate) const CAPTION_TITLE_BOX: (u32, u32, u32, u32) = (1_792_288, 4_800_600, 5_486_400, 566_738);
+/// Picture-with-caption picture area in EMU
+pub(crate) const PICTURE_BOX: (u32, u32, u32, u32) = (1_792_288, 612_775, 5_486_400, 4_114_800);
+/// Picture-with-caption caption text box in EMU
+pub(crate) const CAPTION_BOX: (u32, u32, u32, u32) = (1_792_288, 5_367_338, 5_486_400, 804_862);
+/// Comparison column heading boxes in EMU (left, right), matching `slideLayout9.xml`
+pub(crate) const COMPARISON_HEADING_BOXES: [(u32, u32, u32, u32); 2] =
+    [(457_200, 1_535_113, 4_040_188, 639_762), (4_645_025, 1_535_113, 4_041_775, 639_762)];
+/// Comparison column content boxes in EMU (left, right)
+pub(crate) const COMPARISON_CONTENT_BOXES: [(u32, u32, u32, u32); 2] =
+    [(457_200, 2_174_875, 4_040_188, 3_951_288), (4_645_025, 2_174_875, 4_041_775, 3_951_288)];
+
 /// Slide layout types
 #[derive(Clone, Debug, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SlideLayout {
     /// Title slide (centered title + subtitle)
     CenteredTitle,
@@ -17,6 +41,10 @@ pub enum SlideLayout {
     TitleOnly,
     /// Title at top, content fills rest
     TitleAndBigContent,
+    /// One large picture with a title and caption text beneath it
+    PictureWithCaption,
+    /// Two columns of content, each under its own heading
+    Comparison,
 }
 
 impl SlideLayout {
@@ -30,6 +58,8 @@ impl SlideLayout {
             SlideLayout::Blank => 5,
             SlideLayout::TitleOnly => 6,
             SlideLayout::TitleAndBigContent => 7,
+            SlideLayout::PictureWithCaption => 8,
+            SlideLayout::Comparison => 9,
         }
     }
 
@@ -42,6 +72,74 @@ impl SlideLayout {
             SlideLayout::CenteredTitle => "centeredTitle",
             SlideLayout::TwoColumn => "twoColumn",
             SlideLayout::SectionHeader => "sectionHeader",
+            SlideLayout::PictureWithCaption => "pictureWithCaption",
+            SlideLayout::Comparison => "comparison",
+        }
+    }
+}
+
+/// Text placeholder a slide can fill from its layout
+///
+/// A title slot (`Title`, `CenteredTitle`) and a body slot (`Subtitle`, `Body`) each
+/// take the layout's placeholder of either kind, so a title targets `ctrTitle` on the
+/// title slide and `title` elsewhere.
+#[derive(Clone, Debug, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PlaceholderType {
+    /// Slide title (`title`)
+    Title,
+    /// Title of a title slide (`ctrTitle`)
+    CenteredTitle,
+    /// Subtitle of a title slide (`subTitle`)
+    Subtitle,
+    /// Body text (`body`)
+    Body,
+}
+
+impl PlaceholderType {
+    /// `<p:ph type>` value
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PlaceholderType::Title => "title",
+            PlaceholderType::CenteredTitle => "ctrTitle",
+            PlaceholderType::Subtitle => "subTitle",
+            PlaceholderType::Body => "body",
         }
     }
+
+    /// Whether this fills the title slot rather than the body slot
+    pub fn is_title(&self) -> bool {
+        matches!(self, PlaceholderType::Title | PlaceholderType::CenteredTitle)
+    }
+
+    /// Whether a layout placeholder of `ph_type` fills the same slot
+    pub fn matches(&self, ph_type: &str) -> bool {
+        if self.is_title() {
+            matches!(ph_type, "title" | "ctrTitle")
+        } else {
+            matches!(ph_type, "subTitle" | "body")
+        }
+    }
+
+    /// Standard layout that defines this placeholder, for its fallback position
+    pub(crate) fn home_layout(&self) -> SlideLayout {
+        match self {
+            PlaceholderType::CenteredTitle | PlaceholderType::Subtitle => SlideLayout::CenteredTitle,
+            PlaceholderType::Title | PlaceholderType::Body => SlideLayout::TitleAndContent,
+        }
+    }
+}
+
+/// Copy of `content` with its first image scaled and centered in the picture area
+pub(crate) fn place_caption_picture(content: &SlideContent) -> SlideContent {
+    let mut placed = content.clone();
+    if let Some(image) = placed.images.first_mut() {
+        let (x, y, cx, cy) = PICTURE_BOX;
+        let scale = (cx as f64 / image.width.max(1) as f64).min(cy as f64 / image.height.max(1) as f64);
+        image.width = (image.width as f64 * scale) as u32;
+        image.height = (image.height as f64 * scale) as u32;
+        image.x = x + (cx - image.width) / 2;
+        image.y = y + (cy - image.height) / 2;
+    }
+    placed
 }
@@ -5,14 +5,18 @@
 //! - `BulletPoint` - Individual bullet point with formatting
 //! - `BulletTextFormat` - Text formatting for bullet points
 //! - `SlideLayout` - Layout types (title only, title and content, etc.)
+//! - `PlaceholderType` - Layout placeholders a slide can fill with text
 //! - `SlideContent` - Complete slide content builder
 //! - `CodeBlock` - Code block with syntax highlighting
+//! - `CodeTheme` - Color theme for highlighted code
 
 mod bullet;
 mod layout;
 mod code_block;
+pub mod code_highlight;
 mod content;
 pub mod transition;
+pub mod animation;
 pub mod comments;
 pub mod sections;
 pub mod digital_signature;
@@ -22,12 +26,22 @@ pub mod print_settings;
 pub mod table_merge;
 pub mod embedded_fonts;
 pub mod presentation_settings;
+pub mod background;
+pub mod header_footer;
+pub mod package_compression;
+pub mod raw_xml;
 
 pub use bullet::{BulletStyle, BulletPoint, BulletTextFormat};
-pub use layout::SlideLayout;
+pub use layout::{PlaceholderType, SlideLayout};
+pub(crate) use layout::{
+    place_caption_picture, CAPTION_BOX, CAPTION_TITLE_BOX, CENTERED_TITLE_BOX, COMPARISON_CONTENT_BOXES,
+    COMPARISON_HEADING_BOXES, CONTENT_BOX, SECTION_TITLE_BOX, TITLE_BOX,
+};
 pub use code_block::CodeBlock;
+pub use code_highlight::CodeTheme;
 pub use content::SlideContent;
-pub use transition::TransitionType;
+pub use transition::{TransitionType, TransitionDirection, SlideTransition};
+pub use animation::{Animation, AnimationEffect, AnimationTrigger, SlideAnimation};
 pub use comments::{Comment, CommentAuthor, CommentAuthorList, SlideComments};
 pub use sections::{SlideSection, SectionManager};
 pub use digital_signature::{DigitalSignature, SignerInfo, HashAlgorithm, SignatureCommitment};
@@ -37,4 +51,8 @@ pub use print_settings::{PrintSettings, HandoutLayout, PrintColorMode, PrintWhat
 pub use table_merge::{TableMergeMap, MergeRegion, CellMergeState};
 pub use embedded_fonts::{EmbeddedFontList, EmbeddedFont, FontStyle, FontCharset};
 pub use presentation_settings::PresentationSettings;
+pub use background::SlideBackground;
+pub use header_footer::{HeaderFooter, DateMode, PageHeaderFooter};
+pub use package_compression::Compression;
+pub use raw_xml::RawRelationship;
 
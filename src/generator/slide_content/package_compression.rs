@@ -0,0 +1,79 @@
+//! ZIP compression applied to the parts of a generated package
+//!
+//! XML parts shrink well under Deflate; pictures and clips are usually compressed
+//! already, so they can be given a cheaper setting of their own.
+
+use zip::write::FileOptions;
+use zip::CompressionMethod;
+
+/// How a group of package parts is compressed
+#[derive(Clone, Debug, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Compression {
+    /// No compression; cheapest to write, largest output
+    Stored,
+    /// Deflate at level 1
+    Fast,
+    /// Deflate at the `zip` crate's default level
+    #[default]
+    Default,
+    /// Deflate at level 9; slowest to write, smallest output
+    Best,
+}
+
+impl Compression {
+    /// `options` with this compression method and level
+    pub(crate) fn apply(self, options: FileOptions) -> FileOptions {
+        match self {
+            Compression::Stored => options.compression_method(CompressionMethod::Stored).compression_level(None),
+            Compression::Fast => options.compression_method(CompressionMethod::Deflated).compression_level(Some(1)),
+            Compression::Default => options.compression_method(CompressionMethod::Deflated).compression_level(None),
+            Compression::Best => options.compression_method(CompressionMethod::Deflated).compression_level(Some(9)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{Cursor, Write};
+
+    fn compressed_size(compression: Compression, data: &[u8]) -> (CompressionMethod, u64) {
+        let mut zip = zip::ZipWriter::new(Cursor::new(Vec::new()));
+        zip.start_file("part.xml", compression.apply(FileOptions::default())).unwrap();
+        zip.write_all(data).unwrap();
+        let bytes = zip.finish().unwrap().into_inner();
+        let mut archive = zip::ZipArchive::new(Cursor::new(bytes)).unwrap();
+        let entry = archive.by_index(0).unwrap();
+        (entry.compression(), entry.compressed_size())
+    }
+
+    #[test]
+    fn test_methods_and_levels() {
+        let data = "<a:t>quarterly numbers</a:t>".repeat(500);
+        let (stored, stored_size) = compressed_size(Compression::Stored, data.as_bytes());
+        assert_eq!((stored, stored_size), (CompressionMethod::Stored, data.len() as u64));
+        for compression in [Compression::Fast, Compression::Default, Compression::Best] {
+            let (method, size) = compressed_size(compression, data.as_bytes());
+            assert_eq!(method, CompressionMethod::Deflated);
+            assert!(size < stored_size / 10, "{compression:?}: {size}");
+        }
+    }
+
+    #[test]
+    fn test_default_matches_zip_defaults() {
+        let data = b"<p:sld/>".repeat(100);
+        assert_eq!(
+            compressed_size(Compression::default(), &data),
+            {
+                let mut zip = zip::ZipWriter::new(Cursor::new(Vec::new()));
+                zip.start_file("part.xml", FileOptions::default()).unwrap();
+                zip.write_all(&data).unwrap();
+                let bytes = zip.finish().unwrap().into_inner();
+                let mut archive = zip::ZipArchive::new(Cursor::new(bytes)).unwrap();
+                let entry = archive.by_index(0).unwrap();
+                (entry.compression(), entry.compressed_size())
+            }
+        );
+    }
+}
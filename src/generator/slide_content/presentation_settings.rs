@@ -7,14 +7,22 @@ use super::slide_show_settings::SlideShowSettings;
 use super::print_settings::PrintSettings;
 use super::embedded_fonts::EmbeddedFontList;
 use super::digital_signature::DigitalSignature;
+use super::header_footer::{DateMode, HeaderFooter, PageHeaderFooter};
+use super::sections::SectionManager;
+use super::package_compression::Compression;
+use crate::core::SlideSize;
+use crate::exc::{messages, PptxError, Result};
+use crate::generator::media::{Audio, AudioOptions};
 use crate::generator::PresentationTheme;
+use crate::generator::slide_master::SlideMaster;
 
 /// Presentation-level settings for the PPTX package
 #[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PresentationSettings {
     /// Color and font theme (`ppt/theme/theme1.xml`)
     pub theme: Option<PresentationTheme>,
-    /// Slide show settings (generates `<p:showPr>` in presentation.xml)
+    /// Slide show settings (generates `<p:showPr>` in presProps.xml)
     pub slide_show: Option<SlideShowSettings>,
     /// Print settings (generates `<p:prnPr>` in presentation.xml)
     pub print: Option<PrintSettings>,
@@ -24,6 +32,38 @@ pub struct PresentationSettings {
     pub digital_signature: Option<DigitalSignature>,
     /// Optional `.pptx` template path — clones masters/theme/layouts from an existing deck.
     pub template_path: Option<String>,
+    /// Slide dimensions (generates `<p:sldSz>` in presentation.xml)
+    pub slide_size: Option<SlideSize>,
+    /// Mark data-derived runs (numbers, codes) with `noProof="1"` so PowerPoint skips proofing them
+    pub no_proof_data_runs: bool,
+    /// Footer, date, and slide-number placeholders added to every slide
+    pub header_footer: Option<HeaderFooter>,
+    /// Slide sections (generates `<p14:sectionLst>` in presentation.xml)
+    pub sections: Option<SectionManager>,
+    /// Soundtrack embedded on the first slide and started with the show
+    pub background_audio: Option<Audio>,
+    /// Store a separate copy of repeated images and clips on each slide instead of
+    /// sharing one `ppt/media/` part per unique blob
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub keep_duplicate_media: bool,
+    /// Creation time written to `docProps/core.xml` (`YYYY-MM-DDThh:mm:ssZ`)
+    pub created_at: Option<String>,
+    /// Last-modified time written to `docProps/core.xml` and used for every ZIP entry
+    pub modified_at: Option<String>,
+    /// ZIP compression for XML and other parts (Deflate at the default level when unset)
+    pub compression: Option<Compression>,
+    /// ZIP compression for `ppt/media/` pictures and clips (follows `compression` when unset)
+    pub media_compression: Option<Compression>,
+    /// Header and footer on printed handouts (packages `ppt/handoutMasters/handoutMaster1.xml`)
+    pub handout_header_footer: Option<PageHeaderFooter>,
+    /// Header and footer on notes pages (packages `ppt/notesMasters/notesMaster1.xml`)
+    pub notes_header_footer: Option<PageHeaderFooter>,
+    /// Custom slide master background, text styles and placeholder frames (ignored with a template)
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub slide_master: Option<SlideMaster>,
+    /// Further slide masters, numbered from 2 for [`SlideContent::use_master`](crate::generator::SlideContent::use_master)
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub extra_masters: Vec<SlideMaster>,
 }
 
 impl PresentationSettings {
@@ -36,6 +76,22 @@ impl PresentationSettings {
         self
     }
 
+    pub fn slide_master(mut self, master: SlideMaster) -> Self {
+        self.slide_master = Some(master);
+        self
+    }
+
+    /// Add another slide master with its own layouts and theme; the first one added is master 2
+    pub fn add_master(mut self, master: SlideMaster) -> Self {
+        self.extra_masters.push(master);
+        self
+    }
+
+    /// Number of slide masters in the package, the primary one included
+    pub fn master_count(&self) -> usize {
+        1 + self.extra_masters.len()
+    }
+
     pub fn slide_show(mut self, settings: SlideShowSettings) -> Self {
         self.slide_show = Some(settings);
         self
@@ -61,6 +117,130 @@ impl PresentationSettings {
         self
     }
 
+    pub fn slide_size(mut self, size: impl Into<SlideSize>) -> Self {
+        self.slide_size = Some(size.into());
+        self
+    }
+
+    pub fn no_proof_data_runs(mut self, enabled: bool) -> Self {
+        self.no_proof_data_runs = enabled;
+        self
+    }
+
+    /// Share one `ppt/media/` part between identical images and clips (the default);
+    /// `false` gives every slide its own copy
+    pub fn dedup_media(mut self, enabled: bool) -> Self {
+        self.keep_duplicate_media = !enabled;
+        self
+    }
+
+    /// Fix the creation time instead of using the time of building; fails unless
+    /// `timestamp` is a UTC time like `2024-01-31T09:00:00Z` between 1980 and 2107
+    pub fn created_at(mut self, timestamp: &str) -> Result<Self> {
+        self.created_at = Some(checked_timestamp("created_at", timestamp)?);
+        Ok(self)
+    }
+
+    /// Fix the modification time, which also stamps every ZIP entry, so identical
+    /// input builds to identical bytes. Same format as [`Self::created_at`].
+    pub fn modified_at(mut self, timestamp: &str) -> Result<Self> {
+        self.modified_at = Some(checked_timestamp("modified_at", timestamp)?);
+        Ok(self)
+    }
+
+    /// Compress every part with `compression`, unless media has its own setting
+    pub fn compression(mut self, compression: Compression) -> Self {
+        self.compression = Some(compression);
+        self
+    }
+
+    /// Compress `ppt/media/` pictures and clips with `compression`, e.g. `Stored`
+    /// to skip re-deflating JPEG/PNG data
+    pub fn media_compression(mut self, compression: Compression) -> Self {
+        self.media_compression = Some(compression);
+        self
+    }
+
+    pub fn header_footer(mut self, header_footer: HeaderFooter) -> Self {
+        self.header_footer = Some(header_footer);
+        self
+    }
+
+    /// Show `text` in the footer of every slide
+    pub fn footer(mut self, text: &str) -> Self {
+        self.header_footer = Some(self.header_footer.take().unwrap_or_default().footer(text));
+        self
+    }
+
+    /// Show the slide number on every slide
+    pub fn show_slide_numbers(mut self, show: bool) -> Self {
+        self.header_footer = Some(self.header_footer.take().unwrap_or_default().slide_numbers(show));
+        self
+    }
+
+    /// Show a date on every slide
+    pub fn show_date(mut self, mode: DateMode) -> Self {
+        self.header_footer = Some(self.header_footer.take().unwrap_or_default().date(mode));
+        self
+    }
+
+    pub fn handout_header_footer(mut self, header_footer: PageHeaderFooter) -> Self {
+        self.handout_header_footer = Some(header_footer);
+        self
+    }
+
+    /// Show `text` at the top of every printed handout page
+    pub fn handout_header(mut self, text: &str) -> Self {
+        self.handout_header_footer = Some(self.handout_header_footer.take().unwrap_or_default().header(text));
+        self
+    }
+
+    /// Show `text` at the bottom of every printed handout page
+    pub fn handout_footer(mut self, text: &str) -> Self {
+        self.handout_header_footer = Some(self.handout_header_footer.take().unwrap_or_default().footer(text));
+        self
+    }
+
+    pub fn notes_header_footer(mut self, header_footer: PageHeaderFooter) -> Self {
+        self.notes_header_footer = Some(header_footer);
+        self
+    }
+
+    /// Show `text` at the top of every notes page
+    pub fn notes_header(mut self, text: &str) -> Self {
+        self.notes_header_footer = Some(self.notes_header_footer.take().unwrap_or_default().header(text));
+        self
+    }
+
+    /// Show `text` at the bottom of every notes page
+    pub fn notes_footer(mut self, text: &str) -> Self {
+        self.notes_header_footer = Some(self.notes_header_footer.take().unwrap_or_default().footer(text));
+        self
+    }
+
+    pub fn sections(mut self, sections: SectionManager) -> Self {
+        self.sections = Some(sections);
+        self
+    }
+
+    /// Start a section covering the 0-based `slides`; fails if it overlaps another section
+    pub fn add_section(mut self, name: &str, slides: std::ops::Range<usize>) -> Result<Self> {
+        let mut sections = self.sections.take().unwrap_or_default();
+        sections
+            .add_section(name, slides.start, slides.len())
+            .map_err(PptxError::InvalidValue)?;
+        self.sections = Some(sections);
+        Ok(self)
+    }
+
+    /// Play `audio` from the first slide; with `play_across_slides` it keeps going
+    /// as the show advances instead of stopping at the next slide.
+    pub fn background_audio(mut self, audio: Audio, options: AudioOptions) -> Self {
+        let options = AudioOptions { auto_play: true, ..options };
+        self.background_audio = Some(audio.with_options(options));
+        self
+    }
+
     /// Check if any presentation-level settings are configured
     pub fn has_settings(&self) -> bool {
         self.theme.is_some()
@@ -69,6 +249,30 @@ impl PresentationSettings {
             || self.embedded_fonts.is_some()
             || self.digital_signature.is_some()
             || self.template_path.is_some()
+            || self.slide_size.is_some()
+            || self.no_proof_data_runs
+            || self.header_footer.is_some()
+            || self.sections.is_some()
+            || self.background_audio.is_some()
+            || self.keep_duplicate_media
+            || self.created_at.is_some()
+            || self.modified_at.is_some()
+            || self.compression.is_some()
+            || self.media_compression.is_some()
+            || self.handout_header_footer.is_some()
+            || self.notes_header_footer.is_some()
+            || self.slide_master.is_some()
+            || !self.extra_masters.is_empty()
+    }
+}
+
+fn checked_timestamp(field: &str, timestamp: &str) -> Result<String> {
+    match crate::generator::props_xml::zip_time(timestamp) {
+        Some(_) => Ok(timestamp.to_string()),
+        None => Err(PptxError::InvalidValue(messages::invalid_value(
+            field,
+            &format!("'{timestamp}' is not a UTC time like 2024-01-31T09:00:00Z between 1980 and 2107"),
+        ))),
     }
 }
 
@@ -105,6 +309,111 @@ mod tests {
         assert!(settings.print.is_some());
     }
 
+    #[test]
+    fn test_with_slide_size() {
+        let settings = PresentationSettings::new()
+            .slide_size(crate::core::SlideSizePreset::Screen16x9);
+        assert!(settings.has_settings());
+        assert_eq!(settings.slide_size.map(|s| s.height), Some(5143500));
+    }
+
+    #[test]
+    fn test_dedup_media() {
+        let settings = PresentationSettings::new().dedup_media(false);
+        assert!(settings.has_settings());
+        assert!(settings.keep_duplicate_media);
+        assert!(!settings.dedup_media(true).has_settings());
+    }
+
+    #[test]
+    fn test_fixed_timestamps() {
+        let settings = PresentationSettings::new()
+            .created_at("2024-01-31T09:00:00Z")
+            .unwrap()
+            .modified_at("2024-02-01T10:30:00Z")
+            .unwrap();
+        assert!(settings.has_settings());
+        assert_eq!(settings.created_at.as_deref(), Some("2024-01-31T09:00:00Z"));
+        let err = PresentationSettings::new().modified_at("yesterday").unwrap_err();
+        assert!(err.to_string().contains("modified_at"), "{err}");
+    }
+
+    #[test]
+    fn test_media_compression_is_separate() {
+        let settings = PresentationSettings::new()
+            .compression(Compression::Best)
+            .media_compression(Compression::Stored);
+        assert!(settings.has_settings());
+        assert_eq!(settings.compression, Some(Compression::Best));
+        assert_eq!(settings.media_compression, Some(Compression::Stored));
+    }
+
+    #[test]
+    fn test_with_slide_master() {
+        let settings = PresentationSettings::new().slide_master(SlideMaster::default());
+        assert!(settings.has_settings());
+        assert!(settings.slide_master.is_some());
+    }
+
+    #[test]
+    fn test_with_extra_master() {
+        let settings = PresentationSettings::new().add_master(SlideMaster::default());
+        assert!(settings.has_settings());
+        assert_eq!(settings.master_count(), 2);
+    }
+
+    #[test]
+    fn test_handout_and_notes_header_footer() {
+        let settings = PresentationSettings::new()
+            .handout_header("Course 101")
+            .handout_footer("Week 3")
+            .notes_footer("Speaker copy");
+        assert!(settings.has_settings());
+        let handout = settings.handout_header_footer.as_ref().unwrap();
+        assert_eq!((handout.header.as_deref(), handout.footer.as_deref()), (Some("Course 101"), Some("Week 3")));
+        assert_eq!(settings.notes_header_footer.unwrap().footer.as_deref(), Some("Speaker copy"));
+    }
+
+    #[test]
+    fn test_with_no_proof_data_runs() {
+        let settings = PresentationSettings::new().no_proof_data_runs(true);
+        assert!(settings.has_settings());
+        assert!(settings.no_proof_data_runs);
+    }
+
+    #[test]
+    fn test_with_header_footer() {
+        let settings = PresentationSettings::new()
+            .footer("Confidential — 2024")
+            .show_slide_numbers(true)
+            .show_date(DateMode::Auto);
+        assert!(settings.has_settings());
+        let hf = settings.header_footer.unwrap();
+        assert_eq!(hf.footer.as_deref(), Some("Confidential — 2024"));
+        assert!(hf.slide_numbers);
+        assert_eq!(hf.date, Some(DateMode::Auto));
+    }
+
+    #[test]
+    fn test_add_section() {
+        let settings = PresentationSettings::new()
+            .add_section("Intro", 0..3).unwrap()
+            .add_section("Body", 3..10).unwrap();
+        assert!(settings.has_settings());
+        assert_eq!(settings.sections.as_ref().map(|s| s.len()), Some(2));
+        assert!(settings.add_section("Overlap", 5..6).is_err());
+    }
+
+    #[test]
+    fn test_with_background_audio() {
+        let audio = Audio::from_bytes(vec![1, 2], crate::generator::AudioFormat::Mp3, 0, 0, 1, 1);
+        let settings = PresentationSettings::new()
+            .background_audio(audio, AudioOptions::default().with_play_across_slides(true).hide_icon(true));
+        assert!(settings.has_settings());
+        let options = &settings.background_audio.unwrap().options;
+        assert!(options.auto_play && options.play_across_slides && options.hide_during_show);
+    }
+
     #[test]
     fn test_with_embedded_fonts() {
         let settings = PresentationSettings::new()
@@ -3,8 +3,11 @@
 //! Controls print layout, handout options, and page setup.
 //! Generates `<p:prnPr>` and handout master XML.
 
+use super::header_footer::{DateMode, PageHeaderFooter};
+
 /// Handout layout (slides per page)
 #[derive(Clone, Debug, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum HandoutLayout {
     SlidesPerPage1,
     SlidesPerPage2,
@@ -29,18 +32,19 @@ impl HandoutLayout {
 
     pub fn to_xml_value(&self) -> &'static str {
         match self {
-            HandoutLayout::SlidesPerPage1 => "handout1",
-            HandoutLayout::SlidesPerPage2 => "handout2",
-            HandoutLayout::SlidesPerPage3 => "handout3",
-            HandoutLayout::SlidesPerPage4 => "handout4",
-            HandoutLayout::SlidesPerPage6 => "handout6",
-            HandoutLayout::SlidesPerPage9 => "handout9",
+            HandoutLayout::SlidesPerPage1 => "handouts1",
+            HandoutLayout::SlidesPerPage2 => "handouts2",
+            HandoutLayout::SlidesPerPage3 => "handouts3",
+            HandoutLayout::SlidesPerPage4 => "handouts4",
+            HandoutLayout::SlidesPerPage6 => "handouts6",
+            HandoutLayout::SlidesPerPage9 => "handouts9",
         }
     }
 }
 
 /// Print color mode
 #[derive(Clone, Debug, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PrintColorMode {
     #[default]
     Color,
@@ -60,6 +64,7 @@ impl PrintColorMode {
 
 /// What to print
 #[derive(Clone, Debug, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PrintWhat {
     #[default]
     Slides,
@@ -69,6 +74,7 @@ pub enum PrintWhat {
 }
 
 impl PrintWhat {
+    /// `prnWhat` value; for `Handouts` the layout is appended, see [`PrintSettings::prn_what_value`]
     pub fn to_xml_value(&self) -> &'static str {
         match self {
             PrintWhat::Slides => "slides",
@@ -81,6 +87,7 @@ impl PrintWhat {
 
 /// Page orientation
 #[derive(Clone, Debug, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Orientation {
     #[default]
     Landscape,
@@ -98,6 +105,7 @@ impl Orientation {
 
 /// Print settings for the presentation
 #[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PrintSettings {
     pub print_what: PrintWhat,
     pub color_mode: PrintColorMode,
@@ -175,10 +183,18 @@ impl PrintSettings {
         self
     }
 
+    /// Value of `prnWhat`: handouts carry their slides-per-page layout (`handouts6`)
+    pub fn prn_what_value(&self) -> &'static str {
+        match self.print_what {
+            PrintWhat::Handouts => self.handout_layout.to_xml_value(),
+            other => other.to_xml_value(),
+        }
+    }
+
     /// Generate `<p:prnPr>` XML for presProps.xml
     pub fn to_prnpr_xml(&self) -> String {
         let mut attrs = Vec::new();
-        attrs.push(format!(r#"prnWhat="{}""#, self.print_what.to_xml_value()));
+        attrs.push(format!(r#"prnWhat="{}""#, self.prn_what_value()));
         attrs.push(format!(r#"clrMode="{}""#, self.color_mode.to_xml_value()));
 
         if self.frame_slides {
@@ -194,49 +210,20 @@ impl PrintSettings {
         format!(r#"<p:prnPr {}/>"#, attrs.join(" "))
     }
 
-    /// Generate handout master XML (PowerPoint-compatible structure)
-    pub fn to_handout_master_xml(&self) -> String {
-        let mut xml = String::from(
-            r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?><p:handoutMaster xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships" xmlns:p="http://schemas.openxmlformats.org/presentationml/2006/main"><p:cSld><p:bg><p:bgRef idx="1001"><a:schemeClr val="bg1"/></p:bgRef></p:bg><p:spTree><p:nvGrpSpPr><p:cNvPr id="1" name=""/><p:cNvGrpSpPr/><p:nvPr/></p:nvGrpSpPr><p:grpSpPr><a:xfrm><a:off x="0" y="0"/><a:ext cx="0" cy="0"/><a:chOff x="0" y="0"/><a:chExt cx="0" cy="0"/></a:xfrm></p:grpSpPr>"#,
-        );
-
-        if let Some(ref header) = self.header {
-            xml.push_str(&format!(
-                r#"<p:sp><p:nvSpPr><p:cNvPr id="2" name="Header"/><p:cNvSpPr><a:spLocks noGrp="1"/></p:cNvSpPr><p:nvPr><p:ph type="hdr"/></p:nvPr></p:nvSpPr><p:spPr/><p:txBody><a:bodyPr/><a:lstStyle/><a:p><a:r><a:t>{}</a:t></a:r></a:p></p:txBody></p:sp>"#,
-                xml_escape(header)
-            ));
-        }
-
-        if let Some(ref footer) = self.footer {
-            xml.push_str(&format!(
-                r#"<p:sp><p:nvSpPr><p:cNvPr id="3" name="Footer"/><p:cNvSpPr><a:spLocks noGrp="1"/></p:cNvSpPr><p:nvPr><p:ph type="ftr"/></p:nvPr></p:nvSpPr><p:spPr/><p:txBody><a:bodyPr/><a:lstStyle/><a:p><a:r><a:t>{}</a:t></a:r></a:p></p:txBody></p:sp>"#,
-                xml_escape(footer)
-            ));
-        }
-
-        if self.print_date {
-            xml.push_str(
-                r#"<p:sp><p:nvSpPr><p:cNvPr id="4" name="Date"/><p:cNvSpPr><a:spLocks noGrp="1"/></p:cNvSpPr><p:nvPr><p:ph type="dt"/></p:nvPr></p:nvSpPr><p:spPr/><p:txBody><a:bodyPr/><a:lstStyle/><a:p><a:fld id="{B6F15528-F159-4107-2D14-000000000000}" type="datetimeFigureOut"><a:t/></a:fld><a:endParaRPr/></a:p></p:txBody></p:sp>"#,
-            );
-        }
-
-        if self.print_page_numbers {
-            xml.push_str(
-                r#"<p:sp><p:nvSpPr><p:cNvPr id="5" name="Slide Number"/><p:cNvSpPr><a:spLocks noGrp="1"/></p:cNvSpPr><p:nvPr><p:ph type="sldNum"/></p:nvPr></p:nvSpPr><p:spPr/><p:txBody><a:bodyPr/><a:lstStyle/><a:p><a:fld id="{B6F15528-F159-4107-2D14-000000000001}" type="slidenum"><a:t>‹#›</a:t></a:fld><a:endParaRPr/></a:p></p:txBody></p:sp>"#,
-            );
+    /// Header, footer, date and page number shown on printed handouts
+    pub fn page_header_footer(&self) -> PageHeaderFooter {
+        PageHeaderFooter {
+            header: self.header.clone(),
+            footer: self.footer.clone(),
+            date: self.print_date.then_some(DateMode::Auto),
+            page_numbers: self.print_page_numbers,
         }
-
-        xml.push_str(r#"</p:spTree></p:cSld><p:clrMap bg1="lt1" tx1="dk1" bg2="lt2" tx2="dk2" accent1="accent1" accent2="accent2" accent3="accent3" accent4="accent4" accent5="accent5" accent6="accent6" hlink="hlink" folHlink="folHlink"/></p:handoutMaster>"#);
-        xml
     }
-}
 
-fn xml_escape(s: &str) -> String {
-    s.replace('&', "&amp;")
-        .replace('<', "&lt;")
-        .replace('>', "&gt;")
-        .replace('"', "&quot;")
-        .replace('\'', "&apos;")
+    /// Generate handout master XML (PowerPoint-compatible structure)
+    pub fn to_handout_master_xml(&self) -> String {
+        self.page_header_footer().handout_master_xml()
+    }
 }
 
 #[cfg(test)]
@@ -260,8 +247,8 @@ mod tests {
 
     #[test]
     fn test_handout_layout_xml() {
-        assert_eq!(HandoutLayout::SlidesPerPage1.to_xml_value(), "handout1");
-        assert_eq!(HandoutLayout::SlidesPerPage6.to_xml_value(), "handout6");
+        assert_eq!(HandoutLayout::SlidesPerPage1.to_xml_value(), "handouts1");
+        assert_eq!(HandoutLayout::SlidesPerPage6.to_xml_value(), "handouts6");
     }
 
     #[test]
@@ -339,7 +326,7 @@ mod tests {
             .color_mode(PrintColorMode::BlackAndWhite)
             .frame_slides(true);
         let xml = s.to_prnpr_xml();
-        assert!(xml.contains(r#"prnWhat="handouts""#));
+        assert!(xml.contains(r#"prnWhat="handouts3""#));
         assert!(xml.contains(r#"clrMode="bw""#));
         assert!(xml.contains("frameSlides=\"1\""));
     }
@@ -398,4 +385,14 @@ mod tests {
         let xml = s.to_handout_master_xml();
         assert!(xml.contains("Q&amp;A &lt;Report&gt;"));
     }
+
+    #[test]
+    fn test_prnpr_xml_six_per_page_grayscale() {
+        let xml = PrintSettings::new()
+            .print_what(PrintWhat::Handouts)
+            .handout_layout(HandoutLayout::SlidesPerPage6)
+            .color_mode(PrintColorMode::Grayscale)
+            .to_prnpr_xml();
+        assert_eq!(xml, r#"<p:prnPr prnWhat="handouts6" clrMode="gray" scaleToFitPaper="1"/>"#);
+    }
 }
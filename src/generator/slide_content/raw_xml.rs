@@ -0,0 +1,202 @@
+//! Raw XML escape hatches for shapes and relationships the builder does not model
+//!
+//! Fragments are checked for well-formedness when added and spliced into the
+//! slide's `<p:spTree>` verbatim, except that their `cNvPr` ids are renumbered
+//! past the ids the generated shapes already use.
+
+use std::collections::HashMap;
+use xml::reader::{EventReader, XmlEvent};
+use crate::exc::{messages, PptxError, Result};
+
+/// Namespaces declared on `<p:sld>`, available to raw fragments without redeclaring them
+const FRAGMENT_ROOT_OPEN: &str = r#"<raw xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships" xmlns:p="http://schemas.openxmlformats.org/presentationml/2006/main">"#;
+
+/// A relationship added to the slide's `.rels` part as-is
+///
+/// Ids are `rIdRaw1`, `rIdRaw2`, ... in the order the relationships were added,
+/// so raw shape XML can reference them with `r:id`/`r:embed`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RawRelationship {
+    /// Full relationship type URI
+    pub rel_type: String,
+    /// Target path relative to the slide part, or an external URL
+    pub target: String,
+}
+
+impl RawRelationship {
+    pub fn new(rel_type: &str, target: &str) -> Self {
+        RawRelationship { rel_type: rel_type.to_string(), target: target.to_string() }
+    }
+
+    /// Relationship id for the relationship at `index` (0-based) on its slide
+    pub fn rel_id(index: usize) -> String {
+        format!("rIdRaw{}", index + 1)
+    }
+
+    /// Whether the target points outside the package
+    pub fn is_external(&self) -> bool {
+        self.target.contains("://") || self.target.starts_with("mailto:")
+    }
+
+    /// Generate the `<Relationship>` element for this relationship
+    pub fn to_xml(&self, index: usize) -> String {
+        let mode = if self.is_external() { r#" TargetMode="External""# } else { "" };
+        format!(
+            r#"<Relationship Id="{}" Type="{}" Target="{}"{mode}/>"#,
+            Self::rel_id(index),
+            escape_attr(&self.rel_type),
+            escape_attr(&self.target),
+        )
+    }
+}
+
+fn escape_attr(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Check that a shape fragment is well-formed XML under the slide's namespaces
+pub(crate) fn check_fragment(xml: &str) -> Result<()> {
+    if xml.trim().is_empty() {
+        return Err(PptxError::InvalidXml(messages::empty_xml_content()));
+    }
+    let wrapped = format!("{FRAGMENT_ROOT_OPEN}{xml}</raw>");
+    let mut depth = 0usize;
+    let mut has_element = false;
+    for event in EventReader::new(wrapped.as_bytes()) {
+        match event {
+            Ok(XmlEvent::StartElement { name, .. }) => {
+                if let Some(prefix) = name.prefix.as_deref()
+                    && name.namespace.is_none()
+                {
+                    let detail = format!("undeclared namespace prefix '{prefix}'");
+                    return Err(PptxError::InvalidXml(messages::invalid_xml("raw shape", &detail)));
+                }
+                has_element |= depth > 0;
+                depth += 1;
+            }
+            Ok(XmlEvent::EndElement { .. }) => depth -= 1,
+            // Bare text can't sit between shapes in the shape tree
+            Ok(XmlEvent::Characters(_)) if depth == 1 => {
+                return Err(PptxError::InvalidXml(messages::invalid_xml("raw shape", "text outside an element")));
+            }
+            Ok(_) => {}
+            Err(e) => return Err(PptxError::InvalidXml(messages::invalid_xml("raw shape", &e.to_string()))),
+        }
+    }
+    if has_element {
+        Ok(())
+    } else {
+        Err(PptxError::InvalidXml(messages::invalid_xml("raw shape", "fragment contains no elements")))
+    }
+}
+
+/// Renumber each `cNvPr` id in `fragment` from `next_id` upward
+///
+/// Connection references (`stCxn`/`endCxn`) to renumbered shapes follow their shape.
+/// Returns the fragment and the next unused id.
+fn renumber_shape_ids(fragment: &str, mut next_id: u32) -> (String, u32) {
+    let mut ids: HashMap<String, u32> = HashMap::new();
+    let mut out = rewrite_id_attrs(fragment, "<p:cNvPr ", |old| {
+        let id = *ids.entry(old.to_string()).or_insert_with(|| {
+            next_id += 1;
+            next_id - 1
+        });
+        Some(id)
+    });
+    for tag in ["<a:stCxn ", "<a:endCxn "] {
+        out = rewrite_id_attrs(&out, tag, |old| ids.get(old).copied());
+    }
+    (out, next_id)
+}
+
+/// Rewrite the `id` attribute of every `tag` element; `None` leaves the value alone
+fn rewrite_id_attrs(xml: &str, tag: &str, mut new_id: impl FnMut(&str) -> Option<u32>) -> String {
+    let mut out = String::with_capacity(xml.len() + 16);
+    let mut rest = xml;
+    while let Some(start) = rest.find(tag) {
+        let Some(tag_len) = rest[start..].find('>') else { break };
+        let element = &rest[start..start + tag_len];
+        out.push_str(&rest[..start]);
+        match element.find(" id=\"").map(|pos| pos + " id=\"".len()) {
+            Some(value_start) => {
+                let value_len = element[value_start..].find('"').unwrap_or(0);
+                let old = &element[value_start..value_start + value_len];
+                match new_id(old) {
+                    Some(id) => {
+                        out.push_str(&element[..value_start]);
+                        out.push_str(&id.to_string());
+                        out.push_str(&element[value_start + value_len..]);
+                    }
+                    None => out.push_str(element),
+                }
+            }
+            None => out.push_str(element),
+        }
+        rest = &rest[start + tag_len..];
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Splice raw shape fragments into the end of the slide's shape tree
+pub(crate) fn append_raw_shapes(xml: &mut String, fragments: &[String]) {
+    if fragments.is_empty() {
+        return;
+    }
+    let Some(end) = xml.rfind("</p:spTree>") else {
+        return;
+    };
+    let mut next_id = super::header_footer::max_shape_id(xml) + 1;
+    let mut shapes = String::new();
+    for fragment in fragments {
+        let (renumbered, next) = renumber_shape_ids(fragment, next_id);
+        shapes.push_str(&renumbered);
+        next_id = next;
+    }
+    xml.insert_str(end, &shapes);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_fragment() {
+        assert!(check_fragment(r#"<p:sp><p:nvSpPr><p:cNvPr id="2" name="Raw"/></p:nvSpPr></p:sp>"#).is_ok());
+        assert!(check_fragment("<p:sp><a:off></p:sp>").is_err());
+        assert!(check_fragment("<x:sp/>").is_err());
+        assert!(check_fragment("just text").is_err());
+        assert!(check_fragment("  ").is_err());
+    }
+
+    #[test]
+    fn test_append_raw_shapes_renumbers_ids() {
+        let mut xml = r#"<p:spTree><p:cNvPr id="1" name=""/><p:cNvPr id="4" name="Title"/></p:spTree>"#.to_string();
+        let raw = vec![
+            r#"<p:sp><p:cNvPr id="2" name="A"/></p:sp><p:cxnSp><p:cNvPr name="C" id="3"/><a:stCxn id="2" idx="0"/><a:endCxn id="9" idx="0"/></p:cxnSp>"#.to_string(),
+            r#"<p:sp><p:cNvPr id="2" name="B"/></p:sp>"#.to_string(),
+        ];
+        append_raw_shapes(&mut xml, &raw);
+        assert!(xml.contains(r#"<p:cNvPr id="5" name="A"/>"#));
+        assert!(xml.contains(r#"<p:cNvPr name="C" id="6"/>"#));
+        assert!(xml.contains(r#"<a:stCxn id="5" idx="0"/>"#));
+        assert!(xml.contains(r#"<a:endCxn id="9" idx="0"/>"#));
+        assert!(xml.contains(r#"<p:cNvPr id="7" name="B"/>"#));
+        assert!(xml.ends_with("</p:sp></p:spTree>"));
+    }
+
+    #[test]
+    fn test_raw_relationship_xml() {
+        let link = RawRelationship::new("http://example.com/rel", "https://example.com/?a=1&b=2");
+        assert_eq!(
+            link.to_xml(0),
+            r#"<Relationship Id="rIdRaw1" Type="http://example.com/rel" Target="https://example.com/?a=1&amp;b=2" TargetMode="External"/>"#
+        );
+        assert!(!RawRelationship::new("t", "../media/image1.png").to_xml(1).contains("TargetMode"));
+    }
+}
@@ -3,8 +3,11 @@
 //! Provides section management for grouping slides into logical sections.
 //! Generates proper OOXML `<p:extLst>` section data in presentation.xml.
 
+use crate::generator::package_xml::slide_id_value;
+
 /// A section that groups consecutive slides
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SlideSection {
     pub name: String,
     pub first_slide: usize,
@@ -38,6 +41,7 @@ impl SlideSection {
 
 /// Manages sections across the presentation
 #[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SectionManager {
     sections: Vec<SlideSection>,
 }
@@ -120,33 +124,66 @@ impl SectionManager {
         if self.sections.is_empty() {
             return String::new();
         }
+        format!("<p:extLst>{}</p:extLst>", self.to_ext_xml(total_slides))
+    }
+
+    /// Generate the `<p:ext>` holding `<p14:sectionLst>`, without the `<p:extLst>` wrapper.
+    ///
+    /// PowerPoint requires every slide to belong to a section, so a section runs
+    /// until the next one starts, and slides before the first section land in a
+    /// leading "Default Section".
+    pub fn to_ext_xml(&self, total_slides: usize) -> String {
+        if self.sections.is_empty() {
+            return String::new();
+        }
 
         let mut xml = String::from(
-            r#"<p:extLst><p:ext uri="{521415D9-36F7-43E2-AB2F-B90AF26B5E84}"><p14:sectionLst xmlns:p14="http://schemas.microsoft.com/office/powerpoint/2010/main">"#,
+            r#"<p:ext uri="{521415D9-36F7-43E2-AB2F-B90AF26B5E84}"><p14:sectionLst xmlns:p14="http://schemas.microsoft.com/office/powerpoint/2010/main">"#,
         );
 
-        for section in &self.sections {
-            xml.push_str(&format!(
-                r#"<p14:section name="{}" id="{{{}}}">"#,
-                xml_escape(&section.name),
-                generate_section_id(&section.name),
-            ));
-            xml.push_str("<p14:sldIdLst>");
-            for i in 0..section.slide_count {
-                let slide_id = 256 + section.first_slide + i;
-                if section.first_slide + i < total_slides {
-                    xml.push_str(&format!(r#"<p14:sldId id="{}"/>"#, slide_id));
-                }
-            }
-            xml.push_str("</p14:sldIdLst>");
-            xml.push_str("</p14:section>");
+        let leading = self.sections[0].first_slide.min(total_slides);
+        if leading > 0 {
+            push_section_xml(&mut xml, 0, DEFAULT_SECTION_NAME, 0..leading);
+        }
+        let first_position = usize::from(leading > 0);
+
+        for (i, section) in self.sections.iter().enumerate() {
+            let start = section.first_slide.min(total_slides);
+            let end = if section.slide_count == 0 {
+                start
+            } else {
+                self.sections[i + 1..]
+                    .iter()
+                    .map(|next| next.first_slide)
+                    .find(|&first| first > section.first_slide)
+                    .unwrap_or(total_slides)
+                    .min(total_slides)
+            };
+            push_section_xml(&mut xml, first_position + i, &section.name, start..end);
         }
 
-        xml.push_str("</p14:sectionLst></p:ext></p:extLst>");
+        xml.push_str("</p14:sectionLst></p:ext>");
         xml
     }
 }
 
+/// Name PowerPoint gives slides that precede the first named section
+const DEFAULT_SECTION_NAME: &str = "Default Section";
+
+/// Append the `position`-th `<p14:section>`, listing the slides in `slides` (0-based)
+fn push_section_xml(xml: &mut String, position: usize, name: &str, slides: std::ops::Range<usize>) {
+    xml.push_str(&format!(
+        r#"<p14:section name="{}" id="{{{}}}">"#,
+        xml_escape(name),
+        generate_section_id(name, position),
+    ));
+    xml.push_str("<p14:sldIdLst>");
+    for index in slides {
+        xml.push_str(&format!(r#"<p14:sldId id="{}"/>"#, slide_id_value(index + 1)));
+    }
+    xml.push_str("</p14:sldIdLst></p14:section>");
+}
+
 /// Check if two sections overlap
 fn sections_overlap(a: &SlideSection, b: &SlideSection) -> bool {
     if a.slide_count == 0 || b.slide_count == 0 {
@@ -155,10 +192,11 @@ fn sections_overlap(a: &SlideSection, b: &SlideSection) -> bool {
     a.first_slide < b.first_slide + b.slide_count && b.first_slide < a.first_slide + a.slide_count
 }
 
-/// Generate a deterministic GUID-like ID from a section name
-fn generate_section_id(name: &str) -> String {
+/// Generate a deterministic GUID-like ID from a section's position and name,
+/// so sections sharing a name still get distinct ids
+fn generate_section_id(name: &str, position: usize) -> String {
     let mut hash: u64 = 0xcbf29ce484222325;
-    for byte in name.bytes() {
+    for byte in (position as u64).to_le_bytes().into_iter().chain(name.bytes()) {
         hash ^= byte as u64;
         hash = hash.wrapping_mul(0x100000001b3);
     }
@@ -331,6 +369,25 @@ mod tests {
         assert!(xml.contains(r#"id="257""#));
     }
 
+    #[test]
+    fn test_section_xml_covers_every_slide() {
+        let mut mgr = SectionManager::new();
+        mgr.add_section("Body", 2, 2).unwrap();
+        mgr.add_section("Wrap-up", 6, 1).unwrap();
+        let xml = mgr.to_ext_xml(8);
+
+        let default = xml.find(r#"name="Default Section""#).unwrap();
+        let body = xml.find(r#"name="Body""#).unwrap();
+        let wrap = xml.find(r#"name="Wrap-up""#).unwrap();
+        assert!(default < body && body < wrap);
+        assert!(xml[default..body].contains(r#"<p14:sldId id="256"/><p14:sldId id="257"/></p14:sldIdLst>"#));
+        // Gaps fold into the preceding section; trailing slides into the last
+        assert_eq!(xml[body..wrap].matches("<p14:sldId ").count(), 4);
+        assert!(xml[wrap..].contains(r#"<p14:sldId id="262"/><p14:sldId id="263"/></p14:sldIdLst>"#));
+        assert_eq!(xml.matches("<p14:sldId ").count(), 8);
+        assert!(!xml.contains("extLst"));
+    }
+
     #[test]
     fn test_sections_overlap_fn() {
         let a = SlideSection::new("A", 0, 3);
@@ -350,16 +407,17 @@ mod tests {
 
     #[test]
     fn test_generate_section_id_deterministic() {
-        let id1 = generate_section_id("Test");
-        let id2 = generate_section_id("Test");
+        let id1 = generate_section_id("Test", 0);
+        let id2 = generate_section_id("Test", 0);
         assert_eq!(id1, id2);
     }
 
     #[test]
     fn test_generate_section_id_unique() {
-        let id1 = generate_section_id("Intro");
-        let id2 = generate_section_id("Body");
+        let id1 = generate_section_id("Intro", 0);
+        let id2 = generate_section_id("Body", 0);
         assert_ne!(id1, id2);
+        assert_ne!(generate_section_id("Part", 0), generate_section_id("Part", 1));
     }
 
     #[test]
@@ -369,4 +427,16 @@ mod tests {
         let xml = mgr.to_xml(1);
         assert!(xml.contains("Q&amp;A &lt;Session&gt;"));
     }
+
+    #[test]
+    fn test_sections_with_same_name_get_distinct_ids() {
+        let mut mgr = SectionManager::new();
+        mgr.add_section("Part", 0, 1).unwrap();
+        mgr.add_section("Part", 1, 1).unwrap();
+        let xml = mgr.to_ext_xml(2);
+        let ids: Vec<&str> = xml.match_indices(r#"id="{"#).map(|(at, _)| &xml[at + 5..at + 41]).collect();
+        assert_eq!(ids.len(), 2);
+        assert_ne!(ids[0], ids[1]);
+        assert!(xml.contains(r#"<p14:sldId id="256"/>"#) && xml.contains(r#"<p14:sldId id="257"/>"#));
+    }
 }
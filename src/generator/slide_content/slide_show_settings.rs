@@ -1,10 +1,13 @@
 //! Slide show settings for presentations
 //!
 //! Controls how the presentation is displayed in slide show mode.
-//! Generates `<p:showPr>` XML in presentation.xml.
+//! Generates `<p:showPr>` XML in presProps.xml.
+
+use crate::elements::{color_value_xml, RgbInput};
 
 /// Slide show type
 #[derive(Clone, Debug, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ShowType {
     #[default]
     Speaker,
@@ -13,6 +16,9 @@ pub enum ShowType {
 }
 
 impl ShowType {
+    /// The `<p:showPr>` child selecting the show type: presented by a speaker
+    /// (`present`), browsed by an individual in a window (`browse`) or
+    /// browsed at a kiosk (`kiosk`, restarting after five idle minutes)
     pub fn to_xml_element(&self) -> &'static str {
         match self {
             ShowType::Speaker => r#"<p:present/>"#,
@@ -24,14 +30,15 @@ impl ShowType {
 
 /// Pen color used during slide show
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PenColor {
     pub color: String,
 }
 
 impl PenColor {
-    pub fn new(color: &str) -> Self {
+    pub fn new(color: impl Into<RgbInput>) -> Self {
         Self {
-            color: color.trim_start_matches('#').to_uppercase(),
+            color: color.into().into_value(),
         }
     }
 
@@ -48,15 +55,22 @@ impl Default for PenColor {
 
 /// Slide range for the show
 #[derive(Clone, Debug, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SlideRange {
     #[default]
     All,
+    /// Slide numbers `start..=end`, counting from 1
     Range { start: u32, end: u32 },
+    /// Slide ids (256 for the first slide) played as a custom show
     Custom(Vec<u32>),
 }
 
+/// Id of the custom show written for [`SlideRange::Custom`]
+const CUSTOM_SHOW_ID: u32 = 0;
+
 /// Slide show settings
 #[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SlideShowSettings {
     pub show_type: ShowType,
     pub loop_continuously: bool,
@@ -125,7 +139,7 @@ impl SlideShowSettings {
             .without_narration(true)
     }
 
-    /// Generate `<p:showPr>` XML for presentation.xml
+    /// Generate `<p:showPr>` XML for presProps.xml
     pub fn to_xml(&self) -> String {
         let mut attrs = Vec::new();
 
@@ -159,24 +173,19 @@ impl SlideShowSettings {
             SlideRange::Range { start, end } => {
                 xml.push_str(&format!(r#"<p:sldRg st="{}" end="{}"/>"#, start, end));
             }
-            SlideRange::Custom(slides) => {
-                xml.push_str("<p:custShow>");
-                for s in slides {
-                    xml.push_str(&format!(r#"<p:sldId id="{}"/>"#, s));
-                }
-                xml.push_str("</p:custShow>");
-            }
+            // The slides themselves are listed in presentation.xml's `<p:custShowLst>`
+            SlideRange::Custom(_) => xml.push_str(&format!(r#"<p:custShow id="{CUSTOM_SHOW_ID}"/>"#)),
         }
 
         // Pen color
         xml.push_str(&format!(
-            r#"<p:penClr><a:srgbClr val="{}"/></p:penClr>"#,
-            self.pen_color.color
+            "<p:penClr>{}</p:penClr>",
+            color_value_xml(&self.pen_color.color, "")
         ));
 
         xml.push_str(&format!(
-            r#"<p:extLst><p:ext uri="{{EC167BDD-8182-4AB7-AECC-EB403E3ABB37}}"><p14:laserClr xmlns:p14="http://schemas.microsoft.com/office/powerpoint/2010/main"><a:srgbClr val="{}"/></p14:laserClr></p:ext>"#,
-            self.pen_color.color
+            r#"<p:extLst><p:ext uri="{{EC167BDD-8182-4AB7-AECC-EB403E3ABB37}}"><p14:laserClr xmlns:p14="http://schemas.microsoft.com/office/powerpoint/2010/main">{}</p14:laserClr></p:ext>"#,
+            color_value_xml(&self.pen_color.color, "")
         ));
         if self.show_media_controls {
             xml.push_str(
@@ -188,6 +197,26 @@ impl SlideShowSettings {
         xml.push_str("</p:showPr>");
         xml
     }
+
+    /// `<p:custShowLst>` for presentation.xml defining the custom show a
+    /// [`SlideRange::Custom`] range plays, or `None` for other ranges.
+    ///
+    /// `slide_rel_id` maps a 1-based slide number to its relationship id;
+    /// ids that name no slide in the deck are skipped.
+    pub(crate) fn to_custom_show_list_xml(&self, slide_count: usize, slide_rel_id: impl Fn(usize) -> usize) -> Option<String> {
+        let SlideRange::Custom(ids) = &self.slide_range else {
+            return None;
+        };
+        let slides: String = ids
+            .iter()
+            .filter_map(|&id| id.checked_sub(255).map(|n| n as usize))
+            .filter(|n| (1..=slide_count).contains(n))
+            .map(|n| format!(r#"<p:sldId r:id="rId{}"/>"#, slide_rel_id(n)))
+            .collect();
+        Some(format!(
+            r#"<p:custShowLst><p:custShow name="Custom Show 1" id="{CUSTOM_SHOW_ID}"><p:sldLst>{slides}</p:sldLst></p:custShow></p:custShowLst>"#
+        ))
+    }
 }
 
 #[cfg(test)]
@@ -280,6 +309,19 @@ mod tests {
         assert!(xml.contains("<p:kiosk"));
     }
 
+    #[test]
+    fn test_xml_child_order() {
+        let xml = SlideShowSettings::kiosk()
+            .slide_range(SlideRange::Range { start: 2, end: 4 })
+            .pen_color(PenColor::blue())
+            .to_xml();
+        assert!(xml.starts_with(
+            r#"<p:showPr loop="1" showNarration="0"><p:kiosk restart="300000"/><p:sldRg st="2" end="4"/><p:penClr><a:srgbClr val="0000FF"/></p:penClr><p:extLst>"#
+        ));
+        let browse = SlideShowSettings::new().show_type(ShowType::Browsed).to_xml();
+        assert!(browse.starts_with(r#"<p:showPr><p:browse showScrollbar="1"/><p:sldAll/>"#));
+    }
+
     #[test]
     fn test_xml_slide_range() {
         let s = SlideShowSettings::new()
@@ -294,9 +336,14 @@ mod tests {
         let s = SlideShowSettings::new()
             .slide_range(SlideRange::Custom(vec![256, 258, 260]));
         let xml = s.to_xml();
-        assert!(xml.contains("custShow"));
-        assert!(xml.contains(r#"id="256""#));
-        assert!(xml.contains(r#"id="260""#));
+        assert!(xml.contains(r#"<p:custShow id="0"/>"#));
+        assert!(!xml.contains("sldId"));
+        let list = s.to_custom_show_list_xml(4, |n| n + 1).unwrap();
+        assert_eq!(
+            list,
+            r#"<p:custShowLst><p:custShow name="Custom Show 1" id="0"><p:sldLst><p:sldId r:id="rId2"/><p:sldId r:id="rId4"/></p:sldLst></p:custShow></p:custShowLst>"#
+        );
+        assert!(SlideShowSettings::new().to_custom_show_list_xml(4, |n| n + 1).is_none());
     }
 
     #[test]
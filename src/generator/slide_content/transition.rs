@@ -4,6 +4,7 @@ use crate::core::ToXml;
 
 /// Slide transition effects
 #[derive(Clone, Debug, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TransitionType {
     #[default]
     None,
@@ -20,16 +21,146 @@ pub enum TransitionType {
 impl TransitionType {
     /// Generate XML for the transition
     pub fn to_xml(&self) -> String {
+        SlideTransition::from(*self).to_xml()
+    }
+
+    /// Effect element inside `<p:transition>`; directional effects default to right
+    pub fn effect_xml(&self, direction: Option<TransitionDirection>) -> String {
+        let dir = direction.unwrap_or(TransitionDirection::Right).to_xml_value();
+        match self {
+            TransitionType::None | TransitionType::Cut => String::new(), // Default is cut/instant
+            TransitionType::Fade => "<p:fade/>".to_string(),
+            TransitionType::Push => format!(r#"<p:push dir="{}"/>"#, dir),
+            TransitionType::Wipe => format!(r#"<p:wipe dir="{}"/>"#, dir),
+            TransitionType::Split => r#"<p:split dir="out" orient="horz"/>"#.to_string(),
+            TransitionType::Reveal => format!(r#"<p:reveal dir="{}"/>"#, dir),
+            TransitionType::Cover => format!(r#"<p:cover dir="{}"/>"#, dir),
+            TransitionType::Zoom => r#"<p:zoom dir="in"/>"#.to_string(),
+        }
+    }
+}
+
+/// Direction the incoming slide moves for push, wipe, reveal, and cover
+#[derive(Clone, Debug, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TransitionDirection {
+    Left,
+    Right,
+    Up,
+    Down,
+}
+
+impl TransitionDirection {
+    pub fn to_xml_value(&self) -> &'static str {
         match self {
-            TransitionType::None => String::new(),
-            TransitionType::Cut => String::new(), // Default is cut/instant
-            TransitionType::Fade => r#"<p:transition><p:fade/></p:transition>"#.to_string(),
-            TransitionType::Push => r#"<p:transition><p:push dir="r"/></p:transition>"#.to_string(), // Default right
-            TransitionType::Wipe => r#"<p:transition><p:wipe dir="r"/></p:transition>"#.to_string(), // Default right
-            TransitionType::Split => r#"<p:transition><p:split dir="out" orient="horz"/></p:transition>"#.to_string(),
-            TransitionType::Reveal => r#"<p:transition><p:reveal dir="r"/></p:transition>"#.to_string(),
-            TransitionType::Cover => r#"<p:transition><p:cover dir="r"/></p:transition>"#.to_string(),
-            TransitionType::Zoom => r#"<p:transition><p:zoom dir="in"/></p:transition>"#.to_string(),
+            TransitionDirection::Left => "l",
+            TransitionDirection::Right => "r",
+            TransitionDirection::Up => "u",
+            TransitionDirection::Down => "d",
+        }
+    }
+}
+
+/// A slide's transition effect plus its timing and advance behavior
+#[derive(Clone, Debug, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SlideTransition {
+    pub effect: TransitionType,
+    pub direction: Option<TransitionDirection>,
+    /// Effect duration; written as `p14:dur` with the nearest `spd` as fallback
+    pub duration_ms: Option<u32>,
+    pub advance_on_click: bool,
+    pub advance_after_ms: Option<u32>,
+}
+
+impl Default for SlideTransition {
+    fn default() -> Self {
+        Self::new(TransitionType::None)
+    }
+}
+
+impl From<TransitionType> for SlideTransition {
+    fn from(effect: TransitionType) -> Self {
+        Self::new(effect)
+    }
+}
+
+impl SlideTransition {
+    pub fn new(effect: TransitionType) -> Self {
+        Self {
+            effect,
+            direction: None,
+            duration_ms: None,
+            advance_on_click: true,
+            advance_after_ms: None,
+        }
+    }
+
+    pub fn direction(mut self, direction: TransitionDirection) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+
+    pub fn duration_ms(mut self, ms: u32) -> Self {
+        self.duration_ms = Some(ms);
+        self
+    }
+
+    pub fn advance_on_click(mut self, on_click: bool) -> Self {
+        self.advance_on_click = on_click;
+        self
+    }
+
+    pub fn advance_after_ms(mut self, ms: u32) -> Self {
+        self.advance_after_ms = Some(ms);
+        self
+    }
+
+    /// Whether the slide needs no `<p:transition>` at all
+    pub fn is_empty(&self) -> bool {
+        matches!(self.effect, TransitionType::None | TransitionType::Cut)
+            && self.duration_ms.is_none()
+            && self.advance_on_click
+            && self.advance_after_ms.is_none()
+    }
+
+    /// Generate `<p:transition>`, or nothing when the slide has no transition
+    pub fn to_xml(&self) -> String {
+        if self.is_empty() {
+            return String::new();
+        }
+
+        let mut advance = String::new();
+        if !self.advance_on_click {
+            advance.push_str(r#" advClick="0""#);
+        }
+        if let Some(ms) = self.advance_after_ms {
+            advance.push_str(&format!(r#" advTm="{}""#, ms));
+        }
+        let effect = self.effect.effect_xml(self.direction);
+        let element = |attrs: &str| {
+            if effect.is_empty() {
+                format!("<p:transition{}{}/>", attrs, advance)
+            } else {
+                format!("<p:transition{}{}>{}</p:transition>", attrs, advance, effect)
+            }
+        };
+
+        match self.duration_ms {
+            None => element(""),
+            // PowerPoint 2010+ reads the exact duration; older readers fall back to the preset speed
+            Some(ms) => {
+                let spd = match ms {
+                    0..=624 => "fast",
+                    625..=874 => "med",
+                    _ => "slow",
+                };
+                format!(
+                    r#"<mc:AlternateContent xmlns:mc="http://schemas.openxmlformats.org/markup-compatibility/2006"><mc:Choice xmlns:p14="http://schemas.microsoft.com/office/powerpoint/2010/main" Requires="p14">{}</mc:Choice><mc:Fallback>{}</mc:Fallback></mc:AlternateContent>"#,
+                    element(&format!(r#" spd="{}" p14:dur="{}""#, spd, ms)),
+                    element(&format!(r#" spd="{}""#, spd)),
+                )
+            }
         }
     }
 }
@@ -39,3 +170,43 @@ impl ToXml for TransitionType {
         TransitionType::to_xml(self)
     }
 }
+
+impl ToXml for SlideTransition {
+    fn to_xml(&self) -> String {
+        SlideTransition::to_xml(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_no_transition_emits_nothing() {
+        assert_eq!(TransitionType::None.to_xml(), "");
+        assert_eq!(TransitionType::Cut.to_xml(), "");
+        assert_eq!(TransitionType::Fade.to_xml(), "<p:transition><p:fade/></p:transition>");
+    }
+
+    #[test]
+    fn test_direction_and_advance() {
+        let xml = SlideTransition::new(TransitionType::Push)
+            .direction(TransitionDirection::Left)
+            .advance_on_click(false)
+            .advance_after_ms(3000)
+            .to_xml();
+        assert_eq!(xml, r#"<p:transition advClick="0" advTm="3000"><p:push dir="l"/></p:transition>"#);
+
+        // Auto-advance without an effect still needs the element
+        let xml = SlideTransition::new(TransitionType::None).advance_after_ms(5000).to_xml();
+        assert_eq!(xml, r#"<p:transition advTm="5000"/>"#);
+    }
+
+    #[test]
+    fn test_duration_uses_alternate_content() {
+        let xml = SlideTransition::new(TransitionType::Fade).duration_ms(2000).to_xml();
+        assert!(xml.contains(r#"Requires="p14"><p:transition spd="slow" p14:dur="2000"><p:fade/></p:transition></mc:Choice>"#));
+        assert!(xml.contains(r#"<mc:Fallback><p:transition spd="slow"><p:fade/></p:transition></mc:Fallback>"#));
+        assert!(SlideTransition::new(TransitionType::Fade).duration_ms(500).to_xml().contains(r#"spd="fast""#));
+    }
+}
@@ -0,0 +1,254 @@
+//! Custom slide master (`ppt/slideMasters/slideMaster1.xml`)
+//!
+//! The master holds the deck-wide background, the default title and body text
+//! styles (`<p:txStyles>`) and the title/body placeholder frames. Layout and
+//! slide placeholders inherit from it, so a title font set here applies to
+//! every placeholder title in the deck.
+
+use crate::core::{escape_xml, SlideSize};
+use crate::elements::{color_value_xml, RgbInput};
+use crate::generator::gradients::GradientFill;
+use crate::generator::layout_parts::{scale_bounds, BODY_BOUNDS, TITLE_BOUNDS};
+use crate::generator::presentation_theme::PresentationTheme;
+use crate::generator::slide_content::SlideBackground;
+use crate::generator::text::{proofing_attrs, TextFormat};
+
+/// Master settings that replace the stock master's defaults
+///
+/// Only placeholder text inherits these styles: text set with
+/// [`SlideContent::set_placeholder`](crate::generator::SlideContent::set_placeholder) and
+/// text typed into layout placeholders in PowerPoint. The title and bullet boxes
+/// the builder draws itself carry explicit formatting.
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SlideMaster {
+    /// Background behind every slide that doesn't set its own
+    pub background: Option<SlideBackground>,
+    /// Default formatting of title placeholders
+    pub title_style: Option<TextFormat>,
+    /// Default formatting of body placeholders; the size applies to the first level
+    pub body_style: Option<TextFormat>,
+    /// Title placeholder frame (x, y, cx, cy) in EMU
+    pub title_bounds: Option<(u32, u32, u32, u32)>,
+    /// Body placeholder frame (x, y, cx, cy) in EMU
+    pub body_bounds: Option<(u32, u32, u32, u32)>,
+    /// Colors and fonts of this master's theme; the first master falls back to the deck theme
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub theme: Option<PresentationTheme>,
+}
+
+impl SlideMaster {
+    /// Title frame, the stock one stretched to the slide size unless overridden
+    pub fn title_frame(&self, size: &SlideSize) -> (u32, u32, u32, u32) {
+        self.title_bounds.unwrap_or_else(|| scale_bounds(TITLE_BOUNDS, size))
+    }
+
+    /// Body frame, the stock one stretched to the slide size unless overridden
+    pub fn body_frame(&self, size: &SlideSize) -> (u32, u32, u32, u32) {
+        self.body_bounds.unwrap_or_else(|| scale_bounds(BODY_BOUNDS, size))
+    }
+
+    /// `<p:bg>` for the master
+    pub(crate) fn background_xml(&self) -> String {
+        match &self.background {
+            Some(background) => background.to_xml(None),
+            None => r#"<p:bg><p:bgRef idx="1001"><a:schemeClr val="bg1"/></p:bgRef></p:bg>"#.to_string(),
+        }
+    }
+
+    /// Title and body placeholders, with shape ids starting at `first_id`
+    pub(crate) fn placeholder_shapes_xml(&self, first_id: u32, size: &SlideSize) -> String {
+        let proofing = proofing_attrs(false);
+        let (x, y, cx, cy) = self.title_frame(size);
+        let title = format!(
+            r#"<p:sp><p:nvSpPr><p:cNvPr id="{first_id}" name="Title Placeholder"/><p:cNvSpPr><a:spLocks noGrp="1"/></p:cNvSpPr><p:nvPr><p:ph type="title"/></p:nvPr></p:nvSpPr><p:spPr><a:xfrm><a:off x="{x}" y="{y}"/><a:ext cx="{cx}" cy="{cy}"/></a:xfrm><a:prstGeom prst="rect"><a:avLst/></a:prstGeom></p:spPr><p:txBody><a:bodyPr vert="horz" anchor="ctr"/><a:lstStyle/><a:p><a:r><a:rPr lang="en-US"{proofing}/><a:t>Click to edit Master title style</a:t></a:r></a:p></p:txBody></p:sp>"#
+        );
+        let (x, y, cx, cy) = self.body_frame(size);
+        let body_id = first_id + 1;
+        let body = format!(
+            r#"<p:sp><p:nvSpPr><p:cNvPr id="{body_id}" name="Text Placeholder"/><p:cNvSpPr><a:spLocks noGrp="1"/></p:cNvSpPr><p:nvPr><p:ph type="body" idx="1"/></p:nvPr></p:nvSpPr><p:spPr><a:xfrm><a:off x="{x}" y="{y}"/><a:ext cx="{cx}" cy="{cy}"/></a:xfrm><a:prstGeom prst="rect"><a:avLst/></a:prstGeom></p:spPr><p:txBody><a:bodyPr vert="horz"/><a:lstStyle/><a:p><a:pPr lvl="0"/><a:r><a:rPr lang="en-US"{proofing}/><a:t>Click to edit Master text styles</a:t></a:r></a:p></p:txBody></p:sp>"#
+        );
+        format!("{title}\n{body}")
+    }
+
+    /// Stock `<p:txStyles>` with the title and body styles applied
+    pub(crate) fn text_styles_xml(&self, stock: &str) -> String {
+        let (Some(body_start), Some(other_start)) = (stock.find("<p:bodyStyle>"), stock.find("<p:otherStyle>")) else {
+            return stock.to_string();
+        };
+        let (title, rest) = stock.split_at(body_start);
+        let (body, other) = rest.split_at(other_start - body_start);
+        let mut xml = String::with_capacity(stock.len() + 512);
+        match &self.title_style {
+            Some(format) => xml.push_str(&restyle_levels(title, format)),
+            None => xml.push_str(title),
+        }
+        match &self.body_style {
+            Some(format) => xml.push_str(&restyle_levels(body, format)),
+            None => xml.push_str(body),
+        }
+        xml.push_str(other);
+        xml
+    }
+}
+
+const DEF_RPR_OPEN: &str = r#"<a:defRPr sz=""#;
+const DEF_RPR_CLOSE: &str = "</a:defRPr>";
+
+/// Apply `format` to every level's `<a:defRPr>`; the size only replaces the first level's
+fn restyle_levels(style: &str, format: &TextFormat) -> String {
+    let mut out = String::with_capacity(style.len() + 256);
+    let mut rest = style;
+    let mut first = true;
+    while let Some(start) = rest.find(DEF_RPR_OPEN) {
+        let Some(len) = rest[start..].find(DEF_RPR_CLOSE) else { break };
+        let end = start + len + DEF_RPR_CLOSE.len();
+        out.push_str(&rest[..start]);
+        out.push_str(&restyle_def_rpr(&rest[start..end], format, first));
+        first = false;
+        rest = &rest[end..];
+    }
+    out.push_str(rest);
+    out
+}
+
+fn restyle_def_rpr(def_rpr: &str, format: &TextFormat, with_size: bool) -> String {
+    let mut xml = def_rpr.to_string();
+    if with_size && let Some(size) = format.font_size {
+        let value_start = DEF_RPR_OPEN.len();
+        let value_len = xml[value_start..].find('"').unwrap_or(0);
+        xml.replace_range(value_start..value_start + value_len, &(size * 100).to_string());
+    }
+    let mut attrs = String::new();
+    if format.bold {
+        attrs.push_str(r#" b="1""#);
+    }
+    if format.italic {
+        attrs.push_str(r#" i="1""#);
+    }
+    if format.underline {
+        attrs.push_str(r#" u="sng""#);
+    }
+    if let Some(pos) = xml.find(" kern=") {
+        xml.insert_str(pos, &attrs);
+    }
+    if let Some(color) = &format.color {
+        xml = xml.replace(r#"<a:schemeClr val="tx1"/>"#, &color_value_xml(color, ""));
+    }
+    if let Some(family) = &format.font_family
+        && let Some(start) = xml.find("<a:latin ")
+        && let Some(len) = xml[start..].find("/>")
+    {
+        xml.replace_range(start..start + len + 2, &format!(r#"<a:latin typeface="{}"/>"#, escape_xml(family)));
+    }
+    xml
+}
+
+/// Builder for a [`SlideMaster`]
+///
+/// # Example
+/// ```
+/// use ppt_rs::generator::{MasterBuilder, TextFormat};
+///
+/// let master = MasterBuilder::new()
+///     .title_style(TextFormat::new().font_size(40).bold().color("1F3864"))
+///     .background_color("F7F7F7")
+///     .build();
+/// assert_eq!(master.title_style.unwrap().font_size, Some(40));
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct MasterBuilder {
+    master: SlideMaster,
+}
+
+impl MasterBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Solid background color for the whole deck
+    pub fn background_color(mut self, hex: impl Into<RgbInput>) -> Self {
+        self.master.background = Some(SlideBackground::color(hex));
+        self
+    }
+
+    /// Gradient background for the whole deck
+    pub fn background_gradient(mut self, gradient: GradientFill) -> Self {
+        self.master.background = Some(SlideBackground::Gradient(gradient));
+        self
+    }
+
+    /// Size, weight, color and font of title placeholders
+    pub fn title_style(mut self, format: TextFormat) -> Self {
+        self.master.title_style = Some(format);
+        self
+    }
+
+    /// Size (first level), weight, color and font of body placeholders
+    pub fn body_style(mut self, format: TextFormat) -> Self {
+        self.master.body_style = Some(format);
+        self
+    }
+
+    /// Move the title placeholder; layouts using the stock title frame follow it
+    pub fn title_position(mut self, x: u32, y: u32, width: u32, height: u32) -> Self {
+        self.master.title_bounds = Some((x, y, width, height));
+        self
+    }
+
+    /// Move the body placeholder; layouts using the stock body frame follow it
+    pub fn body_position(mut self, x: u32, y: u32, width: u32, height: u32) -> Self {
+        self.master.body_bounds = Some((x, y, width, height));
+        self
+    }
+
+    /// Give the master its own color and font theme
+    pub fn theme(mut self, theme: PresentationTheme) -> Self {
+        self.master.theme = Some(theme);
+        self
+    }
+
+    pub fn build(self) -> SlideMaster {
+        self.master
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const STOCK: &str = include_str!("slide_master_txstyles.xml");
+
+    #[test]
+    fn test_title_style_restyles_title_only() {
+        let master = MasterBuilder::new()
+            .title_style(TextFormat::new().font_size(40).bold().color("1F3864").font_family("Georgia"))
+            .build();
+        let xml = master.text_styles_xml(STOCK);
+        let title = &xml[..xml.find("<p:bodyStyle>").unwrap()];
+        assert!(title.contains(r#"<a:defRPr sz="4000" b="1" kern="1200"><a:solidFill><a:srgbClr val="1F3864"/></a:solidFill><a:latin typeface="Georgia"/>"#));
+        assert_eq!(&xml[xml.find("<p:bodyStyle>").unwrap()..], &STOCK[STOCK.find("<p:bodyStyle>").unwrap()..]);
+    }
+
+    #[test]
+    fn test_body_size_applies_to_first_level() {
+        let master = MasterBuilder::new().body_style(TextFormat::new().font_size(24).italic()).build();
+        let xml = master.text_styles_xml(STOCK);
+        let body = &xml[xml.find("<p:bodyStyle>").unwrap()..xml.find("<p:otherStyle>").unwrap()];
+        assert!(body.contains(r#"<a:lvl1pPr marL="342900""#));
+        assert!(body.contains(r#"<a:defRPr sz="2400" i="1" kern="1200">"#));
+        assert!(body.contains(r#"<a:defRPr sz="2800" i="1" kern="1200">"#));
+        assert_eq!(body.matches(r#"i="1""#).count(), 9);
+    }
+
+    #[test]
+    fn test_placeholders_use_custom_frames() {
+        let master = MasterBuilder::new().title_position(1, 2, 3, 4).build();
+        let xml = master.placeholder_shapes_xml(5, &SlideSize::default());
+        assert!(xml.contains(r#"<p:cNvPr id="5" name="Title Placeholder"/>"#));
+        assert!(xml.contains(r#"<a:off x="1" y="2"/><a:ext cx="3" cy="4"/>"#));
+        assert!(xml.contains(r#"<p:cNvPr id="6" name="Text Placeholder"/>"#));
+        assert_eq!(xml.matches(r#"<a:rPr lang="en-US" dirty="0"/>"#).count(), 2);
+        assert!(xml.contains(r#"<a:off x="457200" y="1600200"/>"#));
+    }
+}
@@ -1,5 +1,6 @@
-//! Additional content rendering (shapes, images, code blocks, connectors)
+//! Additional content rendering (shapes, images, code blocks, connectors) in z-order
 
+use crate::generator::shapes::Shape;
 use crate::generator::shapes_xml::generate_shape_xml;
 use crate::generator::slide_content::SlideContent;
 
@@ -15,64 +16,132 @@ pub fn render_additional_content(
         + content.code_blocks.len()
         + content.connectors.len()
         + content.charts.len().min(chart_rids.len())
+        + content.shape_groups.iter().map(|g| g.id_count()).sum::<usize>()
+        + content.videos.len()
+        + content.audios.len()
         + ink_rel_id.map_or(0, |_| 1);
     if extra_elements > 0 {
         xml.reserve(extra_elements * 512);
     }
 
+    // Collect drawable elements with their z-order. IDs follow insertion order per element
+    // kind, so they stay unique no matter how the elements are stacked.
+    let mut elements: Vec<(i32, String)> = Vec::with_capacity(extra_elements);
+
     // Render shapes - use shape's fixed ID if set, otherwise auto-assign
-    for (i, shape) in content.shapes.iter().enumerate() {
-        xml.push('\n');
+    let hyperlinks = super::resolved_shape_hyperlinks(content);
+    for (i, (shape, hyperlink)) in content.shapes.iter().zip(hyperlinks).enumerate() {
         let shape_id = shape.id.unwrap_or((i + 10) as u32);
-        xml.push_str(&generate_shape_xml(shape, shape_id));
+        // Only shapes whose link still needs an id, or comes from an action button, are cloned
+        let resolved = shape.hyperlink.as_ref().map_or(hyperlink.is_some(), |h| h.r_id.is_none());
+        let shape_xml = if resolved {
+            generate_shape_xml(&Shape { hyperlink, ..shape.clone() }, shape_id)
+        } else {
+            generate_shape_xml(shape, shape_id)
+        };
+        elements.push((shape.z_order, shape_xml));
     }
 
     // Render images (actual picture elements, not placeholders)
     let image_start_id = 20 + content.shapes.len();
     let image_rel_start = 2 + usize::from(content.notes.is_some());
+    // SVG parts are related after images, charts, and ink
+    let mut svg_rel_id = image_rel_start
+        + content.images.len()
+        + content.charts.len()
+        + usize::from(content.ink_annotations.is_some());
     for (i, image) in content.images.iter().enumerate() {
-        xml.push('\n');
         let rel_id = image_rel_start + i;
-        xml.push_str(&crate::generator::images_xml::generate_image_xml(
+        let svg_rel = image.svg.as_ref().map(|_| {
+            svg_rel_id += 1;
+            svg_rel_id - 1
+        });
+        elements.push((image.z_order, crate::generator::images_xml::generate_image_xml_with_svg(
             image,
             image_start_id + i,
             rel_id,
-        ));
+            svg_rel,
+        )));
     }
 
     // Render code blocks with syntax highlighting
     let code_start_id = 30 + content.shapes.len() + content.images.len();
     for (i, code_block) in content.code_blocks.iter().enumerate() {
-        xml.push('\n');
-        xml.push_str(&generate_code_block(code_start_id + i, code_block));
+        elements.push((0, generate_code_block(code_start_id + i, code_block)));
     }
 
     // Render connectors
     let connector_start_id =
         50 + content.shapes.len() + content.images.len() + content.code_blocks.len();
     for (i, connector) in content.connectors.iter().enumerate() {
-        xml.push('\n');
         let id = connector_start_id + i;
-        xml.push_str(&crate::generator::connectors::generate_connector_xml(
+        elements.push((0, crate::generator::connectors::generate_connector_xml(
             connector, id,
-        ));
+        )));
     }
 
     // Render charts
-    let chart_start_id = 100
-        + content.shapes.len()
-        + content.images.len()
-        + content.code_blocks.len()
-        + content.connectors.len();
+    let chart_start_id = chart_start_id(content);
     for (i, chart) in content.charts.iter().enumerate() {
         if i < chart_rids.len() {
-            xml.push('\n');
             let r_id = &chart_rids[i];
-            xml.push_str(&crate::generator::charts::generate_chart_ref_xml(
+            elements.push((0, crate::generator::charts::generate_chart_ref_xml(
                 chart,
                 r_id,
                 chart_start_id + i,
-            ));
+            )));
+        }
+    }
+
+    // Render shape groups right after the charts; each group takes one ID plus one per child
+    let mut group_id = group_start_id(content);
+    for group in &content.shape_groups {
+        elements.push((0, crate::generator::shape_group::generate_group_xml(group, group_id as u32)));
+        group_id += group.id_count();
+    }
+
+    // Render embedded videos, then audio, after the groups; each relates its clip twice
+    // and its poster or icon once
+    let mut media_rel = super::media_rel_start(content);
+    let mut media_id = group_id;
+    for video in content.videos.iter().filter(|v| v.has_data()) {
+        elements.push((0, crate::generator::media::generate_video_xml(
+            video,
+            media_id,
+            &format!("rId{media_rel}"),
+            &format!("rId{}", media_rel + 1),
+            &format!("rId{}", media_rel + 2),
+        )));
+        media_rel += 3;
+        media_id += 1;
+    }
+    for audio in content.audios.iter().filter(|a| a.has_data()) {
+        elements.push((0, crate::generator::media::generate_audio_xml(
+            audio,
+            media_id,
+            &format!("rId{media_rel}"),
+            &format!("rId{}", media_rel + 1),
+            &format!("rId{}", media_rel + 2),
+        )));
+        media_rel += 3;
+        media_id += 1;
+    }
+
+    // Stable sort keeps insertion order among equal z values
+    elements.sort_by_key(|(z, _)| *z);
+
+    // Negative z-order goes behind the placeholders, right after the spTree group properties
+    let mut behind = String::new();
+    for (z, element) in &elements {
+        let target = if *z < 0 { &mut behind } else { &mut *xml };
+        target.push('\n');
+        target.push_str(element);
+    }
+    if !behind.is_empty() {
+        const TREE_PROPS_END: &str = "</p:grpSpPr>";
+        match xml.find(TREE_PROPS_END) {
+            Some(pos) => xml.insert_str(pos + TREE_PROPS_END.len(), &behind),
+            None => xml.push_str(&behind),
         }
     }
 
@@ -87,26 +156,42 @@ pub fn render_additional_content(
         }
 }
 
+fn chart_start_id(content: &SlideContent) -> usize {
+    100 + content.shapes.len() + content.images.len() + content.code_blocks.len() + content.connectors.len()
+}
+
+/// `cNvPr` id of the first shape group: after the charts
+fn group_start_id(content: &SlideContent) -> usize {
+    chart_start_id(content) + content.charts.len()
+}
+
+/// `cNvPr` id of the first embedded video or audio clip: after charts and shape groups
+pub(super) fn media_start_id(content: &SlideContent) -> usize {
+    group_start_id(content) + content.shape_groups.iter().map(|g| g.id_count()).sum::<usize>()
+}
+
 /// Generate code block XML with syntax highlighting
 fn generate_code_block(
     id: usize,
     code_block: &crate::generator::slide_content::CodeBlock,
 ) -> String {
-    // Use syntax highlighting when available, otherwise use plain text
+    // The built-in tokenizer handles common languages with the block's theme;
+    // syntect covers the rest when available, otherwise lines render plain
+    use crate::generator::slide_content::code_highlight;
+
     #[cfg(feature = "syntect")]
-    let highlighted_xml =
-        crate::cli::syntax::generate_highlighted_code_xml(&code_block.code, &code_block.language);
+    let highlighted_xml = if code_highlight::supports_language(&code_block.language) {
+        code_highlight::highlighted_code_xml(&code_block.code, &code_block.language, &code_block.theme)
+    } else {
+        crate::cli::syntax::generate_highlighted_code_xml(&code_block.code, &code_block.language)
+    };
 
     #[cfg(not(feature = "syntect"))]
-    let highlighted_xml = {
-        // Fallback to basic XML without syntax highlighting
-        let escaped_code = code_block.code
-            .replace('&', "&amp;")
-            .replace('<', "&lt;")
-            .replace('>', "&gt;");
-        format!("<a:t>{}</a:t>", escaped_code)
-    };
+    let highlighted_xml =
+        code_highlight::highlighted_code_xml(&code_block.code, &code_block.language, &code_block.theme);
 
+    let background = &code_block.theme.background;
+    let border = &code_block.theme.border;
     let x = code_block.x;
     let y = code_block.y;
     let width = code_block.width;
@@ -125,8 +210,8 @@ fn generate_code_block(
 <a:ext cx="{width}" cy="{height}"/>
 </a:xfrm>
 <a:prstGeom prst="rect"><a:avLst/></a:prstGeom>
-<a:solidFill><a:srgbClr val="002B36"/></a:solidFill>
-<a:ln w="12700"><a:solidFill><a:srgbClr val="073642"/></a:solidFill></a:ln>
+<a:solidFill><a:srgbClr val="{background}"/></a:solidFill>
+<a:ln w="12700"><a:solidFill><a:srgbClr val="{border}"/></a:solidFill></a:ln>
 </p:spPr>
 <p:txBody>
 <a:bodyPr wrap="square" rtlCol="0" anchor="t" lIns="91440" tIns="45720" rIns="91440" bIns="45720"/>
@@ -135,3 +220,42 @@ fn generate_code_block(
 </p:sp>"#
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::generator::images::Image;
+    use crate::generator::shapes::{Shape, ShapeFill, ShapeType};
+    use crate::generator::slide_xml::create_slide_xml_with_content;
+    use crate::generator::slide_content::SlideContent;
+
+    #[test]
+    fn test_z_order_stacking() {
+        let slide = SlideContent::new("Layers")
+            .add_bullet("Point")
+            .add_shape(
+                Shape::new(ShapeType::Rectangle, 0, 0, 100, 100)
+                    .with_fill(ShapeFill::new("000000").with_transparency(50))
+                    .with_z_order(1),
+            )
+            .add_shape(Shape::new(ShapeType::Rectangle, 0, 0, 9144000, 6858000).with_z_order(-1))
+            .add_image(Image::new("photo.png", 100, 100, "png"));
+        let xml = create_slide_xml_with_content(1, &slide, &[], None);
+
+        let background = xml.find(r#"<p:cNvPr id="11""#).unwrap();
+        let title = xml.find(r#"<p:cNvPr id="2" name="Title"/>"#).unwrap();
+        let image = xml.find("<p:pic>").unwrap();
+        let overlay = xml.find(r#"<p:cNvPr id="10""#).unwrap();
+        assert!(xml.find("</p:grpSpPr>").unwrap() < background);
+        assert!(background < title);
+        assert!(image < overlay);
+    }
+
+    #[test]
+    fn test_default_z_order_keeps_insertion_order() {
+        let slide = SlideContent::new("Plain")
+            .add_shape(Shape::new(ShapeType::Rectangle, 0, 0, 100, 100))
+            .add_image(Image::new("photo.png", 100, 100, "png"));
+        let xml = create_slide_xml_with_content(1, &slide, &[], None);
+        assert!(xml.find("<p:sp>\n<p:nvSpPr>\n<p:cNvPr id=\"10\"").unwrap() < xml.find("<p:pic>").unwrap());
+    }
+}
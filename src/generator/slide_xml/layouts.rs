@@ -1,33 +1,30 @@
 //! Slide layout implementations
 
-use crate::generator::slide_content::{SlideContent, BulletPoint, BulletTextFormat};
+use crate::generator::slide_content::{
+    place_caption_picture, BulletPoint, BulletStyle, SlideContent, CAPTION_BOX, CAPTION_TITLE_BOX,
+    CENTERED_TITLE_BOX, COMPARISON_CONTENT_BOXES, COMPARISON_HEADING_BOXES, CONTENT_BOX, SECTION_TITLE_BOX,
+    TITLE_BOX,
+};
 use crate::core::escape_xml;
 use crate::generator::slide::formatting::generate_text_props;
 use super::common::{SLIDE_HEADER, SLIDE_FOOTER, generate_title_shape};
 use crate::generator::layouts::ExtendedTextProps;
+use crate::generator::text::TextFormat;
 use super::content::render_additional_content;
 
-/// Generate text properties XML for a bullet, merging slide defaults with bullet-specific format
-fn generate_bullet_text_props(
-    default_props: &ExtendedTextProps,
-    bullet_format: Option<&BulletTextFormat>,
-) -> String {
-    if let Some(fmt) = bullet_format {
-        let props = ExtendedTextProps {
-            size: fmt.font_size.map(|s| s * 100).unwrap_or(default_props.size),
-            bold: fmt.bold || default_props.bold,
-            italic: fmt.italic || default_props.italic,
-            underline: fmt.underline || default_props.underline,
-            strikethrough: fmt.strikethrough,
-            subscript: fmt.subscript,
-            superscript: fmt.superscript,
-            color: fmt.color.clone().or_else(|| default_props.color.clone()),
-            highlight: fmt.highlight.clone(),
-            font_family: fmt.font_family.clone().or_else(|| default_props.font_family.clone()),
-        };
-        props.to_xml()
-    } else {
-        default_props.to_xml()
+/// Text properties for one run of a formatted bullet, layered over the bullet's own
+fn run_text_props(bullet_props: &ExtendedTextProps, format: &TextFormat) -> ExtendedTextProps {
+    ExtendedTextProps {
+        size: format.font_size.map(|s| s * 100).unwrap_or(bullet_props.size),
+        bold: format.bold || bullet_props.bold,
+        italic: format.italic || bullet_props.italic,
+        underline: format.underline || bullet_props.underline,
+        strikethrough: format.strikethrough || bullet_props.strikethrough,
+        subscript: format.subscript || (bullet_props.subscript && !format.superscript),
+        superscript: format.superscript || (bullet_props.superscript && !format.subscript),
+        color: format.color.clone().or_else(|| bullet_props.color.clone()),
+        highlight: format.highlight.clone().or_else(|| bullet_props.highlight.clone()),
+        font_family: format.font_family.clone().or_else(|| bullet_props.font_family.clone()),
     }
 }
 
@@ -38,21 +35,29 @@ fn generate_bullet_paragraph_from_point(
 ) -> String {
     let indent = 457200 + (bullet.level * 457200);
     let margin_left = bullet.level * 457200 + indent;
-    let bullet_xml = bullet.style.to_xml();
-    let text_props = generate_bullet_text_props(default_props, bullet.format.as_ref());
-    
+    let bullet_xml = bullet.style.to_xml_with_start(bullet.start_at);
+    let bullet_props = default_props.with_bullet_format(bullet.format.as_ref());
+    let runs = if bullet.runs.is_empty() {
+        format!("\n<a:r>\n{}\n<a:t>{}</a:t>\n</a:r>", bullet_props.to_xml(), escape_xml(&bullet.text))
+    } else {
+        bullet
+            .runs
+            .iter()
+            .map(|run| {
+                let props = run_text_props(&bullet_props, &run.format);
+                format!("\n<a:r>\n{}\n<a:t>{}</a:t>\n</a:r>", props.to_xml(), escape_xml(&run.text))
+            })
+            .collect()
+    };
+
     format!(
         r#"
 <a:p>
 <a:pPr lvl="{}" marL="{}" indent="-{}">
 {}
-</a:pPr>
-<a:r>
-{}
-<a:t>{}</a:t>
-</a:r>
+</a:pPr>{}
 </a:p>"#,
-        bullet.level, margin_left, indent, bullet_xml, text_props, escape_xml(&bullet.text)
+        bullet.level, margin_left, indent, bullet_xml, runs
     )
 }
 
@@ -84,15 +89,8 @@ pub fn create_title_only_slide(
     );
     let title_text = escape_xml(&content.title);
 
-    let title_shape = generate_title_shape(
-        &title_text,
-        &title_props,
-        457200,   // x
-        274638,   // y
-        8230200,  // width
-        1143000,  // height
-        "l",      // align left
-    );
+    let (x, y, width, height) = TITLE_BOX;
+    let title_shape = generate_title_shape(&title_text, &title_props, x, y, width, height, "l");
 
     let mut xml = format!("{}\n{}", SLIDE_HEADER, title_shape);
     render_additional_content(&mut xml, content, chart_rids, ink_rel_id);
@@ -116,15 +114,8 @@ pub fn create_section_header_slide(
     );
     let title_text = escape_xml(&content.title);
 
-    let title_shape = generate_title_shape(
-        &title_text,
-        &title_props,
-        457_200,
-        1_600_200,
-        8_229_600,
-        1_828_800,
-        "ctr",
-    );
+    let (x, y, width, height) = SECTION_TITLE_BOX;
+    let title_shape = generate_title_shape(&title_text, &title_props, x, y, width, height, "ctr");
 
     let mut xml = format!("{}\n{}", SLIDE_HEADER, title_shape);
     render_additional_content(&mut xml, content, chart_rids, ink_rel_id);
@@ -148,15 +139,8 @@ pub fn create_centered_title_slide(
     );
     let title_text = escape_xml(&content.title);
 
-    let title_shape = generate_title_shape(
-        &title_text,
-        &title_props,
-        457200,   // x
-        2743200,  // y (centered vertically)
-        8230200,  // width
-        1371600,  // height
-        "ctr",    // align center
-    );
+    let (x, y, width, height) = CENTERED_TITLE_BOX;
+    let title_shape = generate_title_shape(&title_text, &title_props, x, y, width, height, "ctr");
 
     let mut xml = format!("{}\n{}", SLIDE_HEADER, title_shape);
     render_additional_content(&mut xml, content, chart_rids, ink_rel_id);
@@ -270,15 +254,54 @@ pub fn create_title_and_big_content_slide(
     xml
 }
 
-/// Create a two-column slide
-pub fn create_two_column_slide(
-    content: &SlideContent,
-    chart_rids: &[String],
-    ink_rel_id: Option<&str>,
-) -> String {
-    let title_size = content.title_size.unwrap_or(44) * 100;
-    let content_size = content.content_size.unwrap_or(24) * 100;
+/// Body placeholder holding one column of a two-content or comparison slide
+fn column_placeholder(id: u32, name: &str, idx: u32, (x, y, cx, cy): (u32, u32, u32, u32), paragraphs: &str) -> String {
+    format!(
+        r#"
+<p:sp>
+<p:nvSpPr>
+<p:cNvPr id="{id}" name="{name}"/>
+<p:cNvSpPr><a:spLocks noGrp="1"/></p:cNvSpPr>
+<p:nvPr><p:ph type="body" idx="{idx}"/></p:nvPr>
+</p:nvSpPr>
+<p:spPr>
+<a:xfrm>
+<a:off x="{x}" y="{y}"/>
+<a:ext cx="{cx}" cy="{cy}"/>
+</a:xfrm>
+<a:prstGeom prst="rect"><a:avLst/></a:prstGeom>
+<a:noFill/>
+</p:spPr>
+<p:txBody>
+<a:bodyPr wrap="square" rtlCol="0"/>
+<a:lstStyle/>{paragraphs}
+</p:txBody>
+</p:sp>"#
+    )
+}
 
+/// Left and right column bullets: the explicit column lists when set, otherwise the
+/// slide's bullets split at the column break (or halfway)
+fn column_bullets(content: &SlideContent) -> [Vec<BulletPoint>; 2] {
+    if !content.left_bullets.is_empty() || !content.right_bullets.is_empty() {
+        let points = |texts: &[String]| {
+            texts.iter().map(|t| BulletPoint::new(t).with_style(content.bullet_style)).collect()
+        };
+        return [points(&content.left_bullets), points(&content.right_bullets)];
+    }
+
+    let all: Vec<BulletPoint> = if content.bullets.is_empty() {
+        content.content.iter().map(|t| BulletPoint::new(t).with_style(content.bullet_style)).collect()
+    } else {
+        content.bullets.clone()
+    };
+    let mut left = all;
+    let right = left.split_off(content.column_split());
+    [left, right]
+}
+
+fn column_title_shape(content: &SlideContent) -> String {
+    let title_size = content.title_size.unwrap_or(44) * 100;
     let title_props = generate_text_props(
         title_size,
         content.title_bold,
@@ -286,12 +309,7 @@ pub fn create_two_column_slide(
         content.title_underline,
         content.title_color.as_deref(),
     );
-    let title_text = escape_xml(&content.title);
-
-    let mut xml = String::from(SLIDE_HEADER);
-    
-    // Title
-    xml.push_str(&format!(
+    format!(
         r#"
 <p:sp>
 <p:nvSpPr>
@@ -314,41 +332,134 @@ pub fn create_two_column_slide(
 <a:pPr algn="l"/>
 <a:r>
 {title_props}
-<a:t>{title_text}</a:t>
+<a:t>{}</a:t>
 </a:r>
 </a:p>
 </p:txBody>
-</p:sp>"#
-    ));
+</p:sp>"#,
+        escape_xml(&content.title)
+    )
+}
 
-    let default_props = ExtendedTextProps::with_basic(
-        content_size,
+fn column_text_props(content: &SlideContent) -> ExtendedTextProps {
+    ExtendedTextProps::with_basic(
+        content.content_size.unwrap_or(24) * 100,
         content.content_bold,
         content.content_italic,
         false,
         content.content_color.as_deref(),
-    );
+    )
+}
+
+/// Create a two-column slide with bullets in the layout's left (`idx="1"`) and
+/// right (`idx="2"`) content placeholders
+pub fn create_two_column_slide(
+    content: &SlideContent,
+    chart_rids: &[String],
+    ink_rel_id: Option<&str>,
+) -> String {
+    let mut xml = String::from(SLIDE_HEADER);
+    xml.push_str(&column_title_shape(content));
+
+    let default_props = column_text_props(content);
+    let boxes = [(457200, 1189200, 4115100, 5668800), (4572300, 1189200, 4115100, 5668800)];
+    let names = ["Left Content", "Right Content"];
+    for (i, bullets) in column_bullets(content).iter().enumerate() {
+        if bullets.is_empty() {
+            continue;
+        }
+        let paragraphs: String =
+            bullets.iter().map(|b| generate_bullet_paragraph_from_point(b, &default_props)).collect();
+        xml.push_str(&column_placeholder(3 + i as u32, names[i], 1 + i as u32, boxes[i], &paragraphs));
+    }
+
+    render_additional_content(&mut xml, content, chart_rids, ink_rel_id);
+    xml.push_str(SLIDE_FOOTER);
+    xml
+}
 
-    // Determine which bullets to use
-    let use_styled_bullets = !content.bullets.is_empty();
-    let bullet_count = if use_styled_bullets { content.bullets.len() } else { content.content.len() };
+/// Create a comparison slide: a heading (`idx="1"`, `idx="3"`) above each column of
+/// bullets (`idx="2"`, `idx="4"`)
+pub fn create_comparison_slide(
+    content: &SlideContent,
+    chart_rids: &[String],
+    ink_rel_id: Option<&str>,
+) -> String {
+    let mut xml = String::from(SLIDE_HEADER);
+    xml.push_str(&column_title_shape(content));
+
+    let default_props = column_text_props(content);
+    let mut heading_props = default_props.clone();
+    heading_props.bold = true;
+    let headings = [&content.left_heading, &content.right_heading];
+    let sides = ["Left", "Right"];
+    for (i, bullets) in column_bullets(content).iter().enumerate() {
+        let id = 3 + 2 * i as u32;
+        if let Some(heading) = headings[i] {
+            let heading = BulletPoint::new(heading).with_style(BulletStyle::None);
+            xml.push_str(&column_placeholder(
+                id,
+                &format!("Heading {}", sides[i]),
+                id - 2,
+                COMPARISON_HEADING_BOXES[i],
+                &generate_bullet_paragraph_from_point(&heading, &heading_props),
+            ));
+        }
+        if !bullets.is_empty() {
+            let paragraphs: String =
+                bullets.iter().map(|b| generate_bullet_paragraph_from_point(b, &default_props)).collect();
+            xml.push_str(&column_placeholder(
+                id + 1,
+                &format!("Content {}", sides[i]),
+                id - 1,
+                COMPARISON_CONTENT_BOXES[i],
+                &paragraphs,
+            ));
+        }
+    }
 
-    if bullet_count > 0 {
-        let mid = bullet_count.div_ceil(2);
+    render_additional_content(&mut xml, content, chart_rids, ink_rel_id);
+    xml.push_str(SLIDE_FOOTER);
+    xml
+}
 
-        // Left column
-        xml.push_str(
+/// Create a picture-with-caption slide: the first image fills the picture area
+/// and the slide's text becomes the caption under the title
+pub fn create_picture_with_caption_slide(
+    content: &SlideContent,
+    chart_rids: &[String],
+    ink_rel_id: Option<&str>,
+) -> String {
+    let title_size = content.title_size.unwrap_or(44).min(32) * 100;
+    let caption_size = content.content_size.unwrap_or(28).min(18) * 100;
+
+    let title_props = generate_text_props(
+        title_size,
+        content.title_bold,
+        content.title_italic,
+        content.title_underline,
+        content.title_color.as_deref(),
+    );
+    let title_text = escape_xml(&content.title);
+    let (tx, ty, tcx, tcy) = CAPTION_TITLE_BOX;
+    let title_shape = generate_title_shape(&title_text, &title_props, tx, ty, tcx, tcy, "l");
+
+    let mut xml = format!("{}\n{}", SLIDE_HEADER, title_shape);
+
+    if content.body_paragraph_count() > 0 {
+        let (x, y, cx, cy) = CAPTION_BOX;
+        xml.push_str(&format!(
             r#"
 <p:sp>
 <p:nvSpPr>
-<p:cNvPr id="3" name="Left Content"/>
+<p:cNvPr id="3" name="Caption"/>
 <p:cNvSpPr txBox="1"/>
 <p:nvPr/>
 </p:nvSpPr>
 <p:spPr>
 <a:xfrm>
-<a:off x="457200" y="1189200"/>
-<a:ext cx="4115100" cy="5668800"/>
+<a:off x="{x}" y="{y}"/>
+<a:ext cx="{cx}" cy="{cy}"/>
 </a:xfrm>
 <a:prstGeom prst="rect"><a:avLst/></a:prstGeom>
 <a:noFill/>
@@ -356,15 +467,27 @@ pub fn create_two_column_slide(
 <p:txBody>
 <a:bodyPr wrap="square" rtlCol="0"/>
 <a:lstStyle/>"#
-        );
+        ));
 
-        if use_styled_bullets {
-            for bullet in &content.bullets[..mid] {
-                xml.push_str(&generate_bullet_paragraph_from_point(bullet, &default_props));
+        let default_props = ExtendedTextProps::with_basic(
+            caption_size,
+            content.content_bold,
+            content.content_italic,
+            false,
+            content.content_color.as_deref(),
+        );
+        // Captions drop the default bullet marker but keep numbering and custom markers
+        if !content.bullets.is_empty() {
+            for bullet in &content.bullets {
+                let mut bp = bullet.clone();
+                if matches!(bp.style, BulletStyle::Bullet) {
+                    bp.style = BulletStyle::None;
+                }
+                xml.push_str(&generate_bullet_paragraph_from_point(&bp, &default_props));
             }
         } else {
-            for bullet in &content.content[..mid] {
-                let bp = BulletPoint::new(bullet).with_style(content.bullet_style);
+            for line in &content.content {
+                let bp = BulletPoint::new(line).with_style(BulletStyle::None);
                 xml.push_str(&generate_bullet_paragraph_from_point(&bp, &default_props));
             }
         }
@@ -374,50 +497,9 @@ pub fn create_two_column_slide(
 </p:txBody>
 </p:sp>"#
         );
-
-        // Right column
-        if mid < bullet_count {
-            xml.push_str(
-                r#"
-<p:sp>
-<p:nvSpPr>
-<p:cNvPr id="4" name="Right Content"/>
-<p:cNvSpPr txBox="1"/>
-<p:nvPr/>
-</p:nvSpPr>
-<p:spPr>
-<a:xfrm>
-<a:off x="4572300" y="1189200"/>
-<a:ext cx="4115100" cy="5668800"/>
-</a:xfrm>
-<a:prstGeom prst="rect"><a:avLst/></a:prstGeom>
-<a:noFill/>
-</p:spPr>
-<p:txBody>
-<a:bodyPr wrap="square" rtlCol="0"/>
-<a:lstStyle/>"#
-            );
-
-            if use_styled_bullets {
-                for bullet in &content.bullets[mid..] {
-                    xml.push_str(&generate_bullet_paragraph_from_point(bullet, &default_props));
-                }
-            } else {
-                for bullet in &content.content[mid..] {
-                    let bp = BulletPoint::new(bullet).with_style(content.bullet_style);
-                    xml.push_str(&generate_bullet_paragraph_from_point(&bp, &default_props));
-                }
-            }
-
-            xml.push_str(
-                r#"
-</p:txBody>
-</p:sp>"#
-            );
-        }
     }
 
-    render_additional_content(&mut xml, content, chart_rids, ink_rel_id);
+    render_additional_content(&mut xml, &place_caption_picture(content), chart_rids, ink_rel_id);
     xml.push_str(SLIDE_FOOTER);
     xml
 }
@@ -443,6 +525,7 @@ pub fn create_title_and_content_slide(
     let mut xml = String::from(SLIDE_HEADER);
     
     // Title
+    let (x, y, cx, cy) = TITLE_BOX;
     xml.push_str(&format!(
         r#"
 <p:sp>
@@ -453,8 +536,8 @@ pub fn create_title_and_content_slide(
 </p:nvSpPr>
 <p:spPr>
 <a:xfrm>
-<a:off x="457200" y="274638"/>
-<a:ext cx="8230200" cy="1143000"/>
+<a:off x="{x}" y="{y}"/>
+<a:ext cx="{cx}" cy="{cy}"/>
 </a:xfrm>
 <a:prstGeom prst="rect"><a:avLst/></a:prstGeom>
 <a:noFill/>
@@ -479,7 +562,8 @@ pub fn create_title_and_content_slide(
         xml.push_str(&crate::generator::table::generate_table_xml(table, 3));
     } else if !content.bullets.is_empty() || !content.content.is_empty() {
         // Render bullets if no table
-        xml.push_str(
+        let (x, y, cx, cy) = CONTENT_BOX;
+        xml.push_str(&format!(
             r#"
 <p:sp>
 <p:nvSpPr>
@@ -489,8 +573,8 @@ pub fn create_title_and_content_slide(
 </p:nvSpPr>
 <p:spPr>
 <a:xfrm>
-<a:off x="457200" y="1600200"/>
-<a:ext cx="8230200" cy="4572000"/>
+<a:off x="{x}" y="{y}"/>
+<a:ext cx="{cx}" cy="{cy}"/>
 </a:xfrm>
 <a:prstGeom prst="rect"><a:avLst/></a:prstGeom>
 <a:noFill/>
@@ -498,7 +582,7 @@ pub fn create_title_and_content_slide(
 <p:txBody>
 <a:bodyPr wrap="square" rtlCol="0"/>
 <a:lstStyle/>"#
-        );
+        ));
 
         let default_props = ExtendedTextProps::with_basic(
             content_size,
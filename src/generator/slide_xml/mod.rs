@@ -11,8 +11,13 @@
 mod common;
 mod layouts;
 mod content;
+mod placeholders;
 
 use super::slide_content::{SlideContent, SlideLayout};
+use super::slide_content::animation::timing_xml_with_media;
+use super::slide_content::raw_xml;
+use super::media::MediaPlayback;
+use super::hyperlinks::Hyperlink;
 
 pub use common::create_slide_rels_xml;
 
@@ -58,7 +63,7 @@ pub fn create_slide_xml(slide_num: usize, title: &str) -> String {
 <a:lstStyle/>
 <a:p>
 <a:r>
-<a:rPr lang="en-US"/>
+<a:rPr lang="en-US" dirty="0"/>
 <a:t>{slide_title}</a:t>
 </a:r>
 <a:endParaRPr lang="en-US"/>
@@ -81,7 +86,7 @@ pub fn create_slide_xml_with_content(
     chart_rids: &[String],
     ink_rel_id: Option<&str>,
 ) -> String {
-    let mut xml = match content.layout {
+    let mut xml = match content.effective_layout() {
         SlideLayout::Blank => layouts::create_blank_slide(content, chart_rids, ink_rel_id),
         SlideLayout::TitleOnly => layouts::create_title_only_slide(content, chart_rids, ink_rel_id),
         SlideLayout::CenteredTitle => layouts::create_centered_title_slide(content, chart_rids, ink_rel_id),
@@ -89,7 +94,25 @@ pub fn create_slide_xml_with_content(
         SlideLayout::TwoColumn => layouts::create_two_column_slide(content, chart_rids, ink_rel_id),
         SlideLayout::SectionHeader => layouts::create_section_header_slide(content, chart_rids, ink_rel_id),
         SlideLayout::TitleAndContent => layouts::create_title_and_content_slide(content, chart_rids, ink_rel_id),
+        SlideLayout::PictureWithCaption => layouts::create_picture_with_caption_slide(content, chart_rids, ink_rel_id),
+        SlideLayout::Comparison => layouts::create_comparison_slide(content, chart_rids, ink_rel_id),
     };
+    placeholders::apply_placeholders(&mut xml, content);
+    raw_xml::append_raw_shapes(&mut xml, &content.raw_shapes);
+
+    // Replace the inherited background if the slide sets its own
+    if let Some(background) = &content.background {
+        let rel_id = format!("rId{}", background_rel_id(content));
+        let bg_xml = background.to_xml(background.image().map(|_| rel_id.as_str()));
+        match (xml.find("<p:bg>"), xml.find("</p:bg>")) {
+            (Some(start), Some(end)) => xml.replace_range(start..end + "</p:bg>".len(), &bg_xml),
+            _ => {
+                if let Some(pos) = xml.find("<p:spTree>") {
+                    xml.insert_str(pos, &bg_xml);
+                }
+            }
+        }
+    }
 
     // Inject transition if present
     let transition_xml = content.transition.to_xml();
@@ -98,9 +121,81 @@ pub fn create_slide_xml_with_content(
         xml.insert_str(pos, &transition_xml);
     }
 
+    // Animation and media timing follows the transition
+    let mut media: Vec<MediaPlayback> = Vec::new();
+    let mut media_id = content::media_start_id(content) as u32;
+    for video in content.videos.iter().filter(|video| video.has_data()) {
+        media.push(video.playback(media_id));
+        media_id += 1;
+    }
+    for audio in content.audios.iter().filter(|audio| audio.has_data()) {
+        media.push(audio.playback(media_id));
+        media_id += 1;
+    }
+    if !content.animations.is_empty() || !media.is_empty() {
+        let text_shape_ids: Vec<u32> = [2, 3, 4]
+            .into_iter()
+            .chain(content.shapes.iter().enumerate().map(|(i, shape)| shape.id.unwrap_or((i + 10) as u32)))
+            .collect();
+        let timing_xml = timing_xml_with_media(&content.animations, &text_shape_ids, &media);
+        if let Some(pos) = xml.rfind("</p:sld>") {
+            xml.insert_str(pos, &timing_xml);
+        }
+    }
+
     xml
 }
 
+/// Relationship number of a background picture: after images, charts, ink, and SVG parts
+pub(crate) fn background_rel_id(content: &SlideContent) -> usize {
+    2 + usize::from(content.notes.is_some())
+        + content.images.len()
+        + content.charts.len()
+        + usize::from(content.ink_annotations.is_some())
+        + content.images.iter().filter(|image| image.svg.is_some()).count()
+}
+
+/// Relationship number of the first video or audio media embed, after the background
+/// picture. Each clip takes three: the media embed, the video/audio link, and its poster or icon.
+pub(crate) fn media_rel_start(content: &SlideContent) -> usize {
+    background_rel_id(content) + usize::from(content.background.as_ref().and_then(|bg| bg.image()).is_some())
+}
+
+/// Relationship number of the first auto-numbered shape hyperlink, after the media clips
+/// and the comment list
+pub(crate) fn hyperlink_rel_start(content: &SlideContent) -> usize {
+    let clips = content.videos.iter().filter(|v| v.has_data()).count()
+        + content.audios.iter().filter(|a| a.has_data()).count();
+    media_rel_start(content) + 3 * clips + usize::from(!content.comments.is_empty())
+}
+
+/// Each shape's hyperlink, or its action button's default navigation, with its
+/// relationship id filled in. An id set on the link is
+/// kept; other links that need a relationship are numbered from `hyperlink_rel_start`,
+/// and show navigation gets the empty id it is written with.
+pub(crate) fn resolved_shape_hyperlinks(content: &SlideContent) -> Vec<Option<Hyperlink>> {
+    let mut next_rel = hyperlink_rel_start(content);
+    content
+        .shapes
+        .iter()
+        .map(|shape| {
+            let mut link = shape
+                .hyperlink
+                .clone()
+                .or_else(|| shape.shape_type.default_action().map(Hyperlink::new))?;
+            if link.r_id.is_none() {
+                if link.action.has_relationship() {
+                    link.r_id = Some(format!("rId{next_rel}"));
+                    next_rel += 1;
+                } else {
+                    link.r_id = Some(String::new());
+                }
+            }
+            Some(link)
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use crate::generator::slide::formatting::parse_inline_formatting;
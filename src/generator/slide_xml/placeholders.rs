@@ -0,0 +1,177 @@
+//! Layout placeholders filled with slide text
+
+use crate::core::escape_xml;
+use crate::generator::layout_parts::layout_placeholders;
+use crate::generator::slide_content::{PlaceholderType, SlideContent};
+
+/// Put each of the slide's placeholder texts into the layout's matching placeholder,
+/// replacing the slide's own title or body text box in that slot
+pub(super) fn apply_placeholders(xml: &mut String, content: &SlideContent) {
+    let layout = content.effective_layout().layout_number();
+    for (kind, text) in &content.placeholders {
+        // The slide's own title box is shape 2 and its body box shape 3
+        let slot_id = if kind.is_title() { 2 } else { 3 };
+        let replaced_at = remove_text_box(xml, slot_id);
+        let id = unused_id(xml, slot_id);
+        let shape = match layout_placeholders(layout).iter().find(|ph| kind.matches(ph.ph_type)) {
+            Some(ph) => placeholder_shape(id, ph.name, ph.ph_type, ph.idx, text),
+            None => fallback_text_box(id, *kind, text),
+        };
+        const TREE_PROPS_END: &str = "</p:grpSpPr>";
+        let at = replaced_at
+            .or_else(|| xml.find(TREE_PROPS_END).map(|pos| pos + TREE_PROPS_END.len()))
+            .unwrap_or(xml.len());
+        xml.insert_str(at, &format!("\n{shape}"));
+    }
+}
+
+/// Remove the text box `<p:sp>` with shape id `id`, returning where it started
+fn remove_text_box(xml: &mut String, id: u32) -> Option<usize> {
+    let start = xml.find(&format!("<p:sp>\n<p:nvSpPr>\n<p:cNvPr id=\"{id}\" "))?;
+    let end = start + xml[start..].find("</p:sp>")? + "</p:sp>".len();
+    xml.replace_range(start..end, "");
+    // Drop the line break that separated the box from the previous element
+    if xml[..start].ends_with('\n') {
+        xml.remove(start - 1);
+        return Some(start - 1);
+    }
+    Some(start)
+}
+
+/// `preferred` unless another shape (such as a table) already has it, else the first
+/// id free below the range used by added shapes
+fn unused_id(xml: &str, preferred: u32) -> u32 {
+    let taken = |id: u32| xml.contains(&format!("<p:cNvPr id=\"{id}\" "));
+    std::iter::once(preferred)
+        .chain(5..10)
+        .find(|&id| !taken(id))
+        .unwrap_or(preferred)
+}
+
+fn paragraphs_xml(text: &str) -> String {
+    if text.is_empty() {
+        return r#"<a:p><a:endParaRPr lang="en-US"/></a:p>"#.to_string();
+    }
+    text.lines()
+        .map(|line| {
+            if line.is_empty() {
+                r#"<a:p><a:endParaRPr lang="en-US"/></a:p>"#.to_string()
+            } else {
+                format!(r#"<a:p><a:r><a:rPr lang="en-US" dirty="0"/><a:t>{}</a:t></a:r></a:p>"#, escape_xml(line))
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Placeholder shape with no geometry or run formatting, so both come from the layout and master
+fn placeholder_shape(id: u32, name: &str, ph_type: &str, idx: Option<u32>, text: &str) -> String {
+    let idx_xml = idx.map(|i| format!(r#" idx="{i}""#)).unwrap_or_default();
+    let paragraphs = paragraphs_xml(text);
+    format!(
+        r#"<p:sp>
+<p:nvSpPr>
+<p:cNvPr id="{id}" name="{name}"/>
+<p:cNvSpPr><a:spLocks noGrp="1"/></p:cNvSpPr>
+<p:nvPr><p:ph type="{ph_type}"{idx_xml}/></p:nvPr>
+</p:nvSpPr>
+<p:spPr/>
+<p:txBody>
+<a:bodyPr/>
+<a:lstStyle/>
+{paragraphs}
+</p:txBody>
+</p:sp>"#
+    )
+}
+
+/// Text box where the placeholder sits on the standard layout that defines it
+fn fallback_text_box(id: u32, kind: PlaceholderType, text: &str) -> String {
+    let (x, y, cx, cy) = layout_placeholders(kind.home_layout().layout_number())
+        .iter()
+        .find(|ph| ph.ph_type == kind.as_str())
+        .map_or((457_200, 274_638, 8_229_600, 1_143_000), |ph| ph.bounds);
+    let anchor = if kind.is_title() { r#" anchor="ctr""# } else { "" };
+    let paragraphs = paragraphs_xml(text);
+    format!(
+        r#"<p:sp>
+<p:nvSpPr>
+<p:cNvPr id="{id}" name="TextBox {id}"/>
+<p:cNvSpPr txBox="1"/>
+<p:nvPr/>
+</p:nvSpPr>
+<p:spPr>
+<a:xfrm>
+<a:off x="{x}" y="{y}"/>
+<a:ext cx="{cx}" cy="{cy}"/>
+</a:xfrm>
+<a:prstGeom prst="rect"><a:avLst/></a:prstGeom>
+<a:noFill/>
+</p:spPr>
+<p:txBody>
+<a:bodyPr wrap="square" rtlCol="0"{anchor}/>
+<a:lstStyle/>
+{paragraphs}
+</p:txBody>
+</p:sp>"#
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::generator::slide_content::{PlaceholderType, SlideContent, SlideLayout};
+    use crate::generator::slide_xml::create_slide_xml_with_content;
+
+    #[test]
+    fn test_title_placeholder_replaces_title_box() {
+        let slide = SlideContent::new("Ignored")
+            .add_bullet("Point")
+            .set_placeholder(PlaceholderType::Title, "Quarterly Review");
+        let xml = create_slide_xml_with_content(1, &slide, &[], None);
+        assert!(xml.contains(r#"<p:nvPr><p:ph type="title"/></p:nvPr>"#));
+        assert!(xml.contains("<a:t>Quarterly Review</a:t>"));
+        assert!(!xml.contains("Ignored"));
+        assert_eq!(xml.matches(r#"<p:cNvPr id="2" "#).count(), 1);
+        // The slide's body box is untouched
+        assert!(xml.contains("<a:t>Point</a:t>"));
+    }
+
+    #[test]
+    fn test_title_slide_placeholders_inherit_layout() {
+        let slide = SlideContent::new("Deck")
+            .layout(SlideLayout::CenteredTitle)
+            .set_placeholder(PlaceholderType::Title, "Launch Plan")
+            .set_placeholder(PlaceholderType::Subtitle, "Draft\nFor review");
+        let xml = create_slide_xml_with_content(1, &slide, &[], None);
+        assert!(xml.contains(r#"<p:ph type="ctrTitle"/>"#));
+        assert!(xml.contains(r#"<p:cNvPr id="3" name="Subtitle"/>"#));
+        assert!(xml.contains(r#"<p:ph type="subTitle"/>"#));
+        assert_eq!(xml.matches("<p:spPr/>").count(), 2);
+        assert!(xml.contains("<a:t>Draft</a:t></a:r></a:p>\n<a:p><a:r><a:rPr lang=\"en-US\" dirty=\"0\"/><a:t>For review</a:t>"));
+    }
+
+    #[test]
+    fn test_body_placeholder_replaces_bullets_box() {
+        let slide = SlideContent::new("Agenda")
+            .add_bullet("Old")
+            .set_placeholder(PlaceholderType::Body, "New")
+            .set_placeholder(PlaceholderType::Subtitle, "Newer");
+        assert_eq!(slide.placeholders.len(), 1);
+        let xml = create_slide_xml_with_content(1, &slide, &[], None);
+        assert!(xml.contains(r#"<p:ph type="body" idx="1"/>"#));
+        assert!(xml.contains("<a:t>Newer</a:t>"));
+        assert!(!xml.contains("<a:t>Old</a:t>"));
+    }
+
+    #[test]
+    fn test_missing_placeholder_falls_back_to_text_box() {
+        let slide = SlideContent::new("")
+            .layout(SlideLayout::Blank)
+            .set_placeholder(PlaceholderType::Body, "Loose text");
+        let xml = create_slide_xml_with_content(1, &slide, &[], None);
+        assert!(!xml.contains("<p:ph "));
+        assert!(xml.contains(r#"<p:cNvPr id="3" name="TextBox 3"/>"#));
+        assert!(xml.contains(r#"<a:off x="457200" y="1600200"/>"#));
+        assert!(xml.contains("<a:t>Loose text</a:t>"));
+    }
+}
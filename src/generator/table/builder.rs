@@ -5,6 +5,7 @@ use super::row::TableRow;
 
 /// Table definition with rows and positioning
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Table {
     /// Table rows
     pub rows: Vec<TableRow>,
@@ -1,7 +1,11 @@
 //! Table cell definition and formatting
 
+use crate::elements::ColorInput;
+use crate::generator::text::TextOrientation;
+
 /// Horizontal text alignment
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum CellAlign {
     Left,
     #[default]
@@ -24,6 +28,7 @@ impl CellAlign {
 
 /// Vertical text alignment
 #[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum CellVAlign {
     Top,
     #[default]
@@ -44,6 +49,7 @@ impl CellVAlign {
 
 /// Table cell content with formatting options
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TableCell {
     /// Cell text content
     pub text: String,
@@ -75,6 +81,11 @@ pub struct TableCell {
     pub h_merge: bool,
     /// Whether this cell is vertically merged (covered by a rowSpan)
     pub v_merge: bool,
+    /// Exclude cell text from spelling/grammar checks
+    pub no_proof: bool,
+    /// Direction the cell text flows
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub text_direction: TextOrientation,
 }
 
 impl TableCell {
@@ -96,9 +107,17 @@ impl TableCell {
             row_span: None,
             h_merge: false,
             v_merge: false,
+            no_proof: false,
+            text_direction: TextOrientation::Horizontal,
         }
     }
 
+    /// Set the direction the cell text flows, e.g. vertical for a narrow row header
+    pub fn text_direction(mut self, direction: TextOrientation) -> Self {
+        self.text_direction = direction;
+        self
+    }
+
     /// Set cell text as bold
     pub fn bold(mut self) -> Self {
         self.bold = true;
@@ -117,15 +136,15 @@ impl TableCell {
         self
     }
 
-    /// Set cell text color (RGB hex format, e.g., "FF0000" or "#FF0000")
-    pub fn text_color(mut self, color: &str) -> Self {
-        self.text_color = Some(color.trim_start_matches('#').to_uppercase());
+    /// Set cell text color (RGB hex such as "FF0000", or a theme color such as `SchemeColor::Accent1`)
+    pub fn text_color(mut self, color: impl Into<ColorInput>) -> Self {
+        self.text_color = Some(color.into().into_value());
         self
     }
 
-    /// Set cell background color (RGB hex format, e.g., "FF0000" or "#FF0000")
-    pub fn background_color(mut self, color: &str) -> Self {
-        self.background_color = Some(color.trim_start_matches('#').to_uppercase());
+    /// Set cell background color (RGB hex such as "FF0000", or a theme color such as `SchemeColor::Accent1`)
+    pub fn background_color(mut self, color: impl Into<ColorInput>) -> Self {
+        self.background_color = Some(color.into().into_value());
         self
     }
 
@@ -213,6 +232,12 @@ impl TableCell {
         self
     }
 
+    /// Exclude cell text from spelling/grammar checks (numbers, codes)
+    pub fn no_proof(mut self) -> Self {
+        self.no_proof = true;
+        self
+    }
+
     /// Alias: set column span (gridSpan)
     pub fn with_col_span(self, span: u32) -> Self {
         self.grid_span(span)
@@ -3,7 +3,7 @@
 use super::cell::{CellAlign, CellVAlign, TableCell};
 use crate::core::escape_xml;
 use crate::generator::slide_content::table_merge::CellMergeState;
-use crate::generator::text::{color_to_xml, TextFormat};
+use crate::generator::text::{color_to_xml, TextFormat, TextOrientation};
 
 impl TableCell {
     /// Convert cell formatting to the shared text format model.
@@ -27,6 +27,9 @@ impl TableCell {
         if let Some(ref family) = self.font_family {
             format = format.font_family(family);
         }
+        if self.no_proof {
+            format = format.no_proof();
+        }
         format
     }
 }
@@ -84,23 +87,26 @@ pub fn body_props_xml(_cell: &TableCell) -> String {
     r#"<a:bodyPr/>"#.to_string()
 }
 
-/// Cell properties (`<a:tcPr>`) including background and vertical alignment.
+/// Cell properties (`<a:tcPr>`) including background, vertical alignment and text direction.
 pub fn tc_properties_xml(cell: &TableCell) -> String {
-    let anchor = if cell.valign == CellVAlign::Middle {
-        String::new()
-    } else {
-        format!(r#" anchor="{}""#, cell.valign.as_str())
-    };
+    // Table cells take `vert` on tcPr; PowerPoint ignores it on the cell's bodyPr.
+    let mut attrs = String::new();
+    if cell.text_direction != TextOrientation::Horizontal {
+        attrs.push_str(&format!(r#" vert="{}""#, cell.text_direction.to_xml()));
+    }
+    if cell.valign != CellVAlign::Middle {
+        attrs.push_str(&format!(r#" anchor="{}""#, cell.valign.as_str()));
+    }
 
     if let Some(ref background) = cell.background_color {
         format!(
-            r#"<a:tcPr{anchor}>{}</a:tcPr>"#,
+            r#"<a:tcPr{attrs}>{}</a:tcPr>"#,
             color_to_xml(background)
         )
-    } else if anchor.is_empty() {
+    } else if attrs.is_empty() {
         "<a:tcPr/>".to_string()
     } else {
-        format!(r#"<a:tcPr{anchor}/>"#)
+        format!(r#"<a:tcPr{attrs}/>"#)
     }
 }
 
@@ -152,6 +158,13 @@ mod tests {
         assert!(xml.contains("solidFill"));
     }
 
+    #[test]
+    fn test_run_properties_no_proof() {
+        let xml = run_properties_xml(&TableCell::new("1,200").no_proof());
+        assert!(xml.contains(r#"noProof="1""#));
+        assert!(xml.contains(r#"dirty="0""#));
+    }
+
     #[test]
     fn test_tc_properties_background_and_valign() {
         let cell = TableCell::new("Top").background_color("0000FF").valign_top();
@@ -160,6 +173,13 @@ mod tests {
         assert!(xml.contains(r#"anchor="t""#));
     }
 
+    #[test]
+    fn test_tc_properties_text_direction() {
+        let cell = TableCell::new("Q1").text_direction(TextOrientation::Vertical270).valign_bottom();
+        assert_eq!(tc_properties_xml(&cell), r#"<a:tcPr vert="vert270" anchor="b"/>"#);
+        assert_eq!(tc_properties_xml(&TableCell::new("Q1")), "<a:tcPr/>");
+    }
+
     #[test]
     fn test_generate_cell_alignment() {
         let cell = TableCell::new("Left").align_left();
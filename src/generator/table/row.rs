@@ -4,6 +4,7 @@ use super::cell::TableCell;
 
 /// Table row containing cells
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TableRow {
     /// Cells in this row
     pub cells: Vec<TableCell>,
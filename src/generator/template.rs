@@ -1,20 +1,51 @@
 //! Load theme / master / layout parts from an existing PPTX template.
+//!
+//! The template's slide masters keep their part numbers; each master's theme is
+//! renumbered to `theme{n}` as [`master_theme_number`] assigns it, so it can't
+//! collide with the notes and handout themes the builder writes. Parts the
+//! masters, layouts and themes relate to directly (logos, background images,
+//! tags) are copied next to the original as `template_<name>`. The template's
+//! own slides are not carried over.
 
 use std::collections::HashMap;
 use std::path::Path;
 
-use crate::exc::{PptxError, Result};
+use crate::core::{rels_source_dir, resolve_rel_target};
+use crate::exc::{messages, PptxError, Result};
+use crate::generator::layout_parts::{layout_type, master_theme_number};
+use crate::generator::package_xml::slide_master_id;
 use crate::opc::Package;
+use crate::oxml::{ParsedTheme, ThemeParser, XmlParser};
 
-/// Theme + master + layout parts cloned from an existing `.pptx` file.
+const SLIDE_MASTER_CONTENT_TYPE: &str = "application/vnd.openxmlformats-officedocument.presentationml.slideMaster+xml";
+const SLIDE_LAYOUT_CONTENT_TYPE: &str = "application/vnd.openxmlformats-officedocument.presentationml.slideLayout+xml";
+const THEME_CONTENT_TYPE: &str = "application/vnd.openxmlformats-officedocument.theme+xml";
+
+/// A slide layout carried over from the template
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TemplateLayout {
+    /// Part number, as in `slideLayout{number}.xml`
+    pub number: usize,
+    /// Slide master the layout belongs to (1-based)
+    pub master: usize,
+    /// Layout `type` attribute (`title`, `obj`, ...); `cust` when the part has none
+    pub layout_type: String,
+    /// Layout name shown in PowerPoint's layout gallery
+    pub name: String,
+}
+
+/// Theme + master + layout parts cloned from an existing `.pptx` or `.potx` file.
 #[derive(Clone, Debug, Default)]
 pub struct PptxTemplate {
     parts: HashMap<String, Vec<u8>>,
-    layout_count: usize,
+    layouts: Vec<TemplateLayout>,
+    master_ids: Vec<u32>,
+    content_types: Vec<(String, String)>,
+    table_styles: Option<Vec<u8>>,
 }
 
 impl PptxTemplate {
-    /// Load template parts from a `.pptx` on disk.
+    /// Load template parts from a `.pptx` or `.potx` on disk.
     pub fn load(path: impl AsRef<Path>) -> Result<Self> {
         let pkg = Package::open(path)?;
         Self::from_package(&pkg)
@@ -22,47 +53,103 @@ impl PptxTemplate {
 
     /// Extract template parts from an opened package.
     pub fn from_package(pkg: &Package) -> Result<Self> {
-        let mut parts = HashMap::new();
-        for path in pkg.part_paths() {
-            if (path.starts_with("ppt/theme/")
-                || path.starts_with("ppt/slideMasters/")
-                || path.starts_with("ppt/slideLayouts/")
-                || path == "ppt/tableStyles.xml")
-                && let Some(data) = pkg.get_part(path) {
-                    parts.insert(path.to_string(), data.to_vec());
-                }
-        }
-
-        if !parts.keys().any(|p| p.starts_with("ppt/slideMasters/")) {
+        let mut masters: Vec<usize> = pkg
+            .part_paths()
+            .into_iter()
+            .filter_map(|path| part_number(path, "ppt/slideMasters/slideMaster"))
+            .collect();
+        if masters.is_empty() {
             return Err(PptxError::InvalidValue(
                 "template missing ppt/slideMasters/".into(),
             ));
         }
+        masters.sort_unstable();
+        if masters.iter().zip(1..).any(|(&number, expected)| number != expected) {
+            return Err(template_error("slide masters must be numbered slideMaster1.xml upward"));
+        }
 
-        let layout_count = parts
-            .keys()
-            .filter(|p| {
-                p.starts_with("ppt/slideLayouts/slideLayout")
-                    && p.ends_with(".xml")
-                    && !p.contains("_rels")
-            })
-            .count()
-            .max(1);
+        let mut loader = Loader::new(pkg);
+        let mut layouts = Vec::new();
+        for &master in &masters {
+            let master_path = format!("ppt/slideMasters/slideMaster{master}.xml");
+            let theme = master_theme_number(master);
+            let related = loader.copy_rels(
+                &format!("ppt/slideMasters/_rels/slideMaster{master}.xml.rels"),
+                &format!("ppt/slideMasters/_rels/slideMaster{master}.xml.rels"),
+                Some(&format!("../theme/theme{theme}.xml")),
+            )?;
+            loader.copy_part(&master_path, &master_path)?;
+
+            let mut has_layout = false;
+            for (rel_type, path) in related {
+                if rel_type.ends_with("/theme") {
+                    let theme_path = format!("ppt/theme/theme{theme}.xml");
+                    loader.copy_part(&path, &theme_path)?;
+                    loader.copy_rels(
+                        &rels_path_of(&path),
+                        &format!("ppt/theme/_rels/theme{theme}.xml.rels"),
+                        None,
+                    )?;
+                } else if rel_type.ends_with("/slideLayout")
+                    && let Some(number) = part_number(&path, "ppt/slideLayouts/slideLayout")
+                {
+                    loader.copy_part(&path, &path)?;
+                    loader.copy_rels(&rels_path_of(&path), &rels_path_of(&path), None)?;
+                    layouts.push(read_layout(pkg, &path, number, master));
+                    has_layout = true;
+                }
+            }
+            if !has_layout {
+                return Err(template_error(&format!("slide master {master} has no layouts")));
+            }
+        }
 
-        Ok(PptxTemplate { parts, layout_count })
+        Ok(PptxTemplate {
+            master_ids: read_master_ids(pkg, masters.len()),
+            table_styles: pkg.get_part("ppt/tableStyles.xml").map(<[u8]>::to_vec),
+            parts: loader.parts,
+            content_types: loader.content_types,
+            layouts,
+        })
     }
 
     pub fn layout_count(&self) -> usize {
-        self.layout_count
+        self.layouts.len()
+    }
+
+    /// Number of slide masters in the template
+    pub fn master_count(&self) -> usize {
+        self.master_ids.len()
+    }
+
+    /// `sldMasterId` ids of the template's masters, in master order
+    pub fn master_ids(&self) -> &[u32] {
+        &self.master_ids
     }
 
+    /// Layouts reachable from the template's masters, in master order
+    pub fn layouts(&self) -> &[TemplateLayout] {
+        &self.layouts
+    }
+
+    /// Master, layout and theme parts (and what they relate to), as written to the new deck
     pub fn parts(&self) -> &HashMap<String, Vec<u8>> {
         &self.parts
     }
 
+    /// The template's `ppt/tableStyles.xml`, written in place of the stock one
+    pub fn table_styles(&self) -> Option<&[u8]> {
+        self.table_styles.as_deref()
+    }
+
+    /// Colors and fonts of the first master's theme
+    pub fn theme(&self) -> Option<ParsedTheme> {
+        let xml = self.parts.get("ppt/theme/theme1.xml")?;
+        ThemeParser::parse(&String::from_utf8_lossy(xml)).ok()
+    }
+
     pub fn has_layout(&self, n: usize) -> bool {
-        self.parts
-            .contains_key(&format!("ppt/slideLayouts/slideLayout{n}.xml"))
+        self.layouts.iter().any(|layout| layout.number == n)
     }
 
     /// Resolve layout index for a slide, capped to layouts available in the template.
@@ -70,8 +157,234 @@ impl PptxTemplate {
         if self.has_layout(requested) {
             requested
         } else {
-            1
+            self.layouts.first().map_or(1, |layout| layout.number)
+        }
+    }
+
+    /// Layout part for standard layout `requested` on slide master `master` (1-based)
+    ///
+    /// Prefers the master's layout at the same position when its type matches,
+    /// then the master's first layout of that type, then the one at the same
+    /// position, then the master's first layout.
+    pub fn layout_for(&self, requested: usize, master: usize) -> usize {
+        let on_master: Vec<&TemplateLayout> = self.layouts.iter().filter(|l| l.master == master).collect();
+        let wanted = layout_type(requested);
+        let same_position = requested.checked_sub(1).and_then(|i| on_master.get(i).copied());
+        same_position
+            .filter(|l| l.layout_type == wanted)
+            .or_else(|| on_master.iter().copied().find(|l| l.layout_type == wanted))
+            .or(same_position)
+            .or_else(|| on_master.first().copied())
+            .map_or_else(|| self.resolve_layout_number(requested), |l| l.number)
+    }
+
+    /// Swap the stock master, layout and theme1 overrides in `[Content_Types].xml`
+    /// for the template's parts
+    pub(crate) fn apply_content_types(&self, xml: &mut String) {
+        for prefix in ["/ppt/slideLayouts/", "/ppt/slideMasters/", "/ppt/theme/theme1.xml\""] {
+            remove_overrides(xml, prefix);
+        }
+        let Some(pos) = xml.rfind("</Types>") else {
+            return;
+        };
+        let mut names: Vec<&(String, String)> = self.content_types.iter().collect();
+        names.sort();
+        let overrides: String = names
+            .into_iter()
+            .map(|(part, content_type)| format!("<Override PartName=\"/{part}\" ContentType=\"{content_type}\"/>\n"))
+            .collect();
+        xml.insert_str(pos, &overrides);
+    }
+}
+
+fn template_error(detail: &str) -> PptxError {
+    PptxError::InvalidValue(messages::invalid_value("template", detail))
+}
+
+/// `N` in `{prefix}N.xml`
+fn part_number(path: &str, prefix: &str) -> Option<usize> {
+    path.strip_prefix(prefix)?.strip_suffix(".xml")?.parse().ok()
+}
+
+/// `dir/_rels/name.rels` for part `dir/name`
+fn rels_path_of(part: &str) -> String {
+    match part.rsplit_once('/') {
+        Some((dir, name)) => format!("{dir}/_rels/{name}.rels"),
+        None => format!("_rels/{part}.rels"),
+    }
+}
+
+/// `dir/template_name` for `dir/name`, for a target or a part path
+fn template_name(path: &str) -> String {
+    match path.rsplit_once('/') {
+        Some((dir, name)) => format!("{dir}/template_{name}"),
+        None => format!("template_{path}"),
+    }
+}
+
+fn read_layout(pkg: &Package, path: &str, number: usize, master: usize) -> TemplateLayout {
+    let root = pkg.get_part_string(path).and_then(|xml| XmlParser::parse_str(&xml).ok());
+    let attr = |element: Option<&crate::oxml::XmlElement>, name: &str| {
+        element.and_then(|e| e.attr(name)).map(str::to_string)
+    };
+    TemplateLayout {
+        number,
+        master,
+        layout_type: attr(root.as_ref(), "type").unwrap_or_else(|| "cust".to_string()),
+        name: attr(root.as_ref().and_then(|r| r.find("cSld")), "name").unwrap_or_default(),
+    }
+}
+
+/// Master ids from the template's `presentation.xml`, by master number
+fn read_master_ids(pkg: &Package, master_count: usize) -> Vec<u32> {
+    let mut ids: Vec<u32> = (1..=master_count).map(slide_master_id).collect();
+    let Some(presentation) = pkg.get_part_string("ppt/presentation.xml").and_then(|xml| XmlParser::parse_str(&xml).ok()) else {
+        return ids;
+    };
+    let targets: HashMap<String, String> = pkg
+        .get_part_string("ppt/_rels/presentation.xml.rels")
+        .and_then(|xml| XmlParser::parse_str(&xml).ok())
+        .map(|rels| {
+            rels.find_all("Relationship")
+                .into_iter()
+                .filter_map(|rel| Some((rel.attr("Id")?.to_string(), resolve_rel_target("ppt", rel.attr("Target")?))))
+                .collect()
+        })
+        .unwrap_or_default();
+    for entry in presentation.find_all_descendants("sldMasterId") {
+        if let Some(id) = entry.attr("id").and_then(|id| id.parse().ok())
+            && let Some(number) = entry
+                .attr("r:id")
+                .and_then(|rid| targets.get(rid))
+                .and_then(|target| part_number(target, "ppt/slideMasters/slideMaster"))
+            && let Some(slot) = number.checked_sub(1).and_then(|i| ids.get_mut(i))
+        {
+            *slot = id;
+        }
+    }
+    ids
+}
+
+/// Remove every `<Override>` whose part name starts with `prefix`
+fn remove_overrides(xml: &mut String, prefix: &str) {
+    let needle = format!("<Override PartName=\"{prefix}");
+    while let Some(start) = xml.find(&needle) {
+        let Some(len) = xml[start..].find("/>") else { break };
+        let end = start + len + 2;
+        let start = if xml[..start].ends_with('\n') { start - 1 } else { start };
+        xml.replace_range(start..end, "");
+    }
+}
+
+/// Content types declared by the template's `[Content_Types].xml`
+#[derive(Default)]
+struct ContentTypes {
+    defaults: HashMap<String, String>,
+    overrides: HashMap<String, String>,
+}
+
+impl ContentTypes {
+    fn parse(pkg: &Package) -> Self {
+        let mut types = ContentTypes::default();
+        let Some(root) = pkg.get_part_string("[Content_Types].xml").and_then(|xml| XmlParser::parse_str(&xml).ok()) else {
+            return types;
+        };
+        for entry in root.find_all("Default") {
+            if let (Some(ext), Some(content_type)) = (entry.attr("Extension"), entry.attr("ContentType")) {
+                types.defaults.insert(ext.to_ascii_lowercase(), content_type.to_string());
+            }
+        }
+        for entry in root.find_all("Override") {
+            if let (Some(part), Some(content_type)) = (entry.attr("PartName"), entry.attr("ContentType")) {
+                types.overrides.insert(part.trim_start_matches('/').to_string(), content_type.to_string());
+            }
+        }
+        types
+    }
+
+    fn of(&self, path: &str) -> String {
+        let ext = path.rsplit_once('.').map(|(_, ext)| ext.to_ascii_lowercase()).unwrap_or_default();
+        self.overrides
+            .get(path)
+            .or_else(|| self.defaults.get(&ext))
+            .map_or_else(
+                || {
+                    if path.starts_with("ppt/slideMasters/") {
+                        SLIDE_MASTER_CONTENT_TYPE
+                    } else if path.starts_with("ppt/slideLayouts/") {
+                        SLIDE_LAYOUT_CONTENT_TYPE
+                    } else if path.starts_with("ppt/theme/") {
+                        THEME_CONTENT_TYPE
+                    } else {
+                        "application/octet-stream"
+                    }
+                    .to_string()
+                },
+                String::clone,
+            )
+    }
+}
+
+/// Copies template parts under their new names, recording each one's content type
+struct Loader<'a> {
+    pkg: &'a Package,
+    types: ContentTypes,
+    parts: HashMap<String, Vec<u8>>,
+    content_types: Vec<(String, String)>,
+}
+
+impl<'a> Loader<'a> {
+    fn new(pkg: &'a Package) -> Self {
+        Loader { pkg, types: ContentTypes::parse(pkg), parts: HashMap::new(), content_types: Vec::new() }
+    }
+
+    fn copy_part(&mut self, from: &str, to: &str) -> Result<()> {
+        let data = self
+            .pkg
+            .get_part(from)
+            .ok_or_else(|| template_error(&format!("missing part {from}")))?;
+        if self.parts.insert(to.to_string(), data.to_vec()).is_none() && !to.ends_with(".rels") {
+            self.content_types.push((to.to_string(), self.types.of(from)));
         }
+        Ok(())
+    }
+
+    /// Copy a part's relationships to `to`, retargeting the theme to `theme_target` and
+    /// copying media and other related parts as `template_<name>`
+    ///
+    /// Returns the type and resolved path of each internal relationship as the template had it.
+    fn copy_rels(&mut self, from: &str, to: &str, theme_target: Option<&str>) -> Result<Vec<(String, String)>> {
+        let Some(mut xml) = self.pkg.get_part_string(from) else {
+            return Ok(Vec::new());
+        };
+        let rels = XmlParser::parse_str(&xml)?;
+        let base_dir = rels_source_dir(from);
+        let mut related = Vec::new();
+        for rel in rels.find_all("Relationship") {
+            let (Some(rel_type), Some(target)) = (rel.attr("Type"), rel.attr("Target")) else {
+                continue;
+            };
+            if rel.attr("TargetMode") == Some("External") {
+                continue;
+            }
+            let path = resolve_rel_target(&base_dir, target);
+            let new_target = if rel_type.ends_with("/slideLayout") || rel_type.ends_with("/slideMaster") {
+                None
+            } else if rel_type.ends_with("/theme") {
+                theme_target.map(str::to_string)
+            } else if self.pkg.has_part(&path) {
+                self.copy_part(&path, &template_name(&path))?;
+                Some(template_name(target))
+            } else {
+                None
+            };
+            if let Some(new_target) = new_target {
+                xml = xml.replace(&format!("Target=\"{target}\""), &format!("Target=\"{new_target}\""));
+            }
+            related.push((rel_type.to_string(), path));
+        }
+        self.parts.insert(to.to_string(), xml.into_bytes());
+        Ok(related)
     }
 }
 
@@ -90,4 +403,32 @@ mod tests {
         assert!(tpl.has_layout(1));
         std::fs::remove_file(dir).ok();
     }
+
+    #[test]
+    fn layouts_resolve_by_type_on_their_master() {
+        let layout = |number, master, layout_type: &str| TemplateLayout {
+            number,
+            master,
+            layout_type: layout_type.to_string(),
+            name: String::new(),
+        };
+        let tpl = PptxTemplate {
+            layouts: vec![layout(1, 1, "title"), layout(2, 1, "blank"), layout(3, 1, "obj"), layout(4, 2, "cust")],
+            master_ids: vec![slide_master_id(1), slide_master_id(2)],
+            ..Default::default()
+        };
+        assert_eq!(tpl.layout_for(1, 1), 1);
+        assert_eq!(tpl.layout_for(2, 1), 3);
+        assert_eq!(tpl.layout_for(5, 1), 2);
+        assert_eq!(tpl.layout_for(9, 1), 1);
+        assert_eq!(tpl.layout_for(2, 2), 4);
+    }
+
+    #[test]
+    fn remove_overrides_drops_whole_lines() {
+        let mut xml = "<Types>\n<Override PartName=\"/ppt/slideLayouts/slideLayout1.xml\" ContentType=\"x\"/>\n<Override PartName=\"/ppt/theme/theme1.xml\" ContentType=\"t\"/>\n<Override PartName=\"/ppt/theme/theme12.xml\" ContentType=\"t\"/>\n</Types>".to_string();
+        remove_overrides(&mut xml, "/ppt/slideLayouts/");
+        remove_overrides(&mut xml, "/ppt/theme/theme1.xml\"");
+        assert_eq!(xml, "<Types>\n<Override PartName=\"/ppt/theme/theme12.xml\" ContentType=\"t\"/>\n</Types>");
+    }
 }
@@ -1,7 +1,12 @@
 //! Text formatting options
 
+use crate::elements::{color_value_xml, ColorInput, RgbInput, SchemeColor};
+use crate::generator::gradients::GradientFill;
+use crate::generator::shapes::{ShapeLine, ShapeShadow};
+
 /// Text formatting options
 #[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TextFormat {
     pub bold: bool,
     pub italic: bool,
@@ -13,6 +18,14 @@ pub struct TextFormat {
     pub font_family: Option<String>, // Font family name (e.g., "Arial")
     pub subscript: bool,
     pub superscript: bool,
+    pub no_proof: bool,             // Exclude from spelling/grammar checks
+    pub char_spacing: Option<i32>,  // Letter spacing in 1/100 pt (negative condenses)
+    pub kerning: Option<u32>,       // Minimum font size for kerning in 1/100 pt
+    pub gradient: Option<GradientFill>, // Gradient text fill (replaces `color`)
+    pub outline: Option<ShapeLine>, // Glyph outline, width in EMU
+    pub shadow: Option<ShapeShadow>, // Drop shadow behind the glyphs
+    pub language: Option<String>,   // Proofing language tag (e.g., "zh-CN"); en-US when unset
+    pub alt_language: Option<String>, // Alternate language for fonts/proofing
 }
 
 impl TextFormat {
@@ -45,15 +58,23 @@ impl TextFormat {
         self
     }
 
-    /// Set text color (RGB hex format)
-    pub fn color(mut self, hex_color: &str) -> Self {
-        self.color = Some(hex_color.trim_start_matches('#').to_uppercase());
+    /// Set text color (RGB or theme scheme color); clears any gradient fill
+    pub fn color(mut self, color: impl Into<ColorInput>) -> Self {
+        self.color = Some(color.into().into_value());
+        self.gradient = None;
+        self
+    }
+
+    /// Fill text with a gradient; clears any solid color
+    pub fn gradient_fill(mut self, gradient: GradientFill) -> Self {
+        self.gradient = Some(gradient);
+        self.color = None;
         self
     }
     
     /// Set highlight/background color (RGB hex format)
-    pub fn highlight(mut self, hex_color: &str) -> Self {
-        self.highlight = Some(hex_color.trim_start_matches('#').to_uppercase());
+    pub fn highlight(mut self, hex_color: impl Into<RgbInput>) -> Self {
+        self.highlight = Some(hex_color.into().into_value());
         self
     }
 
@@ -83,6 +104,71 @@ impl TextFormat {
         self
     }
 
+    /// Outline the glyphs; a zero width removes the outline
+    pub fn outline(mut self, width_emu: u32, hex_color: impl Into<RgbInput>) -> Self {
+        self.outline = (width_emu > 0).then(|| ShapeLine::new(hex_color, width_emu));
+        self
+    }
+
+    /// Add a drop shadow behind the glyphs
+    pub fn shadow(mut self, shadow: ShapeShadow) -> Self {
+        self.shadow = Some(shadow);
+        self
+    }
+
+    /// Set the proofing language tag (e.g., "fr-FR")
+    pub fn language(mut self, lang: &str) -> Self {
+        self.language = Some(lang.to_string());
+        self
+    }
+
+    /// Set the alternate language tag (`altLang`)
+    pub fn alt_lang(mut self, lang: &str) -> Self {
+        self.alt_language = Some(lang.to_string());
+        self
+    }
+
+    /// `lang`/`altLang` attributes, defaulting to en-US
+    pub fn lang_attrs(&self) -> String {
+        let mut attrs = format!(
+            " lang=\"{}\"",
+            super::escape_xml(self.language.as_deref().unwrap_or("en-US"))
+        );
+        if let Some(ref alt) = self.alt_language {
+            attrs.push_str(&format!(" altLang=\"{}\"", super::escape_xml(alt)));
+        }
+        attrs
+    }
+
+    /// Exclude this text from spelling/grammar checks (`noProof="1"`)
+    pub fn no_proof(mut self) -> Self {
+        self.no_proof = true;
+        self
+    }
+
+    /// Set character spacing in points (negative values condense text)
+    pub fn char_spacing(mut self, points: f32) -> Self {
+        self.char_spacing = Some((points * 100.0).round() as i32);
+        self
+    }
+
+    /// Kern text at or above the given font size in points
+    pub fn kerning(mut self, points: u32) -> Self {
+        self.kerning = Some(points * 100);
+        self
+    }
+
+    /// Baseline offset in thousandths of a percent, if sub/superscript is set
+    pub fn baseline(&self) -> Option<i32> {
+        if self.subscript {
+            Some(-25000) // 25% below baseline
+        } else if self.superscript {
+            Some(30000) // 30% above baseline
+        } else {
+            None
+        }
+    }
+
     /// Generate XML attributes for text formatting
     pub fn to_xml_attrs(&self) -> String {
         let mut attrs = String::new();
@@ -103,23 +189,33 @@ impl TextFormat {
             attrs.push_str(" strike=\"sngStrike\"");
         }
         
-        if self.subscript {
-            attrs.push_str(" baseline=\"-25000\""); // 25% below baseline
-        } else if self.superscript {
-            attrs.push_str(" baseline=\"30000\""); // 30% above baseline
+        if let Some(baseline) = self.baseline() {
+            attrs.push_str(&format!(" baseline=\"{}\"", baseline));
         }
 
         if let Some(size) = self.font_size {
             attrs.push_str(&format!(" sz=\"{}\"", size * 100));
         }
 
+        if let Some(kern) = self.kerning {
+            attrs.push_str(&format!(" kern=\"{}\"", kern));
+        }
+
+        if let Some(spc) = self.char_spacing {
+            attrs.push_str(&format!(" spc=\"{}\"", spc));
+        }
+
+        if self.no_proof {
+            attrs.push_str(" noProof=\"1\"");
+        }
+
         attrs
     }
     
     /// Generate highlight element if set
     pub fn to_highlight_xml(&self) -> String {
         if let Some(ref color) = self.highlight {
-            format!(r#"<a:highlight><a:srgbClr val="{}"/></a:highlight>"#, color)
+            format!("<a:highlight>{}</a:highlight>", color_element_xml(color))
         } else {
             String::new()
         }
@@ -128,6 +224,7 @@ impl TextFormat {
 
 /// Formatted text with styling
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FormattedText {
     pub text: String,
     pub format: TextFormat,
@@ -173,13 +270,13 @@ impl FormattedText {
     }
 
     /// Builder method for color
-    pub fn color(mut self, hex_color: &str) -> Self {
-        self.format = self.format.color(hex_color);
+    pub fn color(mut self, color: impl Into<ColorInput>) -> Self {
+        self.format = self.format.color(color);
         self
     }
     
     /// Builder method for highlight
-    pub fn highlight(mut self, hex_color: &str) -> Self {
+    pub fn highlight(mut self, hex_color: impl Into<RgbInput>) -> Self {
         self.format = self.format.highlight(hex_color);
         self
     }
@@ -201,12 +298,26 @@ impl FormattedText {
         self.format = self.format.superscript();
         self
     }
+
+    /// Builder method for excluding text from proofing
+    pub fn no_proof(mut self) -> Self {
+        self.format = self.format.no_proof();
+        self
+    }
+}
+
+/// Generate a solid fill for a hex color or theme scheme color name
+pub fn color_to_xml(color: &str) -> String {
+    format!("<a:solidFill>{}</a:solidFill>", color_element_xml(color))
 }
 
-/// Generate XML color element
-pub fn color_to_xml(hex_color: &str) -> String {
-    let clean_color = hex_color.trim_start_matches('#').to_uppercase();
-    format!("<a:solidFill><a:srgbClr val=\"{}\"/></a:solidFill>", clean_color)
+/// Color element for a hex color (`#` and case tolerated) or theme scheme color name
+pub(crate) fn color_element_xml(color: &str) -> String {
+    let value = match SchemeColor::from_name(color) {
+        Some(_) => color.to_string(),
+        None => color.trim_start_matches('#').to_uppercase(),
+    };
+    color_value_xml(&value, "")
 }
 
 #[cfg(test)]
@@ -282,6 +393,33 @@ mod tests {
         assert!(attrs.contains("baseline=\"30000\""));
     }
     
+    #[test]
+    fn test_no_proof() {
+        let attrs = TextFormat::new().no_proof().to_xml_attrs();
+        assert!(attrs.contains("noProof=\"1\""));
+        assert!(!TextFormat::new().to_xml_attrs().contains("noProof"));
+    }
+
+    #[test]
+    fn test_char_spacing_and_kerning() {
+        let attrs = TextFormat::new().char_spacing(2.0).kerning(12).to_xml_attrs();
+        assert!(attrs.contains("spc=\"200\""));
+        assert!(attrs.contains("kern=\"1200\""));
+
+        let attrs = TextFormat::new().char_spacing(-0.5).to_xml_attrs();
+        assert!(attrs.contains("spc=\"-50\""));
+        assert!(!TextFormat::new().to_xml_attrs().contains("spc="));
+    }
+
+    #[test]
+    fn test_lang_attrs() {
+        assert_eq!(TextFormat::new().lang_attrs(), " lang=\"en-US\"");
+        assert_eq!(
+            TextFormat::new().language("zh-CN").alt_lang("en-US").lang_attrs(),
+            " lang=\"zh-CN\" altLang=\"en-US\""
+        );
+    }
+
     #[test]
     fn test_formatted_text_strikethrough() {
         let text = FormattedText::new("Deleted")
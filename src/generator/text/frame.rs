@@ -1,11 +1,12 @@
 //! TextFrame - container for paragraphs
 
 use super::paragraph::Paragraph;
-use super::TextAnchor;
+use super::{AutoFit, TextAnchor, TextOrientation};
 use crate::core::ToXml;
 
 /// A text frame containing paragraphs
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TextFrame {
     pub paragraphs: Vec<Paragraph>,
     pub anchor: TextAnchor,
@@ -14,6 +15,14 @@ pub struct TextFrame {
     pub margin_right: u32,
     pub margin_top: u32,
     pub margin_bottom: u32,
+    /// Overflow behavior; `None` leaves it to the placeholder/master
+    pub autofit: Option<AutoFit>,
+    /// Direction the text flows
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub text_direction: TextOrientation,
+    /// Rotation of the text within the shape, in degrees
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub rotation: Option<i32>,
 }
 
 impl TextFrame {
@@ -27,6 +36,9 @@ impl TextFrame {
             margin_right: 91440,
             margin_top: 45720,    // 0.05 inch
             margin_bottom: 45720,
+            autofit: None,
+            text_direction: TextOrientation::Horizontal,
+            rotation: None,
         }
     }
 
@@ -55,6 +67,24 @@ impl TextFrame {
         self
     }
 
+    /// Set how overflowing text is fitted
+    pub fn autofit(mut self, autofit: AutoFit) -> Self {
+        self.autofit = Some(autofit);
+        self
+    }
+
+    /// Set the direction text flows, e.g. vertical for a sidebar label
+    pub fn text_direction(mut self, direction: TextOrientation) -> Self {
+        self.text_direction = direction;
+        self
+    }
+
+    /// Rotate the text within the shape by `degrees`
+    pub fn rotation(mut self, degrees: i32) -> Self {
+        self.rotation = Some(degrees);
+        self
+    }
+
     /// Set margins (in EMU)
     pub fn margins(mut self, left: u32, right: u32, top: u32, bottom: u32) -> Self {
         self.margin_left = left;
@@ -68,9 +98,24 @@ impl TextFrame {
     pub fn to_xml(&self) -> String {
         let wrap = if self.wrap { "square" } else { "none" };
         
+        let mut extra_attrs = String::new();
+        if self.paragraphs.iter().any(|p| p.direction.is_rtl()) {
+            extra_attrs.push_str(r#" rtlCol="1""#);
+        }
+        if self.text_direction != TextOrientation::Horizontal {
+            extra_attrs.push_str(&format!(r#" vert="{}""#, self.text_direction.to_xml()));
+        }
+        if let Some(degrees) = self.rotation.filter(|d| *d != 0) {
+            extra_attrs.push_str(&format!(r#" rot="{}""#, degrees * 60000));
+        }
+        let body_end = match self.autofit {
+            Some(autofit) => format!(">{}</a:bodyPr>", autofit.to_xml()),
+            None => "/>".to_string(),
+        };
+        
         let mut xml = format!(
-            r#"<p:txBody><a:bodyPr wrap="{}" lIns="{}" rIns="{}" tIns="{}" bIns="{}" anchor="{}"/><a:lstStyle/>"#,
-            wrap, self.margin_left, self.margin_right, self.margin_top, self.margin_bottom, self.anchor.to_xml()
+            r#"<p:txBody><a:bodyPr wrap="{}" lIns="{}" rIns="{}" tIns="{}" bIns="{}" anchor="{}"{}{}<a:lstStyle/>"#,
+            wrap, self.margin_left, self.margin_right, self.margin_top, self.margin_bottom, self.anchor.to_xml(), extra_attrs, body_end
         );
         
         for para in &self.paragraphs {
@@ -117,5 +162,36 @@ mod tests {
         assert!(xml.contains("anchor=\"ctr\""));
         assert!(xml.contains("Title"));
         assert!(xml.contains("Content"));
+        assert!(xml.contains(r#"anchor="ctr"/><a:lstStyle/>"#));
+    }
+
+    #[test]
+    fn test_text_frame_autofit() {
+        let xml = TextFrame::with_text("Long list").autofit(AutoFit::shrink_to(70, 20)).to_xml();
+        assert!(xml.contains(r#"anchor="t"><a:normAutofit fontScale="70000" lnSpcReduction="20000"/></a:bodyPr><a:lstStyle/>"#));
+
+        let xml = TextFrame::with_text("Grow").autofit(AutoFit::Resize).to_xml();
+        assert!(xml.contains("<a:spAutoFit/></a:bodyPr>"));
+    }
+
+    #[test]
+    fn test_text_frame_direction_and_rotation() {
+        let xml = TextFrame::with_text("Sidebar")
+            .text_direction(TextOrientation::Vertical270)
+            .rotation(-90)
+            .to_xml();
+        assert!(xml.contains(r#"anchor="t" vert="vert270" rot="-5400000"/>"#));
+
+        let plain = TextFrame::with_text("Plain").rotation(0).to_xml();
+        assert!(!plain.contains("vert=") && !plain.contains("rot="));
+    }
+
+    #[test]
+    fn test_text_frame_rtl_columns() {
+        let xml = TextFrame::with_text("Hello")
+            .add_paragraph(Paragraph::with_text("שלום").rtl(true))
+            .to_xml();
+        assert!(xml.contains(r#"anchor="t" rtlCol="1"/>"#));
+        assert!(!TextFrame::with_text("Hello").to_xml().contains("rtlCol"));
     }
 }
@@ -5,21 +5,26 @@
 //! - `run` - A run of text with consistent formatting
 //! - `paragraph` - A paragraph with alignment and spacing
 //! - `frame` - Container for text content
+//! - `proofing` - Spell-check (`dirty`/`noProof`) attributes for runs
 
 mod format;
 mod run;
 mod paragraph;
 mod frame;
 pub mod rtl;
+pub mod proofing;
 
 pub use format::{TextFormat, FormattedText, color_to_xml};
+pub(crate) use format::color_element_xml;
 pub use run::Run;
 pub use paragraph::Paragraph;
 pub use frame::TextFrame;
 pub use rtl::{TextDirection, RtlLanguage, RtlTextProps};
+pub use proofing::{proofing_attrs, is_data_text, mark_data_runs_no_proof};
 
 /// Text alignment options
 #[derive(Clone, Debug, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TextAlign {
     #[default]
     Left,
@@ -42,6 +47,7 @@ impl TextAlign {
 
 /// Vertical text anchor
 #[derive(Clone, Debug, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TextAnchor {
     #[default]
     Top,
@@ -60,6 +66,101 @@ impl TextAnchor {
     }
 }
 
+/// Direction text flows in a text body (`vert` on `<a:bodyPr>`/`<a:tcPr>`)
+#[derive(Clone, Debug, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TextOrientation {
+    #[default]
+    Horizontal,
+    /// Rotated 90° clockwise, reading top to bottom
+    Vertical,
+    /// Rotated 270°, reading bottom to top
+    Vertical270,
+    /// Letters stacked one above another
+    WordArtVertical,
+}
+
+impl TextOrientation {
+    /// Get the OOXML `vert` value
+    pub fn to_xml(&self) -> &'static str {
+        match self {
+            TextOrientation::Horizontal => "horz",
+            TextOrientation::Vertical => "vert",
+            TextOrientation::Vertical270 => "vert270",
+            TextOrientation::WordArtVertical => "wordArtVert",
+        }
+    }
+}
+
+/// Paragraph line spacing
+#[derive(Clone, Debug, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LineSpacing {
+    /// Percentage of single spacing in 1/1000 percent (150000 = 1.5x)
+    Percent(u32),
+    /// Exact spacing in 1/100 pt
+    Points(u32),
+}
+
+impl LineSpacing {
+    /// Generate the `<a:lnSpc>` element
+    pub fn to_xml(&self) -> String {
+        match self {
+            LineSpacing::Percent(val) => format!(r#"<a:lnSpc><a:spcPct val="{}"/></a:lnSpc>"#, val),
+            LineSpacing::Points(val) => format!(r#"<a:lnSpc><a:spcPts val="{}"/></a:lnSpc>"#, val),
+        }
+    }
+}
+
+/// How PowerPoint fits text that overflows its frame
+#[derive(Clone, Debug, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AutoFit {
+    /// Never fit; text may overflow (`<a:noAutofit/>`)
+    None,
+    /// Shrink text on overflow; scales are in 1/1000 percent (`<a:normAutofit/>`)
+    Shrink {
+        font_scale: Option<u32>,
+        line_spacing_reduction: Option<u32>,
+    },
+    /// Resize the shape to fit its text (`<a:spAutoFit/>`)
+    Resize,
+}
+
+impl AutoFit {
+    /// Shrink on overflow, letting PowerPoint pick the scale
+    pub fn shrink() -> Self {
+        AutoFit::Shrink { font_scale: None, line_spacing_reduction: None }
+    }
+
+    /// Shrink to a font scale percentage (1-100) and reduce line spacing by a percentage (0-20)
+    pub fn shrink_to(font_scale_percent: u32, line_spacing_reduction_percent: u32) -> Self {
+        AutoFit::Shrink {
+            font_scale: Some(font_scale_percent.clamp(1, 100) * 1000),
+            line_spacing_reduction: Some(line_spacing_reduction_percent.min(20) * 1000),
+        }
+    }
+
+    /// Generate the autofit child of `<a:bodyPr>`
+    pub fn to_xml(&self) -> String {
+        match self {
+            AutoFit::None => "<a:noAutofit/>".to_string(),
+            AutoFit::Shrink { font_scale, line_spacing_reduction } => {
+                let mut xml = String::from("<a:normAutofit");
+                if let Some(scale) = font_scale {
+                    xml.push_str(&format!(r#" fontScale="{}""#, scale));
+                }
+                if let Some(reduction) = line_spacing_reduction.filter(|r| *r > 0) {
+                    xml.push_str(&format!(r#" lnSpcReduction="{}""#, reduction));
+                }
+                xml.push_str("/>");
+                xml
+            }
+            AutoFit::Resize => "<a:spAutoFit/>".to_string(),
+        }
+    }
+}
+
 pub(crate) use crate::core::escape_xml;
 
 
@@ -81,4 +182,24 @@ mod tests {
         assert_eq!(TextAnchor::Middle.to_xml(), "ctr");
         assert_eq!(TextAnchor::Bottom.to_xml(), "b");
     }
+
+    #[test]
+    fn test_text_orientation() {
+        assert_eq!(TextOrientation::default().to_xml(), "horz");
+        assert_eq!(TextOrientation::Vertical.to_xml(), "vert");
+        assert_eq!(TextOrientation::Vertical270.to_xml(), "vert270");
+        assert_eq!(TextOrientation::WordArtVertical.to_xml(), "wordArtVert");
+    }
+
+    #[test]
+    fn test_autofit() {
+        assert_eq!(AutoFit::None.to_xml(), "<a:noAutofit/>");
+        assert_eq!(AutoFit::Resize.to_xml(), "<a:spAutoFit/>");
+        assert_eq!(AutoFit::shrink().to_xml(), "<a:normAutofit/>");
+        assert_eq!(
+            AutoFit::shrink_to(85, 10).to_xml(),
+            r#"<a:normAutofit fontScale="85000" lnSpcReduction="10000"/>"#
+        );
+        assert_eq!(AutoFit::shrink_to(0, 0).to_xml(), r#"<a:normAutofit fontScale="1000"/>"#);
+    }
 }
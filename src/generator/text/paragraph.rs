@@ -1,11 +1,12 @@
 //! Paragraph - a block of text with alignment and spacing
 
 use super::run::Run;
-use super::TextAlign;
+use super::{LineSpacing, TextAlign, TextDirection};
 use crate::core::ToXml;
 
 /// A paragraph containing one or more runs
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Paragraph {
     pub runs: Vec<Run>,
     pub align: TextAlign,
@@ -13,7 +14,12 @@ pub struct Paragraph {
     pub bullet: bool,
     pub spacing_before: Option<u32>,
     pub spacing_after: Option<u32>,
+    /// Exact line spacing in hundredths of a point; `line_spacing_rule` wins when both are set
     pub line_spacing: Option<u32>,
+    /// Line spacing as a percentage or in points
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub line_spacing_rule: Option<LineSpacing>,
+    pub direction: TextDirection,
 }
 
 impl Paragraph {
@@ -27,6 +33,8 @@ impl Paragraph {
             spacing_before: None,
             spacing_after: None,
             line_spacing: None,
+            line_spacing_rule: None,
+            direction: TextDirection::LTR,
         }
     }
 
@@ -79,15 +87,47 @@ impl Paragraph {
         self
     }
 
+    /// Set line spacing as a percentage of single spacing (150 = 1.5x)
+    pub fn line_spacing_percent(mut self, percent: u32) -> Self {
+        self.line_spacing_rule = Some(LineSpacing::Percent(percent * 1000));
+        self
+    }
+
+    /// Set exact line spacing in points
+    pub fn line_spacing_points(mut self, points: f32) -> Self {
+        self.line_spacing_rule = Some(LineSpacing::Points((points * 100.0).round().max(0.0) as u32));
+        self
+    }
+
+    /// Set right-to-left direction. Enabling it right-aligns a left-aligned paragraph,
+    /// and disabling it puts a right-aligned RTL paragraph back to the left
+    pub fn rtl(mut self, rtl: bool) -> Self {
+        let was_rtl = self.direction.is_rtl();
+        self.direction = if rtl { TextDirection::RTL } else { TextDirection::LTR };
+        match (was_rtl, rtl, self.align) {
+            (false, true, TextAlign::Left) => self.align = TextAlign::Right,
+            (true, false, TextAlign::Right) => self.align = TextAlign::Left,
+            _ => {}
+        }
+        self
+    }
+
     /// Generate XML for this paragraph
     pub fn to_xml(&self) -> String {
         let mut xml = String::from("<a:p>");
         
         // Paragraph properties
         let mut ppr = format!(r#"<a:pPr algn="{}" lvl="{}""#, self.align.to_xml(), self.level);
+        if self.direction.is_rtl() {
+            ppr.push_str(r#" rtl="1""#);
+        }
         
-        if self.spacing_before.is_some() || self.spacing_after.is_some() || self.line_spacing.is_some() {
+        let line_spacing = self.line_spacing_rule.or(self.line_spacing.map(LineSpacing::Points));
+        if self.spacing_before.is_some() || self.spacing_after.is_some() || line_spacing.is_some() {
             ppr.push('>');
+            if let Some(line) = line_spacing {
+                ppr.push_str(&line.to_xml());
+            }
             if let Some(before) = self.spacing_before {
                 ppr.push_str(&format!(r#"<a:spcBef><a:spcPts val="{}"/></a:spcBef>"#, before));
             }
@@ -154,4 +194,49 @@ mod tests {
         
         assert!(xml.contains("buChar"));
     }
+
+    #[test]
+    fn test_paragraph_line_spacing() {
+        let xml = Paragraph::with_text("Body")
+            .line_spacing_percent(150)
+            .spacing_before(6)
+            .spacing_after(3)
+            .to_xml();
+        assert!(xml.contains(
+            r#"<a:lnSpc><a:spcPct val="150000"/></a:lnSpc><a:spcBef><a:spcPts val="600"/></a:spcBef><a:spcAft>"#
+        ));
+
+        let xml = Paragraph::with_text("Body").line_spacing_points(24.0).to_xml();
+        assert!(xml.contains(r#"<a:lnSpc><a:spcPts val="2400"/></a:lnSpc>"#));
+        // The plain field is exact spacing in hundredths of a point
+        let mut paragraph = Paragraph::with_text("Body");
+        paragraph.line_spacing = Some(1800);
+        assert!(paragraph.to_xml().contains(r#"<a:lnSpc><a:spcPts val="1800"/></a:lnSpc>"#));
+        assert!(paragraph.line_spacing_percent(120).to_xml().contains(r#"<a:spcPct val="120000"/>"#));
+    }
+
+    #[test]
+    fn test_paragraph_rtl() {
+        let xml = Paragraph::new()
+            .add_run(Run::new("مرحبا").language("ar-SA"))
+            .add_run(Run::new(" PowerPoint"))
+            .rtl(true)
+            .bullet()
+            .to_xml();
+        assert!(xml.contains(r#"<a:pPr algn="r" lvl="0" rtl="1"><a:buChar"#));
+        assert!(xml.contains("مرحبا") && xml.contains(" PowerPoint"));
+
+        // Explicit alignment after rtl() is kept
+        let xml = Paragraph::with_text("שלום").rtl(true).align(TextAlign::Center).to_xml();
+        assert!(xml.contains(r#"algn="ctr" lvl="0" rtl="1""#));
+        assert!(!Paragraph::with_text("Hello").to_xml().contains("rtl="));
+
+        // Turning RTL off undoes the alignment it applied, and only that
+        let xml = Paragraph::with_text("Hello").rtl(true).rtl(false).to_xml();
+        assert!(xml.contains(r#"<a:pPr algn="l" lvl="0"/>"#));
+        let xml = Paragraph::with_text("Hello").align(TextAlign::Right).rtl(false).to_xml();
+        assert!(xml.contains(r#"algn="r""#));
+        let xml = Paragraph::with_text("Hello").rtl(true).align(TextAlign::Center).rtl(false).to_xml();
+        assert!(xml.contains(r#"algn="ctr""#));
+    }
 }
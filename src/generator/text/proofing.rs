@@ -0,0 +1,111 @@
+//! Proofing attributes for text runs
+//!
+//! Generated runs always carry `dirty="0"` so PowerPoint does not re-run
+//! spelling/grammar checks on open. Runs holding data (numbers, codes) can
+//! additionally be marked `noProof="1"`.
+
+/// Run property attributes for proofing state (` noProof="1" dirty="0"` or ` dirty="0"`)
+pub fn proofing_attrs(no_proof: bool) -> &'static str {
+    if no_proof {
+        r#" noProof="1" dirty="0""#
+    } else {
+        r#" dirty="0""#
+    }
+}
+
+/// Whether text looks data-derived (numbers, amounts, dates, or codes like `SKU-1042`)
+/// and should be excluded from proofing.
+pub fn is_data_text(text: &str) -> bool {
+    let text = text.trim();
+    if text.is_empty() || !text.chars().any(|c| c.is_ascii_digit()) {
+        return false;
+    }
+
+    let numeric = text.chars().all(|c| {
+        c.is_ascii_digit() || c.is_whitespace() || ".,%$€£¥+-/:()".contains(c)
+    });
+    let code = !text.contains(char::is_whitespace)
+        && text.chars().all(|c| c.is_ascii_alphanumeric() || "-_./#".contains(c));
+
+    numeric || code
+}
+
+/// Add `noProof="1"` to every run in `xml` whose text is data-derived.
+///
+/// Runs that already carry a `noProof` attribute are left untouched.
+pub fn mark_data_runs_no_proof(xml: &str) -> String {
+    let mut out = String::with_capacity(xml.len() + 256);
+    let mut rest = xml;
+
+    while let Some(start) = rest.find("<a:r>") {
+        let Some(len) = rest[start..].find("</a:r>") else {
+            break;
+        };
+        let end = start + len + "</a:r>".len();
+        out.push_str(&rest[..start]);
+        out.push_str(&mark_run(&rest[start..end]));
+        rest = &rest[end..];
+    }
+
+    out.push_str(rest);
+    out
+}
+
+fn mark_run(run: &str) -> String {
+    let text = run
+        .find("<a:t>")
+        .and_then(|s| run[s + 5..].find("</a:t>").map(|e| &run[s + 5..s + 5 + e]))
+        .unwrap_or("");
+    if !is_data_text(text) || run.contains("noProof=") {
+        return run.to_string();
+    }
+
+    match run.find("<a:rPr") {
+        Some(pos) => {
+            let insert_at = pos + "<a:rPr".len();
+            format!(r#"{} noProof="1"{}"#, &run[..insert_at], &run[insert_at..])
+        }
+        None => run.replacen(
+            "<a:r>",
+            r#"<a:r><a:rPr lang="en-US" noProof="1" dirty="0"/>"#,
+            1,
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_proofing_attrs() {
+        assert_eq!(proofing_attrs(false), r#" dirty="0""#);
+        assert!(proofing_attrs(true).contains(r#"noProof="1""#));
+    }
+
+    #[test]
+    fn test_is_data_text() {
+        assert!(is_data_text("1,234.56"));
+        assert!(is_data_text("$99"));
+        assert!(is_data_text("45%"));
+        assert!(is_data_text("2024-01-31"));
+        assert!(is_data_text("SKU-1042"));
+        assert!(!is_data_text("Revenue"));
+        assert!(!is_data_text("Q3 results were strong"));
+        assert!(!is_data_text(""));
+    }
+
+    #[test]
+    fn test_mark_data_runs_no_proof() {
+        let xml = r#"<a:r><a:rPr lang="en-US" dirty="0"/><a:t>1,200</a:t></a:r><a:r><a:rPr lang="en-US" dirty="0"/><a:t>Total</a:t></a:r>"#;
+        let marked = mark_data_runs_no_proof(xml);
+        assert!(marked.contains(r#"<a:rPr noProof="1" lang="en-US" dirty="0"/><a:t>1,200</a:t>"#));
+        assert!(marked.contains(r#"<a:rPr lang="en-US" dirty="0"/><a:t>Total</a:t>"#));
+    }
+
+    #[test]
+    fn test_mark_run_without_rpr() {
+        let marked = mark_data_runs_no_proof("<a:r><a:t>42</a:t></a:r>");
+        assert!(marked.contains(r#"noProof="1""#));
+    }
+}
@@ -4,6 +4,7 @@
 
 /// Text direction for paragraphs and runs
 #[derive(Clone, Debug, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TextDirection {
     #[default]
     LTR,
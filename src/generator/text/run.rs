@@ -1,11 +1,16 @@
 //! Text run - a span of text with consistent formatting
 
+use crate::elements::{color_value_xml, ColorInput, RgbInput};
 use super::format::TextFormat;
 use super::escape_xml;
+use super::proofing::proofing_attrs;
 use crate::core::ToXml;
+use crate::generator::gradients::{generate_gradient_fill_xml, GradientFill};
+use crate::generator::shapes::ShapeShadow;
 
 /// A run of text with consistent formatting
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Run {
     pub text: String,
     pub format: TextFormat,
@@ -44,9 +49,27 @@ impl Run {
         self
     }
 
-    /// Set color
-    pub fn color(mut self, hex: &str) -> Self {
-        self.format.color = Some(hex.trim_start_matches('#').to_uppercase());
+    /// Set color (clears any gradient fill)
+    pub fn color(mut self, color: impl Into<ColorInput>) -> Self {
+        self.format = self.format.color(color);
+        self
+    }
+
+    /// Fill the text with a gradient (clears any solid color)
+    pub fn gradient_fill(mut self, gradient: GradientFill) -> Self {
+        self.format = self.format.gradient_fill(gradient);
+        self
+    }
+
+    /// Outline the glyphs with a line `width_emu` wide (0 = no outline)
+    pub fn outline(mut self, width_emu: u32, hex: impl Into<RgbInput>) -> Self {
+        self.format = self.format.outline(width_emu, hex);
+        self
+    }
+
+    /// Add a drop shadow behind the glyphs
+    pub fn shadow(mut self, shadow: ShapeShadow) -> Self {
+        self.format = self.format.shadow(shadow);
         self
     }
 
@@ -62,24 +85,90 @@ impl Run {
         self
     }
 
+    /// Set character spacing in points (negative values condense text)
+    pub fn char_spacing(mut self, points: f32) -> Self {
+        self.format = self.format.char_spacing(points);
+        self
+    }
+
+    /// Kern text at or above the given font size in points
+    pub fn kerning(mut self, points: u32) -> Self {
+        self.format = self.format.kerning(points);
+        self
+    }
+
+    /// Raise the run above the baseline (clears subscript)
+    pub fn superscript(mut self) -> Self {
+        self.format = self.format.superscript();
+        self
+    }
+
+    /// Lower the run below the baseline (clears superscript)
+    pub fn subscript(mut self) -> Self {
+        self.format = self.format.subscript();
+        self
+    }
+
+    /// Set the run's language for spell-check and font selection (default en-US)
+    pub fn language(mut self, lang: &str) -> Self {
+        self.format = self.format.language(lang);
+        self
+    }
+
+    /// Set the run's alternate language (`altLang`)
+    pub fn alt_lang(mut self, lang: &str) -> Self {
+        self.format = self.format.alt_lang(lang);
+        self
+    }
+
+    /// Exclude this run from spelling/grammar checks (numbers, codes)
+    pub fn no_proof(mut self) -> Self {
+        self.format.no_proof = true;
+        self
+    }
+
     /// Generate XML for this run
     pub fn to_xml(&self) -> String {
         let size = self.format.font_size.unwrap_or(18) * 100;
         let bold = if self.format.bold { "1" } else { "0" };
         let italic = if self.format.italic { "1" } else { "0" };
         let underline = if self.format.underline { " u=\"sng\"" } else { "" };
-        
-        let color_xml = self.format.color.as_ref()
-            .map(|c| format!(r#"<a:solidFill><a:srgbClr val="{}"/></a:solidFill>"#, c))
+        let baseline = self.format.baseline()
+            .map(|b| format!(" baseline=\"{}\"", b))
             .unwrap_or_default();
-        
+        let kern = self.format.kerning
+            .map(|k| format!(" kern=\"{}\"", k))
+            .unwrap_or_default();
+        let spacing = self.format.char_spacing
+            .map(|s| format!(" spc=\"{}\"", s))
+            .unwrap_or_default();
+
+        // rPr children order: ln, fill, effectLst, latin
+        let outline_xml = self.format.outline.as_ref()
+            .map(|ln| format!(
+                r#"<a:ln w="{}"><a:solidFill>{}</a:solidFill></a:ln>"#,
+                ln.width, color_value_xml(&ln.color, "")
+            ))
+            .unwrap_or_default();
+
+        let color_xml = match (&self.format.gradient, &self.format.color) {
+            (Some(gradient), _) => generate_gradient_fill_xml(gradient),
+            (None, Some(c)) => format!("<a:solidFill>{}</a:solidFill>", color_value_xml(c, "")),
+            (None, None) => String::new(),
+        };
+
+        let shadow_xml = self.format.shadow.as_ref()
+            .map(ShapeShadow::to_xml)
+            .unwrap_or_default();
+
         let font_xml = self.format.font_family.as_ref()
             .map(|f| format!(r#"<a:latin typeface="{}"/>"#, escape_xml(f)))
             .unwrap_or_default();
 
         format!(
-            r#"<a:r><a:rPr lang="en-US" sz="{}" b="{}" i="{}"{} dirty="0">{}{}</a:rPr><a:t>{}</a:t></a:r>"#,
-            size, bold, italic, underline, color_xml, font_xml, escape_xml(&self.text)
+            r#"<a:r><a:rPr{} sz="{}" b="{}" i="{}"{}{}{}{}{}>{}{}{}{}</a:rPr><a:t>{}</a:t></a:r>"#,
+            self.format.lang_attrs(), size, bold, italic, underline, kern, spacing, baseline, proofing_attrs(self.format.no_proof),
+            outline_xml, color_xml, shadow_xml, font_xml, escape_xml(&self.text)
         )
     }
 }
@@ -105,11 +194,90 @@ mod tests {
         assert!(xml.contains("sz=\"2400\""));
     }
 
+    #[test]
+    fn test_run_proofing_attrs() {
+        let xml = Run::new("Hello").to_xml();
+        assert!(xml.contains("dirty=\"0\""));
+        assert!(!xml.contains("noProof"));
+
+        let xml = Run::new("SKU-1042").no_proof().to_xml();
+        assert!(xml.contains("noProof=\"1\" dirty=\"0\""));
+    }
+
+    #[test]
+    fn test_run_baseline() {
+        assert!(!Run::new("x").to_xml().contains("baseline"));
+        assert!(Run::new("2").superscript().to_xml().contains(" baseline=\"30000\""));
+        assert!(Run::new("2").subscript().to_xml().contains(" baseline=\"-25000\""));
+
+        // Last one wins
+        let xml = Run::new("2").subscript().superscript().to_xml();
+        assert!(xml.contains("baseline=\"30000\""));
+        assert!(!xml.contains("-25000"));
+    }
+
+    #[test]
+    fn test_run_spacing() {
+        let xml = Run::new("WIDE").char_spacing(2.0).kerning(14).to_xml();
+        assert!(xml.contains(" kern=\"1400\" spc=\"200\""));
+
+        let xml = Run::new("tight").char_spacing(-1.5).to_xml();
+        assert!(xml.contains(" spc=\"-150\""));
+        assert!(!Run::new("plain").to_xml().contains("spc="));
+    }
+
+    #[test]
+    fn test_run_gradient_fill() {
+        use crate::generator::gradients::{GradientDirection, GradientStop};
+
+        let gradient = GradientFill::linear(GradientDirection::Custom(45))
+            .add_stop(GradientStop::start("FF0000"))
+            .add_stop(GradientStop::end("0000FF"));
+        let xml = Run::new("Title").color("00FF00").gradient_fill(gradient.clone()).to_xml();
+        assert!(xml.contains(r#"<a:gradFill rotWithShape="1"><a:gsLst><a:gs pos="0"><a:srgbClr val="FF0000"/></a:gs>"#));
+        assert!(xml.contains(r#"<a:lin ang="2700000" scaled="1"/></a:gradFill></a:rPr>"#));
+        assert!(!xml.contains("solidFill"));
+
+        // Last one wins
+        let xml = Run::new("Title").gradient_fill(gradient).color("00FF00").to_xml();
+        assert!(xml.contains(r#"<a:solidFill><a:srgbClr val="00FF00"/></a:solidFill>"#));
+        assert!(!xml.contains("gradFill"));
+    }
+
+    #[test]
+    fn test_run_outline() {
+        let xml = Run::new("POSTER").outline(12700, "#000000").color("FFFFFF")
+            .shadow(ShapeShadow::new()).font("Impact").to_xml();
+        let ln = xml.find(r#"<a:ln w="12700"><a:solidFill><a:srgbClr val="000000"/></a:solidFill></a:ln>"#).unwrap();
+        let fill = xml.find(r#"<a:solidFill><a:srgbClr val="FFFFFF"/>"#).unwrap();
+        let effects = xml.find("<a:effectLst>").unwrap();
+        let latin = xml.find("<a:latin").unwrap();
+        assert!(ln < fill && fill < effects && effects < latin);
+
+        // Zero width means no outline
+        let xml = Run::new("plain").outline(0, "000000").to_xml();
+        assert!(!xml.contains("<a:ln"));
+    }
+
+    #[test]
+    fn test_run_language() {
+        assert!(Run::new("Hello").to_xml().starts_with(r#"<a:r><a:rPr lang="en-US" sz="1800""#));
+
+        let xml = Run::new("学而时习之").language("zh-CN").alt_lang("en-US").to_xml();
+        assert!(xml.starts_with(r#"<a:r><a:rPr lang="zh-CN" altLang="en-US" sz="1800""#));
+    }
+
     #[test]
     fn test_font_family() {
         let run = Run::new("Arial text").font("Arial");
         let xml = run.to_xml();
-        
+
         assert!(xml.contains("typeface=\"Arial\""));
     }
+
+    #[test]
+    fn test_run_scheme_color() {
+        let xml = Run::new("Theme").color(crate::elements::SchemeColor::Text2).to_xml();
+        assert!(xml.contains(r#"<a:solidFill><a:schemeClr val="tx2"/></a:solidFill>"#));
+    }
 }
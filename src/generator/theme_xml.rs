@@ -1,11 +1,14 @@
 //! Theme, master, and layout XML generation
 
-use crate::core::append_usize;
+use std::borrow::Cow;
+
+use crate::core::{append_usize, SlideSize};
 use crate::generator::presentation_theme::PresentationTheme;
 use crate::generator::slide_content::print_settings::PrintSettings;
+use crate::generator::slide_master::SlideMaster;
 
 use super::layout_parts::{self, slide_master_footer_shapes, STANDARD_LAYOUT_COUNT};
-use crate::generator::package_xml::SLIDE_LAYOUT_ID;
+use crate::generator::package_xml::slide_master_id;
 
 pub use super::layout_parts::{append_layout_content_type_overrides, layout_rel_target, STANDARD_LAYOUT_COUNT as LAYOUT_COUNT};
 
@@ -24,12 +27,42 @@ pub fn create_layout_rels_xml() -> &'static str {
 
 /// Create slide master XML with all standard layouts and optional footer placeholders.
 pub fn create_slide_master_xml(print: Option<&PrintSettings>) -> String {
+    create_custom_slide_master_xml(print, None, &SlideSize::default())
+}
+
+/// Create slide master XML, applying a custom master's background, text styles
+/// and placeholder frames when one is given.
+pub fn create_custom_slide_master_xml(print: Option<&PrintSettings>, master: Option<&SlideMaster>, size: &SlideSize) -> String {
+    create_numbered_slide_master_xml(print, master, 1, size)
+}
+
+/// Create the XML of slide master `number` (1-based), whose layout ids follow its own id.
+pub fn create_numbered_slide_master_xml(
+    print: Option<&PrintSettings>,
+    master: Option<&SlideMaster>,
+    number: usize,
+    size: &SlideSize,
+) -> String {
     const TX_STYLES: &str = include_str!("slide_master_txstyles.xml");
-    let footer_shapes = slide_master_footer_shapes(print);
+    const STOCK_BACKGROUND: &str = r#"<p:bg>
+<p:bgRef idx="1001">
+<a:schemeClr val="bg1"/>
+</p:bgRef>
+</p:bg>"#;
+    let mut shapes = slide_master_footer_shapes(print);
+    let (background, tx_styles) = match master {
+        Some(master) => {
+            // Footer placeholders take ids from 2; the title and body follow them
+            let first_id = shapes.matches("<p:cNvPr ").count() as u32 + 2;
+            shapes.insert_str(0, &format!("{}\n", master.placeholder_shapes_xml(first_id, size)));
+            (Cow::Owned(master.background_xml()), Cow::Owned(master.text_styles_xml(TX_STYLES)))
+        }
+        None => (Cow::Borrowed(STOCK_BACKGROUND), Cow::Borrowed(TX_STYLES)),
+    };
 
     let mut layout_ids = String::new();
     for i in 0..STANDARD_LAYOUT_COUNT {
-        let id = SLIDE_LAYOUT_ID as usize + i;
+        let id = slide_master_id(number) as usize + 1 + i;
         let rid = i + 1;
         layout_ids.push_str("\n<p:sldLayoutId id=\"");
         append_usize(&mut layout_ids, id);
@@ -42,11 +75,7 @@ pub fn create_slide_master_xml(print: Option<&PrintSettings>) -> String {
         r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
 <p:sldMaster xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships" xmlns:p="http://schemas.openxmlformats.org/presentationml/2006/main">
 <p:cSld>
-<p:bg>
-<p:bgRef idx="1001">
-<a:schemeClr val="bg1"/>
-</p:bgRef>
-</p:bg>
+{background}
 <p:spTree>
 <p:nvGrpSpPr>
 <p:cNvPr id="1" name=""/>
@@ -61,13 +90,13 @@ pub fn create_slide_master_xml(print: Option<&PrintSettings>) -> String {
 <a:chExt cx="0" cy="0"/>
 </a:xfrm>
 </p:grpSpPr>
-{footer_shapes}
+{shapes}
 </p:spTree>
 </p:cSld>
 <p:clrMap bg1="lt1" tx1="dk1" bg2="lt2" tx2="dk2" accent1="accent1" accent2="accent2" accent3="accent3" accent4="accent4" accent5="accent5" accent6="accent6" hlink="hlink" folHlink="folHlink"/>
 <p:sldLayoutIdLst>{layout_ids}
 </p:sldLayoutIdLst>
-{TX_STYLES}
+{tx_styles}
 </p:sldMaster>"#
     )
 }
@@ -111,7 +111,19 @@ impl ColorValue {
 
     /// Convert to Color enum for use in the library
     pub fn to_color(&self) -> Color {
-        Color::Rgb(RgbColor::new(self.r, self.g, self.b))
+        Color::Rgb((*self).into())
+    }
+}
+
+impl From<ColorValue> for RgbColor {
+    fn from(color: ColorValue) -> Self {
+        RgbColor::new(color.r, color.g, color.b)
+    }
+}
+
+impl From<ColorValue> for Color {
+    fn from(color: ColorValue) -> Self {
+        color.to_color()
     }
 }
 
@@ -256,4 +268,10 @@ mod tests {
         assert_eq!(white().to_hex(), "FFFFFF");
         assert_eq!(black().to_hex(), "000000");
     }
+
+    #[test]
+    fn test_color_value_as_setter_argument() {
+        let fill = crate::generator::ShapeFill::new(orange().darker(0.5));
+        assert_eq!(fill.color, "7F5200");
+    }
 }
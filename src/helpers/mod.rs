@@ -218,7 +218,9 @@ pub fn rgb(r: u8, g: u8, b: u8) -> Color {
     Color::Rgb(RgbColor::new(r, g, b))
 }
 
-/// Create a color from a hex string (with or without '#' prefix).
+/// Create a color from a hex string (with or without '#' prefix), shorthand `RGB` hex,
+/// or a CSS color name. Unrecognized strings give black; use [`RgbColor::from_hex`]
+/// to get an error instead.
 ///
 /// # Example
 /// ```
@@ -226,17 +228,10 @@ pub fn rgb(r: u8, g: u8, b: u8) -> Color {
 ///
 /// let color1 = hex("4F81BD");
 /// let color2 = hex("#4F81BD"); // Also works
+/// let color3 = hex("steelblue");
 /// ```
 pub fn hex(color: &str) -> Color {
-    let color = color.trim_start_matches('#');
-    if color.len() == 6 {
-        let r = u8::from_str_radix(&color[0..2], 16).unwrap_or(0);
-        let g = u8::from_str_radix(&color[2..4], 16).unwrap_or(0);
-        let b = u8::from_str_radix(&color[4..6], 16).unwrap_or(0);
-        Color::Rgb(RgbColor::new(r, g, b))
-    } else {
-        Color::Rgb(RgbColor::new(0, 0, 0)) // Default to black
-    }
+    Color::Rgb(RgbColor::from_hex(color).unwrap_or_default())
 }
 
 // ============================================================================
@@ -715,26 +715,24 @@ impl HtmlSlideParser {
                 self.italic = true;
             }
             "title" => {}
-            "img" => {
-                if self.options.include_images {
-                    let alt = attrs.iter().find(|(k, _)| k == "alt").map(|(_, v)| v.as_str()).unwrap_or("");
-                    let src = attrs.iter().find(|(k, _)| k == "src").map(|(_, v)| v.as_str()).unwrap_or("");
-
-                    if !src.is_empty() {
-                        // Try to download and embed the actual image
-                        if let Some(image) = self.load_image(src, alt) {
-                            if let Some(ref mut slide) = self.current_slide {
-                                slide.images.push(image);
-                            } else {
-                                let mut slide = SlideContent::new("Image");
-                                slide.images.push(image);
-                                self.current_slide = Some(slide);
-                            }
+            "img" if self.options.include_images => {
+                let alt = attrs.iter().find(|(k, _)| k == "alt").map(|(_, v)| v.as_str()).unwrap_or("");
+                let src = attrs.iter().find(|(k, _)| k == "src").map(|(_, v)| v.as_str()).unwrap_or("");
+
+                if !src.is_empty() {
+                    // Try to download and embed the actual image
+                    if let Some(image) = self.load_image(src, alt) {
+                        if let Some(ref mut slide) = self.current_slide {
+                            slide.images.push(image);
                         } else {
-                            // Fallback to placeholder if image loading fails
-                            let label = if alt.is_empty() { src } else { alt };
-                            self.add_paragraph(&format!("[Image: {}]", label));
+                            let mut slide = SlideContent::new("Image");
+                            slide.images.push(image);
+                            self.current_slide = Some(slide);
                         }
+                    } else {
+                        // Fallback to placeholder if image loading fails
+                        let label = if alt.is_empty() { src } else { alt };
+                        self.add_paragraph(&format!("[Image: {}]", label));
                     }
                 }
             }
@@ -825,11 +823,9 @@ impl HtmlSlideParser {
                 let cell = std::mem::take(&mut self.current_cell).trim().to_string();
                 self.current_row.push(cell);
             }
-            "tr" => {
-                if !self.current_row.is_empty() {
-                    self.table_rows.push(std::mem::take(&mut self.current_row));
-                    self.current_row = Vec::new();
-                }
+            "tr" if !self.current_row.is_empty() => {
+                self.table_rows.push(std::mem::take(&mut self.current_row));
+                self.current_row = Vec::new();
             }
             "strong" | "b" => {
                 self.text_buffer.push_str("**");
@@ -0,0 +1,344 @@
+//! Markdown to PowerPoint conversion
+//!
+//! A dependency-free, line-based Markdown reader that turns a document into
+//! slides. Line endings are normalized first, so files saved with Windows
+//! `\r\n` endings parse exactly like Unix ones.
+//!
+//! # Supported syntax
+//!
+//! - `# Title` → Starts a new slide with that title (a slide with nothing but
+//!   the title becomes a centered title slide)
+//! - `## Heading` → Starts a new slide with that heading as its title
+//! - `###` and deeper → Bold heading bullets on the current slide
+//! - `-`, `*`, `+` → Bullets; indentation nests them into bullet levels
+//! - `1.` → Numbered bullets
+//! - Fenced code blocks (```` ``` ```` or `~~~`, with optional language) → `CodeBlock`
+//! - GFM pipe tables → `Table` with a styled header row
+//! - `> quote` → Speaker notes
+//! - `---`, `***`, `___` → Slide break (continues the previous title)
+//! - Other lines → Paragraph text; consecutive lines are joined
+//!
+//! Inline markers (`**bold**`, `*italic*`, `` `code` ``) are kept in the text
+//! and rendered by the slide generator.
+
+use crate::exc::{PptxError, Result};
+use crate::generator::slide_content::{BulletPoint, BulletStyle};
+use crate::generator::table::table_from_string_rows;
+use crate::generator::{create_pptx_with_content, CodeBlock, SlideContent, SlideLayout};
+
+/// Deepest bullet level PowerPoint's list styles define
+const MAX_BULLET_LEVEL: u32 = 8;
+
+/// Convert a Markdown document to PPTX bytes
+pub fn markdown_to_pptx(md: &str, title: &str) -> Result<Vec<u8>> {
+    let slides = parse_markdown(md)?;
+    create_pptx_with_content(title, slides)
+        .map_err(|e| PptxError::Generic(format!("Failed to generate presentation: {e}")))
+}
+
+/// Parse a Markdown document into slides
+pub fn parse_markdown(md: &str) -> Result<Vec<SlideContent>> {
+    let normalized = md.replace("\r\n", "\n").replace('\r', "\n");
+    let mut parser = MarkdownSlideParser::default();
+    let mut lines = normalized.lines().peekable();
+
+    while let Some(line) = lines.next() {
+        let trimmed = line.trim();
+
+        if let Some((fence, language)) = code_fence(trimmed) {
+            let mut code = Vec::new();
+            for code_line in lines.by_ref() {
+                if code_line.trim_start().starts_with(fence) {
+                    break;
+                }
+                code.push(code_line);
+            }
+            parser.code_block(&code.join("\n"), language);
+        } else if is_table_row(trimmed) {
+            let mut rows = vec![split_table_row(trimmed)];
+            while let Some(next) = lines.peek().map(|l| l.trim()) {
+                if !is_table_row(next) {
+                    break;
+                }
+                if !is_table_separator(next) {
+                    rows.push(split_table_row(next));
+                }
+                lines.next();
+            }
+            parser.table(rows);
+        } else {
+            parser.line(line);
+        }
+    }
+
+    parser.finish()
+}
+
+/// Opening fence marker and language, if `line` starts a fenced code block
+fn code_fence(line: &str) -> Option<(&'static str, &str)> {
+    let fence = ["```", "~~~"].into_iter().find(|f| line.starts_with(f))?;
+    Some((fence, line[3..].trim_start_matches(&fence[..1]).trim()))
+}
+
+fn is_table_row(line: &str) -> bool {
+    line.starts_with('|') && line.len() > 1 && line[1..].contains('|')
+}
+
+fn is_table_separator(line: &str) -> bool {
+    line.chars().all(|c| matches!(c, '|' | '-' | ':' | ' ' | '\t')) && line.contains('-')
+}
+
+fn split_table_row(line: &str) -> Vec<String> {
+    let inner = line.trim_start_matches('|').trim_end_matches('|');
+    inner.split('|').map(|cell| cell.trim().to_string()).collect()
+}
+
+fn is_rule(line: &str) -> bool {
+    let compact: String = line.chars().filter(|c| !c.is_whitespace()).collect();
+    compact.len() >= 3
+        && ['-', '*', '_'].iter().any(|&marker| compact.chars().all(|c| c == marker))
+}
+
+/// ATX heading level and text
+fn heading(line: &str) -> Option<(usize, &str)> {
+    let level = line.chars().take_while(|&c| c == '#').count();
+    if !(1..=6).contains(&level) {
+        return None;
+    }
+    let rest = &line[level..];
+    if !rest.is_empty() && !rest.starts_with([' ', '\t']) {
+        return None;
+    }
+    Some((level, rest.trim().trim_end_matches('#').trim_end()))
+}
+
+/// List marker style and item text
+fn list_item(line: &str) -> Option<(BulletStyle, &str)> {
+    if let Some(rest) = line.strip_prefix(['-', '*', '+'])
+        && rest.starts_with([' ', '\t'])
+    {
+        return Some((BulletStyle::Bullet, rest.trim()));
+    }
+    let digits = line.chars().take_while(char::is_ascii_digit).count();
+    if digits > 0
+        && let Some(rest) = line[digits..].strip_prefix(['.', ')'])
+        && rest.starts_with([' ', '\t'])
+    {
+        return Some((BulletStyle::Number, rest.trim()));
+    }
+    None
+}
+
+/// Indentation width, counting a tab as four spaces
+fn indent_width(line: &str) -> usize {
+    line.chars()
+        .take_while(|c| c.is_whitespace())
+        .map(|c| if c == '\t' { 4 } else { 1 })
+        .sum()
+}
+
+#[derive(Default)]
+struct MarkdownSlideParser {
+    slides: Vec<SlideContent>,
+    current: Option<SlideContent>,
+    /// Whether the current slide has anything besides its title
+    has_body: bool,
+    paragraph: Vec<String>,
+    /// Indent widths of the open list levels
+    list_indents: Vec<usize>,
+    notes: Vec<String>,
+}
+
+impl MarkdownSlideParser {
+    fn line(&mut self, line: &str) {
+        let trimmed = line.trim();
+
+        if trimmed.is_empty() {
+            self.flush_paragraph();
+            return;
+        }
+
+        if let Some((level, text)) = heading(trimmed) {
+            self.flush_paragraph();
+            self.list_indents.clear();
+            if level <= 2 {
+                self.start_slide(text);
+            } else {
+                self.push_bullet(BulletPoint::new(&format!("**{}**", text)).with_style(BulletStyle::None));
+            }
+        } else if is_rule(trimmed) {
+            self.flush_paragraph();
+            let title = self.current.as_ref().map(|s| format!("{} (continued)", s.title));
+            self.start_slide(title.as_deref().unwrap_or(""));
+        } else if let Some(quote) = trimmed.strip_prefix('>') {
+            self.flush_paragraph();
+            self.notes.push(quote.trim().to_string());
+        } else if let Some((style, text)) = list_item(trimmed) {
+            self.flush_paragraph();
+            let level = self.list_level(indent_width(line));
+            self.push_bullet(BulletPoint::new(text).with_level(level).with_style(style));
+        } else if !self.list_indents.is_empty() && indent_width(line) > 0 && self.paragraph.is_empty() {
+            // Lazy continuation of the previous list item
+            if let Some(slide) = self.current.as_mut()
+                && let (Some(bullet), Some(content)) = (slide.bullets.last_mut(), slide.content.last_mut())
+            {
+                bullet.text.push(' ');
+                bullet.text.push_str(trimmed);
+                content.push(' ');
+                content.push_str(trimmed);
+            }
+        } else {
+            self.list_indents.clear();
+            self.paragraph.push(trimmed.to_string());
+        }
+    }
+
+    /// Nesting level for a list item indented by `indent`
+    fn list_level(&mut self, indent: usize) -> u32 {
+        while self.list_indents.last().is_some_and(|&open| indent < open) {
+            self.list_indents.pop();
+        }
+        if self.list_indents.last().is_none_or(|&open| indent > open) {
+            self.list_indents.push(indent);
+        }
+        (self.list_indents.len() as u32 - 1).min(MAX_BULLET_LEVEL)
+    }
+
+    fn code_block(&mut self, code: &str, language: &str) {
+        self.flush_paragraph();
+        self.list_indents.clear();
+        let language = if language.is_empty() { "text" } else { language };
+        self.slide().code_blocks.push(CodeBlock::new(code, language));
+        self.has_body = true;
+    }
+
+    fn table(&mut self, rows: Vec<Vec<String>>) {
+        self.flush_paragraph();
+        self.list_indents.clear();
+        let table = table_from_string_rows(rows, true);
+        let slide = self.slide();
+        slide.table = Some(table);
+        slide.has_table = true;
+        self.has_body = true;
+    }
+
+    fn start_slide(&mut self, title: &str) {
+        self.finish_slide();
+        self.current = Some(SlideContent::new(title));
+    }
+
+    fn slide(&mut self) -> &mut SlideContent {
+        self.current.get_or_insert_with(|| SlideContent::new(""))
+    }
+
+    fn push_bullet(&mut self, bullet: BulletPoint) {
+        let slide = self.slide();
+        slide.content.push(format!("{}{}", "  ".repeat(bullet.level as usize), bullet.text));
+        slide.bullets.push(bullet);
+        self.has_body = true;
+    }
+
+    fn flush_paragraph(&mut self) {
+        if self.paragraph.is_empty() {
+            return;
+        }
+        let text = std::mem::take(&mut self.paragraph).join(" ");
+        self.push_bullet(BulletPoint::new(&text).with_style(BulletStyle::None));
+    }
+
+    fn finish_slide(&mut self) {
+        self.flush_paragraph();
+        self.list_indents.clear();
+        let Some(mut slide) = self.current.take() else {
+            return;
+        };
+        if !self.notes.is_empty() {
+            slide.notes = Some(std::mem::take(&mut self.notes).join("\n"));
+        }
+        if !std::mem::take(&mut self.has_body) {
+            if slide.title.is_empty() && slide.notes.is_none() {
+                return;
+            }
+            slide = slide.layout(SlideLayout::CenteredTitle);
+        }
+        self.slides.push(slide);
+    }
+
+    fn finish(mut self) -> Result<Vec<SlideContent>> {
+        self.finish_slide();
+        if self.slides.is_empty() {
+            return Err(PptxError::InvalidValue("No slides found in markdown".to_string()));
+        }
+        Ok(self.slides)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DECK: &str = "# Quarterly Review\n\n## Highlights\n\n- Revenue up\n  - EMEA\n    - Germany\n- Costs flat\n\n1. First\n2. Second\n\n> Mention the board meeting\n\n## Code\n\n```rust\nfn main() {}\n```\n\n## Data\n\n| Region | Sales |\n|--------|------:|\n| EMEA   | 100   |\n| APAC   | 80    |\n";
+
+    #[test]
+    fn test_headings_start_slides() {
+        let slides = parse_markdown(DECK).unwrap();
+        let titles: Vec<_> = slides.iter().map(|s| s.title.as_str()).collect();
+        assert_eq!(titles, ["Quarterly Review", "Highlights", "Code", "Data"]);
+        assert_eq!(slides[0].layout, SlideLayout::CenteredTitle);
+        assert_eq!(slides[1].notes.as_deref(), Some("Mention the board meeting"));
+    }
+
+    #[test]
+    fn test_nested_lists_become_levels() {
+        let slides = parse_markdown(DECK).unwrap();
+        let bullets: Vec<_> = slides[1].bullets.iter().map(|b| (b.text.as_str(), b.level, b.style)).collect();
+        assert_eq!(
+            bullets,
+            [
+                ("Revenue up", 0, BulletStyle::Bullet),
+                ("EMEA", 1, BulletStyle::Bullet),
+                ("Germany", 2, BulletStyle::Bullet),
+                ("Costs flat", 0, BulletStyle::Bullet),
+                ("First", 0, BulletStyle::Number),
+                ("Second", 0, BulletStyle::Number),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_code_blocks_and_tables() {
+        let slides = parse_markdown(DECK).unwrap();
+        assert_eq!(slides[2].code_blocks.len(), 1);
+        assert_eq!(slides[2].code_blocks[0].code, "fn main() {}");
+        assert_eq!(slides[2].code_blocks[0].language, "rust");
+
+        let table = slides[3].table.as_ref().unwrap();
+        assert_eq!(table.rows.len(), 3);
+        assert_eq!(table.rows[2].cells[0].text, "APAC");
+    }
+
+    #[test]
+    fn test_windows_line_endings_match_unix() {
+        let unix = parse_markdown(DECK).unwrap();
+        let windows = parse_markdown(&DECK.replace('\n', "\r\n")).unwrap();
+        assert_eq!(format!("{:?}", unix), format!("{:?}", windows));
+        assert!(!windows[2].code_blocks[0].code.contains('\r'));
+    }
+
+    #[test]
+    fn test_paragraphs_rules_and_deep_headings() {
+        let md = "## Intro\nFirst line\nsecond line\n\n### Detail\n---\nMore text";
+        let slides = parse_markdown(md).unwrap();
+        assert_eq!(slides.len(), 2);
+        assert_eq!(slides[0].bullets[0].text, "First line second line");
+        assert_eq!(slides[0].bullets[1].text, "**Detail**");
+        assert_eq!(slides[1].title, "Intro (continued)");
+    }
+
+    #[test]
+    fn test_markdown_to_pptx() {
+        let bytes = markdown_to_pptx(DECK, "Review").unwrap();
+        assert!(crate::core::validate_package_bytes(&bytes).is_valid());
+        assert!(parse_markdown("\n\n").is_err());
+    }
+}
@@ -1,22 +1,80 @@
 pub mod html;
+pub mod markdown;
 
 use crate::api::Presentation;
 use crate::oxml::presentation::PresentationReader;
-use crate::generator::{SlideContent, Shape, ShapeType, TableBuilder, TableRow, TableCell};
-use crate::exc::Result;
+use crate::oxml::chart::ParsedChart;
+use crate::oxml::slide::{ParsedPicture, ParsedTable, ParsedUnsupported};
+use crate::generator::{Chart, ChartSeries, ChartType, Image, SlideContent, Shape, ShapeType, ShapeFill, ShapeLine, TableBuilder, TableRow, TableCell, TableMergeMap, CellMergeState};
+use crate::generator::table::Table;
+use crate::exc::{PptxError, Result};
 
 pub use html::{parse_html, parse_html_with_options, HtmlParseOptions, Html2Ppt};
+pub use markdown::{markdown_to_pptx, parse_markdown};
+
+/// How the importer treats elements it cannot convert (SmartArt, OLE objects, charts)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum UnsupportedElementMode {
+    /// Drop the element silently
+    #[default]
+    Skip,
+    /// Insert a labeled rectangle at the element's position
+    Placeholder,
+    /// Fail the import with details about the element
+    Error,
+}
+
+/// Options for PPTX import
+#[derive(Clone, Debug, Default)]
+pub struct ImportOptions {
+    /// Handling of unsupported elements
+    pub on_unsupported: UnsupportedElementMode,
+}
+
+impl ImportOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn on_unsupported(mut self, mode: UnsupportedElementMode) -> Self {
+        self.on_unsupported = mode;
+        self
+    }
+}
 
 /// Import a presentation from a file path
 pub fn import_pptx(path: &str) -> Result<Presentation> {
-    let reader = PresentationReader::open(path)?;
+    import_pptx_with_options(path, &ImportOptions::default())
+}
+
+/// Import a presentation from a file path with custom options
+pub fn import_pptx_with_options(path: &str, options: &ImportOptions) -> Result<Presentation> {
+    import_from_reader(&PresentationReader::open(path)?, options)
+}
+
+/// Import a presentation from PPTX bytes already in memory
+pub fn import_pptx_bytes(bytes: &[u8]) -> Result<Presentation> {
+    import_pptx_bytes_with_options(bytes, &ImportOptions::default())
+}
+
+/// Import a presentation from PPTX bytes with custom options
+pub fn import_pptx_bytes_with_options(bytes: &[u8], options: &ImportOptions) -> Result<Presentation> {
+    import_from_reader(&PresentationReader::from_bytes(bytes)?, options)
+}
+
+fn import_from_reader(reader: &PresentationReader, options: &ImportOptions) -> Result<Presentation> {
     let mut presentation = Presentation::new();
     
     if let Some(title) = &reader.info().title {
         presentation = presentation.title(title);
     }
+
+    // Keep the deck's palette and fonts so new slides match it
+    if let Some(theme) = reader.theme() {
+        presentation = presentation.with_theme(theme.into());
+    }
     
-    for parsed_slide in reader.get_all_slides()? {
+    for (slide_index, parsed_slide) in reader.get_all_slides()?.into_iter().enumerate() {
         let mut content = SlideContent::new(parsed_slide.title.as_deref().unwrap_or(""));
         
         // Add body text as bullets
@@ -46,36 +104,187 @@ pub fn import_pptx(path: &str) -> Result<Presentation> {
         }
         
         // Add tables
-        for parsed_table in parsed_slide.tables {
-             // Determine column count from first row
-             let col_count = parsed_table.rows.first().map(|r| r.len()).unwrap_or(0);
-             if col_count == 0 { continue; }
-             
-             // Default column width (approx 2 inches)
-             let col_widths = vec![1828800; col_count];
-             
-             let mut table_builder = TableBuilder::new(col_widths);
-             for row in parsed_table.rows {
-                 let cells: Vec<TableCell> = row.into_iter()
-                     .map(|cell| TableCell::new(&cell.text))
-                     .collect();
-                 let table_row = TableRow::new(cells);
-                 table_builder = table_builder.add_row(table_row);
-             }
-             
-             // SlideContent currently supports only one table via 'table' field
-             if content.table.is_none() {
-                 content.table = Some(table_builder.build());
-                 content.has_table = true;
-             }
+        // SlideContent currently supports only one table via 'table' field
+        if let Some(table) = parsed_slide.tables.iter().find_map(build_table) {
+            content.table = Some(table);
+            content.has_table = true;
         }
         
+        if let Some(ref notes) = parsed_slide.notes {
+            content = content.notes(notes);
+        }
+
+        // Add pictures with their embedded media
+        for picture in &parsed_slide.pictures {
+            content = content.add_image(build_image(picture));
+        }
+
+        // Add charts
+        for parsed_chart in &parsed_slide.charts {
+            content = content.add_chart(build_chart(parsed_chart));
+        }
+
+        // Handle elements the importer cannot convert
+        for element in &parsed_slide.unsupported {
+            match options.on_unsupported {
+                UnsupportedElementMode::Skip => {}
+                UnsupportedElementMode::Placeholder => {
+                    content.shapes.push(unsupported_placeholder(element));
+                }
+                UnsupportedElementMode::Error => {
+                    return Err(PptxError::InvalidOperation(format!(
+                        "Unsupported {} '{}' on slide {} at ({}, {}) size {}x{}",
+                        element.kind,
+                        element.name,
+                        slide_index + 1,
+                        element.x,
+                        element.y,
+                        element.width,
+                        element.height
+                    )));
+                }
+            }
+        }
+
         presentation = presentation.add_slide(content);
     }
     
     Ok(presentation)
 }
 
+/// Rebuild a table, restoring merged regions and their continuation cells
+fn build_table(parsed: &ParsedTable) -> Option<Table> {
+    let col_count = parsed.rows.iter().map(|row| row.len()).max().unwrap_or(0);
+    if col_count == 0 {
+        return None;
+    }
+
+    let mut merges = TableMergeMap::new(parsed.rows.len(), col_count);
+    for (r, row) in parsed.rows.iter().enumerate() {
+        for (c, cell) in row.iter().enumerate() {
+            let is_anchor = !cell.h_merge && !cell.v_merge && (cell.row_span > 1 || cell.col_span > 1);
+            if is_anchor {
+                // Spans running off the grid or into another region are imported unmerged
+                let _ = merges.merge_cells(r, c, cell.row_span.max(1) as usize, cell.col_span.max(1) as usize);
+            }
+        }
+    }
+
+    // Default column width (approx 2 inches)
+    let mut table_builder = TableBuilder::new(vec![1828800; col_count]);
+    for (r, row) in parsed.rows.iter().enumerate() {
+        let cells = row
+            .iter()
+            .enumerate()
+            .map(|(c, cell)| {
+                let table_cell = TableCell::new(&cell.text);
+                match merges.cell_state(r, c) {
+                    CellMergeState::Anchor { row_span, col_span } => {
+                        let mut table_cell = table_cell;
+                        if col_span > 1 {
+                            table_cell = table_cell.grid_span(col_span as u32);
+                        }
+                        if row_span > 1 {
+                            table_cell = table_cell.row_span(row_span as u32);
+                        }
+                        table_cell
+                    }
+                    CellMergeState::HMerge => table_cell.h_merge(),
+                    CellMergeState::VMerge => table_cell.v_merge(),
+                    CellMergeState::Normal if cell.h_merge => table_cell.h_merge(),
+                    CellMergeState::Normal if cell.v_merge => table_cell.v_merge(),
+                    CellMergeState::Normal => table_cell,
+                }
+            })
+            .collect();
+        table_builder = table_builder.add_row(TableRow::new(cells));
+    }
+    Some(table_builder.build())
+}
+
+/// Rebuild a picture from its media bytes, keeping its frame and alt text
+fn build_image(picture: &ParsedPicture) -> Image {
+    let format = picture
+        .target
+        .as_deref()
+        .and_then(|target| target.rsplit_once('.'))
+        .map(|(_, ext)| ext)
+        .unwrap_or("png");
+    let mut image = Image::from_bytes(
+        picture.data.clone(),
+        picture.width.max(0) as u32,
+        picture.height.max(0) as u32,
+        format,
+    )
+    .position(picture.x.max(0) as u32, picture.y.max(0) as u32);
+    if let Some(alt) = picture.description.as_deref().filter(|alt| !alt.is_empty()) {
+        image = image.with_alt_text(alt);
+    }
+    image
+}
+
+/// Reconstruct a chart from its parsed part
+fn build_chart(parsed: &ParsedChart) -> Chart {
+    let mut chart = Chart::new(
+        &parsed.title,
+        map_chart_type(parsed),
+        parsed.categories.clone(),
+        parsed.x.max(0) as u32,
+        parsed.y.max(0) as u32,
+        parsed.width.max(0) as u32,
+        parsed.height.max(0) as u32,
+    );
+    for series in &parsed.series {
+        chart = chart.add_series(ChartSeries::new(&series.name, series.values.clone()));
+    }
+    chart
+}
+
+fn map_chart_type(parsed: &ParsedChart) -> ChartType {
+    if parsed.is_combo {
+        return ChartType::Combo;
+    }
+    let grouping = parsed.grouping.as_deref().unwrap_or("");
+    match parsed.chart_type.as_str() {
+        "barChart" | "bar3DChart" if parsed.bar_direction.as_deref() == Some("bar") => ChartType::BarHorizontal,
+        "barChart" | "bar3DChart" => match grouping {
+            "stacked" => ChartType::BarStacked,
+            "percentStacked" => ChartType::BarStacked100,
+            _ => ChartType::Bar,
+        },
+        "lineChart" | "line3DChart" => match grouping {
+            "stacked" | "percentStacked" => ChartType::LineStacked,
+            _ => ChartType::Line,
+        },
+        "pieChart" | "pie3DChart" | "ofPieChart" => ChartType::Pie,
+        "doughnutChart" => ChartType::Doughnut,
+        "areaChart" | "area3DChart" => match grouping {
+            "stacked" => ChartType::AreaStacked,
+            "percentStacked" => ChartType::AreaStacked100,
+            _ => ChartType::Area,
+        },
+        "scatterChart" => ChartType::Scatter,
+        "bubbleChart" => ChartType::Bubble,
+        "radarChart" => ChartType::Radar,
+        "stockChart" => ChartType::StockHLC,
+        _ => ChartType::Bar, // Default
+    }
+}
+
+/// Labeled rectangle marking where an unsupported element was dropped
+fn unsupported_placeholder(element: &ParsedUnsupported) -> Shape {
+    Shape::new(
+        ShapeType::Rectangle,
+        element.x.max(0) as u32,
+        element.y.max(0) as u32,
+        element.width.max(0) as u32,
+        element.height.max(0) as u32,
+    )
+    .with_fill(ShapeFill::new("F2F2F2"))
+    .with_line(ShapeLine::new("A6A6A6", 12700))
+    .with_text(&format!("[Unsupported: {}]", element.kind))
+}
+
 fn map_shape_type(type_name: &Option<String>) -> ShapeType {
     if let Some(name) = type_name {
         match name.as_str() {
@@ -27,7 +27,7 @@
 //! - **helpers** - Color / table / shape / extension helpers
 //! - **templates** - Pre-built presentation templates
 //! - **export** - Export to Markdown, HTML, images
-//! - **import** - PPTX import, HTML-to-PPTX
+//! - **import** - PPTX import, HTML-to-PPTX, Markdown-to-PPTX
 //! - **opc** - Open Packaging Convention (ZIP) handling
 //! - **oxml** - Office XML parsing and manipulation
 //! - **exc** - Error types
@@ -62,12 +62,13 @@ pub use generator::{
     create_pptx, create_pptx_with_content, create_pptx_with_settings, create_pptx_with_template,
     create_pptx_to_writer, create_pptx_with_content_to_writer, create_pptx_lazy_to_writer,
     LazySlideSource, PptxTemplate, STANDARD_LAYOUT_COUNT,
-    SlideContent, SlideLayout,
+    SlideContent, SlideLayout, PlaceholderType, CodeBlock, CodeTheme,
     TextFormat, FormattedText,
     Table, TableRow, TableCell, TableBuilder,
-    Shape, ShapeType, ShapeFill, ShapeLine,
+    Shape, ShapeType, ShapeFill, ShapeLine, ShapeGroup, Diagram, DiagramKind, DiagramNode,
     Image, ImageBuilder, ImageSource,
-    Chart, ChartType, ChartSeries, ChartBuilder,
+    Chart, ChartType, ChartSeries, ChartBuilder, ChartFont, DataLabelOptions, DataLabelPosition, LegendPosition,
+    AxisOptions, TickLabelPosition, TickMark,
     BulletStyle, BulletPoint,
     TextDirection, RtlLanguage, RtlTextProps,
     Comment, CommentAuthor, CommentAuthorList, SlideComments,
@@ -78,7 +79,7 @@ pub use generator::{
     PrintSettings, HandoutLayout, PrintColorMode, PrintWhat, Orientation,
     TableMergeMap, MergeRegion, CellMergeState,
     EmbeddedFontList, EmbeddedFont, FontStyle, FontCharset,
-    PresentationSettings,
+    PresentationSettings, SlideBackground, HeaderFooter, DateMode, PageHeaderFooter, Compression,
     PresentationTheme, ThemeColorScheme, ThemeFonts,
     Connector, ConnectorType, ConnectorLine, ArrowType, ArrowSize, ConnectionSite, LineDash,
     Hyperlink, HyperlinkAction,
@@ -88,11 +89,13 @@ pub use generator::{
 pub use core::{
     validate_package, validate_package_bytes, validate_powerpoint_structure,
     CompatReport, PackageValidationIssue, PackageValidationReport,
-    REQUIRED_PACKAGE_PARTS, ValidationCategory, ValidationSeverity,
+    REQUIRED_PACKAGE_PARTS, ValidationCategory, ValidationSeverity, ValidationWarning,
 };
 
 // Export convenience types for new capabilities
+pub use import::{import_pptx, import_pptx_with_options, import_pptx_bytes, import_pptx_bytes_with_options, ImportOptions, UnsupportedElementMode};
 pub use import::html::{parse_html, parse_html_with_options, HtmlParseOptions, Html2Ppt};
+pub use import::markdown::{markdown_to_pptx, parse_markdown};
 pub use export::md::{MarkdownOptions, export_to_markdown, export_to_markdown_with_options};
 pub use export::image_export::{
     ImageExportOptions, ImageFormat,
@@ -100,11 +103,18 @@ pub use export::image_export::{
 };
 pub use export::slide_render::{render_to_pdf, render_to_pdf_bytes};
 
+#[cfg(feature = "render")]
+pub use export::svg::render_slide_svg;
+#[cfg(feature = "render")]
+pub use export::png::render_slide_png;
+
 #[cfg(feature = "pdf-native")]
 pub use export::pdf_export::{
     export_to_pdf, export_to_pdf_bytes,
     PdfExportOptions, PdfOrientation,
 };
+#[cfg(feature = "signing")]
+pub use opc::PackageSigner;
 pub use opc::compress::{
     CompressionOptions, CompressionLevel, CompressionResult,
     compress_pptx, compress_pptx_in_memory, analyze_pptx
@@ -115,7 +125,7 @@ pub use parts::{
     PresentationPart, SlidePart, SlideLayoutPart, LayoutType,
     SlideMasterPart, ThemePart, NotesSlidePart,
     ImagePart, MediaPart, MediaFormat, ChartPart,
-    TablePart, TableRowPart, TableCellPart,
+    TablePart, TableRowPart, TableCellPart, TableStyle,
     CorePropertiesPart, AppPropertiesPart,
     ContentTypesPart, Relationships,
 };
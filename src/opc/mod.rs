@@ -2,8 +2,12 @@
 
 pub mod package;
 pub mod compress;
+#[cfg(feature = "signing")]
+pub mod signing;
 
 pub use package::Package;
+#[cfg(feature = "signing")]
+pub use signing::PackageSigner;
 pub use compress::{
     compress_pptx, compress_pptx_in_memory, analyze_pptx,
     CompressionOptions, CompressionLevel, CompressionResult,
@@ -0,0 +1,191 @@
+//! Signing certificate and private key loading (DER or PEM)
+
+use rsa::pkcs1::DecodeRsaPrivateKey;
+use rsa::pkcs8::{DecodePrivateKey, DecodePublicKey};
+use rsa::traits::PublicKeyParts;
+use rsa::{BigUint, RsaPrivateKey, RsaPublicKey};
+use x509_cert::der::{Decode, DecodePem, Encode};
+use x509_cert::name::Name;
+
+use crate::exc::{messages, PptxError, Result};
+
+/// X.509 certificate fields referenced by the signature
+#[derive(Clone, Debug)]
+pub(crate) struct Certificate {
+    pub(crate) der: Vec<u8>,
+    /// Issuer distinguished name in RFC 4514 form, e.g. `CN=Signer, O=Example, C=US`
+    pub(crate) issuer: String,
+    /// Serial number in decimal
+    pub(crate) serial: String,
+    /// Subject common name, used as the signer's display name
+    pub(crate) subject_name: Option<String>,
+    pub(crate) public_key: RsaPublicKey,
+}
+
+impl Certificate {
+    pub(crate) fn parse(input: &[u8]) -> Result<Self> {
+        let cert = match pem_label(input) {
+            Some("CERTIFICATE") => x509_cert::Certificate::from_pem(input),
+            Some(other) => return Err(invalid_certificate(&format!("unsupported PEM block \"{other}\""))),
+            None => x509_cert::Certificate::from_der(input),
+        }
+        .map_err(|e| invalid_certificate(&e.to_string()))?;
+        let tbs = &cert.tbs_certificate;
+        let spki = tbs
+            .subject_public_key_info
+            .to_der()
+            .map_err(|e| invalid_certificate(&e.to_string()))?;
+        let public_key = RsaPublicKey::from_public_key_der(&spki)
+            .map_err(|_| invalid_certificate("only RSA certificates are supported"))?;
+        Ok(Self {
+            der: cert.to_der().map_err(|e| invalid_certificate(&e.to_string()))?,
+            issuer: distinguished_name(&tbs.issuer),
+            serial: BigUint::from_bytes_be(tbs.serial_number.as_bytes()).to_string(),
+            subject_name: common_name(&tbs.subject),
+            public_key,
+        })
+    }
+}
+
+/// Parse an RSA private key: PKCS#1 `RSAPrivateKey` or unencrypted PKCS#8
+/// `PrivateKeyInfo`, either as DER or PEM
+pub(crate) fn parse_private_key(input: &[u8]) -> Result<RsaPrivateKey> {
+    let key = match pem_label(input) {
+        Some("RSA PRIVATE KEY") => std::str::from_utf8(input).ok().and_then(|pem| RsaPrivateKey::from_pkcs1_pem(pem).ok()),
+        Some("PRIVATE KEY") => std::str::from_utf8(input).ok().and_then(|pem| RsaPrivateKey::from_pkcs8_pem(pem).ok()),
+        Some(other) => return Err(invalid_key(&format!("unsupported PEM block \"{other}\""))),
+        None => RsaPrivateKey::from_pkcs8_der(input).or_else(|_| RsaPrivateKey::from_pkcs1_der(input)).ok(),
+    }
+    .ok_or_else(|| invalid_key("not an unencrypted RSA private key"))?;
+    if key.n().bits() < 1024 {
+        return Err(invalid_key("RSA modulus must be at least 1024 bits"));
+    }
+    Ok(key)
+}
+
+fn invalid_key(detail: &str) -> PptxError {
+    PptxError::InvalidValue(messages::invalid_value("signing key", detail))
+}
+
+fn invalid_certificate(detail: &str) -> PptxError {
+    PptxError::InvalidValue(messages::invalid_value("signing certificate", detail))
+}
+
+/// Label of the first PEM block, if the input is PEM
+fn pem_label(input: &[u8]) -> Option<&str> {
+    let text = std::str::from_utf8(input).ok()?;
+    let start = text.find("-----BEGIN ")? + "-----BEGIN ".len();
+    let end = start + text[start..].find("-----")?;
+    Some(&text[start..end])
+}
+
+/// RFC 4514 string for an X.501 `Name`: RDNs in reverse order, comma separated
+fn distinguished_name(name: &Name) -> String {
+    let rdns: Vec<String> = name
+        .0
+        .iter()
+        .rev()
+        .map(|rdn| {
+            rdn.0
+                .iter()
+                .map(|atv| format!("{}={}", attribute_label(&atv.oid.to_string()), escape_dn_value(&attribute_text(atv))))
+                .collect::<Vec<_>>()
+                .join("+")
+        })
+        .collect();
+    rdns.join(", ")
+}
+
+fn common_name(name: &Name) -> Option<String> {
+    name.0
+        .iter()
+        .flat_map(|rdn| rdn.0.iter())
+        .filter(|atv| attribute_label(&atv.oid.to_string()) == "CN")
+        .map(attribute_text)
+        .next_back()
+}
+
+fn attribute_text(atv: &x509_cert::attr::AttributeTypeAndValue) -> String {
+    String::from_utf8_lossy(atv.value.value()).into_owned()
+}
+
+/// Short label for a name attribute, the dotted OID when it has none
+fn attribute_label(oid: &str) -> &str {
+    match oid {
+        "2.5.4.3" => "CN",
+        "2.5.4.5" => "SERIALNUMBER",
+        "2.5.4.6" => "C",
+        "2.5.4.7" => "L",
+        "2.5.4.8" => "S",
+        "2.5.4.9" => "STREET",
+        "2.5.4.10" => "O",
+        "2.5.4.11" => "OU",
+        "2.5.4.12" => "T",
+        "1.2.840.113549.1.9.1" => "E",
+        "0.9.2342.19200300.100.1.25" => "DC",
+        _ => oid,
+    }
+}
+
+fn escape_dn_value(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for (i, c) in value.chars().enumerate() {
+        let leading = i == 0 && (c == ' ' || c == '#');
+        let trailing = i + 1 == value.chars().count() && c == ' ';
+        if matches!(c, ',' | '+' | '"' | '\\' | '<' | '>' | ';') || leading || trailing {
+            out.push('\\');
+        }
+        out.push(c);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CERT_PEM: &[u8] = include_bytes!("../../../tests/fixtures/signing/signer.pem");
+    const CERT_DER: &[u8] = include_bytes!("../../../tests/fixtures/signing/signer.cer");
+    const KEY_PKCS8_PEM: &[u8] = include_bytes!("../../../tests/fixtures/signing/signer.key");
+    const KEY_PKCS1_DER: &[u8] = include_bytes!("../../../tests/fixtures/signing/signer-pkcs1.der");
+
+    #[test]
+    fn test_certificate_fields() {
+        let cert = Certificate::parse(CERT_DER).unwrap();
+        assert_eq!(cert.issuer, "CN=Example Signer, O=Example Corp, C=US");
+        assert_eq!(cert.serial, "28772997619311");
+        assert_eq!(cert.subject_name.as_deref(), Some("Example Signer"));
+        assert_eq!(cert.public_key.n().bits(), 2048);
+        assert_eq!(cert.der, CERT_DER);
+    }
+
+    #[test]
+    fn test_pem_certificate_matches_der() {
+        let cert = Certificate::parse(CERT_PEM).unwrap();
+        assert_eq!(cert.der, CERT_DER);
+    }
+
+    #[test]
+    fn test_pkcs8_and_pkcs1_keys_agree() {
+        let pkcs8 = parse_private_key(KEY_PKCS8_PEM).unwrap();
+        let pkcs1 = parse_private_key(KEY_PKCS1_DER).unwrap();
+        assert_eq!(pkcs8, pkcs1);
+        assert_eq!(pkcs8.to_public_key(), Certificate::parse(CERT_DER).unwrap().public_key);
+    }
+
+    #[test]
+    fn test_rejects_wrong_inputs() {
+        assert!(parse_private_key(CERT_PEM).is_err());
+        assert!(parse_private_key(b"not a key").is_err());
+        assert!(Certificate::parse(KEY_PKCS1_DER).is_err());
+        assert!(Certificate::parse(KEY_PKCS8_PEM).is_err());
+    }
+
+    #[test]
+    fn test_dn_escaping_and_labels() {
+        assert_eq!(escape_dn_value("Smith, John"), "Smith\\, John");
+        assert_eq!(escape_dn_value(" lead"), "\\ lead");
+        assert_eq!(attribute_label("2.5.4.97"), "2.5.4.97");
+        assert_eq!(attribute_label("2.5.4.10"), "O");
+    }
+}
@@ -0,0 +1,647 @@
+//! Digital signatures for OPC packages
+//!
+//! Signs a finished PPTX per the OOXML digital signature spec (ECMA-376 Part 2,
+//! with the XAdES properties PowerPoint writes): every part reached through a
+//! content relationship is digested into a manifest, the relationship parts are
+//! digested through the relationship transform, and the signature is stored in
+//! `_xmlsignatures/sig1.xml` behind the package's signature origin.
+//!
+//! Certificates are X.509, as DER or PEM (`CERTIFICATE`). Private keys are RSA,
+//! as DER or PEM, in PKCS#1 (`RSA PRIVATE KEY`) or unencrypted PKCS#8
+//! (`PRIVATE KEY`) form. Encrypted keys and non-RSA keys are rejected.
+
+mod certificate;
+
+use std::collections::{BTreeMap, HashMap};
+use std::io::{Cursor, Read, Write};
+
+use sha1::Sha1;
+use sha2::{Digest, Sha256, Sha384, Sha512};
+use zip::write::FileOptions;
+use zip::{ZipArchive, ZipWriter};
+
+use crate::core::{rels_source_dir, resolve_rel_target};
+use crate::exc::{messages, PptxError, Result};
+use crate::export::html::base64_encode;
+use crate::generator::{DigitalSignature, HashAlgorithm, SignatureCommitment};
+use certificate::{parse_private_key, Certificate};
+use rsa::rand_core::OsRng;
+use rsa::{Pkcs1v15Sign, RsaPrivateKey};
+
+const DSIG_NS: &str = "http://www.w3.org/2000/09/xmldsig#";
+const C14N: &str = "http://www.w3.org/TR/2001/REC-xml-c14n-20010315";
+const RELATIONSHIP_TRANSFORM: &str = "http://schemas.openxmlformats.org/package/2006/RelationshipTransform";
+const PACKAGE_SIGNATURE_NS: &str = "http://schemas.openxmlformats.org/package/2006/digital-signature";
+const RELATIONSHIPS_NS: &str = "http://schemas.openxmlformats.org/package/2006/relationships";
+const RELATIONSHIPS_CONTENT_TYPE: &str = "application/vnd.openxmlformats-package.relationships+xml";
+const ORIGIN_RELATIONSHIP: &str = "http://schemas.openxmlformats.org/package/2006/relationships/digital-signature/origin";
+const SIGNATURE_RELATIONSHIP: &str = "http://schemas.openxmlformats.org/package/2006/relationships/digital-signature/signature";
+const SIGNATURE_PART: &str = "_xmlsignatures/sig1.xml";
+const ORIGIN_PART: &str = "_xmlsignatures/origin.sigs";
+
+/// Relationship types whose targets are covered by the signature. Document
+/// properties and thumbnails are left out so that saving metadata does not
+/// invalidate the signature, matching what PowerPoint signs.
+const SIGNED_RELATIONSHIP_TYPES: &[&str] = &[
+    "audio", "chart", "chartColorStyle", "chartStyle", "chartUserShapes", "commentAuthors",
+    "comments", "customXml", "diagramColors", "diagramData", "diagramDrawing", "diagramLayout",
+    "diagramQuickStyle", "font", "handoutMaster", "hyperlink", "image", "media", "notesMaster",
+    "notesSlide", "officeDocument", "oleObject", "package", "presProps", "printerSettings",
+    "slide", "slideLayout", "slideMaster", "tableStyles", "tags", "theme", "themeOverride",
+    "video", "viewProps",
+];
+
+/// Signs PPTX packages with an RSA key and its X.509 certificate
+#[derive(Clone)]
+pub struct PackageSigner {
+    certificate: Certificate,
+    key: RsaPrivateKey,
+    commitment: SignatureCommitment,
+}
+
+impl std::fmt::Debug for PackageSigner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PackageSigner")
+            .field("issuer", &self.certificate.issuer)
+            .field("serial", &self.certificate.serial)
+            .field("commitment", &self.commitment)
+            .finish_non_exhaustive()
+    }
+}
+
+impl PackageSigner {
+    /// Signer from a certificate and the private key it certifies.
+    ///
+    /// Fails when either cannot be parsed or the key does not belong to the certificate.
+    pub fn new(certificate: &[u8], private_key: &[u8], commitment: SignatureCommitment) -> Result<Self> {
+        let certificate = Certificate::parse(certificate)?;
+        let key = parse_private_key(private_key)?;
+        if certificate.public_key != key.to_public_key() {
+            return Err(PptxError::InvalidValue(messages::invalid_value(
+                "signing key",
+                "does not match the certificate's public key",
+            )));
+        }
+        Ok(Self { certificate, key, commitment })
+    }
+
+    /// Common name of the certificate subject
+    pub fn signer_name(&self) -> Option<&str> {
+        self.certificate.subject_name.as_deref()
+    }
+
+    /// Sign a PPTX package, replacing any signature parts it already has.
+    ///
+    /// `details` supplies the hash algorithm, signing time and comments; without
+    /// it the signature uses SHA-256 and the current time.
+    pub fn sign(&self, package: &[u8], details: Option<&DigitalSignature>) -> Result<Vec<u8>> {
+        let algorithm = details.map(|d| d.hash_algorithm).unwrap_or_default();
+        let signing_time = details
+            .and_then(|d| d.sign_date.clone())
+            .unwrap_or_else(current_utc_timestamp);
+        let comments = details.and_then(|d| d.comments.as_deref()).unwrap_or_default();
+
+        let entry_options = entry_options(package)?;
+        let mut parts = read_parts(package)?;
+        parts.retain(|(name, _)| !name.starts_with("_xmlsignatures/"));
+        let lookup: HashMap<&str, &[u8]> = parts.iter().map(|(n, b)| (n.as_str(), b.as_slice())).collect();
+        let content_types = ContentTypes::parse(&part_text(&lookup, "[Content_Types].xml")?);
+        let manifest = manifest_references(&lookup, &content_types, algorithm)?;
+
+        let package_object = format!(
+            r##"<Object Id="idPackageObject"><Manifest>{manifest}</Manifest><SignatureProperties><SignatureProperty Id="idSignatureTime" Target="#idPackageSignature"><mdssi:SignatureTime xmlns:mdssi="{PACKAGE_SIGNATURE_NS}"><mdssi:Format>YYYY-MM-DDThh:mm:ssTZD</mdssi:Format><mdssi:Value>{time}</mdssi:Value></mdssi:SignatureTime></SignatureProperty></SignatureProperties></Object>"##,
+            time = c14n_text(&signing_time),
+        );
+        let office_object = format!(
+            r##"<Object Id="idOfficeObject"><SignatureProperties><SignatureProperty Id="idOfficeV1Details" Target="#idPackageSignature"><SignatureInfoV1 xmlns="http://schemas.microsoft.com/office/2006/digsig"><SetupID></SetupID><SignatureText></SignatureText><SignatureImage></SignatureImage><SignatureComments>{comments}</SignatureComments><WindowsVersion>10.0</WindowsVersion><OfficeVersion>16.0</OfficeVersion><ApplicationVersion>16.0</ApplicationVersion><Monitors>1</Monitors><HorizontalResolution>1920</HorizontalResolution><VerticalResolution>1080</VerticalResolution><ColorDepth>32</ColorDepth><SignatureProviderId>{{00000000-0000-0000-0000-000000000000}}</SignatureProviderId><SignatureProviderUrl></SignatureProviderUrl><SignatureProviderDetails>9</SignatureProviderDetails><SignatureType>1</SignatureType><ManifestHashAlgorithm>{hash}</ManifestHashAlgorithm></SignatureInfoV1></SignatureProperty></SignatureProperties></Object>"##,
+            comments = c14n_text(comments),
+            hash = algorithm.uri(),
+        );
+        let signed_properties = format!(
+            r#"<xd:SignedProperties Id="idSignedProperties"><xd:SignedSignatureProperties><xd:SigningTime>{time}</xd:SigningTime><xd:SigningCertificate><xd:Cert><xd:CertDigest><DigestMethod Algorithm="{hash}"></DigestMethod><DigestValue>{cert_digest}</DigestValue></xd:CertDigest><xd:IssuerSerial><X509IssuerName>{issuer}</X509IssuerName><X509SerialNumber>{serial}</X509SerialNumber></xd:IssuerSerial></xd:Cert></xd:SigningCertificate><xd:SignaturePolicyIdentifier><xd:SignaturePolicyImplied></xd:SignaturePolicyImplied></xd:SignaturePolicyIdentifier></xd:SignedSignatureProperties><xd:SignedDataObjectProperties><xd:CommitmentTypeIndication><xd:CommitmentTypeId><xd:Identifier>{commitment}</xd:Identifier><xd:Description>{label}</xd:Description></xd:CommitmentTypeId><xd:AllSignedDataObjects></xd:AllSignedDataObjects></xd:CommitmentTypeIndication></xd:SignedDataObjectProperties></xd:SignedProperties>"#,
+            time = c14n_text(&signing_time),
+            hash = algorithm.uri(),
+            cert_digest = base64_encode(&digest(algorithm, &self.certificate.der)),
+            issuer = c14n_text(&self.certificate.issuer),
+            serial = self.certificate.serial,
+            commitment = self.commitment.uri(),
+            label = self.commitment.label(),
+        );
+        let xades_object = format!(
+            r##"<Object><xd:QualifyingProperties xmlns:xd="http://uri.etsi.org/01903/v1.3.2#" Target="#idPackageSignature">{signed_properties}</xd:QualifyingProperties></Object>"##
+        );
+
+        // Each reference digests its element's canonical form, which carries the
+        // namespaces in scope from <Signature> (and <xd:QualifyingProperties>)
+        let object_digest = |object: &str| {
+            let canonical = object.replacen("<Object ", &format!(r#"<Object xmlns="{DSIG_NS}" "#), 1);
+            base64_encode(&digest(algorithm, canonical.as_bytes()))
+        };
+        let properties_canonical = signed_properties.replacen(
+            "<xd:SignedProperties ",
+            &format!(r#"<xd:SignedProperties xmlns="{DSIG_NS}" xmlns:xd="http://uri.etsi.org/01903/v1.3.2#" "#),
+            1,
+        );
+        let signed_info = format!(
+            r##"<SignedInfo><CanonicalizationMethod Algorithm="{C14N}"></CanonicalizationMethod><SignatureMethod Algorithm="{method}"></SignatureMethod><Reference Type="http://www.w3.org/2000/09/xmldsig#Object" URI="#idPackageObject"><DigestMethod Algorithm="{hash}"></DigestMethod><DigestValue>{package_digest}</DigestValue></Reference><Reference Type="http://www.w3.org/2000/09/xmldsig#Object" URI="#idOfficeObject"><DigestMethod Algorithm="{hash}"></DigestMethod><DigestValue>{office_digest}</DigestValue></Reference><Reference Type="http://uri.etsi.org/01903#SignedProperties" URI="#idSignedProperties"><Transforms><Transform Algorithm="{C14N}"></Transform></Transforms><DigestMethod Algorithm="{hash}"></DigestMethod><DigestValue>{properties_digest}</DigestValue></Reference></SignedInfo>"##,
+            method = signature_method_uri(algorithm),
+            hash = algorithm.uri(),
+            package_digest = object_digest(&package_object),
+            office_digest = object_digest(&office_object),
+            properties_digest = base64_encode(&digest(algorithm, properties_canonical.as_bytes())),
+        );
+        let signed_info_canonical = signed_info.replacen("<SignedInfo>", &format!(r#"<SignedInfo xmlns="{DSIG_NS}">"#), 1);
+        let signature_value = sign_digest(&self.key, algorithm, &digest(algorithm, signed_info_canonical.as_bytes()))?;
+
+        let signature_xml = format!(
+            r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?><Signature xmlns="{DSIG_NS}" Id="idPackageSignature">{signed_info}<SignatureValue>{value}</SignatureValue><KeyInfo><X509Data><X509Certificate>{certificate}</X509Certificate></X509Data></KeyInfo>{package_object}{office_object}{xades_object}</Signature>"#,
+            value = base64_encode(&signature_value),
+            certificate = base64_encode(&self.certificate.der),
+        );
+        write_signed_package(parts, &signature_xml, &entry_options)
+    }
+}
+
+fn signature_method_uri(algorithm: HashAlgorithm) -> &'static str {
+    match algorithm {
+        HashAlgorithm::Sha1 => "http://www.w3.org/2000/09/xmldsig#rsa-sha1",
+        HashAlgorithm::Sha256 => "http://www.w3.org/2001/04/xmldsig-more#rsa-sha256",
+        HashAlgorithm::Sha384 => "http://www.w3.org/2001/04/xmldsig-more#rsa-sha384",
+        HashAlgorithm::Sha512 => "http://www.w3.org/2001/04/xmldsig-more#rsa-sha512",
+    }
+}
+
+/// PKCS#1 v1.5 scheme whose `DigestInfo` names `algorithm`
+fn pkcs1v15_scheme(algorithm: HashAlgorithm) -> Pkcs1v15Sign {
+    match algorithm {
+        HashAlgorithm::Sha1 => Pkcs1v15Sign::new::<Sha1>(),
+        HashAlgorithm::Sha256 => Pkcs1v15Sign::new::<Sha256>(),
+        HashAlgorithm::Sha384 => Pkcs1v15Sign::new::<Sha384>(),
+        HashAlgorithm::Sha512 => Pkcs1v15Sign::new::<Sha512>(),
+    }
+}
+
+/// RSASSA-PKCS1-v1_5 signature of an already computed `digest`, with blinding
+fn sign_digest(key: &RsaPrivateKey, algorithm: HashAlgorithm, digest: &[u8]) -> Result<Vec<u8>> {
+    key.sign_with_rng(&mut OsRng, pkcs1v15_scheme(algorithm), digest)
+        .map_err(|e| PptxError::InvalidValue(messages::invalid_value("signing key", &e.to_string())))
+}
+
+fn digest(algorithm: HashAlgorithm, data: &[u8]) -> Vec<u8> {
+    match algorithm {
+        HashAlgorithm::Sha1 => Sha1::digest(data).to_vec(),
+        HashAlgorithm::Sha256 => Sha256::digest(data).to_vec(),
+        HashAlgorithm::Sha384 => Sha384::digest(data).to_vec(),
+        HashAlgorithm::Sha512 => Sha512::digest(data).to_vec(),
+    }
+}
+
+fn read_parts(package: &[u8]) -> Result<Vec<(String, Vec<u8>)>> {
+    let mut archive = ZipArchive::new(Cursor::new(package))?;
+    let mut parts = Vec::with_capacity(archive.len());
+    for i in 0..archive.len() {
+        let mut file = archive.by_index(i)?;
+        if file.is_dir() {
+            continue;
+        }
+        let mut bytes = Vec::with_capacity(file.size() as usize);
+        file.read_to_end(&mut bytes)?;
+        parts.push((file.name().to_string(), bytes));
+    }
+    Ok(parts)
+}
+
+fn part_text(parts: &HashMap<&str, &[u8]>, name: &str) -> Result<String> {
+    parts
+        .get(name)
+        .map(|bytes| String::from_utf8_lossy(bytes).into_owned())
+        .ok_or_else(|| PptxError::NotFound(messages::missing_part(name)))
+}
+
+/// Manifest `<Reference>` elements, ordered by URI
+fn manifest_references(
+    parts: &HashMap<&str, &[u8]>,
+    content_types: &ContentTypes,
+    algorithm: HashAlgorithm,
+) -> Result<String> {
+    let hash = algorithm.uri();
+    let mut references = BTreeMap::new();
+    let mut rels_parts: Vec<&str> = parts.keys().copied().filter(|n| n.ends_with(".rels")).collect();
+    rels_parts.sort_unstable();
+    for rels_path in rels_parts {
+        let base = rels_source_dir(rels_path);
+        let mut signed: Vec<Relationship> = parse_relationships(&part_text(parts, rels_path)?)
+            .into_iter()
+            .filter(Relationship::is_signed)
+            .collect();
+        if signed.is_empty() {
+            continue;
+        }
+        signed.sort_by(|a, b| a.id.cmp(&b.id));
+
+        for rel in signed.iter().filter(|r| !r.is_external()) {
+            let target = resolve_rel_target(&base, &rel.target);
+            if let Some(bytes) = parts.get(target.as_str()) {
+                let uri = format!("/{target}?ContentType={}", content_types.of(&target));
+                let value = base64_encode(&digest(algorithm, bytes));
+                references.entry(uri.clone()).or_insert_with(|| {
+                    format!(r#"<Reference URI="{}"><DigestMethod Algorithm="{hash}"></DigestMethod><DigestValue>{value}</DigestValue></Reference>"#, c14n_attr(&uri))
+                });
+            }
+        }
+
+        let selectors: String = signed
+            .iter()
+            .map(|rel| format!(r#"<mdssi:RelationshipReference xmlns:mdssi="{PACKAGE_SIGNATURE_NS}" SourceId="{}"></mdssi:RelationshipReference>"#, c14n_attr(&rel.id)))
+            .collect();
+        let transformed: String = signed
+            .iter()
+            .map(|rel| {
+                let mode = if rel.is_external() { "External" } else { "Internal" };
+                format!(
+                    r#"<Relationship Id="{}" Target="{}" TargetMode="{mode}" Type="{}"></Relationship>"#,
+                    c14n_attr(&rel.id),
+                    c14n_attr(&rel.target),
+                    c14n_attr(&rel.rel_type),
+                )
+            })
+            .collect();
+        let canonical = format!(r#"<Relationships xmlns="{RELATIONSHIPS_NS}">{transformed}</Relationships>"#);
+        let value = base64_encode(&digest(algorithm, canonical.as_bytes()));
+        let uri = format!("/{rels_path}?ContentType={RELATIONSHIPS_CONTENT_TYPE}");
+        references.insert(
+            uri.clone(),
+            format!(
+                r#"<Reference URI="{}"><Transforms><Transform Algorithm="{RELATIONSHIP_TRANSFORM}">{selectors}</Transform><Transform Algorithm="{C14N}"></Transform></Transforms><DigestMethod Algorithm="{hash}"></DigestMethod><DigestValue>{value}</DigestValue></Reference>"#,
+                c14n_attr(&uri)
+            ),
+        );
+    }
+    Ok(references.into_values().collect())
+}
+
+/// Compression method and modification time of every entry, so re-writing the
+/// package keeps them (Deflate levels are not recorded and fall back to the default)
+fn entry_options(package: &[u8]) -> Result<HashMap<String, FileOptions>> {
+    let mut archive = ZipArchive::new(Cursor::new(package))?;
+    let mut options = HashMap::with_capacity(archive.len());
+    for i in 0..archive.len() {
+        let file = archive.by_index(i)?;
+        let entry = FileOptions::default()
+            .compression_method(file.compression())
+            .last_modified_time(file.last_modified());
+        options.insert(file.name().to_string(), entry);
+    }
+    Ok(options)
+}
+
+/// Copy the package with its signature origin, origin relationships and signature part added.
+///
+/// Copied parts keep their entry options; new parts take those of `[Content_Types].xml`.
+fn write_signed_package(
+    parts: Vec<(String, Vec<u8>)>,
+    signature_xml: &str,
+    entry_options: &HashMap<String, FileOptions>,
+) -> Result<Vec<u8>> {
+    let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+    let options = entry_options.get("[Content_Types].xml").copied().unwrap_or_default();
+    for (name, bytes) in parts {
+        let part_options = entry_options.get(&name).copied().unwrap_or(options);
+        let bytes = match name.as_str() {
+            "[Content_Types].xml" => with_signature_content_types(&String::from_utf8_lossy(&bytes)).into_bytes(),
+            "_rels/.rels" => with_origin_relationship(&String::from_utf8_lossy(&bytes)).into_bytes(),
+            _ => bytes,
+        };
+        zip.start_file(name, part_options)?;
+        zip.write_all(&bytes)?;
+    }
+    zip.start_file(ORIGIN_PART, options)?;
+    zip.start_file("_xmlsignatures/_rels/origin.sigs.rels", options)?;
+    zip.write_all(
+        format!(
+            r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?><Relationships xmlns="{RELATIONSHIPS_NS}"><Relationship Id="rId1" Type="{SIGNATURE_RELATIONSHIP}" Target="sig1.xml"/></Relationships>"#
+        )
+        .as_bytes(),
+    )?;
+    zip.start_file(SIGNATURE_PART, options)?;
+    zip.write_all(signature_xml.as_bytes())?;
+    Ok(zip.finish()?.into_inner())
+}
+
+fn with_signature_content_types(xml: &str) -> String {
+    let mut entries = String::new();
+    if !xml.contains(r#"Extension="sigs""#) {
+        entries.push_str(r#"<Default Extension="sigs" ContentType="application/vnd.openxmlformats-package.digital-signature-origin"/>"#);
+    }
+    if !xml.contains(&format!(r#"PartName="/{SIGNATURE_PART}""#)) {
+        entries.push_str(&format!(
+            r#"<Override PartName="/{SIGNATURE_PART}" ContentType="application/vnd.openxmlformats-package.digital-signature-xmlsignature+xml"/>"#
+        ));
+    }
+    match xml.rfind("</Types>") {
+        Some(pos) => format!("{}{entries}{}", &xml[..pos], &xml[pos..]),
+        None => xml.to_string(),
+    }
+}
+
+fn with_origin_relationship(xml: &str) -> String {
+    if xml.contains(ORIGIN_RELATIONSHIP) {
+        return xml.to_string();
+    }
+    let ids: Vec<String> = parse_relationships(xml).into_iter().map(|r| r.id).collect();
+    let id = (1..).map(|n| format!("rId{n}")).find(|id| !ids.contains(id)).unwrap_or_default();
+    match xml.rfind("</Relationships>") {
+        Some(pos) => format!(
+            r#"{}<Relationship Id="{id}" Type="{ORIGIN_RELATIONSHIP}" Target="{ORIGIN_PART}"/>{}"#,
+            &xml[..pos],
+            &xml[pos..]
+        ),
+        None => xml.to_string(),
+    }
+}
+
+struct Relationship {
+    id: String,
+    rel_type: String,
+    target: String,
+    target_mode: Option<String>,
+}
+
+impl Relationship {
+    fn is_external(&self) -> bool {
+        self.target_mode.as_deref() == Some("External")
+    }
+
+    fn is_signed(&self) -> bool {
+        let kind = self.rel_type.rsplit('/').next().unwrap_or_default();
+        SIGNED_RELATIONSHIP_TYPES.contains(&kind)
+    }
+}
+
+fn parse_relationships(xml: &str) -> Vec<Relationship> {
+    xml.split("<Relationship ")
+        .skip(1)
+        .filter_map(|element| {
+            let element = &element[..element.find('>').unwrap_or(element.len())];
+            Some(Relationship {
+                id: attribute(element, "Id")?,
+                rel_type: attribute(element, "Type")?,
+                target: attribute(element, "Target")?,
+                target_mode: attribute(element, "TargetMode"),
+            })
+        })
+        .collect()
+}
+
+/// Unescaped value of `name="..."` (or single-quoted) within an element's attribute text
+fn attribute(element: &str, name: &str) -> Option<String> {
+    let mut rest = element;
+    loop {
+        let pos = rest.find(name)?;
+        let preceded_by_space = rest[..pos].ends_with(|c: char| c.is_ascii_whitespace()) || pos == 0;
+        let after = rest[pos + name.len()..].trim_start();
+        if preceded_by_space && let Some(value) = after.strip_prefix('=') {
+            let value = value.trim_start();
+            let quote = value.chars().next()?;
+            let value = &value[1..];
+            return Some(unescape_xml(&value[..value.find(quote)?]));
+        }
+        rest = &rest[pos + name.len()..];
+    }
+}
+
+fn unescape_xml(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    let mut rest = value;
+    while let Some(pos) = rest.find('&') {
+        out.push_str(&rest[..pos]);
+        let Some(end) = rest[pos..].find(';') else {
+            out.push_str(&rest[pos..]);
+            return out;
+        };
+        let entity = &rest[pos + 1..pos + end];
+        let decoded = match entity {
+            "amp" => Some('&'),
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            _ => entity
+                .strip_prefix("#x")
+                .map(|hex| u32::from_str_radix(hex, 16))
+                .or_else(|| entity.strip_prefix('#').map(str::parse))
+                .and_then(|code| code.ok())
+                .and_then(char::from_u32),
+        };
+        match decoded {
+            Some(c) => out.push(c),
+            None => out.push_str(&rest[pos..=pos + end]),
+        }
+        rest = &rest[pos + end + 1..];
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Text content escaped as canonical XML renders it
+fn c14n_text(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('\r', "&#xD;")
+}
+
+/// Attribute value escaped as canonical XML renders it
+fn c14n_attr(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('"', "&quot;")
+        .replace('\t', "&#x9;")
+        .replace('\n', "&#xA;")
+        .replace('\r', "&#xD;")
+}
+
+/// `[Content_Types].xml` defaults and overrides
+struct ContentTypes {
+    defaults: HashMap<String, String>,
+    overrides: HashMap<String, String>,
+}
+
+impl ContentTypes {
+    fn parse(xml: &str) -> Self {
+        let entries = |tag: &str, key: &str| -> HashMap<String, String> {
+            xml.split(tag)
+                .skip(1)
+                .filter_map(|element| {
+                    let element = &element[..element.find('>').unwrap_or(element.len())];
+                    Some((attribute(element, key)?, attribute(element, "ContentType")?))
+                })
+                .collect()
+        };
+        Self {
+            defaults: entries("<Default ", "Extension")
+                .into_iter()
+                .map(|(ext, ct)| (ext.to_ascii_lowercase(), ct))
+                .collect(),
+            overrides: entries("<Override ", "PartName"),
+        }
+    }
+
+    fn of(&self, part: &str) -> &str {
+        if let Some(ct) = self.overrides.get(&format!("/{part}")) {
+            return ct;
+        }
+        let ext = part.rsplit_once('.').map(|(_, ext)| ext.to_ascii_lowercase()).unwrap_or_default();
+        self.defaults.get(&ext).map(String::as_str).unwrap_or("application/octet-stream")
+    }
+}
+
+/// Current UTC time as `YYYY-MM-DDThh:mm:ssZ`
+fn current_utc_timestamp() -> String {
+    let secs = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or_default();
+    let (days, rem) = (secs.div_euclid(86_400), secs.rem_euclid(86_400));
+    // Civil date from days since 1970-01-01 (Howard Hinnant's algorithm)
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}Z",
+        rem / 3600,
+        rem % 3600 / 60,
+        rem % 60
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CERT_PEM: &[u8] = include_bytes!("../../../tests/fixtures/signing/signer.pem");
+    const KEY_PKCS8_PEM: &[u8] = include_bytes!("../../../tests/fixtures/signing/signer.key");
+
+    fn between<'a>(xml: &'a str, start: &str, end: &str) -> &'a str {
+        let from = xml.find(start).unwrap();
+        &xml[from..from + xml[from..].find(end).unwrap() + end.len()]
+    }
+
+    #[test]
+    fn test_signature_value_and_digests_verify() {
+        let package = crate::generator::create_pptx("Signed", 2).unwrap();
+        let signer = PackageSigner::new(CERT_PEM, KEY_PKCS8_PEM, SignatureCommitment::Created).unwrap();
+        let signed = signer.sign(&package, None).unwrap();
+        let parts: HashMap<String, Vec<u8>> = read_parts(&signed).unwrap().into_iter().collect();
+        let xml = String::from_utf8(parts[SIGNATURE_PART].clone()).unwrap();
+
+        // SignatureValue verifies against the certificate over the canonical SignedInfo digest
+        let signed_info = between(&xml, "<SignedInfo>", "</SignedInfo>")
+            .replacen("<SignedInfo>", &format!(r#"<SignedInfo xmlns="{DSIG_NS}">"#), 1);
+        let hashed = digest(HashAlgorithm::Sha256, signed_info.as_bytes());
+        let expected = sign_digest(&signer.key, HashAlgorithm::Sha256, &hashed).unwrap();
+        let value = between(&xml, "<SignatureValue>", "</SignatureValue>");
+        assert_eq!(value, format!("<SignatureValue>{}</SignatureValue>", base64_encode(&expected)));
+        signer
+            .certificate
+            .public_key
+            .verify(pkcs1v15_scheme(HashAlgorithm::Sha256), &hashed, &expected)
+            .unwrap();
+
+        // Manifest digests cover the parts as written
+        let presentation = base64_encode(&digest(HashAlgorithm::Sha256, &parts["ppt/presentation.xml"]));
+        assert!(xml.contains(&format!(
+            r#"<Reference URI="/ppt/presentation.xml?ContentType=application/vnd.openxmlformats-officedocument.presentationml.presentation.main+xml"><DigestMethod Algorithm="http://www.w3.org/2001/04/xmlenc#sha256"></DigestMethod><DigestValue>{presentation}</DigestValue></Reference>"#
+        )));
+        assert!(!xml.contains("docProps/core.xml"));
+        assert!(xml.contains(r#"<mdssi:RelationshipReference xmlns:mdssi="http://schemas.openxmlformats.org/package/2006/digital-signature" SourceId="rId1"></mdssi:RelationshipReference>"#));
+        assert!(parts[ORIGIN_PART].is_empty());
+    }
+
+    #[test]
+    fn test_resigning_replaces_signature() {
+        let package = crate::generator::create_pptx("Signed", 1).unwrap();
+        let signer = PackageSigner::new(CERT_PEM, KEY_PKCS8_PEM, SignatureCommitment::Approved).unwrap();
+        let details = DigitalSignature::new(Default::default())
+            .hash_algorithm(HashAlgorithm::Sha512)
+            .sign_date("2025-06-15T10:00:00Z");
+        let once = signer.sign(&package, None).unwrap();
+        let twice = signer.sign(&once, Some(&details)).unwrap();
+        let parts = read_parts(&twice).unwrap();
+        assert_eq!(parts.iter().filter(|(n, _)| n.starts_with("_xmlsignatures/")).count(), 3);
+        let text = |name: &str| String::from_utf8(parts.iter().find(|(n, _)| n == name).unwrap().1.clone()).unwrap();
+        assert_eq!(text("_rels/.rels").matches(ORIGIN_RELATIONSHIP).count(), 1);
+        assert_eq!(text("[Content_Types].xml").matches(r#"Extension="sigs""#).count(), 1);
+        let xml = text(SIGNATURE_PART);
+        assert!(xml.contains("xmldsig-more#rsa-sha512"));
+        assert!(xml.contains("<xd:SigningTime>2025-06-15T10:00:00Z</xd:SigningTime>"));
+        assert!(xml.contains("#ProofOfApproval</xd:Identifier>"));
+    }
+
+    #[test]
+    fn test_mismatched_key_rejected() {
+        let other = include_bytes!("../../../tests/fixtures/signing/other.key");
+        let err = PackageSigner::new(CERT_PEM, other, SignatureCommitment::Created).unwrap_err();
+        assert!(err.to_string().contains("does not match"));
+    }
+
+    #[test]
+    fn test_relationship_attributes_unescaped() {
+        let rels = parse_relationships(
+            r#"<Relationships><Relationship Id="rId1" Type="http://x/hyperlink" Target="https://a.example/?q=1&amp;r=2" TargetMode="External"/><Relationship Target='slides/slide1.xml' Id='rId2' Type='http://x/slide'/></Relationships>"#,
+        );
+        assert_eq!(rels.len(), 2);
+        assert_eq!(rels[0].target, "https://a.example/?q=1&r=2");
+        assert!(rels[0].is_external() && rels[0].is_signed());
+        assert_eq!(rels[1].id, "rId2");
+        assert_eq!(rels[1].target, "slides/slide1.xml");
+        assert!(!rels[1].is_external());
+    }
+
+    #[test]
+    fn test_unsigned_relationship_types() {
+        let rels = parse_relationships(
+            r#"<Relationship Id="rId1" Type="http://schemas.openxmlformats.org/package/2006/relationships/metadata/core-properties" Target="docProps/core.xml"/><Relationship Id="rId2" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/extended-properties" Target="docProps/app.xml"/>"#,
+        );
+        assert!(rels.iter().all(|r| !r.is_signed()));
+    }
+
+    #[test]
+    fn test_content_type_lookup() {
+        let types = ContentTypes::parse(
+            r#"<Types><Default Extension="PNG" ContentType="image/png"/><Default Extension="xml" ContentType="application/xml"/><Override PartName="/ppt/presentation.xml" ContentType="application/vnd.openxmlformats-officedocument.presentationml.presentation.main+xml"/></Types>"#,
+        );
+        assert_eq!(types.of("ppt/media/image1.png"), "image/png");
+        assert_eq!(types.of("ppt/presentation.xml"), "application/vnd.openxmlformats-officedocument.presentationml.presentation.main+xml");
+        assert_eq!(types.of("ppt/other.xml"), "application/xml");
+    }
+
+    #[test]
+    fn test_origin_relationship_takes_free_id() {
+        let xml = with_origin_relationship(r#"<Relationships><Relationship Id="rId1" Type="a" Target="b"/><Relationship Id="rId3" Type="a" Target="c"/></Relationships>"#);
+        assert!(xml.contains(r#"<Relationship Id="rId2" Type="http://schemas.openxmlformats.org/package/2006/relationships/digital-signature/origin" Target="_xmlsignatures/origin.sigs"/></Relationships>"#));
+        assert_eq!(with_origin_relationship(&xml), xml);
+    }
+
+    #[test]
+    fn test_c14n_escaping() {
+        assert_eq!(c14n_text(r#"a & "b" <c>"#), r#"a &amp; "b" &lt;c&gt;"#);
+        assert_eq!(c14n_attr("a&\"b\"\n"), "a&amp;&quot;b&quot;&#xA;");
+    }
+
+    #[test]
+    fn test_timestamp_format() {
+        let now = current_utc_timestamp();
+        assert_eq!(now.len(), 20);
+        assert!(now.starts_with("20") && now.ends_with('Z'));
+        assert_eq!(&now[10..11], "T");
+    }
+}
@@ -0,0 +1,254 @@
+//! Chart part parsing
+//!
+//! Reads chart type, title, categories, and numeric caches from
+//! `ppt/charts/chartN.xml` parts.
+
+use super::xmlchemy::{XmlElement, XmlParser};
+use crate::exc::PptxError;
+
+/// Parsed chart series
+#[derive(Debug, Clone)]
+pub struct ParsedChartSeries {
+    pub name: String,
+    pub values: Vec<f64>,
+}
+
+/// Parsed chart from a slide
+#[derive(Debug, Clone)]
+pub struct ParsedChart {
+    /// Plot element name of the first chart group, e.g. "barChart", "lineChart", "pieChart"
+    pub chart_type: String,
+    /// Bar direction (`bar` or `col`) for bar charts
+    pub bar_direction: Option<String>,
+    /// Grouping (`clustered`, `stacked`, `percentStacked`, ...)
+    pub grouping: Option<String>,
+    /// Whether the plot area contains more than one chart group
+    pub is_combo: bool,
+    pub title: String,
+    pub categories: Vec<String>,
+    pub series: Vec<ParsedChartSeries>,
+    /// Relationship id of the chart part on the slide
+    pub rel_id: String,
+    pub x: i64,
+    pub y: i64,
+    pub width: i64,
+    pub height: i64,
+}
+
+impl ParsedChart {
+    pub fn new(rel_id: &str) -> Self {
+        ParsedChart {
+            chart_type: String::new(),
+            bar_direction: None,
+            grouping: None,
+            is_combo: false,
+            title: String::new(),
+            categories: Vec::new(),
+            series: Vec::new(),
+            rel_id: rel_id.to_string(),
+            x: 0,
+            y: 0,
+            width: 0,
+            height: 0,
+        }
+    }
+}
+
+/// Cache length allowed per `<c:pt>` present; gaps between points are empty cells
+const MAX_POINT_SPREAD: usize = 4;
+/// Cache length always allowed, so short series with trailing empty cells keep their length
+const MIN_POINT_BOUND: usize = 64;
+
+/// Chart part parser
+pub struct ChartParser;
+
+impl ChartParser {
+    /// Parse chart part XML into `chart`, keeping its position and relationship id
+    pub fn parse_into(chart: &mut ParsedChart, xml: &str) -> Result<(), PptxError> {
+        let root = XmlParser::parse_str(xml)?;
+        let Some(chart_el) = root.find("chart") else {
+            return Ok(());
+        };
+
+        if let Some(title) = chart_el.find("title") {
+            chart.title = title
+                .find_all_descendants("t")
+                .iter()
+                .map(|t| t.text_content())
+                .collect();
+        }
+
+        let Some(plot_area) = chart_el.find("plotArea") else {
+            return Ok(());
+        };
+        let groups: Vec<&XmlElement> = plot_area
+            .children
+            .iter()
+            .filter(|c| c.local_name.ends_with("Chart"))
+            .collect();
+        let Some(first) = groups.first() else {
+            return Ok(());
+        };
+
+        chart.chart_type = first.local_name.clone();
+        chart.is_combo = groups.len() > 1;
+        chart.bar_direction = first.find("barDir").and_then(|e| e.attr("val")).map(|s| s.to_string());
+        chart.grouping = first.find("grouping").and_then(|e| e.attr("val")).map(|s| s.to_string());
+
+        for group in &groups {
+            for ser in group.find_all("ser") {
+                chart.series.push(Self::parse_series(ser));
+                if chart.categories.is_empty()
+                    && let Some(cat) = ser.find("cat") {
+                        chart.categories = Self::parse_cache_points(cat);
+                    }
+            }
+        }
+
+        // Fall back to categories cached on the category axis
+        if chart.categories.is_empty()
+            && let Some(cat_ax) = plot_area.find_descendant("catAx") {
+                chart.categories = Self::parse_cache_points(cat_ax);
+            }
+
+        Ok(())
+    }
+
+    fn parse_series(ser: &XmlElement) -> ParsedChartSeries {
+        // Series name from c:tx (strRef cache or literal), or a rich-text c:title
+        let name = ser
+            .find("tx")
+            .or_else(|| ser.find("title"))
+            .map(|tx| {
+                let cached = Self::parse_cache_points(tx);
+                if cached.is_empty() {
+                    tx.find_all_descendants("t")
+                        .iter()
+                        .map(|t| t.text_content())
+                        .chain(tx.find("v").map(|v| v.text_content()))
+                        .collect()
+                } else {
+                    cached.join(" ")
+                }
+            })
+            .unwrap_or_default();
+
+        let values = ser
+            .find("val")
+            .or_else(|| ser.find("yVal"))
+            .map(|val| {
+                Self::parse_cache_points(val)
+                    .into_iter()
+                    .map(|v| v.trim().parse().unwrap_or(0.0))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        ParsedChartSeries { name, values }
+    }
+
+    /// Read `<c:pt idx="n"><c:v>..</c:v></c:pt>` entries from the first str/num cache,
+    /// placing each value at its index.
+    fn parse_cache_points(el: &XmlElement) -> Vec<String> {
+        let Some(cache) = el
+            .find_descendant("strCache")
+            .or_else(|| el.find_descendant("numCache"))
+        else {
+            return Vec::new();
+        };
+
+        let count = cache
+            .find("ptCount")
+            .and_then(|e| e.attr("val"))
+            .and_then(|v| v.parse::<usize>().ok());
+        let points: Vec<(usize, String)> = cache
+            .find_all("pt")
+            .iter()
+            .enumerate()
+            .map(|(i, pt)| {
+                let idx = pt.attr("idx").and_then(|v| v.parse().ok()).unwrap_or(i);
+                (idx, pt.find("v").map(|v| v.text_content()).unwrap_or_default())
+            })
+            .collect();
+
+        // ptCount and idx come from the file, so cap the length against the points
+        // actually present rather than allocating whatever they claim
+        let bound = points.len().saturating_mul(MAX_POINT_SPREAD).max(MIN_POINT_BOUND);
+        let points: Vec<(usize, String)> = points.into_iter().filter(|(idx, _)| *idx < bound).collect();
+        let len = count
+            .unwrap_or(0)
+            .max(points.iter().map(|(idx, _)| idx + 1).max().unwrap_or(0))
+            .min(bound);
+        let mut values = vec![String::new(); len];
+        for (idx, value) in points {
+            values[idx] = value;
+        }
+        values
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_bar_chart_part() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+        <c:chartSpace xmlns:c="http://schemas.openxmlformats.org/drawingml/2006/chart"
+                      xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main">
+            <c:chart>
+                <c:title><c:tx><c:rich><a:p><a:r><a:t>Sales</a:t></a:r></a:p></c:rich></c:tx></c:title>
+                <c:plotArea>
+                    <c:barChart>
+                        <c:barDir val="col"/>
+                        <c:grouping val="clustered"/>
+                        <c:ser>
+                            <c:tx><c:strRef><c:strCache><c:ptCount val="1"/><c:pt idx="0"><c:v>2024</c:v></c:pt></c:strCache></c:strRef></c:tx>
+                            <c:cat><c:strRef><c:strCache><c:ptCount val="2"/>
+                                <c:pt idx="0"><c:v>Q1</c:v></c:pt>
+                                <c:pt idx="1"><c:v>Q2</c:v></c:pt>
+                            </c:strCache></c:strRef></c:cat>
+                            <c:val><c:numRef><c:numCache><c:ptCount val="3"/>
+                                <c:pt idx="0"><c:v>10.5</c:v></c:pt>
+                                <c:pt idx="2"><c:v>30</c:v></c:pt>
+                            </c:numCache></c:numRef></c:val>
+                        </c:ser>
+                    </c:barChart>
+                </c:plotArea>
+            </c:chart>
+        </c:chartSpace>"#;
+
+        let mut chart = ParsedChart::new("rId2");
+        ChartParser::parse_into(&mut chart, xml).unwrap();
+        assert_eq!(chart.chart_type, "barChart");
+        assert_eq!(chart.bar_direction.as_deref(), Some("col"));
+        assert_eq!(chart.title, "Sales");
+        assert_eq!(chart.categories, vec!["Q1", "Q2"]);
+        assert_eq!(chart.series.len(), 1);
+        assert_eq!(chart.series[0].name, "2024");
+        assert_eq!(chart.series[0].values, vec![10.5, 0.0, 30.0]);
+        assert!(!chart.is_combo);
+    }
+
+    #[test]
+    fn test_cache_length_is_bounded() {
+        let xml = r#"<c:chartSpace xmlns:c="http://schemas.openxmlformats.org/drawingml/2006/chart">
+            <c:chart><c:plotArea><c:lineChart><c:ser>
+                <c:cat><c:strRef><c:strCache><c:ptCount val="18446744073709551615"/>
+                    <c:pt idx="0"><c:v>A</c:v></c:pt>
+                </c:strCache></c:strRef></c:cat>
+                <c:val><c:numRef><c:numCache><c:ptCount val="2"/>
+                    <c:pt idx="0"><c:v>1</c:v></c:pt>
+                    <c:pt idx="18446744073709551615"><c:v>2</c:v></c:pt>
+                </c:numCache></c:numRef></c:val>
+            </c:ser></c:lineChart></c:plotArea></c:chart>
+        </c:chartSpace>"#;
+
+        let mut chart = ParsedChart::new("rId2");
+        ChartParser::parse_into(&mut chart, xml).unwrap();
+        assert_eq!(chart.categories.len(), MIN_POINT_BOUND);
+        assert_eq!(chart.categories[0], "A");
+        // The out-of-range point is dropped; ptCount still holds
+        assert_eq!(chart.series[0].values, vec![1.0, 0.0]);
+    }
+}
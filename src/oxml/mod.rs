@@ -1,15 +1,19 @@
 //! OXML (Office XML) element handling
 
+pub mod chart;
 pub mod editor;
 pub mod ns;
 pub mod presentation;
 pub mod repair;
 pub mod slide;
+pub mod theme;
 pub mod xmlchemy;
 
+pub use chart::{ChartParser, ParsedChart, ParsedChartSeries};
 pub use editor::PresentationEditor;
 pub use ns::Namespace;
 pub use presentation::{PresentationInfo, PresentationReader};
 pub use repair::{PptxRepair, RepairIssue, RepairResult};
-pub use slide::{ParsedSlide, SlideParser};
+pub use slide::{OutlineBullet, OutlineChart, ParsedPicture, ParsedSlide, ParsedUnsupported, SlideOutline, SlideParser};
+pub use theme::{ParsedTheme, ThemeParser};
 pub use xmlchemy::{XmlElement, XmlParser};
@@ -2,8 +2,11 @@
 //!
 //! Parses presentation.xml and provides high-level access to presentation content.
 
-use super::slide::{ParsedSlide, SlideParser};
+use super::chart::ChartParser;
+use super::slide::{ParsedSlide, ParsedUnsupported, SlideOutline, SlideParser};
+use super::theme::{ParsedTheme, ThemeParser};
 use super::xmlchemy::XmlParser;
+use crate::core::{rels_source_dir, resolve_rel_target};
 use crate::exc::{messages, PptxError};
 use crate::opc::Package;
 
@@ -49,7 +52,15 @@ pub struct PresentationReader {
 impl PresentationReader {
     /// Open a PPTX file for reading
     pub fn open(path: &str) -> Result<Self, PptxError> {
-        let package = Package::open(path)?;
+        Self::from_package(Package::open(path)?)
+    }
+
+    /// Read a PPTX already held in memory (e.g. an upload)
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, PptxError> {
+        Self::from_package(Package::open_reader(std::io::Cursor::new(bytes))?)
+    }
+
+    fn from_package(package: Package) -> Result<Self, PptxError> {
         let mut reader = PresentationReader {
             package,
             info: PresentationInfo::new(),
@@ -82,7 +93,119 @@ impl PresentationReader {
             .ok_or_else(|| PptxError::NotFound(messages::slide_file_not_found(path)))?;
 
         let xml_str = String::from_utf8_lossy(xml);
-        SlideParser::parse(&xml_str)
+        let mut slide = SlideParser::parse(&xml_str)?;
+        let rels = self.part_relationships(path);
+        self.resolve_charts(&rels, &mut slide);
+        self.resolve_pictures(&rels, &mut slide);
+        slide.notes = self.read_notes(&rels);
+        Ok(slide)
+    }
+
+    /// Theme of the first slide master, falling back to `ppt/theme/theme1.xml`
+    pub fn theme(&self) -> Option<ParsedTheme> {
+        let master = self
+            .part_relationships("ppt/presentation.xml")
+            .into_iter()
+            .find(|(_, rel_type, _)| rel_type.ends_with("/slideMaster"))
+            .map(|(_, _, target)| target);
+        let path = master
+            .and_then(|master| {
+                self.part_relationships(&master)
+                    .into_iter()
+                    .find(|(_, rel_type, _)| rel_type.ends_with("/theme"))
+            })
+            .map(|(_, _, target)| target)
+            .unwrap_or_else(|| "ppt/theme/theme1.xml".to_string());
+        ThemeParser::parse(&self.package.get_part_string(&path)?).ok()
+    }
+
+    /// Relationships `(id, type, resolved target)` of a package part
+    fn part_relationships(&self, part_path: &str) -> Vec<(String, String, String)> {
+        let rels_path = match part_path.rsplit_once('/') {
+            Some((dir, file)) => format!("{dir}/_rels/{file}.rels"),
+            None => format!("_rels/{part_path}.rels"),
+        };
+        let base_dir = rels_source_dir(&rels_path);
+        self.package
+            .get_part_string(&rels_path)
+            .and_then(|xml| XmlParser::parse_str(&xml).ok())
+            .map(|root| {
+                root.find_all("Relationship")
+                    .iter()
+                    .filter_map(|rel| {
+                        Some((
+                            rel.attr("Id")?.to_string(),
+                            rel.attr("Type").unwrap_or("").to_string(),
+                            resolve_rel_target(&base_dir, rel.attr("Target")?),
+                        ))
+                    })
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Read speaker notes text from the slide's `notesSlide` part, if any
+    fn read_notes(&self, rels: &[(String, String, String)]) -> Option<String> {
+        let (_, _, target) = rels.iter().find(|(_, rel_type, _)| rel_type.ends_with("/notesSlide"))?;
+        let xml = self.package.get_part_string(target)?;
+        let notes = SlideParser::parse(&xml).ok()?.body_text.join("\n");
+        if notes.is_empty() { None } else { Some(notes) }
+    }
+
+    /// Load chart parts referenced by the slide; charts that cannot be resolved
+    /// are reported as unsupported elements.
+    fn resolve_charts(&self, rels: &[(String, String, String)], slide: &mut ParsedSlide) {
+        if slide.charts.is_empty() {
+            return;
+        }
+
+        let mut resolved = Vec::with_capacity(slide.charts.len());
+        for mut chart in std::mem::take(&mut slide.charts) {
+            let part = rels
+                .iter()
+                .find(|(id, _, _)| *id == chart.rel_id)
+                .and_then(|(_, _, target)| self.package.get_part_string(target));
+            match part.map(|xml| ChartParser::parse_into(&mut chart, &xml)) {
+                Some(Ok(())) => resolved.push(chart),
+                _ => slide.unsupported.push(ParsedUnsupported {
+                    kind: "Chart".to_string(),
+                    name: chart.title.clone(),
+                    x: chart.x,
+                    y: chart.y,
+                    width: chart.width,
+                    height: chart.height,
+                }),
+            }
+        }
+        slide.charts = resolved;
+    }
+
+    /// Load media parts embedded by the slide's pictures; pictures whose media
+    /// cannot be found are reported as unsupported elements.
+    fn resolve_pictures(&self, rels: &[(String, String, String)], slide: &mut ParsedSlide) {
+        let mut resolved = Vec::with_capacity(slide.pictures.len());
+        for mut picture in std::mem::take(&mut slide.pictures) {
+            let part = rels
+                .iter()
+                .find(|(id, _, _)| *id == picture.rel_id)
+                .and_then(|(_, _, target)| Some((target, self.package.get_part(target)?)));
+            match part {
+                Some((target, data)) => {
+                    picture.target = Some(target.clone());
+                    picture.data = data.to_vec();
+                    resolved.push(picture);
+                }
+                None => slide.unsupported.push(ParsedUnsupported {
+                    kind: "Picture".to_string(),
+                    name: picture.name.clone(),
+                    x: picture.x,
+                    y: picture.y,
+                    width: picture.width,
+                    height: picture.height,
+                }),
+            }
+        }
+        slide.pictures = resolved;
     }
 
     /// Get all slides
@@ -103,6 +226,17 @@ impl PresentationReader {
         Ok(all_text)
     }
 
+    /// Text of every slide with its structure kept: title, bullets with indent
+    /// levels, other text, table cells, chart titles/categories and notes
+    pub fn outline(&self) -> Result<Vec<SlideOutline>, PptxError> {
+        Ok(self
+            .get_all_slides()?
+            .iter()
+            .enumerate()
+            .map(|(i, slide)| slide.outline(i + 1))
+            .collect())
+    }
+
     /// Parse presentation structure
     fn parse_structure(&mut self) -> Result<(), PptxError> {
         // Parse core properties
@@ -236,6 +370,74 @@ mod tests {
         fs::remove_file("test_read.pptx").ok();
     }
 
+    #[test]
+    fn test_read_from_bytes() {
+        let slides = vec![SlideContent::new("In Memory").add_bullet("No temp file")];
+        let pptx_data = create_pptx_with_content("Bytes", slides).unwrap();
+
+        let reader = PresentationReader::from_bytes(&pptx_data).unwrap();
+        assert_eq!(reader.slide_count(), 1);
+        assert_eq!(reader.get_slide(0).unwrap().title.as_deref(), Some("In Memory"));
+
+        assert!(PresentationReader::from_bytes(b"not a zip").is_err());
+    }
+
+    #[test]
+    fn test_outline() {
+        use crate::generator::{ChartBuilder, ChartSeries, ChartType, TableBuilder, TableCell, TableRow};
+
+        let chart = ChartBuilder::new("Revenue", ChartType::Bar)
+            .categories(vec!["Q1", "Q2"])
+            .add_series(ChartSeries::new("2025", vec![1.0, 2.0]))
+            .build();
+        let table = TableBuilder::new(vec![1828800, 1828800])
+            .add_row(TableRow::new(vec![TableCell::new("Name"), TableCell::new("Value")]))
+            .add_row(TableRow::new(vec![TableCell::new("A"), TableCell::new("1")]))
+            .build();
+        let slides = vec![
+            SlideContent::new("Agenda")
+                .add_bullet("Goals")
+                .add_sub_bullet("Revenue")
+                .notes("Keep it short"),
+            SlideContent::new("Numbers").table(table).add_chart(chart),
+        ];
+        let pptx_data = create_pptx_with_content("Outline", slides).unwrap();
+
+        let outline = PresentationReader::from_bytes(&pptx_data).unwrap().outline().unwrap();
+        assert_eq!(outline.len(), 2);
+        assert_eq!(outline[0].number, 1);
+        assert_eq!(outline[0].title.as_deref(), Some("Agenda"));
+        let bullets: Vec<_> = outline[0].bullets.iter().map(|b| (b.text.as_str(), b.level)).collect();
+        assert_eq!(bullets, [("Goals", 0), ("Revenue", 1)]);
+        assert_eq!(outline[0].notes.as_deref(), Some("Keep it short"));
+
+        assert_eq!(outline[1].tables, vec![vec![vec!["Name", "Value"], vec!["A", "1"]]]);
+        assert_eq!(outline[1].charts[0].title, "Revenue");
+        assert_eq!(outline[1].charts[0].categories, ["Q1", "Q2"]);
+    }
+
+    #[test]
+    fn test_read_theme() {
+        use crate::generator::{PresentationTheme, ThemeFonts};
+
+        let office = PresentationReader::from_bytes(&create_pptx_with_content("Office", vec![SlideContent::new("A")]).unwrap())
+            .unwrap()
+            .theme()
+            .unwrap();
+        // The Office template stores dk1/lt1 as system colors
+        assert_eq!(office.colors.dk1, "000000");
+        assert_eq!(office.colors.lt1, "FFFFFF");
+
+        let brand = PresentationTheme::corporate().fonts(ThemeFonts::new("Georgia", "Verdana"));
+        let bytes = crate::api::Presentation::with_title("Brand")
+            .with_theme(brand.clone())
+            .add_slide(SlideContent::new("A"))
+            .build()
+            .unwrap();
+        let theme = PresentationReader::from_bytes(&bytes).unwrap().theme().unwrap();
+        assert_eq!(theme.to_presentation_theme(), brand);
+    }
+
     #[test]
     fn test_extract_all_text() {
         let slides = vec![
@@ -256,4 +458,22 @@ mod tests {
 
         fs::remove_file("test_extract.pptx").ok();
     }
+
+    #[test]
+    fn test_read_speaker_notes() {
+        let slides = vec![
+            SlideContent::new("With Notes").add_bullet("Point").notes("Remember the demo"),
+            SlideContent::new("Without Notes"),
+        ];
+
+        let pptx_data = create_pptx_with_content("Notes Test", slides).unwrap();
+        fs::write("test_read_notes.pptx", &pptx_data).unwrap();
+
+        let reader = PresentationReader::open("test_read_notes.pptx").unwrap();
+        let slides = reader.get_all_slides().unwrap();
+        assert_eq!(slides[0].notes.as_deref(), Some("Remember the demo"));
+        assert!(slides[1].notes.is_none());
+
+        fs::remove_file("test_read_notes.pptx").ok();
+    }
 }
@@ -41,8 +41,20 @@ pub enum RepairIssue {
     MissingNamespace { path: String, namespace: String },
     /// Empty required element
     EmptyRequiredElement { path: String, element: String },
+    /// Slide without a `slideLayout` relationship
+    MissingLayoutRel { slide: String },
+    /// Relationship id used in a slide's XML but absent from its `.rels` part
+    DanglingRelId {
+        path: String,
+        element: String,
+        attribute: String,
+        rel_id: String,
+    },
 }
 
+/// Elements whose relationship attribute is optional, so a dangling id can be dropped
+const DROPPABLE_REL_ELEMENTS: &[&str] = &["a:blip", "a:hlinkClick", "a:hlinkHover"];
+
 impl RepairIssue {
     /// Get severity level (1-3, where 3 is critical)
     pub fn severity(&self) -> u8 {
@@ -56,6 +68,8 @@ impl RepairIssue {
             RepairIssue::CorruptedEntry { .. } => 3,
             RepairIssue::MissingNamespace { .. } => 2,
             RepairIssue::EmptyRequiredElement { .. } => 1,
+            RepairIssue::MissingLayoutRel { .. } => 3,
+            RepairIssue::DanglingRelId { .. } => 2,
         }
     }
 
@@ -71,6 +85,8 @@ impl RepairIssue {
             RepairIssue::CorruptedEntry { .. } => false,
             RepairIssue::MissingNamespace { .. } => true,
             RepairIssue::EmptyRequiredElement { .. } => true,
+            RepairIssue::MissingLayoutRel { .. } => true,
+            RepairIssue::DanglingRelId { element, .. } => DROPPABLE_REL_ELEMENTS.contains(&element.as_str()),
         }
     }
 
@@ -114,6 +130,20 @@ impl RepairIssue {
             RepairIssue::EmptyRequiredElement { path, element } => {
                 format!("Empty required element '{}' in '{}'", element, path)
             }
+            RepairIssue::MissingLayoutRel { slide } => {
+                format!("Slide '{}' has no slide layout relationship", slide)
+            }
+            RepairIssue::DanglingRelId {
+                path,
+                element,
+                attribute,
+                rel_id,
+            } => {
+                format!(
+                    "Dangling {} reference '{}' on <{}> in '{}'",
+                    attribute, rel_id, element, path
+                )
+            }
         }
     }
 }
@@ -202,6 +232,9 @@ impl PptxRepair {
         // Check slide references
         self.check_slide_references();
 
+        // Check each slide's layout relationship and r:id references
+        self.check_slide_part_rels();
+
         // Check content types
         self.check_content_types();
 
@@ -239,6 +272,109 @@ impl PptxRepair {
         result
     }
 
+    /// Regenerate `[Content_Types].xml` from the parts in the package.
+    ///
+    /// Existing `Default` and `Override` entries are kept; a part without one gets
+    /// an `Override` for its known OOXML type or a `Default` for its extension.
+    /// Each addition is listed in `issues_repaired`; parts whose type can't be
+    /// inferred end up in `issues_unrepaired`.
+    pub fn rebuild_content_types(&mut self) -> RepairResult {
+        let mut result = RepairResult::new();
+        let existing = self
+            .package
+            .get_part_string("[Content_Types].xml")
+            .unwrap_or_default();
+        let mut defaults = self.content_type_entries(&existing, "Default", "Extension");
+        let mut overrides = self.content_type_entries(&existing, "Override", "PartName");
+
+        for (ext, content_type) in [
+            ("rels", "application/vnd.openxmlformats-package.relationships+xml"),
+            ("xml", "application/xml"),
+        ] {
+            if !defaults.iter().any(|(e, _)| e.eq_ignore_ascii_case(ext)) {
+                defaults.push((ext.to_string(), content_type.to_string()));
+            }
+        }
+
+        let mut paths: Vec<String> = self
+            .package
+            .part_paths()
+            .into_iter()
+            .filter(|p| *p != "[Content_Types].xml")
+            .map(str::to_string)
+            .collect();
+        paths.sort();
+
+        for path in paths {
+            let part_name = format!("/{}", path);
+            if overrides.iter().any(|(name, _)| name.eq_ignore_ascii_case(&part_name)) {
+                continue;
+            }
+            let issue = RepairIssue::InvalidContentType { path: path.clone() };
+            if let Some(content_type) = override_content_type(&path) {
+                overrides.push((part_name, content_type.to_string()));
+                result.issues_repaired.push(issue);
+                continue;
+            }
+
+            let ext = path.rsplit_once('.').map(|(_, e)| e.to_lowercase()).unwrap_or_default();
+            if defaults.iter().any(|(e, _)| e.eq_ignore_ascii_case(&ext)) {
+                continue;
+            }
+            match default_content_type(&ext) {
+                Some(content_type) => {
+                    defaults.push((ext, content_type.to_string()));
+                    result.issues_repaired.push(issue);
+                }
+                None => result.issues_unrepaired.push(issue),
+            }
+        }
+
+        let mut xml = String::from(
+            r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Types xmlns="http://schemas.openxmlformats.org/package/2006/content-types">
+"#,
+        );
+        for (ext, content_type) in &defaults {
+            xml.push_str(&format!(
+                "  <Default Extension=\"{}\" ContentType=\"{}\"/>\n",
+                ext, content_type
+            ));
+        }
+        for (part_name, content_type) in &overrides {
+            xml.push_str(&format!(
+                "  <Override PartName=\"{}\" ContentType=\"{}\"/>\n",
+                part_name, content_type
+            ));
+        }
+        xml.push_str("</Types>");
+        self.package
+            .add_part("[Content_Types].xml".to_string(), xml.into_bytes());
+
+        result.issues_found = result
+            .issues_repaired
+            .iter()
+            .chain(&result.issues_unrepaired)
+            .cloned()
+            .collect();
+        result.is_valid = result.issues_unrepaired.is_empty();
+        result
+    }
+
+    /// (key, ContentType) pairs of every `<tag ...>` entry in `[Content_Types].xml`
+    fn content_type_entries(&self, xml: &str, tag: &str, key: &str) -> Vec<(String, String)> {
+        let open = format!("<{} ", tag);
+        xml.match_indices(&open)
+            .filter_map(|(pos, _)| {
+                let entry = &xml[pos..pos + xml[pos..].find('>')?];
+                Some((
+                    self.extract_attribute(entry, key)?,
+                    self.extract_attribute(entry, "ContentType")?,
+                ))
+            })
+            .collect()
+    }
+
     /// Save the repaired PPTX to a file
     pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<()> {
         self.package.save(path)
@@ -466,6 +602,46 @@ impl PptxRepair {
         }
     }
 
+    fn slide_paths(&self) -> Vec<String> {
+        let mut slides: Vec<String> = self
+            .package
+            .part_paths()
+            .iter()
+            .filter(|p| p.starts_with("ppt/slides/slide") && p.ends_with(".xml"))
+            .map(|s| s.to_string())
+            .collect();
+        slides.sort();
+        slides
+    }
+
+    fn check_slide_part_rels(&mut self) {
+        for slide in self.slide_paths() {
+            let rels = self
+                .package
+                .get_part_string(&slide_rels_path(&slide))
+                .unwrap_or_default();
+            if !rels.contains("relationships/slideLayout\"") {
+                self.issues.push(RepairIssue::MissingLayoutRel {
+                    slide: slide.clone(),
+                });
+            }
+
+            let known = rels_ids(&rels);
+            let xml = self.package.get_part_string(&slide).unwrap_or_default();
+            let mut reported = HashSet::new();
+            for (element, attribute, rel_id) in rel_id_references(&xml) {
+                if !known.contains(&rel_id) && reported.insert((element.clone(), rel_id.clone())) {
+                    self.issues.push(RepairIssue::DanglingRelId {
+                        path: slide.clone(),
+                        element,
+                        attribute,
+                        rel_id,
+                    });
+                }
+            }
+        }
+    }
+
     // Repair methods
 
     fn repair_issue(&mut self, issue: &RepairIssue) -> Result<()> {
@@ -488,12 +664,82 @@ impl PptxRepair {
             RepairIssue::EmptyRequiredElement { path, element } => {
                 self.repair_empty_element(path, element)
             }
+            RepairIssue::MissingLayoutRel { slide } => self.repair_missing_layout_rel(slide),
+            RepairIssue::DanglingRelId {
+                path,
+                element,
+                attribute,
+                rel_id,
+            } => self.repair_dangling_rel_id(path, element, attribute, rel_id),
             RepairIssue::CorruptedEntry { .. } => Err(PptxError::Generic(
                 "Cannot repair corrupted entry".to_string(),
             )),
         }
     }
 
+    fn repair_missing_layout_rel(&mut self, slide: &str) -> Result<()> {
+        let mut layouts: Vec<&str> = self
+            .package
+            .part_paths()
+            .into_iter()
+            .filter(|p| p.starts_with("ppt/slideLayouts/slideLayout") && p.ends_with(".xml"))
+            .collect();
+        // slideLayout1 before slideLayout10
+        layouts.sort_by_key(|p| (p.len(), p.to_string()));
+        let layout = layouts
+            .first()
+            .map(|p| p.trim_start_matches("ppt/slideLayouts/").to_string())
+            .ok_or_else(|| PptxError::NotFound("No slide layout to link".to_string()))?;
+
+        let rels_path = slide_rels_path(slide);
+        let rels = self.package.get_part_string(&rels_path).unwrap_or_else(|| {
+            r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships">
+</Relationships>"#
+                .to_string()
+        });
+
+        // The new id must not collide with ids the slide already uses, even dangling ones
+        let slide_xml = self.package.get_part_string(slide).unwrap_or_default();
+        let next_id = self.find_max_rel_id(&rels).max(max_referenced_rel_id(&slide_xml)) + 1;
+        let new_rel = format!(
+            "  <Relationship Id=\"rId{}\" Type=\"http://schemas.openxmlformats.org/officeDocument/2006/relationships/slideLayout\" Target=\"../slideLayouts/{}\"/>",
+            next_id, layout
+        );
+        let repaired = rels.replace("</Relationships>", &format!("{}\n</Relationships>", new_rel));
+        self.package.add_part(rels_path, repaired.into_bytes());
+        Ok(())
+    }
+
+    fn repair_dangling_rel_id(
+        &mut self,
+        path: &str,
+        element: &str,
+        attribute: &str,
+        rel_id: &str,
+    ) -> Result<()> {
+        if !DROPPABLE_REL_ELEMENTS.contains(&element) {
+            return Err(PptxError::Generic(format!(
+                "Cannot drop required {} on <{}>",
+                attribute, element
+            )));
+        }
+        if let Some(xml) = self.package.get_part_string(path) {
+            let needle = format!(" {}=\"{}\"", attribute, rel_id);
+            let mut repaired = String::with_capacity(xml.len());
+            let mut copied = 0;
+            for (pos, _) in xml.match_indices(&needle) {
+                if element_name_before(&xml[..pos]).as_deref() == Some(element) {
+                    repaired.push_str(&xml[copied..pos]);
+                    copied = pos + needle.len();
+                }
+            }
+            repaired.push_str(&xml[copied..]);
+            self.package.add_part(path.to_string(), repaired.into_bytes());
+        }
+        Ok(())
+    }
+
     fn repair_missing_part(&mut self, path: &str) -> Result<()> {
         let content = match path {
             "[Content_Types].xml" => self.generate_content_types(),
@@ -882,6 +1128,131 @@ impl PptxRepair {
     }
 }
 
+/// Content type a part needs an `Override` for, from its path
+fn override_content_type(path: &str) -> Option<&'static str> {
+    let (dir, file) = path.rsplit_once('/').unwrap_or(("", path));
+    if !file.ends_with(".xml") {
+        return None;
+    }
+    Some(match (dir, file) {
+        ("ppt", "presentation.xml") => "application/vnd.openxmlformats-officedocument.presentationml.presentation.main+xml",
+        ("ppt", "presProps.xml") => "application/vnd.openxmlformats-officedocument.presentationml.presProps+xml",
+        ("ppt", "viewProps.xml") => "application/vnd.openxmlformats-officedocument.presentationml.viewProps+xml",
+        ("ppt", "tableStyles.xml") => "application/vnd.openxmlformats-officedocument.presentationml.tableStyles+xml",
+        ("ppt", "commentAuthors.xml") => "application/vnd.openxmlformats-officedocument.presentationml.commentAuthors+xml",
+        ("ppt/slides", _) => "application/vnd.openxmlformats-officedocument.presentationml.slide+xml",
+        ("ppt/slideLayouts", _) => "application/vnd.openxmlformats-officedocument.presentationml.slideLayout+xml",
+        ("ppt/slideMasters", _) => "application/vnd.openxmlformats-officedocument.presentationml.slideMaster+xml",
+        ("ppt/notesSlides", _) => "application/vnd.openxmlformats-officedocument.presentationml.notesSlide+xml",
+        ("ppt/notesMasters", _) => "application/vnd.openxmlformats-officedocument.presentationml.notesMaster+xml",
+        ("ppt/handoutMasters", _) => "application/vnd.openxmlformats-officedocument.presentationml.handoutMaster+xml",
+        ("ppt/comments", _) => "application/vnd.openxmlformats-officedocument.presentationml.comments+xml",
+        ("ppt/ink", _) => "application/vnd.openxmlformats-officedocument.presentationml.ink+xml",
+        ("ppt/tags", _) => "application/vnd.openxmlformats-officedocument.presentationml.tags+xml",
+        ("ppt/theme", _) => "application/vnd.openxmlformats-officedocument.theme+xml",
+        ("ppt/charts", f) if f.starts_with("chartEx") => crate::generator::charts::CHARTEX_CONTENT_TYPE,
+        ("ppt/charts", f) if f.starts_with("chart") => "application/vnd.openxmlformats-officedocument.drawingml.chart+xml",
+        ("docProps", "core.xml") => "application/vnd.openxmlformats-package.core-properties+xml",
+        ("docProps", "app.xml") => "application/vnd.openxmlformats-officedocument.extended-properties+xml",
+        ("docProps", "custom.xml") => "application/vnd.openxmlformats-officedocument.custom-properties+xml",
+        _ => return None,
+    })
+}
+
+/// Content type for a `Default` entry, from a lowercase file extension
+fn default_content_type(ext: &str) -> Option<&'static str> {
+    use crate::generator::media::{AudioFormat, VideoFormat};
+
+    Some(match ext {
+        "png" => "image/png",
+        "jpg" | "jpeg" => "image/jpeg",
+        "gif" => "image/gif",
+        "bmp" => "image/bmp",
+        "tif" | "tiff" => "image/tiff",
+        "svg" => "image/svg+xml",
+        "emf" => "image/x-emf",
+        "wmf" => "image/x-wmf",
+        "xlsx" => "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet",
+        "bin" => "application/vnd.openxmlformats-officedocument.oleObject",
+        "fntdata" => "application/x-fontdata",
+        _ => {
+            return VideoFormat::from_extension(ext)
+                .map(|f| f.mime_type())
+                .or_else(|| AudioFormat::from_extension(ext).map(|f| f.mime_type()));
+        }
+    })
+}
+
+/// `ppt/slides/slide1.xml` -> `ppt/slides/_rels/slide1.xml.rels`
+fn slide_rels_path(slide: &str) -> String {
+    match slide.rsplit_once('/') {
+        Some((dir, file)) => format!("{}/_rels/{}.rels", dir, file),
+        None => format!("_rels/{}.rels", slide),
+    }
+}
+
+/// Relationship ids declared in a `.rels` part
+fn rels_ids(rels: &str) -> HashSet<String> {
+    let mut ids = HashSet::new();
+    let mut rest = rels;
+    while let Some(pos) = rest.find(" Id=\"") {
+        rest = &rest[pos + 5..];
+        if let Some(end) = rest.find('"') {
+            ids.insert(rest[..end].to_string());
+            rest = &rest[end..];
+        }
+    }
+    ids
+}
+
+/// Every `r:*="..."` attribute in a part as (element, attribute, relationship id)
+fn rel_id_references(xml: &str) -> Vec<(String, String, String)> {
+    let mut refs = Vec::new();
+    let mut offset = 0;
+    while let Some(pos) = xml[offset..].find(" r:") {
+        let start = offset + pos + 1;
+        let Some(eq) = xml[start..].find("=\"") else {
+            break;
+        };
+        let attribute = &xml[start..start + eq];
+        let value_start = start + eq + 2;
+        let Some(end) = xml[value_start..].find('"') else {
+            break;
+        };
+        offset = value_start + end;
+        if attribute.len() <= 2 || !attribute[2..].chars().all(|c| c.is_ascii_alphanumeric()) {
+            continue;
+        }
+        if let Some(element) = element_name_before(&xml[..start]) {
+            let rel_id = &xml[value_start..value_start + end];
+            refs.push((element, attribute.to_string(), rel_id.to_string()));
+        }
+    }
+    refs
+}
+
+/// Name of the element whose start tag is open at the end of `xml`
+fn element_name_before(xml: &str) -> Option<String> {
+    let open = xml.rfind('<')?;
+    if xml[open..].contains('>') {
+        return None;
+    }
+    let name: String = xml[open + 1..]
+        .chars()
+        .take_while(|c| !c.is_whitespace() && *c != '/' && *c != '>')
+        .collect();
+    (!name.is_empty()).then_some(name)
+}
+
+/// Highest `rIdN` referenced from a part's XML
+fn max_referenced_rel_id(xml: &str) -> u32 {
+    rel_id_references(xml)
+        .iter()
+        .filter_map(|(_, _, id)| id.strip_prefix("rId")?.parse().ok())
+        .max()
+        .unwrap_or(0)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1105,4 +1476,163 @@ mod tests {
         let repaired = repair.attempt_xml_repair(xml);
         assert!(repaired.contains("A &amp; B"));
     }
+
+    fn generated_deck() -> PptxRepair {
+        let bytes = crate::generator::create_pptx("Repair", 2).unwrap();
+        PptxRepair::from_bytes(&bytes).unwrap()
+    }
+
+    #[test]
+    fn test_generated_deck_has_layout_rels_and_no_dangling_ids() {
+        let mut repair = generated_deck();
+        let issues = repair.validate();
+        assert!(
+            !issues.iter().any(|i| matches!(
+                i,
+                RepairIssue::MissingLayoutRel { .. } | RepairIssue::DanglingRelId { .. }
+            )),
+            "{:?}",
+            issues
+        );
+    }
+
+    #[test]
+    fn test_missing_layout_rel_is_restored() {
+        let mut repair = generated_deck();
+        repair.package_mut().remove_part("ppt/slides/_rels/slide2.xml.rels");
+        let rels = repair
+            .package()
+            .get_part_string("ppt/slides/_rels/slide1.xml.rels")
+            .unwrap();
+        let stripped: String = rels
+            .lines()
+            .filter(|line| !line.contains("relationships/slideLayout\""))
+            .collect::<Vec<_>>()
+            .join("\n");
+        repair
+            .package_mut()
+            .add_part("ppt/slides/_rels/slide1.xml.rels".to_string(), stripped.into_bytes());
+
+        let issues = repair.validate();
+        for slide in ["ppt/slides/slide1.xml", "ppt/slides/slide2.xml"] {
+            assert!(issues.contains(&RepairIssue::MissingLayoutRel {
+                slide: slide.to_string()
+            }));
+        }
+
+        let result = repair.repair();
+        assert!(result.is_valid, "{:?}", result.issues_unrepaired);
+        let rels = repair
+            .package()
+            .get_part_string("ppt/slides/_rels/slide2.xml.rels")
+            .unwrap();
+        assert!(rels.contains(r#"Target="../slideLayouts/slideLayout1.xml""#));
+    }
+
+    #[test]
+    fn test_dangling_picture_reference_is_dropped() {
+        let mut repair = generated_deck();
+        let slide = repair
+            .package()
+            .get_part_string("ppt/slides/slide1.xml")
+            .unwrap();
+        let slide = slide.replace(
+            "</p:spTree>",
+            r#"<p:pic><p:nvPicPr><p:cNvPr id="90" name="Gone"/><p:cNvPicPr/><p:nvPr/></p:nvPicPr><p:blipFill><a:blip r:embed="rId42"/></p:blipFill><p:spPr/></p:pic></p:spTree>"#,
+        );
+        repair
+            .package_mut()
+            .add_part("ppt/slides/slide1.xml".to_string(), slide.into_bytes());
+
+        let issues = repair.validate();
+        let dangling = RepairIssue::DanglingRelId {
+            path: "ppt/slides/slide1.xml".to_string(),
+            element: "a:blip".to_string(),
+            attribute: "r:embed".to_string(),
+            rel_id: "rId42".to_string(),
+        };
+        assert!(issues.contains(&dangling));
+        assert!(dangling.is_repairable());
+
+        let result = repair.repair();
+        assert!(result.issues_repaired.contains(&dangling));
+        let slide = repair
+            .package()
+            .get_part_string("ppt/slides/slide1.xml")
+            .unwrap();
+        assert!(slide.contains("<a:blip/>"));
+    }
+
+    #[test]
+    fn test_dangling_required_reference_is_reported() {
+        let issue = RepairIssue::DanglingRelId {
+            path: "ppt/slides/slide1.xml".to_string(),
+            element: "c:chart".to_string(),
+            attribute: "r:id".to_string(),
+            rel_id: "rId9".to_string(),
+        };
+        assert!(!issue.is_repairable());
+        assert!(issue.description().contains("rId9"));
+    }
+
+    #[test]
+    fn test_rel_id_references() {
+        let xml = r#"<p:sld xmlns:r="x"><a:blip r:embed="rId2"/><a:t>not r:id="rId9"</a:t><a:hlinkClick r:id="rId3"/></p:sld>"#;
+        assert_eq!(
+            rel_id_references(xml),
+            vec![
+                ("a:blip".to_string(), "r:embed".to_string(), "rId2".to_string()),
+                ("a:hlinkClick".to_string(), "r:id".to_string(), "rId3".to_string()),
+            ]
+        );
+        assert_eq!(max_referenced_rel_id(xml), 3);
+        assert_eq!(slide_rels_path("ppt/slides/slide4.xml"), "ppt/slides/_rels/slide4.xml.rels");
+    }
+
+    #[test]
+    fn test_rebuild_content_types_adds_missing_entries() {
+        let mut repair = generated_deck();
+        let content_types = repair
+            .package()
+            .get_part_string("[Content_Types].xml")
+            .unwrap()
+            .replace(
+                r#"<Override PartName="/ppt/slides/slide2.xml" ContentType="application/vnd.openxmlformats-officedocument.presentationml.slide+xml"/>"#,
+                "",
+            )
+            .replace(
+                "</Types>",
+                r#"<Override PartName="/ppt/custom/data.xml" ContentType="application/vnd.example+xml"/></Types>"#,
+            );
+        assert!(!content_types.contains("/ppt/slides/slide2.xml"));
+        repair
+            .package_mut()
+            .add_part("[Content_Types].xml".to_string(), content_types.into_bytes());
+        repair
+            .package_mut()
+            .add_part("ppt/media/clip.mp4".to_string(), vec![0; 4]);
+        repair
+            .package_mut()
+            .add_part("ppt/media/blob.qqq".to_string(), vec![0; 4]);
+
+        let result = repair.rebuild_content_types();
+        let added = |path: &str| RepairIssue::InvalidContentType { path: path.to_string() };
+        assert!(result.issues_repaired.contains(&added("ppt/slides/slide2.xml")));
+        assert!(result.issues_repaired.contains(&added("ppt/media/clip.mp4")));
+        assert_eq!(result.issues_unrepaired, vec![added("ppt/media/blob.qqq")]);
+        assert!(!result.is_valid);
+
+        let xml = repair
+            .package()
+            .get_part_string("[Content_Types].xml")
+            .unwrap();
+        assert!(xml.contains(r#"<Override PartName="/ppt/slides/slide2.xml" ContentType="application/vnd.openxmlformats-officedocument.presentationml.slide+xml"/>"#));
+        assert!(xml.contains(r#"<Default Extension="mp4" ContentType="video/mp4"/>"#));
+        assert!(xml.contains(r#"<Override PartName="/ppt/custom/data.xml" ContentType="application/vnd.example+xml"/>"#));
+        assert!(validate_well_formed_xml(&xml).is_ok());
+
+        // A second pass finds nothing new
+        let again = repair.rebuild_content_types();
+        assert_eq!(again.issues_repaired, Vec::new());
+    }
 }
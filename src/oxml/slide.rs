@@ -2,6 +2,7 @@
 //!
 //! Parses slide XML to extract text, shapes, tables, and other content.
 
+use super::chart::ParsedChart;
 use super::xmlchemy::{XmlElement, XmlParser};
 use crate::exc::PptxError;
 
@@ -101,6 +102,10 @@ pub struct ParsedTableCell {
     pub text: String,
     pub row_span: u32,
     pub col_span: u32,
+    /// Covered by a `gridSpan` to the left (`hMerge`)
+    pub h_merge: bool,
+    /// Covered by a `rowSpan` above (`vMerge`)
+    pub v_merge: bool,
 }
 
 /// Parsed table
@@ -129,13 +134,50 @@ impl Default for ParsedTable {
     }
 }
 
+/// Element found on a slide that the importer cannot convert (SmartArt, OLE, charts, ...)
+#[derive(Debug, Clone)]
+pub struct ParsedUnsupported {
+    /// Human-readable element kind, e.g. "SmartArt"
+    pub kind: String,
+    pub name: String,
+    pub x: i64,
+    pub y: i64,
+    pub width: i64,
+    pub height: i64,
+}
+
+/// Picture (`p:pic`) with its embedded media
+#[derive(Debug, Clone)]
+pub struct ParsedPicture {
+    pub name: String,
+    /// Alt text from `cNvPr/@descr`
+    pub description: Option<String>,
+    /// Relationship id of the `a:blip` embed
+    pub rel_id: String,
+    pub x: i64,
+    pub y: i64,
+    pub width: i64,
+    pub height: i64,
+    /// Media part name, filled in once the relationship is resolved
+    pub target: Option<String>,
+    /// Media bytes, filled in once the relationship is resolved
+    pub data: Vec<u8>,
+}
+
 /// Parsed slide content
 #[derive(Debug, Clone)]
 pub struct ParsedSlide {
     pub shapes: Vec<ParsedShape>,
     pub tables: Vec<ParsedTable>,
+    /// Picture references; data is filled in once the media part is resolved
+    pub pictures: Vec<ParsedPicture>,
+    /// Chart references; data is filled in once the chart part is resolved
+    pub charts: Vec<ParsedChart>,
+    pub unsupported: Vec<ParsedUnsupported>,
     pub title: Option<String>,
     pub body_text: Vec<String>,
+    /// Speaker notes text from the slide's notes part
+    pub notes: Option<String>,
 }
 
 impl ParsedSlide {
@@ -143,8 +185,12 @@ impl ParsedSlide {
         ParsedSlide {
             shapes: Vec::new(),
             tables: Vec::new(),
+            pictures: Vec::new(),
+            charts: Vec::new(),
+            unsupported: Vec::new(),
             title: None,
             body_text: Vec::new(),
+            notes: None,
         }
     }
 
@@ -165,12 +211,143 @@ impl ParsedSlide {
     }
 }
 
+/// Outline entry for one body paragraph
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutlineBullet {
+    pub text: String,
+    /// Indent level (`a:pPr/@lvl`), 0 for top-level bullets
+    pub level: u32,
+}
+
+/// Chart text found on a slide
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutlineChart {
+    pub title: String,
+    pub categories: Vec<String>,
+}
+
+/// Text of one slide, keeping its structure
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SlideOutline {
+    /// 1-based slide number
+    pub number: usize,
+    pub title: Option<String>,
+    /// Body placeholder paragraphs in order
+    pub bullets: Vec<OutlineBullet>,
+    /// Text of other shapes (text boxes, labeled shapes), one entry per shape
+    pub text_boxes: Vec<String>,
+    /// Cell text of each table, row by row
+    pub tables: Vec<Vec<Vec<String>>>,
+    pub charts: Vec<OutlineChart>,
+    pub notes: Option<String>,
+}
+
+impl ParsedSlide {
+    /// Structured text of the slide for indexing or a table of contents
+    pub fn outline(&self, number: usize) -> SlideOutline {
+        let bullets = self
+            .shapes
+            .iter()
+            .filter(|shape| shape.is_body)
+            .flat_map(|shape| &shape.paragraphs)
+            .map(|p| OutlineBullet { text: p.text(), level: p.level })
+            .filter(|b| !b.text.trim().is_empty())
+            .collect();
+        let text_boxes = self
+            .shapes
+            .iter()
+            .filter(|shape| !shape.is_title && !shape.is_body)
+            .map(|shape| shape.text())
+            .filter(|text| !text.trim().is_empty())
+            .collect();
+        let tables = self
+            .tables
+            .iter()
+            .map(|table| {
+                table
+                    .rows
+                    .iter()
+                    .map(|row| row.iter().map(|cell| cell.text.clone()).collect())
+                    .collect()
+            })
+            .collect();
+        let charts = self
+            .charts
+            .iter()
+            .map(|chart| OutlineChart {
+                title: chart.title.clone(),
+                categories: chart.categories.clone(),
+            })
+            .collect();
+
+        SlideOutline {
+            number,
+            title: self.title.clone().filter(|t| !t.is_empty()),
+            bullets,
+            text_boxes,
+            tables,
+            charts,
+            notes: self.notes.clone(),
+        }
+    }
+}
+
 impl Default for ParsedSlide {
     fn default() -> Self {
         Self::new()
     }
 }
 
+/// Mapping from a group's child coordinates (`a:chOff`/`a:chExt`) to its frame on
+/// the parent (`a:off`/`a:ext`)
+#[derive(Clone, Copy, Debug, Default)]
+struct GroupTransform {
+    off: (i64, i64),
+    ext: (i64, i64),
+    ch_off: (i64, i64),
+    ch_ext: (i64, i64),
+}
+
+impl GroupTransform {
+    fn from_group(group: &XmlElement) -> Self {
+        let point = |xfrm: &XmlElement, name: &str, x: &str, y: &str| {
+            xfrm.find(name).map_or((0, 0), |e| {
+                (
+                    e.attr(x).and_then(|v| v.parse().ok()).unwrap_or(0),
+                    e.attr(y).and_then(|v| v.parse().ok()).unwrap_or(0),
+                )
+            })
+        };
+        let Some(xfrm) = group.find("grpSpPr").and_then(|p| p.find("xfrm")) else {
+            return Self::default();
+        };
+        Self {
+            off: point(xfrm, "off", "x", "y"),
+            ext: point(xfrm, "ext", "cx", "cy"),
+            ch_off: point(xfrm, "chOff", "x", "y"),
+            ch_ext: point(xfrm, "chExt", "cx", "cy"),
+        }
+    }
+
+    /// Map child-space bounds into the group's parent space
+    fn apply(&self, (x, y, width, height): (i64, i64, i64, i64)) -> (i64, i64, i64, i64) {
+        let scale = |ext: i64, ch_ext: i64, v: i64| {
+            if ch_ext == 0 { v } else { (v as i128 * ext as i128 / ch_ext as i128) as i64 }
+        };
+        (
+            self.off.0 + scale(self.ext.0, self.ch_ext.0, x - self.ch_off.0),
+            self.off.1 + scale(self.ext.1, self.ch_ext.1, y - self.ch_off.1),
+            scale(self.ext.0, self.ch_ext.0, width),
+            scale(self.ext.1, self.ch_ext.1, height),
+        )
+    }
+
+    /// Map bounds nested in `groups` (outermost first) to slide coordinates
+    fn to_slide(groups: &[GroupTransform], bounds: (i64, i64, i64, i64)) -> (i64, i64, i64, i64) {
+        groups.iter().rev().fold(bounds, |bounds, group| group.apply(bounds))
+    }
+}
+
 /// Slide parser
 pub struct SlideParser;
 
@@ -202,10 +379,31 @@ impl SlideParser {
                 }
             }
 
-            // Parse graphic frames (tables, charts)
-            for gf in sp_tree.find_all("graphicFrame") {
+            // Parse pictures, including those inside groups
+            let mut pics = Vec::new();
+            Self::collect_grouped(sp_tree, "pic", &[], &mut pics);
+            for (pic, groups) in pics {
+                if let Some(mut picture) = Self::parse_picture(pic) {
+                    (picture.x, picture.y, picture.width, picture.height) =
+                        GroupTransform::to_slide(&groups, (picture.x, picture.y, picture.width, picture.height));
+                    slide.pictures.push(picture);
+                }
+            }
+
+            // Parse graphic frames (tables, charts), including those inside groups
+            let mut frames = Vec::new();
+            Self::collect_grouped(sp_tree, "graphicFrame", &[], &mut frames);
+            for (gf, groups) in frames {
                 if let Some(table) = Self::parse_table_from_graphic_frame(gf) {
                     slide.tables.push(table);
+                } else if let Some(mut chart) = Self::parse_chart_reference(gf) {
+                    (chart.x, chart.y, chart.width, chart.height) =
+                        GroupTransform::to_slide(&groups, (chart.x, chart.y, chart.width, chart.height));
+                    slide.charts.push(chart);
+                } else if let Some(mut element) = Self::parse_unsupported_graphic_frame(gf) {
+                    (element.x, element.y, element.width, element.height) =
+                        GroupTransform::to_slide(&groups, (element.x, element.y, element.width, element.height));
+                    slide.unsupported.push(element);
                 }
             }
         }
@@ -213,6 +411,32 @@ impl SlideParser {
         Ok(slide)
     }
 
+    /// `tag` elements in `container` and its nested `p:grpSp` groups, each with the
+    /// transforms of the groups it sits in (outermost first). OLE objects are usually
+    /// wrapped in mc:AlternateContent/mc:Choice
+    fn collect_grouped<'a>(
+        container: &'a XmlElement,
+        tag: &str,
+        groups: &[GroupTransform],
+        found: &mut Vec<(&'a XmlElement, Vec<GroupTransform>)>,
+    ) {
+        for element in container.find_all(tag) {
+            found.push((element, groups.to_vec()));
+        }
+        for alt in container.find_all("AlternateContent") {
+            if let Some(choice) = alt.find("Choice") {
+                for element in choice.find_all(tag) {
+                    found.push((element, groups.to_vec()));
+                }
+            }
+        }
+        for group in container.find_all("grpSp") {
+            let mut nested = groups.to_vec();
+            nested.push(GroupTransform::from_group(group));
+            Self::collect_grouped(group, tag, &nested, found);
+        }
+    }
+
     fn parse_shape(sp: &XmlElement) -> Option<ParsedShape> {
         // Get shape name from nvSpPr/cNvPr
         let name = sp
@@ -223,16 +447,7 @@ impl SlideParser {
         let mut shape = ParsedShape::new(name);
 
         // Get position and size from spPr/xfrm
-        if let Some(xfrm) = sp.find_descendant("xfrm") {
-            if let Some(off) = xfrm.find("off") {
-                shape.x = off.attr("x").and_then(|v| v.parse().ok()).unwrap_or(0);
-                shape.y = off.attr("y").and_then(|v| v.parse().ok()).unwrap_or(0);
-            }
-            if let Some(ext) = xfrm.find("ext") {
-                shape.width = ext.attr("cx").and_then(|v| v.parse().ok()).unwrap_or(0);
-                shape.height = ext.attr("cy").and_then(|v| v.parse().ok()).unwrap_or(0);
-            }
-        }
+        (shape.x, shape.y, shape.width, shape.height) = Self::frame_bounds(sp);
 
         // Get shape type from prstGeom
         if let Some(prst_geom) = sp.find_descendant("prstGeom") {
@@ -338,6 +553,78 @@ impl SlideParser {
         false
     }
 
+    /// Position and size `(x, y, cx, cy)` from the first `xfrm` of a shape, picture or graphic frame
+    fn frame_bounds(gf: &XmlElement) -> (i64, i64, i64, i64) {
+        let mut bounds = (0, 0, 0, 0);
+        if let Some(xfrm) = gf.find_descendant("xfrm") {
+            if let Some(off) = xfrm.find("off") {
+                bounds.0 = off.attr("x").and_then(|v| v.parse().ok()).unwrap_or(0);
+                bounds.1 = off.attr("y").and_then(|v| v.parse().ok()).unwrap_or(0);
+            }
+            if let Some(ext) = xfrm.find("ext") {
+                bounds.2 = ext.attr("cx").and_then(|v| v.parse().ok()).unwrap_or(0);
+                bounds.3 = ext.attr("cy").and_then(|v| v.parse().ok()).unwrap_or(0);
+            }
+        }
+        bounds
+    }
+
+    fn parse_picture(pic: &XmlElement) -> Option<ParsedPicture> {
+        let rel_id = pic.find_descendant("blip")?.attr("r:embed")?;
+        let c_nv_pr = pic.find_descendant("cNvPr");
+        let (x, y, width, height) = Self::frame_bounds(pic);
+        Some(ParsedPicture {
+            name: c_nv_pr.and_then(|e| e.attr("name")).unwrap_or("Picture").to_string(),
+            description: c_nv_pr.and_then(|e| e.attr("descr")).map(str::to_string),
+            rel_id: rel_id.to_string(),
+            x,
+            y,
+            width,
+            height,
+            target: None,
+            data: Vec::new(),
+        })
+    }
+
+    fn parse_chart_reference(gf: &XmlElement) -> Option<ParsedChart> {
+        let graphic_data = gf.find_descendant("graphicData")?;
+        if !graphic_data.attr("uri").unwrap_or("").ends_with("/chart") {
+            return None;
+        }
+        let rel_id = graphic_data.find("chart")?.attr("r:id")?;
+
+        let mut chart = ParsedChart::new(rel_id);
+        (chart.x, chart.y, chart.width, chart.height) = Self::frame_bounds(gf);
+        Some(chart)
+    }
+
+    fn parse_unsupported_graphic_frame(gf: &XmlElement) -> Option<ParsedUnsupported> {
+        let uri = gf.find_descendant("graphicData")?.attr("uri").unwrap_or("");
+        let kind = if uri.ends_with("/diagram") {
+            "SmartArt"
+        } else if uri.ends_with("/chart") || uri.contains("/chartex") {
+            "Chart"
+        } else if uri.ends_with("/ole") {
+            "OLE object"
+        } else {
+            "Graphic frame"
+        };
+
+        let (x, y, width, height) = Self::frame_bounds(gf);
+        Some(ParsedUnsupported {
+            kind: kind.to_string(),
+            name: gf
+                .find_descendant("cNvPr")
+                .and_then(|e| e.attr("name"))
+                .unwrap_or("")
+                .to_string(),
+            x,
+            y,
+            width,
+            height,
+        })
+    }
+
     fn parse_table_from_graphic_frame(gf: &XmlElement) -> Option<ParsedTable> {
         // Find table element (a:tbl)
         let tbl = gf.find_descendant("tbl")?;
@@ -357,10 +644,14 @@ impl SlideParser {
                     .and_then(|v| v.parse().ok())
                     .unwrap_or(1);
 
+                let is_set = |name: &str| matches!(tc.attr(name), Some("1") | Some("true"));
+
                 row.push(ParsedTableCell {
                     text,
                     row_span,
                     col_span,
+                    h_merge: is_set("hMerge"),
+                    v_merge: is_set("vMerge"),
                 });
             }
             if !row.is_empty() {
@@ -457,4 +748,120 @@ mod tests {
         assert!(run.italic);
         assert_eq!(run.font_size, Some(4400));
     }
+
+    #[test]
+    fn test_parse_unsupported_graphic_frame() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+        <p:sld xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main"
+               xmlns:p="http://schemas.openxmlformats.org/presentationml/2006/main">
+            <p:cSld>
+                <p:spTree>
+                    <p:graphicFrame>
+                        <p:nvGraphicFramePr>
+                            <p:cNvPr id="4" name="Diagram 3"/>
+                        </p:nvGraphicFramePr>
+                        <p:xfrm>
+                            <a:off x="914400" y="1828800"/>
+                            <a:ext cx="4572000" cy="2286000"/>
+                        </p:xfrm>
+                        <a:graphic>
+                            <a:graphicData uri="http://schemas.openxmlformats.org/drawingml/2006/diagram"/>
+                        </a:graphic>
+                    </p:graphicFrame>
+                </p:spTree>
+            </p:cSld>
+        </p:sld>"#;
+
+        let slide = SlideParser::parse(xml).unwrap();
+        assert!(slide.tables.is_empty());
+        assert_eq!(slide.unsupported.len(), 1);
+        let element = &slide.unsupported[0];
+        assert_eq!(element.kind, "SmartArt");
+        assert_eq!(element.name, "Diagram 3");
+        assert_eq!((element.x, element.y), (914400, 1828800));
+        assert_eq!((element.width, element.height), (4572000, 2286000));
+    }
+
+    #[test]
+    fn test_parse_grouped_unsupported_graphic_frame() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+        <p:sld xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main"
+               xmlns:p="http://schemas.openxmlformats.org/presentationml/2006/main">
+            <p:cSld>
+                <p:spTree>
+                    <p:grpSp>
+                        <p:nvGrpSpPr>
+                            <p:cNvPr id="2" name="Group 1"/>
+                        </p:nvGrpSpPr>
+                        <p:grpSpPr>
+                            <a:xfrm>
+                                <a:off x="1000000" y="2000000"/>
+                                <a:ext cx="2000000" cy="2000000"/>
+                                <a:chOff x="0" y="0"/>
+                                <a:chExt cx="4000000" cy="4000000"/>
+                            </a:xfrm>
+                        </p:grpSpPr>
+                        <p:graphicFrame>
+                            <p:nvGraphicFramePr>
+                                <p:cNvPr id="3" name="Diagram 2"/>
+                            </p:nvGraphicFramePr>
+                            <p:xfrm>
+                                <a:off x="400000" y="800000"/>
+                                <a:ext cx="2000000" cy="1000000"/>
+                            </p:xfrm>
+                            <a:graphic>
+                                <a:graphicData uri="http://schemas.openxmlformats.org/drawingml/2006/diagram"/>
+                            </a:graphic>
+                        </p:graphicFrame>
+                    </p:grpSp>
+                </p:spTree>
+            </p:cSld>
+        </p:sld>"#;
+
+        let slide = SlideParser::parse(xml).unwrap();
+        assert_eq!(slide.unsupported.len(), 1);
+        let element = &slide.unsupported[0];
+        assert_eq!(element.kind, "SmartArt");
+        assert_eq!(element.name, "Diagram 2");
+        // Child coordinates are scaled by half into the group's frame
+        assert_eq!((element.x, element.y), (1200000, 2400000));
+        assert_eq!((element.width, element.height), (1000000, 500000));
+    }
+
+    #[test]
+    fn test_parse_merged_table_cells() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+        <p:sld xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main"
+               xmlns:p="http://schemas.openxmlformats.org/presentationml/2006/main">
+            <p:cSld>
+                <p:spTree>
+                    <p:graphicFrame>
+                        <p:nvGraphicFramePr>
+                            <p:cNvPr id="4" name="Table 3"/>
+                        </p:nvGraphicFramePr>
+                        <a:graphic>
+                            <a:graphicData uri="http://schemas.openxmlformats.org/drawingml/2006/table">
+                                <a:tbl>
+                                    <a:tr h="370840">
+                                        <a:tc gridSpan="2"><a:txBody><a:p><a:r><a:t>Header</a:t></a:r></a:p></a:txBody></a:tc>
+                                        <a:tc hMerge="1"><a:txBody><a:p/></a:txBody></a:tc>
+                                    </a:tr>
+                                    <a:tr h="370840">
+                                        <a:tc><a:txBody><a:p><a:r><a:t>A</a:t></a:r></a:p></a:txBody></a:tc>
+                                        <a:tc vMerge="1"><a:txBody><a:p/></a:txBody></a:tc>
+                                    </a:tr>
+                                </a:tbl>
+                            </a:graphicData>
+                        </a:graphic>
+                    </p:graphicFrame>
+                </p:spTree>
+            </p:cSld>
+        </p:sld>"#;
+
+        let slide = SlideParser::parse(xml).unwrap();
+        let rows = &slide.tables[0].rows;
+        assert_eq!((rows[0][0].col_span, rows[0][0].h_merge), (2, false));
+        assert!(rows[0][1].h_merge);
+        assert!(rows[1][1].v_merge && !rows[1][0].v_merge);
+    }
 }
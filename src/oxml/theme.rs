@@ -0,0 +1,137 @@
+//! Theme part parsing
+//!
+//! Reads the color scheme and major/minor fonts from `ppt/theme/themeN.xml`.
+
+use super::xmlchemy::{XmlElement, XmlParser};
+use crate::exc::PptxError;
+use crate::generator::{PresentationTheme, ThemeColorScheme, ThemeFonts};
+
+/// Parsed theme: name, the 12 scheme colors as `RRGGBB`, and the latin major/minor fonts
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParsedTheme {
+    pub name: String,
+    /// Slots missing from the part keep the Office defaults
+    pub colors: ThemeColorScheme,
+    /// Fonts missing from the part keep the Office defaults
+    pub fonts: ThemeFonts,
+}
+
+impl ParsedTheme {
+    /// Theme for generating new slides that match the parsed deck
+    pub fn to_presentation_theme(&self) -> PresentationTheme {
+        PresentationTheme::new(self.name.clone())
+            .colors(self.colors.clone())
+            .fonts(self.fonts.clone())
+    }
+}
+
+impl From<ParsedTheme> for PresentationTheme {
+    fn from(theme: ParsedTheme) -> Self {
+        theme.to_presentation_theme()
+    }
+}
+
+/// Theme part parser
+pub struct ThemeParser;
+
+impl ThemeParser {
+    /// Parse theme part XML
+    pub fn parse(xml: &str) -> Result<ParsedTheme, PptxError> {
+        let root = XmlParser::parse_str(xml)?;
+        let mut theme = ParsedTheme {
+            name: root.attr("name").unwrap_or("Theme").to_string(),
+            colors: ThemeColorScheme::office(),
+            fonts: ThemeFonts::office(),
+        };
+
+        if let Some(scheme) = root.find_descendant("clrScheme") {
+            let c = &mut theme.colors;
+            let slots = [
+                ("dk1", &mut c.dk1),
+                ("lt1", &mut c.lt1),
+                ("dk2", &mut c.dk2),
+                ("lt2", &mut c.lt2),
+                ("accent1", &mut c.accent1),
+                ("accent2", &mut c.accent2),
+                ("accent3", &mut c.accent3),
+                ("accent4", &mut c.accent4),
+                ("accent5", &mut c.accent5),
+                ("accent6", &mut c.accent6),
+                ("hlink", &mut c.hlink),
+                ("folHlink", &mut c.fol_hlink),
+            ];
+            for (tag, slot) in slots {
+                if let Some(color) = scheme.find(tag).and_then(Self::parse_color) {
+                    *slot = color;
+                }
+            }
+        }
+
+        if let Some(fonts) = root.find_descendant("fontScheme") {
+            if let Some(major) = Self::latin_typeface(fonts, "majorFont") {
+                theme.fonts.major = major;
+            }
+            if let Some(minor) = Self::latin_typeface(fonts, "minorFont") {
+                theme.fonts.minor = minor;
+            }
+        }
+
+        Ok(theme)
+    }
+
+    /// `srgbClr/@val`, or `sysClr/@lastClr` with a fallback for the common system colors
+    fn parse_color(slot: &XmlElement) -> Option<String> {
+        if let Some(val) = slot.find("srgbClr").and_then(|e| e.attr("val")) {
+            return Some(val.to_uppercase());
+        }
+        let sys = slot.find("sysClr")?;
+        if let Some(last) = sys.attr("lastClr") {
+            return Some(last.to_uppercase());
+        }
+        match sys.attr("val")? {
+            "windowText" | "btnText" | "menuText" | "captionText" | "infoText" => Some("000000".to_string()),
+            "window" | "btnFace" | "menu" | "infoBk" | "btnHighlight" => Some("FFFFFF".to_string()),
+            _ => None,
+        }
+    }
+
+    fn latin_typeface(fonts: &XmlElement, font_tag: &str) -> Option<String> {
+        fonts
+            .find(font_tag)?
+            .find("latin")?
+            .attr("typeface")
+            .filter(|t| !t.is_empty())
+            .map(str::to_string)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_srgb_and_sys_colors() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+            <a:theme xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main" name="Client">
+              <a:themeElements>
+                <a:clrScheme name="Client">
+                  <a:dk1><a:sysClr val="windowText" lastClr="0a0a0a"/></a:dk1>
+                  <a:lt1><a:sysClr val="window"/></a:lt1>
+                  <a:accent1><a:srgbClr val="e4002b"/></a:accent1>
+                </a:clrScheme>
+                <a:fontScheme name="Client">
+                  <a:majorFont><a:latin typeface="Georgia"/></a:majorFont>
+                  <a:minorFont><a:latin typeface=""/></a:minorFont>
+                </a:fontScheme>
+              </a:themeElements>
+            </a:theme>"#;
+        let theme = ThemeParser::parse(xml).unwrap();
+        assert_eq!(theme.name, "Client");
+        assert_eq!(theme.colors.dk1, "0A0A0A");
+        assert_eq!(theme.colors.lt1, "FFFFFF");
+        assert_eq!(theme.colors.accent1, "E4002B");
+        // Missing slots and fonts keep the Office defaults
+        assert_eq!(theme.colors.accent2, ThemeColorScheme::office().accent2);
+        assert_eq!(theme.fonts, ThemeFonts::new("Georgia", "Calibri"));
+    }
+}
@@ -31,6 +31,6 @@ pub use slide_layout::{LayoutType, SlideLayoutPart};
 pub use slide_master::SlideMasterPart;
 pub use table::{
     BorderStyle, CellBorder, CellBorders, CellMargins, HorizontalAlign, TableCellPart, TablePart,
-    TableRowPart, VerticalAlign,
+    TableRowPart, TableStyle, VerticalAlign,
 };
 pub use theme::{ThemeColor, ThemeFont, ThemePart};
@@ -5,6 +5,7 @@
 use super::base::{ContentType, Part, PartType};
 use crate::core::escape_xml;
 use crate::exc::PptxError;
+use crate::generator::text::proofing_attrs;
 
 /// Notes slide part (ppt/notesSlides/notesSlideN.xml)
 #[derive(Debug, Clone)]
@@ -69,7 +70,8 @@ impl NotesSlidePart {
                 .lines()
                 .map(|line| {
                     format!(
-                        "<a:p><a:r><a:rPr lang=\"en-US\"/><a:t>{}</a:t></a:r></a:p>",
+                        "<a:p><a:r><a:rPr lang=\"en-US\"{}/><a:t>{}</a:t></a:r></a:p>",
+                        proofing_attrs(false),
                         escape_xml(line)
                     )
                 })
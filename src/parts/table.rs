@@ -8,12 +8,18 @@
 //! - Cell alignment (horizontal and vertical)
 //! - Borders (all sides, individual sides)
 //! - Background colors and gradients
+//! - Color scales (heatmap shading computed from cell values)
 //! - Font customization (size, color, family)
+//! - Multi-paragraph cells and picture fills
 //! - Table styles
 
 use super::base::{ContentType, Part, PartType};
+use super::image::ImagePart;
+use super::relationships::{RelationshipType, Relationships};
 use crate::core::{escape_xml, ToXml};
-use crate::exc::PptxError;
+use crate::elements::{color_value_xml, is_color_value, ColorInput, RgbColor, RgbInput};
+use crate::exc::{messages, PptxError};
+use crate::generator::text::{proofing_attrs, Paragraph};
 
 /// Horizontal alignment
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -118,13 +124,17 @@ impl CellBorder {
     }
 }
 
-/// Cell borders (all four sides)
+/// Cell borders (four sides plus the two diagonals)
 #[derive(Debug, Clone, Default)]
 pub struct CellBorders {
     pub left: Option<CellBorder>,
     pub right: Option<CellBorder>,
     pub top: Option<CellBorder>,
     pub bottom: Option<CellBorder>,
+    /// Top-left to bottom-right line
+    pub diagonal_down: Option<CellBorder>,
+    /// Bottom-left to top-right line
+    pub diagonal_up: Option<CellBorder>,
 }
 
 impl CellBorders {
@@ -134,6 +144,8 @@ impl CellBorders {
             right: Some(border.clone()),
             top: Some(border.clone()),
             bottom: Some(border),
+            diagonal_down: None,
+            diagonal_up: None,
         }
     }
 
@@ -148,9 +160,23 @@ impl CellBorders {
             right: Some(no_border.clone()),
             top: Some(no_border.clone()),
             bottom: Some(no_border),
+            diagonal_down: None,
+            diagonal_up: None,
         }
     }
 
+    /// Add a top-left to bottom-right diagonal
+    pub fn diagonal_down(mut self, border: CellBorder) -> Self {
+        self.diagonal_down = Some(border);
+        self
+    }
+
+    /// Add a bottom-left to top-right diagonal
+    pub fn diagonal_up(mut self, border: CellBorder) -> Self {
+        self.diagonal_up = Some(border);
+        self
+    }
+
     pub fn to_xml(&self) -> String {
         let mut xml = String::new();
         if let Some(ref b) = self.left {
@@ -165,6 +191,12 @@ impl CellBorders {
         if let Some(ref b) = self.bottom {
             xml.push_str(&b.to_xml("lnB"));
         }
+        if let Some(ref b) = self.diagonal_down {
+            xml.push_str(&b.to_xml("lnTlToBr"));
+        }
+        if let Some(ref b) = self.diagonal_up {
+            xml.push_str(&b.to_xml("lnBlToTr"));
+        }
         xml
     }
 }
@@ -225,6 +257,12 @@ pub struct TableCellPart {
     pub borders: Option<CellBorders>,
     pub margins: Option<CellMargins>,
     pub is_merged: bool, // For cells that are part of a merge (not the anchor)
+    /// Rich paragraphs; used instead of `text` when non-empty
+    pub paragraphs: Vec<Paragraph>,
+    /// Picture stretched over the cell as its fill
+    pub image: Option<ImagePart>,
+    /// Relationship id of `image`, set by `TablePart::add_image_relationships`
+    pub image_r_id: Option<String>,
 }
 
 impl TableCellPart {
@@ -247,6 +285,9 @@ impl TableCellPart {
             borders: None,
             margins: None,
             is_merged: false,
+            paragraphs: Vec::new(),
+            image: None,
+            image_r_id: None,
         }
     }
 
@@ -281,15 +322,15 @@ impl TableCellPart {
         self
     }
 
-    /// Set background color
-    pub fn background(mut self, color: impl Into<String>) -> Self {
-        self.background_color = Some(color.into());
+    /// Set background color (RGB or theme scheme color)
+    pub fn background(mut self, color: impl Into<ColorInput>) -> Self {
+        self.background_color = Some(color.into().into_value());
         self
     }
 
-    /// Set text color
-    pub fn color(mut self, color: impl Into<String>) -> Self {
-        self.text_color = Some(color.into());
+    /// Set text color (RGB or theme scheme color)
+    pub fn color(mut self, color: impl Into<ColorInput>) -> Self {
+        self.text_color = Some(color.into().into_value());
         self
     }
 
@@ -354,6 +395,19 @@ impl TableCellPart {
         self
     }
 
+    /// Fill the cell with paragraphs, each with its own runs, alignment, bullets and spacing
+    pub fn with_paragraphs(mut self, paragraphs: Vec<Paragraph>) -> Self {
+        self.paragraphs = paragraphs;
+        self
+    }
+
+    /// Stretch a picture over the cell as its fill, e.g. a check mark icon.
+    /// Tables can't hold inline pictures, so the image replaces any background color.
+    pub fn with_image(mut self, image: ImagePart) -> Self {
+        self.image = Some(image);
+        self
+    }
+
     /// Generate XML for this cell
     pub fn to_xml(&self) -> String {
         // Handle merged cells (placeholders)
@@ -373,12 +427,7 @@ impl TableCellPart {
         let bg_xml = self
             .background_color
             .as_ref()
-            .map(|c| {
-                format!(
-                    r#"<a:solidFill><a:srgbClr val="{}"/></a:solidFill>"#,
-                    c.trim_start_matches('#')
-                )
-            })
+            .map(|c| format!("<a:solidFill>{}</a:solidFill>", color_value_xml(c.trim_start_matches('#'), "")))
             .unwrap_or_default();
 
         // Text run properties
@@ -398,17 +447,13 @@ impl TableCellPart {
         if let Some(size) = self.font_size {
             rpr_attrs.push_str(&format!(r#" sz="{}""#, size * 100));
         }
+        rpr_attrs.push_str(proofing_attrs(false));
 
         // Text color
         let color_xml = self
             .text_color
             .as_ref()
-            .map(|c| {
-                format!(
-                    r#"<a:solidFill><a:srgbClr val="{}"/></a:solidFill>"#,
-                    c.trim_start_matches('#')
-                )
-            })
+            .map(|c| format!("<a:solidFill>{}</a:solidFill>", color_value_xml(c.trim_start_matches('#'), "")))
             .unwrap_or_default();
 
         // Font family
@@ -425,6 +470,41 @@ impl TableCellPart {
             format!(r#"<a:pPr algn="{}"/>"#, self.h_align.as_str())
         };
 
+        // One paragraph per line of text, unless rich paragraphs are set
+        let paragraphs_xml = if self.paragraphs.is_empty() {
+            self.text
+                .split('\n')
+                .map(|line| {
+                    format!(
+                        r#"<a:p>
+              {}
+              <a:r>
+                <a:rPr lang="en-US"{}>{}{}</a:rPr>
+                <a:t>{}</a:t>
+              </a:r>
+            </a:p>"#,
+                        p_pr,
+                        rpr_attrs,
+                        color_xml,
+                        font_xml,
+                        escape_xml(line.trim_end_matches('\r'))
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join("\n            ")
+        } else {
+            self.paragraphs.iter().map(Paragraph::to_xml).collect()
+        };
+
+        // A related picture replaces the background color
+        let fill_xml = match (&self.image, &self.image_r_id) {
+            (Some(_), Some(r_id)) => format!(
+                r#"<a:blipFill><a:blip r:embed="{}"/><a:stretch><a:fillRect/></a:stretch></a:blipFill>"#,
+                r_id
+            ),
+            _ => bg_xml,
+        };
+
         // Cell properties
         let mut tcpr_attrs = format!(r#" anchor="{}""#, self.v_align.as_str());
         if let Some(ref m) = self.margins {
@@ -446,25 +526,15 @@ impl TableCellPart {
           <a:txBody>
             <a:bodyPr/>
             <a:lstStyle/>
-            <a:p>
-              {}
-              <a:r>
-                <a:rPr lang="en-US"{}>{}{}</a:rPr>
-                <a:t>{}</a:t>
-              </a:r>
-            </a:p>
+            {}
           </a:txBody>
           <a:tcPr{}>{}{}</a:tcPr>
         </a:tc>"#,
             attrs,
-            p_pr,
-            rpr_attrs,
-            color_xml,
-            font_xml,
-            escape_xml(&self.text),
+            paragraphs_xml,
             tcpr_attrs,
             borders_xml,
-            bg_xml
+            fill_xml
         )
     }
 }
@@ -526,6 +596,47 @@ impl ToXml for TableRowPart {
     }
 }
 
+/// Built-in PowerPoint table styles
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TableStyle {
+    /// Medium Style 2 - Accent 1 (PowerPoint's default)
+    #[default]
+    MediumBlue,
+    /// Medium Style 2
+    MediumGray,
+    /// Light Style 1
+    LightGray,
+    /// Light Style 1 - Accent 1
+    LightBlue,
+    /// Light Style 2 - Accent 1
+    LightBlueHeader,
+    /// Themed Style 1 - Accent 1
+    ThemedBlue,
+    /// Dark Style 1
+    Dark,
+    /// No Style, Table Grid
+    NoStyleGridlines,
+    /// No Style, No Grid
+    NoStyle,
+}
+
+impl TableStyle {
+    /// Built-in style GUID for `<a:tableStyleId>`
+    pub fn guid(&self) -> &'static str {
+        match self {
+            TableStyle::MediumBlue => "{5C22544A-7EE6-4342-B048-85BDC9FD1C3A}",
+            TableStyle::MediumGray => "{073A0DAA-6AF3-43AB-8588-CEC1D06C72B9}",
+            TableStyle::LightGray => "{9D7B26C5-4107-4FEC-AEDC-1716B250EE53}",
+            TableStyle::LightBlue => "{3B4B98B0-60AC-42C2-AFA5-B58CD77FA1E5}",
+            TableStyle::LightBlueHeader => "{69012ECD-51FC-41F1-AA8D-1B2483CD663E}",
+            TableStyle::ThemedBlue => "{3C2FFA5D-87B4-456A-9821-1D502468CF0F}",
+            TableStyle::Dark => "{E8034E78-7F5D-4C2E-B375-FC64B27BC917}",
+            TableStyle::NoStyleGridlines => "{5940675A-B579-460E-94D1-54222C63F5DA}",
+            TableStyle::NoStyle => "{2D5ABB26-0587-4C30-8999-92F81FD0307C}",
+        }
+    }
+}
+
 /// Table part for embedding in slides
 #[derive(Debug, Clone)]
 pub struct TablePart {
@@ -535,6 +646,15 @@ pub struct TablePart {
     pub y: i64,
     pub width: i64,
     pub height: i64,
+    pub style: TableStyle,
+    pub header_row: bool,
+    pub banded_rows: bool,
+    pub first_column: bool,
+    pub last_row: bool,
+    /// Size columns from their content when rendering
+    pub autofit: bool,
+    /// Set by `col_widths()`; explicit widths win over autofit
+    pub fixed_col_widths: bool,
 }
 
 impl TablePart {
@@ -547,6 +667,13 @@ impl TablePart {
             y: 1828800,      // 2 inches
             width: 7315200,  // 8 inches
             height: 1828800, // 2 inches
+            style: TableStyle::default(),
+            header_row: true,
+            banded_rows: true,
+            first_column: false,
+            last_row: false,
+            autofit: false,
+            fixed_col_widths: false,
         }
     }
 
@@ -576,16 +703,180 @@ impl TablePart {
         self
     }
 
-    /// Set column widths
+    /// Set column widths (takes precedence over `autofit_columns`)
     pub fn col_widths(mut self, widths: Vec<i64>) -> Self {
         self.col_widths = widths;
+        self.fixed_col_widths = true;
+        self
+    }
+
+    /// Size columns in proportion to their longest text
+    pub fn autofit_columns(mut self) -> Self {
+        self.autofit = true;
+        self
+    }
+
+    /// Column widths used for rendering, after autofit
+    pub fn effective_col_widths(&self) -> Vec<i64> {
+        if !self.autofit || self.fixed_col_widths || self.col_widths.is_empty() {
+            return self.col_widths.clone();
+        }
+
+        // Approximate each glyph as 0.55em and add the default cell margins
+        let mut needed = vec![0i64; self.col_widths.len()];
+        // One cell per grid column; spanning anchors and their merged placeholders are skipped
+        for row in &self.rows {
+            for (col, cell) in row.cells.iter().enumerate().take(needed.len()) {
+                if cell.col_span <= 1 && !cell.is_merged {
+                    let chars = cell.text.lines().map(|l| l.chars().count()).max().unwrap_or(0) as i64;
+                    let font_size = cell.font_size.unwrap_or(18) as i64;
+                    let width = chars * font_size * 12700 * 55 / 100 + 2 * 91440;
+                    needed[col] = needed[col].max(width);
+                }
+            }
+        }
+
+        let min_width = 2 * 91440;
+        let weights: Vec<i64> = needed.iter().map(|w| (*w).max(min_width)).collect();
+        let total: i64 = weights.iter().sum();
+        let mut widths: Vec<i64> = weights.iter().map(|w| self.width * w / total).collect();
+        // Give rounding leftovers to the last column so widths sum to the table width
+        let assigned: i64 = widths.iter().sum();
+        if let Some(last) = widths.last_mut() {
+            *last += self.width - assigned;
+        }
+        widths
+    }
+
+    /// Set the built-in table style
+    pub fn style(mut self, style: TableStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Emphasize the first row as a header
+    pub fn header_row(mut self, enabled: bool) -> Self {
+        self.header_row = enabled;
+        self
+    }
+
+    /// Alternate row shading
+    pub fn banded_rows(mut self, enabled: bool) -> Self {
+        self.banded_rows = enabled;
+        self
+    }
+
+    /// Emphasize the first column
+    pub fn first_column(mut self, enabled: bool) -> Self {
+        self.first_column = enabled;
+        self
+    }
+
+    /// Emphasize the last row (totals)
+    pub fn last_row(mut self, enabled: bool) -> Self {
+        self.last_row = enabled;
+        self
+    }
+
+    /// Shade the numeric cells of a 0-based grid `column` between `min_color` (lowest value)
+    /// and `max_color` (highest), heatmap style.
+    ///
+    /// The fills are computed once here and stored as static backgrounds. The header row
+    /// and cells without a number (ignoring `,`, `%` and currency signs) are left unshaded.
+    pub fn color_scale(mut self, column: usize, min_color: impl Into<RgbInput>, max_color: impl Into<RgbInput>) -> Self {
+        let (min_value, max_value) = (min_color.into().into_value(), max_color.into().into_value());
+        let (min_color, max_color) = (RgbColor::from_hex(&min_value), RgbColor::from_hex(&max_value));
+        let skip = usize::from(self.header_row);
+        let values: Vec<(usize, usize, f64)> = self
+            .rows
+            .iter()
+            .enumerate()
+            .skip(skip)
+            .filter_map(|(row_idx, row)| {
+                let (cell_idx, cell) = cell_at_column(row, column)?;
+                Some((row_idx, cell_idx, cell_number(&cell.text)?))
+            })
+            .collect();
+        let (low, high) = values
+            .iter()
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), (_, _, v)| (lo.min(*v), hi.max(*v)));
+        let range = high - low;
+
+        for (row_idx, cell_idx, value) in values {
+            let t = if range > 0.0 { (value - low) / range } else { 0.0 };
+            let channel = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * t).round() as u8;
+            // An unparsed end color is kept as given so building the deck reports it
+            let shade = match (&min_color, &max_color) {
+                (Ok(min), Ok(max)) => RgbColor::new(channel(min.r, max.r), channel(min.g, max.g), channel(min.b, max.b)).to_hex(),
+                (Err(_), _) => min_value.clone(),
+                (_, Err(_)) => max_value.clone(),
+            };
+            self.rows[row_idx].cells[cell_idx].background_color = Some(shade);
+        }
         self
     }
 
+    /// Split a tall table into tables of at most `max_rows_per_slide` rows, one per slide.
+    ///
+    /// With `header_row` on, every chunk starts with a copy of the first row and the count
+    /// includes it. Each chunk keeps the style, flags, position, size and the original
+    /// column widths; row spans are cut at the chunk boundary.
+    pub fn split_across_slides(&self, max_rows_per_slide: usize) -> Vec<TablePart> {
+        let header_rows = usize::from(self.header_row && !self.rows.is_empty());
+        let (header, body) = self.rows.split_at(header_rows);
+        let body_per_chunk = max_rows_per_slide.saturating_sub(header_rows).max(1);
+        if body.len() <= body_per_chunk {
+            return vec![self.clone()];
+        }
+
+        let col_widths = self.effective_col_widths();
+        body.chunks(body_per_chunk)
+            .map(|chunk| {
+                let mut rows: Vec<TableRowPart> = header.iter().chain(chunk).cloned().collect();
+                let row_count = rows.len();
+                for (i, row) in rows.iter_mut().enumerate() {
+                    for cell in &mut row.cells {
+                        cell.row_span = cell.row_span.min((row_count - i) as u32);
+                    }
+                }
+                TablePart {
+                    rows,
+                    col_widths: col_widths.clone(),
+                    fixed_col_widths: true,
+                    ..self.clone()
+                }
+            })
+            .collect()
+    }
+
+    /// Relate every cell picture from the slide that hosts the table, recording each
+    /// relationship id on its cell; call before `to_slide_xml`
+    pub fn add_image_relationships(&mut self, rels: &mut Relationships) {
+        for cell in self.rows.iter_mut().flat_map(|row| row.cells.iter_mut()) {
+            if let Some(image) = &cell.image {
+                cell.image_r_id = Some(rels.add(RelationshipType::Image, &image.rel_target()));
+            }
+        }
+    }
+
+    /// `<a:tblPr>` flag attributes in schema order
+    fn tbl_pr_attrs(&self) -> String {
+        [
+            ("firstRow", self.header_row),
+            ("firstCol", self.first_column),
+            ("lastRow", self.last_row),
+            ("bandRow", self.banded_rows),
+        ]
+        .iter()
+        .filter(|(_, on)| *on)
+        .map(|(name, _)| format!(r#" {}="1""#, name))
+        .collect()
+    }
+
     /// Generate table XML for embedding in a slide
     pub fn to_slide_xml(&self, shape_id: usize) -> String {
         let grid_cols: String = self
-            .col_widths
+            .effective_col_widths()
             .iter()
             .map(|w| format!(r#"<a:gridCol w="{}"/>"#, w))
             .collect::<Vec<_>>()
@@ -612,8 +903,8 @@ impl TablePart {
   <a:graphic>
     <a:graphicData uri="http://schemas.openxmlformats.org/drawingml/2006/table">
       <a:tbl>
-        <a:tblPr firstRow="1" bandRow="1">
-          <a:tableStyleId>{{5C22544A-7EE6-4342-B048-85BDC9FD1C3A}}</a:tableStyleId>
+        <a:tblPr{}>
+          <a:tableStyleId>{}</a:tableStyleId>
         </a:tblPr>
         <a:tblGrid>
         {}
@@ -623,11 +914,34 @@ impl TablePart {
     </a:graphicData>
   </a:graphic>
 </p:graphicFrame>"#,
-            shape_id, shape_id, self.x, self.y, self.width, self.height, grid_cols, rows_xml
+            shape_id,
+            shape_id,
+            self.x,
+            self.y,
+            self.width,
+            self.height,
+            self.tbl_pr_attrs(),
+            self.style.guid(),
+            grid_cols,
+            rows_xml
         )
     }
 }
 
+/// The cell whose span starts at 0-based grid `column`, with its index in the row.
+///
+/// Each grid column holds one cell, the columns a span covers holding
+/// [`TableCellPart::merged`] placeholders, which are never an anchor.
+fn cell_at_column(row: &TableRowPart, column: usize) -> Option<(usize, &TableCellPart)> {
+    row.cells.get(column).filter(|cell| !cell.is_merged).map(|cell| (column, cell))
+}
+
+/// Numeric value of cell text such as `1,250`, `42%` or `$9.99`
+fn cell_number(text: &str) -> Option<f64> {
+    let text = text.trim().trim_start_matches(['$', '€', '£', '¥']).trim_end_matches('%');
+    text.replace(',', "").trim().parse().ok()
+}
+
 impl Default for TablePart {
     fn default() -> Self {
         Self::new()
@@ -648,6 +962,13 @@ impl Part for TablePart {
     }
 
     fn to_xml(&self) -> Result<String, PptxError> {
+        let cell_colors = self.rows.iter().flat_map(|row| &row.cells).flat_map(|cell| [&cell.background_color, &cell.text_color]);
+        if let Some(color) = cell_colors.flatten().find(|color| !is_color_value(color)) {
+            return Err(PptxError::InvalidValue(messages::invalid_value(
+                "table cell color",
+                &format!("\"{color}\" is not a hex color, color name or theme color"),
+            )));
+        }
         Ok(self.to_slide_xml(2))
     }
 
@@ -678,6 +999,12 @@ mod tests {
         assert_eq!(cell.font_size, Some(14));
     }
 
+    #[test]
+    fn test_table_cell_runs_are_not_dirty() {
+        let xml = TableCellPart::new("Total").bold().to_xml();
+        assert!(xml.contains(r#"<a:rPr lang="en-US" b="1" dirty="0">"#));
+    }
+
     #[test]
     fn test_table_cell_span() {
         let cell = TableCellPart::new("Merged").row_span(2).col_span(3);
@@ -713,5 +1040,232 @@ mod tests {
         assert!(xml.contains("p:graphicFrame"));
         assert!(xml.contains("a:tbl"));
         assert!(xml.contains("Test"));
+        assert!(xml.contains(r#"<a:tblPr firstRow="1" bandRow="1">"#));
+        assert!(xml.contains("<a:tableStyleId>{5C22544A-7EE6-4342-B048-85BDC9FD1C3A}</a:tableStyleId>"));
+    }
+
+    #[test]
+    fn test_table_style_and_flags() {
+        let xml = TablePart::new()
+            .add_row(TableRowPart::new(vec![TableCellPart::new("Total")]))
+            .style(TableStyle::LightGray)
+            .banded_rows(false)
+            .first_column(true)
+            .last_row(true)
+            .to_slide_xml(5);
+        assert!(xml.contains(r#"<a:tblPr firstRow="1" firstCol="1" lastRow="1">"#));
+        assert!(xml.contains("<a:tableStyleId>{9D7B26C5-4107-4FEC-AEDC-1716B250EE53}</a:tableStyleId>"));
+
+        let xml = TablePart::new().header_row(false).banded_rows(false).to_slide_xml(1);
+        assert!(xml.contains("<a:tblPr>"));
+    }
+
+    #[test]
+    fn test_cell_diagonal_borders() {
+        let borders = CellBorders::all(CellBorder::new(1.0, "000000"))
+            .diagonal_down(CellBorder::new(0.5, "#808080").style(BorderStyle::Dashed))
+            .diagonal_up(CellBorder::new(0.5, "808080"));
+        let xml = borders.to_xml();
+        let bottom = xml.find("<a:lnB ").unwrap();
+        let down = xml.find(r#"<a:lnTlToBr w="6350""#).unwrap();
+        let up = xml.find("<a:lnBlToTr ").unwrap();
+        assert!(bottom < down && down < up);
+        assert!(xml.contains(r#"<a:prstDash val="dash"/>"#));
+        assert!(!CellBorders::none().to_xml().contains("lnTlToBr"));
+
+        let cell = TableCellPart::new("").borders(CellBorders::default().diagonal_down(CellBorder::new(1.0, "000000")));
+        assert!(cell.to_xml().contains("</a:lnTlToBr>"));
+    }
+
+    #[test]
+    fn test_autofit_columns() {
+        let table = TablePart::new()
+            .add_row(TableRowPart::new(vec![
+                TableCellPart::new("Qty"),
+                TableCellPart::new("Description of the line item"),
+            ]))
+            .add_row(TableRowPart::new(vec![
+                TableCellPart::new("3"),
+                TableCellPart::new("Widgets"),
+            ]))
+            .autofit_columns();
+        let widths = table.effective_col_widths();
+        assert!(widths[1] > widths[0] * 3);
+        assert_eq!(widths.iter().sum::<i64>(), table.width);
+        assert!(table.to_slide_xml(1).contains(&format!(r#"<a:gridCol w="{}"/>"#, widths[1])));
+
+        // Explicit widths win regardless of call order
+        let fixed = table.col_widths(vec![3657600, 3657600]).autofit_columns();
+        assert_eq!(fixed.effective_col_widths(), vec![3657600, 3657600]);
+    }
+
+    #[test]
+    fn test_autofit_columns_after_merged_cells() {
+        let table = TablePart::new()
+            .add_row(TableRowPart::new(vec![
+                TableCellPart::new("Spanning heading").col_span(2),
+                TableCellPart::merged(),
+                TableCellPart::new("Trailing column notes"),
+            ]))
+            .add_row(TableRowPart::new(vec![
+                TableCellPart::new("1"),
+                TableCellPart::new("2"),
+                TableCellPart::new("3"),
+            ]))
+            .autofit_columns();
+        let widths = table.effective_col_widths();
+        assert_eq!(widths.len(), 3);
+        assert!(widths[2] > widths[0] * 3);
+        assert_eq!(widths[0], widths[1]);
+    }
+
+    #[test]
+    fn test_cell_text_lines_become_paragraphs() {
+        let xml = TableCellPart::new("Fast\nCheap").bold().to_xml();
+        assert_eq!(xml.matches("<a:p>").count(), 2);
+        assert!(xml.contains("<a:t>Fast</a:t>") && xml.contains("<a:t>Cheap</a:t>"));
+        assert_eq!(xml.matches(r#"b="1""#).count(), 2);
+        assert!(!xml.contains("Fast\nCheap"));
+    }
+
+    #[test]
+    fn test_cell_with_paragraphs() {
+        use crate::generator::text::{Run, TextAlign};
+
+        let cell = TableCellPart::new("ignored").with_paragraphs(vec![
+            Paragraph::new().add_run(Run::new("Sync").bold()),
+            Paragraph::with_text("Keeps every device up to date").align(TextAlign::Left),
+        ]);
+        let xml = cell.to_xml();
+        assert_eq!(xml.matches("<a:p>").count(), 2);
+        assert!(xml.contains("Keeps every device up to date"));
+        assert!(!xml.contains("ignored"));
+    }
+
+    #[test]
+    fn test_cell_image_fill() {
+        let png = ImagePart::new(3, "png", vec![0x89, b'P', b'N', b'G']);
+        let mut table = TablePart::new().add_row(TableRowPart::new(vec![
+            TableCellPart::new("Feature"),
+            TableCellPart::new("").with_image(png).background("FF0000"),
+        ]));
+
+        // Unrelated pictures are left out rather than written with a dangling id
+        assert!(!table.to_slide_xml(2).contains("blipFill"));
+
+        let mut rels = Relationships::new();
+        rels.add(RelationshipType::SlideLayout, "../slideLayouts/slideLayout1.xml");
+        table.add_image_relationships(&mut rels);
+        assert_eq!(rels.get("rId2").unwrap().target, "../media/image3.png");
+
+        let xml = table.to_slide_xml(2);
+        assert!(xml.contains(r#"<a:blipFill><a:blip r:embed="rId2"/><a:stretch><a:fillRect/></a:stretch></a:blipFill></a:tcPr>"#));
+        assert!(!xml.contains("FF0000"));
+    }
+
+    #[test]
+    fn test_color_scale() {
+        let table = ["Score", "10", "n/a", "55", "10.0", "$1,00"].iter().fold(TablePart::new(), |table, score| {
+            table.add_row(TableRowPart::new(vec![TableCellPart::new("Team"), TableCellPart::new(*score)]))
+        });
+        let table = table.color_scale(1, "FFFFFF", "#FF0000");
+        let shades: Vec<Option<&str>> = table.rows.iter().map(|r| r.cells[1].background_color.as_deref()).collect();
+        assert_eq!(
+            shades,
+            vec![None, Some("FFFFFF"), None, Some("FF8080"), Some("FFFFFF"), Some("FF0000")]
+        );
+        assert!(table.rows.iter().all(|r| r.cells[0].background_color.is_none()));
+        assert!(table.to_slide_xml(1).contains(r#"<a:srgbClr val="FF8080"/>"#));
+
+        // Without a header every row is shaded; equal values take the low color
+        let flat = TablePart::new()
+            .header_row(false)
+            .add_row(TableRowPart::new(vec![TableCellPart::new("7%")]))
+            .add_row(TableRowPart::new(vec![TableCellPart::new("7%")]))
+            .color_scale(0, "00FF00", "FF0000");
+        assert!(flat.rows.iter().all(|r| r.cells[0].background_color.as_deref() == Some("00FF00")));
+
+        // A mistyped end color is kept on the cells and fails the table XML
+        let typo = flat.color_scale(0, "00FF0", "FF0000");
+        assert_eq!(typo.rows[0].cells[0].background_color.as_deref(), Some("00FF0"));
+        let err = typo.to_xml().unwrap_err();
+        assert!(err.to_string().contains(r#"Invalid table cell color: "00FF0""#), "{err}");
+    }
+
+    #[test]
+    fn test_color_scale_follows_spans() {
+        let table = TablePart::new()
+            .header_row(false)
+            .add_row(TableRowPart::new(vec![
+                TableCellPart::new("Wide").col_span(2),
+                TableCellPart::merged(),
+                TableCellPart::new("1"),
+            ]))
+            .add_row(TableRowPart::new(vec![TableCellPart::new("2"), TableCellPart::new("3"), TableCellPart::new("5")]))
+            .color_scale(2, "000000", "FFFFFF");
+        assert_eq!(table.rows[0].cells[2].background_color.as_deref(), Some("000000"));
+        assert_eq!(table.rows[1].cells[2].background_color.as_deref(), Some("FFFFFF"));
+        assert!(table.rows[0].cells[1].background_color.is_none());
+        assert!(table.rows[1].cells[1].background_color.is_none());
+
+        // A column covered by a span has no anchor to shade
+        let covered = TablePart::new()
+            .header_row(false)
+            .add_row(TableRowPart::new(vec![TableCellPart::new("7").col_span(2), TableCellPart::merged()]))
+            .color_scale(1, "000000", "FFFFFF");
+        assert!(covered.rows[0].cells.iter().all(|c| c.background_color.is_none()));
+    }
+
+    #[test]
+    fn test_split_across_slides() {
+        let header = TableRowPart::new(vec![TableCellPart::new("Name").bold(), TableCellPart::new("Score").bold()]);
+        let table = (1..=10).fold(TablePart::new().add_row(header).autofit_columns(), |table, i| {
+            table.add_row(TableRowPart::new(vec![
+                TableCellPart::new(format!("Player number {i}")),
+                TableCellPart::new(i.to_string()),
+            ]))
+        });
+
+        let chunks = table.split_across_slides(4);
+        assert_eq!(chunks.len(), 4);
+        assert_eq!(chunks.iter().map(|c| c.rows.len()).collect::<Vec<_>>(), vec![4, 4, 4, 2]);
+        for chunk in &chunks {
+            assert_eq!(chunk.rows[0].cells[0].text, "Name");
+            assert!(chunk.rows[0].cells[0].bold);
+            assert!(chunk.header_row);
+            assert_eq!(chunk.effective_col_widths(), table.effective_col_widths());
+        }
+        assert_eq!(chunks[1].rows[1].cells[0].text, "Player number 4");
+        assert_eq!(chunks[3].rows[1].cells[0].text, "Player number 10");
+
+        // Without a header row the count is all body rows
+        let plain = table.clone().header_row(false).split_across_slides(4);
+        assert_eq!(plain.len(), 3);
+        assert_eq!(plain[1].rows[0].cells[0].text, "Player number 4");
+
+        // A table that already fits is returned as is
+        assert_eq!(table.split_across_slides(20).len(), 1);
+    }
+
+    #[test]
+    fn test_split_cuts_row_spans_at_chunk_end() {
+        let table = TablePart::new()
+            .add_row(TableRowPart::new(vec![TableCellPart::new("Region")]))
+            .add_row(TableRowPart::new(vec![TableCellPart::new("North").row_span(3)]))
+            .add_row(TableRowPart::new(vec![TableCellPart::new("")]))
+            .add_row(TableRowPart::new(vec![TableCellPart::new("")]));
+        let chunks = table.split_across_slides(3);
+        assert_eq!(chunks[0].rows[1].cells[0].row_span, 2);
+    }
+
+    #[test]
+    fn test_table_cell_scheme_colors() {
+        use crate::elements::SchemeColor;
+
+        let cell = TableCellPart::new("Go").background(SchemeColor::Accent1).color("lt1");
+        assert_eq!(cell.background_color.as_deref(), Some("accent1"));
+        let xml = cell.to_xml();
+        assert!(xml.contains(r#"<a:solidFill><a:schemeClr val="accent1"/></a:solidFill>"#));
+        assert!(xml.contains(r#"<a:solidFill><a:schemeClr val="lt1"/></a:solidFill>"#));
     }
 }
@@ -27,7 +27,7 @@ pub use crate::generator::{
 
 pub use crate::generator::shapes::{GradientDirection, GradientFill, GradientStop};
 
-pub use crate::core::{Dimension, FlexPosition, FlexSize};
+pub use crate::core::{Dimension, FlexPosition, FlexSize, SlideSize, SlideSizePreset};
 pub use crate::elements::{Color, Position, RgbColor, Size};
 pub use crate::exc::Result;
 
@@ -1,7 +1,23 @@
 //! Converter from web content to PowerPoint
 
-use super::{Web2PptError, Result, Web2PptConfig, WebContent, ContentType};
-use crate::{create_pptx_with_content, SlideContent, SlideLayout};
+use super::{Web2PptError, Result, Web2PptConfig, WebContent, WebFetcher, ContentType};
+use crate::{create_pptx_with_content, Image, SlideContent, SlideLayout};
+use std::collections::HashMap;
+use std::time::Duration;
+
+/// Content area below the title `(x, y, width, height)` in EMU
+const CONTENT_AREA: (u32, u32, u32, u32) = (457200, 1600200, 8229600, 4525963);
+/// Gap between stacked images in EMU
+const IMAGE_GAP: u32 = 91440;
+/// Images that stack in the content area with at least half an inch each; any more become alt text
+const MAX_IMAGES_PER_SLIDE: usize = ((CONTENT_AREA.3 + IMAGE_GAP) / (457200 + IMAGE_GAP)) as usize;
+/// Characters that fit on one line of body text across the content area
+const CHARS_PER_LINE: usize = 70;
+
+/// Lines a bullet is expected to wrap to
+fn estimated_lines(text: &str) -> usize {
+    text.chars().count().div_ceil(CHARS_PER_LINE).max(1)
+}
 
 /// Safely truncate text at char boundary
 fn truncate_text(text: &str, max_len: usize) -> String {
@@ -36,6 +52,18 @@ pub struct ConversionOptions {
     pub include_source_url: bool,
     /// Add page numbers
     pub add_page_numbers: bool,
+    /// Images downloaded and embedded per slide, capped at the 8 that fit; extra images become alt-text bullets
+    pub max_images_per_slide: usize,
+    /// Timeout for each image download in seconds
+    pub image_timeout_secs: u64,
+    /// Estimated wrapped lines of text per slide before continuing on a "(cont.)" slide
+    pub max_lines_per_slide: usize,
+    /// Download images when converting HTML that is already in hand ([`html_to_pptx`],
+    /// [`Web2Ppt::convert`]); off by default, so those images become alt-text bullets.
+    ///
+    /// These downloads are blocking: each may take up to `image_timeout_secs`, and they
+    /// panic when called from inside an async runtime. URL conversions always download.
+    pub download_images: bool,
 }
 
 impl Default for ConversionOptions {
@@ -45,6 +73,10 @@ impl Default for ConversionOptions {
             author: None,
             include_source_url: true,
             add_page_numbers: false,
+            max_images_per_slide: 2,
+            image_timeout_secs: 10,
+            max_lines_per_slide: 12,
+            download_images: false,
         }
     }
 }
@@ -78,6 +110,131 @@ impl ConversionOptions {
         self.add_page_numbers = add;
         self
     }
+
+    /// Set how many images are embedded per slide (0 disables downloads); at most 8 fit on a slide
+    pub fn max_images_per_slide(mut self, max: usize) -> Self {
+        self.max_images_per_slide = max;
+        self
+    }
+
+    /// Set image download timeout
+    pub fn image_timeout(mut self, secs: u64) -> Self {
+        self.image_timeout_secs = secs;
+        self
+    }
+
+    /// Set how many wrapped lines of text fit on a slide
+    pub fn max_lines_per_slide(mut self, max: usize) -> Self {
+        self.max_lines_per_slide = max;
+        self
+    }
+
+    /// Download images when converting an HTML string (blocking; see [`ConversionOptions::download_images`])
+    pub fn download_images(mut self, download: bool) -> Self {
+        self.download_images = download;
+        self
+    }
+}
+
+/// Downloads page images, resolving relative sources against the page URL
+struct ImageLoader {
+    fetcher: Option<WebFetcher>,
+    /// Images already downloaded by URL; nothing else is fetched when set
+    prefetched: Option<HashMap<String, Image>>,
+    base: Option<url::Url>,
+    timeout: Duration,
+    max_per_slide: usize,
+}
+
+impl ImageLoader {
+    fn new(config: &Web2PptConfig, content: &WebContent, options: &ConversionOptions, download: bool) -> Self {
+        let enabled = download && config.include_images && options.max_images_per_slide > 0;
+        ImageLoader {
+            fetcher: enabled.then(|| WebFetcher::with_config(config.clone()).ok()).flatten(),
+            prefetched: None,
+            base: url::Url::parse(&content.url).ok(),
+            timeout: Duration::from_secs(options.image_timeout_secs),
+            max_per_slide: options.max_images_per_slide.min(MAX_IMAGES_PER_SLIDE),
+        }
+    }
+
+    /// Download every image on the page concurrently up front, so building slides never blocks
+    async fn prefetch(fetcher: &WebFetcher, content: &WebContent, options: &ConversionOptions) -> Self {
+        let mut loader = ImageLoader {
+            fetcher: None,
+            prefetched: Some(HashMap::new()),
+            base: url::Url::parse(&content.url).ok(),
+            timeout: Duration::from_secs(options.image_timeout_secs),
+            max_per_slide: options.max_images_per_slide.min(MAX_IMAGES_PER_SLIDE),
+        };
+        if !fetcher.config().include_images || options.max_images_per_slide == 0 {
+            return loader;
+        }
+
+        let mut urls: Vec<String> = content.images.iter()
+            .filter_map(|(src, _)| resolve_url(loader.base.as_ref(), src))
+            .map(String::from)
+            .collect();
+        urls.sort();
+        urls.dedup();
+        let downloads = futures::future::join_all(
+            urls.iter().map(|url| fetcher.fetch_image_async(url, loader.timeout)),
+        ).await;
+        loader.prefetched = Some(urls.into_iter()
+            .zip(downloads)
+            .filter_map(|(url, image)| Some((url, image.ok()?)))
+            .collect());
+        loader
+    }
+
+    /// Download `src` if the slide has room; `None` when it is full or the download fails
+    fn load(&self, src: &str, on_slide: usize) -> Option<Image> {
+        if on_slide >= self.max_per_slide {
+            return None;
+        }
+        let url = resolve_url(self.base.as_ref(), src)?;
+        if let Some(prefetched) = &self.prefetched {
+            return prefetched.get(url.as_str()).cloned();
+        }
+        let fetcher = self.fetcher.as_ref()?;
+        fetcher.fetch_image(url.as_str(), self.timeout).ok()
+    }
+}
+
+/// Resolve an image `src` that may be relative to the page
+fn resolve_url(base: Option<&url::Url>, src: &str) -> Option<url::Url> {
+    match url::Url::parse(src) {
+        Ok(url) => Some(url),
+        Err(url::ParseError::RelativeUrlWithoutBase) => base?.join(src).ok(),
+        Err(_) => None,
+    }
+}
+
+/// Stack images in the right half of the content area, or all of it when the slide has no text;
+/// images past [`MAX_IMAGES_PER_SLIDE`] are left off
+fn place_images(mut slide: SlideContent, mut images: Vec<Image>, has_text: bool) -> SlideContent {
+    images.truncate(MAX_IMAGES_PER_SLIDE);
+    if images.is_empty() {
+        return slide;
+    }
+    let (area_x, area_y, area_w, area_h) = CONTENT_AREA;
+    let (x, width) = if has_text {
+        (area_x + area_w / 2 + IMAGE_GAP, area_w / 2 - IMAGE_GAP)
+    } else {
+        (area_x, area_w)
+    };
+    let count = images.len() as u32;
+    let slot_h = (area_h - IMAGE_GAP * (count - 1)) / count;
+
+    for (i, mut image) in images.into_iter().enumerate() {
+        let scale = (width as f64 / image.width.max(1) as f64).min(slot_h as f64 / image.height.max(1) as f64);
+        image.width = (image.width as f64 * scale) as u32;
+        image.height = (image.height as f64 * scale) as u32;
+        image.x = x + (width - image.width) / 2;
+        image.y = area_y + (slot_h + IMAGE_GAP) * i as u32 + (slot_h - image.height) / 2;
+        slide = slide.add_image(image);
+    }
+    slide
 }
 
 /// Web to PowerPoint converter
@@ -97,8 +254,15 @@ impl Web2Ppt {
     }
 
     /// Convert web content to PowerPoint bytes
+    ///
+    /// Images are only downloaded when `options.download_images` is set.
     pub fn convert(&self, content: &WebContent, options: &ConversionOptions) -> Result<Vec<u8>> {
-        let slides = self.build_slides(content, options)?;
+        let images = ImageLoader::new(&self.config, content, options, options.download_images);
+        self.convert_with(content, options, &images)
+    }
+
+    fn convert_with(&self, content: &WebContent, options: &ConversionOptions, images: &ImageLoader) -> Result<Vec<u8>> {
+        let slides = self.build_slides_with(content, options, images)?;
         let title = options.title.as_ref().unwrap_or(&content.title);
 
         create_pptx_with_content(title, slides)
@@ -106,7 +270,13 @@ impl Web2Ppt {
     }
 
     /// Build slides from web content
+    #[cfg(test)]
     fn build_slides(&self, content: &WebContent, options: &ConversionOptions) -> Result<Vec<SlideContent>> {
+        let images = ImageLoader::new(&self.config, content, options, options.download_images);
+        self.build_slides_with(content, options, &images)
+    }
+
+    fn build_slides_with(&self, content: &WebContent, options: &ConversionOptions, images: &ImageLoader) -> Result<Vec<SlideContent>> {
         let mut slides = Vec::new();
 
         // Title slide
@@ -126,9 +296,9 @@ impl Web2Ppt {
 
         // Content slides
         if self.config.group_by_headings {
-            self.build_grouped_slides(content, &mut slides)?;
+            self.build_grouped_slides(content, options, images, &mut slides)?;
         } else {
-            self.build_linear_slides(content, &mut slides)?;
+            self.build_linear_slides(content, options, images, &mut slides)?;
         }
 
         // Limit slides
@@ -140,12 +310,12 @@ impl Web2Ppt {
     }
 
     /// Build slides grouped by headings
-    fn build_grouped_slides(&self, content: &WebContent, slides: &mut Vec<SlideContent>) -> Result<()> {
+    fn build_grouped_slides(&self, content: &WebContent, options: &ConversionOptions, images: &ImageLoader, slides: &mut Vec<SlideContent>) -> Result<()> {
         let groups = content.grouped_by_headings();
 
         // If no groups found, fall back to linear mode
         if groups.is_empty() {
-            return self.build_linear_slides(content, slides);
+            return self.build_linear_slides(content, options, images, slides);
         }
 
         for (heading, blocks) in groups {
@@ -157,67 +327,60 @@ impl Web2Ppt {
                 .layout(SlideLayout::TitleAndContent);
 
             let mut bullet_count = 0;
+            let mut line_count = 0;
+            let mut slide_images = Vec::new();
 
             for block in blocks {
-                if bullet_count >= self.config.max_bullets_per_slide {
+                let bullet = match &block.content_type {
+                    // Truncate long paragraphs - use char_indices for safe slicing
+                    ContentType::Paragraph => Some(truncate_text(&block.text, 200)),
+                    ContentType::ListItem => Some(format!("• {}", truncate_text(&block.text, 180))),
+                    ContentType::Quote => Some(format!("\"{}\"", truncate_text(&block.text, 180))),
+                    ContentType::Code if self.config.include_code => {
+                        Some(format!("[Code] {}", truncate_text(&block.text, 150)))
+                    }
+                    ContentType::Table(rows) if self.config.include_tables && !rows.is_empty() => {
+                        Some(format!("[Table: {} rows × {} cols]",
+                            rows.len(),
+                            rows.first().map(|r| r.len()).unwrap_or(0)
+                        ))
+                    }
+                    ContentType::Image { src, alt } => {
+                        if let Some(image) = images.load(src, slide_images.len()) {
+                            slide_images.push(image.with_alt_text(alt));
+                            None
+                        } else if self.config.include_images && !alt.is_empty() {
+                            Some(format!("[Image: {}]", alt))
+                        } else {
+                            None
+                        }
+                    }
+                    _ => None,
+                };
+                let Some(bullet) = bullet else { continue };
+
+                let lines = estimated_lines(&bullet);
+                if self.is_full(bullet_count, line_count + lines, options) {
                     // Start a new slide for overflow
-                    slides.push(slide);
+                    slides.push(place_images(slide, std::mem::take(&mut slide_images), true));
                     slide = SlideContent::new(&format!("{} (cont.)", heading.text))
                         .layout(SlideLayout::TitleAndContent);
                     bullet_count = 0;
+                    line_count = 0;
 
                     if slides.len() >= self.config.max_slides {
                         break;
                     }
                 }
 
-                match &block.content_type {
-                    ContentType::Paragraph => {
-                        // Truncate long paragraphs - use char_indices for safe slicing
-                        let text = truncate_text(&block.text, 200);
-                        slide = slide.add_bullet(&text);
-                        bullet_count += 1;
-                    }
-                    ContentType::ListItem => {
-                        let text = truncate_text(&block.text, 180);
-                        slide = slide.add_bullet(&format!("• {}", text));
-                        bullet_count += 1;
-                    }
-                    ContentType::Quote => {
-                        let text = truncate_text(&block.text, 180);
-                        slide = slide.add_bullet(&format!("\"{}\"", text));
-                        bullet_count += 1;
-                    }
-                    ContentType::Code => {
-                        if self.config.include_code {
-                            let code = truncate_text(&block.text, 150);
-                            slide = slide.add_bullet(&format!("[Code] {}", code));
-                            bullet_count += 1;
-                        }
-                    }
-                    ContentType::Table(rows) => {
-                        if self.config.include_tables && !rows.is_empty() {
-                            let summary = format!("[Table: {} rows × {} cols]", 
-                                rows.len(), 
-                                rows.first().map(|r| r.len()).unwrap_or(0)
-                            );
-                            slide = slide.add_bullet(&summary);
-                            bullet_count += 1;
-                        }
-                    }
-                    ContentType::Image { alt, .. } => {
-                        if self.config.include_images && !alt.is_empty() {
-                            slide = slide.add_bullet(&format!("[Image: {}]", alt));
-                            bullet_count += 1;
-                        }
-                    }
-                    _ => {}
-                }
+                slide = slide.add_bullet(&bullet);
+                bullet_count += 1;
+                line_count += lines;
             }
 
             // Only add slide if it has content
-            if bullet_count > 0 {
-                slides.push(slide);
+            if bullet_count > 0 || !slide_images.is_empty() {
+                slides.push(place_images(slide, slide_images, bullet_count > 0));
             }
         }
 
@@ -225,9 +388,11 @@ impl Web2Ppt {
     }
 
     /// Build slides linearly (not grouped)
-    fn build_linear_slides(&self, content: &WebContent, slides: &mut Vec<SlideContent>) -> Result<()> {
+    fn build_linear_slides(&self, content: &WebContent, options: &ConversionOptions, images: &ImageLoader, slides: &mut Vec<SlideContent>) -> Result<()> {
         let mut current_slide: Option<SlideContent> = None;
         let mut bullet_count = 0;
+        let mut line_count = 0;
+        let mut slide_images = Vec::new();
 
         // If no content blocks, create a slide with description
         if content.blocks.is_empty() {
@@ -249,8 +414,8 @@ impl Web2Ppt {
                 ContentType::Title | ContentType::Heading(_) => {
                     // Save current slide if it has content
                     if let Some(slide) = current_slide.take() {
-                        if bullet_count > 0 {
-                            slides.push(slide);
+                        if bullet_count > 0 || !slide_images.is_empty() {
+                            slides.push(place_images(slide, std::mem::take(&mut slide_images), bullet_count > 0));
                         }
                     }
 
@@ -260,6 +425,7 @@ impl Web2Ppt {
                             .layout(SlideLayout::TitleAndContent)
                     );
                     bullet_count = 0;
+                    line_count = 0;
                 }
                 ContentType::Paragraph => {
                     // If no current slide, create one
@@ -271,20 +437,20 @@ impl Web2Ppt {
                     }
                     
                     if let Some(ref mut slide) = current_slide {
-                        if bullet_count < self.config.max_bullets_per_slide {
-                            let text = truncate_text(&block.text, 200);
-                            *slide = slide.clone().add_bullet(&text);
-                            bullet_count += 1;
-                        } else {
+                        let text = truncate_text(&block.text, 200);
+                        let lines = estimated_lines(&text);
+                        if self.is_full(bullet_count, line_count + lines, options) {
                             // Start new continuation slide
-                            slides.push(slide.clone());
-                            let title = slide.title.clone();
+                            slides.push(place_images(slide.clone(), std::mem::take(&mut slide_images), true));
+                            let title = slide.title.trim_end_matches(" (cont.)").to_string();
                             *slide = SlideContent::new(&format!("{} (cont.)", title))
                                 .layout(SlideLayout::TitleAndContent);
-                            let text = truncate_text(&block.text, 200);
-                            *slide = slide.clone().add_bullet(&text);
-                            bullet_count = 1;
+                            bullet_count = 0;
+                            line_count = 0;
                         }
+                        *slide = slide.clone().add_bullet(&text);
+                        bullet_count += 1;
+                        line_count += lines;
                     }
                 }
                 ContentType::ListItem => {
@@ -296,19 +462,50 @@ impl Web2Ppt {
                     }
                     
                     if let Some(ref mut slide) = current_slide {
-                        if bullet_count < self.config.max_bullets_per_slide {
-                            let text = truncate_text(&block.text, 180);
-                            *slide = slide.clone().add_bullet(&format!("• {}", text));
+                        let text = format!("• {}", truncate_text(&block.text, 180));
+                        let lines = estimated_lines(&text);
+                        if !self.is_full(bullet_count, line_count + lines, options) {
+                            *slide = slide.clone().add_bullet(&text);
                             bullet_count += 1;
+                            line_count += lines;
                         }
                     }
                 }
                 ContentType::Quote => {
                     if let Some(ref mut slide) = current_slide {
-                        if bullet_count < self.config.max_bullets_per_slide {
-                            let text = truncate_text(&block.text, 180);
-                            *slide = slide.clone().add_bullet(&format!("\"{}\"", text));
+                        let text = format!("\"{}\"", truncate_text(&block.text, 180));
+                        let lines = estimated_lines(&text);
+                        if !self.is_full(bullet_count, line_count + lines, options) {
+                            *slide = slide.clone().add_bullet(&text);
                             bullet_count += 1;
+                            line_count += lines;
+                        }
+                    }
+                }
+                ContentType::Image { src, alt } => {
+                    let image = images.load(src, slide_images.len());
+                    let fallback = image.is_none() && self.config.include_images && !alt.is_empty();
+                    if image.is_none() && !fallback {
+                        continue;
+                    }
+                    if current_slide.is_none() {
+                        current_slide = Some(
+                            SlideContent::new("Overview")
+                                .layout(SlideLayout::TitleAndContent)
+                        );
+                    }
+
+                    if let Some(ref mut slide) = current_slide {
+                        if let Some(image) = image {
+                            slide_images.push(image.with_alt_text(alt));
+                        } else {
+                            let text = format!("[Image: {}]", alt);
+                            let lines = estimated_lines(&text);
+                            if !self.is_full(bullet_count, line_count + lines, options) {
+                                *slide = slide.clone().add_bullet(&text);
+                                bullet_count += 1;
+                                line_count += lines;
+                            }
                         }
                     }
                 }
@@ -318,14 +515,19 @@ impl Web2Ppt {
 
         // Save last slide
         if let Some(slide) = current_slide {
-            if bullet_count > 0 {
-                slides.push(slide);
+            if bullet_count > 0 || !slide_images.is_empty() {
+                slides.push(place_images(slide, slide_images, bullet_count > 0));
             }
         }
 
         Ok(())
     }
 
+    /// Whether a slide with `bullets` already on it can't take more, given the lines it would then fill
+    fn is_full(&self, bullets: usize, lines: usize, options: &ConversionOptions) -> bool {
+        bullets > 0 && (bullets >= self.config.max_bullets_per_slide || lines > options.max_lines_per_slide)
+    }
+
     /// Get config
     pub fn config(&self) -> &Web2PptConfig {
         &self.config
@@ -361,17 +563,77 @@ pub fn url_to_pptx_with_options(
     let parser = WebParser::with_config(config.clone());
     let content = parser.parse(&html, url)?;
 
-    // Convert
-    let converter = Web2Ppt::with_config(config);
-    converter.convert(&content, &options)
+    // Convert, downloading images as the slides are built
+    let images = ImageLoader::new(&config, &content, &options, true);
+    Web2Ppt::with_config(config).convert_with(&content, &options, &images)
+}
+
+/// Convert a URL to PPTX bytes without blocking the async runtime
+///
+/// The page and its images are downloaded asynchronously; parsing and generation run inline.
+#[cfg(feature = "web2ppt")]
+pub async fn url_to_pptx_async(url: &str) -> Result<Vec<u8>> {
+    url_to_pptx_async_with_options(url, Web2PptConfig::default(), ConversionOptions::default()).await
+}
+
+/// Convert a URL to PPTX bytes with options without blocking the async runtime
+#[cfg(feature = "web2ppt")]
+pub async fn url_to_pptx_async_with_options(
+    url: &str,
+    config: Web2PptConfig,
+    options: ConversionOptions,
+) -> Result<Vec<u8>> {
+    let fetcher = WebFetcher::with_config(config.clone())?;
+    url_to_pptx_async_with_fetcher(&fetcher, url, options).await
+}
+
+/// Convert a URL to PPTX bytes using a shared fetcher
+///
+/// Converting several pages with one fetcher reuses its pooled connections:
+///
+/// ```no_run
+/// # async fn run() -> ppt_rs::web2ppt::Result<()> {
+/// use ppt_rs::web2ppt::{url_to_pptx_async_with_fetcher, ConversionOptions, WebFetcher};
+///
+/// let fetcher = WebFetcher::new()?;
+/// let decks = futures::future::join_all(["https://a.example", "https://b.example"].iter().map(|url| {
+///     url_to_pptx_async_with_fetcher(&fetcher, url, ConversionOptions::default())
+/// })).await;
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(feature = "web2ppt")]
+pub async fn url_to_pptx_async_with_fetcher(
+    fetcher: &WebFetcher,
+    url: &str,
+    options: ConversionOptions,
+) -> Result<Vec<u8>> {
+    use super::WebParser;
+
+    // Fetch
+    let html = fetcher.fetch_async(url).await?;
+
+    // Parse
+    let config = fetcher.config().clone();
+    let content = WebParser::with_config(config.clone()).parse(&html, url)?;
+
+    // Convert, with images downloaded ahead of time
+    let images = ImageLoader::prefetch(fetcher, &content, &options).await;
+    Web2Ppt::with_config(config).convert_with(&content, &options, &images)
 }
 
 /// Convert HTML string to PPTX bytes
+///
+/// Nothing is downloaded: images become alt-text bullets. To embed them, use
+/// [`html_to_pptx_with_options`] with [`ConversionOptions::download_images`].
 pub fn html_to_pptx(html: &str, url: &str) -> Result<Vec<u8>> {
     html_to_pptx_with_options(html, url, Web2PptConfig::default(), ConversionOptions::default())
 }
 
 /// Convert HTML string to PPTX bytes with options
+///
+/// Images are only downloaded when `options.download_images` is set; those downloads
+/// block the calling thread and must not run inside an async runtime.
 pub fn html_to_pptx_with_options(
     html: &str,
     url: &str,
@@ -441,4 +703,202 @@ mod tests {
         assert_eq!(converter.config().max_slides, 5);
         assert_eq!(converter.config().max_bullets_per_slide, 3);
     }
+
+    /// Page served at `/page.html` by [`serve_images`]
+    const PAGE: &str = r#"<html><head><title>Async</title></head><body>
+        <h2>Overview</h2>
+        <p>Pages and their images are downloaded without blocking the runtime.</p>
+        <img src="/logo.png" alt="Logo">
+    </body></html>"#;
+
+    /// Serve `/logo.png` and `/page.html` once per request on a local port; everything else is a 404
+    fn serve_images(requests: usize) -> String {
+        use std::io::{Read, Write};
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let base = format!("http://{}", listener.local_addr().unwrap());
+        std::thread::spawn(move || {
+            // PNG signature plus an IHDR for a 200x100 image
+            let mut png = vec![0x89, 0x50, 0x4E, 0x47, 0x0D, 0x0A, 0x1A, 0x0A, 0, 0, 0, 13];
+            png.extend_from_slice(b"IHDR");
+            png.extend_from_slice(&200u32.to_be_bytes());
+            png.extend_from_slice(&100u32.to_be_bytes());
+            png.extend_from_slice(&[8, 2, 0, 0, 0, 0, 0, 0, 0]);
+
+            for stream in listener.incoming().take(requests) {
+                let mut stream = stream.unwrap();
+                let mut request = [0u8; 1024];
+                let n = stream.read(&mut request).unwrap();
+                let request = String::from_utf8_lossy(&request[..n]);
+                if request.starts_with("GET /logo.png ") {
+                    let header = format!("HTTP/1.1 200 OK\r\nContent-Type: image/png\r\nContent-Length: {}\r\nConnection: close\r\n\r\n", png.len());
+                    stream.write_all(header.as_bytes()).unwrap();
+                    stream.write_all(&png).unwrap();
+                } else if request.starts_with("GET /page.html ") {
+                    let header = format!("HTTP/1.1 200 OK\r\nContent-Type: text/html\r\nContent-Length: {}\r\nConnection: close\r\n\r\n", PAGE.len());
+                    stream.write_all(header.as_bytes()).unwrap();
+                    stream.write_all(PAGE.as_bytes()).unwrap();
+                } else {
+                    stream.write_all(b"HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n").unwrap();
+                }
+            }
+        });
+        base
+    }
+
+    #[test]
+    fn test_resolve_relative_image_url() {
+        let base = url::Url::parse("https://example.com/blog/post.html").ok();
+        assert_eq!(resolve_url(base.as_ref(), "img/a.png").unwrap().as_str(), "https://example.com/blog/img/a.png");
+        assert_eq!(resolve_url(base.as_ref(), "/a.png").unwrap().as_str(), "https://example.com/a.png");
+        assert_eq!(resolve_url(base.as_ref(), "https://cdn.example.com/a.png").unwrap().as_str(), "https://cdn.example.com/a.png");
+        assert!(resolve_url(None, "a.png").is_none());
+    }
+
+    #[test]
+    fn test_images_embedded_under_heading() {
+        let base = serve_images(2);
+        let html = r#"
+            <html><head><title>Images</title></head><body>
+                <h2>Architecture</h2>
+                <p>The system is split into services that communicate over a message bus.</p>
+                <img src="/logo.png" alt="Diagram">
+                <img src="/missing.png" alt="Broken chart">
+            </body></html>
+        "#;
+        let content = super::super::WebParser::new().parse(html, &format!("{base}/docs/page.html")).unwrap();
+        let slides = Web2Ppt::new()
+            .build_slides(&content, &ConversionOptions::new().image_timeout(5).download_images(true))
+            .unwrap();
+
+        let slide = slides.iter().find(|s| s.title == "Architecture").unwrap();
+        assert_eq!(slide.images.len(), 1);
+        let image = &slide.images[0];
+        assert_eq!(image.format, "PNG");
+        assert_eq!(image.alt_text.as_deref(), Some("Diagram"));
+        // Placed in the right half of the content area, keeping the 2:1 aspect ratio
+        assert!(image.x >= CONTENT_AREA.0 + CONTENT_AREA.2 / 2);
+        assert_eq!(image.width / 1000, image.height * 2 / 1000);
+        // The failed download is kept as its alt text
+        assert!(slide.bullets.iter().any(|b| b.text == "[Image: Broken chart]"));
+    }
+
+    #[test]
+    fn test_image_limit_per_slide() {
+        let base = serve_images(1);
+        let html = r#"
+            <html><head><title>Release Notes</title></head><body>
+                <h2>Gallery</h2>
+                <p>Screenshots from the latest release of the dashboard and reports.</p>
+                <img src="/logo.png" alt="First">
+                <img src="/logo.png" alt="Second">
+            </body></html>
+        "#;
+        let content = super::super::WebParser::new().parse(html, &base).unwrap();
+        let slides = Web2Ppt::new()
+            .build_slides(&content, &ConversionOptions::new().max_images_per_slide(1).download_images(true))
+            .unwrap();
+
+        let slide = slides.iter().find(|s| s.title == "Gallery").unwrap();
+        assert_eq!(slide.images.len(), 1);
+        assert!(slide.bullets.iter().any(|b| b.text == "[Image: Second]"));
+    }
+
+    #[test]
+    fn test_linear_slides_keep_alt_text_for_skipped_images() {
+        let html = r#"
+            <html><head><title>Release Notes</title></head><body>
+                <p>Screenshots from the latest release of the dashboard, the reports view and the settings pages.</p>
+                <img src="/logo.png" alt="Dashboard">
+            </body></html>
+        "#;
+        let content = super::super::WebParser::new().parse(html, "https://example.com").unwrap();
+        let slides = Web2Ppt::with_config(Web2PptConfig::new().group_by_headings(false))
+            .build_slides(&content, &ConversionOptions::new().max_images_per_slide(0))
+            .unwrap();
+
+        assert!(slides.iter().all(|s| s.images.is_empty()));
+        assert!(slides.iter().flat_map(|s| &s.bullets).any(|b| b.text == "[Image: Dashboard]"));
+    }
+
+    #[test]
+    fn test_image_limit_is_capped_to_what_fits() {
+        assert_eq!(MAX_IMAGES_PER_SLIDE, 8);
+        let images = (0..60).map(|_| Image::from_bytes(vec![0; 8], 200, 100, "PNG")).collect();
+        let slide = place_images(SlideContent::new("Gallery"), images, false);
+        assert_eq!(slide.images.len(), MAX_IMAGES_PER_SLIDE);
+        let last = slide.images.last().unwrap();
+        assert!(last.y + last.height <= CONTENT_AREA.1 + CONTENT_AREA.3);
+
+        let html = r#"
+            <html><head><title>Gallery</title></head><body>
+                <p>Screenshots from the latest release of the dashboard, the reports view and the settings pages.</p>
+            </body></html>
+        "#;
+        let content = super::super::WebParser::new().parse(html, "https://example.com").unwrap();
+        let options = ConversionOptions::new().max_images_per_slide(60);
+        let loader = ImageLoader::new(&Web2PptConfig::default(), &content, &options, true);
+        assert_eq!(loader.max_per_slide, MAX_IMAGES_PER_SLIDE);
+    }
+
+    #[test]
+    fn test_html_conversion_downloads_only_when_asked() {
+        let html = r#"
+            <html><head><title>Gallery</title></head><body>
+                <p>Screenshots from the latest release of the dashboard, the reports view and the settings pages.</p>
+                <img src="/logo.png" alt="Dashboard">
+            </body></html>
+        "#;
+        let content = super::super::WebParser::new().parse(html, "https://example.com").unwrap();
+        let config = Web2PptConfig::default();
+        let options = ConversionOptions::default();
+        assert!(!options.download_images);
+        assert!(ImageLoader::new(&config, &content, &options, options.download_images).fetcher.is_none());
+
+        let options = options.download_images(true);
+        assert!(ImageLoader::new(&config, &content, &options, options.download_images).fetcher.is_some());
+
+        let slides = Web2Ppt::new().build_slides(&content, &ConversionOptions::default()).unwrap();
+        assert!(slides.iter().flat_map(|s| &s.bullets).any(|b| b.text == "[Image: Dashboard]"));
+    }
+
+    #[tokio::test]
+    async fn test_async_conversions_share_fetcher() {
+        // Two pages and one image for each
+        let base = serve_images(4);
+        let url = format!("{base}/page.html");
+        let fetcher = WebFetcher::new().unwrap();
+        let decks = futures::future::join_all((0..2).map(|_| {
+            url_to_pptx_async_with_fetcher(&fetcher, &url, ConversionOptions::new().image_timeout(5))
+        })).await;
+
+        for deck in decks {
+            let bytes = deck.unwrap();
+            assert!(bytes.starts_with(b"PK"));
+            assert!(bytes.windows(b"ppt/media/image".len()).any(|w| w == b"ppt/media/image"));
+        }
+    }
+
+    #[test]
+    fn test_long_section_continues_across_slides() {
+        let paragraph = "Long-form articles keep elaborating on the same point for many sentences, which would overflow a single slide. ".repeat(4);
+        let html = format!(
+            "<html><head><title>Article</title></head><body><h2>Background</h2>{}</body></html>",
+            format!("<p>{paragraph}</p>").repeat(24)
+        );
+        let content = super::super::WebParser::new().parse(&html, "https://example.com/article").unwrap();
+        let slides = Web2Ppt::new()
+            .build_slides(&content, &ConversionOptions::new().max_lines_per_slide(9))
+            .unwrap();
+
+        // Each truncated paragraph wraps to 3 lines, so three fit on a slide
+        let section: Vec<_> = slides.iter().skip(1).collect();
+        assert_eq!(section.len(), 8);
+        assert_eq!(section[0].title, "Background");
+        assert!(section[1..].iter().all(|s| s.title == "Background (cont.)"));
+        for slide in &section {
+            let lines: usize = slide.bullets.iter().map(|b| estimated_lines(&b.text)).sum();
+            assert!(lines <= 9, "{} lines on {}", lines, slide.title);
+        }
+    }
 }
@@ -1,13 +1,25 @@
 //! Web page fetcher for Web2PPT
 
 use super::{Web2PptError, Result, Web2PptConfig};
+use crate::generator::Image;
 use reqwest::blocking::Client;
-use reqwest::header::{HeaderMap, HeaderValue, ACCEPT, ACCEPT_LANGUAGE, CACHE_CONTROL};
+use reqwest::header::{HeaderMap, HeaderValue, ACCEPT, ACCEPT_LANGUAGE, CACHE_CONTROL, CONTENT_TYPE};
+use reqwest::StatusCode;
+use std::sync::OnceLock;
 use std::time::Duration;
 
+/// Accept header for image downloads
+const IMAGE_ACCEPT: &str = "image/avif,image/webp,image/png,image/*;q=0.8,*/*;q=0.5";
+
 /// Fetches web pages
+///
+/// Clones share their connection pools, so one fetcher can serve many concurrent
+/// `fetch_async` calls.
+#[derive(Clone)]
 pub struct WebFetcher {
-    client: Client,
+    /// Built on first blocking use, since a blocking client can't be dropped inside an async runtime
+    client: OnceLock<Client>,
+    async_client: reqwest::Client,
     config: Web2PptConfig,
 }
 
@@ -19,38 +31,32 @@ impl WebFetcher {
 
     /// Create a new web fetcher with custom config
     pub fn with_config(config: Web2PptConfig) -> Result<Self> {
-        // Build headers to look like a real browser
-        let mut headers = HeaderMap::new();
-        headers.insert(ACCEPT, HeaderValue::from_static("text/html,application/xhtml+xml,application/xml;q=0.9,image/webp,*/*;q=0.8"));
-        headers.insert(ACCEPT_LANGUAGE, HeaderValue::from_static("en-US,en;q=0.5"));
-        headers.insert(CACHE_CONTROL, HeaderValue::from_static("no-cache"));
-        
-        let client = Client::builder()
-            .user_agent(&config.user_agent)
-            .timeout(Duration::from_secs(config.timeout_secs))
-            .default_headers(headers)
-            .redirect(reqwest::redirect::Policy::limited(10))
+        let async_client = client_builder(&config)
             .build()
             .map_err(|e| Web2PptError::FetchError(e.to_string()))?;
 
-        Ok(WebFetcher { client, config })
+        Ok(WebFetcher { client: OnceLock::new(), async_client, config })
+    }
+
+    /// The blocking client, built with the same settings as the async one
+    fn client(&self) -> Result<&Client> {
+        if let Some(client) = self.client.get() {
+            return Ok(client);
+        }
+        // The blocking builder keeps its own timeout on top of the wrapped async one
+        let client = reqwest::blocking::ClientBuilder::from(client_builder(&self.config))
+            .timeout(Duration::from_secs(self.config.timeout_secs))
+            .build()
+            .map_err(|e| Web2PptError::FetchError(e.to_string()))?;
+        Ok(self.client.get_or_init(|| client))
     }
 
     /// Fetch HTML content from a URL
     pub fn fetch(&self, url: &str) -> Result<String> {
-        // Validate URL
-        let parsed_url = url::Url::parse(url)
-            .map_err(|e| Web2PptError::InvalidUrl(e.to_string()))?;
-
-        // Only allow http/https
-        if parsed_url.scheme() != "http" && parsed_url.scheme() != "https" {
-            return Err(Web2PptError::InvalidUrl(
-                "Only HTTP and HTTPS URLs are supported".to_string()
-            ));
-        }
+        check_url(url)?;
 
         // Fetch the page with referer header
-        let response = self.client
+        let response = self.client()?
             .get(url)
             .header("Referer", url)
             .send()
@@ -68,6 +74,66 @@ impl WebFetcher {
             .map_err(|e| Web2PptError::FetchError(e.to_string()))
     }
 
+    /// Fetch HTML content from a URL without blocking the async runtime
+    pub async fn fetch_async(&self, url: &str) -> Result<String> {
+        check_url(url)?;
+
+        let response = self.async_client
+            .get(url)
+            .header("Referer", url)
+            .send()
+            .await
+            .map_err(|e| Web2PptError::FetchError(e.to_string()))?;
+
+        if !response.status().is_success() {
+            return Err(Web2PptError::FetchError(
+                format!("HTTP {}: {}", response.status().as_u16(), response.status().as_str())
+            ));
+        }
+
+        response.text()
+            .await
+            .map_err(|e| Web2PptError::FetchError(e.to_string()))
+    }
+
+    /// Download an image for embedding, giving up after `timeout`
+    pub fn fetch_image(&self, url: &str, timeout: Duration) -> Result<Image> {
+        check_url(url)?;
+
+        let response = self.client()?
+            .get(url)
+            .header(ACCEPT, IMAGE_ACCEPT)
+            .timeout(timeout)
+            .send()
+            .map_err(|e| Web2PptError::FetchError(e.to_string()))?;
+        let status = response.status();
+        let content_type = content_type(response.headers());
+        let data = response.bytes()
+            .map_err(|e| Web2PptError::FetchError(e.to_string()))?;
+
+        downloaded_image(url, status, content_type.as_deref(), &data)
+    }
+
+    /// Download an image for embedding without blocking the async runtime
+    pub async fn fetch_image_async(&self, url: &str, timeout: Duration) -> Result<Image> {
+        check_url(url)?;
+
+        let response = self.async_client
+            .get(url)
+            .header(ACCEPT, IMAGE_ACCEPT)
+            .timeout(timeout)
+            .send()
+            .await
+            .map_err(|e| Web2PptError::FetchError(e.to_string()))?;
+        let status = response.status();
+        let content_type = content_type(response.headers());
+        let data = response.bytes()
+            .await
+            .map_err(|e| Web2PptError::FetchError(e.to_string()))?;
+
+        downloaded_image(url, status, content_type.as_deref(), &data)
+    }
+
     /// Fetch and return both URL and HTML
     pub fn fetch_with_url(&self, url: &str) -> Result<(String, String)> {
         let html = self.fetch(url)?;
@@ -80,6 +146,55 @@ impl WebFetcher {
     }
 }
 
+/// Client settings shared by the async and blocking clients
+fn client_builder(config: &Web2PptConfig) -> reqwest::ClientBuilder {
+    reqwest::Client::builder()
+        .user_agent(&config.user_agent)
+        .timeout(Duration::from_secs(config.timeout_secs))
+        .default_headers(browser_headers())
+        .redirect(reqwest::redirect::Policy::limited(10))
+}
+
+/// The Content-Type header, if it is readable
+fn content_type(headers: &HeaderMap) -> Option<String> {
+    headers.get(CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .map(str::to_string)
+}
+
+/// Turn a finished image download into an [`Image`], rejecting failed responses
+fn downloaded_image(url: &str, status: StatusCode, content_type: Option<&str>, data: &[u8]) -> Result<Image> {
+    if !status.is_success() {
+        return Err(Web2PptError::FetchError(
+            format!("HTTP {}: {}", status.as_u16(), url)
+        ));
+    }
+
+    Image::from_downloaded(url, data.to_vec(), content_type)
+        .map_err(|e| Web2PptError::FetchError(e.to_string()))
+}
+
+/// Headers that make requests look like they come from a real browser
+fn browser_headers() -> HeaderMap {
+    let mut headers = HeaderMap::new();
+    headers.insert(ACCEPT, HeaderValue::from_static("text/html,application/xhtml+xml,application/xml;q=0.9,image/webp,*/*;q=0.8"));
+    headers.insert(ACCEPT_LANGUAGE, HeaderValue::from_static("en-US,en;q=0.5"));
+    headers.insert(CACHE_CONTROL, HeaderValue::from_static("no-cache"));
+    headers
+}
+
+/// Only absolute HTTP and HTTPS URLs can be fetched
+fn check_url(url: &str) -> Result<()> {
+    let parsed_url = url::Url::parse(url)
+        .map_err(|e| Web2PptError::InvalidUrl(e.to_string()))?;
+    if parsed_url.scheme() != "http" && parsed_url.scheme() != "https" {
+        return Err(Web2PptError::InvalidUrl(
+            "Only HTTP and HTTPS URLs are supported".to_string()
+        ));
+    }
+    Ok(())
+}
+
 impl Default for WebFetcher {
     fn default() -> Self {
         Self::new().expect("Failed to create default WebFetcher")
@@ -104,6 +219,26 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_fetch_image_rejects_non_http() {
+        let fetcher = WebFetcher::new().unwrap();
+        let result = fetcher.fetch_image("file:///etc/passwd", Duration::from_secs(1));
+        assert!(matches!(result, Err(Web2PptError::InvalidUrl(_))));
+    }
+
+    #[test]
+    fn test_downloaded_image_rejects_failed_status() {
+        let result = downloaded_image("https://example.com/a.png", StatusCode::NOT_FOUND, Some("image/png"), b"");
+        assert!(matches!(result, Err(Web2PptError::FetchError(ref e)) if e == "HTTP 404: https://example.com/a.png"));
+    }
+
+    #[tokio::test]
+    async fn test_fetch_async_rejects_non_http() {
+        let fetcher = WebFetcher::new().unwrap();
+        let result = fetcher.fetch_async("ftp://example.com").await;
+        assert!(matches!(result, Err(Web2PptError::InvalidUrl(_))));
+    }
+
     #[test]
     fn test_config() {
         let config = Web2PptConfig::new().timeout(60);
@@ -17,7 +17,7 @@ pub use fetcher::WebFetcher;
 #[cfg(feature = "web2ppt")]
 pub use parser::{WebParser, WebContent, ContentBlock, ContentType};
 #[cfg(feature = "web2ppt")]
-pub use converter::{Web2Ppt, ConversionOptions, url_to_pptx, url_to_pptx_with_options, html_to_pptx, html_to_pptx_with_options};
+pub use converter::{Web2Ppt, ConversionOptions, url_to_pptx, url_to_pptx_with_options, url_to_pptx_async, url_to_pptx_async_with_options, url_to_pptx_async_with_fetcher, html_to_pptx, html_to_pptx_with_options};
 #[cfg(feature = "web2ppt")]
 pub use config::Web2PptConfig;
 
@@ -2,7 +2,7 @@
 
 use ppt_rs::api::Presentation;
 use ppt_rs::opc::compress::{CompressionOptions, CompressionLevel, compress_pptx};
-use ppt_rs::generator::SlideContent;
+use ppt_rs::generator::{Compression, Image, SlideContent};
 use std::fs;
 use std::path::Path;
 
@@ -289,3 +289,48 @@ fn test_compression_multiple_slides() {
     fs::remove_file(input_path).unwrap();
     fs::remove_file(output_path).unwrap();
 }
+
+fn entry_methods(bytes: &[u8]) -> Vec<(String, zip::CompressionMethod)> {
+    let mut archive = zip::ZipArchive::new(std::io::Cursor::new(bytes)).unwrap();
+    (0..archive.len())
+        .map(|i| {
+            let entry = archive.by_index(i).unwrap();
+            (entry.name().to_string(), entry.compression())
+        })
+        .collect()
+}
+
+fn photo_deck() -> Presentation {
+    let png = include_bytes!("../examples/assets/diagram.png").to_vec();
+    Presentation::with_title("Zip Settings")
+        .add_slide(SlideContent::new("Photo").add_image(Image::from_bytes(png, 914_400, 914_400, "PNG")))
+        .add_slide(SlideContent::new("Text").add_bullet("Point"))
+}
+
+#[test]
+fn test_default_package_compression_deflates_every_part() {
+    let methods = entry_methods(&photo_deck().build().unwrap());
+    assert!(methods.iter().any(|(name, _)| name == "ppt/media/image1.png"));
+    assert!(methods.iter().all(|(_, method)| *method == zip::CompressionMethod::Deflated));
+}
+
+#[test]
+fn test_media_can_be_stored_while_xml_is_deflated() {
+    let bytes = photo_deck()
+        .compression(Compression::Best)
+        .media_compression(Compression::Stored)
+        .build()
+        .unwrap();
+    for (name, method) in entry_methods(&bytes) {
+        let expected = if name.starts_with("ppt/media/") {
+            zip::CompressionMethod::Stored
+        } else {
+            zip::CompressionMethod::Deflated
+        };
+        assert_eq!(method, expected, "{name}");
+    }
+
+    let stored = photo_deck().compression(Compression::Stored).build().unwrap();
+    assert!(entry_methods(&stored).iter().all(|(_, method)| *method == zip::CompressionMethod::Stored));
+    assert!(stored.len() > bytes.len());
+}
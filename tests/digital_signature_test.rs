@@ -0,0 +1,141 @@
+//! Integration tests for signing presentations with `Presentation::sign`
+
+mod common;
+
+use std::io::{Cursor, Read};
+
+use ppt_rs::generator::{DigitalSignature, HashAlgorithm, PresentationSettings, SignerInfo};
+use ppt_rs::generator::{SignatureCommitment, SlideContent};
+use ppt_rs::{Presentation, PptxError};
+use zip::ZipArchive;
+
+const CERT_PEM: &[u8] = include_bytes!("fixtures/signing/signer.pem");
+const CERT_DER: &[u8] = include_bytes!("fixtures/signing/signer.cer");
+const KEY_PKCS8_PEM: &[u8] = include_bytes!("fixtures/signing/signer.key");
+const KEY_PKCS1_DER: &[u8] = include_bytes!("fixtures/signing/signer-pkcs1.der");
+
+fn deck() -> Presentation {
+    Presentation::with_title("Official")
+        .add_slide(SlideContent::new("Results").add_bullet("Revenue up").notes("Read aloud"))
+        .add_slide(SlideContent::new("Outlook"))
+}
+
+fn read_part(bytes: &[u8], name: &str) -> String {
+    let mut archive = ZipArchive::new(Cursor::new(bytes)).unwrap();
+    let mut content = String::new();
+    archive.by_name(name).unwrap().read_to_string(&mut content).unwrap();
+    content
+}
+
+#[test]
+fn signed_deck_has_signature_parts() {
+    let bytes = deck()
+        .sign(CERT_PEM, KEY_PKCS8_PEM, SignatureCommitment::Approved)
+        .unwrap()
+        .build()
+        .unwrap();
+    common::assert_package_valid(&bytes, "signed-deck");
+
+    let parts = common::list_parts(&bytes);
+    for part in ["_xmlsignatures/origin.sigs", "_xmlsignatures/_rels/origin.sigs.rels", "_xmlsignatures/sig1.xml"] {
+        assert!(parts.iter().any(|p| p == part), "missing {part}");
+    }
+    assert!(read_part(&bytes, "_rels/.rels").contains(
+        r#"Type="http://schemas.openxmlformats.org/package/2006/relationships/digital-signature/origin" Target="_xmlsignatures/origin.sigs""#
+    ));
+    assert!(read_part(&bytes, "_xmlsignatures/_rels/origin.sigs.rels").contains(r#"Target="sig1.xml""#));
+    let content_types = read_part(&bytes, "[Content_Types].xml");
+    assert!(content_types.contains(r#"<Default Extension="sigs" ContentType="application/vnd.openxmlformats-package.digital-signature-origin"/>"#));
+    assert!(content_types.contains(r#"PartName="/_xmlsignatures/sig1.xml""#));
+
+    let signature = read_part(&bytes, "_xmlsignatures/sig1.xml");
+    assert!(signature.contains(r#"<SignatureMethod Algorithm="http://www.w3.org/2001/04/xmldsig-more#rsa-sha256"></SignatureMethod>"#));
+    assert!(signature.contains("<X509IssuerName>CN=Example Signer, O=Example Corp, C=US</X509IssuerName>"));
+    assert!(signature.contains("<X509SerialNumber>28772997619311</X509SerialNumber>"));
+    assert!(signature.contains("http://uri.etsi.org/01903/v1.2.2#ProofOfApproval"));
+    for part in ["/ppt/slides/slide1.xml?", "/ppt/slides/slide2.xml?", "/ppt/notesSlides/notesSlide1.xml?", "/ppt/_rels/presentation.xml.rels?"] {
+        assert!(signature.contains(&format!(r#"<Reference URI="{part}"#)), "{part} not signed");
+    }
+}
+
+#[test]
+fn der_and_pem_inputs_sign_identically() {
+    let details = DigitalSignature::new(SignerInfo::new("Example Signer"))
+        .sign_date("2025-06-15T10:00:00Z")
+        .comments("Board pack");
+    let settings = PresentationSettings::new().digital_signature(details);
+    let pem = deck()
+        .with_settings(settings.clone())
+        .sign(CERT_PEM, KEY_PKCS8_PEM, SignatureCommitment::Created)
+        .unwrap()
+        .build()
+        .unwrap();
+    let der = deck()
+        .with_settings(settings)
+        .sign(CERT_DER, KEY_PKCS1_DER, SignatureCommitment::Created)
+        .unwrap()
+        .build()
+        .unwrap();
+    let pem_signature = read_part(&pem, "_xmlsignatures/sig1.xml");
+    assert!(pem_signature.contains("<SignatureComments>Board pack</SignatureComments>"));
+    assert!(pem_signature.contains("<mdssi:Value>2025-06-15T10:00:00Z</mdssi:Value>"));
+    let value = |xml: &str| xml[xml.find("<SignatureValue>").unwrap()..xml.find("</SignatureValue>").unwrap()].to_string();
+    assert_eq!(value(&pem_signature), value(&read_part(&der, "_xmlsignatures/sig1.xml")));
+}
+
+#[test]
+fn settings_hash_algorithm_selects_signature_method() {
+    let settings = PresentationSettings::new()
+        .digital_signature(DigitalSignature::new(SignerInfo::new("Example Signer")).hash_algorithm(HashAlgorithm::Sha384));
+    let bytes = deck()
+        .with_settings(settings)
+        .sign(CERT_PEM, KEY_PKCS8_PEM, SignatureCommitment::Reviewed)
+        .unwrap()
+        .into_bytes()
+        .unwrap();
+    common::assert_package_valid(&bytes, "signed-sha384");
+    let signature = read_part(&bytes, "_xmlsignatures/sig1.xml");
+    assert!(signature.contains("xmldsig-more#rsa-sha384"));
+    assert!(signature.contains(r#"<DigestMethod Algorithm="http://www.w3.org/2001/04/xmldsig-more#sha384"></DigestMethod>"#));
+}
+
+#[test]
+fn saved_file_is_signed() {
+    let path = std::env::temp_dir().join(format!("signed-{}.pptx", std::process::id()));
+    deck()
+        .sign(CERT_DER, KEY_PKCS8_PEM, SignatureCommitment::Created)
+        .unwrap()
+        .save(&path)
+        .unwrap();
+    let bytes = std::fs::read(&path).unwrap();
+    std::fs::remove_file(&path).ok();
+    assert!(read_part(&bytes, "_xmlsignatures/sig1.xml").contains("<SignatureValue>"));
+}
+
+#[test]
+fn unusable_keys_are_rejected() {
+    let err = deck().sign(CERT_PEM, CERT_PEM, SignatureCommitment::Created).unwrap_err();
+    assert!(matches!(err, PptxError::InvalidValue(_)), "{err}");
+    assert!(deck().sign(KEY_PKCS8_PEM, KEY_PKCS8_PEM, SignatureCommitment::Created).is_err());
+    let err = deck()
+        .sign(CERT_PEM, include_bytes!("fixtures/signing/other.key"), SignatureCommitment::Created)
+        .unwrap_err();
+    assert!(err.to_string().contains("does not match the certificate"));
+}
+
+#[test]
+fn signed_deck_with_fixed_times_is_reproducible() {
+    let settings = PresentationSettings::new()
+        .digital_signature(DigitalSignature::new(SignerInfo::new("Example Signer")).sign_date("2025-06-15T10:00:00Z"));
+    let build = || {
+        deck()
+            .with_settings(settings.clone())
+            .created_at("2025-06-15T09:00:00Z")
+            .unwrap()
+            .sign(CERT_PEM, KEY_PKCS8_PEM, SignatureCommitment::Approved)
+            .unwrap()
+            .build()
+            .unwrap()
+    };
+    assert_eq!(build(), build());
+}
@@ -1,6 +1,11 @@
+mod common;
+
 use ppt_rs::api::Presentation;
-use ppt_rs::generator::SlideContent;
-use ppt_rs::import::import_pptx;
+use ppt_rs::generator::{ChartBuilder, ChartSeries, ChartType, Image, PresentationTheme, SlideContent, ThemeFonts};
+use ppt_rs::import::{import_pptx, import_pptx_bytes, import_pptx_with_options, ImportOptions, UnsupportedElementMode};
+use ppt_rs::opc::Package;
+use ppt_rs::oxml::PresentationReader;
+use std::io::Cursor;
 use std::path::Path;
 
 #[test]
@@ -44,3 +49,271 @@ fn test_import_and_merge() {
     std::fs::remove_file(source_path).ok();
     std::fs::remove_file(merged_path).ok();
 }
+
+#[test]
+fn test_import_unsupported_element_modes() {
+    let source_path = "tests/test_unsupported_source.pptx";
+
+    // A chart whose part is missing from the package cannot be reconstructed
+    let chart = ChartBuilder::new("Sales", ChartType::Bar)
+        .position(1000000, 1500000)
+        .size(4000000, 3000000)
+        .add_series(ChartSeries::new("Series 1", vec![1.0, 2.0, 3.0]))
+        .build();
+    let bytes = Presentation::with_title("Charts")
+        .add_slide(SlideContent::new("Chart Slide").add_chart(chart))
+        .build()
+        .expect("Failed to build source PPTX");
+    let mut package = Package::open_reader(Cursor::new(bytes)).expect("Failed to open package");
+    package.remove_part("ppt/charts/chart1.xml");
+    package.save(source_path).expect("Failed to save source PPTX");
+
+    // Default mode drops the chart
+    let skipped = import_pptx(source_path).expect("Failed to import PPTX");
+    assert!(skipped.slides()[0].shapes.is_empty());
+    assert!(skipped.slides()[0].charts.is_empty());
+
+    // Placeholder mode marks where the chart was
+    let options = ImportOptions::new().on_unsupported(UnsupportedElementMode::Placeholder);
+    let placeholder = import_pptx_with_options(source_path, &options).expect("Failed to import PPTX");
+    let shapes = &placeholder.slides()[0].shapes;
+    assert_eq!(shapes.len(), 1);
+    assert_eq!(shapes[0].text.as_deref(), Some("[Unsupported: Chart]"));
+    assert_eq!((shapes[0].x, shapes[0].y), (1000000, 1500000));
+    assert_eq!((shapes[0].width, shapes[0].height), (4000000, 3000000));
+
+    // Error mode fails with details
+    let options = ImportOptions::new().on_unsupported(UnsupportedElementMode::Error);
+    let err = import_pptx_with_options(source_path, &options).unwrap_err();
+    assert!(err.to_string().contains("Unsupported Chart"));
+    assert!(err.to_string().contains("slide 1"));
+
+    std::fs::remove_file(source_path).ok();
+}
+
+#[test]
+fn test_import_charts_round_trip() {
+    let source_path = "tests/test_chart_round_trip.pptx";
+
+    let bar = ChartBuilder::new("Revenue", ChartType::Bar)
+        .categories(vec!["Q1", "Q2", "Q3"])
+        .add_series(ChartSeries::new("2024", vec![100.0, 150.5, 120.0]))
+        .add_series(ChartSeries::new("2025", vec![110.0, 160.0, 130.0]))
+        .build();
+    let line = ChartBuilder::new("Trend", ChartType::Line)
+        .categories(vec!["Jan", "Feb"])
+        .add_series(ChartSeries::new("Visits", vec![10.0, 20.0]))
+        .build();
+    let pie = ChartBuilder::new("Share", ChartType::Pie)
+        .categories(vec!["A", "B"])
+        .add_series(ChartSeries::new("Share", vec![30.0, 70.0]))
+        .build();
+    let horizontal = ChartBuilder::new("Headcount", ChartType::BarHorizontal)
+        .categories(vec!["Sales", "Support"])
+        .add_series(ChartSeries::new("Staff", vec![12.0, 8.0]))
+        .build();
+    Presentation::with_title("Charts")
+        .add_slide(SlideContent::new("Bar").add_chart(bar))
+        .add_slide(SlideContent::new("Line & Pie").add_chart(line).add_chart(pie))
+        .add_slide(SlideContent::new("Horizontal").add_chart(horizontal))
+        .save(source_path)
+        .expect("Failed to save source PPTX");
+
+    let imported = import_pptx(source_path).expect("Failed to import PPTX");
+    let slides = imported.slides();
+
+    let bar = &slides[0].charts[0];
+    assert_eq!(bar.chart_type, ChartType::Bar);
+    assert_eq!(bar.title, "Revenue");
+    assert_eq!(bar.categories, vec!["Q1", "Q2", "Q3"]);
+    assert_eq!(bar.series.len(), 2);
+    assert_eq!(bar.series[0].name, "2024");
+    assert_eq!(bar.series[0].values, vec![100.0, 150.5, 120.0]);
+    assert_eq!(bar.series[1].values, vec![110.0, 160.0, 130.0]);
+
+    assert_eq!(slides[1].charts.len(), 2);
+    let line = &slides[1].charts[0];
+    assert_eq!(line.chart_type, ChartType::Line);
+    assert_eq!(line.categories, vec!["Jan", "Feb"]);
+    assert_eq!(line.series[0].values, vec![10.0, 20.0]);
+    let pie = &slides[1].charts[1];
+    assert_eq!(pie.chart_type, ChartType::Pie);
+    assert_eq!(pie.categories, vec!["A", "B"]);
+    assert_eq!(pie.series[0].values, vec![30.0, 70.0]);
+
+    let horizontal = &slides[2].charts[0];
+    assert_eq!(horizontal.chart_type, ChartType::BarHorizontal);
+    assert_eq!(horizontal.series[0].values, vec![12.0, 8.0]);
+
+    std::fs::remove_file(source_path).ok();
+}
+
+#[test]
+fn test_import_carries_speaker_notes() {
+    let source_path = "tests/test_notes_source.pptx";
+    Presentation::with_title("Notes")
+        .add_slide(SlideContent::new("Intro").notes("Welcome everyone"))
+        .add_slide(SlideContent::new("Body"))
+        .save(source_path)
+        .expect("Failed to save source PPTX");
+
+    let imported = import_pptx(source_path).expect("Failed to import PPTX");
+    assert_eq!(imported.slides()[0].notes.as_deref(), Some("Welcome everyone"));
+    assert!(imported.slides()[1].notes.is_none());
+
+    std::fs::remove_file(source_path).ok();
+}
+
+#[test]
+fn test_import_preserves_merged_table_cells() {
+    use ppt_rs::generator::{TableBuilder, TableCell, TableRow};
+    use std::io::Read;
+
+    let source_path = "tests/test_table_merge_source.pptx";
+    let table = TableBuilder::new(vec![1000000, 1000000, 1000000])
+        .add_row(TableRow::new(vec![
+            TableCell::new("Header").grid_span(2),
+            TableCell::new("").h_merge(),
+            TableCell::new("Side").row_span(2),
+        ]))
+        .add_row(TableRow::new(vec![
+            TableCell::new("A"),
+            TableCell::new("B"),
+            TableCell::new("").v_merge(),
+        ]))
+        .build();
+    Presentation::with_title("Tables")
+        .add_slide(SlideContent::new("Merged").table(table))
+        .save(source_path)
+        .expect("Failed to save source PPTX");
+
+    let imported = import_pptx(source_path).expect("Failed to import PPTX");
+    let rows = &imported.slides()[0].table.as_ref().expect("table imported").rows;
+    assert_eq!(rows[0].cells[0].grid_span, Some(2));
+    assert!(rows[0].cells[1].h_merge);
+    assert_eq!(rows[0].cells[2].row_span, Some(2));
+    assert_eq!(rows[1].cells[1].text, "B");
+    assert!(rows[1].cells[2].v_merge);
+
+    // Writing the imported deck keeps the merges and column positions
+    let bytes = imported.build().expect("Failed to rebuild PPTX");
+    let mut archive = zip::ZipArchive::new(Cursor::new(bytes)).unwrap();
+    let mut xml = String::new();
+    archive.by_name("ppt/slides/slide1.xml").unwrap().read_to_string(&mut xml).unwrap();
+    assert!(xml.contains(r#"<a:tc gridSpan="2">"#));
+    assert!(xml.contains(r#"<a:tc hMerge="1">"#));
+    assert!(xml.contains(r#"<a:tc rowSpan="2">"#));
+    assert!(xml.contains(r#"<a:tc vMerge="1">"#));
+    assert_eq!(xml.matches("</a:tc>").count(), 6);
+
+    std::fs::remove_file(source_path).ok();
+}
+
+#[test]
+fn test_import_from_bytes() {
+    let bytes = Presentation::with_title("Uploaded")
+        .add_slide(SlideContent::new("Slide 1").add_bullet("Point 1"))
+        .add_slide(SlideContent::new("Slide 2"))
+        .build()
+        .unwrap();
+
+    let imported = import_pptx_bytes(&bytes).expect("Failed to import PPTX bytes");
+    assert_eq!(imported.get_title(), "Uploaded");
+    assert_eq!(imported.slide_count(), 2);
+    assert_eq!(imported.slides()[0].content[0], "Point 1");
+
+    assert!(import_pptx_bytes(b"PK\x03\x04 truncated").is_err());
+}
+
+#[test]
+fn test_append_keeps_images_and_charts() {
+    // PNG signature plus an IHDR chunk is enough for an embedded picture
+    let mut png = vec![0x89, 0x50, 0x4E, 0x47, 0x0D, 0x0A, 0x1A, 0x0A, 0, 0, 0, 13];
+    png.extend_from_slice(b"IHDR");
+    png.extend_from_slice(&[0, 0, 0, 2, 0, 0, 0, 1, 8, 2, 0, 0, 0, 0, 0, 0, 0]);
+
+    let team_deck = |team: &str| {
+        let chart = ChartBuilder::new(team, ChartType::Bar)
+            .categories(vec!["Q1", "Q2"])
+            .add_series(ChartSeries::new("Revenue", vec![1.0, 2.0]))
+            .build();
+        let logo = Image::from_bytes(png.clone(), 1828800, 914400, "PNG")
+            .position(457200, 1600200)
+            .with_alt_text("Logo");
+        let bytes = Presentation::with_title(team)
+            .add_slide(SlideContent::new(team).add_image(logo).add_chart(chart))
+            .build()
+            .unwrap();
+        import_pptx_bytes(&bytes).unwrap()
+    };
+
+    let mut merged = team_deck("Sales");
+    merged.append(team_deck("Support"));
+    assert_eq!(merged.slide_count(), 2);
+
+    let image = &merged.slides()[1].images[0];
+    assert_eq!((image.x, image.y, image.width, image.height), (457200, 1600200, 1828800, 914400));
+    assert_eq!(image.alt_text.as_deref(), Some("Logo"));
+    assert_eq!(image.get_bytes().as_deref(), Some(png.as_slice()));
+
+    let bytes = merged.build().unwrap();
+    common::assert_package_valid(&bytes, "merged deck");
+    let parts = common::list_parts(&bytes);
+    // Each source keeps its chart; the shared logo is stored once
+    assert!(parts.iter().any(|p| p == "ppt/charts/chart1.xml"));
+    assert!(parts.iter().any(|p| p == "ppt/charts/chart2.xml"));
+    assert_eq!(parts.iter().filter(|p| p.starts_with("ppt/media/")).count(), 1);
+
+    let reimported = import_pptx_bytes(&bytes).unwrap();
+    assert!(reimported.slides().iter().all(|s| s.images.len() == 1 && s.charts.len() == 1));
+}
+
+#[test]
+fn test_import_picture_inside_group() {
+    let mut png = vec![0x89, 0x50, 0x4E, 0x47, 0x0D, 0x0A, 0x1A, 0x0A, 0, 0, 0, 13];
+    png.extend_from_slice(b"IHDR");
+    png.extend_from_slice(&[0, 0, 0, 2, 0, 0, 0, 1, 8, 2, 0, 0, 0, 0, 0, 0, 0]);
+    let logo = Image::from_bytes(png.clone(), 1828800, 914400, "PNG").position(457200, 1600200);
+    let bytes = Presentation::with_title("Grouped")
+        .add_slide(SlideContent::new("Logo").add_image(logo))
+        .build()
+        .unwrap();
+
+    // Wrap the picture in a group that halves its child space
+    let mut package = Package::open_reader(Cursor::new(bytes)).unwrap();
+    let slide = package.get_part_string("ppt/slides/slide1.xml").unwrap();
+    let (start, end) = (slide.find("<p:pic>").unwrap(), slide.find("</p:pic>").unwrap() + "</p:pic>".len());
+    let group = format!(
+        r#"<p:grpSp><p:nvGrpSpPr><p:cNvPr id="90" name="Group 1"/><p:cNvGrpSpPr/><p:nvPr/></p:nvGrpSpPr><p:grpSpPr><a:xfrm><a:off x="914400" y="914400"/><a:ext cx="4572000" cy="3429000"/><a:chOff x="0" y="0"/><a:chExt cx="9144000" cy="6858000"/></a:xfrm></p:grpSpPr>{}</p:grpSp>"#,
+        &slide[start..end]
+    );
+    let grouped = format!("{}{}{}", &slide[..start], group, &slide[end..]);
+    package.add_part("ppt/slides/slide1.xml".to_string(), grouped.into_bytes());
+    let mut out = Cursor::new(Vec::new());
+    package.save_writer(&mut out).unwrap();
+
+    let imported = import_pptx_bytes(&out.into_inner()).unwrap();
+    let images = &imported.slides()[0].images;
+    assert_eq!(images.len(), 1);
+    let image = &images[0];
+    assert_eq!((image.x, image.y, image.width, image.height), (1143000, 1714500, 914400, 457200));
+    assert_eq!(image.get_bytes().as_deref(), Some(png.as_slice()));
+}
+
+#[test]
+fn test_import_keeps_theme() {
+    let theme = PresentationTheme::corporate().fonts(ThemeFonts::new("Georgia", "Verdana"));
+    let bytes = Presentation::with_title("Client")
+        .with_theme(theme.clone())
+        .add_slide(SlideContent::new("Cover"))
+        .build()
+        .unwrap();
+
+    let imported = import_pptx_bytes(&bytes).unwrap();
+    assert_eq!(imported.get_theme(), Some(&theme));
+
+    let reader = PresentationReader::from_bytes(&bytes).unwrap();
+    let parsed = reader.theme().unwrap();
+    assert_eq!(parsed.colors.accent1, theme.colors.accent1);
+    assert_eq!(parsed.fonts.major, "Georgia");
+}
@@ -3,16 +3,20 @@
 mod common;
 
 use std::fs;
-use std::io::{Cursor, Read};
+use std::io::{Cursor, Read, Write};
+use std::path::Path;
 
 use ppt_rs::generator::{
     create_pptx, create_pptx_with_content, create_pptx_with_settings, create_pptx_with_template,
-    PresentationSettings, PrintSettings, PptxTemplate, SlideContent, SlideLayout,
-    STANDARD_LAYOUT_COUNT,
+    Image, PresentationSettings, PresentationTheme, PrintSettings, PptxTemplate, SlideContent, SlideLayout,
+    ThemeColorScheme, STANDARD_LAYOUT_COUNT,
 };
-use zip::ZipArchive;
+use ppt_rs::oxml::ThemeParser;
+use ppt_rs::Presentation;
+use zip::write::FileOptions;
+use zip::{ZipArchive, ZipWriter};
 
-const ALL_LAYOUTS: [(SlideLayout, &str); 7] = [
+const ALL_LAYOUTS: [(SlideLayout, &str); 9] = [
     (SlideLayout::CenteredTitle, "Centered Title"),
     (SlideLayout::TitleAndContent, "Title and Content"),
     (SlideLayout::TwoColumn, "Two Column"),
@@ -20,6 +24,8 @@ const ALL_LAYOUTS: [(SlideLayout, &str); 7] = [
     (SlideLayout::Blank, "Blank"),
     (SlideLayout::TitleOnly, "Title Only"),
     (SlideLayout::TitleAndBigContent, "Title and Big Content"),
+    (SlideLayout::PictureWithCaption, "Picture with Caption"),
+    (SlideLayout::Comparison, "Comparison"),
 ];
 
 fn read_part(bytes: &[u8], path: &str) -> String {
@@ -167,7 +173,7 @@ fn slide_layout_number_mapping_is_stable() {
 }
 
 #[test]
-fn all_seven_layouts_wired_in_slide_rels() {
+fn all_standard_layouts_wired_in_slide_rels() {
     let slides: Vec<SlideContent> = ALL_LAYOUTS
         .iter()
         .map(|(layout, title)| {
@@ -177,7 +183,7 @@ fn all_seven_layouts_wired_in_slide_rels() {
         })
         .collect();
 
-    let bytes = create_pptx_with_content("All Seven", slides).unwrap();
+    let bytes = create_pptx_with_content("All Layouts", slides).unwrap();
 
     for (i, (layout, _)) in ALL_LAYOUTS.iter().enumerate() {
         let rels = read_part(&bytes, &format!("ppt/slides/_rels/slide{}.xml.rels", i + 1));
@@ -201,6 +207,8 @@ fn layout_part_types_match_ooxml_names() {
         (5, "blank"),
         (6, "titleOnly"),
         (7, "obj"),
+        (8, "picTx"),
+        (9, "twoTxTwoObj"),
     ];
     for (n, type_attr) in checks {
         let xml = read_part(&bytes, &format!("ppt/slideLayouts/slideLayout{n}.xml"));
@@ -211,6 +219,90 @@ fn layout_part_types_match_ooxml_names() {
     }
 }
 
+#[test]
+fn auto_layout_picks_layout_from_content() {
+    let png = vec![0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A, 1, 2, 3, 4];
+    let slides = vec![
+        SlideContent::new("Just a title").auto_layout(),
+        SlideContent::new("Bullets").add_bullet("One").add_bullet("Two").auto_layout(),
+        SlideContent::new("Compare")
+            .add_bullet("Pros")
+            .add_column_break()
+            .add_bullet("Cons")
+            .add_bullet("More cons")
+            .auto_layout(),
+        SlideContent::new("Photo")
+            .add_image(Image::from_bytes(png.clone(), 1_828_800, 914_400, "PNG"))
+            .add_bullet("The harbour at dawn")
+            .auto_layout(),
+        SlideContent::new("Explicit")
+            .layout(SlideLayout::TitleAndContent)
+            .add_image(Image::from_bytes(png, 914_400, 914_400, "PNG"))
+            .add_bullet("Stays title and content")
+            .auto_layout(),
+    ];
+    assert_eq!(slides[0].effective_layout(), SlideLayout::TitleOnly);
+    assert_eq!(slides[3].effective_layout(), SlideLayout::PictureWithCaption);
+    assert_eq!(slides[4].effective_layout(), SlideLayout::TitleAndContent);
+    assert_eq!(slides[4].suggested_layout(), SlideLayout::PictureWithCaption);
+
+    let bytes = create_pptx_with_content("Auto", slides).unwrap();
+    for (i, n) in [6, 2, 3, 8, 2].iter().enumerate() {
+        let rels = read_part(&bytes, &format!("ppt/slides/_rels/slide{}.xml.rels", i + 1));
+        assert!(rels.contains(&format!("slideLayout{n}.xml")), "slide {} rels: {rels}", i + 1);
+    }
+
+    // The column break puts only the first bullet on the left
+    let two = read_part(&bytes, "ppt/slides/slide3.xml");
+    let right = two.find(r#"name="Right Content""#).unwrap();
+    assert!(two.find("<a:t>Pros</a:t>").unwrap() < right);
+    assert!(two.find("<a:t>Cons</a:t>").unwrap() > right);
+
+    // The picture is fitted to the picture area and the caption loses its bullet
+    let photo = read_part(&bytes, "ppt/slides/slide4.xml");
+    assert!(photo.contains(r#"<a:ext cx="5486400" cy="2743200"/>"#), "{photo}");
+    assert!(photo.contains(r#"name="Caption""#));
+    assert!(photo.contains("<a:buNone/>"));
+
+    common::assert_package_valid(&bytes, "auto layout");
+}
+
+#[test]
+fn column_bullets_fill_two_content_placeholders() {
+    let slides = vec![
+        SlideContent::new("Pros vs Cons")
+            .left_bullets(["Fast", "Cheap"])
+            .right_bullets(vec!["Fragile".to_string()]),
+        SlideContent::new("Compare")
+            .left_heading("Pros")
+            .left_bullets(["Fast"])
+            .right_heading("Cons")
+            .right_bullets(["Fragile"]),
+    ];
+    assert_eq!(slides[0].layout, SlideLayout::TwoColumn);
+    assert_eq!(slides[1].layout, SlideLayout::Comparison);
+    let bytes = create_pptx_with_content("Columns", slides).unwrap();
+
+    let two = read_part(&bytes, "ppt/slides/slide1.xml");
+    let left = two.find(r#"<p:ph type="body" idx="1"/>"#).unwrap();
+    let right = two.find(r#"<p:ph type="body" idx="2"/>"#).unwrap();
+    assert!(left < two.find("<a:t>Cheap</a:t>").unwrap());
+    assert!(two.find("<a:t>Cheap</a:t>").unwrap() < right);
+    assert!(right < two.find("<a:t>Fragile</a:t>").unwrap());
+    assert!(read_part(&bytes, "ppt/slides/_rels/slide1.xml.rels").contains("slideLayout3.xml"));
+
+    let comparison = read_part(&bytes, "ppt/slides/slide2.xml");
+    for idx in 1..=4 {
+        assert!(comparison.contains(&format!(r#"<p:ph type="body" idx="{idx}"/>"#)), "idx {idx}");
+    }
+    let heading = comparison.find(r#"idx="3""#).unwrap();
+    assert!(heading < comparison.find("<a:t>Cons</a:t>").unwrap());
+    assert!(comparison.find("<a:t>Cons</a:t>").unwrap() < comparison.find(r#"idx="4""#).unwrap());
+    assert!(read_part(&bytes, "ppt/slides/_rels/slide2.xml.rels").contains("slideLayout9.xml"));
+
+    common::assert_package_valid(&bytes, "column bullets");
+}
+
 #[test]
 fn mixed_layouts_pass_powerpoint_compat_gate() {
     let slides = vec![
@@ -315,3 +407,89 @@ fn default_slide_uses_title_and_content_layout() {
     let rels = read_part(&bytes, "ppt/slides/_rels/slide1.xml.rels");
     assert!(rels.contains("slideLayout2.xml"));
 }
+
+/// A generated deck turned into a corporate template: a red accent theme and a logo on the master
+fn write_corporate_template(path: &Path) {
+    let colors = ThemeColorScheme { accent1: "C00000".to_string(), ..ThemeColorScheme::office() };
+    let base = Presentation::with_title("Corporate")
+        .with_theme(PresentationTheme::new("Corporate").colors(colors))
+        .add_slide(SlideContent::new("Old slide"))
+        .build()
+        .unwrap();
+    let logo = r#"<p:pic><p:nvPicPr><p:cNvPr id="99" name="Logo"/><p:cNvPicPr/><p:nvPr/></p:nvPicPr><p:blipFill><a:blip r:embed="rIdLogo"/><a:stretch><a:fillRect/></a:stretch></p:blipFill><p:spPr><a:xfrm><a:off x="0" y="0"/><a:ext cx="914400" cy="914400"/></a:xfrm><a:prstGeom prst="rect"><a:avLst/></a:prstGeom></p:spPr></p:pic></p:spTree>"#;
+    let logo_rel = r#"<Relationship Id="rIdLogo" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/image" Target="../media/logo.png"/></Relationships>"#;
+
+    let mut archive = ZipArchive::new(Cursor::new(base)).unwrap();
+    let mut zip = ZipWriter::new(fs::File::create(path).unwrap());
+    for i in 0..archive.len() {
+        let mut entry = archive.by_index(i).unwrap();
+        let name = entry.name().to_string();
+        let mut xml = String::new();
+        entry.read_to_string(&mut xml).unwrap();
+        let xml = match name.as_str() {
+            "ppt/slideMasters/slideMaster1.xml" => xml.replace("</p:spTree>", logo),
+            "ppt/slideMasters/_rels/slideMaster1.xml.rels" => xml.replace("</Relationships>", logo_rel),
+            "[Content_Types].xml" if !xml.contains(r#"Extension="png""#) => {
+                xml.replace("</Types>", r#"<Default Extension="png" ContentType="image/png"/></Types>"#)
+            }
+            _ => xml,
+        };
+        zip.start_file(name, FileOptions::default()).unwrap();
+        zip.write_all(xml.as_bytes()).unwrap();
+    }
+    zip.start_file("ppt/media/logo.png", FileOptions::default()).unwrap();
+    zip.write_all(include_bytes!("../examples/assets/diagram.png")).unwrap();
+    zip.finish().unwrap();
+}
+
+#[test]
+fn from_template_keeps_master_media_theme_and_layouts() {
+    let path = std::env::temp_dir().join("ppt_rs_corporate_template.potx");
+    write_corporate_template(&path);
+
+    let template = PptxTemplate::load(&path).unwrap();
+    assert_eq!(template.theme().unwrap().colors.accent1, "C00000");
+
+    let bytes = Presentation::from_template(&path)
+        .unwrap()
+        .title("Quarterly")
+        .add_slide(SlideContent::new("Agenda").add_bullet("Numbers").notes("Speak slowly"))
+        .add_slide(SlideContent::new("Wrap up").layout(SlideLayout::TitleOnly))
+        .build()
+        .unwrap();
+    let parts = common::list_parts(&bytes);
+
+    // The master's logo comes along, renamed so it can't clash with slide media
+    assert!(parts.contains(&"ppt/media/template_logo.png".to_string()));
+    assert!(read_part(&bytes, "ppt/slideMasters/_rels/slideMaster1.xml.rels").contains(r#"Target="../media/template_logo.png""#));
+    assert!(read_part(&bytes, "ppt/slideMasters/slideMaster1.xml").contains(r#"r:embed="rIdLogo""#));
+    assert!(read_part(&bytes, "[Content_Types].xml")
+        .contains(r#"<Override PartName="/ppt/media/template_logo.png" ContentType="image/png"/>"#));
+
+    // Color scheme survives; the notes master still gets its own theme
+    let theme = ThemeParser::parse(&read_part(&bytes, "ppt/theme/theme1.xml")).unwrap();
+    assert_eq!(theme.colors.accent1, "C00000");
+    for part in ["ppt/theme/theme1.xml", "ppt/theme/theme2.xml", "ppt/tableStyles.xml"] {
+        assert_eq!(parts.iter().filter(|p| *p == part).count(), 1, "{part}");
+    }
+
+    // New slides only, on the template's layouts
+    assert!(!parts.contains(&"ppt/slides/slide3.xml".to_string()));
+    assert!(read_part(&bytes, "ppt/slides/_rels/slide1.xml.rels").contains("slideLayout2.xml"));
+    assert!(read_part(&bytes, "ppt/slides/_rels/slide2.xml.rels").contains("slideLayout6.xml"));
+    common::assert_package_valid(&bytes, "corporate template");
+
+    fs::remove_file(path).ok();
+}
+
+#[test]
+fn from_template_rejects_a_deck_without_masters() {
+    let path = std::env::temp_dir().join("ppt_rs_not_a_template.pptx");
+    let mut zip = ZipWriter::new(fs::File::create(&path).unwrap());
+    zip.start_file("ppt/presentation.xml", FileOptions::default()).unwrap();
+    zip.write_all(b"<p:presentation/>").unwrap();
+    zip.finish().unwrap();
+
+    assert!(Presentation::from_template(&path).is_err());
+    fs::remove_file(path).ok();
+}
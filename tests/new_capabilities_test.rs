@@ -114,17 +114,18 @@ fn test_prelude_themes_bridge_to_presentation_theme() {
 
 #[test]
 fn test_presentation_into_bytes_with_theme() {
-    let pres = Presentation::with_title("Into Bytes")
-        .add_slide(SlideContent::new("Slide"))
-        .with_theme(PresentationTheme::tech());
+    let pres = || {
+        Presentation::with_title("Into Bytes")
+            .add_slide(SlideContent::new("Slide"))
+            .with_theme(PresentationTheme::tech())
+            .created_at("2024-05-01T12:00:00Z")
+            .unwrap()
+    };
 
-    let via_build = pres.build().unwrap();
-    let pres2 = Presentation::with_title("Into Bytes")
-        .add_slide(SlideContent::new("Slide"))
-        .with_theme(PresentationTheme::tech());
-    let via_into = pres2.into_bytes().unwrap();
+    let via_build = pres().build().unwrap();
+    let via_into = pres().into_bytes().unwrap();
 
-    assert_eq!(via_build.len(), via_into.len());
+    assert_eq!(via_build, via_into);
     assert!(theme_xml_from_pptx(&via_into).contains("0D47A1"));
 }
 
@@ -315,6 +316,16 @@ fn test_content_types_with_notes_and_charts() {
     assert!(xml.contains("/ppt/tableStyles.xml"));
 }
 
+#[test]
+fn test_empty_notes_produce_no_notes_parts() {
+    let slides = vec![SlideContent::new("Plain").notes("")];
+    assert!(!slides[0].has_notes());
+
+    let xml = create_content_types_xml_with_notes_and_charts(1, Some(&slides), 0, false, &[]);
+    assert!(!xml.contains("notesSlide"));
+    assert!(!xml.contains("notesMaster1.xml"));
+}
+
 #[test]
 fn test_slide_rel_id_helpers() {
     assert_eq!(first_slide_rel_id(false, false), 2);
@@ -328,20 +339,59 @@ fn test_slide_rel_id_helpers() {
 
 #[test]
 fn test_build_and_into_bytes_produce_same_pptx() {
-    let pres = Presentation::with_title("Compare APIs")
-        .add_slide(SlideContent::new("One").add_bullet("A"))
-        .add_slide(SlideContent::new("Two").add_bullet("B"));
-
-    let from_build = pres.build().unwrap();
+    let pres = || {
+        Presentation::with_title("Compare APIs")
+            .add_slide(SlideContent::new("One").add_bullet("A"))
+            .add_slide(SlideContent::new("Two").add_bullet("B"))
+            .created_at("2024-05-01T12:00:00Z")
+            .unwrap()
+    };
 
-    let pres2 = Presentation::with_title("Compare APIs")
-        .add_slide(SlideContent::new("One").add_bullet("A"))
-        .add_slide(SlideContent::new("Two").add_bullet("B"));
-    let from_into = pres2.into_bytes().unwrap();
+    let from_build = pres().build().unwrap();
+    let from_into = pres().into_bytes().unwrap();
 
     assert_eq!(from_build, from_into);
 }
 
+#[test]
+fn test_fixed_timestamps_pin_core_props_and_zip_entries() {
+    use ppt_rs::generator::{ChartBuilder, ChartSeries, ChartType};
+
+    let pres = || {
+        Presentation::with_title("Cached")
+            .add_slide(SlideContent::new("Numbers").add_chart(
+                ChartBuilder::new("Sales", ChartType::Bar)
+                    .categories(vec!["Q1", "Q2"])
+                    .add_series(ChartSeries::new("2024", vec![1.0, 2.5]))
+                    .build(),
+            ))
+            .created_at("2023-11-20T08:15:00Z")
+            .unwrap()
+            .modified_at("2024-02-29T23:59:58Z")
+            .unwrap()
+    };
+    let bytes = pres().build().unwrap();
+    assert_eq!(bytes, pres().into_bytes().unwrap());
+
+    let core = read_zip_entry(&bytes, "docProps/core.xml");
+    assert!(core.contains(r#"<dcterms:created xsi:type="dcterms:W3CDTF">2023-11-20T08:15:00Z</dcterms:created>"#));
+    assert!(core.contains(r#"<dcterms:modified xsi:type="dcterms:W3CDTF">2024-02-29T23:59:58Z</dcterms:modified>"#));
+
+    let mut archive = zip::ZipArchive::new(Cursor::new(&bytes)).unwrap();
+    for i in 0..archive.len() {
+        let entry = archive.by_index(i).unwrap();
+        let time = entry.last_modified();
+        assert_eq!(
+            (time.year(), time.month(), time.day(), time.hour(), time.minute(), time.second()),
+            (2024, 2, 29, 23, 59, 58),
+            "{}",
+            entry.name()
+        );
+    }
+
+    assert!(Presentation::new().created_at("2024-02-29").is_err());
+}
+
 #[test]
 fn test_estimate_slide_payload_counts_text() {
     let slides = vec![
@@ -15,8 +15,10 @@ use ppt_rs::generator::layout_parts::STANDARD_LAYOUT_COUNT;
 use ppt_rs::generator::package_xml::{HANDOUT_MASTER_ID, NOTES_MASTER_ID, SLIDE_LAYOUT_ID};
 use ppt_rs::generator::{
     create_pptx, create_pptx_with_content, create_pptx_with_settings, ChartBuilder,
-    ChartSeries, ChartType, Image, PresentationSettings, PrintSettings, PrintWhat, SlideContent,
+    Audio, AudioFormat, AudioOptions, ChartSeries, ChartType, Image, PresentationSettings, PrintSettings,
+    PrintWhat, SlideContent, Video, VideoFormat, VideoOptions,
 };
+use ppt_rs::Presentation;
 use ppt_rs::generator::slide_content::print_settings::HandoutLayout;
 use zip::write::FileOptions;
 use zip::ZipArchive;
@@ -94,6 +96,60 @@ fn chart_deck_passes_validation() {
     );
 }
 
+#[test]
+fn waterfall_chart_deck_passes_validation() {
+    let waterfall = ChartBuilder::new("Cash flow", ChartType::Waterfall)
+        .categories(vec!["Start", "Sales", "Costs", "End"])
+        .add_series(ChartSeries::new("Cash", vec![100.0, 40.0, -25.0, 115.0]).with_waterfall_totals(vec![0, 3]))
+        .build();
+    let bar = ChartBuilder::new("Sales", ChartType::Bar)
+        .add_series(ChartSeries::new("Q1", vec![1.0, 2.0]))
+        .build();
+    let slide = SlideContent::new("Charts").add_chart(bar).add_chart(waterfall);
+    let bytes = create_pptx_with_content("Waterfall", vec![slide]).unwrap();
+    assert_package_valid(&bytes, "waterfall");
+
+    let content_types = read_part(&bytes, "[Content_Types].xml");
+    assert!(content_types.contains(
+        r#"<Override PartName="/ppt/charts/chartEx2.xml" ContentType="application/vnd.ms-office.chartex+xml"/>"#
+    ));
+    assert!(content_types.contains("/ppt/charts/chart1.xml"));
+
+    let rels = read_part(&bytes, "ppt/slides/_rels/slide1.xml.rels");
+    assert!(rels.contains(
+        r#"Type="http://schemas.microsoft.com/office/2014/relationships/chartEx" Target="../charts/chartEx2.xml""#
+    ));
+    assert!(read_part(&bytes, "ppt/charts/chartEx2.xml").contains(r#"layoutId="waterfall""#));
+    assert!(read_part(&bytes, "ppt/charts/_rels/chartEx2.xml.rels").contains("Microsoft_Excel_Sheet2.xlsx"));
+}
+
+#[test]
+fn editable_chart_data_is_embedded_where_formulas_point() {
+    let chart = ChartBuilder::new("Sales", ChartType::Line)
+        .categories(vec!["Jan", "Feb"])
+        .add_series(ChartSeries::new("North", vec![10.0, 12.0]))
+        .add_series(ChartSeries::new("South", vec![7.0, 9.5]))
+        .build()
+        .editable_data(true);
+    let slide = SlideContent::new("Chart").add_chart(chart);
+    let bytes = create_pptx_with_content("Editable", vec![slide]).unwrap();
+    assert_package_valid(&bytes, "editable chart data");
+
+    let chart_xml = read_part(&bytes, "ppt/charts/chart1.xml");
+    assert!(chart_xml.contains("<c:f>Sheet1!$C$2:$C$3</c:f>"));
+
+    let mut workbook = Vec::new();
+    ZipArchive::new(Cursor::new(&bytes))
+        .unwrap()
+        .by_name("ppt/embeddings/Microsoft_Excel_Sheet1.xlsx")
+        .unwrap()
+        .read_to_end(&mut workbook)
+        .unwrap();
+    let sheet = read_part(&workbook, "xl/worksheets/sheet1.xml");
+    assert!(sheet.contains(r#"<c r="C2"><v>7</v></c>"#));
+    assert!(sheet.contains(r#"<c r="C3"><v>9.5</v></c>"#));
+}
+
 #[test]
 fn notes_handouts_and_images_pass_validation() {
     let print = PrintSettings::default()
@@ -130,6 +186,157 @@ fn notes_handouts_and_images_pass_validation() {
     );
 }
 
+#[test]
+fn embedded_video_with_poster_passes_validation() {
+    let poster = Image::from_bytes(include_bytes!("../examples/assets/diagram.png").to_vec(), 1, 1, "png");
+    let options = VideoOptions::default().poster(poster).trim_start(500);
+    let clip = Video::from_bytes(b"not really an mp4".to_vec(), VideoFormat::Mp4, 914_400, 914_400, 4_572_000, 2_571_750)
+        .with_options(options);
+    let autoplay = Video::from_bytes(b"second clip".to_vec(), VideoFormat::Mov, 0, 0, 914_400, 914_400)
+        .with_options(VideoOptions::default().autoplay(true).loop_playback(true));
+    let slide = SlideContent::new("Demo").add_video(clip).add_video(autoplay);
+    let bytes = create_pptx_with_content("Video", vec![slide]).unwrap();
+    assert_package_valid(&bytes, "video");
+
+    let content_types = read_part(&bytes, "[Content_Types].xml");
+    assert!(content_types.contains(r#"<Default Extension="mp4" ContentType="video/mp4"/>"#));
+    assert!(content_types.contains(r#"<Default Extension="mov" ContentType="video/quicktime"/>"#));
+    assert_eq!(read_part(&bytes, "ppt/media/media1.mp4"), "not really an mp4");
+
+    let rels = read_part(&bytes, "ppt/slides/_rels/slide1.xml.rels");
+    assert!(rels.contains(r#"Type="http://schemas.microsoft.com/office/2007/relationships/media" Target="../media/media1.mp4""#));
+    assert!(rels.contains(r#"Id="rId3" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/video" Target="../media/media1.mp4""#));
+    assert!(rels.contains(r#"Id="rId4" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/image" Target="../media/image1.png""#));
+    assert!(rels.contains(r#"Id="rId7" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/image" Target="../media/image2.png""#));
+
+    let slide_xml = read_part(&bytes, "ppt/slides/slide1.xml");
+    assert!(slide_xml.contains(r#"<a:videoFile r:link="rId3"/>"#));
+    assert!(slide_xml.contains(r#"r:embed="rId2"><p14:trim st="500"/></p14:media>"#));
+    assert!(slide_xml.contains(r#"<a:blip r:embed="rId4"/>"#));
+    assert!(slide_xml.contains(r#"cmd="playFrom(0.0)""#));
+    assert_eq!(slide_xml.matches(r#"nodeType="interactiveSeq""#).count(), 2);
+}
+
+#[test]
+fn background_audio_plays_across_slides_from_slide_one() {
+    let track = Audio::from_bytes(b"ID3 soundtrack".to_vec(), AudioFormat::Mp3, 0, 0, 457_200, 457_200);
+    let options = AudioOptions::default().with_loop(true).with_play_across_slides(true).hide_icon(true);
+    let bytes = Presentation::with_title("Music")
+        .add_slide(SlideContent::new("One"))
+        .add_slide(SlideContent::new("Two"))
+        .background_audio(track, options)
+        .build()
+        .unwrap();
+    assert_package_valid(&bytes, "background audio");
+
+    assert!(read_part(&bytes, "[Content_Types].xml").contains(r#"<Default Extension="mp3" ContentType="audio/mpeg"/>"#));
+    assert_eq!(read_part(&bytes, "ppt/media/media1.mp3"), "ID3 soundtrack");
+    let rels = read_part(&bytes, "ppt/slides/_rels/slide1.xml.rels");
+    assert!(rels.contains(r#"Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/audio" Target="../media/media1.mp3""#));
+
+    let first = read_part(&bytes, "ppt/slides/slide1.xml");
+    assert!(first.contains("<a:audioFile r:link="));
+    assert!(first.contains(r#"cmd="playFrom(0.0)""#));
+    assert!(first.contains(r#"<p:cMediaNode numSld="999" showWhenStopped="0"><p:cTn id="#));
+    assert!(first.contains(r#"repeatCount="indefinite""#));
+    assert!(!read_part(&bytes, "ppt/slides/slide2.xml").contains("audioFile"));
+}
+
+#[test]
+fn slide_comments_write_comment_and_author_parts() {
+    let slides = vec![
+        SlideContent::new("Draft")
+            .add_comment("Ada Lovelace", "Tighten this title", 914_400, 457_200)
+            .add_comment("Grace Hopper", "Add a source", 0, 0),
+        SlideContent::new("Clean"),
+        SlideContent::new("Review").add_comment("Ada Lovelace", "Looks good", 100, 200),
+    ];
+    let bytes = create_pptx_with_content("Comments", slides).unwrap();
+    assert_package_valid(&bytes, "slide comments");
+
+    let authors = read_part(&bytes, "ppt/commentAuthors.xml");
+    assert!(authors.contains(r#"<p:cmAuthor id="0" name="Ada Lovelace" initials="AL" lastIdx="2" clrIdx="0"/>"#));
+    assert!(authors.contains(r#"<p:cmAuthor id="1" name="Grace Hopper" initials="GH" lastIdx="1" clrIdx="1"/>"#));
+    assert!(read_part(&bytes, "ppt/_rels/presentation.xml.rels").contains(r#"Target="commentAuthors.xml""#));
+
+    let first = read_part(&bytes, "ppt/comments/comment1.xml");
+    assert!(first.contains(r#"<p:cm authorId="0" dt="2025-01-01T00:00:00.000" idx="1"><p:pos x="914400" y="457200"/><p:text>Tighten this title</p:text></p:cm>"#));
+    assert!(first.contains(r#"<p:cm authorId="1" dt="2025-01-01T00:00:00.000" idx="1">"#));
+    assert!(read_part(&bytes, "ppt/comments/comment3.xml").contains(r#"authorId="0" dt="2025-01-01T00:00:00.000" idx="2""#));
+    assert!(read_part(&bytes, "ppt/slides/_rels/slide3.xml.rels").contains(r#"Target="../comments/comment3.xml""#));
+    assert!(!read_part(&bytes, "ppt/slides/_rels/slide2.xml.rels").contains("comments"));
+
+    let content_types = read_part(&bytes, "[Content_Types].xml");
+    assert!(content_types.contains(r#"<Override PartName="/ppt/commentAuthors.xml""#));
+    assert!(content_types.contains(r#"<Override PartName="/ppt/comments/comment3.xml""#));
+    assert!(!content_types.contains("/ppt/comments/comment2.xml"));
+}
+
+#[test]
+fn placeholder_text_inherits_layout_placeholders() {
+    use ppt_rs::{PlaceholderType, SlideLayout};
+
+    let slides = vec![
+        SlideContent::new("")
+            .layout(SlideLayout::CenteredTitle)
+            .set_placeholder(PlaceholderType::CenteredTitle, "Roadmap")
+            .set_placeholder(PlaceholderType::Subtitle, "2026 planning"),
+        SlideContent::new("Agenda").set_placeholder(PlaceholderType::Body, "Goals\nRisks"),
+        SlideContent::new("").layout(SlideLayout::Blank).set_placeholder(PlaceholderType::Title, "Free"),
+    ];
+    let bytes = create_pptx_with_content("Placeholders", slides).unwrap();
+    assert_package_valid(&bytes, "placeholder text");
+
+    let title_slide = read_part(&bytes, "ppt/slides/slide1.xml");
+    assert!(title_slide.contains(r#"<p:ph type="ctrTitle"/>"#));
+    assert!(title_slide.contains(r#"<p:ph type="subTitle"/>"#));
+    assert!(read_part(&bytes, "ppt/slides/slide2.xml").contains(r#"<p:ph type="body" idx="1"/>"#));
+    assert!(!read_part(&bytes, "ppt/slides/slide3.xml").contains("<p:ph "));
+}
+
+#[test]
+fn scheme_colors_follow_the_theme() {
+    use ppt_rs::generator::{Shape, ShapeType, TableBuilder, TableCell, TableRow};
+    use ppt_rs::SchemeColor;
+
+    let table = TableBuilder::new(vec![2_000_000])
+        .add_row(TableRow::new(vec![TableCell::new("Next step").background_color(SchemeColor::Accent1)]))
+        .position(500_000, 3_000_000)
+        .build();
+    let slide = SlideContent::new("Call to action")
+        .add_shape(Shape::new(ShapeType::RoundedRectangle, 500_000, 1_500_000, 3_000_000, 800_000)
+            .with_fill(SchemeColor::Accent1)
+            .with_text("Sign up"))
+        .table(table);
+    let bytes = create_pptx_with_content("Theme colors", vec![slide]).unwrap();
+    assert_package_valid(&bytes, "scheme colors");
+
+    let xml = read_part(&bytes, "ppt/slides/slide1.xml");
+    assert_eq!(xml.matches(r#"<a:schemeClr val="accent1"/>"#).count(), 2);
+}
+
+#[test]
+fn gradient_buttons_write_linear_and_radial_fills() {
+    use ppt_rs::generator::{Shape, ShapeGradientDirection, ShapeGradientFill, ShapeType};
+    use ppt_rs::{GradientDirection, GradientFill};
+
+    let slide = SlideContent::new("Buttons")
+        .add_shape(Shape::new(ShapeType::RoundedRectangle, 500_000, 1_500_000, 2_500_000, 700_000)
+            .with_gradient(ShapeGradientFill::linear("5B9BD5", "1F4E79", ShapeGradientDirection::DiagonalDown))
+            .with_text("Start"))
+        .add_shape(Shape::new(ShapeType::Ellipse, 3_500_000, 1_500_000, 1_500_000, 1_500_000)
+            .with_gradient(GradientFill::radial().add_stop(ppt_rs::GradientStop::start("FFFFFF")).add_stop(ppt_rs::GradientStop::end("ED7D31"))))
+        .add_shape(Shape::new(ShapeType::RoundedRectangle, 5_500_000, 1_500_000, 2_500_000, 700_000)
+            .with_gradient(GradientFill::two_color("70AD47", "375623").with_direction(GradientDirection::Horizontal)));
+    let bytes = create_pptx_with_content("Gradients", vec![slide]).unwrap();
+    assert_package_valid(&bytes, "gradient buttons");
+
+    let xml = read_part(&bytes, "ppt/slides/slide1.xml");
+    assert!(xml.contains(r#"<a:lin ang="2700000" scaled="1"/>"#));
+    assert!(xml.contains(r#"<a:path path="circle">"#));
+    assert!(xml.contains(r#"<a:lin ang="0" scaled="1"/>"#));
+}
+
 // ---------------------------------------------------------------------------
 // Rule-focused regressions
 // ---------------------------------------------------------------------------
@@ -13,8 +13,9 @@ use ppt_rs::generator::package_xml::{
 use ppt_rs::generator::{
     create_pptx, create_pptx_lazy_to_writer, create_pptx_to_writer,
     create_pptx_with_content, create_pptx_with_settings, ChartBuilder, ChartSeries,
-    ChartType, LazySlideSource, PresentationSettings, PresentationTheme, PrintSettings,
-    PrintWhat, SlideContent,
+    ChartType, Image, LazySlideSource, PresentationSettings, PresentationTheme, PrintSettings,
+    PenColor, PrintWhat, Shape, ShapeGroup, ShapeType, ShowType, SlideContent, SlideRange,
+    SlideShowSettings,
 };
 use ppt_rs::generator::slide_content::print_settings::HandoutLayout;
 use zip::ZipArchive;
@@ -135,6 +136,53 @@ fn lazy_api_passes_compat_gate() {
     assert_compat(&cursor.into_inner(), "lazy");
 }
 
+#[test]
+fn lazy_api_generates_each_slide_once() {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    struct Src {
+        generated: Rc<RefCell<Vec<usize>>>,
+    }
+    impl LazySlideSource for Src {
+        fn slide_count(&self) -> usize {
+            3
+        }
+        fn generate_slide(&self, index: usize) -> Option<SlideContent> {
+            self.generated.borrow_mut()[index] += 1;
+            let png = vec![0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A, 1, 2, 3, 4];
+            Some(match index {
+                0 => SlideContent::new("Chart").add_chart(bar_chart("Sales")).notes("Talk about sales"),
+                1 => SlideContent::new("Picture")
+                    .add_image(Image::from_bytes(png, 914_400, 914_400, "PNG"))
+                    .add_comment("Ann", "Crop this", 0, 0),
+                _ => SlideContent::new("").add_chart(bar_chart("Costs")).notes("Wrap up"),
+            })
+        }
+    }
+
+    let generated = Rc::new(RefCell::new(vec![0; 3]));
+    let src = Src { generated: Rc::clone(&generated) };
+    let eager: Vec<SlideContent> = (0..3).map(|i| src.generate_slide(i).unwrap()).collect();
+    generated.borrow_mut().fill(0);
+
+    let bytes = create_pptx_lazy_to_writer(Cursor::new(Vec::new()), "Lazy", Box::new(src), None)
+        .unwrap()
+        .into_inner();
+    assert_eq!(*generated.borrow(), [1, 1, 1]);
+    assert_compat(&bytes, "lazy-once");
+
+    let eager = create_pptx_with_content("Lazy", eager).unwrap();
+    let mut lazy_parts = ArchiveParts::from_bytes(&bytes).names;
+    let mut eager_parts = ArchiveParts::from_bytes(&eager).names;
+    lazy_parts.sort();
+    eager_parts.sort();
+    assert_eq!(lazy_parts, eager_parts);
+    for part in ["ppt/slides/_rels/slide3.xml.rels", "ppt/notesSlides/notesSlide2.xml", "docProps/app.xml"] {
+        assert_eq!(ArchiveParts::read_part(&bytes, part), ArchiveParts::read_part(&eager, part), "{part}");
+    }
+}
+
 #[test]
 fn custom_theme_deck_passes_compat_gate() {
     let settings = PresentationSettings::new().theme(PresentationTheme::corporate());
@@ -349,7 +397,8 @@ fn embedding_xlsx_is_non_empty_zip() {
 fn handout_deck_includes_handout_master() {
     let print = PrintSettings::default()
         .print_what(PrintWhat::Handouts)
-        .handout_layout(HandoutLayout::SlidesPerPage6);
+        .handout_layout(HandoutLayout::SlidesPerPage6)
+        .frame_slides(true);
     let settings = PresentationSettings::new().print(print);
     let bytes = create_pptx_with_settings(
         "Handouts",
@@ -374,10 +423,7 @@ fn handout_deck_includes_handout_master() {
     assert!(rels.contains("handoutMaster"));
 
     let pres_props = ArchiveParts::read_part(&bytes, "ppt/presProps.xml");
-    assert!(
-        !pres_props.contains("<p:prnPr"),
-        "handout master packaging should not pair with presProps prnPr"
-    );
+    assert!(pres_props.contains(r#"<p:prnPr prnWhat="handouts6" clrMode="clr" frameSlides="1""#));
 
     let handout = ArchiveParts::read_part(&bytes, "ppt/handoutMasters/handoutMaster1.xml");
     assert!(handout.contains("<p:handoutMaster"));
@@ -407,7 +453,92 @@ fn slides_print_mode_does_not_package_handout_master() {
     assert!(!pres.contains("<p:handoutMasterIdLst>"));
 
     let pres_props = ArchiveParts::read_part(&bytes, "ppt/presProps.xml");
-    assert!(!pres_props.contains("prnWhat"));
+    assert!(pres_props.contains(r#"prnWhat="slides""#));
+}
+
+#[test]
+fn page_header_footer_packages_handout_and_notes_masters() {
+    let settings = PresentationSettings::new()
+        .handout_header("Quarterly Review")
+        .notes_footer("Confidential");
+    let bytes = create_pptx_with_settings(
+        "HeaderFooter",
+        &[SlideContent::new("Slide 1")],
+        Some(settings),
+    )
+    .unwrap();
+    assert_compat(&bytes, "page-header-footer");
+
+    let parts = ArchiveParts::from_bytes(&bytes);
+    assert!(parts.has("ppt/handoutMasters/handoutMaster1.xml"));
+    assert!(parts.has("ppt/notesMasters/notesMaster1.xml"));
+    assert!(!parts.has("ppt/notesSlides/notesSlide1.xml"));
+
+    let handout = ArchiveParts::read_part(&bytes, "ppt/handoutMasters/handoutMaster1.xml");
+    assert!(handout.contains(r#"<p:ph type="hdr""#));
+    assert!(handout.contains("Quarterly Review"));
+    assert!(!handout.contains(r#"<p:ph type="ftr""#));
+
+    let notes_master = ArchiveParts::read_part(&bytes, "ppt/notesMasters/notesMaster1.xml");
+    assert!(notes_master.contains(r#"<p:ph type="ftr""#));
+    assert!(notes_master.contains("Confidential"));
+
+    let content_types = ArchiveParts::read_part(&bytes, "[Content_Types].xml");
+    assert!(content_types.contains("/ppt/notesMasters/notesMaster1.xml"));
+    assert!(content_types.contains("/ppt/handoutMasters/handoutMaster1.xml"));
+}
+
+#[test]
+fn notes_footer_is_repeated_on_notes_slides() {
+    let slides = vec![
+        SlideContent::new("Slide 1").notes("Speaker notes"),
+        SlideContent::new("Slide 2"),
+    ];
+    let settings = PresentationSettings::new().notes_footer("Internal & draft");
+    let bytes = create_pptx_with_settings("NotesFooter", &slides, Some(settings)).unwrap();
+    assert_compat(&bytes, "notes-footer");
+
+    let notes = ArchiveParts::read_part(&bytes, "ppt/notesSlides/notesSlide1.xml");
+    assert!(notes.contains("Speaker notes"));
+    assert!(notes.contains(r#"<p:ph type="ftr""#));
+    assert!(notes.contains("Internal &amp; draft"));
+}
+
+#[test]
+fn kiosk_show_settings_written_to_pres_props() {
+    let slides: Vec<_> = (1..=4).map(|i| SlideContent::new(&format!("Slide {i}"))).collect();
+    let show = SlideShowSettings::kiosk()
+        .pen_color(PenColor::new("00B050"))
+        .slide_range(SlideRange::Range { start: 2, end: 4 });
+    let settings = PresentationSettings::new().slide_show(show);
+    let bytes = create_pptx_with_settings("Kiosk", &slides, Some(settings)).unwrap();
+    assert_compat(&bytes, "kiosk-show");
+
+    let pres_props = ArchiveParts::read_part(&bytes, "ppt/presProps.xml");
+    assert!(pres_props.contains(
+        r#"<p:showPr loop="1" showNarration="0"><p:kiosk restart="300000"/><p:sldRg st="2" end="4"/><p:penClr><a:srgbClr val="00B050"/></p:penClr>"#
+    ));
+}
+
+#[test]
+fn custom_show_range_lists_slides_in_presentation() {
+    let slides: Vec<_> = (1..=3).map(|i| SlideContent::new(&format!("Slide {i}"))).collect();
+    let show = SlideShowSettings::new()
+        .show_type(ShowType::Browsed)
+        .slide_range(SlideRange::Custom(vec![slide_id_value(1) as u32, slide_id_value(3) as u32]));
+    let settings = PresentationSettings::new().slide_show(show);
+    let bytes = create_pptx_with_settings("Custom", &slides, Some(settings)).unwrap();
+    assert_compat(&bytes, "custom-show");
+
+    let pres_props = ArchiveParts::read_part(&bytes, "ppt/presProps.xml");
+    assert!(pres_props.contains(r#"<p:browse showScrollbar="1"/><p:custShow id="0"/>"#));
+    let pres = ArchiveParts::read_part(&bytes, "ppt/presentation.xml");
+    let expected = format!(
+        r#"<p:custShowLst><p:custShow name="Custom Show 1" id="0"><p:sldLst><p:sldId r:id="rId{}"/><p:sldId r:id="rId{}"/></p:sldLst></p:custShow></p:custShowLst><p:defaultTextStyle>"#,
+        slide_rel_id(1, false, false),
+        slide_rel_id(3, false, false),
+    );
+    assert!(pres.contains(&expected));
 }
 
 // ---------------------------------------------------------------------------
@@ -443,6 +574,458 @@ fn notes_deck_wires_notes_master_and_shifts_slide_rids() {
     assert!(notes_rels.contains("theme/theme2.xml"), "notes master uses dedicated theme2");
 }
 
+#[test]
+fn shape_group_deck_passes_compat_gate() {
+    let group = ShapeGroup::new(1_000_000, 1_000_000, 2_000_000, 1_000_000)
+        .add_shape(Shape::new(ShapeType::Rectangle, 0, 0, 1_000_000, 1_000_000))
+        .add_shape(Shape::new(ShapeType::Ellipse, 1_000_000, 0, 1_000_000, 1_000_000));
+    let slide = SlideContent::new("Grouped")
+        .add_shape(Shape::new(ShapeType::Rectangle, 0, 0, 100, 100))
+        .add_shape_group(group);
+    let bytes = create_pptx_with_content("Groups", vec![slide]).unwrap();
+    assert_compat(&bytes, "shape-group");
+
+    let xml = ArchiveParts::read_part(&bytes, "ppt/slides/slide1.xml");
+    assert_eq!(xml.matches("<p:grpSp>").count(), 1);
+    assert!(xml.contains(r#"<a:chExt cx="2000000" cy="1000000"/>"#));
+    // The group and its children continue the slide's id sequence after the shapes
+    let group_xml = &xml[xml.find("<p:grpSp>").unwrap()..];
+    assert!(group_xml.contains(r#"<p:cNvPr id="101""#), "{group_xml}");
+    assert!(group_xml.contains(r#"<p:cNvPr id="103""#), "{group_xml}");
+}
+
+#[test]
+fn diagram_deck_passes_compat_gate() {
+    use ppt_rs::{Diagram, DiagramNode};
+
+    let org = DiagramNode::new("CEO")
+        .child(DiagramNode::new("CTO").children(vec!["Dev", "Ops"]))
+        .child(DiagramNode::new("CFO"));
+    let slides = vec![
+        SlideContent::new("Process")
+            .add_shape_group(Diagram::process(vec!["Plan", "Build", "Test", "Ship", "Learn"]).build()),
+        SlideContent::new("Cycle").add_shape_group(Diagram::cycle(vec!["Plan", "Do", "Check", "Act"]).build()),
+        SlideContent::new("Org").add_shape_group(Diagram::hierarchy(org).build()),
+    ];
+    let bytes = create_pptx_with_content("Diagrams", slides).unwrap();
+    assert_compat(&bytes, "diagrams");
+
+    let xml = ArchiveParts::read_part(&bytes, "ppt/slides/slide1.xml");
+    assert_eq!(xml.matches(r#"<a:prstGeom prst="roundRect">"#).count(), 5);
+    assert_eq!(xml.matches("<p:cxnSp>").count(), 4);
+}
+
+#[test]
+fn freeform_shape_deck_passes_compat_gate() {
+    use ppt_rs::core::Dimension;
+
+    let arrow = Shape::freeform(
+        vec![
+            (Dimension::Inches(1.0), Dimension::Inches(2.0)),
+            (Dimension::Inches(3.0), Dimension::Inches(2.0)),
+            (Dimension::Inches(3.0), Dimension::Inches(1.5)),
+            (Dimension::Inches(4.0), Dimension::Inches(2.5)),
+            (Dimension::Inches(3.0), Dimension::Inches(3.5)),
+            (Dimension::Inches(3.0), Dimension::Inches(3.0)),
+            (Dimension::Inches(1.0), Dimension::Inches(3.0)),
+        ],
+        true,
+    );
+    let slide = SlideContent::new("Freeform").add_shape(arrow);
+    let bytes = create_pptx_with_content("Freeform", vec![slide]).unwrap();
+    assert_compat(&bytes, "freeform");
+
+    let xml = ArchiveParts::read_part(&bytes, "ppt/slides/slide1.xml");
+    assert!(xml.contains(r#"<a:path w="2743200" h="1828800">"#));
+}
+
+#[test]
+fn vertical_text_passes_compat_gate() {
+    use ppt_rs::generator::{Table, TableCell, TableRow, TextFrame, TextOrientation};
+
+    let sidebar = Shape::new(ShapeType::Rectangle, 0, 0, 685_800, 6_858_000).with_text_frame(
+        TextFrame::with_text("Draft for review").text_direction(TextOrientation::Vertical270),
+    );
+    let badge = Shape::new(ShapeType::Rectangle, 6_000_000, 500_000, 1_500_000, 500_000)
+        .with_text_frame(TextFrame::with_text("New").rotation(-15));
+    let table = Table::new(
+        vec![TableRow::new(vec![
+            TableCell::new("Q1").text_direction(TextOrientation::Vertical),
+            TableCell::new("Revenue"),
+        ])],
+        vec![914_400, 2_743_200],
+        1_828_800,
+        1_828_800,
+    );
+    let slide = SlideContent::new("Sidebar").add_shape(sidebar).add_shape(badge).table(table);
+    let bytes = create_pptx_with_content("Vertical", vec![slide]).unwrap();
+    assert_compat(&bytes, "vertical-text");
+
+    let xml = ArchiveParts::read_part(&bytes, "ppt/slides/slide1.xml");
+    assert!(xml.contains(r#"vert="vert270"/><a:lstStyle/>"#));
+    assert!(xml.contains(r#"rot="-900000"/>"#));
+    assert!(xml.contains(r#"<a:tcPr vert="vert"/>"#));
+}
+
+#[test]
+fn agenda_links_jump_to_slides() {
+    use ppt_rs::generator::{Hyperlink, HyperlinkAction};
+
+    let item = |y: u32, text: &str, link: Hyperlink| {
+        Shape::new(ShapeType::Rectangle, 914_400, y, 4_572_000, 457_200)
+            .with_text(text)
+            .with_hyperlink(link)
+    };
+    let agenda = SlideContent::new("Agenda")
+        .add_shape(item(1_371_600, "Results", Hyperlink::jump_to_slide(2)))
+        .add_shape(item(1_828_800, "Outlook", Hyperlink::new(HyperlinkAction::slide(2))))
+        .add_shape(item(2_286_000, "Next", Hyperlink::new(HyperlinkAction::NextSlide)))
+        .add_shape(item(2_743_200, "Docs", Hyperlink::url("https://example.com")));
+    let slides = vec![
+        agenda,
+        SlideContent::new("Outlook").add_bullet("Growth"),
+        SlideContent::new("Results").add_bullet("Up 10%"),
+    ];
+    let bytes = create_pptx_with_content("Agenda", slides).unwrap();
+    assert_compat(&bytes, "agenda-links");
+
+    let xml = ArchiveParts::read_part(&bytes, "ppt/slides/slide1.xml");
+    assert!(xml.contains(r#"<a:hlinkClick r:id="rId2" highlightClick="1" action="ppaction://hlinksldjump"/>"#));
+    assert!(xml.contains(r#"<a:hlinkClick r:id="rId3" highlightClick="1" action="ppaction://hlinksldjump"/>"#));
+    assert!(xml.contains(r#"<a:hlinkClick r:id="" highlightClick="1" action="ppaction://hlinkshowjump?jump=nextslide"/>"#));
+    assert!(xml.contains(r#"<a:hlinkClick r:id="rId4" highlightClick="1"/>"#));
+
+    let rels = ArchiveParts::read_part(&bytes, "ppt/slides/_rels/slide1.xml.rels");
+    assert!(rels.contains(r#"Id="rId2" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/slide" Target="slide3.xml""#));
+    assert!(rels.contains(r#"Id="rId3" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/slide" Target="slide2.xml""#));
+    assert!(rels.contains(r#"Target="https://example.com" TargetMode="External""#));
+    assert!(!rels.contains("ppaction"));
+}
+
+#[test]
+fn action_buttons_navigate_by_default() {
+    use ppt_rs::generator::{Hyperlink, HyperlinkAction};
+
+    let next = || Shape::new(ShapeType::ActionButtonForwardNext, 8_001_000, 6_172_200, 914_400, 457_200);
+    let home = Shape::new(ShapeType::ActionButtonHome, 0, 6_172_200, 914_400, 457_200)
+        .with_hyperlink(Hyperlink::new(HyperlinkAction::EndShow));
+    let slides = vec![
+        SlideContent::new("Welcome").add_shape(next()),
+        SlideContent::new("Tour").add_shape(next()).add_shape(home),
+    ];
+    let bytes = create_pptx_with_content("Kiosk", slides).unwrap();
+    assert_compat(&bytes, "action-buttons");
+
+    for slide in ["ppt/slides/slide1.xml", "ppt/slides/slide2.xml"] {
+        let xml = ArchiveParts::read_part(&bytes, slide);
+        assert!(xml.contains(r#"<a:prstGeom prst="actionButtonForwardNext">"#));
+        assert!(xml.contains(r#"<a:hlinkClick r:id="" highlightClick="1" action="ppaction://hlinkshowjump?jump=nextslide"/>"#));
+    }
+    // An explicit hyperlink replaces the button's default
+    let tour = ArchiveParts::read_part(&bytes, "ppt/slides/slide2.xml");
+    assert!(tour.contains("jump=endshow"));
+    assert!(!tour.contains("jump=firstslide"));
+}
+
+#[test]
+fn link_past_last_slide_is_rejected() {
+    use ppt_rs::generator::Hyperlink;
+
+    let link = Shape::new(ShapeType::Rectangle, 0, 0, 914_400, 914_400).with_hyperlink(Hyperlink::jump_to_slide(5));
+    let slides = vec![SlideContent::new("Agenda").add_shape(link), SlideContent::new("Only")];
+    let err = create_pptx_with_content("Agenda", slides).unwrap_err();
+    assert!(err.to_string().contains("links to slide index 5 (0-based), but the deck has 2 slides"));
+
+    let link = Shape::new(ShapeType::Rectangle, 0, 0, 914_400, 914_400).with_hyperlink(Hyperlink::slide(3));
+    let slides = vec![SlideContent::new("Agenda").add_shape(link), SlideContent::new("Only")];
+    let err = create_pptx_with_content("Agenda", slides).unwrap_err();
+    assert!(err.to_string().contains("links to slide 3, but the deck has 2 slides"));
+}
+
+#[test]
+fn mistyped_color_is_rejected() {
+    use ppt_rs::generator::ShapeFill;
+
+    let typo = Shape::new(ShapeType::Rectangle, 0, 0, 914_400, 914_400).with_fill(ShapeFill::new("#FF000"));
+    let err = create_pptx_with_content("Typo", vec![SlideContent::new("Red").add_shape(typo)]).unwrap_err();
+    assert!(err.to_string().contains(r##"Invalid shape 1 fill: "#FF000" is not a hex color"##), "{err}");
+
+    let settings = PresentationSettings::new().slide_show(SlideShowSettings::new().pen_color(PenColor::new("redd")));
+    let err = create_pptx_with_settings("Typo", &[SlideContent::new("Pen")], Some(settings)).unwrap_err();
+    assert!(err.to_string().contains(r#"Invalid slide show pen color: "redd""#), "{err}");
+
+    // InkML brushes take hex only, so a theme color is rejected there
+    use ppt_rs::generator::{InkAnnotations, InkPen, InkStroke};
+    let mut ink = InkAnnotations::new();
+    ink.add_stroke(InkStroke::new(InkPen::new("accent1", 2)));
+    let err = create_pptx_with_content("Ink", vec![SlideContent::new("Ink").with_ink(ink)]).unwrap_err();
+    assert!(err.to_string().contains(r#"Invalid ink stroke 1 pen: "accent1" is not a hex color"#), "{err}");
+
+    let png = vec![0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A, 1, 2, 3, 4];
+    let image = Image::from_bytes(png.clone(), 914_400, 914_400, "PNG").duotone("accent1", "navvy");
+    let err = create_pptx_with_content("Duotone", vec![SlideContent::new("Tint").add_image(image)]).unwrap_err();
+    assert!(err.to_string().contains(r#"Invalid image 1 duotone light: "navvy""#), "{err}");
+
+    let image = Image::from_bytes(png, 914_400, 914_400, "PNG").border(12_700, "#12345");
+    let err = create_pptx_with_content("Border", vec![SlideContent::new("Frame").add_image(image)]).unwrap_err();
+    assert!(err.to_string().contains(r##"Invalid image 1 border: "#12345""##), "{err}");
+}
+
+#[test]
+fn raw_shape_xml_is_spliced_with_fresh_ids() {
+    let raw = r#"<p:sp><p:nvSpPr><p:cNvPr id="2" name="Raw star"><a:hlinkClick r:id="rIdRaw1"/></p:cNvPr><p:cNvSpPr/><p:nvPr/></p:nvSpPr><p:spPr><a:xfrm><a:off x="914400" y="914400"/><a:ext cx="914400" cy="914400"/></a:xfrm><a:prstGeom prst="star5"><a:avLst/></a:prstGeom></p:spPr></p:sp>"#;
+    let slide = SlideContent::new("Raw")
+        .add_shape(Shape::new(ShapeType::Rectangle, 0, 0, 914_400, 914_400))
+        .add_raw_shape_xml(raw)
+        .unwrap()
+        .add_raw_relationship(
+            "http://schemas.openxmlformats.org/officeDocument/2006/relationships/hyperlink",
+            "https://example.com",
+        );
+    let bytes = create_pptx_with_content("Raw", vec![slide]).unwrap();
+    assert_compat(&bytes, "raw-shape-xml");
+
+    let xml = ArchiveParts::read_part(&bytes, "ppt/slides/slide1.xml");
+    assert_eq!(xml.matches(r#"<p:cNvPr id="2" "#).count(), 1);
+    assert!(xml.contains(r#"name="Raw star""#));
+    let rels = ArchiveParts::read_part(&bytes, "ppt/slides/_rels/slide1.xml.rels");
+    assert!(rels.contains(r#"Id="rIdRaw1""#));
+    assert!(rels.contains(r#"Target="https://example.com" TargetMode="External""#));
+
+    assert!(SlideContent::new("Bad").add_raw_shape_xml("<p:sp><p:spPr></p:sp>").is_err());
+}
+
+#[test]
+fn custom_slide_master_cascades_to_layouts() {
+    use ppt_rs::generator::{MasterBuilder, PlaceholderType, TextFormat};
+
+    let master = MasterBuilder::new()
+        .title_style(TextFormat::new().font_size(40).bold().color("1F3864"))
+        .background_color("F2F2F2")
+        .title_position(457_200, 150_000, 8_229_600, 900_000)
+        .build();
+    let settings = PresentationSettings::new().slide_master(master).print(PrintSettings::new().footer("Draft"));
+    let slide = SlideContent::new("").set_placeholder(PlaceholderType::Title, "Agenda");
+    let bytes = create_pptx_with_settings("Master", &[slide], Some(settings)).unwrap();
+    assert_compat(&bytes, "custom-slide-master");
+
+    let xml = ArchiveParts::read_part(&bytes, "ppt/slideMasters/slideMaster1.xml");
+    assert!(xml.contains(r#"<p:bg><p:bgPr><a:solidFill><a:srgbClr val="F2F2F2"/></a:solidFill>"#));
+    assert!(xml.contains(r#"<p:titleStyle><a:lvl1pPr algn="ctr" defTabSz="457200" rtl="0" eaLnBrk="1" latinLnBrk="0" hangingPunct="1"><a:spcBef><a:spcPct val="0"/></a:spcBef><a:buNone/><a:defRPr sz="4000" b="1" kern="1200"><a:solidFill><a:srgbClr val="1F3864"/></a:solidFill>"#));
+    assert!(xml.contains(r#"<p:ph type="title"/></p:nvPr></p:nvSpPr><p:spPr><a:xfrm><a:off x="457200" y="150000"/>"#));
+    assert!(xml.contains(r#"<p:cNvPr id="2" name="Footer Placeholder"/>"#));
+    assert!(xml.contains(r#"<p:cNvPr id="3" name="Title Placeholder"/>"#));
+
+    // Layouts at the stock title frame follow the master; custom ones keep theirs
+    let content_layout = ArchiveParts::read_part(&bytes, "ppt/slideLayouts/slideLayout2.xml");
+    assert!(content_layout.contains(r#"<a:off x="457200" y="150000"/><a:ext cx="8229600" cy="900000"/>"#));
+    let title_layout = ArchiveParts::read_part(&bytes, "ppt/slideLayouts/slideLayout1.xml");
+    assert!(title_layout.contains(r#"<a:off x="1524000" y="1828800"/>"#));
+
+    let default = create_pptx_with_content("Stock", vec![SlideContent::new("Stock")]).unwrap();
+    let stock = ArchiveParts::read_part(&default, "ppt/slideMasters/slideMaster1.xml");
+    assert!(stock.contains(r#"<a:defRPr sz="4400" kern="1200">"#));
+    assert!(!stock.contains("Title Placeholder"));
+}
+
+#[test]
+fn slides_pick_between_multiple_masters() {
+    use ppt_rs::generator::{MasterBuilder, SlideLayout, TextFormat};
+
+    let dark = MasterBuilder::new()
+        .background_color("1F1F1F")
+        .title_style(TextFormat::new().color("FFFFFF"))
+        .theme(PresentationTheme::corporate_blue())
+        .build();
+    let settings = PresentationSettings::new().add_master(dark).notes_footer("Confidential").handout_header("Quarterly");
+    let slides = vec![
+        SlideContent::new("Part one").layout(SlideLayout::SectionHeader).use_master(2),
+        SlideContent::new("Details").add_bullet("Body"),
+    ];
+    let bytes = create_pptx_with_settings("Masters", &slides, Some(settings)).unwrap();
+    assert_compat(&bytes, "multiple-masters");
+
+    let parts = ArchiveParts::from_bytes(&bytes);
+    assert!(parts.has("ppt/slideMasters/slideMaster2.xml"));
+    assert!(parts.has("ppt/slideLayouts/slideLayout18.xml"));
+    assert!(parts.has("ppt/theme/theme4.xml"));
+
+    let presentation = ArchiveParts::read_part(&bytes, "ppt/presentation.xml");
+    assert!(presentation.contains(r#"<p:sldMasterId id="2147483648" r:id="rId1"/>"#));
+    assert!(presentation.contains(r#"<p:sldMasterId id="2147483660" r:id="rIdMaster2"/>"#));
+    let rels = ArchiveParts::read_part(&bytes, "ppt/_rels/presentation.xml.rels");
+    assert!(rels.contains(r#"Id="rIdMaster2" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/slideMaster" Target="slideMasters/slideMaster2.xml""#));
+
+    let section = ArchiveParts::read_part(&bytes, "ppt/slides/_rels/slide1.xml.rels");
+    assert!(section.contains("../slideLayouts/slideLayout13.xml"));
+    let details = ArchiveParts::read_part(&bytes, "ppt/slides/_rels/slide2.xml.rels");
+    assert!(details.contains("../slideLayouts/slideLayout2.xml"));
+
+    let master = ArchiveParts::read_part(&bytes, "ppt/slideMasters/slideMaster2.xml");
+    assert!(master.contains(r#"<p:sldLayoutId id="2147483661" r:id="rId1"/>"#));
+    let master_rels = ArchiveParts::read_part(&bytes, "ppt/slideMasters/_rels/slideMaster2.xml.rels");
+    assert!(master_rels.contains("../slideLayouts/slideLayout10.xml"));
+    assert!(master_rels.contains("../theme/theme4.xml"));
+    let layout_rels = ArchiveParts::read_part(&bytes, "ppt/slideLayouts/_rels/slideLayout10.xml.rels");
+    assert!(layout_rels.contains("../slideMasters/slideMaster2.xml"));
+
+    let err = create_pptx_with_content("Masters", vec![SlideContent::new("Lost").use_master(3)]).unwrap_err();
+    assert!(err.to_string().contains("slide uses master 3, but the deck has 1 masters"));
+}
+
+#[test]
+fn slide_backgrounds_pass_compat_gate() {
+    use ppt_rs::generator::GradientFill;
+
+    let png = vec![0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A, 1, 2, 3, 4];
+    let slides = vec![
+        SlideContent::new("Dark title").background_color("#1F1F1F"),
+        SlideContent::new("Light content").add_bullet("Body"),
+        SlideContent::new("Gradient").background_gradient(GradientFill::two_color("003366", "66CCFF")),
+        SlideContent::new("Picture")
+            .add_image(Image::from_bytes(png.clone(), 914_400, 914_400, "PNG"))
+            .background_image(Image::from_bytes(png, 914_400, 914_400, "PNG")),
+    ];
+    let bytes = create_pptx_with_content("Backgrounds", slides).unwrap();
+    assert_compat(&bytes, "slide-backgrounds");
+
+    let dark = ArchiveParts::read_part(&bytes, "ppt/slides/slide1.xml");
+    assert!(dark.contains(r#"<p:bg><p:bgPr><a:solidFill><a:srgbClr val="1F1F1F"/></a:solidFill><a:effectLst/></p:bgPr></p:bg>"#));
+    assert_eq!(dark.matches("<p:bg>").count(), 1);
+    let light = ArchiveParts::read_part(&bytes, "ppt/slides/slide2.xml");
+    assert!(light.contains(r#"<p:bgRef idx="1001">"#));
+    let gradient = ArchiveParts::read_part(&bytes, "ppt/slides/slide3.xml");
+    assert!(gradient.contains("<p:bgPr><a:gradFill"));
+
+    // The background shares the media part with the identical picture and gets its own rel
+    let picture = ArchiveParts::read_part(&bytes, "ppt/slides/slide4.xml");
+    assert!(picture.contains(r#"<a:blip r:embed="rId3"/><a:srcRect/><a:stretch>"#));
+    let rels = ArchiveParts::read_part(&bytes, "ppt/slides/_rels/slide4.xml.rels");
+    assert!(rels.contains(r#"Id="rId3""#));
+    assert_eq!(rels.matches("../media/image1.png").count(), 2);
+}
+
+#[test]
+fn footer_and_slide_numbers_pass_compat_gate() {
+    use ppt_rs::generator::DateMode;
+
+    let slides = vec![
+        SlideContent::new("Intro").add_bullet("One"),
+        SlideContent::new("Details").add_shape(Shape::new(ShapeType::Rectangle, 914_400, 914_400, 914_400, 914_400)),
+    ];
+    let settings = PresentationSettings::new()
+        .footer("Confidential — 2024")
+        .show_slide_numbers(true)
+        .show_date(DateMode::Auto);
+    let bytes = create_pptx_with_settings("Footers", &slides, Some(settings)).unwrap();
+    assert_compat(&bytes, "footer-slide-numbers");
+
+    for n in 1..=2 {
+        let xml = ArchiveParts::read_part(&bytes, &format!("ppt/slides/slide{n}.xml"));
+        assert!(xml.contains("<a:t>Confidential — 2024</a:t>"));
+        assert!(xml.contains(&format!(r#"type="slidenum"><a:rPr lang="en-US" dirty="0"/><a:t>{n}</a:t>"#)));
+        assert!(xml.contains(r#"<p:ph type="dt" sz="half" idx="10"/>"#));
+    }
+}
+
+#[test]
+fn sections_pass_compat_gate() {
+    let slides: Vec<SlideContent> = (1..=6).map(|i| SlideContent::new(&format!("Slide {i}"))).collect();
+    let settings = PresentationSettings::new()
+        .add_section("Results", 1..4).unwrap()
+        .add_section("Appendix", 4..6).unwrap();
+    let bytes = create_pptx_with_settings("Sections", &slides, Some(settings)).unwrap();
+    assert_compat(&bytes, "sections");
+
+    let xml = ArchiveParts::read_part(&bytes, "ppt/presentation.xml");
+    let sections = xml.find("<p14:sectionLst").unwrap();
+    assert!(sections < xml.find("<p15:sldGuideLst").unwrap());
+    assert!(xml.contains(r#"<p14:section name="Default Section""#));
+    assert!(xml.contains(r#"<p14:section name="Results""#));
+    assert_eq!(xml.matches("<p14:sldId ").count(), 6);
+    assert_eq!(xml.matches("<p:extLst>").count(), 1);
+}
+
+#[test]
+fn slide_transitions_pass_compat_gate() {
+    use ppt_rs::generator::{TransitionDirection, TransitionType};
+
+    let slides = vec![
+        SlideContent::new("Plain"),
+        SlideContent::new("Push")
+            .transition(TransitionType::Push)
+            .transition_direction(TransitionDirection::Up)
+            .transition_duration_ms(1200),
+        SlideContent::new("Kiosk")
+            .transition(TransitionType::Fade)
+            .advance_on_click(false)
+            .advance_after_ms(4000),
+    ];
+    let bytes = create_pptx_with_content("Transitions", slides).unwrap();
+    assert_compat(&bytes, "slide-transitions");
+
+    assert!(!ArchiveParts::read_part(&bytes, "ppt/slides/slide1.xml").contains("transition"));
+    let push = ArchiveParts::read_part(&bytes, "ppt/slides/slide2.xml");
+    assert!(push.contains(r#"<p:transition spd="slow" p14:dur="1200"><p:push dir="u"/></p:transition>"#));
+    let kiosk = ArchiveParts::read_part(&bytes, "ppt/slides/slide3.xml");
+    assert!(kiosk.contains(r#"<p:transition advClick="0" advTm="4000"><p:fade/></p:transition></p:sld>"#));
+}
+
+#[test]
+fn shape_animations_pass_compat_gate() {
+    use ppt_rs::generator::{Animation, AnimationEffect, AnimationTrigger};
+
+    let slides = vec![
+        SlideContent::new("Agenda")
+            .add_bullet("First")
+            .add_bullet("Second")
+            .add_bullet("Third")
+            .animate_bullets(Animation::new(AnimationEffect::FadeIn)),
+        SlideContent::new("Callout")
+            .add_shape(Shape::new(ShapeType::Rectangle, 914_400, 914_400, 914_400, 914_400).with_id(40))
+            .animate(40, Animation::new(AnimationEffect::FlyIn).trigger(AnimationTrigger::AfterPrevious))
+            .animate(40, Animation::exit(AnimationEffect::Zoom).delay_ms(1000)),
+    ];
+    let bytes = create_pptx_with_content("Animations", slides).unwrap();
+    assert_compat(&bytes, "shape-animations");
+
+    let agenda = ArchiveParts::read_part(&bytes, "ppt/slides/slide1.xml");
+    assert_eq!(agenda.matches(r#"nodeType="clickEffect""#).count(), 3);
+    assert!(agenda.contains(r#"<p:pRg st="2" end="2"/>"#));
+    assert!(agenda.contains(r#"<p:bldP spid="3" grpId="0" build="p"/>"#));
+    assert!(agenda.trim_end().ends_with("</p:timing></p:sld>"));
+
+    let callout = ArchiveParts::read_part(&bytes, "ppt/slides/slide2.xml");
+    assert!(callout.contains(r#"<p:cNvPr id="40""#));
+    assert!(callout.contains(r#"<p:bldP spid="40" grpId="0" animBg="1"/><p:bldP spid="40" grpId="1" animBg="1"/>"#));
+}
+
+#[test]
+fn svg_image_deck_embeds_svg_with_png_fallback() {
+    let png = vec![0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A, 0, 0, 0, 0];
+    let svg = br#"<svg xmlns="http://www.w3.org/2000/svg" width="10" height="10"/>"#.to_vec();
+    let image = Image::from_bytes(png, 914_400, 914_400, "PNG").with_svg(svg);
+    let chart = ChartBuilder::new("Sales", ChartType::Bar)
+        .categories(vec!["Q1"])
+        .add_series(ChartSeries::new("2024", vec![1.0]))
+        .build();
+    let slide = SlideContent::new("Vector").add_image(image).add_chart(chart);
+    let bytes = create_pptx_with_content("SVG", vec![slide]).unwrap();
+    assert_compat(&bytes, "svg-image");
+
+    let content_types = ArchiveParts::read_part(&bytes, "[Content_Types].xml");
+    assert!(content_types.contains(r#"<Default Extension="svg" ContentType="image/svg+xml"/>"#));
+
+    // Layout, PNG, chart, then the SVG part
+    let rels = ArchiveParts::read_part(&bytes, "ppt/slides/_rels/slide1.xml.rels");
+    assert!(rels.contains(r#"Id="rId2""#) && rels.contains("../media/image1.png"));
+    assert!(rels.contains(r#"Id="rId4""#) && rels.contains("../media/image2.svg"));
+
+    let xml = ArchiveParts::read_part(&bytes, "ppt/slides/slide1.xml");
+    assert!(xml.contains(r#"<a:blip r:embed="rId2">"#));
+    assert!(xml.contains(r#"<asvg:svgBlip xmlns:asvg="http://schemas.microsoft.com/office/drawing/2016/SVG/main" r:embed="rId4"/>"#));
+}
+
 #[test]
 fn notes_and_handouts_both_shift_slide_rids() {
     let print = PrintSettings::default()
@@ -544,3 +1127,34 @@ fn bisect_matrix_all_variants_pass_compat_gate() {
         file.write_all(&bytes).unwrap();
     }
 }
+
+#[test]
+fn highlighted_code_blocks_pass_compat_gate() {
+    use ppt_rs::generator::{CodeBlock, CodeTheme};
+
+    let mut slide = SlideContent::new("Code");
+    slide.code_blocks.push(CodeBlock::new("fn main() {\n    let x = 1; // one\n}", "rust").theme(CodeTheme::github_light()));
+    slide.code_blocks.push(CodeBlock::new("PLAIN <text>", "").language("cobol").position(500000, 5000000).size(8000000, 800000));
+    let bytes = create_pptx_with_content("Code", vec![slide]).unwrap();
+    assert_compat(&bytes, "highlighted-code-blocks");
+
+    let xml = ArchiveParts::read_part(&bytes, "ppt/slides/slide1.xml");
+    assert!(xml.contains(r#"<a:srgbClr val="F6F8FA"/>"#));
+    assert!(xml.contains(r#"<a:srgbClr val="CF222E"/></a:solidFill><a:latin typeface="Consolas"/></a:rPr><a:t>fn </a:t>"#));
+    assert!(xml.contains(r#"<a:srgbClr val="6E7781"/></a:solidFill><a:latin typeface="Consolas"/></a:rPr><a:t>// one</a:t>"#));
+    assert!(xml.contains("<a:t>PLAIN &lt;text&gt;</a:t>"));
+}
+
+#[test]
+fn two_content_and_comparison_slides_pass_compat_gate() {
+    let slides = vec![
+        SlideContent::new("Pros vs Cons").left_bullets(["Fast", "Cheap"]).right_bullets(["Fragile"]),
+        SlideContent::new("Options")
+            .left_heading("Build")
+            .left_bullets(["Full control"])
+            .right_heading("Buy")
+            .right_bullets(["Ships today"]),
+    ];
+    let bytes = create_pptx_with_content("Columns", slides).unwrap();
+    assert_compat(&bytes, "two-content-comparison");
+}